@@ -0,0 +1,595 @@
+//! Events that borrow their variable-length contents from the packet buffer.
+//!
+//! [`Event::new`](super::Event::new) copies variable-length contents, such as advertising reports
+//! or attribute values, into fixed-size buffers that are large enough for the longest possible
+//! event. [`Event::new`] in this module deserializes the same packets without copying those
+//! contents: they are borrowed from the packet buffer instead, which keeps the stack usage of the
+//! caller low. Events without variable-length contents are not deserialized at all: they are
+//! returned as [`Event::Other`], and [`OtherEvent::to_event`] deserializes them on demand.
+
+use super::{
+    AdvertisingDataStatus, BisHandleIterator, CteType, Error, IqPacketStatus, IqSampleIterator,
+    LeAdvertisingReportInnerIterator, LeAdvertisingReportIterator,
+    LeExtendedAdvertisingReportInnerIterator, LeExtendedAdvertisingReportIterator, MAX_BIS_COUNT,
+    NUM_COMPLETED_PACKETS_PAIR_LEN, NumberOfCompletedPacketsIterator, PACKET_HEADER_LENGTH, Packet,
+    Phy, SlotDuration, rewrap_bad_status, to_duration_us, to_iso_interval,
+    validate_iq_sample_count,
+};
+use crate::vendor::event::borrowed::VendorEvent;
+use byteorder::{ByteOrder, LittleEndian};
+use core::convert::TryInto;
+
+/// Potential events that can be generated by the controller, borrowing their variable-length
+/// contents from the packet buffer.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Event<'a> {
+    /// Vol 2, Part E, Section 7.7.19
+    NumberOfCompletedPackets(NumberOfCompletedPackets<'a>),
+
+    /// Vol 2, Part E, Section 7.7.65.2
+    LeAdvertisingReport(LeAdvertisingReport<'a>),
+
+    /// Vol 4, Part E, Section 7.7.65.13
+    LeExtendedAdvertisingReport(LeExtendedAdvertisingReport<'a>),
+
+    /// Vol 4, Part E, Section 7.7.65.15
+    LePeriodicAdvertisingReport(LePeriodicAdvertisingReport<'a>),
+
+    /// Vol 4, Part E, Section 7.7.65.21
+    LeConnectionlessIqReport(LeConnectionlessIqReport<'a>),
+
+    /// Vol 4, Part E, Section 7.7.65.22
+    LeConnectionIqReport(LeConnectionIqReport<'a>),
+
+    /// Vol 4, Part E, Section 7.7.65.27
+    LeCreateBigComplete(LeCreateBigComplete<'a>),
+
+    /// Vol 4, Part E, Section 7.7.65.29
+    LeBigSyncEstablished(LeBigSyncEstablished<'a>),
+
+    /// Vendor-specific events (opcode 0xFF)
+    Vendor(VendorEvent<'a>),
+
+    /// Any other event, borrowed from the packet buffer without being deserialized.
+    Other(OtherEvent<'a>),
+}
+
+impl<'a> Event<'a> {
+    /// Deserializes an event from the given packet, borrowing variable-length contents from it.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Event::new`](super::Event::new), except that [`Event::Other`] is returned for
+    /// events that are unknown or cannot be deserialized, as long as the packet is long enough to
+    /// contain them. [`OtherEvent::to_event`] returns those errors instead.
+    pub fn new(packet: Packet<'a>) -> Result<Event<'a>, Error> {
+        require_len_at_least!(packet.0, PACKET_HEADER_LENGTH);
+        require_len!(packet.0, packet.full_length());
+
+        let event_type = packet.0[super::EVENT_TYPE_BYTE];
+        let payload = &packet.0[PACKET_HEADER_LENGTH..packet.full_length()];
+
+        match event_type {
+            0x13 => Ok(Event::NumberOfCompletedPackets(
+                to_number_of_completed_packets(payload)?,
+            )),
+            0x3E if payload.first() == Some(&0x02) => Ok(Event::LeAdvertisingReport(
+                to_le_advertising_report(payload)?,
+            )),
+            0x3E if payload.first() == Some(&0x0D) => Ok(Event::LeExtendedAdvertisingReport(
+                to_le_extended_advertising_report(payload)?,
+            )),
+            0x3E if payload.first() == Some(&0x0F) => Ok(Event::LePeriodicAdvertisingReport(
+                to_le_periodic_advertising_report(payload)?,
+            )),
+            0x3E if payload.first() == Some(&0x15) => Ok(Event::LeConnectionlessIqReport(
+                to_le_connectionless_iq_report(payload)?,
+            )),
+            0x3E if payload.first() == Some(&0x16) => Ok(Event::LeConnectionIqReport(
+                to_le_connection_iq_report(payload)?,
+            )),
+            0x3E if payload.first() == Some(&0x1B) => Ok(Event::LeCreateBigComplete(
+                to_le_create_big_complete(payload)?,
+            )),
+            0x3E if payload.first() == Some(&0x1D) => Ok(Event::LeBigSyncEstablished(
+                to_le_big_sync_established(payload)?,
+            )),
+            0xFF => Ok(Event::Vendor(VendorEvent::new(payload)?)),
+            _ => Ok(Event::Other(OtherEvent {
+                packet: &packet.0[..packet.full_length()],
+            })),
+        }
+    }
+}
+
+/// An event that is not deserialized by [`Event::new`], borrowed from the packet buffer.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OtherEvent<'a> {
+    packet: &'a [u8],
+}
+
+impl<'a> OtherEvent<'a> {
+    /// Returns the event code.
+    pub fn event_code(&self) -> u8 {
+        self.packet[super::EVENT_TYPE_BYTE]
+    }
+
+    /// Returns the event parameters.
+    pub fn params(&self) -> &'a [u8] {
+        &self.packet[PACKET_HEADER_LENGTH..]
+    }
+
+    /// Deserializes the event the same way as [`Event::new`](super::Event::new).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Event::new`](super::Event::new).
+    pub fn to_event(&self) -> Result<super::Event, Error> {
+        super::Event::new(Packet(self.packet))
+    }
+}
+
+/// The [`Number of Completed Packets`](Event::NumberOfCompletedPackets) event, borrowing the
+/// connection handle-number of completed packets pairs from the packet buffer.
+///
+/// See [`NumberOfCompletedPackets`](super::NumberOfCompletedPackets).
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NumberOfCompletedPackets<'a> {
+    pub(super) data: &'a [u8],
+}
+
+impl<'a> NumberOfCompletedPackets<'a> {
+    /// Returns an iterator over the connection handle-number of completed packet pairs.
+    pub fn iter(&self) -> NumberOfCompletedPacketsIterator<'a> {
+        NumberOfCompletedPacketsIterator {
+            data: self.data,
+            next_index: 0,
+        }
+    }
+}
+
+pub(super) fn to_number_of_completed_packets(
+    payload: &[u8],
+) -> Result<NumberOfCompletedPackets<'_>, Error> {
+    require_len_at_least!(payload, 1);
+
+    let num_pairs = payload[0] as usize;
+    require_len!(payload, 1 + num_pairs * NUM_COMPLETED_PACKETS_PAIR_LEN);
+
+    Ok(NumberOfCompletedPackets {
+        data: &payload[1..],
+    })
+}
+
+/// The [LE Advertising Report](Event::LeAdvertisingReport) event, borrowing the advertising
+/// reports from the packet buffer.
+///
+/// See [`LeAdvertisingReport`](super::LeAdvertisingReport).
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeAdvertisingReport<'a> {
+    pub(super) data: &'a [u8],
+}
+
+impl<'a> LeAdvertisingReport<'a> {
+    /// Returns an iterator over the advertisements from the event.
+    pub fn iter(&self) -> LeAdvertisingReportIterator<'a> {
+        LeAdvertisingReportIterator {
+            inner_iter: LeAdvertisingReportInnerIterator {
+                event_data: self.data,
+                next_index: 0,
+            },
+        }
+    }
+}
+
+pub(super) fn to_le_advertising_report(payload: &[u8]) -> Result<LeAdvertisingReport<'_>, Error> {
+    require_len_at_least!(payload, 2);
+
+    let mut check_iter = LeAdvertisingReportInnerIterator {
+        event_data: &payload[2..],
+        next_index: 0,
+    };
+    while (check_iter.next()?).is_some() {}
+
+    Ok(LeAdvertisingReport {
+        data: &payload[2..],
+    })
+}
+
+/// The [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport) event, borrowing the
+/// advertising reports from the packet buffer.
+///
+/// See [`LeExtendedAdvertisingReport`](super::LeExtendedAdvertisingReport).
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeExtendedAdvertisingReport<'a> {
+    pub(super) data: &'a [u8],
+}
+
+impl<'a> LeExtendedAdvertisingReport<'a> {
+    /// Returns an iterator over the advertisements from the event.
+    pub fn iter(&self) -> LeExtendedAdvertisingReportIterator<'a> {
+        LeExtendedAdvertisingReportIterator {
+            inner_iter: LeExtendedAdvertisingReportInnerIterator {
+                event_data: self.data,
+                next_index: 0,
+            },
+        }
+    }
+}
+
+pub(super) fn to_le_extended_advertising_report(
+    payload: &[u8],
+) -> Result<LeExtendedAdvertisingReport<'_>, Error> {
+    require_len_at_least!(payload, 2);
+
+    let mut check_iter = LeExtendedAdvertisingReportInnerIterator {
+        event_data: &payload[2..],
+        next_index: 0,
+    };
+    while (check_iter.next()?).is_some() {}
+
+    Ok(LeExtendedAdvertisingReport {
+        data: &payload[2..],
+    })
+}
+
+/// The [LE Periodic Advertising Report](Event::LePeriodicAdvertisingReport) event, borrowing the
+/// advertising data from the packet buffer.
+///
+/// See [`LePeriodicAdvertisingReport`](super::LePeriodicAdvertisingReport).
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LePeriodicAdvertisingReport<'a> {
+    /// Periodic advertising train the packet was received from.
+    pub sync_handle: crate::SyncHandle,
+
+    /// Transmit power of the advertiser, from -127 dBm to 20 dBm. `None` if not available.
+    pub tx_power: Option<crate::Dbm>,
+
+    /// Received signal strength, from -127 dBm to 20 dBm. `None` if not available.
+    pub rssi: Option<i8>,
+
+    /// Type of Constant Tone Extension in the periodic advertising packet. `None` if there is no
+    /// Constant Tone Extension.
+    pub cte_type: Option<CteType>,
+
+    /// Completeness of the data.
+    pub data_status: AdvertisingDataStatus,
+
+    /// Periodic advertising data, formatted as defined in Vol 3, Part C, Section 11 of the spec.
+    pub data: &'a [u8],
+}
+
+pub(super) fn to_le_periodic_advertising_report(
+    payload: &[u8],
+) -> Result<LePeriodicAdvertisingReport<'_>, Error> {
+    require_len_at_least!(payload, 8);
+    require_len!(payload, 8 + payload[7] as usize);
+
+    Ok(LePeriodicAdvertisingReport {
+        sync_handle: crate::SyncHandle(LittleEndian::read_u16(&payload[1..])),
+        tx_power: match u8::cast_signed(payload[3]) {
+            127 => None,
+            value => Some(crate::Dbm(value)),
+        },
+        rssi: match u8::cast_signed(payload[4]) {
+            127 => None,
+            value => Some(value),
+        },
+        cte_type: match payload[5] {
+            0xFF => None,
+            value => Some(value.try_into()?),
+        },
+        data_status: match payload[6] {
+            0 => AdvertisingDataStatus::Complete,
+            1 => AdvertisingDataStatus::Incomplete,
+            2 => AdvertisingDataStatus::Truncated,
+            other => return Err(Error::BadAdvertisingDataStatus(other)),
+        },
+        data: &payload[8..],
+    })
+}
+
+/// The [LE Connectionless IQ Report](Event::LeConnectionlessIqReport) event, borrowing the IQ
+/// samples from the packet buffer.
+///
+/// See [`LeConnectionlessIqReport`](super::LeConnectionlessIqReport).
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeConnectionlessIqReport<'a> {
+    /// Periodic advertising train the packet was received from. 0x0FFF if the packet was received
+    /// in a receiver test.
+    pub sync_handle: crate::SyncHandle,
+
+    /// Index of the channel the packet was received on. `None` if the packet status is
+    /// [`InsufficientResources`](IqPacketStatus::InsufficientResources).
+    pub channel_index: Option<u8>,
+
+    /// Received signal strength, with a resolution of 0.1 dBm.
+    pub rssi: i16,
+
+    /// Identifies the antenna the received signal strength was measured on.
+    pub rssi_antenna_id: u8,
+
+    /// Type of the sampled Constant Tone Extension. `None` if the packet status is
+    /// [`InsufficientResources`](IqPacketStatus::InsufficientResources).
+    pub cte_type: Option<CteType>,
+
+    /// Durations of the switching and sampling slots. `None` if the packet status is
+    /// [`InsufficientResources`](IqPacketStatus::InsufficientResources).
+    pub slot_duration: Option<SlotDuration>,
+
+    /// Status of the received packet.
+    pub packet_status: IqPacketStatus,
+
+    /// Value of the paEventCounter of the received packet.
+    pub periodic_event_counter: u16,
+
+    pub(super) iq_sample_data: &'a [u8],
+}
+
+impl<'a> LeConnectionlessIqReport<'a> {
+    /// Returns an iterator over the IQ samples.
+    pub fn iq_samples(&self) -> IqSampleIterator<'a> {
+        IqSampleIterator {
+            data: self.iq_sample_data,
+            next_index: 0,
+        }
+    }
+}
+
+pub(super) fn to_le_connectionless_iq_report(
+    payload: &[u8],
+) -> Result<LeConnectionlessIqReport<'_>, Error> {
+    require_len_at_least!(payload, 13);
+    let sample_count = payload[12];
+    validate_iq_sample_count(sample_count)?;
+    require_len!(payload, 13 + 2 * usize::from(sample_count));
+
+    let packet_status = payload[9].try_into()?;
+    let sampled = packet_status != IqPacketStatus::InsufficientResources;
+    Ok(LeConnectionlessIqReport {
+        sync_handle: crate::SyncHandle(LittleEndian::read_u16(&payload[1..])),
+        channel_index: sampled.then_some(payload[3]),
+        rssi: LittleEndian::read_i16(&payload[4..]),
+        rssi_antenna_id: payload[6],
+        cte_type: sampled.then(|| payload[7].try_into()).transpose()?,
+        slot_duration: sampled.then(|| payload[8].try_into()).transpose()?,
+        packet_status,
+        periodic_event_counter: LittleEndian::read_u16(&payload[10..]),
+        iq_sample_data: &payload[13..],
+    })
+}
+
+/// The [LE Connection IQ Report](Event::LeConnectionIqReport) event, borrowing the IQ samples
+/// from the packet buffer.
+///
+/// See [`LeConnectionIqReport`](super::LeConnectionIqReport).
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeConnectionIqReport<'a> {
+    /// Connection the packet was received on.
+    pub conn_handle: crate::ConnectionHandle,
+
+    /// PHY the packet was received on.
+    pub rx_phy: Phy,
+
+    /// Index of the data channel the packet was received on. `None` if the packet status is
+    /// [`InsufficientResources`](IqPacketStatus::InsufficientResources).
+    pub data_channel_index: Option<u8>,
+
+    /// Received signal strength, with a resolution of 0.1 dBm.
+    pub rssi: i16,
+
+    /// Identifies the antenna the received signal strength was measured on.
+    pub rssi_antenna_id: u8,
+
+    /// Type of the sampled Constant Tone Extension. `None` if the packet status is
+    /// [`InsufficientResources`](IqPacketStatus::InsufficientResources).
+    pub cte_type: Option<CteType>,
+
+    /// Durations of the switching and sampling slots. `None` if the packet status is
+    /// [`InsufficientResources`](IqPacketStatus::InsufficientResources).
+    pub slot_duration: Option<SlotDuration>,
+
+    /// Status of the received packet.
+    pub packet_status: IqPacketStatus,
+
+    /// Value of the connEventCounter of the connection event the packet was received in.
+    pub connection_event_counter: u16,
+
+    pub(super) iq_sample_data: &'a [u8],
+}
+
+impl<'a> LeConnectionIqReport<'a> {
+    /// Returns an iterator over the IQ samples.
+    pub fn iq_samples(&self) -> IqSampleIterator<'a> {
+        IqSampleIterator {
+            data: self.iq_sample_data,
+            next_index: 0,
+        }
+    }
+}
+
+pub(super) fn to_le_connection_iq_report(
+    payload: &[u8],
+) -> Result<LeConnectionIqReport<'_>, Error> {
+    require_len_at_least!(payload, 14);
+    let sample_count = payload[13];
+    validate_iq_sample_count(sample_count)?;
+    require_len!(payload, 14 + 2 * usize::from(sample_count));
+
+    let packet_status = payload[10].try_into()?;
+    let sampled = packet_status != IqPacketStatus::InsufficientResources;
+    Ok(LeConnectionIqReport {
+        conn_handle: crate::ConnectionHandle(LittleEndian::read_u16(&payload[1..])),
+        rx_phy: payload[3].try_into()?,
+        data_channel_index: sampled.then_some(payload[4]),
+        rssi: LittleEndian::read_i16(&payload[5..]),
+        rssi_antenna_id: payload[7],
+        cte_type: sampled.then(|| payload[8].try_into()).transpose()?,
+        slot_duration: sampled.then(|| payload[9].try_into()).transpose()?,
+        packet_status,
+        connection_event_counter: LittleEndian::read_u16(&payload[11..]),
+        iq_sample_data: &payload[14..],
+    })
+}
+
+/// The [LE Create BIG Complete](Event::LeCreateBigComplete) event, borrowing the BIS connection
+/// handles from the packet buffer.
+///
+/// See [`LeCreateBigComplete`](super::LeCreateBigComplete).
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeCreateBigComplete<'a> {
+    /// Was the BIG created?
+    pub status: crate::Status,
+
+    /// Identifies the BIG.
+    pub big_handle: crate::BigHandle,
+
+    /// Maximum time for transmission of the PDUs of all the BISes in a BIG event, with a
+    /// resolution of 1 µs.
+    pub big_sync_delay: core::time::Duration,
+
+    /// Actual transport latency of the BIG, with a resolution of 1 µs.
+    pub transport_latency_big: core::time::Duration,
+
+    /// PHY used to transmit the BIS data PDUs. `None` if the status is not
+    /// [`Success`](crate::Status::Success).
+    pub phy: Option<Phy>,
+
+    /// Number of subevents in each BIS event.
+    pub nse: u8,
+
+    /// Number of new payloads in each BIS event.
+    pub bn: u8,
+
+    /// Offset, in multiples of the ISO interval, used for pre-transmissions.
+    pub pto: u8,
+
+    /// Number of times every payload is scheduled for transmission.
+    pub irc: u8,
+
+    /// Maximum size of the payload of a BIS data PDU.
+    pub max_pdu: u16,
+
+    /// Time between consecutive BIG anchor points, with a resolution of 1.25 ms.
+    pub iso_interval: core::time::Duration,
+
+    pub(super) bis_handle_data: &'a [u8],
+}
+
+impl<'a> LeCreateBigComplete<'a> {
+    /// Returns an iterator over the connection handles of the BISes in the BIG.
+    pub fn bis_handles(&self) -> BisHandleIterator<'a> {
+        BisHandleIterator {
+            data: self.bis_handle_data,
+            next_index: 0,
+        }
+    }
+}
+
+pub(super) fn to_le_create_big_complete(payload: &[u8]) -> Result<LeCreateBigComplete<'_>, Error> {
+    require_len_at_least!(payload, 19);
+    let num_bis = payload[18];
+    if usize::from(num_bis) > MAX_BIS_COUNT {
+        return Err(Error::BadNumBis(num_bis));
+    }
+    require_len!(payload, 19 + 2 * usize::from(num_bis));
+
+    let status = payload[1].try_into().map_err(rewrap_bad_status)?;
+    Ok(LeCreateBigComplete {
+        status,
+        big_handle: crate::BigHandle(payload[2]),
+        big_sync_delay: to_duration_us(&payload[3..]),
+        transport_latency_big: to_duration_us(&payload[6..]),
+        phy: (status == crate::Status::Success)
+            .then(|| payload[9].try_into())
+            .transpose()?,
+        nse: payload[10],
+        bn: payload[11],
+        pto: payload[12],
+        irc: payload[13],
+        max_pdu: LittleEndian::read_u16(&payload[14..]),
+        iso_interval: to_iso_interval(&payload[16..]),
+        bis_handle_data: &payload[19..],
+    })
+}
+
+/// The [LE BIG Sync Established](Event::LeBigSyncEstablished) event, borrowing the BIS connection
+/// handles from the packet buffer.
+///
+/// See [`LeBigSyncEstablished`](super::LeBigSyncEstablished).
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeBigSyncEstablished<'a> {
+    /// Was synchronization established?
+    pub status: crate::Status,
+
+    /// Identifies the BIG.
+    pub big_handle: crate::BigHandle,
+
+    /// Actual transport latency of the BIG, with a resolution of 1 µs.
+    pub transport_latency_big: core::time::Duration,
+
+    /// Number of subevents in each BIS event.
+    pub nse: u8,
+
+    /// Number of new payloads in each BIS event.
+    pub bn: u8,
+
+    /// Offset, in multiples of the ISO interval, used for pre-transmissions.
+    pub pto: u8,
+
+    /// Number of times every payload is scheduled for transmission.
+    pub irc: u8,
+
+    /// Maximum size of the payload of a BIS data PDU.
+    pub max_pdu: u16,
+
+    /// Time between consecutive BIG anchor points, with a resolution of 1.25 ms.
+    pub iso_interval: core::time::Duration,
+
+    pub(super) bis_handle_data: &'a [u8],
+}
+
+impl<'a> LeBigSyncEstablished<'a> {
+    /// Returns an iterator over the connection handles of the BISes the Controller is
+    /// synchronized with.
+    pub fn bis_handles(&self) -> BisHandleIterator<'a> {
+        BisHandleIterator {
+            data: self.bis_handle_data,
+            next_index: 0,
+        }
+    }
+}
+
+pub(super) fn to_le_big_sync_established(
+    payload: &[u8],
+) -> Result<LeBigSyncEstablished<'_>, Error> {
+    require_len_at_least!(payload, 15);
+    let num_bis = payload[14];
+    if usize::from(num_bis) > MAX_BIS_COUNT {
+        return Err(Error::BadNumBis(num_bis));
+    }
+    require_len!(payload, 15 + 2 * usize::from(num_bis));
+
+    Ok(LeBigSyncEstablished {
+        status: payload[1].try_into().map_err(rewrap_bad_status)?,
+        big_handle: crate::BigHandle(payload[2]),
+        transport_latency_big: to_duration_us(&payload[3..]),
+        nse: payload[6],
+        bn: payload[7],
+        pto: payload[8],
+        irc: payload[9],
+        max_pdu: LittleEndian::read_u16(&payload[10..]),
+        iso_interval: to_iso_interval(&payload[12..]),
+        bis_handle_data: &payload[15..],
+    })
+}
@@ -10,7 +10,7 @@
 //! 7.6 of the same part of the spec.
 
 use crate::vendor::opcode::VENDOR_OGF;
-use crate::{ConnectionHandle, Status};
+use crate::{BigHandle, ConnectionHandle, Dbm, Status};
 use byteorder::{ByteOrder, LittleEndian};
 use core::convert::{TryFrom, TryInto};
 use core::fmt::{Debug, Formatter, Result as FmtResult};
@@ -156,6 +156,229 @@ impl CommandComplete {
                 ReturnParameters::LeTransmitterTest(to_status(&bytes[3..])?)
             }
             crate::opcode::LE_TEST_END => ReturnParameters::LeTestEnd(to_le_test_end(&bytes[3..])?),
+            crate::opcode::LE_SET_DATA_LENGTH => {
+                ReturnParameters::LeSetDataLength(to_le_set_data_length(&bytes[3..])?)
+            }
+            crate::opcode::LE_READ_SUGGESTED_DEFAULT_DATA_LENGTH => {
+                ReturnParameters::LeReadSuggestedDefaultDataLength(
+                    to_le_suggested_default_data_length(&bytes[3..])?,
+                )
+            }
+            crate::opcode::LE_WRITE_SUGGESTED_DEFAULT_DATA_LENGTH => {
+                ReturnParameters::LeWriteSuggestedDefaultDataLength(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_READ_MAXIMUM_DATA_LENGTH => {
+                ReturnParameters::LeReadMaximumDataLength(to_le_maximum_data_length(&bytes[3..])?)
+            }
+            crate::opcode::LE_ADD_DEVICE_TO_RESOLVING_LIST => {
+                ReturnParameters::LeAddDeviceToResolvingList(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_REMOVE_DEVICE_FROM_RESOLVING_LIST => {
+                ReturnParameters::LeRemoveDeviceFromResolvingList(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_CLEAR_RESOLVING_LIST => {
+                ReturnParameters::LeClearResolvingList(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_READ_RESOLVING_LIST_SIZE => {
+                require_len!(bytes, 5);
+                ReturnParameters::LeReadResolvingListSize(
+                    to_status(&bytes[3..])?,
+                    bytes[4] as usize,
+                )
+            }
+            crate::opcode::LE_READ_PEER_RESOLVABLE_ADDRESS => {
+                ReturnParameters::LeReadPeerResolvableAddress(to_le_resolvable_address(
+                    &bytes[3..],
+                )?)
+            }
+            crate::opcode::LE_READ_LOCAL_RESOLVABLE_ADDRESS => {
+                ReturnParameters::LeReadLocalResolvableAddress(to_le_resolvable_address(
+                    &bytes[3..],
+                )?)
+            }
+            crate::opcode::LE_SET_ADDRESS_RESOLUTION_ENABLE => {
+                ReturnParameters::LeSetAddressResolutionEnable(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_RESOLVABLE_PRIVATE_ADDRESS_TIMEOUT => {
+                ReturnParameters::LeSetResolvablePrivateAddressTimeout(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_READ_PHY => ReturnParameters::LeReadPhy(to_le_read_phy(&bytes[3..])?),
+            crate::opcode::LE_SET_DEFAULT_PHY => {
+                ReturnParameters::LeSetDefaultPhy(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_EXTENDED_ADVERTISING_PARAMETERS => {
+                ReturnParameters::LeSetExtendedAdvertisingParameters(
+                    to_le_set_extended_advertising_parameters(&bytes[3..])?,
+                )
+            }
+            crate::opcode::LE_SET_EXTENDED_ADVERTISING_DATA => {
+                ReturnParameters::LeSetExtendedAdvertisingData(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_EXTENDED_SCAN_RESPONSE_DATA => {
+                ReturnParameters::LeSetExtendedScanResponseData(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_EXTENDED_ADVERTISING_ENABLE => {
+                ReturnParameters::LeSetExtendedAdvertisingEnable(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_READ_MAXIMUM_ADVERTISING_DATA_LENGTH => {
+                require_len!(bytes, 6);
+                ReturnParameters::LeReadMaximumAdvertisingDataLength(
+                    to_status(&bytes[3..])?,
+                    LittleEndian::read_u16(&bytes[4..]) as usize,
+                )
+            }
+            crate::opcode::LE_READ_NUMBER_OF_SUPPORTED_ADVERTISING_SETS => {
+                require_len!(bytes, 5);
+                ReturnParameters::LeReadNumberOfSupportedAdvertisingSets(
+                    to_status(&bytes[3..])?,
+                    bytes[4] as usize,
+                )
+            }
+            crate::opcode::LE_REMOVE_ADVERTISING_SET => {
+                ReturnParameters::LeRemoveAdvertisingSet(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_CLEAR_ADVERTISING_SETS => {
+                ReturnParameters::LeClearAdvertisingSets(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_PERIODIC_ADVERTISING_PARAMETERS => {
+                ReturnParameters::LeSetPeriodicAdvertisingParameters(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_PERIODIC_ADVERTISING_DATA => {
+                ReturnParameters::LeSetPeriodicAdvertisingData(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_PERIODIC_ADVERTISING_ENABLE => {
+                ReturnParameters::LeSetPeriodicAdvertisingEnable(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_EXTENDED_SCAN_PARAMETERS => {
+                ReturnParameters::LeSetExtendedScanParameters(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_EXTENDED_SCAN_ENABLE => {
+                ReturnParameters::LeSetExtendedScanEnable(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_PERIODIC_ADVERTISING_CREATE_SYNC_CANCEL => {
+                ReturnParameters::LePeriodicAdvertisingCreateSyncCancel(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_PERIODIC_ADVERTISING_TERMINATE_SYNC => {
+                ReturnParameters::LePeriodicAdvertisingTerminateSync(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_ADD_DEVICE_TO_PERIODIC_ADVERTISER_LIST => {
+                ReturnParameters::LeAddDeviceToPeriodicAdvertiserList(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_REMOVE_DEVICE_FROM_PERIODIC_ADVERTISER_LIST => {
+                ReturnParameters::LeRemoveDeviceFromPeriodicAdvertiserList(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_CLEAR_PERIODIC_ADVERTISER_LIST => {
+                ReturnParameters::LeClearPeriodicAdvertiserList(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_READ_PERIODIC_ADVERTISER_LIST_SIZE => {
+                require_len!(bytes, 5);
+                ReturnParameters::LeReadPeriodicAdvertiserListSize(
+                    to_status(&bytes[3..])?,
+                    bytes[4] as usize,
+                )
+            }
+            crate::opcode::LE_SET_PRIVACY_MODE => {
+                ReturnParameters::LeSetPrivacyMode(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_PERIODIC_ADVERTISING_SYNC_TRANSFER => {
+                ReturnParameters::LePeriodicAdvertisingSyncTransfer(to_le_sync_transfer(
+                    &bytes[3..],
+                )?)
+            }
+            crate::opcode::LE_PERIODIC_ADVERTISING_SET_INFO_TRANSFER => {
+                ReturnParameters::LePeriodicAdvertisingSetInfoTransfer(to_le_sync_transfer(
+                    &bytes[3..],
+                )?)
+            }
+            crate::opcode::LE_SET_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS => {
+                ReturnParameters::LeSetPeriodicAdvertisingSyncTransferParameters(
+                    to_le_sync_transfer(&bytes[3..])?,
+                )
+            }
+            crate::opcode::LE_SET_DEFAULT_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS => {
+                ReturnParameters::LeSetDefaultPeriodicAdvertisingSyncTransferParameters(to_status(
+                    &bytes[3..],
+                )?)
+            }
+            crate::opcode::LE_READ_ISO_TX_SYNC => {
+                ReturnParameters::LeReadIsoTxSync(to_le_read_iso_tx_sync(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_CIG_PARAMETERS => {
+                ReturnParameters::LeSetCigParameters(to_le_set_cig_parameters(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_CIG_PARAMETERS_TEST => {
+                ReturnParameters::LeSetCigParametersTest(to_le_set_cig_parameters(&bytes[3..])?)
+            }
+            crate::opcode::LE_REMOVE_CIG => {
+                ReturnParameters::LeRemoveCig(to_le_remove_cig(&bytes[3..])?)
+            }
+            crate::opcode::LE_REJECT_CIS_REQUEST => {
+                ReturnParameters::LeRejectCisRequest(to_le_iso_connection(&bytes[3..])?)
+            }
+            crate::opcode::LE_BIG_TERMINATE_SYNC => {
+                ReturnParameters::LeBigTerminateSync(to_le_big_terminate_sync(&bytes[3..])?)
+            }
+            crate::opcode::LE_SETUP_ISO_DATA_PATH => {
+                ReturnParameters::LeSetupIsoDataPath(to_le_iso_connection(&bytes[3..])?)
+            }
+            crate::opcode::LE_REMOVE_ISO_DATA_PATH => {
+                ReturnParameters::LeRemoveIsoDataPath(to_le_iso_connection(&bytes[3..])?)
+            }
+            crate::opcode::LE_ENHANCED_READ_TRANSMIT_POWER_LEVEL => {
+                ReturnParameters::LeEnhancedReadTransmitPowerLevel(
+                    to_le_enhanced_read_transmit_power_level(&bytes[3..])?,
+                )
+            }
+            crate::opcode::LE_SET_PATH_LOSS_REPORTING_PARAMETERS => {
+                ReturnParameters::LeSetPathLossReportingParameters(to_le_power_control_connection(
+                    &bytes[3..],
+                )?)
+            }
+            crate::opcode::LE_SET_PATH_LOSS_REPORTING_ENABLE => {
+                ReturnParameters::LeSetPathLossReportingEnable(to_le_power_control_connection(
+                    &bytes[3..],
+                )?)
+            }
+            crate::opcode::LE_SET_TRANSMIT_POWER_REPORTING_ENABLE => {
+                ReturnParameters::LeSetTransmitPowerReportingEnable(to_le_power_control_connection(
+                    &bytes[3..],
+                )?)
+            }
+            crate::opcode::LE_SET_DEFAULT_SUBRATE => {
+                ReturnParameters::LeSetDefaultSubrate(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_CONNECTIONLESS_CTE_TRANSMIT_PARAMETERS => {
+                ReturnParameters::LeSetConnectionlessCteTransmitParameters(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_CONNECTIONLESS_CTE_TRANSMIT_ENABLE => {
+                ReturnParameters::LeSetConnectionlessCteTransmitEnable(to_status(&bytes[3..])?)
+            }
+            crate::opcode::LE_SET_CONNECTIONLESS_IQ_SAMPLING_ENABLE => {
+                ReturnParameters::LeSetConnectionlessIqSamplingEnable(
+                    to_le_set_connectionless_iq_sampling_enable(&bytes[3..])?,
+                )
+            }
+            crate::opcode::LE_SET_CONNECTION_CTE_RECEIVE_PARAMETERS => {
+                ReturnParameters::LeSetConnectionCteReceiveParameters(to_le_cte_connection(
+                    &bytes[3..],
+                )?)
+            }
+            crate::opcode::LE_SET_CONNECTION_CTE_TRANSMIT_PARAMETERS => {
+                ReturnParameters::LeSetConnectionCteTransmitParameters(to_le_cte_connection(
+                    &bytes[3..],
+                )?)
+            }
+            crate::opcode::LE_CONNECTION_CTE_REQUEST_ENABLE => {
+                ReturnParameters::LeConnectionCteRequestEnable(to_le_cte_connection(&bytes[3..])?)
+            }
+            crate::opcode::LE_CONNECTION_CTE_RESPONSE_ENABLE => {
+                ReturnParameters::LeConnectionCteResponseEnable(to_le_cte_connection(&bytes[3..])?)
+            }
+            crate::opcode::LE_READ_ANTENNA_INFORMATION => {
+                ReturnParameters::LeReadAntennaInformation(to_le_read_antenna_information(
+                    &bytes[3..],
+                )?)
+            }
             other => {
                 if other.ogf() != VENDOR_OGF {
                     return Err(crate::event::Error::UnknownOpcode(other));
@@ -171,6 +394,406 @@ impl CommandComplete {
             return_params: params,
         })
     }
+
+    /// Serializes the CommandComplete event into a buffer, in the same format that
+    /// [`new`](CommandComplete::new) accepts: the number of HCI command packets (1 byte), the
+    /// opcode (2 bytes), and the return parameters.
+    ///
+    /// Returns the number of bytes written to the buffer.
+    ///
+    /// # Panics
+    ///
+    /// The buffer must be large enough to hold the serialized event.
+    pub fn write_into(&self, bytes: &mut [u8]) -> usize {
+        bytes[0] = self.num_hci_command_packets;
+
+        let params = &mut bytes[3..];
+        let (opcode, params_len) = match &self.return_params {
+            ReturnParameters::Spontaneous => (crate::opcode::Opcode(0x0000), 0),
+            ReturnParameters::SetEventMask(status) => {
+                (crate::opcode::SET_EVENT_MASK, write_status(status, params))
+            }
+            ReturnParameters::Reset(status) => (crate::opcode::RESET, write_status(status, params)),
+            ReturnParameters::ReadTxPowerLevel(value) => (
+                crate::opcode::READ_TX_POWER_LEVEL,
+                write_tx_power_level(value, params),
+            ),
+            ReturnParameters::ReadLocalVersionInformation(value) => (
+                crate::opcode::READ_LOCAL_VERSION_INFO,
+                write_local_version_info(value, params),
+            ),
+            ReturnParameters::ReadLocalSupportedCommands(value) => (
+                crate::opcode::READ_LOCAL_SUPPORTED_COMMANDS,
+                write_supported_commands(value, params),
+            ),
+            ReturnParameters::ReadLocalSupportedFeatures(value) => (
+                crate::opcode::READ_LOCAL_SUPPORTED_FEATURES,
+                write_supported_features(value, params),
+            ),
+            ReturnParameters::ReadBdAddr(value) => {
+                (crate::opcode::READ_BD_ADDR, write_bd_addr(value, params))
+            }
+            ReturnParameters::ReadRssi(value) => {
+                (crate::opcode::READ_RSSI, write_read_rssi(value, params))
+            }
+            ReturnParameters::LeSetEventMask(status) => (
+                crate::opcode::LE_SET_EVENT_MASK,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeReadBufferSize(value) => (
+                crate::opcode::LE_READ_BUFFER_SIZE,
+                write_le_read_buffer_status(value, params),
+            ),
+            ReturnParameters::LeReadLocalSupportedFeatures(value) => (
+                crate::opcode::LE_READ_LOCAL_SUPPORTED_FEATURES,
+                write_le_local_supported_features(value, params),
+            ),
+            ReturnParameters::LeSetRandomAddress(status) => (
+                crate::opcode::LE_SET_RANDOM_ADDRESS,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetAdvertisingParameters(status) => (
+                crate::opcode::LE_SET_ADVERTISING_PARAMETERS,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeReadAdvertisingChannelTxPower(value) => (
+                crate::opcode::LE_READ_ADVERTISING_CHANNEL_TX_POWER,
+                write_le_advertising_channel_tx_power(value, params),
+            ),
+            ReturnParameters::LeSetAdvertisingData(status) => (
+                crate::opcode::LE_SET_ADVERTISING_DATA,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetScanResponseData(status) => (
+                crate::opcode::LE_SET_SCAN_RESPONSE_DATA,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetAdvertisingEnable(status) => (
+                crate::opcode::LE_SET_ADVERTISE_ENABLE,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetScanParameters(status) => (
+                crate::opcode::LE_SET_SCAN_PARAMETERS,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetScanEnable(status) => (
+                crate::opcode::LE_SET_SCAN_ENABLE,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeCreateConnectionCancel(status) => (
+                crate::opcode::LE_CREATE_CONNECTION_CANCEL,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeReadWhiteListSize(status, size) => {
+                params[1] = *size as u8;
+                (
+                    crate::opcode::LE_READ_WHITE_LIST_SIZE,
+                    1 + write_status(status, params),
+                )
+            }
+            ReturnParameters::LeClearWhiteList(status) => (
+                crate::opcode::LE_CLEAR_WHITE_LIST,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeAddDeviceToWhiteList(status) => (
+                crate::opcode::LE_ADD_DEVICE_TO_WHITE_LIST,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeRemoveDeviceFromWhiteList(status) => (
+                crate::opcode::LE_REMOVE_DEVICE_FROM_WHITE_LIST,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetHostChannelClassification(status) => (
+                crate::opcode::LE_SET_HOST_CHANNEL_CLASSIFICATION,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeReadChannelMap(value) => (
+                crate::opcode::LE_READ_CHANNEL_MAP,
+                write_le_channel_map_parameters(value, params),
+            ),
+            ReturnParameters::LeEncrypt(value) => (
+                crate::opcode::LE_ENCRYPT,
+                write_le_encrypted_data(value, params),
+            ),
+            ReturnParameters::LeRand(value) => {
+                (crate::opcode::LE_RAND, write_random_number(value, params))
+            }
+            ReturnParameters::LeLongTermKeyRequestReply(value) => (
+                crate::opcode::LE_LTK_REQUEST_REPLY,
+                write_le_ltk_request_reply(value, params),
+            ),
+            ReturnParameters::LeLongTermKeyRequestNegativeReply(value) => (
+                crate::opcode::LE_LTK_REQUEST_NEGATIVE_REPLY,
+                write_le_ltk_request_reply(value, params),
+            ),
+            ReturnParameters::LeReadSupportedStates(value) => (
+                crate::opcode::LE_READ_STATES,
+                write_le_read_states(value, params),
+            ),
+            ReturnParameters::LeReceiverTest(status) => (
+                crate::opcode::LE_RECEIVER_TEST,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeTransmitterTest(status) => (
+                crate::opcode::LE_TRANSMITTER_TEST,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeTestEnd(value) => {
+                (crate::opcode::LE_TEST_END, write_le_test_end(value, params))
+            }
+            ReturnParameters::LeSetDataLength(value) => (
+                crate::opcode::LE_SET_DATA_LENGTH,
+                write_le_set_data_length(value, params),
+            ),
+            ReturnParameters::LeReadSuggestedDefaultDataLength(value) => (
+                crate::opcode::LE_READ_SUGGESTED_DEFAULT_DATA_LENGTH,
+                write_le_suggested_default_data_length(value, params),
+            ),
+            ReturnParameters::LeWriteSuggestedDefaultDataLength(status) => (
+                crate::opcode::LE_WRITE_SUGGESTED_DEFAULT_DATA_LENGTH,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeReadMaximumDataLength(value) => (
+                crate::opcode::LE_READ_MAXIMUM_DATA_LENGTH,
+                write_le_maximum_data_length(value, params),
+            ),
+            ReturnParameters::LeAddDeviceToResolvingList(status) => (
+                crate::opcode::LE_ADD_DEVICE_TO_RESOLVING_LIST,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeRemoveDeviceFromResolvingList(status) => (
+                crate::opcode::LE_REMOVE_DEVICE_FROM_RESOLVING_LIST,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeClearResolvingList(status) => (
+                crate::opcode::LE_CLEAR_RESOLVING_LIST,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeReadResolvingListSize(status, size) => {
+                params[1] = *size as u8;
+                (
+                    crate::opcode::LE_READ_RESOLVING_LIST_SIZE,
+                    1 + write_status(status, params),
+                )
+            }
+            ReturnParameters::LeReadPeerResolvableAddress(value) => (
+                crate::opcode::LE_READ_PEER_RESOLVABLE_ADDRESS,
+                write_le_resolvable_address(value, params),
+            ),
+            ReturnParameters::LeReadLocalResolvableAddress(value) => (
+                crate::opcode::LE_READ_LOCAL_RESOLVABLE_ADDRESS,
+                write_le_resolvable_address(value, params),
+            ),
+            ReturnParameters::LeSetAddressResolutionEnable(status) => (
+                crate::opcode::LE_SET_ADDRESS_RESOLUTION_ENABLE,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetResolvablePrivateAddressTimeout(status) => (
+                crate::opcode::LE_SET_RESOLVABLE_PRIVATE_ADDRESS_TIMEOUT,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeReadPhy(value) => {
+                (crate::opcode::LE_READ_PHY, write_le_read_phy(value, params))
+            }
+            ReturnParameters::LeSetDefaultPhy(status) => (
+                crate::opcode::LE_SET_DEFAULT_PHY,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetExtendedAdvertisingParameters(value) => (
+                crate::opcode::LE_SET_EXTENDED_ADVERTISING_PARAMETERS,
+                write_le_set_extended_advertising_parameters(value, params),
+            ),
+            ReturnParameters::LeSetExtendedAdvertisingData(status) => (
+                crate::opcode::LE_SET_EXTENDED_ADVERTISING_DATA,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetExtendedScanResponseData(status) => (
+                crate::opcode::LE_SET_EXTENDED_SCAN_RESPONSE_DATA,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetExtendedAdvertisingEnable(status) => (
+                crate::opcode::LE_SET_EXTENDED_ADVERTISING_ENABLE,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeReadMaximumAdvertisingDataLength(status, length) => {
+                LittleEndian::write_u16(&mut params[1..], *length as u16);
+                (
+                    crate::opcode::LE_READ_MAXIMUM_ADVERTISING_DATA_LENGTH,
+                    2 + write_status(status, params),
+                )
+            }
+            ReturnParameters::LeReadNumberOfSupportedAdvertisingSets(status, count) => {
+                params[1] = *count as u8;
+                (
+                    crate::opcode::LE_READ_NUMBER_OF_SUPPORTED_ADVERTISING_SETS,
+                    1 + write_status(status, params),
+                )
+            }
+            ReturnParameters::LeRemoveAdvertisingSet(status) => (
+                crate::opcode::LE_REMOVE_ADVERTISING_SET,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeClearAdvertisingSets(status) => (
+                crate::opcode::LE_CLEAR_ADVERTISING_SETS,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetPeriodicAdvertisingParameters(status) => (
+                crate::opcode::LE_SET_PERIODIC_ADVERTISING_PARAMETERS,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetPeriodicAdvertisingData(status) => (
+                crate::opcode::LE_SET_PERIODIC_ADVERTISING_DATA,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetPeriodicAdvertisingEnable(status) => (
+                crate::opcode::LE_SET_PERIODIC_ADVERTISING_ENABLE,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetExtendedScanParameters(status) => (
+                crate::opcode::LE_SET_EXTENDED_SCAN_PARAMETERS,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetExtendedScanEnable(status) => (
+                crate::opcode::LE_SET_EXTENDED_SCAN_ENABLE,
+                write_status(status, params),
+            ),
+            ReturnParameters::LePeriodicAdvertisingCreateSyncCancel(status) => (
+                crate::opcode::LE_PERIODIC_ADVERTISING_CREATE_SYNC_CANCEL,
+                write_status(status, params),
+            ),
+            ReturnParameters::LePeriodicAdvertisingTerminateSync(status) => (
+                crate::opcode::LE_PERIODIC_ADVERTISING_TERMINATE_SYNC,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeAddDeviceToPeriodicAdvertiserList(status) => (
+                crate::opcode::LE_ADD_DEVICE_TO_PERIODIC_ADVERTISER_LIST,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeRemoveDeviceFromPeriodicAdvertiserList(status) => (
+                crate::opcode::LE_REMOVE_DEVICE_FROM_PERIODIC_ADVERTISER_LIST,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeClearPeriodicAdvertiserList(status) => (
+                crate::opcode::LE_CLEAR_PERIODIC_ADVERTISER_LIST,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeReadPeriodicAdvertiserListSize(status, size) => {
+                params[1] = *size as u8;
+                (
+                    crate::opcode::LE_READ_PERIODIC_ADVERTISER_LIST_SIZE,
+                    1 + write_status(status, params),
+                )
+            }
+            ReturnParameters::LeSetPrivacyMode(status) => (
+                crate::opcode::LE_SET_PRIVACY_MODE,
+                write_status(status, params),
+            ),
+            ReturnParameters::LePeriodicAdvertisingSyncTransfer(value) => (
+                crate::opcode::LE_PERIODIC_ADVERTISING_SYNC_TRANSFER,
+                write_le_sync_transfer(value, params),
+            ),
+            ReturnParameters::LePeriodicAdvertisingSetInfoTransfer(value) => (
+                crate::opcode::LE_PERIODIC_ADVERTISING_SET_INFO_TRANSFER,
+                write_le_sync_transfer(value, params),
+            ),
+            ReturnParameters::LeSetPeriodicAdvertisingSyncTransferParameters(value) => (
+                crate::opcode::LE_SET_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS,
+                write_le_sync_transfer(value, params),
+            ),
+            ReturnParameters::LeSetDefaultPeriodicAdvertisingSyncTransferParameters(status) => (
+                crate::opcode::LE_SET_DEFAULT_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeReadIsoTxSync(value) => (
+                crate::opcode::LE_READ_ISO_TX_SYNC,
+                write_le_read_iso_tx_sync(value, params),
+            ),
+            ReturnParameters::LeSetCigParameters(value) => (
+                crate::opcode::LE_SET_CIG_PARAMETERS,
+                write_le_set_cig_parameters(value, params),
+            ),
+            ReturnParameters::LeSetCigParametersTest(value) => (
+                crate::opcode::LE_SET_CIG_PARAMETERS_TEST,
+                write_le_set_cig_parameters(value, params),
+            ),
+            ReturnParameters::LeRemoveCig(value) => (
+                crate::opcode::LE_REMOVE_CIG,
+                write_le_remove_cig(value, params),
+            ),
+            ReturnParameters::LeRejectCisRequest(value) => (
+                crate::opcode::LE_REJECT_CIS_REQUEST,
+                write_le_iso_connection(value, params),
+            ),
+            ReturnParameters::LeBigTerminateSync(value) => (
+                crate::opcode::LE_BIG_TERMINATE_SYNC,
+                write_le_big_terminate_sync(value, params),
+            ),
+            ReturnParameters::LeSetupIsoDataPath(value) => (
+                crate::opcode::LE_SETUP_ISO_DATA_PATH,
+                write_le_iso_connection(value, params),
+            ),
+            ReturnParameters::LeRemoveIsoDataPath(value) => (
+                crate::opcode::LE_REMOVE_ISO_DATA_PATH,
+                write_le_iso_connection(value, params),
+            ),
+            ReturnParameters::LeEnhancedReadTransmitPowerLevel(value) => (
+                crate::opcode::LE_ENHANCED_READ_TRANSMIT_POWER_LEVEL,
+                write_le_enhanced_read_transmit_power_level(value, params),
+            ),
+            ReturnParameters::LeSetPathLossReportingParameters(value) => (
+                crate::opcode::LE_SET_PATH_LOSS_REPORTING_PARAMETERS,
+                write_le_power_control_connection(value, params),
+            ),
+            ReturnParameters::LeSetPathLossReportingEnable(value) => (
+                crate::opcode::LE_SET_PATH_LOSS_REPORTING_ENABLE,
+                write_le_power_control_connection(value, params),
+            ),
+            ReturnParameters::LeSetTransmitPowerReportingEnable(value) => (
+                crate::opcode::LE_SET_TRANSMIT_POWER_REPORTING_ENABLE,
+                write_le_power_control_connection(value, params),
+            ),
+            ReturnParameters::LeSetDefaultSubrate(status) => (
+                crate::opcode::LE_SET_DEFAULT_SUBRATE,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetConnectionlessCteTransmitParameters(status) => (
+                crate::opcode::LE_SET_CONNECTIONLESS_CTE_TRANSMIT_PARAMETERS,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetConnectionlessCteTransmitEnable(status) => (
+                crate::opcode::LE_SET_CONNECTIONLESS_CTE_TRANSMIT_ENABLE,
+                write_status(status, params),
+            ),
+            ReturnParameters::LeSetConnectionlessIqSamplingEnable(value) => (
+                crate::opcode::LE_SET_CONNECTIONLESS_IQ_SAMPLING_ENABLE,
+                write_le_set_connectionless_iq_sampling_enable(value, params),
+            ),
+            ReturnParameters::LeSetConnectionCteReceiveParameters(value) => (
+                crate::opcode::LE_SET_CONNECTION_CTE_RECEIVE_PARAMETERS,
+                write_le_cte_connection(value, params),
+            ),
+            ReturnParameters::LeSetConnectionCteTransmitParameters(value) => (
+                crate::opcode::LE_SET_CONNECTION_CTE_TRANSMIT_PARAMETERS,
+                write_le_cte_connection(value, params),
+            ),
+            ReturnParameters::LeConnectionCteRequestEnable(value) => (
+                crate::opcode::LE_CONNECTION_CTE_REQUEST_ENABLE,
+                write_le_cte_connection(value, params),
+            ),
+            ReturnParameters::LeConnectionCteResponseEnable(value) => (
+                crate::opcode::LE_CONNECTION_CTE_RESPONSE_ENABLE,
+                write_le_cte_connection(value, params),
+            ),
+            ReturnParameters::LeReadAntennaInformation(value) => (
+                crate::opcode::LE_READ_ANTENNA_INFORMATION,
+                write_le_read_antenna_information(value, params),
+            ),
+            ReturnParameters::Vendor(value) => return 1 + value.write_into(&mut bytes[1..]),
+        };
+
+        LittleEndian::write_u16(&mut bytes[1..], opcode.0);
+        3 + params_len
+    }
 }
 
 /// Commands that may generate the [Command Complete](crate::event::Event::CommandComplete) event.
@@ -306,146 +929,768 @@ pub enum ReturnParameters {
     /// Parameters returned by the [LE Test End](crate::host::HostHci::le_test_end) command.
     LeTestEnd(LeTestEnd),
 
-    /// Parameters returned by vendor-specific commands.
-    Vendor(crate::vendor::event::command::VendorReturnParameters),
-}
+    /// Parameters returned by the [LE Set Data Length](crate::host::HostHci::le_set_data_length)
+    /// command.
+    LeSetDataLength(LeSetDataLength),
 
-fn to_status(bytes: &[u8]) -> Result<Status, crate::event::Error> {
-    bytes[0].try_into().map_err(super::rewrap_bad_status)
-}
+    /// Parameters returned by the
+    /// [LE Read Suggested Default Data Length](crate::host::HostHci::le_read_suggested_default_data_length) command.
+    LeReadSuggestedDefaultDataLength(LeSuggestedDefaultDataLength),
 
-/// Values returned by the [Read Transmit Power Level](crate::host::HostHci::read_tx_power_level)
-/// command.
-#[derive(Copy, Clone, Debug)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct TxPowerLevel {
-    /// Did the command fail, and if so, how?
-    pub status: Status,
+    /// Status returned by the
+    /// [LE Write Suggested Default Data Length](crate::host::HostHci::le_write_suggested_default_data_length) command.
+    LeWriteSuggestedDefaultDataLength(Status),
 
-    /// Specifies which connection handle's transmit power level setting is returned
-    pub conn_handle: ConnectionHandle,
+    /// Parameters returned by the
+    /// [LE Read Maximum Data Length](crate::host::HostHci::le_read_maximum_data_length) command.
+    LeReadMaximumDataLength(LeMaximumDataLength),
 
-    /// Power level for the connection handle, in dBm.
-    ///
-    /// Valid range is -30 dBm to +20 dBm, but that is not enforced by this implementation.
-    pub tx_power_level_dbm: i8,
-}
+    /// Status returned by the
+    /// [LE Add Device to Resolving List](crate::host::HostHci::le_add_device_to_resolving_list) command.
+    LeAddDeviceToResolvingList(Status),
 
-fn to_tx_power_level(bytes: &[u8]) -> Result<TxPowerLevel, crate::event::Error> {
-    require_len!(bytes, 4);
-    Ok(TxPowerLevel {
-        status: to_status(bytes)?,
-        conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[1..])),
-        tx_power_level_dbm: u8::cast_signed(bytes[3]),
-    })
-}
+    /// Status returned by the
+    /// [LE Remove Device from Resolving List](crate::host::HostHci::le_remove_device_from_resolving_list)
+    /// command.
+    LeRemoveDeviceFromResolvingList(Status),
 
-/// Values returned by
-/// [Read Local Version Information](crate::host::HostHci::read_local_version_information) command.
-#[derive(Copy, Clone, Debug)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct LocalVersionInfo {
-    /// Did the command fail, and if so, how?
-    pub status: Status,
+    /// Status returned by the [LE Clear Resolving List](crate::host::HostHci::le_clear_resolving_list)
+    /// command.
+    LeClearResolvingList(Status),
 
-    /// The version information of the HCI layer.
-    ///
-    /// See the Bluetooth
-    /// [Assigned Numbers](https://www.bluetooth.com/specifications/assigned-numbers/host-controller-interface).
-    pub hci_version: u8,
+    /// Status and resolving list size returned by the
+    /// [LE Read Resolving List Size](crate::host::HostHci::le_read_resolving_list_size) command.
+    LeReadResolvingListSize(Status, usize),
 
-    /// Revision of the Current HCI in the BR/EDR Controller.  This value is implementation
-    /// dependent.
-    pub hci_revision: u16,
+    /// Parameters returned by the
+    /// [LE Read Peer Resolvable Address](crate::host::HostHci::le_read_peer_resolvable_address) command.
+    LeReadPeerResolvableAddress(LeReadResolvableAddress),
 
-    /// Version of the Current [LMP] or [PAL] in the Controller.
-    ///
-    /// [LMP]: https://www.bluetooth.com/specifications/assigned-numbers/link-manager
-    /// [PAL]: https://www.bluetooth.com/specifications/assigned-numbers/protocol-adaptation-layer
-    pub lmp_version: u8,
+    /// Parameters returned by the
+    /// [LE Read Local Resolvable Address](crate::host::HostHci::le_read_local_resolvable_address) command.
+    LeReadLocalResolvableAddress(LeReadResolvableAddress),
 
-    /// Manufacturer Name of the BR/EDR Controller.  See Bluetooth
-    /// [Assigned Numbers](https://www.bluetooth.com/specifications/assigned-numbers/company-identifiers)
-    pub manufacturer_name: u16,
+    /// Status returned by the
+    /// [LE Set Address Resolution Enable](crate::host::HostHci::le_set_address_resolution_enable) command.
+    LeSetAddressResolutionEnable(Status),
 
-    /// Subversion of the Current [LMP] or [PAL] in the Controller. This value is implementation
-    /// dependent.
-    ///
-    /// [LMP]: https://www.bluetooth.com/specifications/assigned-numbers/link-manager
-    /// [PAL]: https://www.bluetooth.com/specifications/assigned-numbers/protocol-adaptation-layer
-    pub lmp_subversion: u16,
-}
+    /// Status returned by the
+    /// [LE Set Resolvable Private Address Timeout](crate::host::HostHci::le_set_resolvable_private_address_timeout)
+    /// command.
+    LeSetResolvablePrivateAddressTimeout(Status),
 
-fn to_local_version_info(bytes: &[u8]) -> Result<LocalVersionInfo, crate::event::Error> {
-    require_len!(bytes, 9);
+    /// Parameters returned by the [LE Read PHY](crate::host::HostHci::le_read_phy) command.
+    LeReadPhy(LeReadPhy),
 
-    Ok(LocalVersionInfo {
-        status: bytes[0].try_into().map_err(super::rewrap_bad_status)?,
-        hci_version: bytes[1],
-        hci_revision: LittleEndian::read_u16(&bytes[2..]),
-        lmp_version: bytes[4],
-        manufacturer_name: LittleEndian::read_u16(&bytes[5..]),
-        lmp_subversion: LittleEndian::read_u16(&bytes[7..]),
-    })
-}
+    /// Status returned by the [LE Set Default PHY](crate::host::HostHci::le_set_default_phy)
+    /// command.
+    LeSetDefaultPhy(Status),
 
-/// Values returned by the
-/// [Read Local Supported Commands](crate::host::HostHci::read_local_supported_commands) command.
-#[derive(Copy, Clone, Debug)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct LocalSupportedCommands {
-    /// Did the command fail, and if so, how?
-    pub status: Status,
+    /// Status returned by the [LE Set Privacy Mode](crate::host::HostHci::le_set_privacy_mode)
+    /// Parameters returned by the
+    /// [LE Set Extended Advertising Parameters](crate::host::HostHci::le_set_extended_advertising_parameters)
+    /// command.
+    LeSetExtendedAdvertisingParameters(LeSetExtendedAdvertisingParameters),
 
-    /// Flags for supported commands.
-    pub supported_commands: CommandFlags,
-}
+    /// Status returned by the
+    /// [LE Set Extended Advertising Data](crate::host::HostHci::le_set_extended_advertising_data)
+    /// command.
+    LeSetExtendedAdvertisingData(Status),
 
-const COMMAND_FLAGS_SIZE: usize = 64;
+    /// Status returned by the
+    /// [LE Set Extended Scan Response Data](crate::host::HostHci::le_set_extended_scan_response_data)
+    /// command.
+    LeSetExtendedScanResponseData(Status),
 
-bitflag_array! {
-    /// Extended bit field for the command flags of the [`LocalSupportedCommands`] return
-    /// parameters.
-    #[derive(Copy, Clone)]
-    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-    pub struct CommandFlags : COMMAND_FLAGS_SIZE;
-    pub struct CommandFlag;
+    /// Status returned by the
+    /// [LE Set Extended Advertising Enable](crate::host::HostHci::le_set_extended_advertising_enable)
+    /// command.
+    LeSetExtendedAdvertisingEnable(Status),
 
-    /// Inquiry
-    const INQUIRY = 0, 1 << 0;
-    /// Cancel Inquiry
-    const INQUIRY_CANCEL = 0, 1 << 1;
-    /// Periodic Inquiry Mode
-    const PERIODIC_INQUIRY_MODE = 0, 1 << 2;
-    /// Exit Periodic Inquiry Mode
-    const EXIT_PERIODIC_INQUIRY_MODE = 0, 1 << 3;
-    /// Create Connection
-    const CREATE_CONNECTION = 0, 1 << 4;
-    /// Disconnect
-    const DISCONNECT = 0, 1 << 5;
-    /// Add SCO Connection (deprecated by the spec)
-    #[deprecated]
-    const ADD_SCO_CONNECTION = 0, 1 << 6;
-    /// Create Connection Cancel
-    const CREATE_CONNECTION_CANCEL = 0, 1 << 7;
-    /// Accept Connection Request
-    const ACCEPT_CONNECTION_REQUEST = 1, 1 << 0;
-    /// Reject Connection Request
-    const REJECT_CONNECTION_REQUEST = 1, 1 << 1;
-    /// Link Key Request Reply
-    const LINK_KEY_REQUEST_REPLY = 1, 1 << 2;
-    /// Link Key Request Negative Reply
-    const LINK_KEY_REQUEST_NEGATIVE_REPLY = 1, 1 << 3;
-    /// PIN Code Request Reply
-    const PIN_CODE_REQUEST_REPLY = 1, 1 << 4;
-    /// PIN Code Request Negative Reply
-    const PIN_CODE_REQUEST_NEGATIVE_REPLY = 1, 1 << 5;
-    /// Change Connection Packet Type
-    const CHANGE_CONNECTION_PACKET_TYPE = 1, 1 << 6;
-    /// Authentication Requested
-    const AUTHENTICATION_REQUESTED = 1, 1 << 7;
-    /// Set Connection Encryption
-    const SET_CONNECTION_ENCRYPTION = 2, 1 << 0;
+    /// Status and maximum advertising data length returned by the
+    /// [LE Read Maximum Advertising Data Length](crate::host::HostHci::le_read_maximum_advertising_data_length)
+    /// command.
+    LeReadMaximumAdvertisingDataLength(Status, usize),
+
+    /// Status and number of supported advertising sets returned by the
+    /// [LE Read Number of Supported Advertising Sets](crate::host::HostHci::le_read_number_of_supported_advertising_sets)
+    /// command.
+    LeReadNumberOfSupportedAdvertisingSets(Status, usize),
+
+    /// Status returned by the
+    /// [LE Remove Advertising Set](crate::host::HostHci::le_remove_advertising_set) command.
+    LeRemoveAdvertisingSet(Status),
+
+    /// Status returned by the
+    /// [LE Clear Advertising Sets](crate::host::HostHci::le_clear_advertising_sets) command.
+    LeClearAdvertisingSets(Status),
+
+    /// Status returned by the
+    /// [LE Set Periodic Advertising Parameters](crate::host::HostHci::le_set_periodic_advertising_parameters)
+    /// command.
+    LeSetPeriodicAdvertisingParameters(Status),
+
+    /// Status returned by the
+    /// [LE Set Periodic Advertising Data](crate::host::HostHci::le_set_periodic_advertising_data)
+    /// command.
+    LeSetPeriodicAdvertisingData(Status),
+
+    /// Status returned by the
+    /// [LE Set Periodic Advertising Enable](crate::host::HostHci::le_set_periodic_advertising_enable)
+    /// command.
+    LeSetPeriodicAdvertisingEnable(Status),
+
+    /// Status returned by the
+    /// [LE Set Extended Scan Parameters](crate::host::HostHci::le_set_extended_scan_parameters)
+    /// command.
+    LeSetExtendedScanParameters(Status),
+
+    /// Status returned by the
+    /// [LE Set Extended Scan Enable](crate::host::HostHci::le_set_extended_scan_enable) command.
+    LeSetExtendedScanEnable(Status),
+
+    /// Status returned by the
+    /// [LE Periodic Advertising Create Sync Cancel](crate::host::HostHci::le_periodic_advertising_create_sync_cancel)
+    /// command.
+    LePeriodicAdvertisingCreateSyncCancel(Status),
+
+    /// Status returned by the
+    /// [LE Periodic Advertising Terminate Sync](crate::host::HostHci::le_periodic_advertising_terminate_sync)
+    /// command.
+    LePeriodicAdvertisingTerminateSync(Status),
+
+    /// Status returned by the
+    /// [LE Add Device to Periodic Advertiser List](crate::host::HostHci::le_add_device_to_periodic_advertiser_list)
+    /// command.
+    LeAddDeviceToPeriodicAdvertiserList(Status),
+
+    /// Status returned by the
+    /// [LE Remove Device from Periodic Advertiser List](crate::host::HostHci::le_remove_device_from_periodic_advertiser_list)
+    /// command.
+    LeRemoveDeviceFromPeriodicAdvertiserList(Status),
+
+    /// Status returned by the
+    /// [LE Clear Periodic Advertiser List](crate::host::HostHci::le_clear_periodic_advertiser_list)
+    /// command.
+    LeClearPeriodicAdvertiserList(Status),
+
+    /// Status and periodic advertiser list size returned by the
+    /// [LE Read Periodic Advertiser List Size](crate::host::HostHci::le_read_periodic_advertiser_list_size)
+    /// command.
+    LeReadPeriodicAdvertiserListSize(Status, usize),
+
+    /// Status returned by the [LE Set Privacy Mode](crate::host::HostHci::le_set_privacy_mode)
+    /// command.
+    LeSetPrivacyMode(Status),
+
+    /// Parameters returned by the
+    /// [LE Periodic Advertising Sync Transfer](crate::host::HostHci::le_periodic_advertising_sync_transfer)
+    /// command.
+    LePeriodicAdvertisingSyncTransfer(LeSyncTransfer),
+
+    /// Parameters returned by the
+    /// [LE Periodic Advertising Set Info Transfer](crate::host::HostHci::le_periodic_advertising_set_info_transfer)
+    /// command.
+    LePeriodicAdvertisingSetInfoTransfer(LeSyncTransfer),
+
+    /// Parameters returned by the
+    /// [LE Set Periodic Advertising Sync Transfer Parameters](crate::host::HostHci::le_set_periodic_advertising_sync_transfer_parameters)
+    /// command.
+    LeSetPeriodicAdvertisingSyncTransferParameters(LeSyncTransfer),
+
+    /// Status returned by the
+    /// [LE Set Default Periodic Advertising Sync Transfer Parameters](crate::host::HostHci::le_set_default_periodic_advertising_sync_transfer_parameters)
+    /// command.
+    LeSetDefaultPeriodicAdvertisingSyncTransferParameters(Status),
+
+    /// Parameters returned by the [LE Read ISO TX Sync](crate::host::HostHci::le_read_iso_tx_sync)
+    /// command.
+    LeReadIsoTxSync(LeReadIsoTxSync),
+
+    /// Parameters returned by the
+    /// [LE Set CIG Parameters](crate::host::HostHci::le_set_cig_parameters) command.
+    LeSetCigParameters(LeSetCigParameters),
+
+    /// Parameters returned by the
+    /// [LE Set CIG Parameters Test](crate::host::HostHci::le_set_cig_parameters_test) command.
+    LeSetCigParametersTest(LeSetCigParameters),
+
+    /// Parameters returned by the [LE Remove CIG](crate::host::HostHci::le_remove_cig) command.
+    LeRemoveCig(LeRemoveCig),
+
+    /// Parameters returned by the
+    /// [LE Reject CIS Request](crate::host::HostHci::le_reject_cis_request) command.
+    LeRejectCisRequest(LeIsoConnection),
+
+    /// Parameters returned by the
+    /// [LE BIG Terminate Sync](crate::host::HostHci::le_big_terminate_sync) command.
+    LeBigTerminateSync(LeBigTerminateSync),
+
+    /// Parameters returned by the
+    /// [LE Setup ISO Data Path](crate::host::HostHci::le_setup_iso_data_path) command.
+    LeSetupIsoDataPath(LeIsoConnection),
+
+    /// Parameters returned by the
+    /// [LE Remove ISO Data Path](crate::host::HostHci::le_remove_iso_data_path) command.
+    LeRemoveIsoDataPath(LeIsoConnection),
+
+    /// Parameters returned by the
+    /// [LE Enhanced Read Transmit Power Level](crate::host::HostHci::le_enhanced_read_transmit_power_level)
+    /// command.
+    LeEnhancedReadTransmitPowerLevel(LeEnhancedReadTransmitPowerLevel),
+
+    /// Parameters returned by the
+    /// [LE Set Path Loss Reporting Parameters](crate::host::HostHci::le_set_path_loss_reporting_parameters)
+    /// command.
+    LeSetPathLossReportingParameters(LePowerControlConnection),
+
+    /// Parameters returned by the
+    /// [LE Set Path Loss Reporting Enable](crate::host::HostHci::le_set_path_loss_reporting_enable)
+    /// command.
+    LeSetPathLossReportingEnable(LePowerControlConnection),
+
+    /// Parameters returned by the
+    /// [LE Set Transmit Power Reporting Enable](crate::host::HostHci::le_set_transmit_power_reporting_enable)
+    /// command.
+    LeSetTransmitPowerReportingEnable(LePowerControlConnection),
+
+    /// Status returned by the
+    /// [LE Set Default Subrate](crate::host::HostHci::le_set_default_subrate) command.
+    LeSetDefaultSubrate(Status),
+
+    /// Status returned by the
+    /// [LE Set Connectionless CTE Transmit Parameters](crate::host::HostHci::le_set_connectionless_cte_transmit_parameters)
+    /// command.
+    LeSetConnectionlessCteTransmitParameters(Status),
+
+    /// Status returned by the
+    /// [LE Set Connectionless CTE Transmit Enable](crate::host::HostHci::le_set_connectionless_cte_transmit_enable)
+    /// command.
+    LeSetConnectionlessCteTransmitEnable(Status),
+
+    /// Parameters returned by the
+    /// [LE Set Connectionless IQ Sampling Enable](crate::host::HostHci::le_set_connectionless_iq_sampling_enable)
+    /// command.
+    LeSetConnectionlessIqSamplingEnable(LeSetConnectionlessIqSamplingEnable),
+
+    /// Parameters returned by the
+    /// [LE Set Connection CTE Receive Parameters](crate::host::HostHci::le_set_connection_cte_receive_parameters)
+    /// command.
+    LeSetConnectionCteReceiveParameters(LeCteConnection),
+
+    /// Parameters returned by the
+    /// [LE Set Connection CTE Transmit Parameters](crate::host::HostHci::le_set_connection_cte_transmit_parameters)
+    /// command.
+    LeSetConnectionCteTransmitParameters(LeCteConnection),
+
+    /// Parameters returned by the
+    /// [LE Connection CTE Request Enable](crate::host::HostHci::le_connection_cte_request_enable)
+    /// command.
+    LeConnectionCteRequestEnable(LeCteConnection),
+
+    /// Parameters returned by the
+    /// [LE Connection CTE Response Enable](crate::host::HostHci::le_connection_cte_response_enable)
+    /// command.
+    LeConnectionCteResponseEnable(LeCteConnection),
+
+    /// Parameters returned by the
+    /// [LE Read Antenna Information](crate::host::HostHci::le_read_antenna_information) command.
+    LeReadAntennaInformation(LeReadAntennaInformation),
+
+    /// Parameters returned by vendor-specific commands.
+    Vendor(crate::vendor::event::command::VendorReturnParameters),
+}
+
+impl ReturnParameters {
+    /// Returns the opcode of the command that generated these return parameters. For
+    /// [`Spontaneous`](ReturnParameters::Spontaneous) return parameters, this is 0x0000.
+    pub fn opcode(&self) -> crate::opcode::Opcode {
+        match self {
+            ReturnParameters::Spontaneous => crate::opcode::Opcode(0x0000),
+            ReturnParameters::SetEventMask(..) => crate::opcode::SET_EVENT_MASK,
+            ReturnParameters::Reset(..) => crate::opcode::RESET,
+            ReturnParameters::ReadTxPowerLevel(..) => crate::opcode::READ_TX_POWER_LEVEL,
+            ReturnParameters::ReadLocalVersionInformation(..) => {
+                crate::opcode::READ_LOCAL_VERSION_INFO
+            }
+            ReturnParameters::ReadLocalSupportedCommands(..) => {
+                crate::opcode::READ_LOCAL_SUPPORTED_COMMANDS
+            }
+            ReturnParameters::ReadLocalSupportedFeatures(..) => {
+                crate::opcode::READ_LOCAL_SUPPORTED_FEATURES
+            }
+            ReturnParameters::ReadBdAddr(..) => crate::opcode::READ_BD_ADDR,
+            ReturnParameters::ReadRssi(..) => crate::opcode::READ_RSSI,
+            ReturnParameters::LeSetEventMask(..) => crate::opcode::LE_SET_EVENT_MASK,
+            ReturnParameters::LeReadBufferSize(..) => crate::opcode::LE_READ_BUFFER_SIZE,
+            ReturnParameters::LeReadLocalSupportedFeatures(..) => {
+                crate::opcode::LE_READ_LOCAL_SUPPORTED_FEATURES
+            }
+            ReturnParameters::LeSetRandomAddress(..) => crate::opcode::LE_SET_RANDOM_ADDRESS,
+            ReturnParameters::LeSetAdvertisingParameters(..) => {
+                crate::opcode::LE_SET_ADVERTISING_PARAMETERS
+            }
+            ReturnParameters::LeReadAdvertisingChannelTxPower(..) => {
+                crate::opcode::LE_READ_ADVERTISING_CHANNEL_TX_POWER
+            }
+            ReturnParameters::LeSetAdvertisingData(..) => crate::opcode::LE_SET_ADVERTISING_DATA,
+            ReturnParameters::LeSetScanResponseData(..) => crate::opcode::LE_SET_SCAN_RESPONSE_DATA,
+            ReturnParameters::LeSetAdvertisingEnable(..) => crate::opcode::LE_SET_ADVERTISE_ENABLE,
+            ReturnParameters::LeSetScanParameters(..) => crate::opcode::LE_SET_SCAN_PARAMETERS,
+            ReturnParameters::LeSetScanEnable(..) => crate::opcode::LE_SET_SCAN_ENABLE,
+            ReturnParameters::LeCreateConnectionCancel(..) => {
+                crate::opcode::LE_CREATE_CONNECTION_CANCEL
+            }
+            ReturnParameters::LeReadWhiteListSize(..) => crate::opcode::LE_READ_WHITE_LIST_SIZE,
+            ReturnParameters::LeClearWhiteList(..) => crate::opcode::LE_CLEAR_WHITE_LIST,
+            ReturnParameters::LeAddDeviceToWhiteList(..) => {
+                crate::opcode::LE_ADD_DEVICE_TO_WHITE_LIST
+            }
+            ReturnParameters::LeRemoveDeviceFromWhiteList(..) => {
+                crate::opcode::LE_REMOVE_DEVICE_FROM_WHITE_LIST
+            }
+            ReturnParameters::LeSetHostChannelClassification(..) => {
+                crate::opcode::LE_SET_HOST_CHANNEL_CLASSIFICATION
+            }
+            ReturnParameters::LeReadChannelMap(..) => crate::opcode::LE_READ_CHANNEL_MAP,
+            ReturnParameters::LeEncrypt(..) => crate::opcode::LE_ENCRYPT,
+            ReturnParameters::LeRand(..) => crate::opcode::LE_RAND,
+            ReturnParameters::LeLongTermKeyRequestReply(..) => crate::opcode::LE_LTK_REQUEST_REPLY,
+            ReturnParameters::LeLongTermKeyRequestNegativeReply(..) => {
+                crate::opcode::LE_LTK_REQUEST_NEGATIVE_REPLY
+            }
+            ReturnParameters::LeReadSupportedStates(..) => crate::opcode::LE_READ_STATES,
+            ReturnParameters::LeReceiverTest(..) => crate::opcode::LE_RECEIVER_TEST,
+            ReturnParameters::LeTransmitterTest(..) => crate::opcode::LE_TRANSMITTER_TEST,
+            ReturnParameters::LeTestEnd(..) => crate::opcode::LE_TEST_END,
+            ReturnParameters::LeSetDataLength(..) => crate::opcode::LE_SET_DATA_LENGTH,
+            ReturnParameters::LeReadSuggestedDefaultDataLength(..) => {
+                crate::opcode::LE_READ_SUGGESTED_DEFAULT_DATA_LENGTH
+            }
+            ReturnParameters::LeWriteSuggestedDefaultDataLength(..) => {
+                crate::opcode::LE_WRITE_SUGGESTED_DEFAULT_DATA_LENGTH
+            }
+            ReturnParameters::LeReadMaximumDataLength(..) => {
+                crate::opcode::LE_READ_MAXIMUM_DATA_LENGTH
+            }
+            ReturnParameters::LeAddDeviceToResolvingList(..) => {
+                crate::opcode::LE_ADD_DEVICE_TO_RESOLVING_LIST
+            }
+            ReturnParameters::LeRemoveDeviceFromResolvingList(..) => {
+                crate::opcode::LE_REMOVE_DEVICE_FROM_RESOLVING_LIST
+            }
+            ReturnParameters::LeClearResolvingList(..) => crate::opcode::LE_CLEAR_RESOLVING_LIST,
+            ReturnParameters::LeReadResolvingListSize(..) => {
+                crate::opcode::LE_READ_RESOLVING_LIST_SIZE
+            }
+            ReturnParameters::LeReadPeerResolvableAddress(..) => {
+                crate::opcode::LE_READ_PEER_RESOLVABLE_ADDRESS
+            }
+            ReturnParameters::LeReadLocalResolvableAddress(..) => {
+                crate::opcode::LE_READ_LOCAL_RESOLVABLE_ADDRESS
+            }
+            ReturnParameters::LeSetAddressResolutionEnable(..) => {
+                crate::opcode::LE_SET_ADDRESS_RESOLUTION_ENABLE
+            }
+            ReturnParameters::LeSetResolvablePrivateAddressTimeout(..) => {
+                crate::opcode::LE_SET_RESOLVABLE_PRIVATE_ADDRESS_TIMEOUT
+            }
+            ReturnParameters::LeReadPhy(..) => crate::opcode::LE_READ_PHY,
+            ReturnParameters::LeSetDefaultPhy(..) => crate::opcode::LE_SET_DEFAULT_PHY,
+            ReturnParameters::LeSetExtendedAdvertisingParameters(..) => {
+                crate::opcode::LE_SET_EXTENDED_ADVERTISING_PARAMETERS
+            }
+            ReturnParameters::LeSetExtendedAdvertisingData(..) => {
+                crate::opcode::LE_SET_EXTENDED_ADVERTISING_DATA
+            }
+            ReturnParameters::LeSetExtendedScanResponseData(..) => {
+                crate::opcode::LE_SET_EXTENDED_SCAN_RESPONSE_DATA
+            }
+            ReturnParameters::LeSetExtendedAdvertisingEnable(..) => {
+                crate::opcode::LE_SET_EXTENDED_ADVERTISING_ENABLE
+            }
+            ReturnParameters::LeReadMaximumAdvertisingDataLength(..) => {
+                crate::opcode::LE_READ_MAXIMUM_ADVERTISING_DATA_LENGTH
+            }
+            ReturnParameters::LeReadNumberOfSupportedAdvertisingSets(..) => {
+                crate::opcode::LE_READ_NUMBER_OF_SUPPORTED_ADVERTISING_SETS
+            }
+            ReturnParameters::LeRemoveAdvertisingSet(..) => {
+                crate::opcode::LE_REMOVE_ADVERTISING_SET
+            }
+            ReturnParameters::LeClearAdvertisingSets(..) => {
+                crate::opcode::LE_CLEAR_ADVERTISING_SETS
+            }
+            ReturnParameters::LeSetPeriodicAdvertisingParameters(..) => {
+                crate::opcode::LE_SET_PERIODIC_ADVERTISING_PARAMETERS
+            }
+            ReturnParameters::LeSetPeriodicAdvertisingData(..) => {
+                crate::opcode::LE_SET_PERIODIC_ADVERTISING_DATA
+            }
+            ReturnParameters::LeSetPeriodicAdvertisingEnable(..) => {
+                crate::opcode::LE_SET_PERIODIC_ADVERTISING_ENABLE
+            }
+            ReturnParameters::LeSetExtendedScanParameters(..) => {
+                crate::opcode::LE_SET_EXTENDED_SCAN_PARAMETERS
+            }
+            ReturnParameters::LeSetExtendedScanEnable(..) => {
+                crate::opcode::LE_SET_EXTENDED_SCAN_ENABLE
+            }
+            ReturnParameters::LePeriodicAdvertisingCreateSyncCancel(..) => {
+                crate::opcode::LE_PERIODIC_ADVERTISING_CREATE_SYNC_CANCEL
+            }
+            ReturnParameters::LePeriodicAdvertisingTerminateSync(..) => {
+                crate::opcode::LE_PERIODIC_ADVERTISING_TERMINATE_SYNC
+            }
+            ReturnParameters::LeAddDeviceToPeriodicAdvertiserList(..) => {
+                crate::opcode::LE_ADD_DEVICE_TO_PERIODIC_ADVERTISER_LIST
+            }
+            ReturnParameters::LeRemoveDeviceFromPeriodicAdvertiserList(..) => {
+                crate::opcode::LE_REMOVE_DEVICE_FROM_PERIODIC_ADVERTISER_LIST
+            }
+            ReturnParameters::LeClearPeriodicAdvertiserList(..) => {
+                crate::opcode::LE_CLEAR_PERIODIC_ADVERTISER_LIST
+            }
+            ReturnParameters::LeReadPeriodicAdvertiserListSize(..) => {
+                crate::opcode::LE_READ_PERIODIC_ADVERTISER_LIST_SIZE
+            }
+            ReturnParameters::LeSetPrivacyMode(..) => crate::opcode::LE_SET_PRIVACY_MODE,
+            ReturnParameters::LePeriodicAdvertisingSyncTransfer(..) => {
+                crate::opcode::LE_PERIODIC_ADVERTISING_SYNC_TRANSFER
+            }
+            ReturnParameters::LePeriodicAdvertisingSetInfoTransfer(..) => {
+                crate::opcode::LE_PERIODIC_ADVERTISING_SET_INFO_TRANSFER
+            }
+            ReturnParameters::LeSetPeriodicAdvertisingSyncTransferParameters(..) => {
+                crate::opcode::LE_SET_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS
+            }
+            ReturnParameters::LeSetDefaultPeriodicAdvertisingSyncTransferParameters(..) => {
+                crate::opcode::LE_SET_DEFAULT_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS
+            }
+            ReturnParameters::LeReadIsoTxSync(..) => crate::opcode::LE_READ_ISO_TX_SYNC,
+            ReturnParameters::LeSetCigParameters(..) => crate::opcode::LE_SET_CIG_PARAMETERS,
+            ReturnParameters::LeSetCigParametersTest(..) => {
+                crate::opcode::LE_SET_CIG_PARAMETERS_TEST
+            }
+            ReturnParameters::LeRemoveCig(..) => crate::opcode::LE_REMOVE_CIG,
+            ReturnParameters::LeRejectCisRequest(..) => crate::opcode::LE_REJECT_CIS_REQUEST,
+            ReturnParameters::LeBigTerminateSync(..) => crate::opcode::LE_BIG_TERMINATE_SYNC,
+            ReturnParameters::LeSetupIsoDataPath(..) => crate::opcode::LE_SETUP_ISO_DATA_PATH,
+            ReturnParameters::LeRemoveIsoDataPath(..) => crate::opcode::LE_REMOVE_ISO_DATA_PATH,
+            ReturnParameters::LeEnhancedReadTransmitPowerLevel(..) => {
+                crate::opcode::LE_ENHANCED_READ_TRANSMIT_POWER_LEVEL
+            }
+            ReturnParameters::LeSetPathLossReportingParameters(..) => {
+                crate::opcode::LE_SET_PATH_LOSS_REPORTING_PARAMETERS
+            }
+            ReturnParameters::LeSetPathLossReportingEnable(..) => {
+                crate::opcode::LE_SET_PATH_LOSS_REPORTING_ENABLE
+            }
+            ReturnParameters::LeSetTransmitPowerReportingEnable(..) => {
+                crate::opcode::LE_SET_TRANSMIT_POWER_REPORTING_ENABLE
+            }
+            ReturnParameters::LeSetDefaultSubrate(..) => crate::opcode::LE_SET_DEFAULT_SUBRATE,
+            ReturnParameters::LeSetConnectionlessCteTransmitParameters(..) => {
+                crate::opcode::LE_SET_CONNECTIONLESS_CTE_TRANSMIT_PARAMETERS
+            }
+            ReturnParameters::LeSetConnectionlessCteTransmitEnable(..) => {
+                crate::opcode::LE_SET_CONNECTIONLESS_CTE_TRANSMIT_ENABLE
+            }
+            ReturnParameters::LeSetConnectionlessIqSamplingEnable(..) => {
+                crate::opcode::LE_SET_CONNECTIONLESS_IQ_SAMPLING_ENABLE
+            }
+            ReturnParameters::LeSetConnectionCteReceiveParameters(..) => {
+                crate::opcode::LE_SET_CONNECTION_CTE_RECEIVE_PARAMETERS
+            }
+            ReturnParameters::LeSetConnectionCteTransmitParameters(..) => {
+                crate::opcode::LE_SET_CONNECTION_CTE_TRANSMIT_PARAMETERS
+            }
+            ReturnParameters::LeConnectionCteRequestEnable(..) => {
+                crate::opcode::LE_CONNECTION_CTE_REQUEST_ENABLE
+            }
+            ReturnParameters::LeConnectionCteResponseEnable(..) => {
+                crate::opcode::LE_CONNECTION_CTE_RESPONSE_ENABLE
+            }
+            ReturnParameters::LeReadAntennaInformation(..) => {
+                crate::opcode::LE_READ_ANTENNA_INFORMATION
+            }
+            ReturnParameters::Vendor(value) => value.opcode(),
+        }
+    }
+
+    /// Returns the status of the command that generated these return parameters, or `None` for
+    /// [`Spontaneous`](ReturnParameters::Spontaneous) return parameters and vendor-specific return
+    /// parameters that do not include a status.
+    pub fn status(&self) -> Option<Status> {
+        match self {
+            ReturnParameters::Spontaneous => None,
+            ReturnParameters::SetEventMask(status) => Some(*status),
+            ReturnParameters::Reset(status) => Some(*status),
+            ReturnParameters::ReadTxPowerLevel(params) => Some(params.status),
+            ReturnParameters::ReadLocalVersionInformation(params) => Some(params.status),
+            ReturnParameters::ReadLocalSupportedCommands(params) => Some(params.status),
+            ReturnParameters::ReadLocalSupportedFeatures(params) => Some(params.status),
+            ReturnParameters::ReadBdAddr(params) => Some(params.status),
+            ReturnParameters::ReadRssi(params) => Some(params.status),
+            ReturnParameters::LeSetEventMask(status) => Some(*status),
+            ReturnParameters::LeReadBufferSize(params) => Some(params.status),
+            ReturnParameters::LeReadLocalSupportedFeatures(params) => Some(params.status),
+            ReturnParameters::LeSetRandomAddress(status) => Some(*status),
+            ReturnParameters::LeSetAdvertisingParameters(status) => Some(*status),
+            ReturnParameters::LeReadAdvertisingChannelTxPower(params) => Some(params.status),
+            ReturnParameters::LeSetAdvertisingData(status) => Some(*status),
+            ReturnParameters::LeSetScanResponseData(status) => Some(*status),
+            ReturnParameters::LeSetAdvertisingEnable(status) => Some(*status),
+            ReturnParameters::LeSetScanParameters(status) => Some(*status),
+            ReturnParameters::LeSetScanEnable(status) => Some(*status),
+            ReturnParameters::LeCreateConnectionCancel(status) => Some(*status),
+            ReturnParameters::LeReadWhiteListSize(status, _) => Some(*status),
+            ReturnParameters::LeClearWhiteList(status) => Some(*status),
+            ReturnParameters::LeAddDeviceToWhiteList(status) => Some(*status),
+            ReturnParameters::LeRemoveDeviceFromWhiteList(status) => Some(*status),
+            ReturnParameters::LeSetHostChannelClassification(status) => Some(*status),
+            ReturnParameters::LeReadChannelMap(params) => Some(params.status),
+            ReturnParameters::LeEncrypt(params) => Some(params.status),
+            ReturnParameters::LeRand(params) => Some(params.status),
+            ReturnParameters::LeLongTermKeyRequestReply(params) => Some(params.status),
+            ReturnParameters::LeLongTermKeyRequestNegativeReply(params) => Some(params.status),
+            ReturnParameters::LeReadSupportedStates(params) => Some(params.status),
+            ReturnParameters::LeReceiverTest(status) => Some(*status),
+            ReturnParameters::LeTransmitterTest(status) => Some(*status),
+            ReturnParameters::LeTestEnd(params) => Some(params.status),
+            ReturnParameters::LeSetDataLength(params) => Some(params.status),
+            ReturnParameters::LeReadSuggestedDefaultDataLength(params) => Some(params.status),
+            ReturnParameters::LeWriteSuggestedDefaultDataLength(status) => Some(*status),
+            ReturnParameters::LeReadMaximumDataLength(params) => Some(params.status),
+            ReturnParameters::LeAddDeviceToResolvingList(status) => Some(*status),
+            ReturnParameters::LeRemoveDeviceFromResolvingList(status) => Some(*status),
+            ReturnParameters::LeClearResolvingList(status) => Some(*status),
+            ReturnParameters::LeReadResolvingListSize(status, _) => Some(*status),
+            ReturnParameters::LeReadPeerResolvableAddress(params) => Some(params.status),
+            ReturnParameters::LeReadLocalResolvableAddress(params) => Some(params.status),
+            ReturnParameters::LeSetAddressResolutionEnable(status) => Some(*status),
+            ReturnParameters::LeSetResolvablePrivateAddressTimeout(status) => Some(*status),
+            ReturnParameters::LeReadPhy(params) => Some(params.status),
+            ReturnParameters::LeSetDefaultPhy(status) => Some(*status),
+            ReturnParameters::LeSetExtendedAdvertisingParameters(params) => Some(params.status),
+            ReturnParameters::LeSetExtendedAdvertisingData(status) => Some(*status),
+            ReturnParameters::LeSetExtendedScanResponseData(status) => Some(*status),
+            ReturnParameters::LeSetExtendedAdvertisingEnable(status) => Some(*status),
+            ReturnParameters::LeReadMaximumAdvertisingDataLength(status, _) => Some(*status),
+            ReturnParameters::LeReadNumberOfSupportedAdvertisingSets(status, _) => Some(*status),
+            ReturnParameters::LeRemoveAdvertisingSet(status) => Some(*status),
+            ReturnParameters::LeClearAdvertisingSets(status) => Some(*status),
+            ReturnParameters::LeSetPeriodicAdvertisingParameters(status) => Some(*status),
+            ReturnParameters::LeSetPeriodicAdvertisingData(status) => Some(*status),
+            ReturnParameters::LeSetPeriodicAdvertisingEnable(status) => Some(*status),
+            ReturnParameters::LeSetExtendedScanParameters(status) => Some(*status),
+            ReturnParameters::LeSetExtendedScanEnable(status) => Some(*status),
+            ReturnParameters::LePeriodicAdvertisingCreateSyncCancel(status) => Some(*status),
+            ReturnParameters::LePeriodicAdvertisingTerminateSync(status) => Some(*status),
+            ReturnParameters::LeAddDeviceToPeriodicAdvertiserList(status) => Some(*status),
+            ReturnParameters::LeRemoveDeviceFromPeriodicAdvertiserList(status) => Some(*status),
+            ReturnParameters::LeClearPeriodicAdvertiserList(status) => Some(*status),
+            ReturnParameters::LeReadPeriodicAdvertiserListSize(status, _) => Some(*status),
+            ReturnParameters::LeSetPrivacyMode(status) => Some(*status),
+            ReturnParameters::LePeriodicAdvertisingSyncTransfer(params) => Some(params.status),
+            ReturnParameters::LePeriodicAdvertisingSetInfoTransfer(params) => Some(params.status),
+            ReturnParameters::LeSetPeriodicAdvertisingSyncTransferParameters(params) => {
+                Some(params.status)
+            }
+            ReturnParameters::LeSetDefaultPeriodicAdvertisingSyncTransferParameters(status) => {
+                Some(*status)
+            }
+            ReturnParameters::LeReadIsoTxSync(params) => Some(params.status),
+            ReturnParameters::LeSetCigParameters(params) => Some(params.status),
+            ReturnParameters::LeSetCigParametersTest(params) => Some(params.status),
+            ReturnParameters::LeRemoveCig(params) => Some(params.status),
+            ReturnParameters::LeRejectCisRequest(params) => Some(params.status),
+            ReturnParameters::LeBigTerminateSync(params) => Some(params.status),
+            ReturnParameters::LeSetupIsoDataPath(params) => Some(params.status),
+            ReturnParameters::LeRemoveIsoDataPath(params) => Some(params.status),
+            ReturnParameters::LeEnhancedReadTransmitPowerLevel(params) => Some(params.status),
+            ReturnParameters::LeSetPathLossReportingParameters(params) => Some(params.status),
+            ReturnParameters::LeSetPathLossReportingEnable(params) => Some(params.status),
+            ReturnParameters::LeSetTransmitPowerReportingEnable(params) => Some(params.status),
+            ReturnParameters::LeSetDefaultSubrate(status) => Some(*status),
+            ReturnParameters::LeSetConnectionlessCteTransmitParameters(status) => Some(*status),
+            ReturnParameters::LeSetConnectionlessCteTransmitEnable(status) => Some(*status),
+            ReturnParameters::LeSetConnectionlessIqSamplingEnable(params) => Some(params.status),
+            ReturnParameters::LeSetConnectionCteReceiveParameters(params) => Some(params.status),
+            ReturnParameters::LeSetConnectionCteTransmitParameters(params) => Some(params.status),
+            ReturnParameters::LeConnectionCteRequestEnable(params) => Some(params.status),
+            ReturnParameters::LeConnectionCteResponseEnable(params) => Some(params.status),
+            ReturnParameters::LeReadAntennaInformation(params) => Some(params.status),
+            ReturnParameters::Vendor(params) => params.status(),
+        }
+    }
+}
+
+fn to_status(bytes: &[u8]) -> Result<Status, crate::event::Error> {
+    bytes[0].try_into().map_err(super::rewrap_bad_status)
+}
+
+fn write_status(status: &Status, bytes: &mut [u8]) -> usize {
+    bytes[0] = (*status).into();
+    1
+}
+
+/// Values returned by the [Read Transmit Power Level](crate::host::HostHci::read_tx_power_level)
+/// command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TxPowerLevel {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Specifies which connection handle's transmit power level setting is returned
+    pub conn_handle: ConnectionHandle,
+
+    /// Power level for the connection handle, in dBm.
+    ///
+    /// Valid range is -30 dBm to +20 dBm, but that is not enforced by this implementation.
+    pub tx_power_level_dbm: i8,
+}
+
+impl TxPowerLevel {
+    /// Returns the power level for the connection handle.
+    pub fn tx_power_level(&self) -> Dbm {
+        Dbm(self.tx_power_level_dbm)
+    }
+}
+
+fn to_tx_power_level(bytes: &[u8]) -> Result<TxPowerLevel, crate::event::Error> {
+    require_len!(bytes, 4);
+    Ok(TxPowerLevel {
+        status: to_status(bytes)?,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[1..])),
+        tx_power_level_dbm: u8::cast_signed(bytes[3]),
+    })
+}
+
+fn write_tx_power_level(params: &TxPowerLevel, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+    bytes[3] = i8::cast_unsigned(params.tx_power_level_dbm);
+    4
+}
+
+/// Values returned by
+/// [Read Local Version Information](crate::host::HostHci::read_local_version_information) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LocalVersionInfo {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// The version information of the HCI layer.
+    ///
+    /// See the Bluetooth
+    /// [Assigned Numbers](https://www.bluetooth.com/specifications/assigned-numbers/host-controller-interface).
+    pub hci_version: u8,
+
+    /// Revision of the Current HCI in the BR/EDR Controller.  This value is implementation
+    /// dependent.
+    pub hci_revision: u16,
+
+    /// Version of the Current [LMP] or [PAL] in the Controller.
+    ///
+    /// [LMP]: https://www.bluetooth.com/specifications/assigned-numbers/link-manager
+    /// [PAL]: https://www.bluetooth.com/specifications/assigned-numbers/protocol-adaptation-layer
+    pub lmp_version: u8,
+
+    /// Manufacturer Name of the BR/EDR Controller.  See Bluetooth
+    /// [Assigned Numbers](https://www.bluetooth.com/specifications/assigned-numbers/company-identifiers)
+    pub manufacturer_name: u16,
+
+    /// Subversion of the Current [LMP] or [PAL] in the Controller. This value is implementation
+    /// dependent.
+    ///
+    /// [LMP]: https://www.bluetooth.com/specifications/assigned-numbers/link-manager
+    /// [PAL]: https://www.bluetooth.com/specifications/assigned-numbers/protocol-adaptation-layer
+    pub lmp_subversion: u16,
+}
+
+fn to_local_version_info(bytes: &[u8]) -> Result<LocalVersionInfo, crate::event::Error> {
+    require_len!(bytes, 9);
+
+    Ok(LocalVersionInfo {
+        status: bytes[0].try_into().map_err(super::rewrap_bad_status)?,
+        hci_version: bytes[1],
+        hci_revision: LittleEndian::read_u16(&bytes[2..]),
+        lmp_version: bytes[4],
+        manufacturer_name: LittleEndian::read_u16(&bytes[5..]),
+        lmp_subversion: LittleEndian::read_u16(&bytes[7..]),
+    })
+}
+
+fn write_local_version_info(params: &LocalVersionInfo, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    bytes[1] = params.hci_version;
+    LittleEndian::write_u16(&mut bytes[2..], params.hci_revision);
+    bytes[4] = params.lmp_version;
+    LittleEndian::write_u16(&mut bytes[5..], params.manufacturer_name);
+    LittleEndian::write_u16(&mut bytes[7..], params.lmp_subversion);
+    9
+}
+
+/// Values returned by the
+/// [Read Local Supported Commands](crate::host::HostHci::read_local_supported_commands) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LocalSupportedCommands {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Flags for supported commands.
+    pub supported_commands: CommandFlags,
+}
+
+const COMMAND_FLAGS_SIZE: usize = 64;
+
+bitflag_array! {
+    /// Extended bit field for the command flags of the [`LocalSupportedCommands`] return
+    /// parameters.
+    #[derive(Copy, Clone)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    pub struct CommandFlags : COMMAND_FLAGS_SIZE;
+    pub struct CommandFlag;
+
+    /// Inquiry
+    const INQUIRY = 0, 1 << 0;
+    /// Cancel Inquiry
+    const INQUIRY_CANCEL = 0, 1 << 1;
+    /// Periodic Inquiry Mode
+    const PERIODIC_INQUIRY_MODE = 0, 1 << 2;
+    /// Exit Periodic Inquiry Mode
+    const EXIT_PERIODIC_INQUIRY_MODE = 0, 1 << 3;
+    /// Create Connection
+    const CREATE_CONNECTION = 0, 1 << 4;
+    /// Disconnect
+    const DISCONNECT = 0, 1 << 5;
+    /// Add SCO Connection (deprecated by the spec)
+    #[deprecated]
+    const ADD_SCO_CONNECTION = 0, 1 << 6;
+    /// Create Connection Cancel
+    const CREATE_CONNECTION_CANCEL = 0, 1 << 7;
+    /// Accept Connection Request
+    const ACCEPT_CONNECTION_REQUEST = 1, 1 << 0;
+    /// Reject Connection Request
+    const REJECT_CONNECTION_REQUEST = 1, 1 << 1;
+    /// Link Key Request Reply
+    const LINK_KEY_REQUEST_REPLY = 1, 1 << 2;
+    /// Link Key Request Negative Reply
+    const LINK_KEY_REQUEST_NEGATIVE_REPLY = 1, 1 << 3;
+    /// PIN Code Request Reply
+    const PIN_CODE_REQUEST_REPLY = 1, 1 << 4;
+    /// PIN Code Request Negative Reply
+    const PIN_CODE_REQUEST_NEGATIVE_REPLY = 1, 1 << 5;
+    /// Change Connection Packet Type
+    const CHANGE_CONNECTION_PACKET_TYPE = 1, 1 << 6;
+    /// Authentication Requested
+    const AUTHENTICATION_REQUESTED = 1, 1 << 7;
+    /// Set Connection Encryption
+    const SET_CONNECTION_ENCRYPTION = 2, 1 << 0;
     /// Change Connection Link Key
     const CHANGE_CONNECTION_LINK_KEY = 2, 1 << 1;
     /// Master Link Key
@@ -973,862 +2218,1623 @@ bitflag_array! {
     const LE_SET_PRIVACY_MODE = 39, 1 << 2;
 }
 
-impl Debug for CommandFlags {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        writeln!(f, "{:?}", &self.0[..16])?;
-        writeln!(f, "{:?}", &self.0[16..32])?;
-        writeln!(f, "{:?}", &self.0[32..39])
+impl Debug for CommandFlags {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        writeln!(f, "{:?}", &self.0[..16])?;
+        writeln!(f, "{:?}", &self.0[16..32])?;
+        writeln!(f, "{:?}", &self.0[32..39])
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for CommandFlags {
+    type Error = crate::event::Error;
+    fn try_from(value: &[u8]) -> Result<CommandFlags, Self::Error> {
+        require_len!(value, COMMAND_FLAGS_SIZE);
+
+        CommandFlags::from_bits(value).ok_or(crate::event::Error::BadCommandFlag)
+    }
+}
+
+fn to_supported_commands(bytes: &[u8]) -> Result<LocalSupportedCommands, crate::event::Error> {
+    require_len!(bytes, 1 + COMMAND_FLAGS_SIZE);
+    Ok(LocalSupportedCommands {
+        status: bytes[0].try_into().map_err(super::rewrap_bad_status)?,
+        supported_commands: bytes[1..=COMMAND_FLAGS_SIZE]
+            .try_into()
+            .map_err(|e| match e {
+                crate::event::Error::BadLength(actual, expected) => {
+                    crate::event::Error::BadLength(actual, expected)
+                }
+                crate::event::Error::BadCommandFlag => crate::event::Error::BadCommandFlag,
+                _ => unreachable!(),
+            })?,
+    })
+}
+
+fn write_supported_commands(params: &LocalSupportedCommands, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    params
+        .supported_commands
+        .copy_into_slice(&mut bytes[1..=COMMAND_FLAGS_SIZE]);
+    1 + COMMAND_FLAGS_SIZE
+}
+
+/// Values returned by the
+/// [Read Local Supported Features](crate::host::HostHci::read_local_supported_features) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LocalSupportedFeatures {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Flags for supported features.
+    pub supported_features: LmpFeatures,
+}
+
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// See the Bluetooth Specification, v4.1 or later, Vol 2, Part C, Section 3.3 (Table 3.2).
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct LmpFeatures : u64 {
+        /// 3-slot packets
+        const THREE_SLOT_PACKETS = 1 << 0;
+        /// 5-slot packets
+        const FIVE_SLOT_PACKETS = 1 << 1;
+        /// Encryption
+        const ENCRYPTION = 1 << 2;
+        /// Slot offset
+        const SLOT_OFFSET = 1 << 3;
+        /// Timing accuracy
+        const TIMING_ACCURACY = 1 << 4;
+        /// Role switch
+        const ROLE_SWITCH = 1 << 5;
+        /// Hold mode
+        const HOLD_MODE = 1 << 6;
+        /// Sniff mode
+        const SNIFF_MODE = 1 << 7;
+        /// Power control requests
+        const POWER_CONTROL_REQUESTS = 1 << 9;
+        /// Channel quality driven data rate (CQDDR)
+        const CHANNEL_QUALITY_DRIVEN_DATA_RATE_CQDDR = 1 << 10;
+        /// SCO link
+        const SCO_LINK = 1 << 11;
+        /// HV2 packets
+        const HV2_PACKETS = 1 << 12;
+        /// HV3 packets
+        const HV3_PACKETS = 1 << 13;
+        /// μ-law log synchronous data
+        const MU_LAW_LOG_SYNCHRONOUS_DATA = 1 << 14;
+        /// A-law log synchronous data
+        const A_LAW_LOG_SYNCHRONOUS_DATA = 1 << 15;
+        /// CVSD synchronous data
+        const CVSD_SYNCHRONOUS_DATA = 1 << 16;
+        /// Paging parameter negotiation
+        const PAGING_PARAMETER_NEGOTIATION = 1 << 17;
+        /// Power control
+        const POWER_CONTROL = 1 << 18;
+        /// Transparent synchronous data
+        const TRANSPARENT_SYNCHRONOUS_DATA = 1 << 19;
+        /// Flow control lag (least significant bit)
+        const FLOW_CONTROL_LAG_LSB = 1 << 20;
+        /// Flow control lag (middle bit)
+        const FLOW_CONTROL_LAG_MID = 1 << 21;
+        /// Flow control lag (most significant bit)
+        const FLOW_CONTROL_LAG_MSB = 1 << 22;
+        /// Broadcast Encryption
+        const BROADCAST_ENCRYPTION = 1 << 23;
+        /// Enhanced Data Rate ACL 2 Mb/s mode
+        const ENHANCED_DATA_RATE_ACL_2_MB_PER_S_MODE = 1 << 25;
+        /// Enhanced Data Rate ACL 3 Mb/s mode
+        const ENHANCED_DATA_RATE_ACL_3_MB_PER_S_MODE = 1 << 26;
+        /// Enhanced inquiry scan
+        const ENHANCED_INQUIRY_SCAN = 1 << 27;
+        /// Interlaced inquiry scan
+        const INTERLACED_INQUIRY_SCAN = 1 << 28;
+        /// Interlaced page scan
+        const INTERLACED_PAGE_SCAN = 1 << 29;
+        /// RSSI with inquiry results
+        const RSSI_WITH_INQUIRY_RESULTS = 1 << 30;
+        /// Extended SCO link (EV3 packets)
+        const EXTENDED_SCO_LINK_EV3_PACKETS = 1 << 31;
+        /// EV4 packets
+        const EV4_PACKETS = 1 << 32;
+        /// EV5 packets
+        const EV5_PACKETS = 1 << 33;
+        /// AFH capable peripheral
+        const AFH_CAPABLE_PERIPHERAL = 1 << 35;
+        /// AFH classification peripheral
+        const AFH_CLASSIFICATION_PERIPHERAL = 1 << 36;
+        /// BR/EDR Not Supported
+        const BR_EDR_NOT_SUPPORTED = 1 << 37;
+        /// LE Supported (Controller)
+        const LE_SUPPORTED_BY_CONTROLLER = 1 << 38;
+        /// 3-slot Enhanced Data Rate ACL packets
+        const THREE_SLOT_ENHANCED_DATA_RATE_ACL_PACKETS = 1 << 39;
+        /// 5-slot Enhanced Data Rate ACL packets
+        const FIVE_SLOT_ENHANCED_DATA_RATE_ACL_PACKETS = 1 << 40;
+        /// Sniff subrating
+        const SNIFF_SUBRATING = 1 << 41;
+        /// Pause encryption
+        const PAUSE_ENCRYPTION = 1 << 42;
+        /// AFH capable central device
+        const AFH_CAPABLE_CENTRAL_DEVICE = 1 << 43;
+        /// AFH classification central device
+        const AFH_CLASSIFICATION_CENTRAL_DEVICE = 1 << 44;
+        /// Enhanced Data Rate eSCO 2 Mb/s mode
+        const ENHANCED_DATA_RATE_ESCO_2_MB_PER_S_MODE = 1 << 45;
+        /// Enhanced Data Rate eSCO 3 Mb/s mode
+        const ENHANCED_DATA_RATE_ESCO_3_MB_PER_S_MODE = 1 << 46;
+        /// 3-slot Enhanced Data Rate eSCO packets
+        const THREE_SLOT_ENHANCED_DATA_RATE_ESCO_PACKETS = 1 << 47;
+        /// Extended Inquiry Response
+        const EXTENDED_INQUIRY_RESPONSE = 1 << 48;
+        /// Simultaneous LE and BR/EDR to Same Device Capable (Controller)
+        const SIMULTANEOUS_LE_AND_BR_EDR_TO_SAME_DEVICE_CAPABLE = 1 << 49;
+        /// Secure Simple Pairing
+        const SECURE_SIMPLE_PAIRING = 1 << 51;
+        /// Encapsulated PDU
+        const ENCAPSULATED_PDU = 1 << 52;
+        /// Erroneous Data Reporting
+        const ERRONEOUS_DATA_REPORTING = 1 << 53;
+        /// Non-flushable Packet Boundary Flag
+        const NON_FLUSHABLE_PACKET_BOUNDARY_FLAG = 1 << 54;
+        /// Link Supervision Timeout Changed Event
+        const LINK_SUPERVISION_TIMEOUT_CHANGED_EVENT = 1 << 56;
+        /// Inquiry TX Power Level
+        const INQUIRY_TX_POWER_LEVEL = 1 << 57;
+        /// Enhanced Power Control
+        const ENHANCED_POWER_CONTROL = 1 << 58;
+        /// Extended features
+        const EXTENDED_FEATURES = 1 << 63;
+    }
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// See the Bluetooth Specification, v4.1 or later, Vol 2, Part C, Section 3.3 (Table 3.2).
+    #[derive(Default)]
+    pub struct LmpFeatures : u64 {
+        /// 3-slot packets
+        const THREE_SLOT_PACKETS = 1 << 0;
+        /// 5-slot packets
+        const FIVE_SLOT_PACKETS = 1 << 1;
+        /// Encryption
+        const ENCRYPTION = 1 << 2;
+        /// Slot offset
+        const SLOT_OFFSET = 1 << 3;
+        /// Timing accuracy
+        const TIMING_ACCURACY = 1 << 4;
+        /// Role switch
+        const ROLE_SWITCH = 1 << 5;
+        /// Hold mode
+        const HOLD_MODE = 1 << 6;
+        /// Sniff mode
+        const SNIFF_MODE = 1 << 7;
+        /// Power control requests
+        const POWER_CONTROL_REQUESTS = 1 << 9;
+        /// Channel quality driven data rate (CQDDR)
+        const CHANNEL_QUALITY_DRIVEN_DATA_RATE_CQDDR = 1 << 10;
+        /// SCO link
+        const SCO_LINK = 1 << 11;
+        /// HV2 packets
+        const HV2_PACKETS = 1 << 12;
+        /// HV3 packets
+        const HV3_PACKETS = 1 << 13;
+        /// μ-law log synchronous data
+        const MU_LAW_LOG_SYNCHRONOUS_DATA = 1 << 14;
+        /// A-law log synchronous data
+        const A_LAW_LOG_SYNCHRONOUS_DATA = 1 << 15;
+        /// CVSD synchronous data
+        const CVSD_SYNCHRONOUS_DATA = 1 << 16;
+        /// Paging parameter negotiation
+        const PAGING_PARAMETER_NEGOTIATION = 1 << 17;
+        /// Power control
+        const POWER_CONTROL = 1 << 18;
+        /// Transparent synchronous data
+        const TRANSPARENT_SYNCHRONOUS_DATA = 1 << 19;
+        /// Flow control lag (least significant bit)
+        const FLOW_CONTROL_LAG_LSB = 1 << 20;
+        /// Flow control lag (middle bit)
+        const FLOW_CONTROL_LAG_MID = 1 << 21;
+        /// Flow control lag (most significant bit)
+        const FLOW_CONTROL_LAG_MSB = 1 << 22;
+        /// Broadcast Encryption
+        const BROADCAST_ENCRYPTION = 1 << 23;
+        /// Enhanced Data Rate ACL 2 Mb/s mode
+        const ENHANCED_DATA_RATE_ACL_2_MB_PER_S_MODE = 1 << 25;
+        /// Enhanced Data Rate ACL 3 Mb/s mode
+        const ENHANCED_DATA_RATE_ACL_3_MB_PER_S_MODE = 1 << 26;
+        /// Enhanced inquiry scan
+        const ENHANCED_INQUIRY_SCAN = 1 << 27;
+        /// Interlaced inquiry scan
+        const INTERLACED_INQUIRY_SCAN = 1 << 28;
+        /// Interlaced page scan
+        const INTERLACED_PAGE_SCAN = 1 << 29;
+        /// RSSI with inquiry results
+        const RSSI_WITH_INQUIRY_RESULTS = 1 << 30;
+        /// Extended SCO link (EV3 packets)
+        const EXTENDED_SCO_LINK_EV3_PACKETS = 1 << 31;
+        /// EV4 packets
+        const EV4_PACKETS = 1 << 32;
+        /// EV5 packets
+        const EV5_PACKETS = 1 << 33;
+        /// AFH capable peripheral
+        const AFH_CAPABLE_PERIPHERAL = 1 << 35;
+        /// AFH classification peripheral
+        const AFH_CLASSIFICATION_PERIPHERAL = 1 << 36;
+        /// BR/EDR Not Supported
+        const BR_EDR_NOT_SUPPORTED = 1 << 37;
+        /// LE Supported (Controller)
+        const LE_SUPPORTED_BY_CONTROLLER = 1 << 38;
+        /// 3-slot Enhanced Data Rate ACL packets
+        const THREE_SLOT_ENHANCED_DATA_RATE_ACL_PACKETS = 1 << 39;
+        /// 5-slot Enhanced Data Rate ACL packets
+        const FIVE_SLOT_ENHANCED_DATA_RATE_ACL_PACKETS = 1 << 40;
+        /// Sniff subrating
+        const SNIFF_SUBRATING = 1 << 41;
+        /// Pause encryption
+        const PAUSE_ENCRYPTION = 1 << 42;
+        /// AFH capable central device
+        const AFH_CAPABLE_CENTRAL_DEVICE = 1 << 43;
+        /// AFH classification central device
+        const AFH_CLASSIFICATION_CENTRAL_DEVICE = 1 << 44;
+        /// Enhanced Data Rate eSCO 2 Mb/s mode
+        const ENHANCED_DATA_RATE_ESCO_2_MB_PER_S_MODE = 1 << 45;
+        /// Enhanced Data Rate eSCO 3 Mb/s mode
+        const ENHANCED_DATA_RATE_ESCO_3_MB_PER_S_MODE = 1 << 46;
+        /// 3-slot Enhanced Data Rate eSCO packets
+        const THREE_SLOT_ENHANCED_DATA_RATE_ESCO_PACKETS = 1 << 47;
+        /// Extended Inquiry Response
+        const EXTENDED_INQUIRY_RESPONSE = 1 << 48;
+        /// Simultaneous LE and BR/EDR to Same Device Capable (Controller)
+        const SIMULTANEOUS_LE_AND_BR_EDR_TO_SAME_DEVICE_CAPABLE = 1 << 49;
+        /// Secure Simple Pairing
+        const SECURE_SIMPLE_PAIRING = 1 << 51;
+        /// Encapsulated PDU
+        const ENCAPSULATED_PDU = 1 << 52;
+        /// Erroneous Data Reporting
+        const ERRONEOUS_DATA_REPORTING = 1 << 53;
+        /// Non-flushable Packet Boundary Flag
+        const NON_FLUSHABLE_PACKET_BOUNDARY_FLAG = 1 << 54;
+        /// Link Supervision Timeout Changed Event
+        const LINK_SUPERVISION_TIMEOUT_CHANGED_EVENT = 1 << 56;
+        /// Inquiry TX Power Level
+        const INQUIRY_TX_POWER_LEVEL = 1 << 57;
+        /// Enhanced Power Control
+        const ENHANCED_POWER_CONTROL = 1 << 58;
+        /// Extended features
+        const EXTENDED_FEATURES = 1 << 63;
+    }
+}
+
+fn to_supported_features(bytes: &[u8]) -> Result<LocalSupportedFeatures, crate::event::Error> {
+    require_len!(bytes, 9);
+    Ok(LocalSupportedFeatures {
+        status: to_status(bytes)?,
+        supported_features: LmpFeatures::from_bits_truncate(LittleEndian::read_u64(&bytes[1..])),
+    })
+}
+
+fn write_supported_features(params: &LocalSupportedFeatures, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u64(&mut bytes[1..], params.supported_features.bits());
+    9
+}
+
+/// Values returned by the [Read BD ADDR](crate::host::HostHci::read_bd_addr) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ReadBdAddr {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Address of the device.
+    pub bd_addr: crate::BdAddr,
+}
+
+fn to_bd_addr(bytes: &[u8]) -> Result<ReadBdAddr, crate::event::Error> {
+    require_len!(bytes, 7);
+    let mut bd_addr = crate::BdAddr([0; 6]);
+    bd_addr.0.copy_from_slice(&bytes[1..]);
+    Ok(ReadBdAddr {
+        status: to_status(bytes)?,
+        bd_addr,
+    })
+}
+
+fn write_bd_addr(params: &ReadBdAddr, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    bytes[1..7].copy_from_slice(&params.bd_addr.0);
+    7
+}
+
+/// Values returned by the [Read RSSI](crate::host::HostHci::read_rssi) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ReadRssi {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// The Handle for the connection for which the RSSI has been read.
+    ///
+    /// The Handle is a connection handle for a BR/EDR Controller and a physical link handle for an
+    /// AMP Controller.
+    pub conn_handle: ConnectionHandle,
+
+    /// - BR/EDR
+    ///   - No range restriction
+    ///   - Units: dB
+    /// - AMP:
+    ///   - Range: AMP type specific
+    ///   - Units: dBm
+    /// - LE:
+    ///   - Range: -127 to 20, range not checked by this implementation. 127 indicates RSSI not
+    ///     available.
+    ///   - Units: dBm
+    pub rssi: i8,
+}
+
+fn to_read_rssi(bytes: &[u8]) -> Result<ReadRssi, crate::event::Error> {
+    require_len!(bytes, 4);
+    Ok(ReadRssi {
+        status: to_status(bytes)?,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[1..])),
+        rssi: u8::cast_signed(bytes[3]),
+    })
+}
+
+fn write_read_rssi(params: &ReadRssi, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+    bytes[3] = i8::cast_unsigned(params.rssi);
+    4
+}
+
+/// Values returned by the [LE Read Buffer Size](crate::host::HostHci::le_read_buffer_size) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeReadBufferSize {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// The size of the L2CAP PDU segments contained in ACL Data Packets, which are transferred from
+    /// the Host to the Controller to be broken up into packets by the Link Layer. Both the Host and
+    /// the Controller shall support command and event packets, where the data portion (excluding
+    /// header) contained in the packets is 255 octets in size.
+    ///
+    /// Note: Does not include the length of the HCI Data Packet header.
+    ///
+    /// If `data_packet_count` is 0, then the controller has no dedicated LE read buffer, so the
+    /// caller should use the `read_buffer_size` command.
+    pub data_packet_length: u16,
+
+    /// Contains the total number of HCI ACL Data Packets that can be stored in the data buffers of
+    /// the Controller. The Host determines how the buffers are to be divided between different
+    /// Connection Handles.
+    ///
+    /// If `data_packet_count` is 0, then the controller has no dedicated LE read buffer, so the
+    /// caller should use the `read_buffer_size` command.
+    pub data_packet_count: u8,
+}
+
+fn to_le_read_buffer_status(bytes: &[u8]) -> Result<LeReadBufferSize, crate::event::Error> {
+    require_len!(bytes, 4);
+    Ok(LeReadBufferSize {
+        status: to_status(bytes)?,
+        data_packet_length: LittleEndian::read_u16(&bytes[1..]),
+        data_packet_count: bytes[3],
+    })
+}
+
+fn write_le_read_buffer_status(params: &LeReadBufferSize, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u16(&mut bytes[1..], params.data_packet_length);
+    bytes[3] = params.data_packet_count;
+    4
+}
+
+/// Values returned by the
+/// [LE Read Local Supported Features](crate::host::HostHci::le_read_local_supported_features) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeSupportedFeatures {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Supported LE features.
+    pub supported_features: LeFeatures,
+}
+
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// Possible LE features for the
+    /// [LE Read Local Supported Features](crate::host::HostHci::le_read_local_supported_features) command.
+    /// See the Bluetooth specification, Vol 6, Part B, Section 4.6.  See Table 4.3 (v4.1 of the spec),
+    /// Table 4.4 (v4.2 and v5.0).
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct LeFeatures : u64 {
+        /// LE Encryption.  Valid from controller to controller.
+        const ENCRYPTION = 1 << 0;
+        /// Connection Parameters Request Procedure.  Valid from controller to controller.
+        const CONNECTION_PARAMETERS_REQUEST_PROCEDURE = 1 << 1;
+        /// Extended Reject Indication.  Valid from controller to controller.
+        const EXTENDED_REJECT_INDICATION = 1 << 2;
+        /// Peripheral-initiated Features Exchange.  Valid from controller to controller.
+        const PERIPHERALINITIATED_FEATURES_EXCHANGE = 1 << 3;
+        /// LE Ping.  Not valid from controller to controller.
+        const PING = 1 << 4;
+        /// LE Data Packet Length Extension.  Valid from controller to controller.
+        const DATA_PACKET_LENGTH_EXTENSION = 1 << 5;
+        /// LL Privacy.  Not valid from controller to controller.
+        const LL_PRIVACY = 1 << 6;
+        /// Extended Scanner Filter Policies.  Not valid from controller to controller.
+        const EXTENDED_SCANNER_FILTER_POLICIES = 1 << 7;
+        /// LE 2M PHY.  Valid from controller to controller.
+        const PHY_2M = 1 << 8;
+        /// Stable Modulation Index - Transmitter.  Valid from controller to controller.
+        const STABLE_MODULATION_INDEX_TX = 1 << 9;
+        /// Stable Modulation Index - Receiver.  Valid from controller to controller.
+        const STABLE_MODULATION_INDEX_RX = 1 << 10;
+        /// LE Coded PHY.  Valid from controller to controller.
+        const CODED_PHY = 1 << 11;
+        /// LE Extended Advertising.  Not valid from controller to controller.
+        const EXTENDED_ADVERTISING = 1 << 12;
+        /// LE Periodic Advertising.  Not valid from controller to controller.
+        const PERIODIC_ADVERTISING = 1 << 13;
+        /// Channel Selection Algorithm #2.  Valid from controller to controller.
+        const CHANNEL_SELECTION_ALGORITHM_2 = 1 << 14;
+        /// LE Power Class 1.  Valid from controller to controller.
+        const POWER_CLASS_1 = 1 << 15;
+        /// Minimum Number of Used Channels Procedure
+        const MINIMUM_NUMBER_OF_USED_CHANNELS_PROCEDURE = 1 << 16;
+    }
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// Possible LE features for the
+    /// [LE Read Local Supported Features](::host::Hci::le_read_local_supported_features) command.
+    /// See the Bluetooth specification, Vol 6, Part B, Section 4.6.  See Table 4.3 (v4.1 of the spec),
+    /// Table 4.4 (v4.2 and v5.0).
+    #[derive(Default)]
+    pub struct LeFeatures : u64 {
+        /// LE Encryption.  Valid from controller to controller.
+        const ENCRYPTION = 1 << 0;
+        /// Connection Parameters Request Procedure.  Valid from controller to controller.
+        const CONNECTION_PARAMETERS_REQUEST_PROCEDURE = 1 << 1;
+        /// Extended Reject Indication.  Valid from controller to controller.
+        const EXTENDED_REJECT_INDICATION = 1 << 2;
+        /// Peripheral-initiated Features Exchange.  Valid from controller to controller.
+        const PERIPHERALINITIATED_FEATURES_EXCHANGE = 1 << 3;
+        /// LE Ping.  Not valid from controller to controller.
+        const PING = 1 << 4;
+        /// LE Data Packet Length Extension.  Valid from controller to controller.
+        const DATA_PACKET_LENGTH_EXTENSION = 1 << 5;
+        /// LL Privacy.  Not valid from controller to controller.
+        const LL_PRIVACY = 1 << 6;
+        /// Extended Scanner Filter Policies.  Not valid from controller to controller.
+        const EXTENDED_SCANNER_FILTER_POLICIES = 1 << 7;
+        /// LE 2M PHY.  Valid from controller to controller.
+        const PHY_2M = 1 << 8;
+        /// Stable Modulation Index - Transmitter.  Valid from controller to controller.
+        const STABLE_MODULATION_INDEX_TX = 1 << 9;
+        /// Stable Modulation Index - Receiver.  Valid from controller to controller.
+        const STABLE_MODULATION_INDEX_RX = 1 << 10;
+        /// LE Coded PHY.  Valid from controller to controller.
+        const CODED_PHY = 1 << 11;
+        /// LE Extended Advertising.  Not valid from controller to controller.
+        const EXTENDED_ADVERTISING = 1 << 12;
+        /// LE Periodic Advertising.  Not valid from controller to controller.
+        const PERIODIC_ADVERTISING = 1 << 13;
+        /// Channel Selection Algorithm #2.  Valid from controller to controller.
+        const CHANNEL_SELECTION_ALGORITHM_2 = 1 << 14;
+        /// LE Power Class 1.  Valid from controller to controller.
+        const POWER_CLASS_1 = 1 << 15;
+        /// Minimum Number of Used Channels Procedure
+        const MINIMUM_NUMBER_OF_USED_CHANNELS_PROCEDURE = 1 << 16;
+    }
+}
+
+fn to_le_local_supported_features(
+    bytes: &[u8],
+) -> Result<LeSupportedFeatures, crate::event::Error> {
+    require_len!(bytes, 9);
+    Ok(LeSupportedFeatures {
+        status: to_status(bytes)?,
+        supported_features: LeFeatures::from_bits_truncate(LittleEndian::read_u64(&bytes[1..])),
+    })
+}
+
+fn write_le_local_supported_features(params: &LeSupportedFeatures, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u64(&mut bytes[1..], params.supported_features.bits());
+    9
+}
+
+/// Values returned by the
+/// [LE Read Advertising Channel TX Power](crate::host::HostHci::le_read_advertising_channel_tx_power)
+/// command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeAdvertisingChannelTxPower {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+    /// The transmit power of the advertising channel.
+    ///   - Range: -20 ≤ N ≤ 10 (this is not enforced in this implementation)
+    ///   - Units: dBm
+    ///   - Accuracy: ±4 dB
+    pub power: i8,
+}
+
+impl LeAdvertisingChannelTxPower {
+    /// Returns the transmit power of the advertising channel.
+    pub fn power(&self) -> Dbm {
+        Dbm(self.power)
+    }
+}
+
+fn to_le_advertising_channel_tx_power(
+    bytes: &[u8],
+) -> Result<LeAdvertisingChannelTxPower, crate::event::Error> {
+    require_len!(bytes, 2);
+    Ok(LeAdvertisingChannelTxPower {
+        status: to_status(bytes)?,
+        power: u8::cast_signed(bytes[1]),
+    })
+}
+
+fn write_le_advertising_channel_tx_power(
+    params: &LeAdvertisingChannelTxPower,
+    bytes: &mut [u8],
+) -> usize {
+    write_status(&params.status, bytes);
+    bytes[1] = i8::cast_unsigned(params.power);
+    2
+}
+
+fn to_le_set_advertise_enable(status: Status) -> ReturnParameters {
+    ReturnParameters::LeSetAdvertisingEnable(status)
+}
+
+/// Parameters returned by the [LE Read Channel Map](crate::host::HostHci::le_read_channel_map) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ChannelMapParameters {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Connection handle whose channel map is returned.
+    pub conn_handle: ConnectionHandle,
+
+    /// Channels that may be used for this connection.
+    pub channel_map: crate::ChannelClassification,
+}
+
+fn to_le_channel_map_parameters(bytes: &[u8]) -> Result<ChannelMapParameters, crate::event::Error> {
+    require_len!(bytes, 8);
+
+    let mut channel_bits = [0; 5];
+    channel_bits.copy_from_slice(&bytes[3..8]);
+    let channel_bits = channel_bits;
+    Ok(ChannelMapParameters {
+        status: to_status(&bytes[0..])?,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[1..])),
+        channel_map: crate::ChannelClassification::from_bits(&bytes[3..])
+            .ok_or(crate::event::Error::InvalidChannelMap(channel_bits))?,
+    })
+}
+
+fn write_le_channel_map_parameters(params: &ChannelMapParameters, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+    params.channel_map.copy_into_slice(&mut bytes[3..8]);
+    8
+}
+
+/// Parameters returned by the [LE Encrypt](crate::host::HostHci::le_encrypt) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EncryptedReturnParameters {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Encrypted data block.
+    ///
+    /// The most significant octet (last) of the block corresponds to `out[0]` using the notation
+    /// specified in FIPS 197.
+    pub encrypted_data: EncryptedBlock,
+}
+
+/// Newtype for a 128-bit encrypted block of data.
+///
+/// See [`EncryptedReturnParameters`].
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EncryptedBlock(pub [u8; 16]);
+
+impl Debug for EncryptedBlock {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        writeln!(f, "AES-128 Encrypted Data ({:X?})", &self.0)
+    }
+}
+
+fn to_le_encrypted_data(bytes: &[u8]) -> Result<EncryptedReturnParameters, crate::event::Error> {
+    require_len!(bytes, 17);
+
+    let mut block = [0; 16];
+    block.copy_from_slice(&bytes[1..]);
+    Ok(EncryptedReturnParameters {
+        status: to_status(bytes)?,
+        encrypted_data: EncryptedBlock(block),
+    })
+}
+
+fn write_le_encrypted_data(params: &EncryptedReturnParameters, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    bytes[1..17].copy_from_slice(&params.encrypted_data.0);
+    17
+}
+
+/// Return parameters for the [LE Rand](crate::host::HostHci::le_rand) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeRandom {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Controller-generated random number.
+    pub random_number: u64,
+}
+
+fn to_random_number(bytes: &[u8]) -> Result<LeRandom, crate::event::Error> {
+    require_len!(bytes, 9);
+
+    Ok(LeRandom {
+        status: to_status(bytes)?,
+        random_number: LittleEndian::read_u64(&bytes[1..]),
+    })
+}
+
+fn write_random_number(params: &LeRandom, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u64(&mut bytes[1..], params.random_number);
+    9
+}
+
+/// Parameters returned by the
+/// [LE LTK Request Reply](crate::host::HostHci::le_long_term_key_request_reply) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeLongTermRequestReply {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Connection handle that the request came from
+    pub conn_handle: ConnectionHandle,
+}
+
+fn to_le_ltk_request_reply(bytes: &[u8]) -> Result<LeLongTermRequestReply, crate::event::Error> {
+    require_len!(bytes, 3);
+
+    Ok(LeLongTermRequestReply {
+        status: to_status(bytes)?,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[1..])),
+    })
+}
+
+fn write_le_ltk_request_reply(params: &LeLongTermRequestReply, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+    3
+}
+
+/// Parameters returned by the
+/// [LE Read Supported States](crate::host::HostHci::le_read_supported_states) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeReadSupportedStates {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// States or state combinations supported by the Controller. Multiple state and state
+    /// combinations may be supported.
+    pub supported_states: LeStates,
+}
+
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// Possible LE states or state combinations for the
+    /// [LE Read Supported States](crate::host::HostHci::le_read_supported_states) command.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct LeStates : u64 {
+        /// Non-connectable advertising state alone.
+        const NON_CONNECTABLE_ADVERTISING = 1 << 0;
+        /// Scannable advertising state alone
+        const SCANNABLE_ADVERTISING = 1 << 1;
+        /// Connectable advertising state alone
+        const CONNECTABLE_ADVERTISING = 1 << 2;
+        /// Directed advertising (high duty cycle) state alone
+        const DIRECTED_ADVERTISING_HIGH_DUTY_CYCLE = 1 << 3;
+        /// Passive scanning state alone
+        const PASSIVE_SCANNING = 1 << 4;
+        /// Active scanning state alone
+        const ACTIVE_SCANNING = 1 << 5;
+        /// Initianing state alone
+        const INITIATING = 1 << 6;
+        /// Peripheral (slave) connection state alone
+        const PERIPHERAL_CONNECTION = 1 << 7;
+        /// Non-connectable advertising and passive scan states.
+        const NONCONN_AD_AND_PASS_SCAN = 1 << 8;
+        /// Scannable advertising and passive scan states
+        const SCAN_AD_AND_PASS_SCAN = 1 << 9;
+        /// Connectable advertising and passive scan states
+        const CONN_AD_AND_PASS_SCAN = 1 << 10;
+        /// Directed advertising (high duty cycle) and passive scan states
+        const DIR_AD_HDC_AND_PASS_SCAN = 1 << 11;
+        /// Non-connectable advertising and active scan states.
+        const NONCONN_AD_AND_ACT_SCAN = 1 << 12;
+        /// Scannable advertising and active scan states
+        const SCAN_AD_AND_ACT_SCAN = 1 << 13;
+        /// Connectable advertising and active scan states
+        const CONN_AD_AND_ACT_SCAN = 1 << 14;
+        /// Directed advertising (high duty cycle) and active scan states
+        const DIR_AD_HDC_AND_ACT_SCAN = 1 << 15;
+        /// Non-connectable advertising and initiating states.
+        const NONCONN_AD_AND_INITIATING = 1 << 16;
+        /// Scannable advertising and initiating states
+        const SCAN_AD_AND_INITIATING = 1 << 17;
+        /// Non-connectable advertising and central (master) connection states.
+        const NONCONN_AD_AND_CENTRAL_CONN = 1 << 18;
+        /// Scannable advertising and central (master) connection states
+        const SCAN_AD_AND_CENTRAL_CONN = 1 << 19;
+        /// Non-connectable advertising and peripheral (slave) connection states.
+        const NONCONN_AD_AND_PERIPH_CONN = 1 << 20;
+        /// Scannable advertising and peripheral (slave) connection states
+        const SCAN_AD_AND_PERIPH_CONN = 1 << 21;
+        /// Passive scan and initiating states
+        const PASS_SCAN_AND_INITIATING = 1 << 22;
+        /// Active scan and initiating states
+        const ACT_SCAN_AND_INITIATING = 1 << 23;
+        /// Passive scan and central (master) connection states
+        const PASS_SCAN_AND_CENTRAL_CONN = 1 << 24;
+        /// Active scan and central (master) connection states
+        const ACT_SCAN_AND_CENTRAL_CONN = 1 << 25;
+        /// Passive scan and peripheral (slave) connection states
+        const PASS_SCAN_AND_PERIPH_CONN = 1 << 26;
+        /// Active scan and peripheral (slave) connection states
+        const ACT_SCAN_AND_PERIPH_CONN = 1 << 27;
+        /// Initiating and central (master) connection states
+        const INITIATING_AND_CENTRAL_CONN = 1 << 28;
+        /// Directed advertising (low duty cycle) state alone
+        const DIRECTED_ADVERTISING_LOW_DUTY_CYCLE = 1 << 29;
+        /// Directed advertising (low duty cycle) and passive scan states
+        const DIR_AD_LDC_AND_PASS_SCAN = 1 << 30;
+        /// Directed advertising (low duty cycle) and active scan states
+        const DIR_AD_LDC_AND_ACT_SCAN = 1 << 31;
+        /// Connectable advertising and initiating states
+        const CONN_AD_AND_INITIATING = 1 << 32;
+        /// Directed advertising (high duty cycle) and initiating states
+        const DIR_AD_HDC_AND_INITIATING = 1 << 33;
+        /// Directed advertising (low duty cycle) and initiating states
+        const DIR_AD_LDC_AND_INITIATING = 1 << 34;
+        /// Connectable advertising and central (master) connection states
+        const CONN_AD_AND_CENTRAL_CONN = 1 << 35;
+        /// Directed advertising (high duty cycle) and central (master) states
+        const DIR_AD_HDC_AND_CENTRAL_CONN = 1 << 36;
+        /// Directed advertising (low duty cycle) and central (master) states
+        const DIR_AD_LDC_AND_CENTRAL_CONN = 1 << 37;
+        /// Connectable advertising and peripheral (slave) connection states
+        const CONN_AD_AND_PERIPH_CONN = 1 << 38;
+        /// Directed advertising (high duty cycle) and peripheral (slave) states
+        const DIR_AD_HDC_AND_PERIPH_CONN = 1 << 39;
+        /// Directed advertising (low duty cycle) and peripheral (slave) states
+        const DIR_AD_LDC_AND_PERIPH_CONN = 1 << 40;
+        /// Initiating and peripheral (slave) connection states
+        const INITIATING_AND_PERIPH_CONN = 1 << 41;
+    }
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// Possible LE states or state combinations for the
+    /// [LE Read Supported States](crate::host::HostHci::le_read_supported_states) command.
+    #[derive(Default)]
+    pub struct LeStates : u64 {
+        /// Non-connectable advertising state alone.
+        const NON_CONNECTABLE_ADVERTISING = 1 << 0;
+        /// Scannable advertising state alone
+        const SCANNABLE_ADVERTISING = 1 << 1;
+        /// Connectable advertising state alone
+        const CONNECTABLE_ADVERTISING = 1 << 2;
+        /// Directed advertising (high duty cycle) state alone
+        const DIRECTED_ADVERTISING_HIGH_DUTY_CYCLE = 1 << 3;
+        /// Passive scanning state alone
+        const PASSIVE_SCANNING = 1 << 4;
+        /// Active scanning state alone
+        const ACTIVE_SCANNING = 1 << 5;
+        /// Initianing state alone
+        const INITIATING = 1 << 6;
+        /// Peripheral (slave) connection state alone
+        const PERIPHERAL_CONNECTION = 1 << 7;
+        /// Non-connectable advertising and passive scan states.
+        const NONCONN_AD_AND_PASS_SCAN = 1 << 8;
+        /// Scannable advertising and passive scan states
+        const SCAN_AD_AND_PASS_SCAN = 1 << 9;
+        /// Connectable advertising and passive scan states
+        const CONN_AD_AND_PASS_SCAN = 1 << 10;
+        /// Directed advertising (high duty cycle) and passive scan states
+        const DIR_AD_HDC_AND_PASS_SCAN = 1 << 11;
+        /// Non-connectable advertising and active scan states.
+        const NONCONN_AD_AND_ACT_SCAN = 1 << 12;
+        /// Scannable advertising and active scan states
+        const SCAN_AD_AND_ACT_SCAN = 1 << 13;
+        /// Connectable advertising and active scan states
+        const CONN_AD_AND_ACT_SCAN = 1 << 14;
+        /// Directed advertising (high duty cycle) and active scan states
+        const DIR_AD_HDC_AND_ACT_SCAN = 1 << 15;
+        /// Non-connectable advertising and initiating states.
+        const NONCONN_AD_AND_INITIATING = 1 << 16;
+        /// Scannable advertising and initiating states
+        const SCAN_AD_AND_INITIATING = 1 << 17;
+        /// Non-connectable advertising and central (master) connection states.
+        const NONCONN_AD_AND_CENTRAL_CONN = 1 << 18;
+        /// Scannable advertising and central (master) connection states
+        const SCAN_AD_AND_CENTRAL_CONN = 1 << 19;
+        /// Non-connectable advertising and peripheral (slave) connection states.
+        const NONCONN_AD_AND_PERIPH_CONN = 1 << 20;
+        /// Scannable advertising and peripheral (slave) connection states
+        const SCAN_AD_AND_PERIPH_CONN = 1 << 21;
+        /// Passive scan and initiating states
+        const PASS_SCAN_AND_INITIATING = 1 << 22;
+        /// Active scan and initiating states
+        const ACT_SCAN_AND_INITIATING = 1 << 23;
+        /// Passive scan and central (master) connection states
+        const PASS_SCAN_AND_CENTRAL_CONN = 1 << 24;
+        /// Active scan and central (master) connection states
+        const ACT_SCAN_AND_CENTRAL_CONN = 1 << 25;
+        /// Passive scan and peripheral (slave) connection states
+        const PASS_SCAN_AND_PERIPH_CONN = 1 << 26;
+        /// Active scan and peripheral (slave) connection states
+        const ACT_SCAN_AND_PERIPH_CONN = 1 << 27;
+        /// Initiating and central (master) connection states
+        const INITIATING_AND_CENTRAL_CONN = 1 << 28;
+        /// Directed advertising (low duty cycle) state alone
+        const DIRECTED_ADVERTISING_LOW_DUTY_CYCLE = 1 << 29;
+        /// Directed advertising (low duty cycle) and passive scan states
+        const DIR_AD_LDC_AND_PASS_SCAN = 1 << 30;
+        /// Directed advertising (low duty cycle) and active scan states
+        const DIR_AD_LDC_AND_ACT_SCAN = 1 << 31;
+        /// Connectable advertising and initiating states
+        const CONN_AD_AND_INITIATING = 1 << 32;
+        /// Directed advertising (high duty cycle) and initiating states
+        const DIR_AD_HDC_AND_INITIATING = 1 << 33;
+        /// Directed advertising (low duty cycle) and initiating states
+        const DIR_AD_LDC_AND_INITIATING = 1 << 34;
+        /// Connectable advertising and central (master) connection states
+        const CONN_AD_AND_CENTRAL_CONN = 1 << 35;
+        /// Directed advertising (high duty cycle) and central (master) states
+        const DIR_AD_HDC_AND_CENTRAL_CONN = 1 << 36;
+        /// Directed advertising (low duty cycle) and central (master) states
+        const DIR_AD_LDC_AND_CENTRAL_CONN = 1 << 37;
+        /// Connectable advertising and peripheral (slave) connection states
+        const CONN_AD_AND_PERIPH_CONN = 1 << 38;
+        /// Directed advertising (high duty cycle) and peripheral (slave) states
+        const DIR_AD_HDC_AND_PERIPH_CONN = 1 << 39;
+        /// Directed advertising (low duty cycle) and peripheral (slave) states
+        const DIR_AD_LDC_AND_PERIPH_CONN = 1 << 40;
+        /// Initiating and peripheral (slave) connection states
+        const INITIATING_AND_PERIPH_CONN = 1 << 41;
     }
 }
 
-impl<'a> TryFrom<&'a [u8]> for CommandFlags {
-    type Error = crate::event::Error;
-    fn try_from(value: &[u8]) -> Result<CommandFlags, Self::Error> {
-        require_len!(value, COMMAND_FLAGS_SIZE);
+fn to_le_read_states(bytes: &[u8]) -> Result<LeReadSupportedStates, crate::event::Error> {
+    require_len!(bytes, 9);
 
-        CommandFlags::from_bits(value).ok_or(crate::event::Error::BadCommandFlag)
-    }
+    let bitfield = LittleEndian::read_u64(&bytes[1..]);
+    Ok(LeReadSupportedStates {
+        status: to_status(bytes)?,
+        supported_states: LeStates::from_bits(bitfield)
+            .ok_or(crate::event::Error::InvalidLeStates(bitfield))?,
+    })
 }
 
-fn to_supported_commands(bytes: &[u8]) -> Result<LocalSupportedCommands, crate::event::Error> {
-    require_len!(bytes, 1 + COMMAND_FLAGS_SIZE);
-    Ok(LocalSupportedCommands {
-        status: bytes[0].try_into().map_err(super::rewrap_bad_status)?,
-        supported_commands: bytes[1..=COMMAND_FLAGS_SIZE]
-            .try_into()
-            .map_err(|e| match e {
-                crate::event::Error::BadLength(actual, expected) => {
-                    crate::event::Error::BadLength(actual, expected)
-                }
-                crate::event::Error::BadCommandFlag => crate::event::Error::BadCommandFlag,
-                _ => unreachable!(),
-            })?,
+fn write_le_read_states(params: &LeReadSupportedStates, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u64(&mut bytes[1..], params.supported_states.bits());
+    9
+}
+
+/// Parameters returned by the [LE Test End](crate::host::HostHci::le_test_end) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeTestEnd {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// The number of packets received during the test.  For transmitter tests, this value shall be
+    /// 0.
+    pub number_of_packets: usize,
+}
+
+fn to_le_test_end(bytes: &[u8]) -> Result<LeTestEnd, crate::event::Error> {
+    require_len!(bytes, 3);
+
+    Ok(LeTestEnd {
+        status: to_status(bytes)?,
+        number_of_packets: LittleEndian::read_u16(&bytes[1..]) as usize,
+    })
+}
+
+fn write_le_test_end(params: &LeTestEnd, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u16(&mut bytes[1..], params.number_of_packets as u16);
+    3
+}
+
+/// Parameters returned by the [LE Set Data Length](crate::host::HostHci::le_set_data_length)
+/// command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeSetDataLength {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Connection handle whose data length was set.
+    pub conn_handle: ConnectionHandle,
+}
+
+fn to_le_set_data_length(bytes: &[u8]) -> Result<LeSetDataLength, crate::event::Error> {
+    require_len!(bytes, 3);
+
+    Ok(LeSetDataLength {
+        status: to_status(bytes)?,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[1..])),
+    })
+}
+
+fn write_le_set_data_length(params: &LeSetDataLength, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+    3
+}
+
+/// Parameters returned by the
+/// [LE Read Suggested Default Data Length](crate::host::HostHci::le_read_suggested_default_data_length)
+/// command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeSuggestedDefaultDataLength {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// The Host's suggested maximum number of payload octets in the LL Data PDUs the Controller
+    /// sends on new connections.
+    pub suggested_max_tx_octets: u16,
+
+    /// The Host's suggested maximum transmission time, in microseconds, of the packets containing
+    /// LL Data PDUs the Controller sends on new connections.
+    pub suggested_max_tx_time: u16,
+}
+
+fn to_le_suggested_default_data_length(
+    bytes: &[u8],
+) -> Result<LeSuggestedDefaultDataLength, crate::event::Error> {
+    require_len!(bytes, 5);
+
+    Ok(LeSuggestedDefaultDataLength {
+        status: to_status(bytes)?,
+        suggested_max_tx_octets: LittleEndian::read_u16(&bytes[1..]),
+        suggested_max_tx_time: LittleEndian::read_u16(&bytes[3..]),
+    })
+}
+
+fn write_le_suggested_default_data_length(
+    params: &LeSuggestedDefaultDataLength,
+    bytes: &mut [u8],
+) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u16(&mut bytes[1..], params.suggested_max_tx_octets);
+    LittleEndian::write_u16(&mut bytes[3..], params.suggested_max_tx_time);
+    5
+}
+
+/// Parameters returned by the
+/// [LE Read Maximum Data Length](crate::host::HostHci::le_read_maximum_data_length) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeMaximumDataLength {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Maximum number of payload octets that the Controller supports for sending an LL Data PDU.
+    pub supported_max_tx_octets: u16,
+
+    /// Maximum time, in microseconds, that the Controller supports for sending a packet containing
+    /// an LL Data PDU.
+    pub supported_max_tx_time: u16,
+
+    /// Maximum number of payload octets that the Controller supports for receiving an LL Data PDU.
+    pub supported_max_rx_octets: u16,
+
+    /// Maximum time, in microseconds, that the Controller supports for receiving a packet
+    /// containing an LL Data PDU.
+    pub supported_max_rx_time: u16,
+}
+
+fn to_le_maximum_data_length(bytes: &[u8]) -> Result<LeMaximumDataLength, crate::event::Error> {
+    require_len!(bytes, 9);
+
+    Ok(LeMaximumDataLength {
+        status: to_status(bytes)?,
+        supported_max_tx_octets: LittleEndian::read_u16(&bytes[1..]),
+        supported_max_tx_time: LittleEndian::read_u16(&bytes[3..]),
+        supported_max_rx_octets: LittleEndian::read_u16(&bytes[5..]),
+        supported_max_rx_time: LittleEndian::read_u16(&bytes[7..]),
+    })
+}
+
+fn write_le_maximum_data_length(params: &LeMaximumDataLength, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u16(&mut bytes[1..], params.supported_max_tx_octets);
+    LittleEndian::write_u16(&mut bytes[3..], params.supported_max_tx_time);
+    LittleEndian::write_u16(&mut bytes[5..], params.supported_max_rx_octets);
+    LittleEndian::write_u16(&mut bytes[7..], params.supported_max_rx_time);
+    9
+}
+
+/// Parameters returned by the [LE Read PHY](crate::host::HostHci::le_read_phy) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeReadPhy {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// Connection handle whose PHYs were read.
+    pub conn_handle: ConnectionHandle,
+
+    /// PHY used for transmission on the connection.
+    pub tx_phy: crate::event::Phy,
+
+    /// PHY used for reception on the connection.
+    pub rx_phy: crate::event::Phy,
+}
+
+fn to_le_read_phy(bytes: &[u8]) -> Result<LeReadPhy, crate::event::Error> {
+    require_len!(bytes, 5);
+
+    Ok(LeReadPhy {
+        status: to_status(bytes)?,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[1..])),
+        tx_phy: crate::event::Phy::try_from(bytes[3])?,
+        rx_phy: crate::event::Phy::try_from(bytes[4])?,
     })
 }
 
-/// Values returned by the
-/// [Read Local Supported Features](crate::host::HostHci::read_local_supported_features) command.
+fn write_le_read_phy(params: &LeReadPhy, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+    bytes[3] = params.tx_phy.into();
+    bytes[4] = params.rx_phy.into();
+    5
+}
+
+/// Parameters returned by the
+/// [LE Read Peer Resolvable Address](crate::host::HostHci::le_read_peer_resolvable_address) and
+/// [LE Read Local Resolvable Address](crate::host::HostHci::le_read_local_resolvable_address)
+/// commands.
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct LocalSupportedFeatures {
+pub struct LeReadResolvableAddress {
     /// Did the command fail, and if so, how?
     pub status: Status,
 
-    /// Flags for supported features.
-    pub supported_features: LmpFeatures,
-}
-
-#[cfg(not(feature = "defmt"))]
-bitflags::bitflags! {
-    /// See the Bluetooth Specification, v4.1 or later, Vol 2, Part C, Section 3.3 (Table 3.2).
-    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
-    pub struct LmpFeatures : u64 {
-        /// 3-slot packets
-        const THREE_SLOT_PACKETS = 1 << 0;
-        /// 5-slot packets
-        const FIVE_SLOT_PACKETS = 1 << 1;
-        /// Encryption
-        const ENCRYPTION = 1 << 2;
-        /// Slot offset
-        const SLOT_OFFSET = 1 << 3;
-        /// Timing accuracy
-        const TIMING_ACCURACY = 1 << 4;
-        /// Role switch
-        const ROLE_SWITCH = 1 << 5;
-        /// Hold mode
-        const HOLD_MODE = 1 << 6;
-        /// Sniff mode
-        const SNIFF_MODE = 1 << 7;
-        /// Power control requests
-        const POWER_CONTROL_REQUESTS = 1 << 9;
-        /// Channel quality driven data rate (CQDDR)
-        const CHANNEL_QUALITY_DRIVEN_DATA_RATE_CQDDR = 1 << 10;
-        /// SCO link
-        const SCO_LINK = 1 << 11;
-        /// HV2 packets
-        const HV2_PACKETS = 1 << 12;
-        /// HV3 packets
-        const HV3_PACKETS = 1 << 13;
-        /// μ-law log synchronous data
-        const MU_LAW_LOG_SYNCHRONOUS_DATA = 1 << 14;
-        /// A-law log synchronous data
-        const A_LAW_LOG_SYNCHRONOUS_DATA = 1 << 15;
-        /// CVSD synchronous data
-        const CVSD_SYNCHRONOUS_DATA = 1 << 16;
-        /// Paging parameter negotiation
-        const PAGING_PARAMETER_NEGOTIATION = 1 << 17;
-        /// Power control
-        const POWER_CONTROL = 1 << 18;
-        /// Transparent synchronous data
-        const TRANSPARENT_SYNCHRONOUS_DATA = 1 << 19;
-        /// Flow control lag (least significant bit)
-        const FLOW_CONTROL_LAG_LSB = 1 << 20;
-        /// Flow control lag (middle bit)
-        const FLOW_CONTROL_LAG_MID = 1 << 21;
-        /// Flow control lag (most significant bit)
-        const FLOW_CONTROL_LAG_MSB = 1 << 22;
-        /// Broadcast Encryption
-        const BROADCAST_ENCRYPTION = 1 << 23;
-        /// Enhanced Data Rate ACL 2 Mb/s mode
-        const ENHANCED_DATA_RATE_ACL_2_MB_PER_S_MODE = 1 << 25;
-        /// Enhanced Data Rate ACL 3 Mb/s mode
-        const ENHANCED_DATA_RATE_ACL_3_MB_PER_S_MODE = 1 << 26;
-        /// Enhanced inquiry scan
-        const ENHANCED_INQUIRY_SCAN = 1 << 27;
-        /// Interlaced inquiry scan
-        const INTERLACED_INQUIRY_SCAN = 1 << 28;
-        /// Interlaced page scan
-        const INTERLACED_PAGE_SCAN = 1 << 29;
-        /// RSSI with inquiry results
-        const RSSI_WITH_INQUIRY_RESULTS = 1 << 30;
-        /// Extended SCO link (EV3 packets)
-        const EXTENDED_SCO_LINK_EV3_PACKETS = 1 << 31;
-        /// EV4 packets
-        const EV4_PACKETS = 1 << 32;
-        /// EV5 packets
-        const EV5_PACKETS = 1 << 33;
-        /// AFH capable peripheral
-        const AFH_CAPABLE_PERIPHERAL = 1 << 35;
-        /// AFH classification peripheral
-        const AFH_CLASSIFICATION_PERIPHERAL = 1 << 36;
-        /// BR/EDR Not Supported
-        const BR_EDR_NOT_SUPPORTED = 1 << 37;
-        /// LE Supported (Controller)
-        const LE_SUPPORTED_BY_CONTROLLER = 1 << 38;
-        /// 3-slot Enhanced Data Rate ACL packets
-        const THREE_SLOT_ENHANCED_DATA_RATE_ACL_PACKETS = 1 << 39;
-        /// 5-slot Enhanced Data Rate ACL packets
-        const FIVE_SLOT_ENHANCED_DATA_RATE_ACL_PACKETS = 1 << 40;
-        /// Sniff subrating
-        const SNIFF_SUBRATING = 1 << 41;
-        /// Pause encryption
-        const PAUSE_ENCRYPTION = 1 << 42;
-        /// AFH capable central device
-        const AFH_CAPABLE_CENTRAL_DEVICE = 1 << 43;
-        /// AFH classification central device
-        const AFH_CLASSIFICATION_CENTRAL_DEVICE = 1 << 44;
-        /// Enhanced Data Rate eSCO 2 Mb/s mode
-        const ENHANCED_DATA_RATE_ESCO_2_MB_PER_S_MODE = 1 << 45;
-        /// Enhanced Data Rate eSCO 3 Mb/s mode
-        const ENHANCED_DATA_RATE_ESCO_3_MB_PER_S_MODE = 1 << 46;
-        /// 3-slot Enhanced Data Rate eSCO packets
-        const THREE_SLOT_ENHANCED_DATA_RATE_ESCO_PACKETS = 1 << 47;
-        /// Extended Inquiry Response
-        const EXTENDED_INQUIRY_RESPONSE = 1 << 48;
-        /// Simultaneous LE and BR/EDR to Same Device Capable (Controller)
-        const SIMULTANEOUS_LE_AND_BR_EDR_TO_SAME_DEVICE_CAPABLE = 1 << 49;
-        /// Secure Simple Pairing
-        const SECURE_SIMPLE_PAIRING = 1 << 51;
-        /// Encapsulated PDU
-        const ENCAPSULATED_PDU = 1 << 52;
-        /// Erroneous Data Reporting
-        const ERRONEOUS_DATA_REPORTING = 1 << 53;
-        /// Non-flushable Packet Boundary Flag
-        const NON_FLUSHABLE_PACKET_BOUNDARY_FLAG = 1 << 54;
-        /// Link Supervision Timeout Changed Event
-        const LINK_SUPERVISION_TIMEOUT_CHANGED_EVENT = 1 << 56;
-        /// Inquiry TX Power Level
-        const INQUIRY_TX_POWER_LEVEL = 1 << 57;
-        /// Enhanced Power Control
-        const ENHANCED_POWER_CONTROL = 1 << 58;
-        /// Extended features
-        const EXTENDED_FEATURES = 1 << 63;
-    }
+    /// Resolvable Private Address currently used by the peer or the local device.
+    pub address: crate::BdAddr,
 }
 
-#[cfg(feature = "defmt")]
-defmt::bitflags! {
-    /// See the Bluetooth Specification, v4.1 or later, Vol 2, Part C, Section 3.3 (Table 3.2).
-    #[derive(Default)]
-    pub struct LmpFeatures : u64 {
-        /// 3-slot packets
-        const THREE_SLOT_PACKETS = 1 << 0;
-        /// 5-slot packets
-        const FIVE_SLOT_PACKETS = 1 << 1;
-        /// Encryption
-        const ENCRYPTION = 1 << 2;
-        /// Slot offset
-        const SLOT_OFFSET = 1 << 3;
-        /// Timing accuracy
-        const TIMING_ACCURACY = 1 << 4;
-        /// Role switch
-        const ROLE_SWITCH = 1 << 5;
-        /// Hold mode
-        const HOLD_MODE = 1 << 6;
-        /// Sniff mode
-        const SNIFF_MODE = 1 << 7;
-        /// Power control requests
-        const POWER_CONTROL_REQUESTS = 1 << 9;
-        /// Channel quality driven data rate (CQDDR)
-        const CHANNEL_QUALITY_DRIVEN_DATA_RATE_CQDDR = 1 << 10;
-        /// SCO link
-        const SCO_LINK = 1 << 11;
-        /// HV2 packets
-        const HV2_PACKETS = 1 << 12;
-        /// HV3 packets
-        const HV3_PACKETS = 1 << 13;
-        /// μ-law log synchronous data
-        const MU_LAW_LOG_SYNCHRONOUS_DATA = 1 << 14;
-        /// A-law log synchronous data
-        const A_LAW_LOG_SYNCHRONOUS_DATA = 1 << 15;
-        /// CVSD synchronous data
-        const CVSD_SYNCHRONOUS_DATA = 1 << 16;
-        /// Paging parameter negotiation
-        const PAGING_PARAMETER_NEGOTIATION = 1 << 17;
-        /// Power control
-        const POWER_CONTROL = 1 << 18;
-        /// Transparent synchronous data
-        const TRANSPARENT_SYNCHRONOUS_DATA = 1 << 19;
-        /// Flow control lag (least significant bit)
-        const FLOW_CONTROL_LAG_LSB = 1 << 20;
-        /// Flow control lag (middle bit)
-        const FLOW_CONTROL_LAG_MID = 1 << 21;
-        /// Flow control lag (most significant bit)
-        const FLOW_CONTROL_LAG_MSB = 1 << 22;
-        /// Broadcast Encryption
-        const BROADCAST_ENCRYPTION = 1 << 23;
-        /// Enhanced Data Rate ACL 2 Mb/s mode
-        const ENHANCED_DATA_RATE_ACL_2_MB_PER_S_MODE = 1 << 25;
-        /// Enhanced Data Rate ACL 3 Mb/s mode
-        const ENHANCED_DATA_RATE_ACL_3_MB_PER_S_MODE = 1 << 26;
-        /// Enhanced inquiry scan
-        const ENHANCED_INQUIRY_SCAN = 1 << 27;
-        /// Interlaced inquiry scan
-        const INTERLACED_INQUIRY_SCAN = 1 << 28;
-        /// Interlaced page scan
-        const INTERLACED_PAGE_SCAN = 1 << 29;
-        /// RSSI with inquiry results
-        const RSSI_WITH_INQUIRY_RESULTS = 1 << 30;
-        /// Extended SCO link (EV3 packets)
-        const EXTENDED_SCO_LINK_EV3_PACKETS = 1 << 31;
-        /// EV4 packets
-        const EV4_PACKETS = 1 << 32;
-        /// EV5 packets
-        const EV5_PACKETS = 1 << 33;
-        /// AFH capable peripheral
-        const AFH_CAPABLE_PERIPHERAL = 1 << 35;
-        /// AFH classification peripheral
-        const AFH_CLASSIFICATION_PERIPHERAL = 1 << 36;
-        /// BR/EDR Not Supported
-        const BR_EDR_NOT_SUPPORTED = 1 << 37;
-        /// LE Supported (Controller)
-        const LE_SUPPORTED_BY_CONTROLLER = 1 << 38;
-        /// 3-slot Enhanced Data Rate ACL packets
-        const THREE_SLOT_ENHANCED_DATA_RATE_ACL_PACKETS = 1 << 39;
-        /// 5-slot Enhanced Data Rate ACL packets
-        const FIVE_SLOT_ENHANCED_DATA_RATE_ACL_PACKETS = 1 << 40;
-        /// Sniff subrating
-        const SNIFF_SUBRATING = 1 << 41;
-        /// Pause encryption
-        const PAUSE_ENCRYPTION = 1 << 42;
-        /// AFH capable central device
-        const AFH_CAPABLE_CENTRAL_DEVICE = 1 << 43;
-        /// AFH classification central device
-        const AFH_CLASSIFICATION_CENTRAL_DEVICE = 1 << 44;
-        /// Enhanced Data Rate eSCO 2 Mb/s mode
-        const ENHANCED_DATA_RATE_ESCO_2_MB_PER_S_MODE = 1 << 45;
-        /// Enhanced Data Rate eSCO 3 Mb/s mode
-        const ENHANCED_DATA_RATE_ESCO_3_MB_PER_S_MODE = 1 << 46;
-        /// 3-slot Enhanced Data Rate eSCO packets
-        const THREE_SLOT_ENHANCED_DATA_RATE_ESCO_PACKETS = 1 << 47;
-        /// Extended Inquiry Response
-        const EXTENDED_INQUIRY_RESPONSE = 1 << 48;
-        /// Simultaneous LE and BR/EDR to Same Device Capable (Controller)
-        const SIMULTANEOUS_LE_AND_BR_EDR_TO_SAME_DEVICE_CAPABLE = 1 << 49;
-        /// Secure Simple Pairing
-        const SECURE_SIMPLE_PAIRING = 1 << 51;
-        /// Encapsulated PDU
-        const ENCAPSULATED_PDU = 1 << 52;
-        /// Erroneous Data Reporting
-        const ERRONEOUS_DATA_REPORTING = 1 << 53;
-        /// Non-flushable Packet Boundary Flag
-        const NON_FLUSHABLE_PACKET_BOUNDARY_FLAG = 1 << 54;
-        /// Link Supervision Timeout Changed Event
-        const LINK_SUPERVISION_TIMEOUT_CHANGED_EVENT = 1 << 56;
-        /// Inquiry TX Power Level
-        const INQUIRY_TX_POWER_LEVEL = 1 << 57;
-        /// Enhanced Power Control
-        const ENHANCED_POWER_CONTROL = 1 << 58;
-        /// Extended features
-        const EXTENDED_FEATURES = 1 << 63;
-    }
-}
+fn to_le_resolvable_address(bytes: &[u8]) -> Result<LeReadResolvableAddress, crate::event::Error> {
+    require_len!(bytes, 7);
 
-fn to_supported_features(bytes: &[u8]) -> Result<LocalSupportedFeatures, crate::event::Error> {
-    require_len!(bytes, 9);
-    Ok(LocalSupportedFeatures {
+    let mut address = crate::BdAddr([0; 6]);
+    address.0.copy_from_slice(&bytes[1..]);
+    Ok(LeReadResolvableAddress {
         status: to_status(bytes)?,
-        supported_features: LmpFeatures::from_bits_truncate(LittleEndian::read_u64(&bytes[1..])),
+        address,
     })
 }
 
-/// Values returned by the [Read BD ADDR](crate::host::HostHci::read_bd_addr) command.
+fn write_le_resolvable_address(params: &LeReadResolvableAddress, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    bytes[1..7].copy_from_slice(&params.address.0);
+    7
+}
+
+/// Parameters returned by the
+/// [LE Set Extended Advertising Parameters](crate::host::HostHci::le_set_extended_advertising_parameters)
+/// command.
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct ReadBdAddr {
+pub struct LeSetExtendedAdvertisingParameters {
     /// Did the command fail, and if so, how?
     pub status: Status,
 
-    /// Address of the device.
-    pub bd_addr: crate::BdAddr,
+    /// Transmit power selected by the Controller for the advertising set.
+    pub selected_tx_power: Dbm,
 }
 
-fn to_bd_addr(bytes: &[u8]) -> Result<ReadBdAddr, crate::event::Error> {
-    require_len!(bytes, 7);
-    let mut bd_addr = crate::BdAddr([0; 6]);
-    bd_addr.0.copy_from_slice(&bytes[1..]);
-    Ok(ReadBdAddr {
+fn to_le_set_extended_advertising_parameters(
+    bytes: &[u8],
+) -> Result<LeSetExtendedAdvertisingParameters, crate::event::Error> {
+    require_len!(bytes, 2);
+
+    Ok(LeSetExtendedAdvertisingParameters {
         status: to_status(bytes)?,
-        bd_addr,
+        selected_tx_power: Dbm(u8::cast_signed(bytes[1])),
     })
 }
 
-/// Values returned by the [Read RSSI](crate::host::HostHci::read_rssi) command.
+fn write_le_set_extended_advertising_parameters(
+    params: &LeSetExtendedAdvertisingParameters,
+    bytes: &mut [u8],
+) -> usize {
+    write_status(&params.status, bytes);
+    bytes[1] = i8::cast_unsigned(params.selected_tx_power.0);
+    2
+}
+
+/// Parameters returned by the
+/// [LE Periodic Advertising Sync Transfer](crate::host::HostHci::le_periodic_advertising_sync_transfer),
+/// [LE Periodic Advertising Set Info Transfer](crate::host::HostHci::le_periodic_advertising_set_info_transfer)
+/// and
+/// [LE Set Periodic Advertising Sync Transfer Parameters](crate::host::HostHci::le_set_periodic_advertising_sync_transfer_parameters)
+/// commands.
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct ReadRssi {
+pub struct LeSyncTransfer {
     /// Did the command fail, and if so, how?
     pub status: Status,
 
-    /// The Handle for the connection for which the RSSI has been read.
-    ///
-    /// The Handle is a connection handle for a BR/EDR Controller and a physical link handle for an
-    /// AMP Controller.
+    /// Connection handle given in the command.
     pub conn_handle: ConnectionHandle,
-
-    /// - BR/EDR
-    ///   - No range restriction
-    ///   - Units: dB
-    /// - AMP:
-    ///   - Range: AMP type specific
-    ///   - Units: dBm
-    /// - LE:
-    ///   - Range: -127 to 20, range not checked by this implementation. 127 indicates RSSI not
-    ///     available.
-    ///   - Units: dBm
-    pub rssi: i8,
 }
 
-fn to_read_rssi(bytes: &[u8]) -> Result<ReadRssi, crate::event::Error> {
-    require_len!(bytes, 4);
-    Ok(ReadRssi {
+fn to_le_sync_transfer(bytes: &[u8]) -> Result<LeSyncTransfer, crate::event::Error> {
+    require_len!(bytes, 3);
+
+    Ok(LeSyncTransfer {
         status: to_status(bytes)?,
         conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[1..])),
-        rssi: u8::cast_signed(bytes[3]),
     })
 }
 
-/// Values returned by the [LE Read Buffer Size](crate::host::HostHci::le_read_buffer_size) command.
+fn write_le_sync_transfer(params: &LeSyncTransfer, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+    3
+}
+
+/// Parameters returned by the [LE Read ISO TX Sync](crate::host::HostHci::le_read_iso_tx_sync)
+/// command.
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct LeReadBufferSize {
+pub struct LeReadIsoTxSync {
     /// Did the command fail, and if so, how?
     pub status: Status,
 
-    /// The size of the L2CAP PDU segments contained in ACL Data Packets, which are transferred from
-    /// the Host to the Controller to be broken up into packets by the Link Layer. Both the Host and
-    /// the Controller shall support command and event packets, where the data portion (excluding
-    /// header) contained in the packets is 255 octets in size.
-    ///
-    /// Note: Does not include the length of the HCI Data Packet header.
-    ///
-    /// If `data_packet_count` is 0, then the controller has no dedicated LE read buffer, so the
-    /// caller should use the `read_buffer_size` command.
-    pub data_packet_length: u16,
+    /// Connection handle of the CIS or BIS.
+    pub conn_handle: ConnectionHandle,
 
-    /// Contains the total number of HCI ACL Data Packets that can be stored in the data buffers of
-    /// the Controller. The Host determines how the buffers are to be divided between different
-    /// Connection Handles.
-    ///
-    /// If `data_packet_count` is 0, then the controller has no dedicated LE read buffer, so the
-    /// caller should use the `read_buffer_size` command.
-    pub data_packet_count: u8,
+    /// Packet sequence number of the SDU.
+    pub packet_sequence_number: u16,
+
+    /// CIG or BIG reference point of the transmitted SDU, in microseconds of the Controller
+    /// clock.
+    pub tx_time_stamp: u32,
+
+    /// Time between the reference point and the time the SDU was actually sent.
+    pub time_offset: core::time::Duration,
 }
 
-fn to_le_read_buffer_status(bytes: &[u8]) -> Result<LeReadBufferSize, crate::event::Error> {
-    require_len!(bytes, 4);
-    Ok(LeReadBufferSize {
+fn to_le_read_iso_tx_sync(bytes: &[u8]) -> Result<LeReadIsoTxSync, crate::event::Error> {
+    require_len!(bytes, 12);
+
+    Ok(LeReadIsoTxSync {
         status: to_status(bytes)?,
-        data_packet_length: LittleEndian::read_u16(&bytes[1..]),
-        data_packet_count: bytes[3],
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[1..])),
+        packet_sequence_number: LittleEndian::read_u16(&bytes[3..]),
+        tx_time_stamp: LittleEndian::read_u32(&bytes[5..]),
+        time_offset: core::time::Duration::from_micros(u64::from(LittleEndian::read_u24(
+            &bytes[9..],
+        ))),
     })
 }
 
-/// Values returned by the
-/// [LE Read Local Supported Features](crate::host::HostHci::le_read_local_supported_features) command.
-#[derive(Copy, Clone, Debug)]
+fn write_le_read_iso_tx_sync(params: &LeReadIsoTxSync, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+    LittleEndian::write_u16(&mut bytes[3..], params.packet_sequence_number);
+    LittleEndian::write_u32(&mut bytes[5..], params.tx_time_stamp);
+    LittleEndian::write_u24(&mut bytes[9..], params.time_offset.as_micros() as u32);
+    12
+}
+
+// Maximum number of CISes in a CIG.
+const MAX_CIS_COUNT: usize = 0x1F;
+
+/// Parameters returned by the
+/// [LE Set CIG Parameters](crate::host::HostHci::le_set_cig_parameters) and
+/// [LE Set CIG Parameters Test](crate::host::HostHci::le_set_cig_parameters_test) commands.
+#[derive(Copy, Clone)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct LeSupportedFeatures {
+pub struct LeSetCigParameters {
     /// Did the command fail, and if so, how?
     pub status: Status,
 
-    /// Supported LE features.
-    pub supported_features: LeFeatures,
+    /// Identifies the CIG.
+    pub cig_id: u8,
+
+    // Number of CIS connection handles in the event, and a buffer that can hold all of them.
+    conn_handle_count: usize,
+    conn_handle_buf: [ConnectionHandle; MAX_CIS_COUNT],
 }
 
-#[cfg(not(feature = "defmt"))]
-bitflags::bitflags! {
-    /// Possible LE features for the
-    /// [LE Read Local Supported Features](crate::host::HostHci::le_read_local_supported_features) command.
-    /// See the Bluetooth specification, Vol 6, Part B, Section 4.6.  See Table 4.3 (v4.1 of the spec),
-    /// Table 4.4 (v4.2 and v5.0).
-    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
-    pub struct LeFeatures : u64 {
-        /// LE Encryption.  Valid from controller to controller.
-        const ENCRYPTION = 1 << 0;
-        /// Connection Parameters Request Procedure.  Valid from controller to controller.
-        const CONNECTION_PARAMETERS_REQUEST_PROCEDURE = 1 << 1;
-        /// Extended Reject Indication.  Valid from controller to controller.
-        const EXTENDED_REJECT_INDICATION = 1 << 2;
-        /// Peripheral-initiated Features Exchange.  Valid from controller to controller.
-        const PERIPHERALINITIATED_FEATURES_EXCHANGE = 1 << 3;
-        /// LE Ping.  Not valid from controller to controller.
-        const PING = 1 << 4;
-        /// LE Data Packet Length Extension.  Valid from controller to controller.
-        const DATA_PACKET_LENGTH_EXTENSION = 1 << 5;
-        /// LL Privacy.  Not valid from controller to controller.
-        const LL_PRIVACY = 1 << 6;
-        /// Extended Scanner Filter Policies.  Not valid from controller to controller.
-        const EXTENDED_SCANNER_FILTER_POLICIES = 1 << 7;
-        /// LE 2M PHY.  Valid from controller to controller.
-        const PHY_2M = 1 << 8;
-        /// Stable Modulation Index - Transmitter.  Valid from controller to controller.
-        const STABLE_MODULATION_INDEX_TX = 1 << 9;
-        /// Stable Modulation Index - Receiver.  Valid from controller to controller.
-        const STABLE_MODULATION_INDEX_RX = 1 << 10;
-        /// LE Coded PHY.  Valid from controller to controller.
-        const CODED_PHY = 1 << 11;
-        /// LE Extended Advertising.  Not valid from controller to controller.
-        const EXTENDED_ADVERTISING = 1 << 12;
-        /// LE Periodic Advertising.  Not valid from controller to controller.
-        const PERIODIC_ADVERTISING = 1 << 13;
-        /// Channel Selection Algorithm #2.  Valid from controller to controller.
-        const CHANNEL_SELECTION_ALGORITHM_2 = 1 << 14;
-        /// LE Power Class 1.  Valid from controller to controller.
-        const POWER_CLASS_1 = 1 << 15;
-        /// Minimum Number of Used Channels Procedure
-        const MINIMUM_NUMBER_OF_USED_CHANNELS_PROCEDURE = 1 << 16;
+impl LeSetCigParameters {
+    /// Returns the connection handles of the CISes, in the order they were given in the command.
+    pub fn conn_handles(&self) -> &[ConnectionHandle] {
+        &self.conn_handle_buf[..self.conn_handle_count]
     }
 }
 
-#[cfg(feature = "defmt")]
-defmt::bitflags! {
-    /// Possible LE features for the
-    /// [LE Read Local Supported Features](::host::Hci::le_read_local_supported_features) command.
-    /// See the Bluetooth specification, Vol 6, Part B, Section 4.6.  See Table 4.3 (v4.1 of the spec),
-    /// Table 4.4 (v4.2 and v5.0).
-    #[derive(Default)]
-    pub struct LeFeatures : u64 {
-        /// LE Encryption.  Valid from controller to controller.
-        const ENCRYPTION = 1 << 0;
-        /// Connection Parameters Request Procedure.  Valid from controller to controller.
-        const CONNECTION_PARAMETERS_REQUEST_PROCEDURE = 1 << 1;
-        /// Extended Reject Indication.  Valid from controller to controller.
-        const EXTENDED_REJECT_INDICATION = 1 << 2;
-        /// Peripheral-initiated Features Exchange.  Valid from controller to controller.
-        const PERIPHERALINITIATED_FEATURES_EXCHANGE = 1 << 3;
-        /// LE Ping.  Not valid from controller to controller.
-        const PING = 1 << 4;
-        /// LE Data Packet Length Extension.  Valid from controller to controller.
-        const DATA_PACKET_LENGTH_EXTENSION = 1 << 5;
-        /// LL Privacy.  Not valid from controller to controller.
-        const LL_PRIVACY = 1 << 6;
-        /// Extended Scanner Filter Policies.  Not valid from controller to controller.
-        const EXTENDED_SCANNER_FILTER_POLICIES = 1 << 7;
-        /// LE 2M PHY.  Valid from controller to controller.
-        const PHY_2M = 1 << 8;
-        /// Stable Modulation Index - Transmitter.  Valid from controller to controller.
-        const STABLE_MODULATION_INDEX_TX = 1 << 9;
-        /// Stable Modulation Index - Receiver.  Valid from controller to controller.
-        const STABLE_MODULATION_INDEX_RX = 1 << 10;
-        /// LE Coded PHY.  Valid from controller to controller.
-        const CODED_PHY = 1 << 11;
-        /// LE Extended Advertising.  Not valid from controller to controller.
-        const EXTENDED_ADVERTISING = 1 << 12;
-        /// LE Periodic Advertising.  Not valid from controller to controller.
-        const PERIODIC_ADVERTISING = 1 << 13;
-        /// Channel Selection Algorithm #2.  Valid from controller to controller.
-        const CHANNEL_SELECTION_ALGORITHM_2 = 1 << 14;
-        /// LE Power Class 1.  Valid from controller to controller.
-        const POWER_CLASS_1 = 1 << 15;
-        /// Minimum Number of Used Channels Procedure
-        const MINIMUM_NUMBER_OF_USED_CHANNELS_PROCEDURE = 1 << 16;
+impl Debug for LeSetCigParameters {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("LeSetCigParameters")
+            .field("status", &self.status)
+            .field("cig_id", &self.cig_id)
+            .field("conn_handles", &self.conn_handles())
+            .finish()
     }
 }
 
-fn to_le_local_supported_features(
-    bytes: &[u8],
-) -> Result<LeSupportedFeatures, crate::event::Error> {
-    require_len!(bytes, 9);
-    Ok(LeSupportedFeatures {
+fn to_le_set_cig_parameters(bytes: &[u8]) -> Result<LeSetCigParameters, crate::event::Error> {
+    require_len_at_least!(bytes, 3);
+    let conn_handle_count = bytes[2] as usize;
+    if conn_handle_count > MAX_CIS_COUNT {
+        return Err(crate::event::Error::BadLength(
+            bytes.len(),
+            3 + 2 * MAX_CIS_COUNT,
+        ));
+    }
+    require_len!(bytes, 3 + 2 * conn_handle_count);
+
+    let mut conn_handle_buf = [ConnectionHandle(0); MAX_CIS_COUNT];
+    for (handle, chunk) in conn_handle_buf.iter_mut().zip(bytes[3..].chunks_exact(2)) {
+        *handle = ConnectionHandle(LittleEndian::read_u16(chunk));
+    }
+
+    Ok(LeSetCigParameters {
         status: to_status(bytes)?,
-        supported_features: LeFeatures::from_bits_truncate(LittleEndian::read_u64(&bytes[1..])),
+        cig_id: bytes[1],
+        conn_handle_count,
+        conn_handle_buf,
     })
 }
 
-/// Values returned by the
-/// [LE Read Advertising Channel TX Power](crate::host::HostHci::le_read_advertising_channel_tx_power)
-/// command.
+fn write_le_set_cig_parameters(params: &LeSetCigParameters, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    bytes[1] = params.cig_id;
+    bytes[2] = params.conn_handle_count as u8;
+    for (handle, chunk) in params
+        .conn_handles()
+        .iter()
+        .zip(bytes[3..].chunks_exact_mut(2))
+    {
+        LittleEndian::write_u16(chunk, handle.0);
+    }
+    3 + 2 * params.conn_handle_count
+}
+
+/// Parameters returned by the [LE Remove CIG](crate::host::HostHci::le_remove_cig) command.
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct LeAdvertisingChannelTxPower {
+pub struct LeRemoveCig {
     /// Did the command fail, and if so, how?
     pub status: Status,
-    /// The transmit power of the advertising channel.
-    ///   - Range: -20 ≤ N ≤ 10 (this is not enforced in this implementation)
-    ///   - Units: dBm
-    ///   - Accuracy: ±4 dB
-    pub power: i8,
+
+    /// Identifies the removed CIG.
+    pub cig_id: u8,
 }
 
-fn to_le_advertising_channel_tx_power(
-    bytes: &[u8],
-) -> Result<LeAdvertisingChannelTxPower, crate::event::Error> {
+fn to_le_remove_cig(bytes: &[u8]) -> Result<LeRemoveCig, crate::event::Error> {
     require_len!(bytes, 2);
-    Ok(LeAdvertisingChannelTxPower {
+
+    Ok(LeRemoveCig {
         status: to_status(bytes)?,
-        power: u8::cast_signed(bytes[1]),
+        cig_id: bytes[1],
     })
 }
 
-fn to_le_set_advertise_enable(status: Status) -> ReturnParameters {
-    ReturnParameters::LeSetAdvertisingEnable(status)
+fn write_le_remove_cig(params: &LeRemoveCig, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    bytes[1] = params.cig_id;
+    2
 }
 
-/// Parameters returned by the [LE Read Channel Map](crate::host::HostHci::le_read_channel_map) command.
+/// Parameters returned by the
+/// [LE Reject CIS Request](crate::host::HostHci::le_reject_cis_request),
+/// [LE Setup ISO Data Path](crate::host::HostHci::le_setup_iso_data_path) and
+/// [LE Remove ISO Data Path](crate::host::HostHci::le_remove_iso_data_path) commands.
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct ChannelMapParameters {
+pub struct LeIsoConnection {
     /// Did the command fail, and if so, how?
     pub status: Status,
 
-    /// Connection handle whose channel map is returned.
+    /// Connection handle given in the command.
     pub conn_handle: ConnectionHandle,
-
-    /// Channels that may be used for this connection.
-    pub channel_map: crate::ChannelClassification,
 }
 
-fn to_le_channel_map_parameters(bytes: &[u8]) -> Result<ChannelMapParameters, crate::event::Error> {
-    require_len!(bytes, 8);
+fn to_le_iso_connection(bytes: &[u8]) -> Result<LeIsoConnection, crate::event::Error> {
+    require_len!(bytes, 3);
 
-    let mut channel_bits = [0; 5];
-    channel_bits.copy_from_slice(&bytes[3..8]);
-    let channel_bits = channel_bits;
-    Ok(ChannelMapParameters {
-        status: to_status(&bytes[0..])?,
+    Ok(LeIsoConnection {
+        status: to_status(bytes)?,
         conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[1..])),
-        channel_map: crate::ChannelClassification::from_bits(&bytes[3..])
-            .ok_or(crate::event::Error::InvalidChannelMap(channel_bits))?,
     })
 }
 
-/// Parameters returned by the [LE Encrypt](crate::host::HostHci::le_encrypt) command.
+fn write_le_iso_connection(params: &LeIsoConnection, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+    3
+}
+
+/// Parameters returned by the
+/// [LE BIG Terminate Sync](crate::host::HostHci::le_big_terminate_sync) command.
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct EncryptedReturnParameters {
+pub struct LeBigTerminateSync {
     /// Did the command fail, and if so, how?
     pub status: Status,
 
-    /// Encrypted data block.
-    ///
-    /// The most significant octet (last) of the block corresponds to `out[0]` using the notation
-    /// specified in FIPS 197.
-    pub encrypted_data: EncryptedBlock,
+    /// Identifies the BIG.
+    pub big_handle: BigHandle,
+}
+
+fn to_le_big_terminate_sync(bytes: &[u8]) -> Result<LeBigTerminateSync, crate::event::Error> {
+    require_len!(bytes, 2);
+
+    Ok(LeBigTerminateSync {
+        status: to_status(bytes)?,
+        big_handle: BigHandle(bytes[1]),
+    })
+}
+
+fn write_le_big_terminate_sync(params: &LeBigTerminateSync, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    bytes[1] = params.big_handle.0;
+    2
+}
+
+/// Parameters returned by the
+/// [LE Enhanced Read Transmit Power Level](crate::host::HostHci::le_enhanced_read_transmit_power_level)
+/// command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeEnhancedReadTransmitPowerLevel {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
+
+    /// ACL connection whose transmit power levels were read.
+    pub conn_handle: ConnectionHandle,
+
+    /// PHY whose transmit power levels were read.
+    pub phy: crate::event::PowerControlPhy,
+
+    /// Current transmit power level. `None` if not available.
+    pub current_tx_power_level: Option<Dbm>,
+
+    /// Maximum transmit power level.
+    pub max_tx_power_level: Dbm,
+}
+
+fn to_le_enhanced_read_transmit_power_level(
+    bytes: &[u8],
+) -> Result<LeEnhancedReadTransmitPowerLevel, crate::event::Error> {
+    require_len!(bytes, 6);
+
+    Ok(LeEnhancedReadTransmitPowerLevel {
+        status: to_status(bytes)?,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[1..])),
+        phy: bytes[3].try_into()?,
+        current_tx_power_level: match u8::cast_signed(bytes[4]) {
+            127 => None,
+            value => Some(Dbm(value)),
+        },
+        max_tx_power_level: Dbm(u8::cast_signed(bytes[5])),
+    })
 }
 
-/// Newtype for a 128-bit encrypted block of data.
-///
-/// See [`EncryptedReturnParameters`].
-#[derive(Copy, Clone)]
+fn write_le_enhanced_read_transmit_power_level(
+    params: &LeEnhancedReadTransmitPowerLevel,
+    bytes: &mut [u8],
+) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+    bytes[3] = params.phy.into();
+    bytes[4] = i8::cast_unsigned(params.current_tx_power_level.map_or(127, |level| level.0));
+    bytes[5] = i8::cast_unsigned(params.max_tx_power_level.0);
+    6
+}
+
+/// Parameters returned by the
+/// [LE Set Path Loss Reporting Parameters](crate::host::HostHci::le_set_path_loss_reporting_parameters),
+/// [LE Set Path Loss Reporting Enable](crate::host::HostHci::le_set_path_loss_reporting_enable)
+/// and
+/// [LE Set Transmit Power Reporting Enable](crate::host::HostHci::le_set_transmit_power_reporting_enable)
+/// commands.
+#[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct EncryptedBlock(pub [u8; 16]);
+pub struct LePowerControlConnection {
+    /// Did the command fail, and if so, how?
+    pub status: Status,
 
-impl Debug for EncryptedBlock {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        writeln!(f, "AES-128 Encrypted Data ({:X?})", &self.0)
-    }
+    /// Connection handle given in the command.
+    pub conn_handle: ConnectionHandle,
 }
 
-fn to_le_encrypted_data(bytes: &[u8]) -> Result<EncryptedReturnParameters, crate::event::Error> {
-    require_len!(bytes, 17);
+fn to_le_power_control_connection(
+    bytes: &[u8],
+) -> Result<LePowerControlConnection, crate::event::Error> {
+    require_len!(bytes, 3);
 
-    let mut block = [0; 16];
-    block.copy_from_slice(&bytes[1..]);
-    Ok(EncryptedReturnParameters {
+    Ok(LePowerControlConnection {
         status: to_status(bytes)?,
-        encrypted_data: EncryptedBlock(block),
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[1..])),
     })
 }
 
-/// Return parameters for the [LE Rand](crate::host::HostHci::le_rand) command.
+fn write_le_power_control_connection(params: &LePowerControlConnection, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+    3
+}
+
+/// Parameters returned by the
+/// [LE Set Connectionless IQ Sampling Enable](crate::host::HostHci::le_set_connectionless_iq_sampling_enable)
+/// command.
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct LeRandom {
+pub struct LeSetConnectionlessIqSamplingEnable {
     /// Did the command fail, and if so, how?
     pub status: Status,
 
-    /// Controller-generated random number.
-    pub random_number: u64,
+    /// Sync handle given in the command.
+    pub sync_handle: crate::SyncHandle,
 }
 
-fn to_random_number(bytes: &[u8]) -> Result<LeRandom, crate::event::Error> {
-    require_len!(bytes, 9);
+fn to_le_set_connectionless_iq_sampling_enable(
+    bytes: &[u8],
+) -> Result<LeSetConnectionlessIqSamplingEnable, crate::event::Error> {
+    require_len!(bytes, 3);
 
-    Ok(LeRandom {
+    Ok(LeSetConnectionlessIqSamplingEnable {
         status: to_status(bytes)?,
-        random_number: LittleEndian::read_u64(&bytes[1..]),
+        sync_handle: crate::SyncHandle(LittleEndian::read_u16(&bytes[1..])),
     })
 }
 
+fn write_le_set_connectionless_iq_sampling_enable(
+    params: &LeSetConnectionlessIqSamplingEnable,
+    bytes: &mut [u8],
+) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u16(&mut bytes[1..], params.sync_handle.0);
+    3
+}
+
 /// Parameters returned by the
-/// [LE LTK Request Reply](crate::host::HostHci::le_long_term_key_request_reply) command.
+/// [LE Set Connection CTE Receive Parameters](crate::host::HostHci::le_set_connection_cte_receive_parameters),
+/// [LE Set Connection CTE Transmit Parameters](crate::host::HostHci::le_set_connection_cte_transmit_parameters),
+/// [LE Connection CTE Request Enable](crate::host::HostHci::le_connection_cte_request_enable)
+/// and
+/// [LE Connection CTE Response Enable](crate::host::HostHci::le_connection_cte_response_enable)
+/// commands.
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct LeLongTermRequestReply {
+pub struct LeCteConnection {
     /// Did the command fail, and if so, how?
     pub status: Status,
 
-    /// Connection handle that the request came from
+    /// Connection handle given in the command.
     pub conn_handle: ConnectionHandle,
 }
 
-fn to_le_ltk_request_reply(bytes: &[u8]) -> Result<LeLongTermRequestReply, crate::event::Error> {
+fn to_le_cte_connection(bytes: &[u8]) -> Result<LeCteConnection, crate::event::Error> {
     require_len!(bytes, 3);
 
-    Ok(LeLongTermRequestReply {
+    Ok(LeCteConnection {
         status: to_status(bytes)?,
         conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[1..])),
     })
 }
 
+fn write_le_cte_connection(params: &LeCteConnection, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    LittleEndian::write_u16(&mut bytes[1..], params.conn_handle.0);
+    3
+}
+
 /// Parameters returned by the
-/// [LE Read Supported States](crate::host::HostHci::le_read_supported_states) command.
+/// [LE Read Antenna Information](crate::host::HostHci::le_read_antenna_information) command.
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct LeReadSupportedStates {
+pub struct LeReadAntennaInformation {
     /// Did the command fail, and if so, how?
     pub status: Status,
 
-    /// States or state combinations supported by the Controller. Multiple state and state
-    /// combinations may be supported.
-    pub supported_states: LeStates,
+    /// Optional switching and sampling rates supported by the Controller.
+    pub supported_switching_sampling_rates: SwitchingSamplingRates,
+
+    /// Number of antennae supported by the Controller.
+    pub num_antennae: u8,
+
+    /// Maximum length of an antenna switching pattern supported by the Controller.
+    pub max_switching_pattern_length: u8,
+
+    /// Maximum length of a transmitted Constant Tone Extension supported by the Controller, with
+    /// a resolution of 8 µs.
+    pub max_cte_length: core::time::Duration,
 }
 
 #[cfg(not(feature = "defmt"))]
 bitflags::bitflags! {
-    /// Possible LE states or state combinations for the
-    /// [LE Read Supported States](crate::host::HostHci::le_read_supported_states) command.
+    /// Optional switching and sampling rates returned by the
+    /// [LE Read Antenna Information](crate::host::HostHci::le_read_antenna_information) command.
     #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
-    pub struct LeStates : u64 {
-        /// Non-connectable advertising state alone.
-        const NON_CONNECTABLE_ADVERTISING = 1 << 0;
-        /// Scannable advertising state alone
-        const SCANNABLE_ADVERTISING = 1 << 1;
-        /// Connectable advertising state alone
-        const CONNECTABLE_ADVERTISING = 1 << 2;
-        /// Directed advertising (high duty cycle) state alone
-        const DIRECTED_ADVERTISING_HIGH_DUTY_CYCLE = 1 << 3;
-        /// Passive scanning state alone
-        const PASSIVE_SCANNING = 1 << 4;
-        /// Active scanning state alone
-        const ACTIVE_SCANNING = 1 << 5;
-        /// Initianing state alone
-        const INITIATING = 1 << 6;
-        /// Peripheral (slave) connection state alone
-        const PERIPHERAL_CONNECTION = 1 << 7;
-        /// Non-connectable advertising and passive scan states.
-        const NONCONN_AD_AND_PASS_SCAN = 1 << 8;
-        /// Scannable advertising and passive scan states
-        const SCAN_AD_AND_PASS_SCAN = 1 << 9;
-        /// Connectable advertising and passive scan states
-        const CONN_AD_AND_PASS_SCAN = 1 << 10;
-        /// Directed advertising (high duty cycle) and passive scan states
-        const DIR_AD_HDC_AND_PASS_SCAN = 1 << 11;
-        /// Non-connectable advertising and active scan states.
-        const NONCONN_AD_AND_ACT_SCAN = 1 << 12;
-        /// Scannable advertising and active scan states
-        const SCAN_AD_AND_ACT_SCAN = 1 << 13;
-        /// Connectable advertising and active scan states
-        const CONN_AD_AND_ACT_SCAN = 1 << 14;
-        /// Directed advertising (high duty cycle) and active scan states
-        const DIR_AD_HDC_AND_ACT_SCAN = 1 << 15;
-        /// Non-connectable advertising and initiating states.
-        const NONCONN_AD_AND_INITIATING = 1 << 16;
-        /// Scannable advertising and initiating states
-        const SCAN_AD_AND_INITIATING = 1 << 17;
-        /// Non-connectable advertising and central (master) connection states.
-        const NONCONN_AD_AND_CENTRAL_CONN = 1 << 18;
-        /// Scannable advertising and central (master) connection states
-        const SCAN_AD_AND_CENTRAL_CONN = 1 << 19;
-        /// Non-connectable advertising and peripheral (slave) connection states.
-        const NONCONN_AD_AND_PERIPH_CONN = 1 << 20;
-        /// Scannable advertising and peripheral (slave) connection states
-        const SCAN_AD_AND_PERIPH_CONN = 1 << 21;
-        /// Passive scan and initiating states
-        const PASS_SCAN_AND_INITIATING = 1 << 22;
-        /// Active scan and initiating states
-        const ACT_SCAN_AND_INITIATING = 1 << 23;
-        /// Passive scan and central (master) connection states
-        const PASS_SCAN_AND_CENTRAL_CONN = 1 << 24;
-        /// Active scan and central (master) connection states
-        const ACT_SCAN_AND_CENTRAL_CONN = 1 << 25;
-        /// Passive scan and peripheral (slave) connection states
-        const PASS_SCAN_AND_PERIPH_CONN = 1 << 26;
-        /// Active scan and peripheral (slave) connection states
-        const ACT_SCAN_AND_PERIPH_CONN = 1 << 27;
-        /// Initiating and central (master) connection states
-        const INITIATING_AND_CENTRAL_CONN = 1 << 28;
-        /// Directed advertising (low duty cycle) state alone
-        const DIRECTED_ADVERTISING_LOW_DUTY_CYCLE = 1 << 29;
-        /// Directed advertising (low duty cycle) and passive scan states
-        const DIR_AD_LDC_AND_PASS_SCAN = 1 << 30;
-        /// Directed advertising (low duty cycle) and active scan states
-        const DIR_AD_LDC_AND_ACT_SCAN = 1 << 31;
-        /// Connectable advertising and initiating states
-        const CONN_AD_AND_INITIATING = 1 << 32;
-        /// Directed advertising (high duty cycle) and initiating states
-        const DIR_AD_HDC_AND_INITIATING = 1 << 33;
-        /// Directed advertising (low duty cycle) and initiating states
-        const DIR_AD_LDC_AND_INITIATING = 1 << 34;
-        /// Connectable advertising and central (master) connection states
-        const CONN_AD_AND_CENTRAL_CONN = 1 << 35;
-        /// Directed advertising (high duty cycle) and central (master) states
-        const DIR_AD_HDC_AND_CENTRAL_CONN = 1 << 36;
-        /// Directed advertising (low duty cycle) and central (master) states
-        const DIR_AD_LDC_AND_CENTRAL_CONN = 1 << 37;
-        /// Connectable advertising and peripheral (slave) connection states
-        const CONN_AD_AND_PERIPH_CONN = 1 << 38;
-        /// Directed advertising (high duty cycle) and peripheral (slave) states
-        const DIR_AD_HDC_AND_PERIPH_CONN = 1 << 39;
-        /// Directed advertising (low duty cycle) and peripheral (slave) states
-        const DIR_AD_LDC_AND_PERIPH_CONN = 1 << 40;
-        /// Initiating and peripheral (slave) connection states
-        const INITIATING_AND_PERIPH_CONN = 1 << 41;
+    pub struct SwitchingSamplingRates : u8 {
+        /// 1 μs switching supported for AoD transmission
+        const AOD_TX_SWITCHING_1US = 1 << 0;
+        /// 1 μs sampling supported for AoD reception
+        const AOD_RX_SAMPLING_1US = 1 << 1;
+        /// 1 μs switching and sampling supported for AoA reception
+        const AOA_RX_SWITCHING_SAMPLING_1US = 1 << 2;
     }
 }
 
 #[cfg(feature = "defmt")]
 defmt::bitflags! {
-    /// Possible LE states or state combinations for the
-    /// [LE Read Supported States](crate::host::HostHci::le_read_supported_states) command.
+    /// Optional switching and sampling rates returned by the
+    /// [LE Read Antenna Information](crate::host::HostHci::le_read_antenna_information) command.
     #[derive(Default)]
-    pub struct LeStates : u64 {
-        /// Non-connectable advertising state alone.
-        const NON_CONNECTABLE_ADVERTISING = 1 << 0;
-        /// Scannable advertising state alone
-        const SCANNABLE_ADVERTISING = 1 << 1;
-        /// Connectable advertising state alone
-        const CONNECTABLE_ADVERTISING = 1 << 2;
-        /// Directed advertising (high duty cycle) state alone
-        const DIRECTED_ADVERTISING_HIGH_DUTY_CYCLE = 1 << 3;
-        /// Passive scanning state alone
-        const PASSIVE_SCANNING = 1 << 4;
-        /// Active scanning state alone
-        const ACTIVE_SCANNING = 1 << 5;
-        /// Initianing state alone
-        const INITIATING = 1 << 6;
-        /// Peripheral (slave) connection state alone
-        const PERIPHERAL_CONNECTION = 1 << 7;
-        /// Non-connectable advertising and passive scan states.
-        const NONCONN_AD_AND_PASS_SCAN = 1 << 8;
-        /// Scannable advertising and passive scan states
-        const SCAN_AD_AND_PASS_SCAN = 1 << 9;
-        /// Connectable advertising and passive scan states
-        const CONN_AD_AND_PASS_SCAN = 1 << 10;
-        /// Directed advertising (high duty cycle) and passive scan states
-        const DIR_AD_HDC_AND_PASS_SCAN = 1 << 11;
-        /// Non-connectable advertising and active scan states.
-        const NONCONN_AD_AND_ACT_SCAN = 1 << 12;
-        /// Scannable advertising and active scan states
-        const SCAN_AD_AND_ACT_SCAN = 1 << 13;
-        /// Connectable advertising and active scan states
-        const CONN_AD_AND_ACT_SCAN = 1 << 14;
-        /// Directed advertising (high duty cycle) and active scan states
-        const DIR_AD_HDC_AND_ACT_SCAN = 1 << 15;
-        /// Non-connectable advertising and initiating states.
-        const NONCONN_AD_AND_INITIATING = 1 << 16;
-        /// Scannable advertising and initiating states
-        const SCAN_AD_AND_INITIATING = 1 << 17;
-        /// Non-connectable advertising and central (master) connection states.
-        const NONCONN_AD_AND_CENTRAL_CONN = 1 << 18;
-        /// Scannable advertising and central (master) connection states
-        const SCAN_AD_AND_CENTRAL_CONN = 1 << 19;
-        /// Non-connectable advertising and peripheral (slave) connection states.
-        const NONCONN_AD_AND_PERIPH_CONN = 1 << 20;
-        /// Scannable advertising and peripheral (slave) connection states
-        const SCAN_AD_AND_PERIPH_CONN = 1 << 21;
-        /// Passive scan and initiating states
-        const PASS_SCAN_AND_INITIATING = 1 << 22;
-        /// Active scan and initiating states
-        const ACT_SCAN_AND_INITIATING = 1 << 23;
-        /// Passive scan and central (master) connection states
-        const PASS_SCAN_AND_CENTRAL_CONN = 1 << 24;
-        /// Active scan and central (master) connection states
-        const ACT_SCAN_AND_CENTRAL_CONN = 1 << 25;
-        /// Passive scan and peripheral (slave) connection states
-        const PASS_SCAN_AND_PERIPH_CONN = 1 << 26;
-        /// Active scan and peripheral (slave) connection states
-        const ACT_SCAN_AND_PERIPH_CONN = 1 << 27;
-        /// Initiating and central (master) connection states
-        const INITIATING_AND_CENTRAL_CONN = 1 << 28;
-        /// Directed advertising (low duty cycle) state alone
-        const DIRECTED_ADVERTISING_LOW_DUTY_CYCLE = 1 << 29;
-        /// Directed advertising (low duty cycle) and passive scan states
-        const DIR_AD_LDC_AND_PASS_SCAN = 1 << 30;
-        /// Directed advertising (low duty cycle) and active scan states
-        const DIR_AD_LDC_AND_ACT_SCAN = 1 << 31;
-        /// Connectable advertising and initiating states
-        const CONN_AD_AND_INITIATING = 1 << 32;
-        /// Directed advertising (high duty cycle) and initiating states
-        const DIR_AD_HDC_AND_INITIATING = 1 << 33;
-        /// Directed advertising (low duty cycle) and initiating states
-        const DIR_AD_LDC_AND_INITIATING = 1 << 34;
-        /// Connectable advertising and central (master) connection states
-        const CONN_AD_AND_CENTRAL_CONN = 1 << 35;
-        /// Directed advertising (high duty cycle) and central (master) states
-        const DIR_AD_HDC_AND_CENTRAL_CONN = 1 << 36;
-        /// Directed advertising (low duty cycle) and central (master) states
-        const DIR_AD_LDC_AND_CENTRAL_CONN = 1 << 37;
-        /// Connectable advertising and peripheral (slave) connection states
-        const CONN_AD_AND_PERIPH_CONN = 1 << 38;
-        /// Directed advertising (high duty cycle) and peripheral (slave) states
-        const DIR_AD_HDC_AND_PERIPH_CONN = 1 << 39;
-        /// Directed advertising (low duty cycle) and peripheral (slave) states
-        const DIR_AD_LDC_AND_PERIPH_CONN = 1 << 40;
-        /// Initiating and peripheral (slave) connection states
-        const INITIATING_AND_PERIPH_CONN = 1 << 41;
+    pub struct SwitchingSamplingRates : u8 {
+        /// 1 μs switching supported for AoD transmission
+        const AOD_TX_SWITCHING_1US = 1 << 0;
+        /// 1 μs sampling supported for AoD reception
+        const AOD_RX_SAMPLING_1US = 1 << 1;
+        /// 1 μs switching and sampling supported for AoA reception
+        const AOA_RX_SWITCHING_SAMPLING_1US = 1 << 2;
     }
 }
 
-fn to_le_read_states(bytes: &[u8]) -> Result<LeReadSupportedStates, crate::event::Error> {
-    require_len!(bytes, 9);
+fn to_le_read_antenna_information(
+    bytes: &[u8],
+) -> Result<LeReadAntennaInformation, crate::event::Error> {
+    require_len!(bytes, 5);
 
-    let bitfield = LittleEndian::read_u64(&bytes[1..]);
-    Ok(LeReadSupportedStates {
+    Ok(LeReadAntennaInformation {
         status: to_status(bytes)?,
-        supported_states: LeStates::from_bits(bitfield)
-            .ok_or(crate::event::Error::InvalidLeStates(bitfield))?,
+        supported_switching_sampling_rates: SwitchingSamplingRates::from_bits_truncate(bytes[1]),
+        num_antennae: bytes[2],
+        max_switching_pattern_length: bytes[3],
+        max_cte_length: core::time::Duration::from_micros(8) * u32::from(bytes[4]),
     })
 }
 
-/// Parameters returned by the [LE Test End](crate::host::HostHci::le_test_end) command.
-#[derive(Copy, Clone, Debug)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct LeTestEnd {
-    /// Did the command fail, and if so, how?
-    pub status: Status,
-
-    /// The number of packets received during the test.  For transmitter tests, this value shall be
-    /// 0.
-    pub number_of_packets: usize,
-}
-
-fn to_le_test_end(bytes: &[u8]) -> Result<LeTestEnd, crate::event::Error> {
-    require_len!(bytes, 3);
-
-    Ok(LeTestEnd {
-        status: to_status(bytes)?,
-        number_of_packets: LittleEndian::read_u16(&bytes[1..]) as usize,
-    })
+fn write_le_read_antenna_information(params: &LeReadAntennaInformation, bytes: &mut [u8]) -> usize {
+    write_status(&params.status, bytes);
+    bytes[1] = params.supported_switching_sampling_rates.bits();
+    bytes[2] = params.num_antennae;
+    bytes[3] = params.max_switching_pattern_length;
+    bytes[4] = (params.max_cte_length.as_micros() / 8) as u8;
+    5
 }
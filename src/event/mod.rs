@@ -28,9 +28,10 @@ macro_rules! require_len_at_least {
     };
 }
 
+pub mod borrowed;
 pub mod command;
 
-use crate::types::{ConnectionIntervalError, FixedConnectionInterval};
+use crate::types::{ConnectionIntervalError, FixedConnectionInterval, P256PublicKey};
 use crate::vendor::event::VendorEvent;
 use crate::vendor::VendorError;
 use crate::{BadStatusError, ConnectionHandle, Status};
@@ -100,27 +101,95 @@ pub enum Event {
     /// This event is generated when local P-256 key generation is complete.
     ///
     /// Vol 4, Part E, 7.7.65.8
-    LeReadLocalP256PublicKeyComplete([u8; 64]),
+    LeReadLocalP256PublicKeyComplete(LeReadLocalP256PublicKeyComplete),
 
     /// This event indicates that LE Diffie Hellman key generation has been completed by the Controller.
     ///
     /// Vol 4, Part E, Section 7.7.65.9
-    LeGenerateDHKeyComplete([u8; 32]),
+    LeGenerateDHKeyComplete(LeGenerateDHKeyComplete),
 
     /// Vol 4, Part E, Section 7.7.65.10
     LeEnhancedConnectionComplete(LeEnhancedConnectionComplete),
 
+    /// Vol 4, Part E, Section 7.7.65.11
+    LeDirectedAdvertisingReport(LeDirectedAdvertisingReport),
+
     /// Vol 2, Part E, Section 7.7.65.12
     LePhyUpdateComplete(LePhyUpdateComplete),
 
-    // TODO: le_enhanced_connection_complete
-    // TODO: le_directed_advertising_report
-    // TODO: le_phy_update_complete
-    // TODO: le_extended_advertising_report
-    // TODO: le_scan_timeout
-    // TODO: le_advertising_set_terminated
-    // TODO: le_scan_reauest_received
-    // TODO: le_channel_selection_algorithm
+    /// Vol 4, Part E, Section 7.7.65.13
+    LeExtendedAdvertisingReport(LeExtendedAdvertisingReport),
+
+    /// Vol 4, Part E, Section 7.7.65.14
+    LePeriodicAdvertisingSyncEstablished(LePeriodicAdvertisingSyncEstablished),
+
+    /// Vol 4, Part E, Section 7.7.65.15
+    LePeriodicAdvertisingReport(LePeriodicAdvertisingReport),
+
+    /// Indicates that the Controller has not received a periodic advertising packet of the train
+    /// identified by the sync handle within the sync timeout, and has stopped receiving it.
+    ///
+    /// Vol 4, Part E, Section 7.7.65.16
+    LePeriodicAdvertisingSyncLost(crate::SyncHandle),
+
+    /// Indicates that scanning enabled by the
+    /// [LE Set Extended Scan Enable](crate::host::HostHci::le_set_extended_scan_enable) command
+    /// has ended at the end of its duration.
+    ///
+    /// Vol 4, Part E, Section 7.7.65.17
+    LeScanTimeout,
+
+    /// Vol 4, Part E, Section 7.7.65.18
+    LeAdvertisingSetTerminated(LeAdvertisingSetTerminated),
+
+    /// Vol 4, Part E, Section 7.7.65.19
+    LeScanRequestReceived(LeScanRequestReceived),
+
+    /// Vol 4, Part E, Section 7.7.65.20
+    LeChannelSelectionAlgorithm(LeChannelSelectionAlgorithm),
+
+    /// Vol 4, Part E, Section 7.7.65.21
+    LeConnectionlessIqReport(LeConnectionlessIqReport),
+
+    /// Vol 4, Part E, Section 7.7.65.22
+    LeConnectionIqReport(LeConnectionIqReport),
+
+    /// Vol 4, Part E, Section 7.7.65.23
+    LeCteRequestFailed(LeCteRequestFailed),
+
+    /// Vol 4, Part E, Section 7.7.65.24
+    LePeriodicAdvertisingSyncTransferReceived(LePeriodicAdvertisingSyncTransferReceived),
+
+    /// Vol 4, Part E, Section 7.7.65.25
+    LeCisEstablished(LeCisEstablished),
+
+    /// Vol 4, Part E, Section 7.7.65.26
+    LeCisRequest(LeCisRequest),
+
+    /// Vol 4, Part E, Section 7.7.65.27
+    LeCreateBigComplete(LeCreateBigComplete),
+
+    /// Vol 4, Part E, Section 7.7.65.28
+    LeTerminateBigComplete(LeTerminateBigComplete),
+
+    /// Vol 4, Part E, Section 7.7.65.29
+    LeBigSyncEstablished(LeBigSyncEstablished),
+
+    /// Vol 4, Part E, Section 7.7.65.30
+    LeBigSyncLost(LeBigSyncLost),
+
+    /// Vol 4, Part E, Section 7.7.65.32
+    LePathLossThreshold(LePathLossThreshold),
+
+    /// Vol 4, Part E, Section 7.7.65.33
+    LeTransmitPowerReporting(LeTransmitPowerReporting),
+
+    /// Vol 4, Part E, Section 7.7.65.34
+    LeBigInfoAdvertisingReport(LeBigInfoAdvertisingReport),
+
+    /// Vol 4, Part E, Section 7.7.65.35
+    LeSubrateChange(LeSubrateChange),
+
     /// Vendor-specific events (opcode 0xFF)
     Vendor(VendorEvent),
 }
@@ -180,9 +249,10 @@ pub enum Error {
     /// not recognized. Includes the unrecognized byte.
     BadLeConnectionRole(u8),
 
-    /// For the [LE Connection Complete](Event::LeConnectionComplete) or
-    /// [LE Advertising Report](Event::LeAdvertisingReport) events: The address type was not recognized.
-    /// Includes the unrecognized byte.
+    /// For the [LE Connection Complete](Event::LeConnectionComplete),
+    /// [LE Advertising Report](Event::LeAdvertisingReport) or
+    /// [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport) events: The address
+    /// type was not recognized. Includes the unrecognized byte.
     BadLeAddressType(u8),
 
     /// For the [LE Connection Complete](Event::LeConnectionComplete) event: The returned connection
@@ -194,8 +264,9 @@ pub enum Error {
     /// accuracy value was not recognized.  Includes the unrecognized byte.
     BadLeCentralClockAccuracy(u8),
 
-    /// For the [LE Advertising Report](Event::LeAdvertisingReport) event: The packet ended with a
-    /// partial report.
+    /// For the [LE Advertising Report](Event::LeAdvertisingReport) or
+    /// [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport) events: The packet
+    /// ended with a partial report.
     LeAdvertisementReportIncomplete,
 
     /// For the [LE Advertising Report](Event::LeAdvertisingReport) event: The packet includes an
@@ -207,14 +278,74 @@ pub enum Error {
     /// bytes of flags.
     BadRemoteUsedFeatureFlag(u64),
 
-    /// For the [LE PHY Update Complete](Event::LePhyUpdateComplete) event: The PHY type was not
-    /// recognized. Includes the unrecognized byte.
+    /// For the [LE PHY Update Complete](Event::LePhyUpdateComplete) event and the
+    /// [LE Read PHY](crate::host::HostHci::le_read_phy) command: The PHY type was not recognized.
+    /// Includes the unrecognized byte.
     BadPhy(u8),
 
     /// For the [Hardware Error](Event::HardwareError) event: The error code was not recongnized.
     /// Includes the unrecongnized code.
     BadHardwareError(u8),
 
+    /// For the [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport) event: The
+    /// packet includes an event type with a reserved bit set or a reserved data status. Includes
+    /// the event type.
+    BadLeExtendedAdvertisementType(u16),
+
+    /// For the [LE Channel Selection Algorithm](Event::LeChannelSelectionAlgorithm) event: The
+    /// channel selection algorithm was not recognized. Includes the unrecognized byte.
+    BadChannelSelectionAlgorithm(u8),
+
+    /// For the [LE Periodic Advertising Report](Event::LePeriodicAdvertisingReport) event: The
+    /// data status was not recognized. Includes the unrecognized byte.
+    BadAdvertisingDataStatus(u8),
+
+    /// For the [LE Periodic Advertising Report](Event::LePeriodicAdvertisingReport),
+    /// [LE Connectionless IQ Report](Event::LeConnectionlessIqReport) and
+    /// [LE Connection IQ Report](Event::LeConnectionIqReport) events: The Constant Tone Extension
+    /// type was not recognized. Includes the unrecognized byte.
+    BadCteType(u8),
+
+    /// For the [LE Create BIG Complete](Event::LeCreateBigComplete) and
+    /// [LE BIG Sync Established](Event::LeBigSyncEstablished) events: The number of BISes is
+    /// greater than 31. Includes the number.
+    BadNumBis(u8),
+
+    /// For the [LE BIGInfo Advertising Report](Event::LeBigInfoAdvertisingReport) event: The
+    /// framing was not recognized. Includes the unrecognized byte.
+    BadFraming(u8),
+
+    /// For the [LE Transmit Power Reporting](Event::LeTransmitPowerReporting) event: The PHY was
+    /// not recognized. Includes the unrecognized byte.
+    BadPowerControlPhy(u8),
+
+    /// For the [LE Path Loss Threshold](Event::LePathLossThreshold) event: The zone entered was
+    /// not recognized. Includes the unrecognized byte.
+    BadPathLossZone(u8),
+
+    /// For the [LE Transmit Power Reporting](Event::LeTransmitPowerReporting) event: The reason
+    /// for the report was not recognized. Includes the unrecognized byte.
+    BadTransmitPowerReportingReason(u8),
+
+    /// For the [LE Transmit Power Reporting](Event::LeTransmitPowerReporting) event: The transmit
+    /// power level flags included a reserved bit. Includes the flags.
+    BadTxPowerLevelFlags(u8),
+
+    /// For the [LE Connectionless IQ Report](Event::LeConnectionlessIqReport) and
+    /// [LE Connection IQ Report](Event::LeConnectionIqReport) events: The slot durations were not
+    /// recognized. Includes the unrecognized byte.
+    BadSlotDuration(u8),
+
+    /// For the [LE Connectionless IQ Report](Event::LeConnectionlessIqReport) and
+    /// [LE Connection IQ Report](Event::LeConnectionIqReport) events: The packet status was not
+    /// recognized. Includes the unrecognized byte.
+    BadIqPacketStatus(u8),
+
+    /// For the [LE Connectionless IQ Report](Event::LeConnectionlessIqReport) and
+    /// [LE Connection IQ Report](Event::LeConnectionIqReport) events: The number of IQ samples is
+    /// neither 0 nor between 9 and 82. Includes the number.
+    BadIqSampleCount(u8),
+
     /// A vendor-specific error was detected when deserializing a vendor-specific event.
     Vendor(VendorError),
 }
@@ -295,6 +426,137 @@ impl Event {
             _ => Err(Error::UnknownEvent(event_type)),
         }
     }
+
+    /// Serializes the event into the given buffer, in the same format that [`new`](Event::new)
+    /// accepts: the event code, the parameter length, and the event parameters.
+    ///
+    /// Returns the number of bytes written to the buffer.
+    ///
+    /// # Panics
+    ///
+    /// The buffer must be large enough to hold the serialized event. A buffer of 257 bytes (the
+    /// maximum HCI event packet size) is always sufficient.
+    pub fn write_into(&self, buf: &mut [u8]) -> usize {
+        let payload = &mut buf[PACKET_HEADER_LENGTH..];
+        let (event_type, param_len) = match self {
+            Event::ConnectionComplete(event) => (0x03, write_connection_complete(event, payload)),
+            Event::DisconnectionComplete(event) => {
+                (0x05, write_disconnection_complete(event, payload))
+            }
+            Event::EncryptionChange(event) => (0x08, write_encryption_change(event, payload)),
+            Event::ReadRemoteVersionInformationComplete(event) => {
+                (0x0C, write_remote_version_info(event, payload))
+            }
+            Event::CommandComplete(event) => (0x0E, event.write_into(payload)),
+            Event::CommandStatus(event) => (0x0F, write_command_status(event, payload)),
+            Event::HardwareError(event) => (0x10, write_hardware_error(event, payload)),
+            Event::NumberOfCompletedPackets(event) => {
+                (0x13, write_number_of_completed_packets(event, payload))
+            }
+            Event::DataBufferOverflow(event) => (0x1A, write_data_buffer_overflow(event, payload)),
+            Event::EncryptionKeyRefreshComplete(event) => {
+                (0x30, write_encryption_key_refresh_complete(event, payload))
+            }
+            Event::LeConnectionComplete(event) => {
+                (0x3E, write_le_connection_complete(event, payload))
+            }
+            Event::LeAdvertisingReport(event) => {
+                (0x3E, write_le_advertising_report(event, payload))
+            }
+            Event::LeConnectionUpdateComplete(event) => {
+                (0x3E, write_le_connection_update_complete(event, payload))
+            }
+            Event::LeReadRemoteUsedFeaturesComplete(event) => (
+                0x3E,
+                write_le_read_remote_used_features_complete(event, payload),
+            ),
+            Event::LeLongTermKeyRequest(event) => (0x3E, write_le_ltk_request(event, payload)),
+            Event::LeDataLengthChangeEvent(event) => {
+                (0x3E, write_le_data_length_change_event(event, payload))
+            }
+            Event::LeReadLocalP256PublicKeyComplete(event) => {
+                (0x3E, write_le_read_local_p256_public_key(event, payload))
+            }
+            Event::LeGenerateDHKeyComplete(event) => {
+                (0x3E, write_le_generate_dhkey_complete(event, payload))
+            }
+            Event::LeEnhancedConnectionComplete(event) => {
+                (0x3E, write_le_enhanced_connection_complete(event, payload))
+            }
+            Event::LeDirectedAdvertisingReport(event) => {
+                (0x3E, write_le_directed_advertising_report(event, payload))
+            }
+            Event::LePhyUpdateComplete(event) => {
+                (0x3E, write_le_phy_update_complete(event, payload))
+            }
+            Event::LeExtendedAdvertisingReport(event) => {
+                (0x3E, write_le_extended_advertising_report(event, payload))
+            }
+            Event::LePeriodicAdvertisingSyncEstablished(event) => (
+                0x3E,
+                write_le_periodic_advertising_sync_established(event, payload),
+            ),
+            Event::LePeriodicAdvertisingReport(event) => {
+                (0x3E, write_le_periodic_advertising_report(event, payload))
+            }
+            Event::LePeriodicAdvertisingSyncLost(sync_handle) => {
+                payload[0] = 0x10;
+                LittleEndian::write_u16(&mut payload[1..], sync_handle.0);
+                (0x3E, 3)
+            }
+            Event::LeScanTimeout => {
+                payload[0] = 0x11;
+                (0x3E, 1)
+            }
+            Event::LeAdvertisingSetTerminated(event) => {
+                (0x3E, write_le_advertising_set_terminated(event, payload))
+            }
+            Event::LeScanRequestReceived(event) => {
+                (0x3E, write_le_scan_request_received(event, payload))
+            }
+            Event::LeChannelSelectionAlgorithm(event) => {
+                (0x3E, write_le_channel_selection_algorithm(event, payload))
+            }
+            Event::LeConnectionlessIqReport(event) => {
+                (0x3E, write_le_connectionless_iq_report(event, payload))
+            }
+            Event::LeConnectionIqReport(event) => {
+                (0x3E, write_le_connection_iq_report(event, payload))
+            }
+            Event::LeCteRequestFailed(event) => (0x3E, write_le_cte_request_failed(event, payload)),
+            Event::LePeriodicAdvertisingSyncTransferReceived(event) => (
+                0x3E,
+                write_le_periodic_advertising_sync_transfer_received(event, payload),
+            ),
+            Event::LeCisEstablished(event) => (0x3E, write_le_cis_established(event, payload)),
+            Event::LeCisRequest(event) => (0x3E, write_le_cis_request(event, payload)),
+            Event::LeCreateBigComplete(event) => {
+                (0x3E, write_le_create_big_complete(event, payload))
+            }
+            Event::LeTerminateBigComplete(event) => {
+                (0x3E, write_le_terminate_big_complete(event, payload))
+            }
+            Event::LeBigSyncEstablished(event) => {
+                (0x3E, write_le_big_sync_established(event, payload))
+            }
+            Event::LeBigSyncLost(event) => (0x3E, write_le_big_sync_lost(event, payload)),
+            Event::LePathLossThreshold(event) => {
+                (0x3E, write_le_path_loss_threshold(event, payload))
+            }
+            Event::LeTransmitPowerReporting(event) => {
+                (0x3E, write_le_transmit_power_reporting(event, payload))
+            }
+            Event::LeBigInfoAdvertisingReport(event) => {
+                (0x3E, write_le_big_info_advertising_report(event, payload))
+            }
+            Event::LeSubrateChange(event) => (0x3E, write_le_subrate_change(event, payload)),
+            Event::Vendor(event) => (0xFF, event.write_into(payload)),
+        };
+
+        buf[EVENT_TYPE_BYTE] = event_type;
+        buf[PARAM_LEN_BYTE] = param_len as u8;
+        PACKET_HEADER_LENGTH + param_len
+    }
 }
 
 fn to_le_meta_event(payload: &[u8]) -> Result<Event, Error> {
@@ -325,9 +587,74 @@ fn to_le_meta_event(payload: &[u8]) -> Result<Event, Error> {
         0x0A => Ok(Event::LeEnhancedConnectionComplete(
             to_le_enhanced_connection_complete(payload)?,
         )),
+        0x0B => Ok(Event::LeDirectedAdvertisingReport(
+            to_le_directed_advertising_report(payload)?,
+        )),
         0x0C => Ok(Event::LePhyUpdateComplete(to_le_phy_update_complete(
             payload,
         )?)),
+        0x0D => Ok(Event::LeExtendedAdvertisingReport(
+            to_le_extended_advertising_report(payload)?,
+        )),
+        0x0E => Ok(Event::LePeriodicAdvertisingSyncEstablished(
+            to_le_periodic_advertising_sync_established(payload)?,
+        )),
+        0x0F => Ok(Event::LePeriodicAdvertisingReport(
+            to_le_periodic_advertising_report(payload)?,
+        )),
+        0x10 => {
+            require_len!(payload, 3);
+            Ok(Event::LePeriodicAdvertisingSyncLost(crate::SyncHandle(
+                LittleEndian::read_u16(&payload[1..]),
+            )))
+        }
+        0x11 => {
+            require_len!(payload, 1);
+            Ok(Event::LeScanTimeout)
+        }
+        0x12 => Ok(Event::LeAdvertisingSetTerminated(
+            to_le_advertising_set_terminated(payload)?,
+        )),
+        0x13 => Ok(Event::LeScanRequestReceived(to_le_scan_request_received(
+            payload,
+        )?)),
+        0x14 => Ok(Event::LeChannelSelectionAlgorithm(
+            to_le_channel_selection_algorithm(payload)?,
+        )),
+        0x15 => Ok(Event::LeConnectionlessIqReport(
+            to_le_connectionless_iq_report(payload)?,
+        )),
+        0x16 => Ok(Event::LeConnectionIqReport(to_le_connection_iq_report(
+            payload,
+        )?)),
+        0x17 => Ok(Event::LeCteRequestFailed(to_le_cte_request_failed(
+            payload,
+        )?)),
+        0x18 => Ok(Event::LePeriodicAdvertisingSyncTransferReceived(
+            to_le_periodic_advertising_sync_transfer_received(payload)?,
+        )),
+        0x19 => Ok(Event::LeCisEstablished(to_le_cis_established(payload)?)),
+        0x1A => Ok(Event::LeCisRequest(to_le_cis_request(payload)?)),
+        0x1B => Ok(Event::LeCreateBigComplete(to_le_create_big_complete(
+            payload,
+        )?)),
+        0x1C => Ok(Event::LeTerminateBigComplete(to_le_terminate_big_complete(
+            payload,
+        )?)),
+        0x1D => Ok(Event::LeBigSyncEstablished(to_le_big_sync_established(
+            payload,
+        )?)),
+        0x1E => Ok(Event::LeBigSyncLost(to_le_big_sync_lost(payload)?)),
+        0x20 => Ok(Event::LePathLossThreshold(to_le_path_loss_threshold(
+            payload,
+        )?)),
+        0x21 => Ok(Event::LeTransmitPowerReporting(
+            to_le_transmit_power_reporting(payload)?,
+        )),
+        0x22 => Ok(Event::LeBigInfoAdvertisingReport(
+            to_le_big_info_advertising_report(payload)?,
+        )),
+        0x23 => Ok(Event::LeSubrateChange(to_le_subrate_change(payload)?)),
 
         _ => Err(Error::UnknownEvent(payload[0])),
     }
@@ -376,6 +703,15 @@ impl TryFrom<u8> for LinkType {
     }
 }
 
+impl From<LinkType> for u8 {
+    fn from(value: LinkType) -> u8 {
+        match value {
+            LinkType::Sco => 0,
+            LinkType::Acl => 1,
+        }
+    }
+}
+
 fn to_connection_complete(payload: &[u8]) -> Result<ConnectionComplete, Error> {
     require_len!(payload, 11);
 
@@ -390,6 +726,15 @@ fn to_connection_complete(payload: &[u8]) -> Result<ConnectionComplete, Error> {
     })
 }
 
+fn write_connection_complete(event: &ConnectionComplete, payload: &mut [u8]) -> usize {
+    payload[0] = event.status.into();
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    payload[3..9].copy_from_slice(&event.bd_addr.0);
+    payload[9] = event.link_type.into();
+    payload[10] = event.encryption_enabled as u8;
+    11
+}
+
 fn try_into_encryption_enabled(value: u8) -> Result<bool, Error> {
     match value {
         0 => Ok(false),
@@ -434,6 +779,13 @@ fn to_disconnection_complete(payload: &[u8]) -> Result<DisconnectionComplete, Er
     })
 }
 
+fn write_disconnection_complete(event: &DisconnectionComplete, payload: &mut [u8]) -> usize {
+    payload[0] = event.status.into();
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    payload[3] = event.reason.into();
+    4
+}
+
 /// The [Encryption Change](Event::EncryptionChange) event is used to indicate that the change of
 /// the encryption mode has been completed.
 ///
@@ -494,6 +846,16 @@ impl TryFrom<u8> for Encryption {
     }
 }
 
+impl From<Encryption> for u8 {
+    fn from(value: Encryption) -> u8 {
+        match value {
+            Encryption::Off => 0x00,
+            Encryption::On => 0x01,
+            Encryption::OnAesCcmForBrEdr => 0x02,
+        }
+    }
+}
+
 fn to_encryption_change(payload: &[u8]) -> Result<EncryptionChange, Error> {
     require_len!(payload, 4);
     Ok(EncryptionChange {
@@ -503,6 +865,13 @@ fn to_encryption_change(payload: &[u8]) -> Result<EncryptionChange, Error> {
     })
 }
 
+fn write_encryption_change(event: &EncryptionChange, payload: &mut [u8]) -> usize {
+    payload[0] = event.status.into();
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    payload[3] = event.encryption.into();
+    4
+}
+
 /// Indicates the completion of the process obtaining the version information of the remote
 /// Controller specified by [`conn_handle`](RemoteVersionInformation::conn_handle).
 ///
@@ -558,6 +927,15 @@ fn to_remote_version_info(payload: &[u8]) -> Result<RemoteVersionInformation, Er
     })
 }
 
+fn write_remote_version_info(event: &RemoteVersionInformation, payload: &mut [u8]) -> usize {
+    payload[0] = event.status.into();
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    payload[3] = event.version;
+    LittleEndian::write_u16(&mut payload[4..], event.mfgr_name);
+    LittleEndian::write_u16(&mut payload[6..], event.subversion);
+    8
+}
+
 /// The [Command Status](Event::CommandStatus) event is used to indicate that the command described
 /// by the [`opcode`](CommandStatus::opcode) parameter has been received, and that the Controller is
 /// currently performing the task for this command.
@@ -588,6 +966,13 @@ fn to_command_status(buffer: &[u8]) -> Result<CommandStatus, Error> {
     })
 }
 
+fn write_command_status(event: &CommandStatus, buffer: &mut [u8]) -> usize {
+    buffer[0] = event.status.into();
+    buffer[1] = event.num_hci_command_packets;
+    LittleEndian::write_u16(&mut buffer[2..], event.opcode.0);
+    4
+}
+
 /// The [Hardware Error](Event::HardwareError) event is used to notify the Host that a hardware
 /// failure has occurred in the Controller.
 ///
@@ -616,6 +1001,11 @@ fn to_hardware_error(payload: &[u8]) -> Result<HardwareError, Error> {
     }
 }
 
+fn write_hardware_error(event: &HardwareError, payload: &mut [u8]) -> usize {
+    payload[0] = *event as u8;
+    1
+}
+
 /// The [`Number of Completed Packets`](Event::NumberOfCompletedPackets) event is used by the
 /// Controller to indicate to the Host how many HCI Data Packets have been completed (transmitted or
 /// flushed) for each connection handle since the previous Number Of Completed Packets event was
@@ -672,7 +1062,7 @@ impl NumberOfCompletedPackets {
     /// Returns an iterator over the connection handle-number of completed packet pairs.
     pub fn iter(&self) -> NumberOfCompletedPacketsIterator<'_> {
         NumberOfCompletedPacketsIterator {
-            event: self,
+            data: &self.data_buf[..self.num_handles * NUM_COMPLETED_PACKETS_PAIR_LEN],
             next_index: 0,
         }
     }
@@ -682,7 +1072,7 @@ impl NumberOfCompletedPackets {
 /// [`NumberOfCompletedPackets`] event.
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct NumberOfCompletedPacketsIterator<'a> {
-    event: &'a NumberOfCompletedPackets,
+    data: &'a [u8],
     next_index: usize,
 }
 
@@ -692,16 +1082,15 @@ impl<'a> Iterator for NumberOfCompletedPacketsIterator<'a> {
 
     /// Returns the next connection handle-number of completed packets pair from the event.
     fn next(&mut self) -> Option<Self::Item> {
-        if self.next_index >= self.event.num_handles * NUM_COMPLETED_PACKETS_PAIR_LEN {
+        if self.next_index >= self.data.len() {
             return None;
         }
 
         let index = self.next_index;
         self.next_index += NUM_COMPLETED_PACKETS_PAIR_LEN;
         Some(NumberOfCompletedPacketsPair {
-            conn_handle: ConnectionHandle(LittleEndian::read_u16(&self.event.data_buf[index..])),
-            num_completed_packets: LittleEndian::read_u16(&self.event.data_buf[index + 2..])
-                as usize,
+            conn_handle: ConnectionHandle(LittleEndian::read_u16(&self.data[index..])),
+            num_completed_packets: LittleEndian::read_u16(&self.data[index + 2..]) as usize,
         })
     }
 }
@@ -720,19 +1109,26 @@ pub struct NumberOfCompletedPacketsPair {
 }
 
 fn to_number_of_completed_packets(payload: &[u8]) -> Result<NumberOfCompletedPackets, Error> {
-    require_len_at_least!(payload, 1);
-
-    let num_pairs = payload[0] as usize;
-    require_len!(payload, 1 + num_pairs * NUM_COMPLETED_PACKETS_PAIR_LEN);
+    let event = borrowed::to_number_of_completed_packets(payload)?;
 
     let mut data_buf = [0; NUMBER_OF_COMPLETED_PACKETS_MAX_LEN];
-    data_buf[..num_pairs * NUM_COMPLETED_PACKETS_PAIR_LEN].copy_from_slice(&payload[1..]);
+    data_buf[..event.data.len()].copy_from_slice(event.data);
     Ok(NumberOfCompletedPackets {
-        num_handles: num_pairs,
+        num_handles: payload[0] as usize,
         data_buf,
     })
 }
 
+fn write_number_of_completed_packets(
+    event: &NumberOfCompletedPackets,
+    payload: &mut [u8],
+) -> usize {
+    let data_len = event.num_handles * NUM_COMPLETED_PACKETS_PAIR_LEN;
+    payload[0] = event.num_handles as u8;
+    payload[1..=data_len].copy_from_slice(&event.data_buf[..data_len]);
+    1 + data_len
+}
+
 /// Indicates that the Controller's data buffers have been overflowed.  This can occur if the Host
 /// has sent more packets than allowed.
 ///
@@ -751,6 +1147,11 @@ fn to_data_buffer_overflow(payload: &[u8]) -> Result<DataBufferOverflow, Error>
     })
 }
 
+fn write_data_buffer_overflow(event: &DataBufferOverflow, payload: &mut [u8]) -> usize {
+    payload[0] = event.link_type.into();
+    1
+}
+
 /// Indicates to the Host that the encryption key was refreshed.
 ///
 /// The encryption key is refreshed on the given
@@ -787,6 +1188,15 @@ fn to_encryption_key_refresh_complete(
     })
 }
 
+fn write_encryption_key_refresh_complete(
+    event: &EncryptionKeyRefreshComplete,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = event.status.into();
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    3
+}
+
 /// Indicates to both of the Hosts forming the connection that a new connection has been
 /// created. Upon the creation of the connection a connection handle shall be assigned by the
 /// Controller, and passed to the Host in this event. If the connection establishment fails this
@@ -846,6 +1256,15 @@ impl TryFrom<u8> for ConnectionRole {
     }
 }
 
+impl From<ConnectionRole> for u8 {
+    fn from(value: ConnectionRole) -> u8 {
+        match value {
+            ConnectionRole::Central => 0,
+            ConnectionRole::Peripheral => 1,
+        }
+    }
+}
+
 /// Values for the central (master) clock accuracy as returned by the
 /// [LE Connection Complete](Event::LeConnectionComplete) event.
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -887,6 +1306,21 @@ impl TryFrom<u8> for CentralClockAccuracy {
     }
 }
 
+impl From<CentralClockAccuracy> for u8 {
+    fn from(value: CentralClockAccuracy) -> u8 {
+        match value {
+            CentralClockAccuracy::Ppm500 => 0,
+            CentralClockAccuracy::Ppm250 => 1,
+            CentralClockAccuracy::Ppm150 => 2,
+            CentralClockAccuracy::Ppm100 => 3,
+            CentralClockAccuracy::Ppm75 => 4,
+            CentralClockAccuracy::Ppm50 => 5,
+            CentralClockAccuracy::Ppm30 => 6,
+            CentralClockAccuracy::Ppm20 => 7,
+        }
+    }
+}
+
 fn to_le_connection_complete(payload: &[u8]) -> Result<LeConnectionComplete, Error> {
     require_len!(payload, 19);
     let mut bd_addr = crate::BdAddr([0; 6]);
@@ -903,6 +1337,17 @@ fn to_le_connection_complete(payload: &[u8]) -> Result<LeConnectionComplete, Err
     })
 }
 
+fn write_le_connection_complete(event: &LeConnectionComplete, payload: &mut [u8]) -> usize {
+    payload[0] = 0x01;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.conn_handle.0);
+    payload[4] = event.role.into();
+    event.peer_bd_addr.copy_into_slice(&mut payload[5..12]);
+    event.conn_interval.copy_into_slice(&mut payload[12..18]);
+    payload[18] = event.central_clock_accuracy.into();
+    19
+}
+
 /// The [LE Advertising Report](Event::LeAdvertisingReport) event indicates that a Bluetooth device
 /// or multiple Bluetooth devices have responded to an active scan or received some information
 /// during a passive scan. The Controller may queue these advertising reports and send information
@@ -1094,19 +1539,34 @@ impl TryFrom<u8> for AdvertisementEvent {
     }
 }
 
+impl From<AdvertisementEvent> for u8 {
+    fn from(value: AdvertisementEvent) -> u8 {
+        match value {
+            AdvertisementEvent::Advertisement => 0,
+            AdvertisementEvent::DirectAdvertisement => 1,
+            AdvertisementEvent::Scan => 2,
+            AdvertisementEvent::NonConnectableAdvertisement => 3,
+            AdvertisementEvent::ScanResponse => 4,
+        }
+    }
+}
+
 fn to_le_advertising_report(payload: &[u8]) -> Result<LeAdvertisingReport, Error> {
-    let mut check_iter = LeAdvertisingReportInnerIterator {
-        event_data: &payload[2..],
-        next_index: 0,
-    };
-    while (check_iter.next()?).is_some() {}
+    let report = borrowed::to_le_advertising_report(payload)?;
 
-    let data_len = payload.len() - 2;
+    let data_len = report.data.len();
     let mut data_buf = [0; MAX_ADVERTISING_REPORT_LEN];
-    data_buf[..data_len].copy_from_slice(&payload[2..]);
+    data_buf[..data_len].copy_from_slice(report.data);
     Ok(LeAdvertisingReport { data_len, data_buf })
 }
 
+fn write_le_advertising_report(event: &LeAdvertisingReport, payload: &mut [u8]) -> usize {
+    payload[0] = 0x02;
+    payload[1] = event.iter().count() as u8;
+    payload[2..2 + event.data_len].copy_from_slice(&event.data_buf[..event.data_len]);
+    2 + event.data_len
+}
+
 /// Indicates that the Controller process to update the connection has completed.
 ///
 /// On a peripheral, if no connection parameters are updated, then this event shall not
@@ -1148,6 +1608,17 @@ fn to_le_connection_update_complete(payload: &[u8]) -> Result<LeConnectionUpdate
     })
 }
 
+fn write_le_connection_update_complete(
+    event: &LeConnectionUpdateComplete,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x03;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.conn_handle.0);
+    event.conn_interval.copy_into_slice(&mut payload[4..10]);
+    10
+}
+
 /// Indicates the completion of the process of the Controller obtaining the features used on the
 /// connection and the features supported by the remote Bluetooth device specified by
 /// [`conn_handle`](LeReadRemoteUsedFeaturesComplete::conn_handle).
@@ -1182,6 +1653,17 @@ fn to_le_read_remote_used_features_complete(
     })
 }
 
+fn write_le_read_remote_used_features_complete(
+    event: &LeReadRemoteUsedFeaturesComplete,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x04;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.conn_handle.0);
+    LittleEndian::write_u64(&mut payload[4..], event.features.bits());
+    12
+}
+
 /// The [LE Long Term Key Request](Event::LeLongTermKeyRequest) event indicates that the master
 /// device is attempting to encrypt or re-encrypt the link and is requesting the Long Term Key from
 /// the Host. (See Vol 6, Part B, Section 5.1.3).
@@ -1208,6 +1690,14 @@ fn to_le_ltk_request(payload: &[u8]) -> Result<LeLongTermKeyRequest, Error> {
     })
 }
 
+fn write_le_ltk_request(event: &LeLongTermKeyRequest, payload: &mut [u8]) -> usize {
+    payload[0] = 0x05;
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    LittleEndian::write_u64(&mut payload[3..], event.random_value);
+    LittleEndian::write_u16(&mut payload[11..], event.encrypted_diversifier);
+    13
+}
+
 /// Indicates that either the maximum Payload length of a LL DATA PDU
 /// has changed or the maximum transmission time of packets which contain
 /// LL Data PDUs.
@@ -1248,10 +1738,22 @@ fn to_le_data_length_change_event(payload: &[u8]) -> Result<LeDataLengthChangeEv
     })
 }
 
+fn write_le_data_length_change_event(event: &LeDataLengthChangeEvent, payload: &mut [u8]) -> usize {
+    payload[0] = 0x07;
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+
+    LittleEndian::write_u16(&mut payload[3..], event.max_tx_octets);
+    LittleEndian::write_u16(&mut payload[5..], event.max_tx_time);
+
+    LittleEndian::write_u16(&mut payload[7..], event.max_rx_octets);
+    LittleEndian::write_u16(&mut payload[9..], event.max_rx_time);
+    11
+}
+
 /// PHY types supported by Bluetooth LE.
 ///
 /// See Vol 1, Part A, Section 3.2.2 of the spec.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Phy {
     /// The LE 1M PHY supports a datarate of 1 MBit/s.
@@ -1267,14 +1769,24 @@ impl TryFrom<u8> for Phy {
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
-            0 => Ok(Phy::Le1M),
-            1 => Ok(Phy::Le2M),
-            2 => Ok(Phy::LeCoded),
+            1 => Ok(Phy::Le1M),
+            2 => Ok(Phy::Le2M),
+            3 => Ok(Phy::LeCoded),
             other => Err(Error::BadPhy(other)),
         }
     }
 }
 
+impl From<Phy> for u8 {
+    fn from(value: Phy) -> u8 {
+        match value {
+            Phy::Le1M => 1,
+            Phy::Le2M => 2,
+            Phy::LeCoded => 3,
+        }
+    }
+}
+
 /// Indicates that the controller has changed the transmitter
 /// or receiver PHY in use.
 ///
@@ -1306,20 +1818,82 @@ fn to_le_phy_update_complete(payload: &[u8]) -> Result<LePhyUpdateComplete, Erro
     })
 }
 
-fn to_le_read_local_p256_public_key(payload: &[u8]) -> Result<[u8; 64], Error> {
-    require_len!(payload, 65);
+fn write_le_phy_update_complete(event: &LePhyUpdateComplete, payload: &mut [u8]) -> usize {
+    payload[0] = 0x0C;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.conn_handle.0);
+    payload[4] = event.tx_phy.into();
+    payload[5] = event.rx_phy.into();
+    6
+}
+
+/// Indicates that the controller has generated a new local P-256 key pair, in response to the
+/// [LE Read Local P-256 Public Key](crate::host::HostHci::le_read_local_p256_public_key) command.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.8 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeReadLocalP256PublicKeyComplete {
+    /// Did the key generation fail, and if so, how?
+    pub status: Status,
+    /// Local P-256 public key. Only valid if the status is [`Success`](Status::Success).
+    pub key: P256PublicKey,
+}
+
+fn to_le_read_local_p256_public_key(
+    payload: &[u8],
+) -> Result<LeReadLocalP256PublicKeyComplete, Error> {
+    require_len!(payload, 66);
 
     let mut key = [0; 64];
-    key.copy_from_slice(&payload[1..]);
-    Ok(key)
+    key.copy_from_slice(&payload[2..]);
+    Ok(LeReadLocalP256PublicKeyComplete {
+        status: payload[1].try_into().map_err(rewrap_bad_status)?,
+        key: P256PublicKey(key),
+    })
+}
+
+fn write_le_read_local_p256_public_key(
+    event: &LeReadLocalP256PublicKeyComplete,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x08;
+    payload[1] = event.status.into();
+    payload[2..66].copy_from_slice(&event.key.0);
+    66
+}
+
+/// Indicates that the controller has generated a Diffie Hellman key, in response to the
+/// [LE Generate DHKey](crate::host::HostHci::le_generate_dhkey) command.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.9 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeGenerateDHKeyComplete {
+    /// Did the key generation fail, and if so, how? The status is
+    /// [`InvalidParameters`](Status::InvalidParameters) if the remote public key is not on the
+    /// P-256 curve.
+    pub status: Status,
+    /// Diffie Hellman key, little-endian. Only valid if the status is [`Success`](Status::Success).
+    pub dh_key: [u8; 32],
 }
 
-fn to_le_generate_dhkey_complete(payload: &[u8]) -> Result<[u8; 32], Error> {
-    require_len!(payload, 33);
+fn to_le_generate_dhkey_complete(payload: &[u8]) -> Result<LeGenerateDHKeyComplete, Error> {
+    require_len!(payload, 34);
+
+    let mut dh_key = [0; 32];
+    dh_key.copy_from_slice(&payload[2..]);
+    Ok(LeGenerateDHKeyComplete {
+        status: payload[1].try_into().map_err(rewrap_bad_status)?,
+        dh_key,
+    })
+}
 
-    let mut key = [0; 32];
-    key.copy_from_slice(&payload[1..]);
-    Ok(key)
+fn write_le_generate_dhkey_complete(event: &LeGenerateDHKeyComplete, payload: &mut [u8]) -> usize {
+    payload[0] = 0x09;
+    payload[1] = event.status.into();
+    payload[2..34].copy_from_slice(&event.dh_key);
+    34
 }
 
 /// This event indicates to both of the Hosts forming the connection that a new connection has been created.
@@ -1413,3 +1987,2105 @@ fn to_le_enhanced_connection_complete(
         central_clock_accuracy: payload[30].try_into()?,
     })
 }
+
+fn write_le_enhanced_connection_complete(
+    event: &LeEnhancedConnectionComplete,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x0A;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.conn_handle.0);
+    payload[4] = event.role.into();
+    event.peer_bd_addr.copy_into_slice(&mut payload[5..12]);
+    payload[12..18].copy_from_slice(&event.local_resolvable_private_address.0);
+    payload[18..24].copy_from_slice(&event.peer_resolvable_private_address.0);
+    event.conn_interval.copy_into_slice(&mut payload[24..30]);
+    payload[30] = event.central_clock_accuracy.into();
+    31
+}
+
+/// The [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport) event indicates that
+/// one or more Bluetooth devices have responded to an active scan or have broadcast advertisements
+/// that were received during a passive scan. It reports both legacy and extended advertising
+/// PDUs.
+///
+/// This event shall only be generated if scanning was enabled using the
+/// [LE Set Extended Scan Enable](crate::host::HostHci::le_set_extended_scan_enable) command.
+///
+/// Advertising data that does not fit in one event is split across several reports from the same
+/// advertiser, each with the [`data_status`](LeExtendedAdvertisement::data_status) of all but the
+/// last one set to [`Incomplete`](AdvertisingDataStatus::Incomplete).
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.13 of the spec.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeExtendedAdvertisingReport {
+    data_len: usize,
+    data_buf: [u8; MAX_ADVERTISING_REPORT_LEN],
+}
+
+impl LeExtendedAdvertisingReport {
+    /// Returns an iterator over the advertisements from the event.
+    pub fn iter(&self) -> LeExtendedAdvertisingReportIterator<'_> {
+        LeExtendedAdvertisingReportIterator {
+            inner_iter: LeExtendedAdvertisingReportInnerIterator {
+                event_data: &self.data_buf[..self.data_len],
+                next_index: 0,
+            },
+        }
+    }
+}
+
+impl Debug for LeExtendedAdvertisingReport {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{{")?;
+        for report in self.iter() {
+            write!(f, "{:?}", report)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// Iterator over the individual advertisements in the
+/// [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport) event.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeExtendedAdvertisingReportIterator<'a> {
+    inner_iter: LeExtendedAdvertisingReportInnerIterator<'a>,
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct LeExtendedAdvertisingReportInnerIterator<'a> {
+    event_data: &'a [u8],
+    next_index: usize,
+}
+
+impl<'a> Iterator for LeExtendedAdvertisingReportIterator<'a> {
+    type Item = LeExtendedAdvertisement<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner_iter.next().unwrap()
+    }
+}
+
+// Length of a single report, without its data.
+const EXTENDED_ADVERTISEMENT_HEADER_LEN: usize = 24;
+
+impl<'a> LeExtendedAdvertisingReportInnerIterator<'a> {
+    fn next(&mut self) -> Result<Option<LeExtendedAdvertisement<'a>>, Error> {
+        if self.next_index >= self.event_data.len() {
+            return Ok(None);
+        }
+
+        let report = &self.event_data[self.next_index..];
+        if report.len() < EXTENDED_ADVERTISEMENT_HEADER_LEN {
+            return Err(Error::LeAdvertisementReportIncomplete);
+        }
+        let data_end = EXTENDED_ADVERTISEMENT_HEADER_LEN + report[23] as usize;
+        if report.len() < data_end {
+            return Err(Error::LeAdvertisementReportIncomplete);
+        }
+        self.next_index += data_end;
+
+        let event_type = LittleEndian::read_u16(&report[0..]);
+        let data_status = match (event_type >> 5) & 0b11 {
+            0b00 => AdvertisingDataStatus::Complete,
+            0b01 => AdvertisingDataStatus::Incomplete,
+            0b10 => AdvertisingDataStatus::Truncated,
+            _ => return Err(Error::BadLeExtendedAdvertisementType(event_type)),
+        };
+        let event_type = ExtendedAdvertisementEvent::from_bits(event_type & !(0b11 << 5))
+            .ok_or(Error::BadLeExtendedAdvertisementType(event_type))?;
+
+        Ok(Some(LeExtendedAdvertisement {
+            event_type,
+            data_status,
+            address: match report[2] {
+                0xFF => None,
+                addr_type => Some(to_peer_addr_type(addr_type, &report[3..9])?),
+            },
+            primary_phy: report[9].try_into()?,
+            secondary_phy: match report[10] {
+                0x00 => None,
+                phy => Some(phy.try_into()?),
+            },
+            sid: match report[11] {
+                0xFF => None,
+                sid => Some(sid),
+            },
+            tx_power: match u8::cast_signed(report[12]) {
+                127 => None,
+                value => Some(crate::Dbm(value)),
+            },
+            rssi: match u8::cast_signed(report[13]) {
+                127 => None,
+                value => Some(value),
+            },
+            periodic_adv_interval: match LittleEndian::read_u16(&report[14..]) {
+                0 => None,
+                value => Some(core::time::Duration::from_micros(1250) * u32::from(value)),
+            },
+            direct_address: if event_type.contains(ExtendedAdvertisementEvent::DIRECTED) {
+                Some(match report[16] {
+                    0xFE => DirectAddress::UnresolvedRandom(crate::BdAddr(
+                        report[17..23].try_into().unwrap(),
+                    )),
+                    addr_type => {
+                        DirectAddress::Address(to_peer_addr_type(addr_type, &report[17..23])?)
+                    }
+                })
+            } else {
+                None
+            },
+            data: &report[EXTENDED_ADVERTISEMENT_HEADER_LEN..data_end],
+        }))
+    }
+}
+
+fn to_peer_addr_type(addr_type: u8, addr: &[u8]) -> Result<crate::host::PeerAddrType, Error> {
+    let addr = crate::BdAddr(addr.try_into().unwrap());
+    match addr_type {
+        0x00 => Ok(crate::host::PeerAddrType::PublicDeviceAddress(addr)),
+        0x01 => Ok(crate::host::PeerAddrType::RandomDeviceAddress(addr)),
+        0x02 => Ok(crate::host::PeerAddrType::PublicIdentityAddress(addr)),
+        0x03 => Ok(crate::host::PeerAddrType::RandomIdentityAddress(addr)),
+        other => Err(Error::BadLeAddressType(other)),
+    }
+}
+
+/// A single advertising report returned by the
+/// [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport) event.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeExtendedAdvertisement<'a> {
+    /// Properties of the advertising event that was received.
+    pub event_type: ExtendedAdvertisementEvent,
+
+    /// Whether `data` completes the advertising data, or more is to come.
+    pub data_status: AdvertisingDataStatus,
+
+    /// Address of the advertising device. If the Controller resolved a Resolvable Private Address,
+    /// this is the identity address of the device. `None` for anonymous advertisements.
+    pub address: Option<crate::host::PeerAddrType>,
+
+    /// PHY used on the primary advertising channels.
+    pub primary_phy: Phy,
+
+    /// PHY used on the secondary advertising channels. `None` if no packets were received on the
+    /// secondary advertising channels.
+    pub secondary_phy: Option<Phy>,
+
+    /// Value of the Advertising SID subfield in the ADI field of the PDU. `None` if there is no
+    /// ADI field, as with legacy advertising.
+    pub sid: Option<u8>,
+
+    /// Power level at which the advertisement was transmitted. `None` if not available.
+    pub tx_power: Option<crate::Dbm>,
+
+    /// Received signal strength, from -127 dBm to 20 dBm. `None` if not available.
+    pub rssi: Option<i8>,
+
+    /// Interval of the periodic advertising that the advertiser also does. `None` if there is no
+    /// periodic advertising.
+    pub periodic_adv_interval: Option<core::time::Duration>,
+
+    /// Address the advertisement is directed to. `None` for undirected advertisements.
+    pub direct_address: Option<DirectAddress>,
+
+    /// Fragment of the advertising or scan response data formatted as defined in Vol 3, Part C,
+    /// Section 11 of the spec.
+    pub data: &'a [u8],
+}
+
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// Properties of an advertising event reported in an
+    /// [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport) event.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct ExtendedAdvertisementEvent : u16 {
+        /// Connectable advertising
+        const CONNECTABLE = 1 << 0;
+        /// Scannable advertising
+        const SCANNABLE = 1 << 1;
+        /// Directed advertising
+        const DIRECTED = 1 << 2;
+        /// Scan response
+        const SCAN_RESPONSE = 1 << 3;
+        /// Legacy advertising PDUs used
+        const LEGACY = 1 << 4;
+    }
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// Properties of an advertising event reported in an
+    /// [LE Extended Advertising Report](Event::LeExtendedAdvertisingReport) event.
+    pub struct ExtendedAdvertisementEvent : u16 {
+        /// Connectable advertising
+        const CONNECTABLE = 1 << 0;
+        /// Scannable advertising
+        const SCANNABLE = 1 << 1;
+        /// Directed advertising
+        const DIRECTED = 1 << 2;
+        /// Scan response
+        const SCAN_RESPONSE = 1 << 3;
+        /// Legacy advertising PDUs used
+        const LEGACY = 1 << 4;
+    }
+}
+
+/// Completeness of the data in an [`LeExtendedAdvertisement`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AdvertisingDataStatus {
+    /// The data is complete.
+    Complete,
+    /// The data is incomplete, and more data is to come in the next report.
+    Incomplete,
+    /// The data is incomplete, and no more data is to come: the Controller did not receive the
+    /// rest of it.
+    Truncated,
+}
+
+/// Address a directed advertisement is addressed to, as reported in an [`LeExtendedAdvertisement`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DirectAddress {
+    /// The target address. If the Controller resolved a Resolvable Private Address, this is the
+    /// identity address of the local device.
+    Address(crate::host::PeerAddrType),
+    /// A Resolvable Private Address that the Controller was unable to resolve.
+    UnresolvedRandom(crate::BdAddr),
+}
+
+fn to_le_extended_advertising_report(payload: &[u8]) -> Result<LeExtendedAdvertisingReport, Error> {
+    let report = borrowed::to_le_extended_advertising_report(payload)?;
+
+    let data_len = report.data.len();
+    let mut data_buf = [0; MAX_ADVERTISING_REPORT_LEN];
+    data_buf[..data_len].copy_from_slice(report.data);
+    Ok(LeExtendedAdvertisingReport { data_len, data_buf })
+}
+
+fn write_le_extended_advertising_report(
+    event: &LeExtendedAdvertisingReport,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x0D;
+    payload[1] = event.iter().count() as u8;
+    payload[2..2 + event.data_len].copy_from_slice(&event.data_buf[..event.data_len]);
+    2 + event.data_len
+}
+
+/// The [LE Directed Advertising Report](Event::LeDirectedAdvertisingReport) event indicates that
+/// directed advertisements were received where the advertiser is using a Resolvable Private
+/// Address for the target address that the Controller is unable to resolve, and the scan filter
+/// policy is [`AddressedToThisDevice`](crate::host::ScanFilterPolicy::AddressedToThisDevice) or
+/// [`WhiteListAddressedToThisDevice`](crate::host::ScanFilterPolicy::WhiteListAddressedToThisDevice).
+/// It is generated instead of the [LE Advertising Report](Event::LeAdvertisingReport) event for
+/// these advertisements.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.11 of the spec.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeDirectedAdvertisingReport {
+    num_reports: usize,
+    data_buf: [u8; LE_DIRECTED_ADVERTISING_REPORT_MAX_LEN],
+}
+
+const LE_DIRECTED_ADVERTISEMENT_LEN: usize = 16;
+
+// The maximum number of reports that fit in an HCI event packet, after the subevent code and the
+// number of reports.
+const LE_DIRECTED_ADVERTISING_REPORT_MAX_LEN: usize = 15 * LE_DIRECTED_ADVERTISEMENT_LEN;
+
+impl LeDirectedAdvertisingReport {
+    /// Returns an iterator over the advertisements from the event.
+    pub fn iter(&self) -> LeDirectedAdvertisingReportIterator<'_> {
+        LeDirectedAdvertisingReportIterator {
+            data: &self.data_buf[..self.num_reports * LE_DIRECTED_ADVERTISEMENT_LEN],
+        }
+    }
+}
+
+impl Debug for LeDirectedAdvertisingReport {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{{")?;
+        for report in self.iter() {
+            write!(f, "{:?}", report)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// Iterator over the individual advertisements in the
+/// [LE Directed Advertising Report](Event::LeDirectedAdvertisingReport) event.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeDirectedAdvertisingReportIterator<'a> {
+    data: &'a [u8],
+}
+
+impl Iterator for LeDirectedAdvertisingReportIterator<'_> {
+    type Item = LeDirectedAdvertisement;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let (report, rest) = self.data.split_at(LE_DIRECTED_ADVERTISEMENT_LEN);
+        self.data = rest;
+
+        // The reports were validated when the event was deserialized.
+        Some(to_le_directed_advertisement(report).unwrap())
+    }
+}
+
+/// A single advertising report returned by the
+/// [LE Directed Advertising Report](Event::LeDirectedAdvertisingReport) event.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeDirectedAdvertisement {
+    /// Address of the advertising device. If the Controller resolved a Resolvable Private Address,
+    /// this is the identity address of the device.
+    pub address: crate::host::PeerAddrType,
+
+    /// The Resolvable Private Address the advertisement is directed to, which the Controller was
+    /// unable to resolve.
+    pub direct_address: crate::BdAddr,
+
+    /// Received signal strength, from -127 dBm to 20 dBm. `None` if not available.
+    pub rssi: Option<i8>,
+}
+
+fn to_le_directed_advertisement(report: &[u8]) -> Result<LeDirectedAdvertisement, Error> {
+    // Only connectable directed advertising (ADV_DIRECT_IND) is reported.
+    if report[0] != 0x01 {
+        return Err(Error::BadLeAdvertisementType(report[0]));
+    }
+    // The direct address is always a random address.
+    if report[8] != 0x01 {
+        return Err(Error::BadLeAddressType(report[8]));
+    }
+
+    Ok(LeDirectedAdvertisement {
+        address: to_peer_addr_type(report[1], &report[2..8])?,
+        direct_address: crate::BdAddr(report[9..15].try_into().unwrap()),
+        rssi: match u8::cast_signed(report[15]) {
+            127 => None,
+            value => Some(value),
+        },
+    })
+}
+
+fn to_le_directed_advertising_report(payload: &[u8]) -> Result<LeDirectedAdvertisingReport, Error> {
+    require_len_at_least!(payload, 2);
+
+    let num_reports = payload[1] as usize;
+    let data_len = num_reports * LE_DIRECTED_ADVERTISEMENT_LEN;
+    require_len!(payload, 2 + data_len);
+    for report in payload[2..].chunks_exact(LE_DIRECTED_ADVERTISEMENT_LEN) {
+        to_le_directed_advertisement(report)?;
+    }
+
+    let mut data_buf = [0; LE_DIRECTED_ADVERTISING_REPORT_MAX_LEN];
+    data_buf[..data_len].copy_from_slice(&payload[2..]);
+    Ok(LeDirectedAdvertisingReport {
+        num_reports,
+        data_buf,
+    })
+}
+
+fn write_le_directed_advertising_report(
+    event: &LeDirectedAdvertisingReport,
+    payload: &mut [u8],
+) -> usize {
+    let data_len = event.num_reports * LE_DIRECTED_ADVERTISEMENT_LEN;
+    payload[0] = 0x0B;
+    payload[1] = event.num_reports as u8;
+    payload[2..2 + data_len].copy_from_slice(&event.data_buf[..data_len]);
+    2 + data_len
+}
+
+/// Indicates that the Controller has terminated advertising in an advertising set, either because
+/// its [duration](crate::types::extended_advertisement::AdvSet::duration) elapsed or its
+/// [maximum number of advertising events](crate::types::extended_advertisement::AdvSet::max_extended_adv_events)
+/// was reached, or because a connection was created.
+///
+/// This event is not generated when the Host disables advertising.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.18 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeAdvertisingSetTerminated {
+    /// [`Success`](Status::Success) if advertising ended because a connection was created,
+    /// [`AdvertisingTimeout`](Status::AdvertisingTimeout) if the duration elapsed, and
+    /// [`LimitReached`](Status::LimitReached) if the maximum number of advertising events was
+    /// reached.
+    pub status: Status,
+
+    /// Advertising set in which advertising has ended.
+    pub adv_handle: crate::AdvertisingHandle,
+
+    /// Connection that was created. Only valid if `status` is [`Success`](Status::Success).
+    pub conn_handle: ConnectionHandle,
+
+    /// Number of completed extended advertising events the Controller sent in the advertising
+    /// set. Only valid if a maximum number of advertising events was set.
+    pub num_completed_extended_adv_events: u8,
+}
+
+fn to_le_advertising_set_terminated(payload: &[u8]) -> Result<LeAdvertisingSetTerminated, Error> {
+    require_len!(payload, 6);
+
+    Ok(LeAdvertisingSetTerminated {
+        status: payload[1].try_into().map_err(rewrap_bad_status)?,
+        adv_handle: crate::AdvertisingHandle(payload[2]),
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&payload[3..])),
+        num_completed_extended_adv_events: payload[5],
+    })
+}
+
+fn write_le_advertising_set_terminated(
+    event: &LeAdvertisingSetTerminated,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x12;
+    payload[1] = event.status.into();
+    payload[2] = event.adv_handle.0;
+    LittleEndian::write_u16(&mut payload[3..], event.conn_handle.0);
+    payload[5] = event.num_completed_extended_adv_events;
+    6
+}
+
+/// Indicates that a scan request has been received by an advertiser, for an advertising set with
+/// [scan request notifications](crate::host::ExtendedAdvertisingParameters::scan_request_notification_enable)
+/// enabled.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.19 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeScanRequestReceived {
+    /// Advertising set for which the scan request was received.
+    pub adv_handle: crate::AdvertisingHandle,
+
+    /// Address of the scanning device. If the Controller resolved a Resolvable Private Address,
+    /// this is the identity address of the device.
+    pub scanner_address: crate::host::PeerAddrType,
+}
+
+fn to_le_scan_request_received(payload: &[u8]) -> Result<LeScanRequestReceived, Error> {
+    require_len!(payload, 9);
+
+    Ok(LeScanRequestReceived {
+        adv_handle: crate::AdvertisingHandle(payload[1]),
+        scanner_address: to_peer_addr_type(payload[2], &payload[3..9])?,
+    })
+}
+
+fn write_le_scan_request_received(event: &LeScanRequestReceived, payload: &mut [u8]) -> usize {
+    payload[0] = 0x13;
+    payload[1] = event.adv_handle.0;
+    event.scanner_address.copy_into_slice(&mut payload[2..9]);
+    9
+}
+
+/// Indicates which channel selection algorithm is used on a data channel connection. It is
+/// generated after a connection is created, following the
+/// [LE Connection Complete](Event::LeConnectionComplete) or
+/// [LE Enhanced Connection Complete](Event::LeEnhancedConnectionComplete) event.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.20 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeChannelSelectionAlgorithm {
+    /// Connection for which the algorithm is reported.
+    pub conn_handle: ConnectionHandle,
+
+    /// Channel selection algorithm used on the connection.
+    pub algorithm: ChannelSelectionAlgorithm,
+}
+
+/// Channel selection algorithms, as returned by the
+/// [LE Channel Selection Algorithm](Event::LeChannelSelectionAlgorithm) event.
+///
+/// See Vol 6, Part B, Section 4.5.8 of the spec.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ChannelSelectionAlgorithm {
+    /// LE Channel Selection Algorithm #1
+    Algorithm1,
+    /// LE Channel Selection Algorithm #2
+    Algorithm2,
+}
+
+impl TryFrom<u8> for ChannelSelectionAlgorithm {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ChannelSelectionAlgorithm::Algorithm1),
+            1 => Ok(ChannelSelectionAlgorithm::Algorithm2),
+            other => Err(Error::BadChannelSelectionAlgorithm(other)),
+        }
+    }
+}
+
+impl From<ChannelSelectionAlgorithm> for u8 {
+    fn from(value: ChannelSelectionAlgorithm) -> u8 {
+        match value {
+            ChannelSelectionAlgorithm::Algorithm1 => 0,
+            ChannelSelectionAlgorithm::Algorithm2 => 1,
+        }
+    }
+}
+
+fn to_le_channel_selection_algorithm(payload: &[u8]) -> Result<LeChannelSelectionAlgorithm, Error> {
+    require_len!(payload, 4);
+
+    Ok(LeChannelSelectionAlgorithm {
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&payload[1..])),
+        algorithm: payload[3].try_into()?,
+    })
+}
+
+fn write_le_channel_selection_algorithm(
+    event: &LeChannelSelectionAlgorithm,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x14;
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    payload[3] = event.algorithm.into();
+    4
+}
+
+/// Indicates that the Controller has received the first periodic advertising packet from an
+/// advertiser after the
+/// [LE Periodic Advertising Create Sync](crate::host::HostHci::le_periodic_advertising_create_sync)
+/// command has been sent, or that the command was cancelled or failed.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.14 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LePeriodicAdvertisingSyncEstablished {
+    /// Did the synchronization succeed?
+    pub status: Status,
+
+    /// Identifies the periodic advertising train in later commands and events.
+    pub sync_handle: crate::SyncHandle,
+
+    /// Value of the Advertising SID subfield in the ADI field of the PDU.
+    pub adv_sid: u8,
+
+    /// Address of the advertiser. If the Controller resolved a Resolvable Private Address, this
+    /// is the identity address of the advertiser.
+    pub adv_address: crate::host::PeerAddrType,
+
+    /// PHY used for the periodic advertising. `None` if the status is not
+    /// [`Success`](Status::Success).
+    pub adv_phy: Option<Phy>,
+
+    /// Periodic advertising interval, with a resolution of 1.25 ms.
+    pub periodic_adv_interval: core::time::Duration,
+
+    /// Clock accuracy of the advertiser. `None` if the status is not [`Success`](Status::Success).
+    pub adv_clock_accuracy: Option<CentralClockAccuracy>,
+}
+
+fn to_le_periodic_advertising_sync_established(
+    payload: &[u8],
+) -> Result<LePeriodicAdvertisingSyncEstablished, Error> {
+    require_len!(payload, 16);
+
+    let status = payload[1].try_into().map_err(rewrap_bad_status)?;
+    let synced = status == Status::Success;
+    Ok(LePeriodicAdvertisingSyncEstablished {
+        status,
+        sync_handle: crate::SyncHandle(LittleEndian::read_u16(&payload[2..])),
+        adv_sid: payload[4],
+        adv_address: to_peer_addr_type(payload[5], &payload[6..12])?,
+        adv_phy: synced.then(|| payload[12].try_into()).transpose()?,
+        periodic_adv_interval: core::time::Duration::from_micros(1250)
+            * u32::from(LittleEndian::read_u16(&payload[13..])),
+        adv_clock_accuracy: synced.then(|| payload[15].try_into()).transpose()?,
+    })
+}
+
+fn write_le_periodic_advertising_sync_established(
+    event: &LePeriodicAdvertisingSyncEstablished,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x0E;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.sync_handle.0);
+    payload[4] = event.adv_sid;
+    event.adv_address.copy_into_slice(&mut payload[5..12]);
+    payload[12] = event.adv_phy.map_or(0, u8::from);
+    LittleEndian::write_u16(
+        &mut payload[13..],
+        (event.periodic_adv_interval.as_micros() / 1250) as u16,
+    );
+    payload[15] = event.adv_clock_accuracy.map_or(0, u8::from);
+    16
+}
+
+/// Indicates that the Controller has received a periodic advertising packet of a periodic
+/// advertising train it is synchronized with.
+///
+/// Advertising data that does not fit in one event is split across several reports, each with
+/// the [`data_status`](LePeriodicAdvertisingReport::data_status) of all but the last one set to
+/// [`Incomplete`](AdvertisingDataStatus::Incomplete).
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.15 of the spec.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LePeriodicAdvertisingReport {
+    /// Periodic advertising train the packet was received from.
+    pub sync_handle: crate::SyncHandle,
+
+    /// Transmit power of the advertiser, from -127 dBm to 20 dBm. `None` if not available.
+    pub tx_power: Option<crate::Dbm>,
+
+    /// Received signal strength, from -127 dBm to 20 dBm. `None` if not available.
+    pub rssi: Option<i8>,
+
+    /// Type of Constant Tone Extension in the periodic advertising packet. `None` if there is no
+    /// Constant Tone Extension.
+    pub cte_type: Option<CteType>,
+
+    /// Completeness of the data.
+    pub data_status: AdvertisingDataStatus,
+
+    data_len: usize,
+    data_buf: [u8; MAX_PERIODIC_ADVERTISING_DATA_LEN],
+}
+
+// The maximum length of the data that fits in an HCI event packet, after the subevent code and
+// the other parameters of the report.
+const MAX_PERIODIC_ADVERTISING_DATA_LEN: usize = 247;
+
+impl LePeriodicAdvertisingReport {
+    /// Returns the periodic advertising data, formatted as defined in Vol 3, Part C, Section 11 of
+    /// the spec.
+    pub fn data(&self) -> &[u8] {
+        &self.data_buf[..self.data_len]
+    }
+}
+
+impl Debug for LePeriodicAdvertisingReport {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("LePeriodicAdvertisingReport")
+            .field("sync_handle", &self.sync_handle)
+            .field("tx_power", &self.tx_power)
+            .field("rssi", &self.rssi)
+            .field("cte_type", &self.cte_type)
+            .field("data_status", &self.data_status)
+            .field("data", &self.data())
+            .finish()
+    }
+}
+
+/// Types of Constant Tone Extension.
+///
+/// See Vol 6, Part B, Section 2.5.1 of the spec.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CteType {
+    /// AoA Constant Tone Extension
+    AoA,
+    /// AoD Constant Tone Extension with 1 μs slots
+    AoD1us,
+    /// AoD Constant Tone Extension with 2 μs slots
+    AoD2us,
+}
+
+impl TryFrom<u8> for CteType {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(CteType::AoA),
+            1 => Ok(CteType::AoD1us),
+            2 => Ok(CteType::AoD2us),
+            other => Err(Error::BadCteType(other)),
+        }
+    }
+}
+
+impl From<CteType> for u8 {
+    fn from(value: CteType) -> u8 {
+        match value {
+            CteType::AoA => 0,
+            CteType::AoD1us => 1,
+            CteType::AoD2us => 2,
+        }
+    }
+}
+
+fn to_le_periodic_advertising_report(payload: &[u8]) -> Result<LePeriodicAdvertisingReport, Error> {
+    let report = borrowed::to_le_periodic_advertising_report(payload)?;
+
+    let data_len = report.data.len();
+    let mut data_buf = [0; MAX_PERIODIC_ADVERTISING_DATA_LEN];
+    data_buf[..data_len].copy_from_slice(report.data);
+    Ok(LePeriodicAdvertisingReport {
+        sync_handle: report.sync_handle,
+        tx_power: report.tx_power,
+        rssi: report.rssi,
+        cte_type: report.cte_type,
+        data_status: report.data_status,
+        data_len,
+        data_buf,
+    })
+}
+
+fn write_le_periodic_advertising_report(
+    event: &LePeriodicAdvertisingReport,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x0F;
+    LittleEndian::write_u16(&mut payload[1..], event.sync_handle.0);
+    payload[3] = i8::cast_unsigned(event.tx_power.map_or(127, |power| power.0));
+    payload[4] = event.rssi.unwrap_or(127) as u8;
+    payload[5] = event.cte_type.map_or(0xFF, u8::from);
+    payload[6] = match event.data_status {
+        AdvertisingDataStatus::Complete => 0,
+        AdvertisingDataStatus::Incomplete => 1,
+        AdvertisingDataStatus::Truncated => 2,
+    };
+    payload[7] = event.data_len as u8;
+    payload[8..8 + event.data_len].copy_from_slice(event.data());
+    8 + event.data_len
+}
+
+/// Durations of the switching and sampling slots of a Constant Tone Extension.
+///
+/// See Vol 6, Part B, Section 2.5.4 of the spec.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SlotDuration {
+    /// Switching and sampling slots of 1 μs
+    Slot1us,
+    /// Switching and sampling slots of 2 μs
+    Slot2us,
+}
+
+impl TryFrom<u8> for SlotDuration {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(SlotDuration::Slot1us),
+            2 => Ok(SlotDuration::Slot2us),
+            other => Err(Error::BadSlotDuration(other)),
+        }
+    }
+}
+
+impl From<SlotDuration> for u8 {
+    fn from(value: SlotDuration) -> u8 {
+        match value {
+            SlotDuration::Slot1us => 1,
+            SlotDuration::Slot2us => 2,
+        }
+    }
+}
+
+/// Status of the packet whose Constant Tone Extension was sampled, reported in the
+/// [LE Connectionless IQ Report](Event::LeConnectionlessIqReport) and
+/// [LE Connection IQ Report](Event::LeConnectionIqReport) events.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum IqPacketStatus {
+    /// The CRC was correct.
+    CrcCorrect,
+    /// The CRC was incorrect, and the Length and CTETime fields of the packet were used to
+    /// determine the sampling points.
+    CrcIncorrectUsedLength,
+    /// The CRC was incorrect, but the Controller determined the position and length of the
+    /// Constant Tone Extension in some other way.
+    CrcIncorrectUsedOther,
+    /// The Controller did not have sufficient resources to sample the Constant Tone Extension. The
+    /// channel index, CTE type and slot durations are not valid, and no samples are reported.
+    InsufficientResources,
+}
+
+impl TryFrom<u8> for IqPacketStatus {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x00 => Ok(IqPacketStatus::CrcCorrect),
+            0x01 => Ok(IqPacketStatus::CrcIncorrectUsedLength),
+            0x02 => Ok(IqPacketStatus::CrcIncorrectUsedOther),
+            0xFF => Ok(IqPacketStatus::InsufficientResources),
+            other => Err(Error::BadIqPacketStatus(other)),
+        }
+    }
+}
+
+impl From<IqPacketStatus> for u8 {
+    fn from(value: IqPacketStatus) -> u8 {
+        match value {
+            IqPacketStatus::CrcCorrect => 0x00,
+            IqPacketStatus::CrcIncorrectUsedLength => 0x01,
+            IqPacketStatus::CrcIncorrectUsedOther => 0x02,
+            IqPacketStatus::InsufficientResources => 0xFF,
+        }
+    }
+}
+
+/// An IQ sample taken from a Constant Tone Extension.
+///
+/// Each component is -128 (0x80) if no valid sample was available.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct IqSample {
+    /// In-phase component of the sample.
+    pub i: i8,
+
+    /// Quadrature-phase component of the sample.
+    pub q: i8,
+}
+
+// The maximum number of IQ samples in an IQ report event.
+const MAX_IQ_SAMPLE_COUNT: usize = 0x52;
+
+// The maximum number of bytes of IQ samples in an event.
+const MAX_IQ_SAMPLES_LEN: usize = 2 * MAX_IQ_SAMPLE_COUNT;
+
+// Validates the number of IQ samples in an IQ report event: either none, or from 9 to 82.
+fn validate_iq_sample_count(sample_count: u8) -> Result<(), Error> {
+    const SAMPLE_COUNT_RANGE: core::ops::RangeInclusive<usize> = 0x09..=MAX_IQ_SAMPLE_COUNT;
+
+    if sample_count != 0 && !SAMPLE_COUNT_RANGE.contains(&usize::from(sample_count)) {
+        return Err(Error::BadIqSampleCount(sample_count));
+    }
+
+    Ok(())
+}
+
+/// Iterator over the IQ samples from the [`LeConnectionlessIqReport`] and
+/// [`LeConnectionIqReport`] events.
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct IqSampleIterator<'a> {
+    data: &'a [u8],
+    next_index: usize,
+}
+
+impl Iterator for IqSampleIterator<'_> {
+    type Item = IqSample;
+
+    /// Returns the next IQ sample from the event.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_index >= self.data.len() {
+            return None;
+        }
+
+        let index = self.next_index;
+        self.next_index += 2;
+        Some(IqSample {
+            i: u8::cast_signed(self.data[index]),
+            q: u8::cast_signed(self.data[index + 1]),
+        })
+    }
+}
+
+impl Debug for IqSampleIterator<'_> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// Reports the IQ samples taken from the Constant Tone Extension of a periodic advertising packet,
+/// after connectionless IQ sampling has been enabled with the
+/// [`le_set_connectionless_iq_sampling_enable`](crate::host::HostHci::le_set_connectionless_iq_sampling_enable)
+/// command.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.21 of the spec.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeConnectionlessIqReport {
+    /// Periodic advertising train the packet was received from. 0x0FFF if the packet was received
+    /// in a receiver test.
+    pub sync_handle: crate::SyncHandle,
+
+    /// Index of the channel the packet was received on. `None` if the packet status is
+    /// [`InsufficientResources`](IqPacketStatus::InsufficientResources).
+    pub channel_index: Option<u8>,
+
+    /// Received signal strength, with a resolution of 0.1 dBm.
+    pub rssi: i16,
+
+    /// Identifies the antenna the received signal strength was measured on.
+    pub rssi_antenna_id: u8,
+
+    /// Type of the sampled Constant Tone Extension. `None` if the packet status is
+    /// [`InsufficientResources`](IqPacketStatus::InsufficientResources).
+    pub cte_type: Option<CteType>,
+
+    /// Durations of the switching and sampling slots. `None` if the packet status is
+    /// [`InsufficientResources`](IqPacketStatus::InsufficientResources).
+    pub slot_duration: Option<SlotDuration>,
+
+    /// Status of the received packet.
+    pub packet_status: IqPacketStatus,
+
+    /// Value of the paEventCounter of the received packet.
+    pub periodic_event_counter: u16,
+
+    sample_count: usize,
+    sample_buf: [u8; MAX_IQ_SAMPLES_LEN],
+}
+
+impl LeConnectionlessIqReport {
+    /// Returns an iterator over the IQ samples.
+    pub fn iq_samples(&self) -> IqSampleIterator<'_> {
+        IqSampleIterator {
+            data: &self.sample_buf[..2 * self.sample_count],
+            next_index: 0,
+        }
+    }
+}
+
+impl Debug for LeConnectionlessIqReport {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("LeConnectionlessIqReport")
+            .field("sync_handle", &self.sync_handle)
+            .field("channel_index", &self.channel_index)
+            .field("rssi", &self.rssi)
+            .field("rssi_antenna_id", &self.rssi_antenna_id)
+            .field("cte_type", &self.cte_type)
+            .field("slot_duration", &self.slot_duration)
+            .field("packet_status", &self.packet_status)
+            .field("periodic_event_counter", &self.periodic_event_counter)
+            .field("iq_samples", &self.iq_samples())
+            .finish()
+    }
+}
+
+fn to_le_connectionless_iq_report(payload: &[u8]) -> Result<LeConnectionlessIqReport, Error> {
+    let report = borrowed::to_le_connectionless_iq_report(payload)?;
+
+    let mut sample_buf = [0; MAX_IQ_SAMPLES_LEN];
+    sample_buf[..report.iq_sample_data.len()].copy_from_slice(report.iq_sample_data);
+    Ok(LeConnectionlessIqReport {
+        sync_handle: report.sync_handle,
+        channel_index: report.channel_index,
+        rssi: report.rssi,
+        rssi_antenna_id: report.rssi_antenna_id,
+        cte_type: report.cte_type,
+        slot_duration: report.slot_duration,
+        packet_status: report.packet_status,
+        periodic_event_counter: report.periodic_event_counter,
+        sample_count: report.iq_sample_data.len() / 2,
+        sample_buf,
+    })
+}
+
+fn write_le_connectionless_iq_report(
+    event: &LeConnectionlessIqReport,
+    payload: &mut [u8],
+) -> usize {
+    let data_len = 2 * event.sample_count;
+    payload[0] = 0x15;
+    LittleEndian::write_u16(&mut payload[1..], event.sync_handle.0);
+    payload[3] = event.channel_index.unwrap_or(0);
+    LittleEndian::write_i16(&mut payload[4..], event.rssi);
+    payload[6] = event.rssi_antenna_id;
+    payload[7] = event.cte_type.map_or(0, u8::from);
+    payload[8] = event.slot_duration.map_or(0, u8::from);
+    payload[9] = event.packet_status.into();
+    LittleEndian::write_u16(&mut payload[10..], event.periodic_event_counter);
+    payload[12] = event.sample_count as u8;
+    payload[13..13 + data_len].copy_from_slice(&event.sample_buf[..data_len]);
+    13 + data_len
+}
+
+/// Reports the IQ samples taken from the Constant Tone Extension of a data packet received on a
+/// connection, after IQ sampling has been enabled with the
+/// [`le_set_connection_cte_receive_parameters`](crate::host::HostHci::le_set_connection_cte_receive_parameters)
+/// command.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.22 of the spec.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeConnectionIqReport {
+    /// Connection the packet was received on.
+    pub conn_handle: ConnectionHandle,
+
+    /// PHY the packet was received on.
+    pub rx_phy: Phy,
+
+    /// Index of the data channel the packet was received on. `None` if the packet status is
+    /// [`InsufficientResources`](IqPacketStatus::InsufficientResources).
+    pub data_channel_index: Option<u8>,
+
+    /// Received signal strength, with a resolution of 0.1 dBm.
+    pub rssi: i16,
+
+    /// Identifies the antenna the received signal strength was measured on.
+    pub rssi_antenna_id: u8,
+
+    /// Type of the sampled Constant Tone Extension. `None` if the packet status is
+    /// [`InsufficientResources`](IqPacketStatus::InsufficientResources).
+    pub cte_type: Option<CteType>,
+
+    /// Durations of the switching and sampling slots. `None` if the packet status is
+    /// [`InsufficientResources`](IqPacketStatus::InsufficientResources).
+    pub slot_duration: Option<SlotDuration>,
+
+    /// Status of the received packet.
+    pub packet_status: IqPacketStatus,
+
+    /// Value of the connEventCounter of the connection event the packet was received in.
+    pub connection_event_counter: u16,
+
+    sample_count: usize,
+    sample_buf: [u8; MAX_IQ_SAMPLES_LEN],
+}
+
+impl LeConnectionIqReport {
+    /// Returns an iterator over the IQ samples.
+    pub fn iq_samples(&self) -> IqSampleIterator<'_> {
+        IqSampleIterator {
+            data: &self.sample_buf[..2 * self.sample_count],
+            next_index: 0,
+        }
+    }
+}
+
+impl Debug for LeConnectionIqReport {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("LeConnectionIqReport")
+            .field("conn_handle", &self.conn_handle)
+            .field("rx_phy", &self.rx_phy)
+            .field("data_channel_index", &self.data_channel_index)
+            .field("rssi", &self.rssi)
+            .field("rssi_antenna_id", &self.rssi_antenna_id)
+            .field("cte_type", &self.cte_type)
+            .field("slot_duration", &self.slot_duration)
+            .field("packet_status", &self.packet_status)
+            .field("connection_event_counter", &self.connection_event_counter)
+            .field("iq_samples", &self.iq_samples())
+            .finish()
+    }
+}
+
+fn to_le_connection_iq_report(payload: &[u8]) -> Result<LeConnectionIqReport, Error> {
+    let report = borrowed::to_le_connection_iq_report(payload)?;
+
+    let mut sample_buf = [0; MAX_IQ_SAMPLES_LEN];
+    sample_buf[..report.iq_sample_data.len()].copy_from_slice(report.iq_sample_data);
+    Ok(LeConnectionIqReport {
+        conn_handle: report.conn_handle,
+        rx_phy: report.rx_phy,
+        data_channel_index: report.data_channel_index,
+        rssi: report.rssi,
+        rssi_antenna_id: report.rssi_antenna_id,
+        cte_type: report.cte_type,
+        slot_duration: report.slot_duration,
+        packet_status: report.packet_status,
+        connection_event_counter: report.connection_event_counter,
+        sample_count: report.iq_sample_data.len() / 2,
+        sample_buf,
+    })
+}
+
+fn write_le_connection_iq_report(event: &LeConnectionIqReport, payload: &mut [u8]) -> usize {
+    let data_len = 2 * event.sample_count;
+    payload[0] = 0x16;
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    payload[3] = event.rx_phy.into();
+    payload[4] = event.data_channel_index.unwrap_or(0);
+    LittleEndian::write_i16(&mut payload[5..], event.rssi);
+    payload[7] = event.rssi_antenna_id;
+    payload[8] = event.cte_type.map_or(0, u8::from);
+    payload[9] = event.slot_duration.map_or(0, u8::from);
+    payload[10] = event.packet_status.into();
+    LittleEndian::write_u16(&mut payload[11..], event.connection_event_counter);
+    payload[13] = event.sample_count as u8;
+    payload[14..14 + data_len].copy_from_slice(&event.sample_buf[..data_len]);
+    14 + data_len
+}
+
+/// Indicates that a Constant Tone Extension requested with the
+/// [`le_connection_cte_request_enable`](crate::host::HostHci::le_connection_cte_request_enable)
+/// command was not received from the peer device.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.23 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeCteRequestFailed {
+    /// Why did the request fail? [`Success`](Status::Success) if the peer device responded
+    /// without a Constant Tone Extension.
+    pub status: Status,
+
+    /// Connection the Constant Tone Extension was requested on.
+    pub conn_handle: ConnectionHandle,
+}
+
+fn to_le_cte_request_failed(payload: &[u8]) -> Result<LeCteRequestFailed, Error> {
+    require_len!(payload, 4);
+
+    Ok(LeCteRequestFailed {
+        status: payload[1].try_into().map_err(rewrap_bad_status)?,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&payload[2..])),
+    })
+}
+
+fn write_le_cte_request_failed(event: &LeCteRequestFailed, payload: &mut [u8]) -> usize {
+    payload[0] = 0x17;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.conn_handle.0);
+    4
+}
+
+/// Reports that the Controller has received periodic advertising synchronization information from
+/// a connected device, and either successfully synchronized to the periodic advertising train or
+/// timed out while attempting to do so.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.24 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LePeriodicAdvertisingSyncTransferReceived {
+    /// Did the synchronization succeed?
+    pub status: Status,
+
+    /// Connection on which the synchronization information was received.
+    pub conn_handle: ConnectionHandle,
+
+    /// Value provided by the Host of the peer device.
+    pub service_data: u16,
+
+    /// Identifies the periodic advertising train in later commands and events.
+    pub sync_handle: crate::SyncHandle,
+
+    /// Value of the Advertising SID subfield in the ADI field of the PDU.
+    pub adv_sid: u8,
+
+    /// Address of the advertiser. If the Controller resolved a Resolvable Private Address, this
+    /// is the identity address of the advertiser.
+    pub adv_address: crate::host::PeerAddrType,
+
+    /// PHY used for the periodic advertising. `None` if the status is not
+    /// [`Success`](Status::Success).
+    pub adv_phy: Option<Phy>,
+
+    /// Periodic advertising interval, with a resolution of 1.25 ms.
+    pub periodic_adv_interval: core::time::Duration,
+
+    /// Clock accuracy of the advertiser. `None` if the status is not [`Success`](Status::Success).
+    pub adv_clock_accuracy: Option<CentralClockAccuracy>,
+}
+
+fn to_le_periodic_advertising_sync_transfer_received(
+    payload: &[u8],
+) -> Result<LePeriodicAdvertisingSyncTransferReceived, Error> {
+    require_len!(payload, 20);
+
+    let status = payload[1].try_into().map_err(rewrap_bad_status)?;
+    let synced = status == Status::Success;
+    Ok(LePeriodicAdvertisingSyncTransferReceived {
+        status,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&payload[2..])),
+        service_data: LittleEndian::read_u16(&payload[4..]),
+        sync_handle: crate::SyncHandle(LittleEndian::read_u16(&payload[6..])),
+        adv_sid: payload[8],
+        adv_address: to_peer_addr_type(payload[9], &payload[10..16])?,
+        adv_phy: synced.then(|| payload[16].try_into()).transpose()?,
+        periodic_adv_interval: core::time::Duration::from_micros(1250)
+            * u32::from(LittleEndian::read_u16(&payload[17..])),
+        adv_clock_accuracy: synced.then(|| payload[19].try_into()).transpose()?,
+    })
+}
+
+fn write_le_periodic_advertising_sync_transfer_received(
+    event: &LePeriodicAdvertisingSyncTransferReceived,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x18;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.conn_handle.0);
+    LittleEndian::write_u16(&mut payload[4..], event.service_data);
+    LittleEndian::write_u16(&mut payload[6..], event.sync_handle.0);
+    payload[8] = event.adv_sid;
+    event.adv_address.copy_into_slice(&mut payload[9..16]);
+    payload[16] = event.adv_phy.map_or(0, u8::from);
+    LittleEndian::write_u16(
+        &mut payload[17..],
+        (event.periodic_adv_interval.as_micros() / 1250) as u16,
+    );
+    payload[19] = event.adv_clock_accuracy.map_or(0, u8::from);
+    20
+}
+
+/// Indicates that a CIS has been established, or that the Controller failed to establish it. It
+/// is generated on both the Central and the Peripheral.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.25 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeCisEstablished {
+    /// Was the CIS established?
+    pub status: Status,
+
+    /// Connection handle of the CIS.
+    pub conn_handle: ConnectionHandle,
+
+    /// Maximum time for transmission of the PDUs of all the CISes in a CIG event, with a
+    /// resolution of 1 µs.
+    pub cig_sync_delay: core::time::Duration,
+
+    /// Maximum time for transmission of the PDUs of this CIS in a CIG event, with a resolution of
+    /// 1 µs.
+    pub cis_sync_delay: core::time::Duration,
+
+    /// Actual transport latency from the Central to the Peripheral, with a resolution of 1 µs.
+    pub transport_latency_c_to_p: core::time::Duration,
+
+    /// Actual transport latency from the Peripheral to the Central, with a resolution of 1 µs.
+    pub transport_latency_p_to_c: core::time::Duration,
+
+    /// PHY used from the Central to the Peripheral. `None` if the status is not
+    /// [`Success`](Status::Success).
+    pub phy_c_to_p: Option<Phy>,
+
+    /// PHY used from the Peripheral to the Central. `None` if the status is not
+    /// [`Success`](Status::Success).
+    pub phy_p_to_c: Option<Phy>,
+
+    /// Maximum number of subevents in each CIS event.
+    pub nse: u8,
+
+    /// Burst number from the Central to the Peripheral.
+    pub bn_c_to_p: u8,
+
+    /// Burst number from the Peripheral to the Central.
+    pub bn_p_to_c: u8,
+
+    /// Flush timeout, in multiples of the ISO interval, from the Central to the Peripheral.
+    pub ft_c_to_p: u8,
+
+    /// Flush timeout, in multiples of the ISO interval, from the Peripheral to the Central.
+    pub ft_p_to_c: u8,
+
+    /// Maximum size of the payload of a CIS data PDU from the Central.
+    pub max_pdu_c_to_p: u16,
+
+    /// Maximum size of the payload of a CIS data PDU from the Peripheral.
+    pub max_pdu_p_to_c: u16,
+
+    /// Time between consecutive CIS anchor points, with a resolution of 1.25 ms.
+    pub iso_interval: core::time::Duration,
+}
+
+fn to_le_cis_established(payload: &[u8]) -> Result<LeCisEstablished, Error> {
+    require_len!(payload, 29);
+
+    let status = payload[1].try_into().map_err(rewrap_bad_status)?;
+    let established = status == Status::Success;
+    Ok(LeCisEstablished {
+        status,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&payload[2..])),
+        cig_sync_delay: to_duration_us(&payload[4..]),
+        cis_sync_delay: to_duration_us(&payload[7..]),
+        transport_latency_c_to_p: to_duration_us(&payload[10..]),
+        transport_latency_p_to_c: to_duration_us(&payload[13..]),
+        phy_c_to_p: established.then(|| payload[16].try_into()).transpose()?,
+        phy_p_to_c: established.then(|| payload[17].try_into()).transpose()?,
+        nse: payload[18],
+        bn_c_to_p: payload[19],
+        bn_p_to_c: payload[20],
+        ft_c_to_p: payload[21],
+        ft_p_to_c: payload[22],
+        max_pdu_c_to_p: LittleEndian::read_u16(&payload[23..]),
+        max_pdu_p_to_c: LittleEndian::read_u16(&payload[25..]),
+        iso_interval: to_iso_interval(&payload[27..]),
+    })
+}
+
+// Deserializes a 3-byte duration with a resolution of 1 µs.
+fn to_duration_us(bytes: &[u8]) -> core::time::Duration {
+    core::time::Duration::from_micros(u64::from(LittleEndian::read_u24(bytes)))
+}
+
+// Deserializes a 2-byte ISO interval, with a resolution of 1.25 ms.
+fn to_iso_interval(bytes: &[u8]) -> core::time::Duration {
+    core::time::Duration::from_micros(1250) * u32::from(LittleEndian::read_u16(bytes))
+}
+
+fn write_le_cis_established(event: &LeCisEstablished, payload: &mut [u8]) -> usize {
+    payload[0] = 0x19;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.conn_handle.0);
+    LittleEndian::write_u24(&mut payload[4..], event.cig_sync_delay.as_micros() as u32);
+    LittleEndian::write_u24(&mut payload[7..], event.cis_sync_delay.as_micros() as u32);
+    LittleEndian::write_u24(
+        &mut payload[10..],
+        event.transport_latency_c_to_p.as_micros() as u32,
+    );
+    LittleEndian::write_u24(
+        &mut payload[13..],
+        event.transport_latency_p_to_c.as_micros() as u32,
+    );
+    payload[16] = event.phy_c_to_p.map_or(0, u8::from);
+    payload[17] = event.phy_p_to_c.map_or(0, u8::from);
+    payload[18] = event.nse;
+    payload[19] = event.bn_c_to_p;
+    payload[20] = event.bn_p_to_c;
+    payload[21] = event.ft_c_to_p;
+    payload[22] = event.ft_p_to_c;
+    LittleEndian::write_u16(&mut payload[23..], event.max_pdu_c_to_p);
+    LittleEndian::write_u16(&mut payload[25..], event.max_pdu_p_to_c);
+    LittleEndian::write_u16(
+        &mut payload[27..],
+        (event.iso_interval.as_micros() / 1250) as u16,
+    );
+    29
+}
+
+/// Indicates that the Controller has received a request from the Central to create a CIS. The
+/// Host shall answer with the
+/// [`le_accept_cis_request`](crate::host::HostHci::le_accept_cis_request) or
+/// [`le_reject_cis_request`](crate::host::HostHci::le_reject_cis_request) command.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.26 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeCisRequest {
+    /// Connection handle of the ACL connection with the Central.
+    pub acl_conn_handle: ConnectionHandle,
+
+    /// Connection handle of the requested CIS.
+    pub cis_conn_handle: ConnectionHandle,
+
+    /// Identifies the CIG that the CIS belongs to.
+    pub cig_id: u8,
+
+    /// Identifies the CIS in the CIG.
+    pub cis_id: u8,
+}
+
+fn to_le_cis_request(payload: &[u8]) -> Result<LeCisRequest, Error> {
+    require_len!(payload, 7);
+
+    Ok(LeCisRequest {
+        acl_conn_handle: ConnectionHandle(LittleEndian::read_u16(&payload[1..])),
+        cis_conn_handle: ConnectionHandle(LittleEndian::read_u16(&payload[3..])),
+        cig_id: payload[5],
+        cis_id: payload[6],
+    })
+}
+
+fn write_le_cis_request(event: &LeCisRequest, payload: &mut [u8]) -> usize {
+    payload[0] = 0x1A;
+    LittleEndian::write_u16(&mut payload[1..], event.acl_conn_handle.0);
+    LittleEndian::write_u16(&mut payload[3..], event.cis_conn_handle.0);
+    payload[5] = event.cig_id;
+    payload[6] = event.cis_id;
+    7
+}
+
+// The maximum number of BISes in a BIG.
+const MAX_BIS_COUNT: usize = 0x1F;
+
+// The maximum number of bytes of BIS connection handles in an event.
+const MAX_BIS_HANDLES_LEN: usize = 2 * MAX_BIS_COUNT;
+
+/// Iterator over the BIS connection handles from the
+/// [`LeCreateBigComplete`] and [`LeBigSyncEstablished`] events.
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BisHandleIterator<'a> {
+    data: &'a [u8],
+    next_index: usize,
+}
+
+impl Iterator for BisHandleIterator<'_> {
+    type Item = ConnectionHandle;
+
+    /// Returns the next BIS connection handle from the event.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_index >= self.data.len() {
+            return None;
+        }
+
+        let index = self.next_index;
+        self.next_index += 2;
+        Some(ConnectionHandle(LittleEndian::read_u16(
+            &self.data[index..],
+        )))
+    }
+}
+
+impl Debug for BisHandleIterator<'_> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// Indicates that a BIG has been created, or that the Controller failed to create it, after the
+/// [`le_create_big`](crate::host::HostHci::le_create_big) command.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.27 of the spec.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeCreateBigComplete {
+    /// Was the BIG created?
+    pub status: Status,
+
+    /// Identifies the BIG.
+    pub big_handle: crate::BigHandle,
+
+    /// Maximum time for transmission of the PDUs of all the BISes in a BIG event, with a
+    /// resolution of 1 µs.
+    pub big_sync_delay: core::time::Duration,
+
+    /// Actual transport latency of the BIG, with a resolution of 1 µs.
+    pub transport_latency_big: core::time::Duration,
+
+    /// PHY used to transmit the BIS data PDUs. `None` if the status is not
+    /// [`Success`](Status::Success).
+    pub phy: Option<Phy>,
+
+    /// Number of subevents in each BIS event.
+    pub nse: u8,
+
+    /// Number of new payloads in each BIS event.
+    pub bn: u8,
+
+    /// Offset, in multiples of the ISO interval, used for pre-transmissions.
+    pub pto: u8,
+
+    /// Number of times every payload is scheduled for transmission.
+    pub irc: u8,
+
+    /// Maximum size of the payload of a BIS data PDU.
+    pub max_pdu: u16,
+
+    /// Time between consecutive BIG anchor points, with a resolution of 1.25 ms.
+    pub iso_interval: core::time::Duration,
+
+    num_bis: usize,
+    bis_handle_buf: [u8; MAX_BIS_HANDLES_LEN],
+}
+
+impl LeCreateBigComplete {
+    /// Returns an iterator over the connection handles of the BISes in the BIG.
+    pub fn bis_handles(&self) -> BisHandleIterator<'_> {
+        BisHandleIterator {
+            data: &self.bis_handle_buf[..2 * self.num_bis],
+            next_index: 0,
+        }
+    }
+}
+
+impl Debug for LeCreateBigComplete {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("LeCreateBigComplete")
+            .field("status", &self.status)
+            .field("big_handle", &self.big_handle)
+            .field("big_sync_delay", &self.big_sync_delay)
+            .field("transport_latency_big", &self.transport_latency_big)
+            .field("phy", &self.phy)
+            .field("nse", &self.nse)
+            .field("bn", &self.bn)
+            .field("pto", &self.pto)
+            .field("irc", &self.irc)
+            .field("max_pdu", &self.max_pdu)
+            .field("iso_interval", &self.iso_interval)
+            .field("bis_handles", &self.bis_handles())
+            .finish()
+    }
+}
+
+fn to_le_create_big_complete(payload: &[u8]) -> Result<LeCreateBigComplete, Error> {
+    let event = borrowed::to_le_create_big_complete(payload)?;
+
+    let mut bis_handle_buf = [0; MAX_BIS_HANDLES_LEN];
+    bis_handle_buf[..event.bis_handle_data.len()].copy_from_slice(event.bis_handle_data);
+    Ok(LeCreateBigComplete {
+        status: event.status,
+        big_handle: event.big_handle,
+        big_sync_delay: event.big_sync_delay,
+        transport_latency_big: event.transport_latency_big,
+        phy: event.phy,
+        nse: event.nse,
+        bn: event.bn,
+        pto: event.pto,
+        irc: event.irc,
+        max_pdu: event.max_pdu,
+        iso_interval: event.iso_interval,
+        num_bis: event.bis_handle_data.len() / 2,
+        bis_handle_buf,
+    })
+}
+
+fn write_le_create_big_complete(event: &LeCreateBigComplete, payload: &mut [u8]) -> usize {
+    let data_len = 2 * event.num_bis;
+    payload[0] = 0x1B;
+    payload[1] = event.status.into();
+    payload[2] = event.big_handle.0;
+    LittleEndian::write_u24(&mut payload[3..], event.big_sync_delay.as_micros() as u32);
+    LittleEndian::write_u24(
+        &mut payload[6..],
+        event.transport_latency_big.as_micros() as u32,
+    );
+    payload[9] = event.phy.map_or(0, u8::from);
+    payload[10] = event.nse;
+    payload[11] = event.bn;
+    payload[12] = event.pto;
+    payload[13] = event.irc;
+    LittleEndian::write_u16(&mut payload[14..], event.max_pdu);
+    LittleEndian::write_u16(
+        &mut payload[16..],
+        (event.iso_interval.as_micros() / 1250) as u16,
+    );
+    payload[18] = event.num_bis as u8;
+    payload[19..19 + data_len].copy_from_slice(&event.bis_handle_buf[..data_len]);
+    19 + data_len
+}
+
+/// Indicates that the transmission of all the BISes of a BIG has been terminated.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.28 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeTerminateBigComplete {
+    /// Identifies the terminated BIG.
+    pub big_handle: crate::BigHandle,
+
+    /// Reason for the termination.
+    pub reason: Status,
+}
+
+fn to_le_terminate_big_complete(payload: &[u8]) -> Result<LeTerminateBigComplete, Error> {
+    require_len!(payload, 3);
+
+    Ok(LeTerminateBigComplete {
+        big_handle: crate::BigHandle(payload[1]),
+        reason: payload[2].try_into().map_err(rewrap_bad_status)?,
+    })
+}
+
+fn write_le_terminate_big_complete(event: &LeTerminateBigComplete, payload: &mut [u8]) -> usize {
+    payload[0] = 0x1C;
+    payload[1] = event.big_handle.0;
+    payload[2] = event.reason.into();
+    3
+}
+
+/// Indicates that the Controller has synchronized with one or more BISes of a BIG, or that it
+/// failed to, after the [`le_big_create_sync`](crate::host::HostHci::le_big_create_sync) command.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.29 of the spec.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeBigSyncEstablished {
+    /// Was synchronization established?
+    pub status: Status,
+
+    /// Identifies the BIG.
+    pub big_handle: crate::BigHandle,
+
+    /// Actual transport latency of the BIG, with a resolution of 1 µs.
+    pub transport_latency_big: core::time::Duration,
+
+    /// Number of subevents in each BIS event.
+    pub nse: u8,
+
+    /// Number of new payloads in each BIS event.
+    pub bn: u8,
+
+    /// Offset, in multiples of the ISO interval, used for pre-transmissions.
+    pub pto: u8,
+
+    /// Number of times every payload is scheduled for transmission.
+    pub irc: u8,
+
+    /// Maximum size of the payload of a BIS data PDU.
+    pub max_pdu: u16,
+
+    /// Time between consecutive BIG anchor points, with a resolution of 1.25 ms.
+    pub iso_interval: core::time::Duration,
+
+    num_bis: usize,
+    bis_handle_buf: [u8; MAX_BIS_HANDLES_LEN],
+}
+
+impl LeBigSyncEstablished {
+    /// Returns an iterator over the connection handles of the BISes the Controller is
+    /// synchronized with.
+    pub fn bis_handles(&self) -> BisHandleIterator<'_> {
+        BisHandleIterator {
+            data: &self.bis_handle_buf[..2 * self.num_bis],
+            next_index: 0,
+        }
+    }
+}
+
+impl Debug for LeBigSyncEstablished {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("LeBigSyncEstablished")
+            .field("status", &self.status)
+            .field("big_handle", &self.big_handle)
+            .field("transport_latency_big", &self.transport_latency_big)
+            .field("nse", &self.nse)
+            .field("bn", &self.bn)
+            .field("pto", &self.pto)
+            .field("irc", &self.irc)
+            .field("max_pdu", &self.max_pdu)
+            .field("iso_interval", &self.iso_interval)
+            .field("bis_handles", &self.bis_handles())
+            .finish()
+    }
+}
+
+fn to_le_big_sync_established(payload: &[u8]) -> Result<LeBigSyncEstablished, Error> {
+    let event = borrowed::to_le_big_sync_established(payload)?;
+
+    let mut bis_handle_buf = [0; MAX_BIS_HANDLES_LEN];
+    bis_handle_buf[..event.bis_handle_data.len()].copy_from_slice(event.bis_handle_data);
+    Ok(LeBigSyncEstablished {
+        status: event.status,
+        big_handle: event.big_handle,
+        transport_latency_big: event.transport_latency_big,
+        nse: event.nse,
+        bn: event.bn,
+        pto: event.pto,
+        irc: event.irc,
+        max_pdu: event.max_pdu,
+        iso_interval: event.iso_interval,
+        num_bis: event.bis_handle_data.len() / 2,
+        bis_handle_buf,
+    })
+}
+
+fn write_le_big_sync_established(event: &LeBigSyncEstablished, payload: &mut [u8]) -> usize {
+    let data_len = 2 * event.num_bis;
+    payload[0] = 0x1D;
+    payload[1] = event.status.into();
+    payload[2] = event.big_handle.0;
+    LittleEndian::write_u24(
+        &mut payload[3..],
+        event.transport_latency_big.as_micros() as u32,
+    );
+    payload[6] = event.nse;
+    payload[7] = event.bn;
+    payload[8] = event.pto;
+    payload[9] = event.irc;
+    LittleEndian::write_u16(&mut payload[10..], event.max_pdu);
+    LittleEndian::write_u16(
+        &mut payload[12..],
+        (event.iso_interval.as_micros() / 1250) as u16,
+    );
+    payload[14] = event.num_bis as u8;
+    payload[15..15 + data_len].copy_from_slice(&event.bis_handle_buf[..data_len]);
+    15 + data_len
+}
+
+/// Indicates that the Controller has lost synchronization with a BIG, or that synchronization
+/// was terminated by the remote device.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.30 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeBigSyncLost {
+    /// Identifies the BIG.
+    pub big_handle: crate::BigHandle,
+
+    /// Reason synchronization was lost.
+    pub reason: Status,
+}
+
+fn to_le_big_sync_lost(payload: &[u8]) -> Result<LeBigSyncLost, Error> {
+    require_len!(payload, 3);
+
+    Ok(LeBigSyncLost {
+        big_handle: crate::BigHandle(payload[1]),
+        reason: payload[2].try_into().map_err(rewrap_bad_status)?,
+    })
+}
+
+fn write_le_big_sync_lost(event: &LeBigSyncLost, payload: &mut [u8]) -> usize {
+    payload[0] = 0x1E;
+    payload[1] = event.big_handle.0;
+    payload[2] = event.reason.into();
+    3
+}
+
+/// Reports the BIGInfo of a BIG, received in a periodic advertising train the Controller is
+/// synchronized with.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.34 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeBigInfoAdvertisingReport {
+    /// Periodic advertising train the BIGInfo was received from.
+    pub sync_handle: crate::SyncHandle,
+
+    /// Number of BISes in the BIG.
+    pub num_bis: u8,
+
+    /// Number of subevents in each BIS event.
+    pub nse: u8,
+
+    /// Time between consecutive BIG anchor points, with a resolution of 1.25 ms.
+    pub iso_interval: core::time::Duration,
+
+    /// Number of new payloads in each BIS event.
+    pub bn: u8,
+
+    /// Offset, in multiples of the ISO interval, used for pre-transmissions.
+    pub pto: u8,
+
+    /// Number of times every payload is scheduled for transmission.
+    pub irc: u8,
+
+    /// Maximum size of the payload of a BIS data PDU.
+    pub max_pdu: u16,
+
+    /// Interval between the start of consecutive SDUs, with a resolution of 1 µs.
+    pub sdu_interval: core::time::Duration,
+
+    /// Maximum size of an SDU.
+    pub max_sdu: u16,
+
+    /// PHY used to transmit the BIS data PDUs.
+    pub phy: Phy,
+
+    /// Format of the BIS data PDUs.
+    pub framing: crate::host::Framing,
+
+    /// Are the BISes encrypted?
+    pub encryption: bool,
+}
+
+fn to_le_big_info_advertising_report(payload: &[u8]) -> Result<LeBigInfoAdvertisingReport, Error> {
+    require_len!(payload, 20);
+
+    Ok(LeBigInfoAdvertisingReport {
+        sync_handle: crate::SyncHandle(LittleEndian::read_u16(&payload[1..])),
+        num_bis: payload[3],
+        nse: payload[4],
+        iso_interval: to_iso_interval(&payload[5..]),
+        bn: payload[7],
+        pto: payload[8],
+        irc: payload[9],
+        max_pdu: LittleEndian::read_u16(&payload[10..]),
+        sdu_interval: to_duration_us(&payload[12..]),
+        max_sdu: LittleEndian::read_u16(&payload[15..]),
+        phy: payload[17].try_into()?,
+        framing: match payload[18] {
+            0 => crate::host::Framing::Unframed,
+            1 => crate::host::Framing::Framed,
+            other => return Err(Error::BadFraming(other)),
+        },
+        encryption: payload[19] != 0,
+    })
+}
+
+fn write_le_big_info_advertising_report(
+    event: &LeBigInfoAdvertisingReport,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x22;
+    LittleEndian::write_u16(&mut payload[1..], event.sync_handle.0);
+    payload[3] = event.num_bis;
+    payload[4] = event.nse;
+    LittleEndian::write_u16(
+        &mut payload[5..],
+        (event.iso_interval.as_micros() / 1250) as u16,
+    );
+    payload[7] = event.bn;
+    payload[8] = event.pto;
+    payload[9] = event.irc;
+    LittleEndian::write_u16(&mut payload[10..], event.max_pdu);
+    LittleEndian::write_u24(&mut payload[12..], event.sdu_interval.as_micros() as u32);
+    LittleEndian::write_u16(&mut payload[15..], event.max_sdu);
+    payload[17] = event.phy.into();
+    payload[18] = event.framing as u8;
+    payload[19] = event.encryption as u8;
+    20
+}
+
+/// PHYs whose transmit power level is read or reported by the LE Power Control commands and
+/// events.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.117.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PowerControlPhy {
+    /// LE 1M PHY
+    Le1M,
+    /// LE 2M PHY
+    Le2M,
+    /// LE Coded PHY with S=8 data coding
+    LeCodedS8,
+    /// LE Coded PHY with S=2 data coding
+    LeCodedS2,
+}
+
+impl TryFrom<u8> for PowerControlPhy {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(PowerControlPhy::Le1M),
+            2 => Ok(PowerControlPhy::Le2M),
+            3 => Ok(PowerControlPhy::LeCodedS8),
+            4 => Ok(PowerControlPhy::LeCodedS2),
+            other => Err(Error::BadPowerControlPhy(other)),
+        }
+    }
+}
+
+impl From<PowerControlPhy> for u8 {
+    fn from(value: PowerControlPhy) -> u8 {
+        match value {
+            PowerControlPhy::Le1M => 1,
+            PowerControlPhy::Le2M => 2,
+            PowerControlPhy::LeCodedS8 => 3,
+            PowerControlPhy::LeCodedS2 => 4,
+        }
+    }
+}
+
+/// Indicates that the path loss of an ACL connection has entered a different zone, as defined by
+/// the [`le_set_path_loss_reporting_parameters`](crate::host::HostHci::le_set_path_loss_reporting_parameters)
+/// command.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.32 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LePathLossThreshold {
+    /// ACL connection whose path loss is monitored.
+    pub conn_handle: ConnectionHandle,
+
+    /// Current path loss, in dB. `None` if not available.
+    pub current_path_loss: Option<u8>,
+
+    /// Zone that the path loss has entered.
+    pub zone_entered: PathLossZone,
+}
+
+/// Zones of the path loss, as reported by the [LE Path Loss Threshold](Event::LePathLossThreshold)
+/// event.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PathLossZone {
+    /// The path loss is below the low threshold.
+    Low,
+    /// The path loss is between the low and high thresholds.
+    Middle,
+    /// The path loss is above the high threshold.
+    High,
+}
+
+fn to_le_path_loss_threshold(payload: &[u8]) -> Result<LePathLossThreshold, Error> {
+    require_len!(payload, 5);
+
+    Ok(LePathLossThreshold {
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&payload[1..])),
+        current_path_loss: match payload[3] {
+            0xFF => None,
+            value => Some(value),
+        },
+        zone_entered: match payload[4] {
+            0 => PathLossZone::Low,
+            1 => PathLossZone::Middle,
+            2 => PathLossZone::High,
+            other => return Err(Error::BadPathLossZone(other)),
+        },
+    })
+}
+
+fn write_le_path_loss_threshold(event: &LePathLossThreshold, payload: &mut [u8]) -> usize {
+    payload[0] = 0x20;
+    LittleEndian::write_u16(&mut payload[1..], event.conn_handle.0);
+    payload[3] = event.current_path_loss.unwrap_or(0xFF);
+    payload[4] = match event.zone_entered {
+        PathLossZone::Low => 0,
+        PathLossZone::Middle => 1,
+        PathLossZone::High => 2,
+    };
+    5
+}
+
+/// Reports the transmit power level of the local or remote Controller on an ACL connection, either
+/// because it has changed or because the
+/// [`le_read_remote_transmit_power_level`](crate::host::HostHci::le_read_remote_transmit_power_level)
+/// command has completed.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.33 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeTransmitPowerReporting {
+    /// Was the power level read? Only meaningful for the
+    /// [`ReadRemoteCompleted`](TransmitPowerReportingReason::ReadRemoteCompleted) reason.
+    pub status: Status,
+
+    /// ACL connection whose transmit power level is reported.
+    pub conn_handle: ConnectionHandle,
+
+    /// Why the event was generated.
+    pub reason: TransmitPowerReportingReason,
+
+    /// PHY whose transmit power level is reported.
+    pub phy: PowerControlPhy,
+
+    /// Transmit power level. `None` if not available.
+    ///
+    /// For a remote Controller, a level of 126 dBm indicates that the remote Controller is not
+    /// managing its power levels on this PHY.
+    pub tx_power_level: Option<crate::Dbm>,
+
+    /// Whether the transmit power level is at its minimum or maximum.
+    pub tx_power_level_flags: TxPowerLevelFlags,
+
+    /// Change in the transmit power level since the last report, in dB. `None` if not available.
+    pub delta: Option<i8>,
+}
+
+/// Reasons for the [LE Transmit Power Reporting](Event::LeTransmitPowerReporting) event.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TransmitPowerReportingReason {
+    /// The transmit power level of the local Controller has changed.
+    LocalChange,
+    /// The transmit power level of the remote Controller has changed.
+    RemoteChange,
+    /// The
+    /// [`le_read_remote_transmit_power_level`](crate::host::HostHci::le_read_remote_transmit_power_level)
+    /// command has completed.
+    ReadRemoteCompleted,
+}
+
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// Limits reached by the transmit power level reported in an
+    /// [LE Transmit Power Reporting](Event::LeTransmitPowerReporting) event.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct TxPowerLevelFlags : u8 {
+        /// The transmit power level is at its minimum.
+        const AT_MINIMUM = 1 << 0;
+        /// The transmit power level is at its maximum.
+        const AT_MAXIMUM = 1 << 1;
+    }
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// Limits reached by the transmit power level reported in an
+    /// [LE Transmit Power Reporting](Event::LeTransmitPowerReporting) event.
+    pub struct TxPowerLevelFlags : u8 {
+        /// The transmit power level is at its minimum.
+        const AT_MINIMUM = 1 << 0;
+        /// The transmit power level is at its maximum.
+        const AT_MAXIMUM = 1 << 1;
+    }
+}
+
+fn to_le_transmit_power_reporting(payload: &[u8]) -> Result<LeTransmitPowerReporting, Error> {
+    require_len!(payload, 9);
+
+    Ok(LeTransmitPowerReporting {
+        status: payload[1].try_into().map_err(rewrap_bad_status)?,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&payload[2..])),
+        reason: match payload[4] {
+            0 => TransmitPowerReportingReason::LocalChange,
+            1 => TransmitPowerReportingReason::RemoteChange,
+            2 => TransmitPowerReportingReason::ReadRemoteCompleted,
+            other => return Err(Error::BadTransmitPowerReportingReason(other)),
+        },
+        phy: payload[5].try_into()?,
+        tx_power_level: match u8::cast_signed(payload[6]) {
+            127 => None,
+            value => Some(crate::Dbm(value)),
+        },
+        tx_power_level_flags: TxPowerLevelFlags::from_bits(payload[7])
+            .ok_or(Error::BadTxPowerLevelFlags(payload[7]))?,
+        delta: match u8::cast_signed(payload[8]) {
+            127 => None,
+            value => Some(value),
+        },
+    })
+}
+
+fn write_le_transmit_power_reporting(
+    event: &LeTransmitPowerReporting,
+    payload: &mut [u8],
+) -> usize {
+    payload[0] = 0x21;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.conn_handle.0);
+    payload[4] = match event.reason {
+        TransmitPowerReportingReason::LocalChange => 0,
+        TransmitPowerReportingReason::RemoteChange => 1,
+        TransmitPowerReportingReason::ReadRemoteCompleted => 2,
+    };
+    payload[5] = event.phy.into();
+    payload[6] = i8::cast_unsigned(event.tx_power_level.map_or(127, |level| level.0));
+    payload[7] = event.tx_power_level_flags.bits();
+    payload[8] = i8::cast_unsigned(event.delta.unwrap_or(127));
+    9
+}
+
+/// Indicates that a [subrate request](crate::host::HostHci::le_subrate_request) has completed, or
+/// that the subrating parameters of a connection have been changed by the remote device.
+///
+/// Note: The subrate factor returned in this event is selected by the Controller from the range
+/// provided by the Host.
+///
+/// Defined in Vol 4, Part E, Section 7.7.65.35 of the spec.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeSubrateChange {
+    /// Did the subrate change fail, and if so, how?
+    pub status: Status,
+
+    /// ACL connection whose subrating parameters are reported.
+    pub conn_handle: ConnectionHandle,
+
+    /// Subrate factor applied to the underlying connection interval.
+    pub subrate_factor: u16,
+
+    /// Peripheral latency, in number of subrated connection events.
+    pub peripheral_latency: u16,
+
+    /// Number of underlying connection events to remain active for after a packet containing data
+    /// is received.
+    pub continuation_number: u16,
+
+    /// Supervision timeout for this connection.
+    pub supervision_timeout: core::time::Duration,
+}
+
+fn to_le_subrate_change(payload: &[u8]) -> Result<LeSubrateChange, Error> {
+    require_len!(payload, 12);
+
+    Ok(LeSubrateChange {
+        status: payload[1].try_into().map_err(rewrap_bad_status)?,
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&payload[2..])),
+        subrate_factor: LittleEndian::read_u16(&payload[4..]),
+        peripheral_latency: LittleEndian::read_u16(&payload[6..]),
+        continuation_number: LittleEndian::read_u16(&payload[8..]),
+        supervision_timeout: core::time::Duration::from_millis(10)
+            * u32::from(LittleEndian::read_u16(&payload[10..])),
+    })
+}
+
+fn write_le_subrate_change(event: &LeSubrateChange, payload: &mut [u8]) -> usize {
+    payload[0] = 0x23;
+    payload[1] = event.status.into();
+    LittleEndian::write_u16(&mut payload[2..], event.conn_handle.0);
+    LittleEndian::write_u16(&mut payload[4..], event.subrate_factor);
+    LittleEndian::write_u16(&mut payload[6..], event.peripheral_latency);
+    LittleEndian::write_u16(&mut payload[8..], event.continuation_number);
+    LittleEndian::write_u16(
+        &mut payload[10..],
+        (event.supervision_timeout.as_millis() / 10) as u16,
+    );
+    12
+}
@@ -0,0 +1,333 @@
+//! Flow control of ACL data sent to the controller.
+//!
+//! The controller has a fixed number of buffers for ACL data packets sent by the host, each of a
+//! fixed size, both reported by the [LE Read Buffer Size](super::HostHci::le_read_buffer_size)
+//! command. Each packet sent occupies a buffer until the controller reports it as transmitted or
+//! flushed in a [Number of Completed Packets](crate::event::Event::NumberOfCompletedPackets) event.
+//! When a connection is closed, the controller flushes its packets without reporting them, so the
+//! host shall consider their buffers free once it receives the
+//! [Disconnection Complete](crate::event::Event::DisconnectionComplete) event. See the Bluetooth
+//! spec, Vol 4, Part E, Section 4.1.1.
+//!
+//! [`AclCredits`] wraps a [`Controller`] to keep that count, per connection, from the packets
+//! written and read through it, and to fragment L2CAP PDUs into packets that fit the controller's
+//! buffers.
+//!
+//! A controller that supports BR/EDR may report no dedicated LE buffers, in which case LE data
+//! shares the buffers reported by the Read Buffer Size command (see the Bluetooth spec, Vol 4, Part
+//! E, Section 7.8.2). Pass that size to [`AclCredits::set_buffers`].
+
+use byteorder::{ByteOrder, LittleEndian};
+
+use crate::event::command::LeReadBufferSize;
+use crate::host::uart::{AclBroadcastFlag, AclDataHeader, AclPacketBoundary, Packet, UartHci};
+use crate::{ConnectionHandle, Controller, DataController, Opcode, Status};
+
+const PACKET_TYPE_ACL_DATA: u8 = 0x02;
+const PACKET_TYPE_HCI_EVENT: u8 = 0x04;
+const EVENT_DISCONNECTION_COMPLETE: u8 = 0x05;
+const EVENT_COMMAND_COMPLETE: u8 = 0x0E;
+const EVENT_NUMBER_OF_COMPLETED_PACKETS: u8 = 0x13;
+
+/// Potential errors from sending ACL data through [`AclCredits`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Error {
+    /// The controller's buffer size is not known yet. Send the
+    /// [LE Read Buffer Size](super::HostHci::le_read_buffer_size) command and read its result
+    /// through the [`AclCredits`], or call [`set_buffer_size`](AclCredits::set_buffer_size) or
+    /// [`set_buffers`](AclCredits::set_buffers).
+    UnknownBufferSize,
+
+    /// Packets are already outstanding on as many connections as the [`AclCredits`] can track.
+    /// Contains the connection handle that could not be tracked.
+    TooManyConnections(ConnectionHandle),
+
+    /// There was an error reading a packet from the controller while waiting for buffers. Contains
+    /// the underlying error.
+    Read(crate::host::uart::Error),
+}
+
+/// Wraps a [`Controller`] to track the controller's free ACL data buffers.
+///
+/// The wrapper implements [`Controller`] itself, so all of the command traits are available on it.
+/// It updates its count from:
+///
+/// - the result of the [LE Read Buffer Size](super::HostHci::le_read_buffer_size) command, which
+///   sets the number and size of the buffers;
+/// - each ACL data packet written, which occupies a buffer;
+/// - [Number of Completed Packets](crate::event::Event::NumberOfCompletedPackets) events, which
+///   free the buffers of the packets they report;
+/// - successful [Disconnection Complete](crate::event::Event::DisconnectionComplete) events, which
+///   free the buffers of all packets outstanding on the connection.
+///
+/// Packets must be read whole, as [`UartHci::read`] and [`UartHci::read_into`] do. Up to `N`
+/// connections may have packets outstanding at the same time.
+pub struct AclCredits<C, const N: usize = 8> {
+    controller: C,
+    data_packet_length: usize,
+    data_packet_count: usize,
+    available: usize,
+    connections: [Option<Outstanding>; N],
+}
+
+#[derive(Copy, Clone)]
+struct Outstanding {
+    conn_handle: ConnectionHandle,
+    packets: usize,
+}
+
+impl<C, const N: usize> AclCredits<C, N> {
+    /// Wraps the given controller. The buffer size is unknown until the result of the
+    /// [LE Read Buffer Size](super::HostHci::le_read_buffer_size) command is read, or
+    /// [`set_buffer_size`](AclCredits::set_buffer_size) or [`set_buffers`](AclCredits::set_buffers)
+    /// is called.
+    pub fn new(controller: C) -> Self {
+        Self {
+            controller,
+            data_packet_length: 0,
+            data_packet_count: 0,
+            available: 0,
+            connections: [None; N],
+        }
+    }
+
+    /// Sets the number and size of the controller's buffers from the result of the
+    /// [LE Read Buffer Size](super::HostHci::le_read_buffer_size) command. All buffers are
+    /// considered free.
+    ///
+    /// Results that report a failure are ignored. So are results that report no dedicated LE
+    /// buffers: LE data then shares the buffers reported by the Read Buffer Size command, which
+    /// must be passed to [`set_buffers`](AclCredits::set_buffers) instead.
+    pub fn set_buffer_size(&mut self, params: &LeReadBufferSize) {
+        if params.status != Status::Success {
+            return;
+        }
+
+        self.set_buffers(
+            params.data_packet_length as usize,
+            params.data_packet_count as usize,
+        );
+    }
+
+    /// Sets the maximum length of the data in an ACL data packet and the number of the
+    /// controller's buffers. All buffers are considered free.
+    ///
+    /// A length or count of 0 is ignored.
+    pub fn set_buffers(&mut self, data_packet_length: usize, data_packet_count: usize) {
+        if data_packet_length == 0 || data_packet_count == 0 {
+            return;
+        }
+
+        self.data_packet_length = data_packet_length;
+        self.data_packet_count = data_packet_count;
+        self.available = data_packet_count;
+        self.connections = [None; N];
+    }
+
+    /// Returns the maximum length of the data in an ACL data packet, if the buffer size is known.
+    pub fn data_packet_length(&self) -> Option<usize> {
+        if self.data_packet_count == 0 {
+            return None;
+        }
+
+        Some(self.data_packet_length)
+    }
+
+    /// Returns the number of free buffers in the controller.
+    pub fn available(&self) -> usize {
+        self.available
+    }
+
+    /// Returns the number of packets sent on the connection that the controller has not reported
+    /// as completed.
+    pub fn outstanding(&self, conn_handle: ConnectionHandle) -> usize {
+        self.find(conn_handle)
+            .map_or(0, |i| self.connections[i].unwrap().packets)
+    }
+
+    /// Returns a reference to the wrapped controller.
+    pub fn controller(&self) -> &C {
+        &self.controller
+    }
+
+    /// Returns a mutable reference to the wrapped controller.
+    ///
+    /// Packets written and events read directly through the wrapped controller do not update the
+    /// buffer count.
+    pub fn controller_mut(&mut self) -> &mut C {
+        &mut self.controller
+    }
+
+    /// Returns the wrapped controller.
+    pub fn into_inner(self) -> C {
+        self.controller
+    }
+
+    fn find(&self, conn_handle: ConnectionHandle) -> Option<usize> {
+        self.connections
+            .iter()
+            .position(|c| matches!(c, Some(c) if c.conn_handle == conn_handle))
+    }
+
+    fn can_track(&self, conn_handle: ConnectionHandle) -> bool {
+        self.find(conn_handle).is_some() || self.connections.iter().any(Option::is_none)
+    }
+
+    fn packet_sent(&mut self, conn_handle: ConnectionHandle) {
+        if self.data_packet_count == 0 {
+            return;
+        }
+
+        self.available = self.available.saturating_sub(1);
+        if let Some(i) = self.find(conn_handle) {
+            self.connections[i].as_mut().unwrap().packets += 1;
+        } else if let Some(slot) = self.connections.iter_mut().find(|c| c.is_none()) {
+            *slot = Some(Outstanding {
+                conn_handle,
+                packets: 1,
+            });
+        }
+    }
+
+    fn packets_completed(&mut self, conn_handle: ConnectionHandle, count: usize) {
+        let count = match self.find(conn_handle) {
+            Some(i) => {
+                let outstanding = self.connections[i].as_mut().unwrap();
+                let count = count.min(outstanding.packets);
+                outstanding.packets -= count;
+                if outstanding.packets == 0 {
+                    self.connections[i] = None;
+                }
+                count
+            }
+            None => count,
+        };
+        self.available = (self.available + count).min(self.data_packet_count);
+    }
+
+    fn disconnected(&mut self, conn_handle: ConnectionHandle) {
+        if let Some(i) = self.find(conn_handle) {
+            let packets = self.connections[i].unwrap().packets;
+            self.connections[i] = None;
+            self.available = (self.available + packets).min(self.data_packet_count);
+        }
+    }
+
+    fn update_from_event(&mut self, packet: &[u8]) {
+        if packet.len() < 3 || packet[0] != PACKET_TYPE_HCI_EVENT {
+            return;
+        }
+
+        let end = packet.len().min(3 + packet[2] as usize);
+        let params = &packet[3..end];
+        match packet[1] {
+            // Number of command packets (1), opcode (2), status (1), data packet length (2), data
+            // packet count (1).
+            EVENT_COMMAND_COMPLETE
+                if params.len() >= 7
+                    && Opcode(LittleEndian::read_u16(&params[1..]))
+                        == crate::opcode::LE_READ_BUFFER_SIZE =>
+            {
+                if let Ok(status) = Status::try_from(params[3]) {
+                    self.set_buffer_size(&LeReadBufferSize {
+                        status,
+                        data_packet_length: LittleEndian::read_u16(&params[4..]),
+                        data_packet_count: params[6],
+                    });
+                }
+            }
+            EVENT_NUMBER_OF_COMPLETED_PACKETS => {
+                // Number of handles (1), then pairs of connection handle (2) and count (2).
+                if let Some((&num_handles, pairs)) = params.split_first() {
+                    for pair in pairs.chunks_exact(4).take(num_handles as usize) {
+                        self.packets_completed(
+                            ConnectionHandle(LittleEndian::read_u16(&pair[0..])),
+                            LittleEndian::read_u16(&pair[2..]) as usize,
+                        );
+                    }
+                }
+            }
+            // Status (1), connection handle (2), reason (1).
+            EVENT_DISCONNECTION_COMPLETE if params.len() >= 3 && params[0] == 0 => {
+                self.disconnected(ConnectionHandle(LittleEndian::read_u16(&params[1..])));
+            }
+            _ => (),
+        }
+    }
+}
+
+impl<C: DataController, const N: usize> AclCredits<C, N> {
+    /// Sends an L2CAP PDU on the connection, fragmented into ACL data packets that fit the
+    /// controller's buffers.
+    ///
+    /// Before each packet, if no buffers are free, reads packets from the controller until a
+    /// [Number of Completed Packets](crate::event::Event::NumberOfCompletedPackets) or
+    /// [Disconnection Complete](crate::event::Event::DisconnectionComplete) event frees one. Every
+    /// packet read is passed to `on_packet` in the order it was read.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`Error::UnknownBufferSize`] if the buffer size is not known. Nothing is written
+    ///   in this case.
+    /// - Returns [`Error::TooManyConnections`] if packets are outstanding on `N` other connections.
+    ///   Nothing is written in this case.
+    /// - Returns [`Error::Read`] if there is an error reading a packet while waiting for a buffer.
+    ///   The fragments before the one being waited for have been written in this case.
+    pub async fn write_pdu<F>(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        pdu: &[u8],
+        mut on_packet: F,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(Packet),
+    {
+        let data_packet_length = self.data_packet_length().ok_or(Error::UnknownBufferSize)?;
+        if !self.can_track(conn_handle) {
+            return Err(Error::TooManyConnections(conn_handle));
+        }
+
+        for (i, fragment) in pdu.chunks(data_packet_length).enumerate() {
+            while self.available == 0 {
+                on_packet(self.read().await.map_err(Error::Read)?);
+            }
+
+            let header = AclDataHeader {
+                conn_handle,
+                packet_boundary: if i == 0 {
+                    AclPacketBoundary::FirstNonFlushable
+                } else {
+                    AclPacketBoundary::Continuing
+                },
+                broadcast: AclBroadcastFlag::PointToPoint,
+            };
+            // The fragment is no longer than the buffer size, so it always fits in the header.
+            self.write_acl_data(&header, fragment).await.unwrap();
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: Controller, const N: usize> Controller for AclCredits<C, N> {
+    async fn controller_write(&mut self, opcode: Opcode, payload: &[u8]) {
+        self.controller.controller_write(opcode, payload).await;
+    }
+
+    async fn controller_read_into(&mut self, buffer: &mut [u8]) {
+        self.controller.controller_read_into(buffer).await;
+        self.update_from_event(buffer);
+    }
+}
+
+impl<C: DataController, const N: usize> DataController for AclCredits<C, N> {
+    async fn controller_write_data(&mut self, header: &[u8], payload: &[u8]) {
+        self.controller.controller_write_data(header, payload).await;
+        if header.len() >= 3 && header[0] == PACKET_TYPE_ACL_DATA {
+            self.packet_sent(ConnectionHandle(
+                LittleEndian::read_u16(&header[1..]) & 0x0FFF,
+            ));
+        }
+    }
+}
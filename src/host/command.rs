@@ -0,0 +1,2243 @@
+//! Deserialization of HCI command packets.
+//!
+//! This module is the counterpart of [`HostHci`](super::HostHci) and the vendor-specific command
+//! traits: it turns a command packet, as sent by the host, back into a typed [`Command`]. This is
+//! useful for decoding captured HCI traffic, or for emulating a controller.
+//!
+//! For the command packet format, see the Bluetooth specification, Vol 4, Part E, Section 5.4.1.
+
+use crate::event::{
+    CentralClockAccuracy, CteType, NUMBER_OF_COMPLETED_PACKETS_MAX_LEN, NumberOfCompletedPackets,
+    PowerControlPhy, SlotDuration,
+};
+use crate::host::{
+    AdvertisingFilterPolicy, AdvertisingInterval, AdvertisingParameters, AdvertisingType,
+    AesParameters, AllPhys, BigParameters, BigSyncParameters, Channels, CigParameters,
+    CigTestParameters, CisConnection, CisParameters, CisTestParameters, CodecId,
+    ConnectionCteReceiveParameters, ConnectionCteRequestParameters,
+    ConnectionCteTransmitParameters, ConnectionFilterPolicy, ConnectionInterval,
+    ConnectionParameters, ConnectionUpdateParameters, ConnectionlessCteTransmitParameters,
+    ConnectionlessIqSamplingParameters, CteTypes, DataPathDirection, DataPathDirections,
+    EncryptionKey, EncryptionParameters, EventFlags, ExpectedConnectionLength,
+    ExtendedAdvertisingData, ExtendedAdvertisingParameters, ExtendedConnectionParameters,
+    ExtendedScanEnable, ExtendedScanParameters, FilterDuplicates, FlowControl, Framing,
+    HostBufferSize, IsoDataPathParameters, LeEventFlags, OwnAddressType, Packing,
+    PathLossReportingParameters, PeerAddrType, PeriodicAdvertiser, PeriodicAdvertisingData,
+    PeriodicAdvertisingParameters, PeriodicAdvertisingSyncParameters, PhyConnectionParameters,
+    PhyMask, PhyOptions, PhyPreferences, PhyScanParameters, PlaintextBlock, PrivacyMode,
+    PrivateKeyType, ResolvingListParameters, ScanFilterPolicy, ScanParameters, ScanType,
+    ScanWindow, SubrateParameters, SyncCteType, SyncOptions, SyncTransferMode,
+    SyncTransferParameters, TestPacketPayload, TxPowerLevel,
+};
+use crate::opcode::Opcode;
+use crate::types::extended_advertisement::{
+    ADV_SET_LEN, AdvSetList, AdvertisingEvent, AdvertisingOperation, AdvertisingPhy,
+    ExtendedAdvertisingInterval, ExtendedAdvertisingIntervalError,
+};
+use crate::types::{
+    AdvertisingIntervalError, ConnectionIntervalError, ExpectedConnectionLengthError,
+    P256PublicKey, ScanWindowError, SubrateParametersError,
+};
+use crate::vendor::command::{VendorCommand, VendorCommandError};
+use crate::vendor::opcode::VENDOR_OGF;
+use crate::{
+    AdvertisingHandle, BadStatusError, BdAddr, BdAddrType, BigHandle, ChannelClassification,
+    ConnectionHandle, Status, SyncHandle,
+};
+use byteorder::{ByteOrder, LittleEndian};
+use core::convert::TryInto;
+
+macro_rules! require_len {
+    ($left:expr, $right:expr) => {
+        if $left.len() != $right {
+            return Err(crate::host::command::Error::BadLength($left.len(), $right));
+        }
+    };
+}
+
+macro_rules! require_len_at_least {
+    ($left:expr, $right:expr) => {
+        if $left.len() < $right {
+            return Err(crate::host::command::Error::BadLength($left.len(), $right));
+        }
+    };
+}
+
+/// Defines a newtype to indicate that the buffer is supposed to contain an HCI command.
+///
+/// The buffer holds the opcode (2 bytes), the parameter length (1 byte), and the parameters. It
+/// does not include the packet type byte that precedes commands on a [UART](super::uart)
+/// transport.
+pub struct Packet<'a>(pub &'a [u8]);
+
+impl<'a> Packet<'a> {
+    fn full_length(&self) -> usize {
+        PACKET_HEADER_LENGTH + self.0[PARAM_LEN_BYTE] as usize
+    }
+}
+
+const PACKET_HEADER_LENGTH: usize = 3;
+const PARAM_LEN_BYTE: usize = 2;
+
+/// Commands that may be sent by the host to the controller.
+///
+/// Each variant corresponds to one of the [`HostHci`](super::HostHci) methods, and holds the same
+/// parameters that method takes. Vendor-specific commands are held by the
+/// [`Vendor`](Command::Vendor) variant.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Command<'a> {
+    /// The [Disconnect](super::HostHci::disconnect) command.
+    Disconnect {
+        /// Connection handle of the connection to terminate.
+        conn_handle: ConnectionHandle,
+        /// Reason for the disconnection.
+        reason: Status,
+    },
+
+    /// The [Read Remote Version Information](super::HostHci::read_remote_version_information)
+    /// command.
+    ReadRemoteVersionInformation(ConnectionHandle),
+
+    /// The [Set Event Mask](super::HostHci::set_event_mask) command.
+    SetEventMask(EventFlags),
+
+    /// The [Reset](super::HostHci::reset) command.
+    Reset,
+
+    /// The [Read Transmit Power Level](super::HostHci::read_tx_power_level) command.
+    ReadTxPowerLevel {
+        /// Connection handle of the connection to query.
+        conn_handle: ConnectionHandle,
+        /// Which power level to read.
+        power_level_type: TxPowerLevel,
+    },
+
+    /// The [Set Controller To Host Flow Control](super::HostHci::set_controller_to_host_flow_control)
+    /// command.
+    SetControllerToHostFlowControl(FlowControl),
+
+    /// The [Host Buffer Size](super::HostHci::host_buffer_size) command.
+    HostBufferSize(HostBufferSize),
+
+    /// The [Host Number Of Completed Packets](super::HostHci::number_of_completed_packets)
+    /// command.
+    NumberOfCompletedPackets(NumberOfCompletedPackets),
+
+    /// The [Read Local Version Information](super::HostHci::read_local_version_information)
+    /// command.
+    ReadLocalVersionInformation,
+
+    /// The [Read Local Supported Commands](super::HostHci::read_local_supported_commands) command.
+    ReadLocalSupportedCommands,
+
+    /// The [Read Local Supported Features](super::HostHci::read_local_supported_features) command.
+    ReadLocalSupportedFeatures,
+
+    /// The [Read BD ADDR](super::HostHci::read_bd_addr) command.
+    ReadBdAddr,
+
+    /// The [Read RSSI](super::HostHci::read_rssi) command.
+    ReadRssi(ConnectionHandle),
+
+    /// The [LE Set Event Mask](super::HostHci::le_set_event_mask) command.
+    LeSetEventMask(LeEventFlags),
+
+    /// The [LE Read Buffer Size](super::HostHci::le_read_buffer_size) command.
+    LeReadBufferSize,
+
+    /// The [LE Read Local Supported Features](super::HostHci::le_read_local_supported_features)
+    /// command.
+    LeReadLocalSupportedFeatures,
+
+    /// The [LE Set Random Address](super::HostHci::le_set_random_address) command.
+    LeSetRandomAddress(BdAddr),
+
+    /// The [LE Set Advertising Parameters](super::HostHci::le_set_advertising_parameters) command.
+    LeSetAdvertisingParameters(AdvertisingParameters),
+
+    /// The [LE Read Advertising Channel TX Power](super::HostHci::le_read_advertising_channel_tx_power)
+    /// command.
+    LeReadAdvertisingChannelTxPower,
+
+    /// The [LE Set Advertising Data](super::HostHci::le_set_advertising_data) command. Includes
+    /// only the significant part of the advertising data.
+    LeSetAdvertisingData(&'a [u8]),
+
+    /// The [LE Set Scan Response Data](super::HostHci::le_set_scan_response_data) command.
+    /// Includes only the significant part of the scan response data.
+    LeSetScanResponseData(&'a [u8]),
+
+    /// The [LE Set Advertising Enable](super::HostHci::le_set_advertising_enable) command.
+    LeSetAdvertisingEnable(bool),
+
+    /// The [LE Set Scan Parameters](super::HostHci::le_set_scan_parameters) command.
+    LeSetScanParameters(ScanParameters),
+
+    /// The [LE Set Scan Enable](super::HostHci::le_set_scan_enable) command.
+    LeSetScanEnable {
+        /// Whether scanning is enabled.
+        enable: bool,
+        /// Whether the controller should filter out duplicate advertising reports.
+        filter_duplicates: bool,
+    },
+
+    /// The [LE Create Connection](super::HostHci::le_create_connection) command.
+    LeCreateConnection(ConnectionParameters),
+
+    /// The [LE Create Connection Cancel](super::HostHci::le_create_connection_cancel) command.
+    LeCreateConnectionCancel,
+
+    /// The [LE Read White List Size](super::HostHci::le_read_white_list_size) command.
+    LeReadWhiteListSize,
+
+    /// The [LE Clear White List](super::HostHci::le_clear_white_list) command.
+    LeClearWhiteList,
+
+    /// The [LE Add Device To White List](super::HostHci::le_add_device_to_white_list) command.
+    LeAddDeviceToWhiteList(BdAddrType),
+
+    /// The [LE Add Device To White List](super::HostHci::le_add_anon_advertising_devices_to_white_list)
+    /// command, for anonymous advertisers.
+    LeAddAnonAdvertisingDevicesToWhiteList,
+
+    /// The [LE Remove Device From White List](super::HostHci::le_remove_device_from_white_list)
+    /// command.
+    LeRemoveDeviceFromWhiteList(BdAddrType),
+
+    /// The [LE Remove Device From White List](super::HostHci::le_remove_anon_advertising_devices_from_white_list)
+    /// command, for anonymous advertisers.
+    LeRemoveAnonAdvertisingDevicesFromWhiteList,
+
+    /// The [LE Connection Update](super::HostHci::le_connection_update) command.
+    LeConnectionUpdate(ConnectionUpdateParameters),
+
+    /// The [LE Set Host Channel Classification](super::HostHci::le_set_host_channel_classification)
+    /// command.
+    LeSetHostChannelClassification(ChannelClassification),
+
+    /// The [LE Read Channel Map](super::HostHci::le_read_channel_map) command.
+    LeReadChannelMap(ConnectionHandle),
+
+    /// The [LE Read Remote Used Features](super::HostHci::le_read_remote_used_features) command.
+    LeReadRemoteUsedFeatures(ConnectionHandle),
+
+    /// The [LE Encrypt](super::HostHci::le_encrypt) command.
+    LeEncrypt(AesParameters),
+
+    /// The [LE Rand](super::HostHci::le_rand) command.
+    LeRand,
+
+    /// The [LE Start Encryption](super::HostHci::le_start_encryption) command.
+    LeStartEncryption(EncryptionParameters),
+
+    /// The [LE Long Term Key Request Reply](super::HostHci::le_long_term_key_request_reply)
+    /// command.
+    LeLongTermKeyRequestReply {
+        /// Connection handle of the connection that requested the key.
+        conn_handle: ConnectionHandle,
+        /// The long term key for the connection.
+        key: EncryptionKey,
+    },
+
+    /// The [LE Long Term Key Request Negative Reply](super::HostHci::le_long_term_key_request_negative_reply)
+    /// command.
+    LeLongTermKeyRequestNegativeReply(ConnectionHandle),
+
+    /// The [LE Read Supported States](super::HostHci::le_read_supported_states) command.
+    LeReadSupportedStates,
+
+    /// The [LE Receiver Test](super::HostHci::le_receiver_test) command. Includes the RF channel.
+    LeReceiverTest(u8),
+
+    /// The [LE Transmitter Test](super::HostHci::le_transmitter_test) command.
+    LeTransmitterTest {
+        /// RF channel to transmit on.
+        channel: u8,
+        /// Length of the test payload, in bytes.
+        payload_length: usize,
+        /// Contents of the test payload.
+        payload: TestPacketPayload,
+    },
+
+    /// The [LE Test End](super::HostHci::le_test_end) command.
+    LeTestEnd,
+
+    /// The [LE Set Data Length](super::HostHci::le_set_data_length) command.
+    LeSetDataLength {
+        /// Connection handle whose data length is set.
+        conn_handle: ConnectionHandle,
+        /// Preferred maximum number of payload octets.
+        tx_octets: u16,
+        /// Preferred maximum transmission time, in microseconds.
+        tx_time: u16,
+    },
+
+    /// The [LE Read Suggested Default Data Length](super::HostHci::le_read_suggested_default_data_length)
+    /// command.
+    LeReadSuggestedDefaultDataLength,
+
+    /// The [LE Write Suggested Default Data Length](super::HostHci::le_write_suggested_default_data_length)
+    /// command.
+    LeWriteSuggestedDefaultDataLength {
+        /// Suggested maximum number of payload octets.
+        tx_octets: u16,
+        /// Suggested maximum transmission time, in microseconds.
+        tx_time: u16,
+    },
+
+    /// The [LE Read Maximum Data Length](super::HostHci::le_read_maximum_data_length) command.
+    LeReadMaximumDataLength,
+
+    /// The [LE Read Local P-256 Public Key](super::HostHci::le_read_local_p256_public_key)
+    /// command.
+    LeReadLocalP256PublicKey,
+
+    /// The [LE Generate DHKey](super::HostHci::le_generate_dhkey) command. Includes the remote
+    /// device's public key.
+    LeGenerateDHKey(P256PublicKey),
+
+    /// The [LE Add Device to Resolving List](super::HostHci::le_add_device_to_resolving_list)
+    /// command.
+    LeAddDeviceToResolvingList(ResolvingListParameters),
+
+    /// The [LE Remove Device from Resolving List](super::HostHci::le_remove_device_from_resolving_list)
+    /// command. Includes the identity address of the peer.
+    LeRemoveDeviceFromResolvingList(BdAddrType),
+
+    /// The [LE Clear Resolving List](super::HostHci::le_clear_resolving_list) command.
+    LeClearResolvingList,
+
+    /// The [LE Read Resolving List Size](super::HostHci::le_read_resolving_list_size) command.
+    LeReadResolvingListSize,
+
+    /// The [LE Read Peer Resolvable Address](super::HostHci::le_read_peer_resolvable_address)
+    /// command. Includes the identity address of the peer.
+    LeReadPeerResolvableAddress(BdAddrType),
+
+    /// The [LE Read Local Resolvable Address](super::HostHci::le_read_local_resolvable_address)
+    /// command. Includes the identity address of the peer.
+    LeReadLocalResolvableAddress(BdAddrType),
+
+    /// The [LE Set Address Resolution Enable](super::HostHci::le_set_address_resolution_enable)
+    /// command.
+    LeSetAddressResolutionEnable(bool),
+
+    /// The [LE Set Resolvable Private Address Timeout](super::HostHci::le_set_resolvable_private_address_timeout)
+    /// command.
+    LeSetResolvablePrivateAddressTimeout(core::time::Duration),
+
+    /// The [LE Read PHY](super::HostHci::le_read_phy) command.
+    LeReadPhy(ConnectionHandle),
+
+    /// The [LE Set Default PHY](super::HostHci::le_set_default_phy) command.
+    LeSetDefaultPhy(PhyPreferences),
+
+    /// The [LE Set PHY](super::HostHci::le_set_phy) command.
+    LeSetPhy {
+        /// Connection handle whose PHYs should change.
+        conn_handle: ConnectionHandle,
+        /// Preferred transmitter and receiver PHYs.
+        phys: PhyPreferences,
+        /// Preferred coding on the LE Coded PHY.
+        options: PhyOptions,
+    },
+
+    /// The [LE Set Extended Advertising Parameters](super::HostHci::le_set_extended_advertising_parameters)
+    /// command.
+    LeSetExtendedAdvertisingParameters(ExtendedAdvertisingParameters),
+
+    /// The [LE Set Extended Advertising Data](super::HostHci::le_set_extended_advertising_data)
+    /// command.
+    LeSetExtendedAdvertisingData(ExtendedAdvertisingData<'a>),
+
+    /// The [LE Set Extended Scan Response Data](super::HostHci::le_set_extended_scan_response_data)
+    /// command.
+    LeSetExtendedScanResponseData(ExtendedAdvertisingData<'a>),
+
+    /// The [LE Set Extended Advertising Enable](super::HostHci::le_set_extended_advertising_enable)
+    /// command.
+    LeSetExtendedAdvertisingEnable {
+        /// Enable or disable advertising.
+        enable: bool,
+        /// Advertising sets to enable or disable. Empty to disable all sets.
+        sets: AdvSetList<'a>,
+    },
+
+    /// The [LE Read Maximum Advertising Data Length](super::HostHci::le_read_maximum_advertising_data_length)
+    /// command.
+    LeReadMaximumAdvertisingDataLength,
+
+    /// The [LE Read Number of Supported Advertising Sets](super::HostHci::le_read_number_of_supported_advertising_sets)
+    /// command.
+    LeReadNumberOfSupportedAdvertisingSets,
+
+    /// The [LE Remove Advertising Set](super::HostHci::le_remove_advertising_set) command.
+    LeRemoveAdvertisingSet(AdvertisingHandle),
+
+    /// The [LE Clear Advertising Sets](super::HostHci::le_clear_advertising_sets) command.
+    LeClearAdvertisingSets,
+
+    /// The [LE Set Periodic Advertising Parameters](super::HostHci::le_set_periodic_advertising_parameters)
+    /// command.
+    LeSetPeriodicAdvertisingParameters(PeriodicAdvertisingParameters),
+
+    /// The [LE Set Periodic Advertising Data](super::HostHci::le_set_periodic_advertising_data)
+    /// command.
+    LeSetPeriodicAdvertisingData(PeriodicAdvertisingData<'a>),
+
+    /// The [LE Set Periodic Advertising Enable](super::HostHci::le_set_periodic_advertising_enable)
+    /// command.
+    LeSetPeriodicAdvertisingEnable {
+        /// Enable or disable periodic advertising.
+        enable: bool,
+        /// Advertising set in which periodic advertising is enabled or disabled.
+        adv_handle: AdvertisingHandle,
+    },
+
+    /// The [LE Set Extended Scan Parameters](super::HostHci::le_set_extended_scan_parameters)
+    /// command.
+    LeSetExtendedScanParameters(ExtendedScanParameters),
+
+    /// The [LE Set Extended Scan Enable](super::HostHci::le_set_extended_scan_enable) command.
+    LeSetExtendedScanEnable(ExtendedScanEnable),
+
+    /// The [LE Extended Create Connection](super::HostHci::le_extended_create_connection) command.
+    LeExtendedCreateConnection(ExtendedConnectionParameters),
+
+    /// The [LE Periodic Advertising Create Sync](super::HostHci::le_periodic_advertising_create_sync)
+    /// command.
+    LePeriodicAdvertisingCreateSync(PeriodicAdvertisingSyncParameters),
+
+    /// The [LE Periodic Advertising Create Sync Cancel](super::HostHci::le_periodic_advertising_create_sync_cancel)
+    /// command.
+    LePeriodicAdvertisingCreateSyncCancel,
+
+    /// The [LE Periodic Advertising Terminate Sync](super::HostHci::le_periodic_advertising_terminate_sync)
+    /// command.
+    LePeriodicAdvertisingTerminateSync(SyncHandle),
+
+    /// The [LE Add Device to Periodic Advertiser List](super::HostHci::le_add_device_to_periodic_advertiser_list)
+    /// command.
+    LeAddDeviceToPeriodicAdvertiserList(PeriodicAdvertiser),
+
+    /// The [LE Remove Device from Periodic Advertiser List](super::HostHci::le_remove_device_from_periodic_advertiser_list)
+    /// command.
+    LeRemoveDeviceFromPeriodicAdvertiserList(PeriodicAdvertiser),
+
+    /// The [LE Clear Periodic Advertiser List](super::HostHci::le_clear_periodic_advertiser_list)
+    /// command.
+    LeClearPeriodicAdvertiserList,
+
+    /// The [LE Read Periodic Advertiser List Size](super::HostHci::le_read_periodic_advertiser_list_size)
+    /// command.
+    LeReadPeriodicAdvertiserListSize,
+
+    /// The [LE Set Privacy Mode](super::HostHci::le_set_privacy_mode) command.
+    LeSetPrivacyMode {
+        /// Identity address of the peer.
+        peer_identity_address: BdAddrType,
+        /// Privacy mode used for the peer.
+        privacy_mode: PrivacyMode,
+    },
+
+    /// The [LE Periodic Advertising Sync Transfer](super::HostHci::le_periodic_advertising_sync_transfer)
+    /// command.
+    LePeriodicAdvertisingSyncTransfer {
+        /// Connection to send the synchronization information on.
+        conn_handle: ConnectionHandle,
+        /// Value provided by the Host to the peer device.
+        service_data: u16,
+        /// Periodic advertising train whose synchronization information is sent.
+        sync_handle: SyncHandle,
+    },
+
+    /// The [LE Periodic Advertising Set Info Transfer](super::HostHci::le_periodic_advertising_set_info_transfer)
+    /// command.
+    LePeriodicAdvertisingSetInfoTransfer {
+        /// Connection to send the synchronization information on.
+        conn_handle: ConnectionHandle,
+        /// Value provided by the Host to the peer device.
+        service_data: u16,
+        /// Advertising set whose periodic advertising synchronization information is sent.
+        adv_handle: AdvertisingHandle,
+    },
+
+    /// The [LE Set Periodic Advertising Sync Transfer Parameters](super::HostHci::le_set_periodic_advertising_sync_transfer_parameters)
+    /// command.
+    LeSetPeriodicAdvertisingSyncTransferParameters {
+        /// Connection on which the synchronization information is received.
+        conn_handle: ConnectionHandle,
+        /// How the synchronization information is processed.
+        params: SyncTransferParameters,
+    },
+
+    /// The [LE Set Default Periodic Advertising Sync Transfer Parameters](super::HostHci::le_set_default_periodic_advertising_sync_transfer_parameters)
+    /// command.
+    LeSetDefaultPeriodicAdvertisingSyncTransferParameters(SyncTransferParameters),
+
+    /// The [LE Generate DHKey (v2)](super::HostHci::le_generate_dhkey_v2) command.
+    LeGenerateDHKeyV2 {
+        /// Public key of the remote device.
+        remote_key: P256PublicKey,
+        /// Private key used to generate the DHKey.
+        key_type: PrivateKeyType,
+    },
+
+    /// The [LE Read ISO TX Sync](super::HostHci::le_read_iso_tx_sync) command.
+    LeReadIsoTxSync(ConnectionHandle),
+
+    /// The [LE Set CIG Parameters](super::HostHci::le_set_cig_parameters) command.
+    LeSetCigParameters {
+        /// Parameters of the CIG.
+        params: CigParameters,
+        /// Parameters of each CIS in the CIG.
+        cis: CisParametersList<'a>,
+    },
+
+    /// The [LE Set CIG Parameters Test](super::HostHci::le_set_cig_parameters_test) command.
+    LeSetCigParametersTest {
+        /// Parameters of the CIG.
+        params: CigTestParameters,
+        /// Parameters of each CIS in the CIG.
+        cis: CisTestParametersList<'a>,
+    },
+
+    /// The [LE Create CIS](super::HostHci::le_create_cis) command.
+    LeCreateCis(CisConnectionList<'a>),
+
+    /// The [LE Remove CIG](super::HostHci::le_remove_cig) command.
+    LeRemoveCig(u8),
+
+    /// The [LE Accept CIS Request](super::HostHci::le_accept_cis_request) command.
+    LeAcceptCisRequest(ConnectionHandle),
+
+    /// The [LE Reject CIS Request](super::HostHci::le_reject_cis_request) command.
+    LeRejectCisRequest {
+        /// Connection handle of the requested CIS.
+        conn_handle: ConnectionHandle,
+        /// Reason the request is rejected.
+        reason: Status,
+    },
+
+    /// The [LE Create BIG](super::HostHci::le_create_big) command.
+    LeCreateBig(BigParameters),
+
+    /// The [LE Terminate BIG](super::HostHci::le_terminate_big) command.
+    LeTerminateBig {
+        /// Identifies the BIG.
+        big_handle: BigHandle,
+        /// Reason the BIG is terminated.
+        reason: Status,
+    },
+
+    /// The [LE BIG Create Sync](super::HostHci::le_big_create_sync) command.
+    LeBigCreateSync(BigSyncParameters<'a>),
+
+    /// The [LE BIG Terminate Sync](super::HostHci::le_big_terminate_sync) command.
+    LeBigTerminateSync(BigHandle),
+
+    /// The [LE Setup ISO Data Path](super::HostHci::le_setup_iso_data_path) command.
+    LeSetupIsoDataPath(IsoDataPathParameters<'a>),
+
+    /// The [LE Remove ISO Data Path](super::HostHci::le_remove_iso_data_path) command.
+    LeRemoveIsoDataPath {
+        /// Connection handle of the CIS or BIS.
+        conn_handle: ConnectionHandle,
+        /// Data paths to remove.
+        directions: DataPathDirections,
+    },
+
+    /// The [LE Enhanced Read Transmit Power
+    /// Level](super::HostHci::le_enhanced_read_transmit_power_level) command.
+    LeEnhancedReadTransmitPowerLevel {
+        /// ACL connection whose transmit power levels are read.
+        conn_handle: ConnectionHandle,
+        /// PHY whose transmit power levels are read.
+        phy: PowerControlPhy,
+    },
+
+    /// The [LE Read Remote Transmit Power
+    /// Level](super::HostHci::le_read_remote_transmit_power_level) command.
+    LeReadRemoteTransmitPowerLevel {
+        /// ACL connection whose remote transmit power level is read.
+        conn_handle: ConnectionHandle,
+        /// PHY whose transmit power level is read.
+        phy: PowerControlPhy,
+    },
+
+    /// The [LE Set Path Loss Reporting
+    /// Parameters](super::HostHci::le_set_path_loss_reporting_parameters) command.
+    LeSetPathLossReportingParameters(PathLossReportingParameters),
+
+    /// The [LE Set Path Loss Reporting
+    /// Enable](super::HostHci::le_set_path_loss_reporting_enable) command.
+    LeSetPathLossReportingEnable {
+        /// ACL connection whose path loss is monitored.
+        conn_handle: ConnectionHandle,
+        /// Whether path loss reporting is enabled.
+        enable: bool,
+    },
+
+    /// The [LE Set Transmit Power Reporting
+    /// Enable](super::HostHci::le_set_transmit_power_reporting_enable) command.
+    LeSetTransmitPowerReportingEnable {
+        /// ACL connection whose transmit power levels are reported.
+        conn_handle: ConnectionHandle,
+        /// Whether changes of the local transmit power level are reported.
+        local_enable: bool,
+        /// Whether changes of the remote transmit power level are reported.
+        remote_enable: bool,
+    },
+
+    /// The [LE Set Default Subrate](super::HostHci::le_set_default_subrate) command.
+    LeSetDefaultSubrate(SubrateParameters),
+
+    /// The [LE Subrate Request](super::HostHci::le_subrate_request) command.
+    LeSubrateRequest {
+        /// ACL connection whose subrating parameters are changed.
+        conn_handle: ConnectionHandle,
+        /// Requested subrating parameters.
+        params: SubrateParameters,
+    },
+
+    /// The [LE Set Connectionless CTE Transmit
+    /// Parameters](super::HostHci::le_set_connectionless_cte_transmit_parameters) command.
+    LeSetConnectionlessCteTransmitParameters(ConnectionlessCteTransmitParameters<'a>),
+
+    /// The [LE Set Connectionless CTE Transmit
+    /// Enable](super::HostHci::le_set_connectionless_cte_transmit_enable) command.
+    LeSetConnectionlessCteTransmitEnable {
+        /// Advertising set whose periodic advertising carries the Constant Tone Extensions.
+        adv_handle: AdvertisingHandle,
+        /// Whether Constant Tone Extensions are transmitted.
+        enable: bool,
+    },
+
+    /// The [LE Set Connectionless IQ Sampling
+    /// Enable](super::HostHci::le_set_connectionless_iq_sampling_enable) command.
+    LeSetConnectionlessIqSamplingEnable(ConnectionlessIqSamplingParameters<'a>),
+
+    /// The [LE Set Connection CTE Receive
+    /// Parameters](super::HostHci::le_set_connection_cte_receive_parameters) command.
+    LeSetConnectionCteReceiveParameters(ConnectionCteReceiveParameters<'a>),
+
+    /// The [LE Set Connection CTE Transmit
+    /// Parameters](super::HostHci::le_set_connection_cte_transmit_parameters) command.
+    LeSetConnectionCteTransmitParameters(ConnectionCteTransmitParameters<'a>),
+
+    /// The [LE Connection CTE Request Enable](super::HostHci::le_connection_cte_request_enable)
+    /// command.
+    LeConnectionCteRequestEnable(ConnectionCteRequestParameters),
+
+    /// The [LE Connection CTE Response
+    /// Enable](super::HostHci::le_connection_cte_response_enable) command.
+    LeConnectionCteResponseEnable {
+        /// ACL connection on which Constant Tone Extension requests are answered.
+        conn_handle: ConnectionHandle,
+        /// Whether Constant Tone Extension requests are answered.
+        enable: bool,
+    },
+
+    /// The [LE Read Antenna Information](super::HostHci::le_read_antenna_information) command.
+    LeReadAntennaInformation,
+
+    /// A vendor-specific command.
+    Vendor(VendorCommand<'a>),
+}
+
+/// Types of errors that may occur when deserializing a command packet.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Error {
+    /// The command's opcode was not recognized. Includes the unrecognized opcode.
+    UnknownOpcode(Opcode),
+
+    /// The packet was too short to contain the header, or did not match the length given in the
+    /// header, or was too short for the parameters of its command. Includes the actual and the
+    /// expected length, respectively.
+    BadLength(usize, usize),
+
+    /// A status or reason code was not recognized. Includes the unrecognized byte.
+    BadStatus(u8),
+
+    /// The event mask of a [`SetEventMask`](Command::SetEventMask) command included a reserved
+    /// bit. Includes the flags.
+    BadEventFlags(u64),
+
+    /// The event mask of an [`LeSetEventMask`](Command::LeSetEventMask) command included a
+    /// reserved bit. Includes the flags.
+    BadLeEventFlags(u64),
+
+    /// The type of transmit power level was not recognized. Includes the unrecognized byte.
+    BadTxPowerLevelType(u8),
+
+    /// The flow control setting was not recognized. Includes the unrecognized byte.
+    BadFlowControl(u8),
+
+    /// The advertising type was not recognized. Includes the unrecognized byte.
+    BadAdvertisingType(u8),
+
+    /// The advertising interval was invalid. Includes the error returned when building the
+    /// [`AdvertisingInterval`].
+    BadAdvertisingInterval(AdvertisingIntervalError),
+
+    /// The own address type was not recognized. Includes the unrecognized byte.
+    BadOwnAddressType(u8),
+
+    /// The type of a Bluetooth address was not recognized. Includes the unrecognized byte.
+    BadBdAddrType(u8),
+
+    /// The type of a peer address was not recognized. Includes the unrecognized byte.
+    BadPeerAddrType(u8),
+
+    /// An advertising channel map included a reserved bit. Includes the map.
+    BadChannelMap(u8),
+
+    /// The advertising filter policy was not recognized. Includes the unrecognized byte.
+    BadAdvertisingFilterPolicy(u8),
+
+    /// The scan type was not recognized. Includes the unrecognized byte.
+    BadScanType(u8),
+
+    /// The scan filter policy was not recognized. Includes the unrecognized byte.
+    BadScanFilterPolicy(u8),
+
+    /// The initiator filter policy was not recognized. Includes the unrecognized byte.
+    BadConnectionFilterPolicy(u8),
+
+    /// The scan window was invalid. Includes the error returned when building the
+    /// [`ScanWindow`].
+    BadScanWindow(ScanWindowError),
+
+    /// The connection interval was invalid. Includes the error returned when building the
+    /// [`ConnectionInterval`].
+    BadConnectionInterval(ConnectionIntervalError),
+
+    /// The expected connection length was invalid. Includes the error returned when building the
+    /// [`ExpectedConnectionLength`].
+    BadExpectedConnectionLength(ExpectedConnectionLengthError),
+
+    /// The channel classification included a reserved bit. Includes the classification.
+    BadChannelClassification([u8; 5]),
+
+    /// The test packet payload type was not recognized. Includes the unrecognized byte.
+    BadTestPacketPayload(u8),
+
+    /// The PHY directions without preference included a reserved bit. Includes the flags.
+    BadAllPhys(u8),
+
+    /// A set of PHYs included a reserved bit. Includes the flags.
+    BadPhyMask(u8),
+
+    /// The preferred coding on the LE Coded PHY was not recognized. Includes the unrecognized
+    /// value.
+    BadPhyOptions(u16),
+
+    /// The privacy mode was not recognized. Includes the unrecognized byte.
+    BadPrivacyMode(u8),
+
+    /// The private key type was not recognized. Includes the unrecognized byte.
+    BadPrivateKeyType(u8),
+
+    /// The advertising event properties included a reserved bit. Includes the properties.
+    BadAdvertisingEvent(u16),
+
+    /// The extended advertising interval was invalid. Includes the error returned when building
+    /// the [`ExtendedAdvertisingInterval`].
+    BadExtendedAdvertisingInterval(ExtendedAdvertisingIntervalError),
+
+    /// The advertising PHY was not recognized. Includes the unrecognized byte.
+    BadAdvertisingPhy(u8),
+
+    /// The advertising data operation was not recognized. Includes the unrecognized byte.
+    BadAdvertisingOperation(u8),
+
+    /// The duplicate filtering setting was not recognized. Includes the unrecognized byte.
+    BadFilterDuplicates(u8),
+
+    /// The periodic advertising properties included a reserved bit. Includes the properties.
+    BadPeriodicAdvertisingProperties(u16),
+
+    /// The periodic advertising sync options included a reserved bit. Includes the options.
+    BadSyncOptions(u8),
+
+    /// The Constant Tone Extension types for periodic advertising sync included a reserved bit.
+    /// Includes the types.
+    BadSyncCteType(u8),
+
+    /// The periodic advertising sync transfer mode was not recognized. Includes the unrecognized
+    /// byte.
+    BadSyncTransferMode(u8),
+
+    /// The CIS packing was not recognized. Includes the unrecognized byte.
+    BadPacking(u8),
+
+    /// The CIS framing was not recognized. Includes the unrecognized byte.
+    BadFraming(u8),
+
+    /// The worst-case sleep clock accuracy was not recognized. Includes the unrecognized byte.
+    BadSleepClockAccuracy(u8),
+
+    /// The data path direction was not recognized. Includes the unrecognized byte.
+    BadDataPathDirection(u8),
+
+    /// The LE Power Control PHY was not recognized. Includes the unrecognized byte.
+    BadPowerControlPhy(u8),
+
+    /// The subrating parameters were invalid. Includes the error returned when building the
+    /// [`SubrateParameters`].
+    BadSubrateParameters(SubrateParametersError),
+
+    /// The Constant Tone Extension type was not recognized. Includes the unrecognized byte.
+    BadCteType(u8),
+
+    /// The Constant Tone Extension types included a reserved bit. Includes the types.
+    BadCteTypes(u8),
+
+    /// The slot durations were not recognized. Includes the unrecognized byte.
+    BadSlotDuration(u8),
+
+    /// A vendor-specific error was detected when deserializing a vendor-specific command.
+    Vendor(VendorCommandError),
+}
+
+impl<'a> Command<'a> {
+    /// Deserializes a command from the given packet. The packet should contain all of the data
+    /// needed to deserialize the command.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadLength`](Error::BadLength) error if the length of the packet is not sufficient to
+    ///   either (1) contain a packet header, or (2) contain the packet data as defined by the
+    ///   header, or (3) contain the parameters of the command.
+    /// - [`UnknownOpcode`](Error::UnknownOpcode) error if the opcode is not one of the commands
+    ///   this crate can send.
+    /// - Other errors if the parameters of the command are invalid. This includes
+    ///   vendor-specific errors for vendor commands.
+    pub fn new(packet: Packet<'a>) -> Result<Command<'a>, Error> {
+        require_len_at_least!(packet.0, PACKET_HEADER_LENGTH);
+        require_len!(packet.0, packet.full_length());
+
+        let opcode = Opcode(LittleEndian::read_u16(&packet.0[0..]));
+        let params = &packet.0[PACKET_HEADER_LENGTH..];
+
+        match opcode {
+            crate::opcode::DISCONNECT => to_disconnect(params),
+            crate::opcode::READ_REMOTE_VERSION_INFO => Ok(Command::ReadRemoteVersionInformation(
+                to_conn_handle(params)?,
+            )),
+            crate::opcode::SET_EVENT_MASK => to_set_event_mask(params),
+            crate::opcode::RESET => to_no_params(params, Command::Reset),
+            crate::opcode::READ_TX_POWER_LEVEL => to_read_tx_power_level(params),
+            crate::opcode::SET_CONTROLLER_TO_HOST_FLOW_CONTROL => {
+                to_set_controller_to_host_flow_control(params)
+            }
+            crate::opcode::HOST_BUFFER_SIZE => to_host_buffer_size(params),
+            crate::opcode::NUMBER_OF_COMPLETED_PACKETS => to_number_of_completed_packets(params),
+            crate::opcode::READ_LOCAL_VERSION_INFO => {
+                to_no_params(params, Command::ReadLocalVersionInformation)
+            }
+            crate::opcode::READ_LOCAL_SUPPORTED_COMMANDS => {
+                to_no_params(params, Command::ReadLocalSupportedCommands)
+            }
+            crate::opcode::READ_LOCAL_SUPPORTED_FEATURES => {
+                to_no_params(params, Command::ReadLocalSupportedFeatures)
+            }
+            crate::opcode::READ_BD_ADDR => to_no_params(params, Command::ReadBdAddr),
+            crate::opcode::READ_RSSI => Ok(Command::ReadRssi(to_conn_handle(params)?)),
+            crate::opcode::LE_SET_EVENT_MASK => to_le_set_event_mask(params),
+            crate::opcode::LE_READ_BUFFER_SIZE => to_no_params(params, Command::LeReadBufferSize),
+            crate::opcode::LE_READ_LOCAL_SUPPORTED_FEATURES => {
+                to_no_params(params, Command::LeReadLocalSupportedFeatures)
+            }
+            crate::opcode::LE_SET_RANDOM_ADDRESS => {
+                require_len!(params, 6);
+                Ok(Command::LeSetRandomAddress(to_bd_addr(params)))
+            }
+            crate::opcode::LE_SET_ADVERTISING_PARAMETERS => {
+                to_le_set_advertising_parameters(params)
+            }
+            crate::opcode::LE_READ_ADVERTISING_CHANNEL_TX_POWER => {
+                to_no_params(params, Command::LeReadAdvertisingChannelTxPower)
+            }
+            crate::opcode::LE_SET_ADVERTISING_DATA => {
+                Ok(Command::LeSetAdvertisingData(to_outbound_data(params)?))
+            }
+            crate::opcode::LE_SET_SCAN_RESPONSE_DATA => {
+                Ok(Command::LeSetScanResponseData(to_outbound_data(params)?))
+            }
+            crate::opcode::LE_SET_ADVERTISE_ENABLE => {
+                require_len!(params, 1);
+                Ok(Command::LeSetAdvertisingEnable(params[0] != 0))
+            }
+            crate::opcode::LE_SET_SCAN_PARAMETERS => to_le_set_scan_parameters(params),
+            crate::opcode::LE_SET_SCAN_ENABLE => {
+                require_len!(params, 2);
+                Ok(Command::LeSetScanEnable {
+                    enable: params[0] != 0,
+                    filter_duplicates: params[1] != 0,
+                })
+            }
+            crate::opcode::LE_CREATE_CONNECTION => to_le_create_connection(params),
+            crate::opcode::LE_CREATE_CONNECTION_CANCEL => {
+                to_no_params(params, Command::LeCreateConnectionCancel)
+            }
+            crate::opcode::LE_READ_WHITE_LIST_SIZE => {
+                to_no_params(params, Command::LeReadWhiteListSize)
+            }
+            crate::opcode::LE_CLEAR_WHITE_LIST => to_no_params(params, Command::LeClearWhiteList),
+            crate::opcode::LE_ADD_DEVICE_TO_WHITE_LIST => {
+                require_len!(params, 7);
+                if params[0] == ANONYMOUS_ADVERTISERS {
+                    Ok(Command::LeAddAnonAdvertisingDevicesToWhiteList)
+                } else {
+                    Ok(Command::LeAddDeviceToWhiteList(to_bd_addr_type(params)?))
+                }
+            }
+            crate::opcode::LE_REMOVE_DEVICE_FROM_WHITE_LIST => {
+                require_len!(params, 7);
+                if params[0] == ANONYMOUS_ADVERTISERS {
+                    Ok(Command::LeRemoveAnonAdvertisingDevicesFromWhiteList)
+                } else {
+                    Ok(Command::LeRemoveDeviceFromWhiteList(to_bd_addr_type(
+                        params,
+                    )?))
+                }
+            }
+            crate::opcode::LE_CONNECTION_UPDATE => to_le_connection_update(params),
+            crate::opcode::LE_SET_HOST_CHANNEL_CLASSIFICATION => {
+                to_le_set_host_channel_classification(params)
+            }
+            crate::opcode::LE_READ_CHANNEL_MAP => {
+                Ok(Command::LeReadChannelMap(to_conn_handle(params)?))
+            }
+            crate::opcode::LE_READ_REMOTE_USED_FEATURES => {
+                Ok(Command::LeReadRemoteUsedFeatures(to_conn_handle(params)?))
+            }
+            crate::opcode::LE_ENCRYPT => to_le_encrypt(params),
+            crate::opcode::LE_RAND => to_no_params(params, Command::LeRand),
+            crate::opcode::LE_START_ENCRYPTION => to_le_start_encryption(params),
+            crate::opcode::LE_LTK_REQUEST_REPLY => to_le_ltk_request_reply(params),
+            crate::opcode::LE_LTK_REQUEST_NEGATIVE_REPLY => Ok(
+                Command::LeLongTermKeyRequestNegativeReply(to_conn_handle(params)?),
+            ),
+            crate::opcode::LE_READ_STATES => to_no_params(params, Command::LeReadSupportedStates),
+            crate::opcode::LE_RECEIVER_TEST => {
+                require_len!(params, 1);
+                Ok(Command::LeReceiverTest(params[0]))
+            }
+            crate::opcode::LE_TRANSMITTER_TEST => to_le_transmitter_test(params),
+            crate::opcode::LE_TEST_END => to_no_params(params, Command::LeTestEnd),
+            crate::opcode::LE_SET_DATA_LENGTH => to_le_set_data_length(params),
+            crate::opcode::LE_READ_SUGGESTED_DEFAULT_DATA_LENGTH => {
+                to_no_params(params, Command::LeReadSuggestedDefaultDataLength)
+            }
+            crate::opcode::LE_WRITE_SUGGESTED_DEFAULT_DATA_LENGTH => {
+                to_le_write_suggested_default_data_length(params)
+            }
+            crate::opcode::LE_READ_MAXIMUM_DATA_LENGTH => {
+                to_no_params(params, Command::LeReadMaximumDataLength)
+            }
+            crate::opcode::LE_READ_LOCAL_P256_PUBLIC_KEY => {
+                to_no_params(params, Command::LeReadLocalP256PublicKey)
+            }
+            crate::opcode::LE_GENERATE_DHKEY => {
+                require_len!(params, 64);
+                Ok(Command::LeGenerateDHKey(P256PublicKey(
+                    params.try_into().unwrap(),
+                )))
+            }
+            crate::opcode::LE_ADD_DEVICE_TO_RESOLVING_LIST => {
+                to_le_add_device_to_resolving_list(params)
+            }
+            crate::opcode::LE_REMOVE_DEVICE_FROM_RESOLVING_LIST => {
+                require_len!(params, 7);
+                Ok(Command::LeRemoveDeviceFromResolvingList(to_bd_addr_type(
+                    params,
+                )?))
+            }
+            crate::opcode::LE_CLEAR_RESOLVING_LIST => {
+                to_no_params(params, Command::LeClearResolvingList)
+            }
+            crate::opcode::LE_READ_RESOLVING_LIST_SIZE => {
+                to_no_params(params, Command::LeReadResolvingListSize)
+            }
+            crate::opcode::LE_READ_PEER_RESOLVABLE_ADDRESS => {
+                require_len!(params, 7);
+                Ok(Command::LeReadPeerResolvableAddress(to_bd_addr_type(
+                    params,
+                )?))
+            }
+            crate::opcode::LE_READ_LOCAL_RESOLVABLE_ADDRESS => {
+                require_len!(params, 7);
+                Ok(Command::LeReadLocalResolvableAddress(to_bd_addr_type(
+                    params,
+                )?))
+            }
+            crate::opcode::LE_SET_ADDRESS_RESOLUTION_ENABLE => {
+                require_len!(params, 1);
+                Ok(Command::LeSetAddressResolutionEnable(params[0] != 0))
+            }
+            crate::opcode::LE_SET_RESOLVABLE_PRIVATE_ADDRESS_TIMEOUT => {
+                require_len!(params, 2);
+                Ok(Command::LeSetResolvablePrivateAddressTimeout(
+                    core::time::Duration::from_secs(LittleEndian::read_u16(params) as u64),
+                ))
+            }
+            crate::opcode::LE_READ_PHY => Ok(Command::LeReadPhy(to_conn_handle(params)?)),
+            crate::opcode::LE_SET_DEFAULT_PHY => to_le_set_default_phy(params),
+            crate::opcode::LE_SET_PHY => to_le_set_phy(params),
+            crate::opcode::LE_SET_EXTENDED_ADVERTISING_PARAMETERS => {
+                to_le_set_extended_advertising_parameters(params)
+            }
+            crate::opcode::LE_SET_EXTENDED_ADVERTISING_DATA => Ok(
+                Command::LeSetExtendedAdvertisingData(to_extended_advertising_data(params)?),
+            ),
+            crate::opcode::LE_SET_EXTENDED_SCAN_RESPONSE_DATA => Ok(
+                Command::LeSetExtendedScanResponseData(to_extended_advertising_data(params)?),
+            ),
+            crate::opcode::LE_SET_EXTENDED_ADVERTISING_ENABLE => {
+                to_le_set_extended_advertising_enable(params)
+            }
+            crate::opcode::LE_READ_MAXIMUM_ADVERTISING_DATA_LENGTH => {
+                to_no_params(params, Command::LeReadMaximumAdvertisingDataLength)
+            }
+            crate::opcode::LE_READ_NUMBER_OF_SUPPORTED_ADVERTISING_SETS => {
+                to_no_params(params, Command::LeReadNumberOfSupportedAdvertisingSets)
+            }
+            crate::opcode::LE_REMOVE_ADVERTISING_SET => {
+                require_len!(params, 1);
+                Ok(Command::LeRemoveAdvertisingSet(AdvertisingHandle(
+                    params[0],
+                )))
+            }
+            crate::opcode::LE_CLEAR_ADVERTISING_SETS => {
+                to_no_params(params, Command::LeClearAdvertisingSets)
+            }
+            crate::opcode::LE_SET_PERIODIC_ADVERTISING_PARAMETERS => {
+                to_le_set_periodic_advertising_parameters(params)
+            }
+            crate::opcode::LE_SET_PERIODIC_ADVERTISING_DATA => {
+                to_le_set_periodic_advertising_data(params)
+            }
+            crate::opcode::LE_SET_PERIODIC_ADVERTISING_ENABLE => {
+                require_len!(params, 2);
+                Ok(Command::LeSetPeriodicAdvertisingEnable {
+                    enable: params[0] != 0,
+                    adv_handle: AdvertisingHandle(params[1]),
+                })
+            }
+            crate::opcode::LE_SET_EXTENDED_SCAN_PARAMETERS => {
+                to_le_set_extended_scan_parameters(params)
+            }
+            crate::opcode::LE_SET_EXTENDED_SCAN_ENABLE => to_le_set_extended_scan_enable(params),
+            crate::opcode::LE_EXTENDED_CREATE_CONNECTION => {
+                to_le_extended_create_connection(params)
+            }
+            crate::opcode::LE_PERIODIC_ADVERTISING_CREATE_SYNC => {
+                to_le_periodic_advertising_create_sync(params)
+            }
+            crate::opcode::LE_PERIODIC_ADVERTISING_CREATE_SYNC_CANCEL => {
+                to_no_params(params, Command::LePeriodicAdvertisingCreateSyncCancel)
+            }
+            crate::opcode::LE_PERIODIC_ADVERTISING_TERMINATE_SYNC => {
+                require_len!(params, 2);
+                Ok(Command::LePeriodicAdvertisingTerminateSync(SyncHandle(
+                    LittleEndian::read_u16(params),
+                )))
+            }
+            crate::opcode::LE_ADD_DEVICE_TO_PERIODIC_ADVERTISER_LIST => Ok(
+                Command::LeAddDeviceToPeriodicAdvertiserList(to_periodic_advertiser(params)?),
+            ),
+            crate::opcode::LE_REMOVE_DEVICE_FROM_PERIODIC_ADVERTISER_LIST => Ok(
+                Command::LeRemoveDeviceFromPeriodicAdvertiserList(to_periodic_advertiser(params)?),
+            ),
+            crate::opcode::LE_CLEAR_PERIODIC_ADVERTISER_LIST => {
+                to_no_params(params, Command::LeClearPeriodicAdvertiserList)
+            }
+            crate::opcode::LE_READ_PERIODIC_ADVERTISER_LIST_SIZE => {
+                to_no_params(params, Command::LeReadPeriodicAdvertiserListSize)
+            }
+            crate::opcode::LE_SET_PRIVACY_MODE => to_le_set_privacy_mode(params),
+            crate::opcode::LE_PERIODIC_ADVERTISING_SYNC_TRANSFER => {
+                require_len!(params, 6);
+                Ok(Command::LePeriodicAdvertisingSyncTransfer {
+                    conn_handle: ConnectionHandle(LittleEndian::read_u16(&params[0..])),
+                    service_data: LittleEndian::read_u16(&params[2..]),
+                    sync_handle: SyncHandle(LittleEndian::read_u16(&params[4..])),
+                })
+            }
+            crate::opcode::LE_PERIODIC_ADVERTISING_SET_INFO_TRANSFER => {
+                require_len!(params, 5);
+                Ok(Command::LePeriodicAdvertisingSetInfoTransfer {
+                    conn_handle: ConnectionHandle(LittleEndian::read_u16(&params[0..])),
+                    service_data: LittleEndian::read_u16(&params[2..]),
+                    adv_handle: AdvertisingHandle(params[4]),
+                })
+            }
+            crate::opcode::LE_SET_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS => {
+                require_len!(params, 8);
+                Ok(Command::LeSetPeriodicAdvertisingSyncTransferParameters {
+                    conn_handle: ConnectionHandle(LittleEndian::read_u16(&params[0..])),
+                    params: to_sync_transfer_parameters(&params[2..])?,
+                })
+            }
+            crate::opcode::LE_SET_DEFAULT_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS => {
+                require_len!(params, 6);
+                Ok(
+                    Command::LeSetDefaultPeriodicAdvertisingSyncTransferParameters(
+                        to_sync_transfer_parameters(params)?,
+                    ),
+                )
+            }
+            crate::opcode::LE_GENERATE_DHKEY_V2 => to_le_generate_dhkey_v2(params),
+            crate::opcode::LE_READ_ISO_TX_SYNC => {
+                Ok(Command::LeReadIsoTxSync(to_conn_handle(params)?))
+            }
+            crate::opcode::LE_SET_CIG_PARAMETERS => to_le_set_cig_parameters(params),
+            crate::opcode::LE_SET_CIG_PARAMETERS_TEST => to_le_set_cig_parameters_test(params),
+            crate::opcode::LE_CREATE_CIS => to_le_create_cis(params),
+            crate::opcode::LE_REMOVE_CIG => {
+                require_len!(params, 1);
+                Ok(Command::LeRemoveCig(params[0]))
+            }
+            crate::opcode::LE_ACCEPT_CIS_REQUEST => {
+                Ok(Command::LeAcceptCisRequest(to_conn_handle(params)?))
+            }
+            crate::opcode::LE_REJECT_CIS_REQUEST => {
+                require_len!(params, 3);
+                Ok(Command::LeRejectCisRequest {
+                    conn_handle: ConnectionHandle(LittleEndian::read_u16(params)),
+                    reason: to_status(params[2])?,
+                })
+            }
+            crate::opcode::LE_CREATE_BIG => to_le_create_big(params),
+            crate::opcode::LE_TERMINATE_BIG => {
+                require_len!(params, 2);
+                Ok(Command::LeTerminateBig {
+                    big_handle: BigHandle(params[0]),
+                    reason: to_status(params[1])?,
+                })
+            }
+            crate::opcode::LE_BIG_CREATE_SYNC => to_le_big_create_sync(params),
+            crate::opcode::LE_BIG_TERMINATE_SYNC => {
+                require_len!(params, 1);
+                Ok(Command::LeBigTerminateSync(BigHandle(params[0])))
+            }
+            crate::opcode::LE_SETUP_ISO_DATA_PATH => to_le_setup_iso_data_path(params),
+            crate::opcode::LE_REMOVE_ISO_DATA_PATH => {
+                require_len!(params, 3);
+                Ok(Command::LeRemoveIsoDataPath {
+                    conn_handle: ConnectionHandle(LittleEndian::read_u16(params)),
+                    directions: DataPathDirections::from_bits(params[2])
+                        .ok_or(Error::BadDataPathDirection(params[2]))?,
+                })
+            }
+            crate::opcode::LE_ENHANCED_READ_TRANSMIT_POWER_LEVEL => {
+                require_len!(params, 3);
+                Ok(Command::LeEnhancedReadTransmitPowerLevel {
+                    conn_handle: ConnectionHandle(LittleEndian::read_u16(params)),
+                    phy: to_power_control_phy(params[2])?,
+                })
+            }
+            crate::opcode::LE_READ_REMOTE_TRANSMIT_POWER_LEVEL => {
+                require_len!(params, 3);
+                Ok(Command::LeReadRemoteTransmitPowerLevel {
+                    conn_handle: ConnectionHandle(LittleEndian::read_u16(params)),
+                    phy: to_power_control_phy(params[2])?,
+                })
+            }
+            crate::opcode::LE_SET_PATH_LOSS_REPORTING_PARAMETERS => {
+                to_le_set_path_loss_reporting_parameters(params)
+            }
+            crate::opcode::LE_SET_PATH_LOSS_REPORTING_ENABLE => {
+                require_len!(params, 3);
+                Ok(Command::LeSetPathLossReportingEnable {
+                    conn_handle: ConnectionHandle(LittleEndian::read_u16(params)),
+                    enable: params[2] != 0,
+                })
+            }
+            crate::opcode::LE_SET_TRANSMIT_POWER_REPORTING_ENABLE => {
+                require_len!(params, 4);
+                Ok(Command::LeSetTransmitPowerReportingEnable {
+                    conn_handle: ConnectionHandle(LittleEndian::read_u16(params)),
+                    local_enable: params[2] != 0,
+                    remote_enable: params[3] != 0,
+                })
+            }
+            crate::opcode::LE_SET_DEFAULT_SUBRATE => {
+                require_len!(params, 10);
+                Ok(Command::LeSetDefaultSubrate(to_subrate_parameters(params)?))
+            }
+            crate::opcode::LE_SUBRATE_REQUEST => {
+                require_len!(params, 12);
+                Ok(Command::LeSubrateRequest {
+                    conn_handle: ConnectionHandle(LittleEndian::read_u16(params)),
+                    params: to_subrate_parameters(&params[2..])?,
+                })
+            }
+            crate::opcode::LE_SET_CONNECTIONLESS_CTE_TRANSMIT_PARAMETERS => {
+                to_le_set_connectionless_cte_transmit_parameters(params)
+            }
+            crate::opcode::LE_SET_CONNECTIONLESS_CTE_TRANSMIT_ENABLE => {
+                require_len!(params, 2);
+                Ok(Command::LeSetConnectionlessCteTransmitEnable {
+                    adv_handle: AdvertisingHandle(params[0]),
+                    enable: params[1] != 0,
+                })
+            }
+            crate::opcode::LE_SET_CONNECTIONLESS_IQ_SAMPLING_ENABLE => {
+                to_le_set_connectionless_iq_sampling_enable(params)
+            }
+            crate::opcode::LE_SET_CONNECTION_CTE_RECEIVE_PARAMETERS => {
+                to_le_set_connection_cte_receive_parameters(params)
+            }
+            crate::opcode::LE_SET_CONNECTION_CTE_TRANSMIT_PARAMETERS => {
+                to_le_set_connection_cte_transmit_parameters(params)
+            }
+            crate::opcode::LE_CONNECTION_CTE_REQUEST_ENABLE => {
+                require_len!(params, 7);
+                Ok(Command::LeConnectionCteRequestEnable(
+                    ConnectionCteRequestParameters {
+                        conn_handle: ConnectionHandle(LittleEndian::read_u16(params)),
+                        enable: params[2] != 0,
+                        cte_request_interval: LittleEndian::read_u16(&params[3..]),
+                        requested_cte_length: to_cte_length(params[5]),
+                        requested_cte_type: to_cte_type(params[6])?,
+                    },
+                ))
+            }
+            crate::opcode::LE_CONNECTION_CTE_RESPONSE_ENABLE => {
+                require_len!(params, 3);
+                Ok(Command::LeConnectionCteResponseEnable {
+                    conn_handle: ConnectionHandle(LittleEndian::read_u16(params)),
+                    enable: params[2] != 0,
+                })
+            }
+            crate::opcode::LE_READ_ANTENNA_INFORMATION => {
+                to_no_params(params, Command::LeReadAntennaInformation)
+            }
+            other => {
+                if other.ogf() != VENDOR_OGF {
+                    return Err(Error::UnknownOpcode(other));
+                }
+
+                Ok(Command::Vendor(VendorCommand::new(other, params)?))
+            }
+        }
+    }
+}
+
+// Address type used by the white list commands to refer to anonymous advertisers.
+const ANONYMOUS_ADVERTISERS: u8 = 0xFF;
+
+fn to_no_params<'a>(params: &[u8], command: Command<'a>) -> Result<Command<'a>, Error> {
+    require_len!(params, 0);
+    Ok(command)
+}
+
+fn to_disconnect(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 3);
+    Ok(Command::Disconnect {
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&params[0..])),
+        reason: to_status(params[2])?,
+    })
+}
+
+fn to_set_event_mask(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 8);
+    let bits = LittleEndian::read_u64(params);
+    EventFlags::from_bits(bits)
+        .map(Command::SetEventMask)
+        .ok_or(Error::BadEventFlags(bits))
+}
+
+fn to_read_tx_power_level(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 3);
+    Ok(Command::ReadTxPowerLevel {
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&params[0..])),
+        power_level_type: match params[2] {
+            0x00 => TxPowerLevel::Current,
+            0x01 => TxPowerLevel::Maximum,
+            other => return Err(Error::BadTxPowerLevelType(other)),
+        },
+    })
+}
+
+fn to_set_controller_to_host_flow_control(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 1);
+    Ok(Command::SetControllerToHostFlowControl(match params[0] {
+        0x00 => FlowControl::Off,
+        0x01 => FlowControl::HciAclDataOnly,
+        0x02 => FlowControl::HciSyncDataOnly,
+        0x03 => FlowControl::Both,
+        other => return Err(Error::BadFlowControl(other)),
+    }))
+}
+
+fn to_host_buffer_size(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 7);
+    Ok(Command::HostBufferSize(HostBufferSize {
+        acl_data_packet_length: LittleEndian::read_u16(&params[0..]),
+        sync_data_packet_length: params[2],
+        total_acl_data_packets: LittleEndian::read_u16(&params[3..]),
+        total_sync_data_packets: LittleEndian::read_u16(&params[5..]),
+    }))
+}
+
+fn to_number_of_completed_packets(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len_at_least!(params, 1);
+    let num_handles = params[0] as usize;
+    require_len_at_least!(params, 1 + 4 * num_handles);
+
+    let data_len = params.len() - 1;
+    let mut data_buf = [0; NUMBER_OF_COMPLETED_PACKETS_MAX_LEN];
+    require_len_at_least!(data_buf, data_len);
+    data_buf[..data_len].copy_from_slice(&params[1..]);
+
+    Ok(Command::NumberOfCompletedPackets(
+        NumberOfCompletedPackets {
+            num_handles,
+            data_buf,
+        },
+    ))
+}
+
+fn to_le_set_event_mask(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 8);
+    let bits = LittleEndian::read_u64(params);
+    LeEventFlags::from_bits(bits)
+        .map(Command::LeSetEventMask)
+        .ok_or(Error::BadLeEventFlags(bits))
+}
+
+fn to_le_set_advertising_parameters(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 15);
+    Ok(Command::LeSetAdvertisingParameters(AdvertisingParameters {
+        advertising_interval: to_advertising_interval(&params[0..5])?,
+        own_address_type: to_own_address_type(params[5])?,
+        peer_address: to_bd_addr_type(&params[6..13])?,
+        advertising_channel_map: to_channels(params[13])?,
+        advertising_filter_policy: to_advertising_filter_policy(params[14])?,
+    }))
+}
+
+fn to_outbound_data(params: &[u8]) -> Result<&[u8], Error> {
+    require_len_at_least!(params, 1);
+    let data_len = params[0] as usize;
+    require_len_at_least!(params, 1 + data_len);
+
+    Ok(&params[1..1 + data_len])
+}
+
+fn to_le_set_scan_parameters(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 7);
+    Ok(Command::LeSetScanParameters(ScanParameters {
+        scan_type: to_scan_type(params[0])?,
+        scan_window: to_scan_window(&params[1..5])?,
+        own_address_type: to_own_address_type(params[5])?,
+        filter_policy: to_scan_filter_policy(params[6])?,
+    }))
+}
+
+fn to_le_create_connection(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 25);
+    Ok(Command::LeCreateConnection(ConnectionParameters {
+        scan_window: to_scan_window(&params[0..4])?,
+        initiator_filter_policy: to_connection_filter_policy(params[4])?,
+        peer_address: to_peer_addr_type(&params[5..12])?,
+        own_address_type: to_own_address_type(params[12])?,
+        conn_interval: to_conn_interval(&params[13..21])?,
+        expected_connection_length: to_expected_connection_length(&params[21..25])?,
+    }))
+}
+
+fn to_le_connection_update(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 14);
+    Ok(Command::LeConnectionUpdate(ConnectionUpdateParameters {
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&params[0..])),
+        conn_interval: to_conn_interval(&params[2..10])?,
+        expected_connection_length: to_expected_connection_length(&params[10..14])?,
+    }))
+}
+
+fn to_le_set_host_channel_classification(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 5);
+    ChannelClassification::from_bits(params)
+        .map(Command::LeSetHostChannelClassification)
+        .ok_or_else(|| Error::BadChannelClassification(params.try_into().unwrap()))
+}
+
+fn to_le_encrypt(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 32);
+    Ok(Command::LeEncrypt(AesParameters {
+        key: EncryptionKey(params[..16].try_into().unwrap()),
+        plaintext_data: PlaintextBlock(params[16..].try_into().unwrap()),
+    }))
+}
+
+fn to_le_start_encryption(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 28);
+    Ok(Command::LeStartEncryption(EncryptionParameters {
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&params[0..])),
+        random_number: LittleEndian::read_u64(&params[2..]),
+        encrypted_diversifier: LittleEndian::read_u16(&params[10..]),
+        long_term_key: EncryptionKey(params[12..].try_into().unwrap()),
+    }))
+}
+
+fn to_le_ltk_request_reply(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 18);
+    Ok(Command::LeLongTermKeyRequestReply {
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&params[0..])),
+        key: EncryptionKey(params[2..].try_into().unwrap()),
+    })
+}
+
+fn to_le_transmitter_test(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 3);
+    Ok(Command::LeTransmitterTest {
+        channel: params[0],
+        payload_length: params[1] as usize,
+        payload: match params[2] {
+            0x00 => TestPacketPayload::PrbS9,
+            0x01 => TestPacketPayload::Nibbles10,
+            0x02 => TestPacketPayload::Bits10,
+            0x03 => TestPacketPayload::PrbS15,
+            0x04 => TestPacketPayload::All1,
+            0x05 => TestPacketPayload::All0,
+            0x06 => TestPacketPayload::Nibbles01,
+            0x07 => TestPacketPayload::Bits01,
+            other => return Err(Error::BadTestPacketPayload(other)),
+        },
+    })
+}
+
+fn to_le_set_data_length(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 6);
+    Ok(Command::LeSetDataLength {
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&params[0..])),
+        tx_octets: LittleEndian::read_u16(&params[2..]),
+        tx_time: LittleEndian::read_u16(&params[4..]),
+    })
+}
+
+fn to_le_write_suggested_default_data_length(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 4);
+    Ok(Command::LeWriteSuggestedDefaultDataLength {
+        tx_octets: LittleEndian::read_u16(&params[0..]),
+        tx_time: LittleEndian::read_u16(&params[2..]),
+    })
+}
+
+fn to_le_add_device_to_resolving_list(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 39);
+    Ok(Command::LeAddDeviceToResolvingList(
+        ResolvingListParameters {
+            peer_identity_address: to_bd_addr_type(&params[0..7])?,
+            peer_irk: EncryptionKey(params[7..23].try_into().unwrap()),
+            local_irk: EncryptionKey(params[23..39].try_into().unwrap()),
+        },
+    ))
+}
+
+fn to_le_set_default_phy(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 3);
+    Ok(Command::LeSetDefaultPhy(to_phy_preferences(params)?))
+}
+
+fn to_le_set_phy(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 7);
+    Ok(Command::LeSetPhy {
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(&params[0..])),
+        phys: to_phy_preferences(&params[2..5])?,
+        options: match LittleEndian::read_u16(&params[5..]) {
+            0x0000 => PhyOptions::NoPreference,
+            0x0001 => PhyOptions::S2,
+            0x0002 => PhyOptions::S8,
+            other => return Err(Error::BadPhyOptions(other)),
+        },
+    })
+}
+
+fn to_le_set_extended_advertising_parameters(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 25);
+    let adv_event_properties = LittleEndian::read_u16(&params[1..]);
+    Ok(Command::LeSetExtendedAdvertisingParameters(
+        ExtendedAdvertisingParameters {
+            adv_handle: AdvertisingHandle(params[0]),
+            adv_event_properties: AdvertisingEvent::from_bits(adv_event_properties)
+                .ok_or(Error::BadAdvertisingEvent(adv_event_properties))?,
+            adv_interval: ExtendedAdvertisingInterval::from_u24_bytes(&params[3..9])
+                .map_err(Error::BadExtendedAdvertisingInterval)?,
+            primary_adv_channel_map: to_channels(params[9])?,
+            own_address_type: to_own_address_type(params[10])?,
+            peer_address: to_bd_addr_type(&params[11..18])?,
+            adv_filter_policy: to_advertising_filter_policy(params[18])?,
+            adv_tx_power: match params[19] {
+                0x7F => None,
+                power => Some(crate::Dbm(u8::cast_signed(power))),
+            },
+            primary_adv_phy: to_advertising_phy(params[20])?,
+            secondary_adv_max_skip: params[21],
+            secondary_adv_phy: to_advertising_phy(params[22])?,
+            adv_sid: params[23],
+            scan_request_notification_enable: params[24] != 0,
+        },
+    ))
+}
+
+fn to_extended_advertising_data(params: &[u8]) -> Result<ExtendedAdvertisingData<'_>, Error> {
+    require_len_at_least!(params, 4);
+    let data_end = 4 + params[3] as usize;
+    require_len!(params, data_end);
+
+    Ok(ExtendedAdvertisingData {
+        adv_handle: AdvertisingHandle(params[0]),
+        operation: to_advertising_operation(params[1])?,
+        fragment: params[2] == 0,
+        data: &params[4..data_end],
+    })
+}
+
+fn to_le_set_extended_advertising_enable(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len_at_least!(params, 2);
+    let list_end = 2 + ADV_SET_LEN * params[1] as usize;
+    require_len!(params, list_end);
+
+    Ok(Command::LeSetExtendedAdvertisingEnable {
+        enable: params[0] != 0,
+        sets: AdvSetList {
+            data: &params[2..list_end],
+        },
+    })
+}
+
+fn to_le_set_extended_scan_parameters(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len_at_least!(params, 3);
+    let phys = to_phy_mask(params[2])?;
+    if phys.contains(PhyMask::LE_2M) {
+        return Err(Error::BadPhyMask(params[2]));
+    }
+    require_len!(params, 3 + 5 * phys.bits().count_ones() as usize);
+
+    let mut chunks = params[3..].chunks_exact(5);
+    let mut next_phy = |phy| -> Result<Option<PhyScanParameters>, Error> {
+        if !phys.contains(phy) {
+            return Ok(None);
+        }
+
+        let bytes = chunks.next().unwrap();
+        Ok(Some(PhyScanParameters {
+            scan_type: to_scan_type(bytes[0])?,
+            scan_window: to_scan_window(&bytes[1..5])?,
+        }))
+    };
+    Ok(Command::LeSetExtendedScanParameters(
+        ExtendedScanParameters {
+            own_address_type: to_own_address_type(params[0])?,
+            filter_policy: to_scan_filter_policy(params[1])?,
+            le_1m_phy: next_phy(PhyMask::LE_1M)?,
+            le_coded_phy: next_phy(PhyMask::LE_CODED)?,
+        },
+    ))
+}
+
+fn to_le_set_extended_scan_enable(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 6);
+    Ok(Command::LeSetExtendedScanEnable(ExtendedScanEnable {
+        enable: params[0] != 0,
+        filter_duplicates: match params[1] {
+            0x00 => FilterDuplicates::Disabled,
+            0x01 => FilterDuplicates::Enabled,
+            0x02 => FilterDuplicates::ResetEachPeriod,
+            other => return Err(Error::BadFilterDuplicates(other)),
+        },
+        duration: core::time::Duration::from_millis(10)
+            * u32::from(LittleEndian::read_u16(&params[2..])),
+        period: core::time::Duration::from_millis(1280)
+            * u32::from(LittleEndian::read_u16(&params[4..])),
+    }))
+}
+
+fn to_le_extended_create_connection(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len_at_least!(params, 10);
+    let phys = to_phy_mask(params[9])?;
+    require_len!(params, 10 + 16 * phys.bits().count_ones() as usize);
+
+    let mut chunks = params[10..].chunks_exact(16);
+    let mut next_phy = |phy| -> Result<Option<PhyConnectionParameters>, Error> {
+        if !phys.contains(phy) {
+            return Ok(None);
+        }
+
+        let bytes = chunks.next().unwrap();
+        Ok(Some(PhyConnectionParameters {
+            scan_window: to_scan_window(&bytes[0..4])?,
+            conn_interval: to_conn_interval(&bytes[4..12])?,
+            expected_connection_length: to_expected_connection_length(&bytes[12..16])?,
+        }))
+    };
+    Ok(Command::LeExtendedCreateConnection(
+        ExtendedConnectionParameters {
+            initiator_filter_policy: to_connection_filter_policy(params[0])?,
+            own_address_type: to_own_address_type(params[1])?,
+            peer_address: to_peer_addr_type(&params[2..9])?,
+            le_1m_phy: next_phy(PhyMask::LE_1M)?,
+            le_2m_phy: next_phy(PhyMask::LE_2M)?,
+            le_coded_phy: next_phy(PhyMask::LE_CODED)?,
+        },
+    ))
+}
+
+fn to_le_set_periodic_advertising_parameters(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 7);
+    let properties = LittleEndian::read_u16(&params[5..]);
+    if properties & !PeriodicAdvertisingParameters::INCLUDE_TX_POWER != 0 {
+        return Err(Error::BadPeriodicAdvertisingProperties(properties));
+    }
+
+    Ok(Command::LeSetPeriodicAdvertisingParameters(
+        PeriodicAdvertisingParameters {
+            adv_handle: AdvertisingHandle(params[0]),
+            interval_min: core::time::Duration::from_micros(1250)
+                * u32::from(LittleEndian::read_u16(&params[1..])),
+            interval_max: core::time::Duration::from_micros(1250)
+                * u32::from(LittleEndian::read_u16(&params[3..])),
+            include_tx_power: properties != 0,
+        },
+    ))
+}
+
+fn to_le_set_periodic_advertising_data(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len_at_least!(params, 3);
+    let data_end = 3 + params[2] as usize;
+    require_len!(params, data_end);
+
+    Ok(Command::LeSetPeriodicAdvertisingData(
+        PeriodicAdvertisingData {
+            adv_handle: AdvertisingHandle(params[0]),
+            operation: to_advertising_operation(params[1])?,
+            data: &params[3..data_end],
+        },
+    ))
+}
+
+fn to_le_periodic_advertising_create_sync(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 14);
+    Ok(Command::LePeriodicAdvertisingCreateSync(
+        PeriodicAdvertisingSyncParameters {
+            options: SyncOptions::from_bits(params[0]).ok_or(Error::BadSyncOptions(params[0]))?,
+            adv_sid: params[1],
+            adv_address: to_bd_addr_type(&params[2..9])?,
+            skip: LittleEndian::read_u16(&params[9..]),
+            sync_timeout: core::time::Duration::from_millis(10)
+                * u32::from(LittleEndian::read_u16(&params[11..])),
+            sync_cte_type: SyncCteType::from_bits(params[13])
+                .ok_or(Error::BadSyncCteType(params[13]))?,
+        },
+    ))
+}
+
+fn to_periodic_advertiser(params: &[u8]) -> Result<PeriodicAdvertiser, Error> {
+    require_len!(params, 8);
+    Ok(PeriodicAdvertiser {
+        address: to_bd_addr_type(&params[0..7])?,
+        adv_sid: params[7],
+    })
+}
+
+fn to_le_set_privacy_mode(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 8);
+    Ok(Command::LeSetPrivacyMode {
+        peer_identity_address: to_bd_addr_type(&params[0..7])?,
+        privacy_mode: match params[7] {
+            0x00 => PrivacyMode::Network,
+            0x01 => PrivacyMode::Device,
+            other => return Err(Error::BadPrivacyMode(other)),
+        },
+    })
+}
+
+/// Deserializes the periodic advertising sync transfer parameters: the mode (1 byte), skip (2
+/// bytes), sync timeout (2 bytes) and CTE types (1 byte).
+fn to_sync_transfer_parameters(bytes: &[u8]) -> Result<SyncTransferParameters, Error> {
+    Ok(SyncTransferParameters {
+        mode: match bytes[0] {
+            0x00 => SyncTransferMode::NoSync,
+            0x01 => SyncTransferMode::ReportsDisabled,
+            0x02 => SyncTransferMode::ReportsEnabled,
+            0x03 => SyncTransferMode::ReportsEnabledFilterDuplicates,
+            other => return Err(Error::BadSyncTransferMode(other)),
+        },
+        skip: LittleEndian::read_u16(&bytes[1..]),
+        sync_timeout: core::time::Duration::from_millis(10)
+            * u32::from(LittleEndian::read_u16(&bytes[3..])),
+        sync_cte_type: SyncCteType::from_bits(bytes[5]).ok_or(Error::BadSyncCteType(bytes[5]))?,
+    })
+}
+
+fn to_le_generate_dhkey_v2(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 65);
+    Ok(Command::LeGenerateDHKeyV2 {
+        remote_key: P256PublicKey(params[0..64].try_into().unwrap()),
+        key_type: match params[64] {
+            0x00 => PrivateKeyType::Generated,
+            0x01 => PrivateKeyType::Debug,
+            other => return Err(Error::BadPrivateKeyType(other)),
+        },
+    })
+}
+
+fn to_le_set_cig_parameters(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len_at_least!(params, 15);
+    let list_end = 15 + CisParameters::LEN * params[14] as usize;
+    require_len!(params, list_end);
+
+    let cis = CisParametersList {
+        data: &params[15..list_end],
+    };
+    for bytes in cis.data.chunks_exact(CisParameters::LEN) {
+        to_cis_parameters(bytes)?;
+    }
+
+    Ok(Command::LeSetCigParameters {
+        params: CigParameters {
+            cig_id: params[0],
+            sdu_interval_c_to_p: to_duration_us(&params[1..]),
+            sdu_interval_p_to_c: to_duration_us(&params[4..]),
+            worst_case_sca: to_sleep_clock_accuracy(params[7])?,
+            packing: to_packing(params[8])?,
+            framing: to_framing(params[9])?,
+            max_transport_latency_c_to_p: core::time::Duration::from_millis(u64::from(
+                LittleEndian::read_u16(&params[10..]),
+            )),
+            max_transport_latency_p_to_c: core::time::Duration::from_millis(u64::from(
+                LittleEndian::read_u16(&params[12..]),
+            )),
+        },
+        cis,
+    })
+}
+
+fn to_le_set_cig_parameters_test(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len_at_least!(params, 15);
+    let list_end = 15 + CisTestParameters::LEN * params[14] as usize;
+    require_len!(params, list_end);
+
+    let cis = CisTestParametersList {
+        data: &params[15..list_end],
+    };
+    for bytes in cis.data.chunks_exact(CisTestParameters::LEN) {
+        to_cis_test_parameters(bytes)?;
+    }
+
+    Ok(Command::LeSetCigParametersTest {
+        params: CigTestParameters {
+            cig_id: params[0],
+            sdu_interval_c_to_p: to_duration_us(&params[1..]),
+            sdu_interval_p_to_c: to_duration_us(&params[4..]),
+            ft_c_to_p: params[7],
+            ft_p_to_c: params[8],
+            iso_interval: core::time::Duration::from_micros(1250)
+                * u32::from(LittleEndian::read_u16(&params[9..])),
+            worst_case_sca: to_sleep_clock_accuracy(params[11])?,
+            packing: to_packing(params[12])?,
+            framing: to_framing(params[13])?,
+        },
+        cis,
+    })
+}
+
+fn to_le_create_cis(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len_at_least!(params, 1);
+    let list_end = 1 + CisConnection::LEN * params[0] as usize;
+    require_len!(params, list_end);
+
+    Ok(Command::LeCreateCis(CisConnectionList {
+        data: &params[1..list_end],
+    }))
+}
+
+fn to_le_create_big(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 31);
+
+    Ok(Command::LeCreateBig(BigParameters {
+        big_handle: BigHandle(params[0]),
+        adv_handle: AdvertisingHandle(params[1]),
+        num_bis: params[2],
+        sdu_interval: to_duration_us(&params[3..]),
+        max_sdu: LittleEndian::read_u16(&params[6..]),
+        max_transport_latency: core::time::Duration::from_millis(u64::from(
+            LittleEndian::read_u16(&params[8..]),
+        )),
+        rtn: params[10],
+        phy: to_phy_mask(params[11])?,
+        packing: to_packing(params[12])?,
+        framing: to_framing(params[13])?,
+        broadcast_code: to_broadcast_code(&params[14..31]),
+    }))
+}
+
+fn to_le_big_create_sync(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len_at_least!(params, 24);
+    let bis_end = 24 + params[23] as usize;
+    require_len!(params, bis_end);
+
+    Ok(Command::LeBigCreateSync(BigSyncParameters {
+        big_handle: BigHandle(params[0]),
+        sync_handle: SyncHandle(LittleEndian::read_u16(&params[1..])),
+        broadcast_code: to_broadcast_code(&params[3..20]),
+        mse: params[20],
+        big_sync_timeout: core::time::Duration::from_millis(10)
+            * u32::from(LittleEndian::read_u16(&params[21..])),
+        bis: &params[24..bis_end],
+    }))
+}
+
+// Deserializes the encryption flag (1 byte) followed by the broadcast code (16 bytes).
+fn to_broadcast_code(bytes: &[u8]) -> Option<[u8; 16]> {
+    if bytes[0] == 0 {
+        return None;
+    }
+
+    let mut code = [0; 16];
+    code.copy_from_slice(&bytes[1..17]);
+    Some(code)
+}
+
+fn to_le_setup_iso_data_path(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len_at_least!(params, 13);
+    let config_end = 13 + params[12] as usize;
+    require_len!(params, config_end);
+
+    Ok(Command::LeSetupIsoDataPath(IsoDataPathParameters {
+        conn_handle: ConnectionHandle(LittleEndian::read_u16(params)),
+        direction: match params[2] {
+            0x00 => DataPathDirection::Input,
+            0x01 => DataPathDirection::Output,
+            other => return Err(Error::BadDataPathDirection(other)),
+        },
+        data_path_id: params[3],
+        codec_id: CodecId {
+            coding_format: params[4],
+            company_id: LittleEndian::read_u16(&params[5..]),
+            vendor_codec_id: LittleEndian::read_u16(&params[7..]),
+        },
+        controller_delay: to_duration_us(&params[9..]),
+        codec_configuration: &params[13..config_end],
+    }))
+}
+
+/// Deserializes a 3-byte duration in microseconds.
+fn to_le_set_path_loss_reporting_parameters(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len!(params, 8);
+
+    let to_threshold = |value| match value {
+        0xFF => None,
+        value => Some(value),
+    };
+    Ok(Command::LeSetPathLossReportingParameters(
+        PathLossReportingParameters {
+            conn_handle: ConnectionHandle(LittleEndian::read_u16(params)),
+            high_threshold: to_threshold(params[2]),
+            high_hysteresis: params[3],
+            low_threshold: to_threshold(params[4]),
+            low_hysteresis: params[5],
+            min_time_spent: LittleEndian::read_u16(&params[6..]),
+        },
+    ))
+}
+
+fn to_power_control_phy(value: u8) -> Result<PowerControlPhy, Error> {
+    value
+        .try_into()
+        .map_err(|_| Error::BadPowerControlPhy(value))
+}
+
+fn to_le_set_connectionless_cte_transmit_parameters(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len_at_least!(params, 5);
+
+    Ok(Command::LeSetConnectionlessCteTransmitParameters(
+        ConnectionlessCteTransmitParameters {
+            adv_handle: AdvertisingHandle(params[0]),
+            cte_length: to_cte_length(params[1]),
+            cte_type: to_cte_type(params[2])?,
+            cte_count: params[3],
+            antenna_ids: to_switching_pattern(&params[4..])?,
+        },
+    ))
+}
+
+fn to_le_set_connectionless_iq_sampling_enable(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len_at_least!(params, 6);
+
+    Ok(Command::LeSetConnectionlessIqSamplingEnable(
+        ConnectionlessIqSamplingParameters {
+            sync_handle: SyncHandle(LittleEndian::read_u16(params)),
+            sampling_enable: params[2] != 0,
+            slot_duration: to_slot_duration(params[3])?,
+            max_sampled_ctes: params[4],
+            antenna_ids: to_switching_pattern(&params[5..])?,
+        },
+    ))
+}
+
+fn to_le_set_connection_cte_receive_parameters(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len_at_least!(params, 5);
+
+    Ok(Command::LeSetConnectionCteReceiveParameters(
+        ConnectionCteReceiveParameters {
+            conn_handle: ConnectionHandle(LittleEndian::read_u16(params)),
+            sampling_enable: params[2] != 0,
+            slot_duration: to_slot_duration(params[3])?,
+            antenna_ids: to_switching_pattern(&params[4..])?,
+        },
+    ))
+}
+
+fn to_le_set_connection_cte_transmit_parameters(params: &[u8]) -> Result<Command<'_>, Error> {
+    require_len_at_least!(params, 4);
+
+    Ok(Command::LeSetConnectionCteTransmitParameters(
+        ConnectionCteTransmitParameters {
+            conn_handle: ConnectionHandle(LittleEndian::read_u16(params)),
+            cte_types: CteTypes::from_bits(params[2]).ok_or(Error::BadCteTypes(params[2]))?,
+            antenna_ids: to_switching_pattern(&params[3..])?,
+        },
+    ))
+}
+
+// Deserializes the antenna switching pattern: its length (1 byte) followed by the antenna IDs.
+// The pattern must end the parameters.
+fn to_switching_pattern(bytes: &[u8]) -> Result<&[u8], Error> {
+    let end = 1 + bytes[0] as usize;
+    require_len!(bytes, end);
+
+    Ok(&bytes[1..end])
+}
+
+// Deserializes the length of a Constant Tone Extension, in units of 8 µs.
+fn to_cte_length(value: u8) -> core::time::Duration {
+    core::time::Duration::from_micros(8) * u32::from(value)
+}
+
+fn to_cte_type(value: u8) -> Result<CteType, Error> {
+    value.try_into().map_err(|_| Error::BadCteType(value))
+}
+
+fn to_slot_duration(value: u8) -> Result<SlotDuration, Error> {
+    value.try_into().map_err(|_| Error::BadSlotDuration(value))
+}
+
+fn to_duration_us(bytes: &[u8]) -> core::time::Duration {
+    core::time::Duration::from_micros(u64::from(LittleEndian::read_u24(bytes)))
+}
+
+fn to_sleep_clock_accuracy(value: u8) -> Result<CentralClockAccuracy, Error> {
+    value
+        .try_into()
+        .map_err(|_| Error::BadSleepClockAccuracy(value))
+}
+
+fn to_packing(value: u8) -> Result<Packing, Error> {
+    match value {
+        0x00 => Ok(Packing::Sequential),
+        0x01 => Ok(Packing::Interleaved),
+        other => Err(Error::BadPacking(other)),
+    }
+}
+
+fn to_framing(value: u8) -> Result<Framing, Error> {
+    match value {
+        0x00 => Ok(Framing::Unframed),
+        0x01 => Ok(Framing::Framed),
+        other => Err(Error::BadFraming(other)),
+    }
+}
+
+fn to_cis_parameters(bytes: &[u8]) -> Result<CisParameters, Error> {
+    Ok(CisParameters {
+        cis_id: bytes[0],
+        max_sdu_c_to_p: LittleEndian::read_u16(&bytes[1..]),
+        max_sdu_p_to_c: LittleEndian::read_u16(&bytes[3..]),
+        phy_c_to_p: to_phy_mask(bytes[5])?,
+        phy_p_to_c: to_phy_mask(bytes[6])?,
+        rtn_c_to_p: bytes[7],
+        rtn_p_to_c: bytes[8],
+    })
+}
+
+fn to_cis_test_parameters(bytes: &[u8]) -> Result<CisTestParameters, Error> {
+    Ok(CisTestParameters {
+        cis_id: bytes[0],
+        nse: bytes[1],
+        max_sdu_c_to_p: LittleEndian::read_u16(&bytes[2..]),
+        max_sdu_p_to_c: LittleEndian::read_u16(&bytes[4..]),
+        max_pdu_c_to_p: LittleEndian::read_u16(&bytes[6..]),
+        max_pdu_p_to_c: LittleEndian::read_u16(&bytes[8..]),
+        phy_c_to_p: to_phy_mask(bytes[10])?,
+        phy_p_to_c: to_phy_mask(bytes[11])?,
+        bn_c_to_p: bytes[12],
+        bn_p_to_c: bytes[13],
+    })
+}
+
+/// Deserializes a connection handle that makes up the entire parameter list of a command.
+pub(crate) fn to_conn_handle(params: &[u8]) -> Result<ConnectionHandle, Error> {
+    require_len!(params, 2);
+    Ok(ConnectionHandle(LittleEndian::read_u16(params)))
+}
+
+pub(crate) fn to_status(value: u8) -> Result<Status, Error> {
+    value.try_into().map_err(|e| {
+        let BadStatusError::BadValue(v) = e;
+        Error::BadStatus(v)
+    })
+}
+
+pub(crate) fn to_bd_addr(bytes: &[u8]) -> BdAddr {
+    let mut addr = BdAddr([0; 6]);
+    addr.0.copy_from_slice(&bytes[..6]);
+    addr
+}
+
+/// Deserializes an address type (1 byte) followed by the address (6 bytes).
+pub(crate) fn to_bd_addr_type(bytes: &[u8]) -> Result<BdAddrType, Error> {
+    crate::to_bd_addr_type(bytes[0], to_bd_addr(&bytes[1..7]))
+        .map_err(|e| Error::BadBdAddrType(e.0))
+}
+
+pub(crate) fn to_own_address_type(value: u8) -> Result<OwnAddressType, Error> {
+    match value {
+        0x00 => Ok(OwnAddressType::Public),
+        0x01 => Ok(OwnAddressType::Random),
+        0x02 => Ok(OwnAddressType::PrivateFallbackPublic),
+        0x03 => Ok(OwnAddressType::PrivateFallbackRandom),
+        _ => Err(Error::BadOwnAddressType(value)),
+    }
+}
+
+pub(crate) fn to_advertising_type(value: u8) -> Result<AdvertisingType, Error> {
+    match value {
+        0x00 => Ok(AdvertisingType::ConnectableUndirected),
+        0x01 => Ok(AdvertisingType::ConnectableDirectedHighDutyCycle),
+        0x02 => Ok(AdvertisingType::ScannableUndirected),
+        0x03 => Ok(AdvertisingType::NonConnectableUndirected),
+        0x04 => Ok(AdvertisingType::ConnectableDirectedLowDutyCycle),
+        _ => Err(Error::BadAdvertisingType(value)),
+    }
+}
+
+/// Deserializes an advertising interval: the minimum and maximum (2 bytes each, in units of 0.625
+/// ms) followed by the advertising type (1 byte).
+pub(crate) fn to_advertising_interval(bytes: &[u8]) -> Result<AdvertisingInterval, Error> {
+    let advertising_type = to_advertising_type(bytes[4])?;
+    let builder = AdvertisingInterval::for_type(advertising_type);
+    if advertising_type == AdvertisingType::ConnectableDirectedHighDutyCycle {
+        builder.build()
+    } else {
+        builder.with_range(
+            to_duration_625us(LittleEndian::read_u16(&bytes[0..])),
+            to_duration_625us(LittleEndian::read_u16(&bytes[2..])),
+        )
+    }
+    .map_err(Error::BadAdvertisingInterval)
+}
+
+/// Converts a time in units of 0.625 ms, as used for advertising and scanning intervals, to a
+/// duration.
+pub(crate) fn to_duration_625us(value: u16) -> core::time::Duration {
+    core::time::Duration::from_micros(625) * u32::from(value)
+}
+
+pub(crate) fn to_channels(value: u8) -> Result<Channels, Error> {
+    Channels::from_bits(value).ok_or(Error::BadChannelMap(value))
+}
+
+pub(crate) fn to_advertising_filter_policy(value: u8) -> Result<AdvertisingFilterPolicy, Error> {
+    match value {
+        0x00 => Ok(AdvertisingFilterPolicy::AllowConnectionAndScan),
+        0x01 => Ok(AdvertisingFilterPolicy::AllowConnectionWhiteListScan),
+        0x02 => Ok(AdvertisingFilterPolicy::WhiteListConnectionAllowScan),
+        0x03 => Ok(AdvertisingFilterPolicy::WhiteListConnectionAndScan),
+        _ => Err(Error::BadAdvertisingFilterPolicy(value)),
+    }
+}
+
+pub(crate) fn to_scan_type(value: u8) -> Result<ScanType, Error> {
+    match value {
+        0x00 => Ok(ScanType::Passive),
+        0x01 => Ok(ScanType::Active),
+        _ => Err(Error::BadScanType(value)),
+    }
+}
+
+pub(crate) fn to_scan_filter_policy(value: u8) -> Result<ScanFilterPolicy, Error> {
+    match value {
+        0x00 => Ok(ScanFilterPolicy::AcceptAll),
+        0x01 => Ok(ScanFilterPolicy::WhiteList),
+        0x02 => Ok(ScanFilterPolicy::AddressedToThisDevice),
+        0x03 => Ok(ScanFilterPolicy::WhiteListAddressedToThisDevice),
+        _ => Err(Error::BadScanFilterPolicy(value)),
+    }
+}
+
+pub(crate) fn to_connection_filter_policy(value: u8) -> Result<ConnectionFilterPolicy, Error> {
+    match value {
+        0x00 => Ok(ConnectionFilterPolicy::UseAddress),
+        0x01 => Ok(ConnectionFilterPolicy::WhiteList),
+        _ => Err(Error::BadConnectionFilterPolicy(value)),
+    }
+}
+
+pub(crate) fn to_scan_window(bytes: &[u8]) -> Result<ScanWindow, Error> {
+    ScanWindow::from_bytes(bytes).map_err(Error::BadScanWindow)
+}
+
+pub(crate) fn to_conn_interval(bytes: &[u8]) -> Result<ConnectionInterval, Error> {
+    ConnectionInterval::from_bytes(bytes).map_err(Error::BadConnectionInterval)
+}
+
+pub(crate) fn to_expected_connection_length(
+    bytes: &[u8],
+) -> Result<ExpectedConnectionLength, Error> {
+    ExpectedConnectionLength::from_bytes(bytes).map_err(Error::BadExpectedConnectionLength)
+}
+
+fn to_subrate_parameters(bytes: &[u8]) -> Result<SubrateParameters, Error> {
+    SubrateParameters::from_bytes(bytes).map_err(Error::BadSubrateParameters)
+}
+
+/// Deserializes a peer address type (1 byte) followed by the address (6 bytes).
+pub(crate) fn to_peer_addr_type(bytes: &[u8]) -> Result<PeerAddrType, Error> {
+    let addr = to_bd_addr(&bytes[1..7]);
+    match bytes[0] {
+        0x00 => Ok(PeerAddrType::PublicDeviceAddress(addr)),
+        0x01 => Ok(PeerAddrType::RandomDeviceAddress(addr)),
+        0x02 => Ok(PeerAddrType::PublicIdentityAddress(addr)),
+        0x03 => Ok(PeerAddrType::RandomIdentityAddress(addr)),
+        other => Err(Error::BadPeerAddrType(other)),
+    }
+}
+
+/// Deserializes the preferred PHYs: the directions without preference, then the transmitter and
+/// receiver PHYs (1 byte each).
+pub(crate) fn to_phy_preferences(bytes: &[u8]) -> Result<PhyPreferences, Error> {
+    Ok(PhyPreferences {
+        all_phys: AllPhys::from_bits(bytes[0]).ok_or(Error::BadAllPhys(bytes[0]))?,
+        tx_phys: to_phy_mask(bytes[1])?,
+        rx_phys: to_phy_mask(bytes[2])?,
+    })
+}
+
+pub(crate) fn to_phy_mask(value: u8) -> Result<PhyMask, Error> {
+    PhyMask::from_bits(value).ok_or(Error::BadPhyMask(value))
+}
+
+pub(crate) fn to_advertising_phy(value: u8) -> Result<AdvertisingPhy, Error> {
+    match value {
+        0x01 => Ok(AdvertisingPhy::Le1M),
+        0x02 => Ok(AdvertisingPhy::Le2M),
+        0x03 => Ok(AdvertisingPhy::LeCoded),
+        other => Err(Error::BadAdvertisingPhy(other)),
+    }
+}
+
+pub(crate) fn to_advertising_operation(value: u8) -> Result<AdvertisingOperation, Error> {
+    match value {
+        0x00 => Ok(AdvertisingOperation::IntermediateFragment),
+        0x01 => Ok(AdvertisingOperation::FirstFragment),
+        0x02 => Ok(AdvertisingOperation::LastFragment),
+        0x03 => Ok(AdvertisingOperation::CompleteData),
+        0x04 => Ok(AdvertisingOperation::UnchangedData),
+        other => Err(Error::BadAdvertisingOperation(other)),
+    }
+}
+
+/// List of CIS configurations in a deserialized
+/// [LE Set CIG Parameters](super::HostHci::le_set_cig_parameters) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CisParametersList<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> CisParametersList<'a> {
+    /// Returns the number of CIS configurations in the list.
+    pub fn len(&self) -> usize {
+        self.data.len() / CisParameters::LEN
+    }
+
+    /// Returns true if the list contains no CIS configurations.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns an iterator over the CIS configurations in the list.
+    pub fn iter(&self) -> impl Iterator<Item = CisParameters> + 'a {
+        // The list is validated when the command is deserialized.
+        self.data
+            .chunks_exact(CisParameters::LEN)
+            .filter_map(|bytes| to_cis_parameters(bytes).ok())
+    }
+}
+
+/// List of CIS configurations in a deserialized
+/// [LE Set CIG Parameters Test](super::HostHci::le_set_cig_parameters_test) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CisTestParametersList<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> CisTestParametersList<'a> {
+    /// Returns the number of CIS configurations in the list.
+    pub fn len(&self) -> usize {
+        self.data.len() / CisTestParameters::LEN
+    }
+
+    /// Returns true if the list contains no CIS configurations.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns an iterator over the CIS configurations in the list.
+    pub fn iter(&self) -> impl Iterator<Item = CisTestParameters> + 'a {
+        // The list is validated when the command is deserialized.
+        self.data
+            .chunks_exact(CisTestParameters::LEN)
+            .filter_map(|bytes| to_cis_test_parameters(bytes).ok())
+    }
+}
+
+/// List of CISes in a deserialized [LE Create CIS](super::HostHci::le_create_cis) command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CisConnectionList<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> CisConnectionList<'a> {
+    /// Returns the number of CISes in the list.
+    pub fn len(&self) -> usize {
+        self.data.len() / CisConnection::LEN
+    }
+
+    /// Returns true if the list contains no CISes.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns an iterator over the CISes in the list.
+    pub fn iter(&self) -> impl Iterator<Item = CisConnection> + 'a {
+        self.data
+            .chunks_exact(CisConnection::LEN)
+            .map(|bytes| CisConnection {
+                cis_conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[0..])),
+                acl_conn_handle: ConnectionHandle(LittleEndian::read_u16(&bytes[2..])),
+            })
+    }
+}
@@ -0,0 +1,207 @@
+//! Flow control of HCI commands.
+//!
+//! The controller limits the number of commands the host may send before waiting for their
+//! results. Each [Command Complete](crate::event::Event::CommandComplete) and
+//! [Command Status](crate::event::Event::CommandStatus) event carries the number of command
+//! packets the controller can currently accept (`Num_HCI_Command_Packets`); sending more commands
+//! than that overruns the controller's command queue. See the Bluetooth spec, Vol 4, Part E,
+//! Section 4.4.
+//!
+//! [`CommandCredits`] wraps a [`Controller`] to track that number. Each command written through
+//! the wrapper consumes one credit, and writing a command when no credits remain blocks until the
+//! controller returns one. Each Command Complete or Command Status event read through it replaces
+//! the count with the number the controller reported. This includes the Command
+//! Complete events with opcode 0x0000 that the controller sends on its own: one with 0 credits
+//! during startup tells the host to wait, and a later one with 1 or more tells it that commands
+//! may be sent.
+
+use crate::host::uart::{Packet, UartHci};
+use crate::{Controller, DataController, Opcode};
+
+const PACKET_TYPE_HCI_EVENT: u8 = 0x04;
+const EVENT_COMMAND_COMPLETE: u8 = 0x0E;
+const EVENT_COMMAND_STATUS: u8 = 0x0F;
+
+/// Error returned when no command credits remain. The host must not send a command until the
+/// controller returns a credit in a Command Complete or Command Status event.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NoCommandCredits;
+
+/// Wraps a [`Controller`] to track the number of commands the controller can accept.
+///
+/// The wrapper implements [`Controller`] itself, so all of the command traits are available on it.
+/// A command written when no credits remain is not sent until a credit is returned: the wrapper
+/// reads packets from the controller until then, and passes every packet read, or the error from
+/// reading it, to `on_packet`. To fail instead of blocking, send commands through
+/// [`send`](CommandCredits::send).
+///
+/// Credits are read from each buffer filled by
+/// [`controller_read_into`](Controller::controller_read_into), so packets must be read whole, as
+/// [`UartHci::read`] and [`UartHci::read_into`] do.
+pub struct CommandCredits<C, F> {
+    controller: C,
+    credits: u8,
+    on_packet: F,
+}
+
+impl<C, F> CommandCredits<C, F> {
+    /// Wraps the given controller. The host starts with one credit, as required by the Bluetooth
+    /// spec (Vol 4, Part E, Section 4.4) after power-on or reset.
+    ///
+    /// Packets read while a command waits for a credit are passed to `on_packet`.
+    pub fn new(controller: C, on_packet: F) -> Self {
+        Self::with_credits(controller, 1, on_packet)
+    }
+
+    /// Wraps the given controller, starting with the given number of credits. Use 0 for controllers
+    /// that announce they are ready with a Command Complete event for opcode 0x0000.
+    ///
+    /// Packets read while a command waits for a credit are passed to `on_packet`.
+    pub fn with_credits(controller: C, credits: u8, on_packet: F) -> Self {
+        Self {
+            controller,
+            credits,
+            on_packet,
+        }
+    }
+
+    /// Returns the number of commands that may be sent before the controller returns more credits.
+    pub fn credits(&self) -> u8 {
+        self.credits
+    }
+
+    /// Returns an error if no command may be sent now.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NoCommandCredits`] if no credits remain.
+    pub fn check_credit(&self) -> Result<(), NoCommandCredits> {
+        if self.credits == 0 {
+            return Err(NoCommandCredits);
+        }
+
+        Ok(())
+    }
+
+    /// Returns a reference to the wrapped controller.
+    pub fn controller(&self) -> &C {
+        &self.controller
+    }
+
+    /// Returns a mutable reference to the wrapped controller.
+    ///
+    /// Commands written and events read directly through the wrapped controller do not update the
+    /// credit count.
+    pub fn controller_mut(&mut self) -> &mut C {
+        &mut self.controller
+    }
+
+    /// Returns the wrapped controller.
+    pub fn into_inner(self) -> C {
+        self.controller
+    }
+
+    fn update_credits(&mut self, packet: &[u8]) {
+        if packet.len() < 3 || packet[0] != PACKET_TYPE_HCI_EVENT {
+            return;
+        }
+
+        // The event parameters begin after the packet type, event code and parameter length. The
+        // number of credits is the first parameter of Command Complete, and the second (after the
+        // status) of Command Status.
+        let index = match packet[1] {
+            EVENT_COMMAND_COMPLETE => 3,
+            EVENT_COMMAND_STATUS => 4,
+            _ => return,
+        };
+        if let Some(&credits) = packet.get(index) {
+            self.credits = credits;
+        }
+    }
+}
+
+impl<C: Controller, F> CommandCredits<C, F>
+where
+    F: FnMut(Result<Packet, crate::host::uart::Error>),
+{
+    /// Sends a command through `command` if a credit is available, and returns its result.
+    ///
+    /// ```
+    /// # extern crate stm32wb_hci as hci;
+    /// # use hci::host::command_credits::{CommandCredits, NoCommandCredits};
+    /// # use hci::host::HostHci;
+    /// # use hci::host::uart::{Error, Packet};
+    /// # async fn reset<C: hci::Controller, F: FnMut(Result<Packet, Error>)>(
+    /// #     hci: &mut CommandCredits<C, F>,
+    /// # ) -> Result<(), NoCommandCredits> {
+    /// hci.send(async |hci| hci.reset().await).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NoCommandCredits`] if no credits remain. `command` is not called in this case.
+    pub async fn send<S, R>(&mut self, command: S) -> Result<R, NoCommandCredits>
+    where
+        S: AsyncFnOnce(&mut Self) -> R,
+    {
+        self.check_credit()?;
+
+        Ok(command(self).await)
+    }
+
+    /// Reads packets from the controller until at least one command credit is available. Returns
+    /// immediately if a credit is already available.
+    ///
+    /// Every packet read is passed to `on_packet` in the order it was read, including the events
+    /// that returned credits, since they may also carry the results of earlier commands.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`UartHci::read`]. Credits returned by the packet that failed to
+    /// deserialize are still counted.
+    pub async fn wait_for_credit<P>(
+        &mut self,
+        mut on_packet: P,
+    ) -> Result<(), crate::host::uart::Error>
+    where
+        P: FnMut(Packet),
+    {
+        while self.credits == 0 {
+            on_packet(self.read().await?);
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: Controller, F> Controller for CommandCredits<C, F>
+where
+    F: FnMut(Result<Packet, crate::host::uart::Error>),
+{
+    async fn controller_write(&mut self, opcode: Opcode, payload: &[u8]) {
+        while self.credits == 0 {
+            let packet = self.read().await;
+            (self.on_packet)(packet);
+        }
+
+        self.credits -= 1;
+        self.controller.controller_write(opcode, payload).await;
+    }
+
+    async fn controller_read_into(&mut self, buffer: &mut [u8]) {
+        self.controller.controller_read_into(buffer).await;
+        self.update_credits(buffer);
+    }
+}
+
+impl<C: DataController, F> DataController for CommandCredits<C, F>
+where
+    F: FnMut(Result<Packet, crate::host::uart::Error>),
+{
+    async fn controller_write_data(&mut self, header: &[u8], payload: &[u8]) {
+        self.controller.controller_write_data(header, payload).await;
+    }
+}
@@ -0,0 +1,176 @@
+//! Correlation of commands with the events that answer them.
+//!
+//! Commands sent through [`HostHci`](super::HostHci) and the vendor-specific traits only write to
+//! the controller; the result of the command arrives later, in a
+//! [Command Complete](crate::event::Event::CommandComplete) or
+//! [Command Status](crate::event::Event::CommandStatus) event. [`Correlator`] wraps a
+//! [`Controller`] to remember the opcode of the last command it sent, so that the application can
+//! await the matching event instead of matching opcodes in its own event loop:
+//!
+//! ```
+//! # extern crate stm32wb_hci as hci;
+//! # use hci::host::correlator::{CommandResponse, Correlator, Error};
+//! # use hci::host::HostHci;
+//! # use hci::event::command::ReturnParameters;
+//! # async fn reset<C: hci::Controller>(controller: C) -> Result<(), Error> {
+//! let mut hci = Correlator::new(controller);
+//! hci.reset().await;
+//! match hci.response(|_packet| { /* dispatch unrelated packets */ }).await? {
+//!     CommandResponse::Complete(ReturnParameters::Reset(status)) => { /* ... */ }
+//!     _ => { /* ... */ }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::event::command::ReturnParameters;
+use crate::host::uart::{Packet, UartHci};
+use crate::{Controller, DataController, Event, Opcode, Status};
+
+/// Potential errors from awaiting the response to a command.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Error {
+    /// No command was sent through the [`Correlator`] since the last response was returned.
+    NoPendingCommand,
+
+    /// The controller reported that the command failed, in a
+    /// [Command Complete](crate::event::Event::CommandComplete) or
+    /// [Command Status](crate::event::Event::CommandStatus) event. Contains the status.
+    CommandFailed(Status),
+
+    /// There was an error reading a packet from the controller. Contains the underlying error.
+    Read(crate::host::uart::Error),
+}
+
+/// Response of the controller to a command.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CommandResponse {
+    /// The controller sent a successful [Command Complete](crate::event::Event::CommandComplete)
+    /// event for the command. Contains the return parameters of the command.
+    Complete(ReturnParameters),
+
+    /// The controller sent a successful [Command Status](crate::event::Event::CommandStatus) event
+    /// for the command. The outcome of the command is reported by a later event.
+    Started,
+}
+
+impl CommandResponse {
+    /// Returns the return parameters of the command, if it completed.
+    pub fn return_params(self) -> Option<ReturnParameters> {
+        match self {
+            CommandResponse::Complete(params) => Some(params),
+            CommandResponse::Started => None,
+        }
+    }
+}
+
+/// Wraps a [`Controller`] to correlate the commands sent through it with the events that answer
+/// them.
+///
+/// The correlator implements [`Controller`] itself, so all of the command traits are available on
+/// it. After sending a command, call [`response`](Correlator::response) to read packets until the
+/// controller answers that command.
+pub struct Correlator<C> {
+    controller: C,
+    pending: Option<Opcode>,
+}
+
+impl<C> Correlator<C> {
+    /// Wraps the given controller.
+    pub fn new(controller: C) -> Self {
+        Self {
+            controller,
+            pending: None,
+        }
+    }
+
+    /// Returns the opcode of the last command sent, if the response to it has not been returned
+    /// yet.
+    pub fn pending_opcode(&self) -> Option<Opcode> {
+        self.pending
+    }
+
+    /// Returns a reference to the wrapped controller.
+    pub fn controller(&self) -> &C {
+        &self.controller
+    }
+
+    /// Returns a mutable reference to the wrapped controller.
+    ///
+    /// Commands written directly to the wrapped controller are not correlated.
+    pub fn controller_mut(&mut self) -> &mut C {
+        &mut self.controller
+    }
+
+    /// Returns the wrapped controller.
+    pub fn into_inner(self) -> C {
+        self.controller
+    }
+}
+
+impl<C: Controller> Correlator<C> {
+    /// Reads packets from the controller until it answers the last command sent, and returns the
+    /// answer.
+    ///
+    /// Packets that do not answer the command (data packets, other events, and Command Complete or
+    /// Command Status events for other opcodes) are passed to `on_unrelated` in the order they were
+    /// read, so that the caller can dispatch or buffer them.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`Error::NoPendingCommand`] if no command was sent since the last response. No
+    ///   packets are read in this case.
+    /// - Returns [`Error::CommandFailed`] if the controller sends a Command Complete or Command
+    ///   Status event with a status other than [`Success`](Status::Success) for the command.
+    /// - Returns [`Error::Read`] if there is an error reading a packet. The command is still
+    ///   pending in this case, so the response can be awaited again.
+    pub async fn response<F>(&mut self, mut on_unrelated: F) -> Result<CommandResponse, Error>
+    where
+        F: FnMut(Packet),
+    {
+        let opcode = self.pending.ok_or(Error::NoPendingCommand)?;
+        loop {
+            match self.controller.read().await.map_err(Error::Read)? {
+                Packet::Event(Event::CommandComplete(event))
+                    if event.return_params.opcode() == opcode =>
+                {
+                    self.pending = None;
+                    return match event.return_params.status() {
+                        Some(status) if status != Status::Success => {
+                            Err(Error::CommandFailed(status))
+                        }
+                        _ => Ok(CommandResponse::Complete(event.return_params)),
+                    };
+                }
+                Packet::Event(Event::CommandStatus(event)) if event.opcode == opcode => {
+                    self.pending = None;
+                    return match event.status {
+                        Status::Success => Ok(CommandResponse::Started),
+                        status => Err(Error::CommandFailed(status)),
+                    };
+                }
+                packet => on_unrelated(packet),
+            }
+        }
+    }
+}
+
+impl<C: Controller> Controller for Correlator<C> {
+    async fn controller_write(&mut self, opcode: Opcode, payload: &[u8]) {
+        self.controller.controller_write(opcode, payload).await;
+        self.pending = Some(opcode);
+    }
+
+    async fn controller_read_into(&mut self, buffer: &mut [u8]) {
+        self.controller.controller_read_into(buffer).await;
+    }
+}
+
+impl<C: DataController> DataController for Correlator<C> {
+    async fn controller_write_data(&mut self, header: &[u8], payload: &[u8]) {
+        self.controller.controller_write_data(header, payload).await;
+    }
+}
@@ -8,18 +8,30 @@
 //! support sending the packet ID, as `uart` does. In that case, it would make sense to also remove
 //! `uart` and move its contents up one level.
 
-use crate::event::{NumberOfCompletedPackets, NUMBER_OF_COMPLETED_PACKETS_MAX_LEN};
-use crate::ConnectionHandle;
+use crate::event::{
+    CentralClockAccuracy, CteType, NUMBER_OF_COMPLETED_PACKETS_MAX_LEN, NumberOfCompletedPackets,
+    PowerControlPhy, SlotDuration,
+};
+use crate::types::extended_advertisement::{
+    AdvSet, AdvertisingEvent, AdvertisingOperation, AdvertisingPhy, ExtendedAdvertisingInterval,
+};
+use crate::{AdvertisingHandle, BigHandle, ConnectionHandle, SyncHandle};
 use byteorder::{ByteOrder, LittleEndian};
 use core::convert::Into;
 use core::fmt::{Debug, Formatter, Result as FmtResult};
 use core::time::Duration;
 
+pub mod acl_credits;
+pub mod command;
+pub mod command_credits;
+pub mod correlator;
+pub mod receive_pool;
 pub mod uart;
 
 pub use super::types::{
     AdvertisingInterval, AdvertisingType, ConnectionInterval, ConnectionIntervalBuilder,
-    ExpectedConnectionLength, ScanWindow,
+    ExpectedConnectionLength, P256PublicKey, ScanWindow, SubrateParameters,
+    SubrateParametersBuilder,
 };
 
 use crate::Status;
@@ -691,7 +703,8 @@ pub trait HostHci {
     async fn le_create_connection(&mut self, params: &ConnectionParameters);
 
     /// Cancels the [`le_create_connection`](HostHci::le_create_connection) or
-    /// `le_extended_create_connection` (for v5.0) command. This command shall only be issued after
+    /// [`le_extended_create_connection`](HostHci::le_extended_create_connection) (for v5.0)
+    /// command. This command shall only be issued after
     /// the [`le_create_connection`](HostHci::le_create_connection) command has been issued, a
     /// [`CommandStatus`](crate::event::Event::CommandStatus) event has been received for the
     /// [`le_create_connection`](HostHci::le_create_connection) command and before the
@@ -1105,1279 +1118,5588 @@ pub trait HostHci {
     ///
     /// A [Command Complete](crate::event::command::ReturnParameters::LeTestEnd) event is generated.
     async fn le_test_end(&mut self);
-}
-
-/// Errors that may occur when sending commands to the controller.  Must be specialized on the types
-/// of communication errors.
-#[derive(Copy, Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub enum Error {
-    /// For the [`disconnect`](HostHci::disconnect) command: The provided reason is not a valid
-    /// disconnection reason. Includes the reported reason.
-    BadDisconnectionReason(Status),
-
-    /// For the [`le_set_random_address`](HostHci::le_set_random_address) command: The provided address
-    /// does not meet the rules for random addresses in the Bluetooth Spec, Vol 6, Part B, Section
-    /// 1.3.  Includes the invalid address.
-    BadRandomAddress(crate::BdAddr),
-
-    /// For the [`le_set_advertising_parameters`](HostHci::le_set_advertising_parameters) command: The
-    /// channel map did not include any enabled channels.  Includes the provided channel map.
-    BadChannelMap(Channels),
-
-    /// For the [`le_set_advertising_data`](HostHci::le_set_advertising_data) or
-    /// [`le_set_scan_response_data`](HostHci::le_set_scan_response_data) commands: The provided data is
-    /// too long to fit in the command.  The maximum allowed length is 31.  The actual length is
-    /// returned.
-    AdvertisingDataTooLong(usize),
 
-    /// For the [`le_create_connection`](HostHci::le_create_connection) command: the connection length
-    /// range is inverted (i.e, the minimum is greater than the maximum). Returns the range, min
-    /// first.
-    BadConnectionLengthRange(Duration, Duration),
+    /// Suggests the maximum payload size and transmission time of the LL Data PDUs the Controller
+    /// sends on the given connection. The Controller may use smaller or larger values based on
+    /// local information.
+    ///
+    /// - `tx_octets` is the preferred maximum number of payload octets, in the range 0x001B (27)
+    ///   to 0x00FB (251).
+    /// - `tx_time` is the preferred maximum transmission time of a packet, in microseconds, in
+    ///   the range 0x0148 (328) to 0x4290 (17040).
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.33.
+    ///
+    /// # Errors
+    ///
+    /// - [`InvalidDataLengthOctets`](Error::InvalidDataLengthOctets) if `tx_octets` is out of
+    ///   range.
+    /// - [`InvalidDataLengthTime`](Error::InvalidDataLengthTime) if `tx_time` is out of range.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetDataLength) event is
+    /// generated. If the maximum payload size or transmission time of the connection changes, an
+    /// [LE Data Length Change](crate::event::Event::LeDataLengthChangeEvent) event is also
+    /// generated.
+    async fn le_set_data_length(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        tx_octets: u16,
+        tx_time: u16,
+    ) -> Result<(), Error>;
 
-    /// For the [`le_set_host_channel_classification`](HostHci::le_set_host_channel_classification)
-    /// command: all channels were marked 'bad'.
-    NoValidChannel,
+    /// Reads the Host's suggested values for the maximum payload size and transmission time of
+    /// the LL Data PDUs the Controller sends on new connections.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.34.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadSuggestedDefaultDataLength)
+    /// event is generated.
+    async fn le_read_suggested_default_data_length(&mut self);
 
-    /// For the [`le_receiver_test`](HostHci::le_receiver_test) and
-    /// [`le_transmitter_test`](HostHci::le_transmitter_test) commands: the channel was out of
-    /// range. The maximum allowed channel is 39. Includes the invalid value.
-    InvalidTestChannel(u8),
+    /// Sets the Host's suggested values for the maximum payload size and transmission time of the
+    /// LL Data PDUs the Controller sends on new connections.
+    ///
+    /// - `tx_octets` is the suggested maximum number of payload octets, in the range 0x001B (27)
+    ///   to 0x00FB (251).
+    /// - `tx_time` is the suggested maximum transmission time of a packet, in microseconds, in the
+    ///   range 0x0148 (328) to 0x4290 (17040).
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.35.
+    ///
+    /// # Errors
+    ///
+    /// - [`InvalidDataLengthOctets`](Error::InvalidDataLengthOctets) if `tx_octets` is out of
+    ///   range.
+    /// - [`InvalidDataLengthTime`](Error::InvalidDataLengthTime) if `tx_time` is out of range.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeWriteSuggestedDefaultDataLength)
+    /// event is generated.
+    async fn le_write_suggested_default_data_length(
+        &mut self,
+        tx_octets: u16,
+        tx_time: u16,
+    ) -> Result<(), Error>;
 
-    /// For the [`le_transmitter_test`](HostHci::le_transmitter_test) command: The payload length is
-    /// invalid. The maximum value is 37. Includes the invalid value.
-    InvalidTestPayloadLength(usize),
-}
+    /// Reads the maximum payload size and transmission time of the LL Data PDUs that the
+    /// Controller supports.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.46.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadMaximumDataLength) event
+    /// is generated.
+    async fn le_read_maximum_data_length(&mut self);
 
-async fn set_outbound_data<T>(
-    controller: &mut T,
-    opcode: crate::opcode::Opcode,
-    data: &[u8],
-) -> Result<(), Error>
-where
-    T: crate::Controller,
-{
-    const MAX_DATA_LEN: usize = 31;
-    if data.len() > MAX_DATA_LEN {
-        return Err(Error::AdvertisingDataTooLong(data.len()));
-    }
-    let mut params = [0; 32];
-    params[0] = data.len() as u8;
-    params[1..=data.len()].copy_from_slice(data);
-    controller.controller_write(opcode, &params).await;
+    /// Reads the current transmitter and receiver PHYs on the given connection.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.47.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadPhy) event is
+    /// generated.
+    async fn le_read_phy(&mut self, conn_handle: ConnectionHandle);
 
-    Ok(())
-}
+    /// Specifies the Host's preferred transmitter and receiver PHYs for all subsequent connections.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.48.
+    ///
+    /// # Errors
+    ///
+    /// - [`NoTxPhys`](Error::NoTxPhys) if no transmitter PHY is preferred, and `all_phys` does not
+    ///   include [`NO_TX_PREFERENCE`](AllPhys::NO_TX_PREFERENCE).
+    /// - [`NoRxPhys`](Error::NoRxPhys) if no receiver PHY is preferred, and `all_phys` does not
+    ///   include [`NO_RX_PREFERENCE`](AllPhys::NO_RX_PREFERENCE).
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetDefaultPhy) event is
+    /// generated.
+    async fn le_set_default_phy(&mut self, phys: &PhyPreferences) -> Result<(), Error>;
 
-impl<T> HostHci for T
-where
-    T: crate::Controller,
-{
-    async fn disconnect(
+    /// Requests a change of the transmitter and receiver PHYs on the given connection. The
+    /// Controller may choose PHYs other than the preferred ones, depending on the capabilities of
+    /// the remote device and local information.
+    ///
+    /// `options` indicates the preferred coding when transmitting on the LE Coded PHY.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.49.
+    ///
+    /// # Errors
+    ///
+    /// - [`NoTxPhys`](Error::NoTxPhys) if no transmitter PHY is preferred, and `all_phys` does not
+    ///   include [`NO_TX_PREFERENCE`](AllPhys::NO_TX_PREFERENCE).
+    /// - [`NoRxPhys`](Error::NoRxPhys) if no receiver PHY is preferred, and `all_phys` does not
+    ///   include [`NO_RX_PREFERENCE`](AllPhys::NO_RX_PREFERENCE).
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// When the Controller receives this command, the Controller sends the
+    /// [Command Status](crate::event::Event::CommandStatus) event to the Host. The
+    /// [LE PHY Update Complete](crate::event::Event::LePhyUpdateComplete) event is generated when
+    /// the PHY update procedure completes, or immediately if neither PHY changes.
+    ///
+    /// Note: A Command Complete event is not sent by the Controller to indicate that this command
+    /// has been completed. Instead, the LE PHY Update Complete event indicates that this command
+    /// has been completed.
+    async fn le_set_phy(
         &mut self,
         conn_handle: ConnectionHandle,
-        reason: Status,
-    ) -> Result<(), Error> {
-        match reason {
-            Status::AuthFailure
-            | Status::RemoteTerminationByUser
-            | Status::RemoteTerminationLowResources
-            | Status::RemoteTerminationPowerOff
-            | Status::UnsupportedRemoteFeature
-            | Status::PairingWithUnitKeyNotSupported
-            | Status::UnacceptableConnectionParameters => (),
-            _ => return Err(Error::BadDisconnectionReason(reason)),
-        }
+        phys: &PhyPreferences,
+        options: PhyOptions,
+    ) -> Result<(), Error>;
 
-        let mut params = [0; 3];
-        LittleEndian::write_u16(&mut params[0..], conn_handle.0);
-        params[2] = reason.into();
-        self.controller_write(crate::opcode::DISCONNECT, &params)
-            .await;
+    /// Adds one device to the resolving list used to generate and resolve Resolvable Private
+    /// Addresses in the Controller.
+    ///
+    /// This command shall not be used when address resolution is enabled in the Controller and
+    /// advertising, scanning or an outstanding [`le_create_connection`](HostHci::le_create_connection)
+    /// command is active.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.38.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeAddDeviceToResolvingList)
+    /// event is generated.
+    async fn le_add_device_to_resolving_list(&mut self, params: &ResolvingListParameters);
 
-        Ok(())
-    }
+    /// Removes one device from the resolving list used to resolve Resolvable Private Addresses in
+    /// the Controller.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.39.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeRemoveDeviceFromResolvingList)
+    /// event is generated.
+    async fn le_remove_device_from_resolving_list(
+        &mut self,
+        peer_identity_address: crate::BdAddrType,
+    );
 
-    async fn read_remote_version_information(&mut self, conn_handle: ConnectionHandle) {
-        let mut params = [0; 2];
-        LittleEndian::write_u16(&mut params, conn_handle.0);
-        self.controller_write(crate::opcode::READ_REMOTE_VERSION_INFO, &params)
-            .await;
-    }
+    /// Removes all devices from the resolving list used to resolve Resolvable Private Addresses in
+    /// the Controller.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.40.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeClearResolvingList) event is
+    /// generated.
+    async fn le_clear_resolving_list(&mut self);
 
-    async fn set_event_mask(&mut self, mask: EventFlags) {
-        let mut params = [0; 8];
-        LittleEndian::write_u64(&mut params, mask.bits());
+    /// Reads the total number of entries in the resolving list that can be stored in the
+    /// Controller.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.41.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadResolvingListSize) event
+    /// is generated.
+    async fn le_read_resolving_list_size(&mut self);
 
-        self.controller_write(crate::opcode::SET_EVENT_MASK, &params)
-            .await;
-    }
+    /// Reads the current peer Resolvable Private Address being used for the peer device with the
+    /// given identity address.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.42.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadPeerResolvableAddress)
+    /// event is generated.
+    async fn le_read_peer_resolvable_address(&mut self, peer_identity_address: crate::BdAddrType);
 
-    async fn reset(&mut self) {
-        self.controller_write(crate::opcode::RESET, &[]).await;
-    }
+    /// Reads the current local Resolvable Private Address being used for the peer device with the
+    /// given identity address.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.43.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadLocalResolvableAddress)
+    /// event is generated.
+    async fn le_read_local_resolvable_address(&mut self, peer_identity_address: crate::BdAddrType);
 
-    async fn read_tx_power_level(
-        &mut self,
-        conn_handle: ConnectionHandle,
-        power_level_type: TxPowerLevel,
-    ) {
-        let mut params = [0; 3];
-        LittleEndian::write_u16(&mut params, conn_handle.0);
-        params[2] = power_level_type as u8;
-        self.controller_write(crate::opcode::READ_TX_POWER_LEVEL, &params)
-            .await;
-    }
+    /// Enables or disables the resolution of Resolvable Private Addresses in the Controller. When
+    /// disabled, the resolving list is not used to generate or resolve addresses.
+    ///
+    /// This command shall not be used while advertising, scanning or an outstanding
+    /// [`le_create_connection`](HostHci::le_create_connection) command is active.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.44.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetAddressResolutionEnable)
+    /// event is generated.
+    async fn le_set_address_resolution_enable(&mut self, enable: bool);
 
-    async fn set_controller_to_host_flow_control(&mut self, flow_control: FlowControl) {
-        self.controller_write(
-            crate::opcode::SET_CONTROLLER_TO_HOST_FLOW_CONTROL,
-            &[flow_control as u8],
+    /// Sets the length of time the Controller uses a Resolvable Private Address before a new one
+    /// is generated and starts being used. The timeout is sent in whole seconds; fractions of a
+    /// second are discarded.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.45.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadRpaTimeout`](Error::BadRpaTimeout) if the timeout is shorter than 1 second or
+    ///   longer than 1 hour.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetResolvablePrivateAddressTimeout)
+    /// event is generated.
+    async fn le_set_resolvable_private_address_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<(), Error>;
+
+    /// Sets the privacy mode used by the Controller for the peer device with the given identity
+    /// address, which must be in the resolving list.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.77.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetPrivacyMode) event is
+    /// generated.
+    async fn le_set_privacy_mode(
+        &mut self,
+        peer_identity_address: crate::BdAddrType,
+        privacy_mode: PrivacyMode,
+    );
+
+    /// Requests the Controller to generate a new P-256 key pair and return the public key. The
+    /// private key is kept in the Controller, and used by later
+    /// [`le_generate_dhkey`](HostHci::le_generate_dhkey) commands.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.36.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// The Controller sends the [Command Status](crate::event::Event::CommandStatus) event to the
+    /// Host when the command is received. An
+    /// [LE Read Local P-256 Public Key Complete](crate::event::Event::LeReadLocalP256PublicKeyComplete)
+    /// event is generated when the key pair has been generated.
+    async fn le_read_local_p256_public_key(&mut self);
+
+    /// Requests the Controller to generate a Diffie Hellman key from the remote device's P-256
+    /// public key and the local private key generated by the last
+    /// [`le_read_local_p256_public_key`](HostHci::le_read_local_p256_public_key) command.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.37.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// The Controller sends the [Command Status](crate::event::Event::CommandStatus) event to the
+    /// Host when the command is received. An
+    /// [LE Generate DHKey Complete](crate::event::Event::LeGenerateDHKeyComplete) event is
+    /// generated when the key has been generated.
+    async fn le_generate_dhkey(&mut self, remote_key: &P256PublicKey);
+
+    /// Requests the Controller to generate a Diffie Hellman key from the remote device's P-256
+    /// public key and either the generated local private key, or the debug private key defined in
+    /// the Bluetooth spec (Vol 3, Part H, Section 2.3.5.6.1).
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.94.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// The Controller sends the [Command Status](crate::event::Event::CommandStatus) event to the
+    /// Host when the command is received. An
+    /// [LE Generate DHKey Complete](crate::event::Event::LeGenerateDHKeyComplete) event is
+    /// generated when the key has been generated.
+    async fn le_generate_dhkey_v2(&mut self, remote_key: &P256PublicKey, key_type: PrivateKeyType);
+
+    /// Sets the parameters of an advertising set, creating the set if it does not exist yet.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.53.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadChannelMap`](Error::BadChannelMap) if no channels are enabled in the primary
+    ///   advertising channel map.
+    /// - [`BadPrimaryAdvertisingPhy`](Error::BadPrimaryAdvertisingPhy) if the primary advertising
+    ///   PHY is [`Le2M`](AdvertisingPhy::Le2M), which cannot be used on the primary advertising
+    ///   channels.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetExtendedAdvertisingParameters)
+    /// event is generated.
+    ///
+    /// The Host shall not issue this command when advertising is enabled for the set; if it is the
+    /// [Command Disallowed](Status::CommandDisallowed) error code shall be used.
+    async fn le_set_extended_advertising_parameters(
+        &mut self,
+        params: &ExtendedAdvertisingParameters,
+    ) -> Result<(), Error>;
+
+    /// Sets the data used in the advertising PDUs of an advertising set that have a data field.
+    ///
+    /// Data longer than fits in one command can be sent in several fragments, using the
+    /// [`operation`](ExtendedAdvertisingData::operation) of each fragment to tell the Controller
+    /// where it goes. The maximum total length supported by the Controller is returned by the
+    /// [`le_read_maximum_advertising_data_length`](HostHci::le_read_maximum_advertising_data_length)
+    /// command.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.54.
+    ///
+    /// # Errors
+    ///
+    /// - [`ExtendedAdvertisingDataTooLong`](Error::ExtendedAdvertisingDataTooLong) if the data is
+    ///   longer than 251 bytes.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetExtendedAdvertisingData)
+    /// event is generated.
+    async fn le_set_extended_advertising_data(
+        &mut self,
+        data: &ExtendedAdvertisingData<'_>,
+    ) -> Result<(), Error>;
+
+    /// Sets the data used in the scan response PDUs of a scannable advertising set.
+    ///
+    /// Data longer than fits in one command can be sent in several fragments, as for the
+    /// [`le_set_extended_advertising_data`](HostHci::le_set_extended_advertising_data) command.
+    /// The [`UnchangedData`](AdvertisingOperation::UnchangedData) operation cannot be used for scan
+    /// response data.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.55.
+    ///
+    /// # Errors
+    ///
+    /// - [`ExtendedAdvertisingDataTooLong`](Error::ExtendedAdvertisingDataTooLong) if the data is
+    ///   longer than 251 bytes.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetExtendedScanResponseData)
+    /// event is generated.
+    async fn le_set_extended_scan_response_data(
+        &mut self,
+        data: &ExtendedAdvertisingData<'_>,
+    ) -> Result<(), Error>;
+
+    /// Enables or disables advertising for one or more advertising sets. Each set may advertise
+    /// for a limited [`duration`](AdvSet::duration) or number of
+    /// [advertising events](AdvSet::max_extended_adv_events).
+    ///
+    /// Disabling advertising with no sets disables all advertising sets.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.56.
+    ///
+    /// # Errors
+    ///
+    /// - [`TooManyAdvertisingSets`](Error::TooManyAdvertisingSets) if more than 63 sets are
+    ///   given.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetExtendedAdvertisingEnable)
+    /// event is generated.
+    ///
+    /// When advertising ends in a set because its duration elapsed, its maximum number of
+    /// advertising events was reached or a connection was created, an
+    /// [LE Advertising Set Terminated](crate::event::Event::LeAdvertisingSetTerminated) event is
+    /// generated.
+    async fn le_set_extended_advertising_enable(
+        &mut self,
+        enable: bool,
+        sets: &[AdvSet],
+    ) -> Result<(), Error>;
+
+    /// Reads the maximum length of the advertising data or scan response data supported by the
+    /// Controller for an advertising set.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.57.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadMaximumAdvertisingDataLength)
+    /// event is generated.
+    async fn le_read_maximum_advertising_data_length(&mut self);
+
+    /// Reads the maximum number of advertising sets supported by the Controller at the same time.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.58.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadNumberOfSupportedAdvertisingSets)
+    /// event is generated.
+    async fn le_read_number_of_supported_advertising_sets(&mut self);
+
+    /// Removes an advertising set from the Controller.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.59.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeRemoveAdvertisingSet) event
+    /// is generated.
+    async fn le_remove_advertising_set(&mut self, adv_handle: AdvertisingHandle);
+
+    /// Removes all existing advertising sets from the Controller.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.60.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeClearAdvertisingSets) event
+    /// is generated.
+    async fn le_clear_advertising_sets(&mut self);
+
+    /// Sets the scan parameters used by the extended scanning commands, for each PHY on which
+    /// advertising packets are received.
+    ///
+    /// The Host shall not issue this command when scanning is enabled in the Controller; if it is
+    /// the [`CommandDisallowed`](Status::CommandDisallowed) error code shall be used.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.64.
+    ///
+    /// # Errors
+    ///
+    /// - [`NoScanningPhys`](Error::NoScanningPhys) if no parameters are given for any PHY.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetExtendedScanParameters)
+    /// event is generated.
+    async fn le_set_extended_scan_parameters(
+        &mut self,
+        params: &ExtendedScanParameters,
+    ) -> Result<(), Error>;
+
+    /// Starts or stops scanning with the parameters set by
+    /// [`le_set_extended_scan_parameters`](HostHci::le_set_extended_scan_parameters). Scanning may
+    /// last for a limited [`duration`](ExtendedScanEnable::duration), and be repeated every
+    /// [`period`](ExtendedScanEnable::period).
+    ///
+    /// If `enable` is true and scanning is already enabled, the new `filter_duplicates`,
+    /// `duration` and `period` shall take effect.
+    ///
+    /// Note: Disabling scanning when it is disabled has no effect.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.65.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadScanDuration`](Error::BadScanDuration) if the duration is out of range.
+    /// - [`BadScanPeriod`](Error::BadScanPeriod) if the period is out of range, or is not longer
+    ///   than the duration.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetExtendedScanEnable)
+    /// event is generated.
+    ///
+    /// Zero or more
+    /// [LE Extended Advertising Reports](crate::event::Event::LeExtendedAdvertisingReport) are
+    /// generated by the Controller based on advertising packets received and the duplicate
+    /// filtering. When the duration expires and the period is zero, scanning stops and an
+    /// [LE Scan Timeout](crate::event::Event::LeScanTimeout) event is generated.
+    async fn le_set_extended_scan_enable(
+        &mut self,
+        params: &ExtendedScanEnable,
+    ) -> Result<(), Error>;
+
+    /// Creates a Link Layer connection to a connectable advertiser, using extended or legacy
+    /// advertisements received on any of the PHYs given parameters.
+    ///
+    /// The Host shall not issue this command when another `le_extended_create_connection` or
+    /// [`le_create_connection`](HostHci::le_create_connection) is pending in the Controller; if
+    /// this does occur the Controller shall return the
+    /// [`CommandDisallowed`](Status::CommandDisallowed) error code.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.66.
+    ///
+    /// # Errors
+    ///
+    /// - [`NoInitiatingPhys`](Error::NoInitiatingPhys) if no parameters are given for any PHY.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// The Controller sends the [Command Status](crate::event::Event::CommandStatus) event to the
+    /// Host when the event is received. An
+    /// [LE Enhanced Connection Complete](crate::event::Event::LeEnhancedConnectionComplete) event
+    /// shall be generated when a connection is created or the connection creation procedure is
+    /// cancelled. If a connection is created, an
+    /// [LE Channel Selection Algorithm](crate::event::Event::LeChannelSelectionAlgorithm) event is
+    /// also generated.
+    ///
+    /// Note: No Command Complete event is sent by the Controller to indicate that this command has
+    /// been completed. Instead, the LE Enhanced Connection Complete event indicates that this
+    /// command has been completed.
+    async fn le_extended_create_connection(
+        &mut self,
+        params: &ExtendedConnectionParameters,
+    ) -> Result<(), Error>;
+
+    /// Sets the parameters for periodic advertising in an advertising set. The set shall have
+    /// been created by the
+    /// [`le_set_extended_advertising_parameters`](HostHci::le_set_extended_advertising_parameters)
+    /// command, with non-connectable and non-scannable properties.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.61.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadPeriodicAdvertisingInterval`](Error::BadPeriodicAdvertisingInterval) if the minimum
+    ///   interval is shorter than 7.5 ms, the maximum is longer than 81.91875 s, or the minimum is
+    ///   greater than the maximum.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetPeriodicAdvertisingParameters)
+    /// event is generated.
+    async fn le_set_periodic_advertising_parameters(
+        &mut self,
+        params: &PeriodicAdvertisingParameters,
+    ) -> Result<(), Error>;
+
+    /// Sets the data used in the periodic advertising PDUs of an advertising set.
+    ///
+    /// Data longer than fits in one command can be sent in several fragments, using the
+    /// [`operation`](PeriodicAdvertisingData::operation) of each fragment to tell the Controller
+    /// where it goes.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.62.
+    ///
+    /// # Errors
+    ///
+    /// - [`PeriodicAdvertisingDataTooLong`](Error::PeriodicAdvertisingDataTooLong) if the data is
+    ///   longer than 252 bytes.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetPeriodicAdvertisingData)
+    /// event is generated.
+    async fn le_set_periodic_advertising_data(
+        &mut self,
+        data: &PeriodicAdvertisingData<'_>,
+    ) -> Result<(), Error>;
+
+    /// Enables or disables periodic advertising in an advertising set. Periodic advertising
+    /// starts once extended advertising is also enabled for the set, with the
+    /// [`le_set_extended_advertising_enable`](HostHci::le_set_extended_advertising_enable)
+    /// command.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.63.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetPeriodicAdvertisingEnable)
+    /// event is generated.
+    async fn le_set_periodic_advertising_enable(
+        &mut self,
+        enable: bool,
+        adv_handle: AdvertisingHandle,
+    );
+
+    /// Synchronizes with a periodic advertising train from an advertiser, and starts receiving
+    /// its periodic advertising packets. The advertiser is either given in the parameters, or
+    /// is any advertiser in the periodic advertiser list.
+    ///
+    /// Scanning shall be enabled with the
+    /// [`le_set_extended_scan_enable`](HostHci::le_set_extended_scan_enable) command for
+    /// synchronization to take place.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.67.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadAdvertisingSid`](Error::BadAdvertisingSid) if the advertising SID is greater than
+    ///   0x0F.
+    /// - [`BadSyncSkip`](Error::BadSyncSkip) if the skip is greater than 0x01F3.
+    /// - [`BadSyncTimeout`](Error::BadSyncTimeout) if the sync timeout is shorter than 100 ms or
+    ///   longer than 163.84 s.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// The Controller sends the [Command Status](crate::event::Event::CommandStatus) event to the
+    /// Host when the command is received. An
+    /// [LE Periodic Advertising Sync Established](crate::event::Event::LePeriodicAdvertisingSyncEstablished)
+    /// event is generated when synchronization is established or cancelled, followed by
+    /// [LE Periodic Advertising Reports](crate::event::Event::LePeriodicAdvertisingReport) unless
+    /// reporting is initially disabled.
+    async fn le_periodic_advertising_create_sync(
+        &mut self,
+        params: &PeriodicAdvertisingSyncParameters,
+    ) -> Result<(), Error>;
+
+    /// Cancels a pending
+    /// [`le_periodic_advertising_create_sync`](HostHci::le_periodic_advertising_create_sync)
+    /// command.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.68.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LePeriodicAdvertisingCreateSyncCancel)
+    /// event is generated. If the cancellation succeeds, an
+    /// [LE Periodic Advertising Sync Established](crate::event::Event::LePeriodicAdvertisingSyncEstablished)
+    /// event is then generated with the
+    /// [`OperationCancelledByHost`](Status::OperationCancelledByHost) status.
+    async fn le_periodic_advertising_create_sync_cancel(&mut self);
+
+    /// Stops reception of the periodic advertising train identified by the sync handle.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.69.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LePeriodicAdvertisingTerminateSync)
+    /// event is generated.
+    async fn le_periodic_advertising_terminate_sync(&mut self, sync_handle: SyncHandle);
+
+    /// Adds an advertiser to the periodic advertiser list stored in the Controller, which is used
+    /// by the [`le_periodic_advertising_create_sync`](HostHci::le_periodic_advertising_create_sync)
+    /// command.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.70.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadAdvertisingSid`](Error::BadAdvertisingSid) if the advertising SID is greater than
+    ///   0x0F.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeAddDeviceToPeriodicAdvertiserList)
+    /// event is generated.
+    async fn le_add_device_to_periodic_advertiser_list(
+        &mut self,
+        advertiser: &PeriodicAdvertiser,
+    ) -> Result<(), Error>;
+
+    /// Removes an advertiser from the periodic advertiser list stored in the Controller.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.71.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadAdvertisingSid`](Error::BadAdvertisingSid) if the advertising SID is greater than
+    ///   0x0F.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeRemoveDeviceFromPeriodicAdvertiserList)
+    /// event is generated.
+    async fn le_remove_device_from_periodic_advertiser_list(
+        &mut self,
+        advertiser: &PeriodicAdvertiser,
+    ) -> Result<(), Error>;
+
+    /// Removes all advertisers from the periodic advertiser list stored in the Controller.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.72.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeClearPeriodicAdvertiserList)
+    /// event is generated.
+    async fn le_clear_periodic_advertiser_list(&mut self);
+
+    /// Reads the total number of periodic advertiser list entries that can be stored in the
+    /// Controller.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.73.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadPeriodicAdvertiserListSize)
+    /// event is generated.
+    async fn le_read_periodic_advertiser_list_size(&mut self);
+
+    /// Sends synchronization information about a periodic advertising train the Controller is
+    /// synchronized with to a connected device.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.89.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LePeriodicAdvertisingSyncTransfer)
+    /// event is generated. The connected device is notified with the
+    /// [LE Periodic Advertising Sync Transfer Received](crate::event::Event::LePeriodicAdvertisingSyncTransferReceived)
+    /// event.
+    async fn le_periodic_advertising_sync_transfer(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        service_data: u16,
+        sync_handle: SyncHandle,
+    );
+
+    /// Sends synchronization information about the periodic advertising in a local advertising
+    /// set to a connected device.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.90.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LePeriodicAdvertisingSetInfoTransfer)
+    /// event is generated. The connected device is notified with the
+    /// [LE Periodic Advertising Sync Transfer Received](crate::event::Event::LePeriodicAdvertisingSyncTransferReceived)
+    /// event.
+    async fn le_periodic_advertising_set_info_transfer(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        service_data: u16,
+        adv_handle: AdvertisingHandle,
+    );
+
+    /// Specifies how the Controller processes periodic advertising synchronization information
+    /// received from the device identified by the connection handle.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.91.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadSyncSkip`](Error::BadSyncSkip) if the skip is greater than 0x01F3.
+    /// - [`BadSyncTimeout`](Error::BadSyncTimeout) if the sync timeout is shorter than 100 ms or
+    ///   longer than 163.84 s.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetPeriodicAdvertisingSyncTransferParameters)
+    /// event is generated.
+    async fn le_set_periodic_advertising_sync_transfer_parameters(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        params: &SyncTransferParameters,
+    ) -> Result<(), Error>;
+
+    /// Specifies the initial value of the parameters used by the Controller to process periodic
+    /// advertising synchronization information received on all future connections. The
+    /// parameters of existing connections are not changed.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.92.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadSyncSkip`](Error::BadSyncSkip) if the skip is greater than 0x01F3.
+    /// - [`BadSyncTimeout`](Error::BadSyncTimeout) if the sync timeout is shorter than 100 ms or
+    ///   longer than 163.84 s.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetDefaultPeriodicAdvertisingSyncTransferParameters)
+    /// event is generated.
+    async fn le_set_default_periodic_advertising_sync_transfer_parameters(
+        &mut self,
+        params: &SyncTransferParameters,
+    ) -> Result<(), Error>;
+
+    /// Reads the time stamp and time offset of a transmitted SDU, identified by the packet
+    /// sequence number, on a CIS or BIS.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.96.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadIsoTxSync) event is
+    /// generated.
+    async fn le_read_iso_tx_sync(&mut self, conn_handle: ConnectionHandle);
+
+    /// Creates a CIG, or modifies the CIG if it has not been created yet, with one CIS per entry
+    /// of `cis`. Used by the Central.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.97.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadCigId`](Error::BadCigId) if the CIG ID is greater than 0xEF.
+    /// - [`BadCisId`](Error::BadCisId) if a CIS ID is greater than 0xEF.
+    /// - [`BadSduInterval`](Error::BadSduInterval) if an SDU interval is shorter than 255 µs or
+    ///   longer than 1,048,575 µs.
+    /// - [`BadMaxTransportLatency`](Error::BadMaxTransportLatency) if a maximum transport latency
+    ///   is shorter than 5 ms or longer than 4 s.
+    /// - [`TooManyCis`](Error::TooManyCis) if more than 26 CIS configurations are given.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetCigParameters) event is
+    /// generated. It includes the connection handle assigned to each CIS.
+    async fn le_set_cig_parameters(
+        &mut self,
+        params: &CigParameters,
+        cis: &[CisParameters],
+    ) -> Result<(), Error>;
+
+    /// Creates a CIG, or modifies the CIG if it has not been created yet, with low-level control
+    /// over its configuration. Only used for testing.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.98.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadCigId`](Error::BadCigId) if the CIG ID is greater than 0xEF.
+    /// - [`BadCisId`](Error::BadCisId) if a CIS ID is greater than 0xEF.
+    /// - [`BadSduInterval`](Error::BadSduInterval) if an SDU interval is shorter than 255 µs or
+    ///   longer than 1,048,575 µs.
+    /// - [`BadIsoInterval`](Error::BadIsoInterval) if the ISO interval is shorter than 5 ms or
+    ///   longer than 4 s.
+    /// - [`TooManyCis`](Error::TooManyCis) if more than 17 CIS configurations are given.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetCigParametersTest) event
+    /// is generated. It includes the connection handle assigned to each CIS.
+    async fn le_set_cig_parameters_test(
+        &mut self,
+        params: &CigTestParameters,
+        cis: &[CisTestParameters],
+    ) -> Result<(), Error>;
+
+    /// Creates one or more CISes, each over an existing ACL connection. Used by the Central.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.99.
+    ///
+    /// # Errors
+    ///
+    /// - [`TooManyCis`](Error::TooManyCis) if more than 31 CISes are given.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// The Controller sends the [Command Status](crate::event::Event::CommandStatus) event to the
+    /// Host when the command is received. The Peripherals are notified with the
+    /// [LE CIS Request](crate::event::Event::LeCisRequest) event, and an
+    /// [LE CIS Established](crate::event::Event::LeCisEstablished) event is generated for each CIS
+    /// when it is established or fails to be.
+    async fn le_create_cis(&mut self, cis: &[CisConnection]) -> Result<(), Error>;
+
+    /// Removes a CIG that has been set up with the
+    /// [`le_set_cig_parameters`](HostHci::le_set_cig_parameters) command. All the CISes of the CIG
+    /// shall be disconnected.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.100.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeRemoveCig) event is
+    /// generated.
+    async fn le_remove_cig(&mut self, cig_id: u8);
+
+    /// Accepts a request for a CIS from the Central, reported in an
+    /// [LE CIS Request](crate::event::Event::LeCisRequest) event. Used by the Peripheral.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.101.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// The Controller sends the [Command Status](crate::event::Event::CommandStatus) event to the
+    /// Host when the command is received. An
+    /// [LE CIS Established](crate::event::Event::LeCisEstablished) event is generated when the CIS
+    /// is established or fails to be.
+    async fn le_accept_cis_request(&mut self, conn_handle: ConnectionHandle);
+
+    /// Rejects a request for a CIS from the Central, reported in an
+    /// [LE CIS Request](crate::event::Event::LeCisRequest) event. Used by the Peripheral.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.102.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeRejectCisRequest) event is
+    /// generated.
+    async fn le_reject_cis_request(&mut self, conn_handle: ConnectionHandle, reason: Status);
+
+    /// Sets up the data path of an established CIS or BIS, in one direction, between the Host and
+    /// the Controller or a vendor-specific transport.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.109.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadControllerDelay`](Error::BadControllerDelay) if the controller delay is longer than
+    ///   4 s.
+    /// - [`CodecConfigurationTooLong`](Error::CodecConfigurationTooLong) if the codec
+    ///   configuration is longer than 242 bytes.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetupIsoDataPath) event is
+    /// generated.
+    async fn le_setup_iso_data_path(
+        &mut self,
+        params: &IsoDataPathParameters<'_>,
+    ) -> Result<(), Error>;
+
+    /// Removes the data paths of a CIS or BIS in the given directions.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.110.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeRemoveIsoDataPath) event is
+    /// generated.
+    async fn le_remove_iso_data_path(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        directions: DataPathDirections,
+    );
+
+    /// Creates a BIG with one or more BISes, transmitted in the periodic advertising train of an
+    /// advertising set.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.103.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadNumBis`](Error::BadNumBis) if the number of BISes is 0 or greater than 31.
+    /// - [`BadSduInterval`](Error::BadSduInterval) if the SDU interval is shorter than 255 µs or
+    ///   longer than 1,048,575 µs.
+    /// - [`BadMaxTransportLatency`](Error::BadMaxTransportLatency) if the maximum transport
+    ///   latency is shorter than 5 ms or longer than 4 s.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// The Controller sends the [Command Status](crate::event::Event::CommandStatus) event to the
+    /// Host when the command is received. An
+    /// [LE Create BIG Complete](crate::event::Event::LeCreateBigComplete) event is generated when
+    /// the BIG is created or fails to be.
+    async fn le_create_big(&mut self, params: &BigParameters) -> Result<(), Error>;
+
+    /// Terminates a BIG created with the [`le_create_big`](HostHci::le_create_big) command, or
+    /// cancels its creation.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.105.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// The Controller sends the [Command Status](crate::event::Event::CommandStatus) event to the
+    /// Host when the command is received. An
+    /// [LE Terminate BIG Complete](crate::event::Event::LeTerminateBigComplete) event is
+    /// generated when the BIG is terminated. If its creation is cancelled, an
+    /// [LE Create BIG Complete](crate::event::Event::LeCreateBigComplete) event is generated
+    /// instead.
+    async fn le_terminate_big(&mut self, big_handle: BigHandle, reason: Status);
+
+    /// Synchronizes with one or more BISes of a BIG described in the BIGInfo of a periodic
+    /// advertising train the Controller is synchronized with.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.106.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadBigSyncTimeout`](Error::BadBigSyncTimeout) if the sync timeout is shorter than
+    ///   100 ms or longer than 163.84 s.
+    /// - [`TooManyBis`](Error::TooManyBis) if more than 31 BISes are given.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// The Controller sends the [Command Status](crate::event::Event::CommandStatus) event to the
+    /// Host when the command is received. An
+    /// [LE BIG Sync Established](crate::event::Event::LeBigSyncEstablished) event is generated
+    /// when synchronization is established or fails to be.
+    async fn le_big_create_sync(&mut self, params: &BigSyncParameters<'_>) -> Result<(), Error>;
+
+    /// Stops synchronization with a BIG, or cancels a pending
+    /// [`le_big_create_sync`](HostHci::le_big_create_sync) command.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.107.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeBigTerminateSync) event is
+    /// generated. If a pending synchronization is cancelled, an
+    /// [LE BIG Sync Established](crate::event::Event::LeBigSyncEstablished) event is then
+    /// generated with the [`OperationCancelledByHost`](Status::OperationCancelledByHost) status.
+    async fn le_big_terminate_sync(&mut self, big_handle: BigHandle);
+
+    /// Reads the current and maximum transmit power levels of the local Controller on an ACL
+    /// connection, for the given PHY.
+    ///
+    /// Unlike [`read_tx_power_level`](HostHci::read_tx_power_level), the levels are returned for
+    /// a specific PHY. The power level used by the Controller outside of connections is set with
+    /// the vendor-specific
+    /// [`set_tx_power_level`](crate::vendor::command::hal::HalCommands::set_tx_power_level)
+    /// command.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.117.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command
+    /// Complete](crate::event::command::ReturnParameters::LeEnhancedReadTransmitPowerLevel) event
+    /// is generated.
+    async fn le_enhanced_read_transmit_power_level(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        phy: PowerControlPhy,
+    );
+
+    /// Reads the transmit power level used by the remote Controller on an ACL connection, for the
+    /// given PHY.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.118.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// The Controller sends the [Command Status](crate::event::Event::CommandStatus) event to the
+    /// Host when the command is received. An
+    /// [LE Transmit Power Reporting](crate::event::Event::LeTransmitPowerReporting) event is
+    /// generated when the power level has been read from the remote Controller.
+    async fn le_read_remote_transmit_power_level(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        phy: PowerControlPhy,
+    );
+
+    /// Sets the path loss thresholds for an ACL connection. Path loss reporting is then enabled
+    /// with the [`le_set_path_loss_reporting_enable`](HostHci::le_set_path_loss_reporting_enable)
+    /// command.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.119.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command
+    /// Complete](crate::event::command::ReturnParameters::LeSetPathLossReportingParameters) event
+    /// is generated.
+    async fn le_set_path_loss_reporting_parameters(&mut self, params: &PathLossReportingParameters);
+
+    /// Enables or disables path loss reporting for an ACL connection.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.120.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command
+    /// Complete](crate::event::command::ReturnParameters::LeSetPathLossReportingEnable) event is
+    /// generated. While reporting is enabled, an
+    /// [LE Path Loss Threshold](crate::event::Event::LePathLossThreshold) event is generated
+    /// each time the path loss enters a different zone.
+    async fn le_set_path_loss_reporting_enable(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        enable: bool,
+    );
+
+    /// Enables or disables the reporting of transmit power level changes of the local and remote
+    /// Controllers on an ACL connection.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.121.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command
+    /// Complete](crate::event::command::ReturnParameters::LeSetTransmitPowerReportingEnable)
+    /// event is generated. While reporting is enabled, an
+    /// [LE Transmit Power Reporting](crate::event::Event::LeTransmitPowerReporting) event is
+    /// generated each time the transmit power level of the local or remote Controller changes.
+    async fn le_set_transmit_power_reporting_enable(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        local_enable: bool,
+        remote_enable: bool,
+    );
+
+    /// Sets the initial values for the acceptable parameters for subrating requests for all
+    /// future ACL connections where the Controller is the Central.
+    ///
+    /// This command does not affect any existing connection.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.123.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeSetDefaultSubrate) event
+    /// is generated.
+    async fn le_set_default_subrate(&mut self, params: &SubrateParameters);
+
+    /// Requests a change to the subrating factor and/or other parameters applied to an existing
+    /// connection.
+    ///
+    /// The actual subrate factor selected by the Link Layer may be different from the range
+    /// provided by the Host through this command.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.124.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// When the Controller receives the command, the Controller sends the
+    /// [Command Status](crate::event::Event::CommandStatus) event to the Host. The
+    /// [LE Subrate Change](crate::event::Event::LeSubrateChange) event is generated when the
+    /// subrating parameters have been applied by the Controller.
+    async fn le_subrate_request(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        params: &SubrateParameters,
+    );
+
+    /// Sets the type, length and antenna switching pattern of the Constant Tone Extensions
+    /// transmitted in the periodic advertising of an advertising set.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.80.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadCteLength`](Error::BadCteLength) if the CTE length is shorter than 16 µs or longer
+    ///   than 160 µs.
+    /// - [`BadCteCount`](Error::BadCteCount) if the CTE count is 0 or greater than 16.
+    /// - [`BadSwitchingPatternLength`](Error::BadSwitchingPatternLength) if fewer than 2 or more
+    ///   than 75 antenna IDs are given.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command
+    /// Complete](crate::event::command::ReturnParameters::LeSetConnectionlessCteTransmitParameters)
+    /// event is generated.
+    async fn le_set_connectionless_cte_transmit_parameters(
+        &mut self,
+        params: &ConnectionlessCteTransmitParameters<'_>,
+    ) -> Result<(), Error>;
+
+    /// Enables or disables the transmission of Constant Tone Extensions in the periodic
+    /// advertising of an advertising set.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.81.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command
+    /// Complete](crate::event::command::ReturnParameters::LeSetConnectionlessCteTransmitEnable)
+    /// event is generated.
+    async fn le_set_connectionless_cte_transmit_enable(
+        &mut self,
+        adv_handle: AdvertisingHandle,
+        enable: bool,
+    );
+
+    /// Enables or disables the sampling of the Constant Tone Extensions received in a periodic
+    /// advertising train the Controller is synchronized with.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.82.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadMaxSampledCtes`](Error::BadMaxSampledCtes) if the maximum number of sampled CTEs is
+    ///   greater than 16.
+    /// - [`BadSwitchingPatternLength`](Error::BadSwitchingPatternLength) if sampling is enabled
+    ///   and fewer than 2 or more than 75 antenna IDs are given.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command
+    /// Complete](crate::event::command::ReturnParameters::LeSetConnectionlessIqSamplingEnable)
+    /// event is generated. While sampling is enabled, an
+    /// [LE Connectionless IQ Report](crate::event::Event::LeConnectionlessIqReport) event is
+    /// generated for each sampled Constant Tone Extension.
+    async fn le_set_connectionless_iq_sampling_enable(
+        &mut self,
+        params: &ConnectionlessIqSamplingParameters<'_>,
+    ) -> Result<(), Error>;
+
+    /// Enables or disables the sampling of the Constant Tone Extensions received on an ACL
+    /// connection.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.83.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadSwitchingPatternLength`](Error::BadSwitchingPatternLength) if sampling is enabled
+    ///   and fewer than 2 or more than 75 antenna IDs are given.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command
+    /// Complete](crate::event::command::ReturnParameters::LeSetConnectionCteReceiveParameters)
+    /// event is generated. While sampling is enabled, an
+    /// [LE Connection IQ Report](crate::event::Event::LeConnectionIqReport) event is generated
+    /// for each sampled Constant Tone Extension.
+    async fn le_set_connection_cte_receive_parameters(
+        &mut self,
+        params: &ConnectionCteReceiveParameters<'_>,
+    ) -> Result<(), Error>;
+
+    /// Sets the types of Constant Tone Extension and the antenna switching pattern used when
+    /// responding to Constant Tone Extension requests on an ACL connection.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.84.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadSwitchingPatternLength`](Error::BadSwitchingPatternLength) if fewer than 2 or more
+    ///   than 75 antenna IDs are given.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command
+    /// Complete](crate::event::command::ReturnParameters::LeSetConnectionCteTransmitParameters)
+    /// event is generated.
+    async fn le_set_connection_cte_transmit_parameters(
+        &mut self,
+        params: &ConnectionCteTransmitParameters<'_>,
+    ) -> Result<(), Error>;
+
+    /// Starts or stops requesting Constant Tone Extensions from the peer device on an ACL
+    /// connection.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.85.
+    ///
+    /// # Errors
+    ///
+    /// - [`BadCteLength`](Error::BadCteLength) if the requested CTE length is shorter than 16 µs
+    ///   or longer than 160 µs.
+    /// - Underlying communication errors.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command
+    /// Complete](crate::event::command::ReturnParameters::LeConnectionCteRequestEnable) event is
+    /// generated. While requests are enabled, an
+    /// [LE Connection IQ Report](crate::event::Event::LeConnectionIqReport) event is generated
+    /// for each received Constant Tone Extension, and an
+    /// [LE CTE Request Failed](crate::event::Event::LeCteRequestFailed) event is generated when
+    /// the peer device does not respond with one.
+    async fn le_connection_cte_request_enable(
+        &mut self,
+        params: &ConnectionCteRequestParameters,
+    ) -> Result<(), Error>;
+
+    /// Enables or disables responding to Constant Tone Extension requests from the peer device on
+    /// an ACL connection.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.86.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command
+    /// Complete](crate::event::command::ReturnParameters::LeConnectionCteResponseEnable) event is
+    /// generated.
+    async fn le_connection_cte_response_enable(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        enable: bool,
+    );
+
+    /// Reads the switching and sampling capabilities and the antennae of the Controller.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 7.8.87.
+    ///
+    /// # Errors
+    ///
+    /// Only underlying communication errors are reported.
+    ///
+    /// # Generated events
+    ///
+    /// A [Command Complete](crate::event::command::ReturnParameters::LeReadAntennaInformation)
+    /// event is generated.
+    async fn le_read_antenna_information(&mut self);
+}
+
+/// Errors that may occur when sending commands to the controller.  Must be specialized on the types
+/// of communication errors.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Error {
+    /// For the [`disconnect`](HostHci::disconnect) command: The provided reason is not a valid
+    /// disconnection reason. Includes the reported reason.
+    BadDisconnectionReason(Status),
+
+    /// For the [`le_set_random_address`](HostHci::le_set_random_address) command: The provided address
+    /// does not meet the rules for random addresses in the Bluetooth Spec, Vol 6, Part B, Section
+    /// 1.3.  Includes the invalid address.
+    BadRandomAddress(crate::BdAddr),
+
+    /// For the [`le_set_advertising_parameters`](HostHci::le_set_advertising_parameters) command: The
+    /// channel map did not include any enabled channels.  Includes the provided channel map.
+    BadChannelMap(Channels),
+
+    /// For the [`le_set_advertising_data`](HostHci::le_set_advertising_data) or
+    /// [`le_set_scan_response_data`](HostHci::le_set_scan_response_data) commands: The provided data is
+    /// too long to fit in the command.  The maximum allowed length is 31.  The actual length is
+    /// returned.
+    AdvertisingDataTooLong(usize),
+
+    /// For the [`le_create_connection`](HostHci::le_create_connection) command: the connection length
+    /// range is inverted (i.e, the minimum is greater than the maximum). Returns the range, min
+    /// first.
+    BadConnectionLengthRange(Duration, Duration),
+
+    /// For the [`le_set_host_channel_classification`](HostHci::le_set_host_channel_classification)
+    /// command: all channels were marked 'bad'.
+    NoValidChannel,
+
+    /// For the [`le_receiver_test`](HostHci::le_receiver_test) and
+    /// [`le_transmitter_test`](HostHci::le_transmitter_test) commands: the channel was out of
+    /// range. The maximum allowed channel is 39. Includes the invalid value.
+    InvalidTestChannel(u8),
+
+    /// For the [`le_transmitter_test`](HostHci::le_transmitter_test) command: The payload length is
+    /// invalid. The maximum value is 37. Includes the invalid value.
+    InvalidTestPayloadLength(usize),
+
+    /// For the [`le_set_data_length`](HostHci::le_set_data_length) and
+    /// [`le_write_suggested_default_data_length`](HostHci::le_write_suggested_default_data_length)
+    /// commands: the number of payload octets is out of range. The allowed range is 27 to 251.
+    /// Includes the invalid value.
+    InvalidDataLengthOctets(u16),
+
+    /// For the [`le_set_data_length`](HostHci::le_set_data_length) and
+    /// [`le_write_suggested_default_data_length`](HostHci::le_write_suggested_default_data_length)
+    /// commands: the transmission time is out of range. The allowed range is 328 to 17040
+    /// microseconds. Includes the invalid value.
+    InvalidDataLengthTime(u16),
+
+    /// For the [`le_set_default_phy`](HostHci::le_set_default_phy) and
+    /// [`le_set_phy`](HostHci::le_set_phy) commands: no transmitter PHY was preferred, but the
+    /// Host did not indicate that it has no preference.
+    NoTxPhys,
+
+    /// For the [`le_set_default_phy`](HostHci::le_set_default_phy) and
+    /// [`le_set_phy`](HostHci::le_set_phy) commands: no receiver PHY was preferred, but the Host
+    /// did not indicate that it has no preference.
+    NoRxPhys,
+
+    /// For the
+    /// [`le_set_resolvable_private_address_timeout`](HostHci::le_set_resolvable_private_address_timeout)
+    /// command: the timeout is out of range. The allowed range is 1 second to 1 hour. Includes the
+    /// invalid value.
+    BadRpaTimeout(Duration),
+
+    /// For the
+    /// [`le_set_extended_advertising_parameters`](HostHci::le_set_extended_advertising_parameters)
+    /// command: the PHY cannot be used on the primary advertising channels. Includes the PHY.
+    BadPrimaryAdvertisingPhy(AdvertisingPhy),
+
+    /// For the [`le_set_extended_advertising_data`](HostHci::le_set_extended_advertising_data) or
+    /// [`le_set_extended_scan_response_data`](HostHci::le_set_extended_scan_response_data)
+    /// commands: The provided data is too long to fit in the command. The maximum allowed length
+    /// is 251. The actual length is returned.
+    ExtendedAdvertisingDataTooLong(usize),
+
+    /// For the
+    /// [`le_set_extended_advertising_enable`](HostHci::le_set_extended_advertising_enable) command:
+    /// More advertising sets were given than fit in the command. The maximum allowed number is 63.
+    /// The actual number is returned.
+    TooManyAdvertisingSets(usize),
+
+    /// For the [`le_set_extended_scan_parameters`](HostHci::le_set_extended_scan_parameters)
+    /// command: no scan parameters were given for any PHY.
+    NoScanningPhys,
+
+    /// For the [`le_set_extended_scan_enable`](HostHci::le_set_extended_scan_enable) command: the
+    /// duration is out of range. It must be zero (scan until disabled), or from 10 ms to 655.35
+    /// seconds. Includes the invalid value.
+    BadScanDuration(Duration),
+
+    /// For the [`le_set_extended_scan_enable`](HostHci::le_set_extended_scan_enable) command: the
+    /// period is out of range. It must be zero (scan continuously), or from 1.28 seconds to
+    /// 83,884.8 seconds and longer than a non-zero duration. Includes the invalid value.
+    BadScanPeriod(Duration),
+
+    /// For the [`le_extended_create_connection`](HostHci::le_extended_create_connection) command:
+    /// no connection parameters were given for any PHY.
+    NoInitiatingPhys,
+
+    /// For the [`le_set_periodic_advertising_parameters`](HostHci::le_set_periodic_advertising_parameters)
+    /// command: The periodic advertising interval is out of range, or the minimum is greater than
+    /// the maximum. Includes the minimum and maximum.
+    BadPeriodicAdvertisingInterval(Duration, Duration),
+
+    /// For the [`le_set_periodic_advertising_data`](HostHci::le_set_periodic_advertising_data)
+    /// command: The data is too long to fit in one command. Includes the length of the data.
+    PeriodicAdvertisingDataTooLong(usize),
+
+    /// For the [`le_periodic_advertising_create_sync`](HostHci::le_periodic_advertising_create_sync)
+    /// and periodic advertiser list commands: The advertising SID is greater than 0x0F. Includes
+    /// the SID.
+    BadAdvertisingSid(u8),
+
+    /// For the [`le_periodic_advertising_create_sync`](HostHci::le_periodic_advertising_create_sync)
+    /// and periodic advertising sync transfer parameters commands: The number of periodic
+    /// advertising packets that can be skipped is greater than 0x01F3. Includes the skip.
+    BadSyncSkip(u16),
+
+    /// For the [`le_periodic_advertising_create_sync`](HostHci::le_periodic_advertising_create_sync)
+    /// and periodic advertising sync transfer parameters commands: The sync timeout is out of
+    /// range. Includes the timeout.
+    BadSyncTimeout(Duration),
+
+    /// For the [`le_set_cig_parameters`](HostHci::le_set_cig_parameters) and
+    /// [`le_set_cig_parameters_test`](HostHci::le_set_cig_parameters_test) commands: The CIG ID is
+    /// greater than 0xEF. Includes the ID.
+    BadCigId(u8),
+
+    /// For the [`le_set_cig_parameters`](HostHci::le_set_cig_parameters) and
+    /// [`le_set_cig_parameters_test`](HostHci::le_set_cig_parameters_test) commands: A CIS ID is
+    /// greater than 0xEF. Includes the ID.
+    BadCisId(u8),
+
+    /// For the [`le_set_cig_parameters`](HostHci::le_set_cig_parameters),
+    /// [`le_set_cig_parameters_test`](HostHci::le_set_cig_parameters_test) and
+    /// [`le_create_cis`](HostHci::le_create_cis) commands: More CISes were given than fit in the
+    /// command. The actual number is returned.
+    TooManyCis(usize),
+
+    /// For the [`le_set_cig_parameters`](HostHci::le_set_cig_parameters),
+    /// [`le_set_cig_parameters_test`](HostHci::le_set_cig_parameters_test) and
+    /// [`le_create_big`](HostHci::le_create_big) commands: An SDU interval is out of range.
+    /// Includes the interval.
+    BadSduInterval(Duration),
+
+    /// For the [`le_set_cig_parameters`](HostHci::le_set_cig_parameters) and
+    /// [`le_create_big`](HostHci::le_create_big) commands: A maximum transport latency is out of
+    /// range. Includes the latency.
+    BadMaxTransportLatency(Duration),
+
+    /// For the [`le_set_cig_parameters_test`](HostHci::le_set_cig_parameters_test) command: The
+    /// ISO interval is out of range. Includes the interval.
+    BadIsoInterval(Duration),
+
+    /// For the [`le_setup_iso_data_path`](HostHci::le_setup_iso_data_path) command: The controller
+    /// delay is longer than 4 s. Includes the delay.
+    BadControllerDelay(Duration),
+
+    /// For the [`le_setup_iso_data_path`](HostHci::le_setup_iso_data_path) command: The codec
+    /// configuration is too long to fit in the command. The maximum allowed length is 242. The
+    /// actual length is returned.
+    CodecConfigurationTooLong(usize),
+
+    /// For the [`le_create_big`](HostHci::le_create_big) command: The number of BISes is 0 or
+    /// greater than 31. Includes the number.
+    BadNumBis(u8),
+
+    /// For the [`le_big_create_sync`](HostHci::le_big_create_sync) command: The BIG sync timeout
+    /// is out of range. Includes the timeout.
+    BadBigSyncTimeout(Duration),
+
+    /// For the [`le_big_create_sync`](HostHci::le_big_create_sync) command: More BISes were given
+    /// than fit in the command. The maximum allowed number is 31. The actual number is returned.
+    TooManyBis(usize),
+
+    /// For the
+    /// [`le_set_connectionless_cte_transmit_parameters`](HostHci::le_set_connectionless_cte_transmit_parameters)
+    /// and [`le_connection_cte_request_enable`](HostHci::le_connection_cte_request_enable)
+    /// commands: The CTE length is shorter than 16 µs or longer than 160 µs. Includes the length.
+    BadCteLength(Duration),
+
+    /// For the
+    /// [`le_set_connectionless_cte_transmit_parameters`](HostHci::le_set_connectionless_cte_transmit_parameters)
+    /// command: The CTE count is 0 or greater than 16. Includes the count.
+    BadCteCount(u8),
+
+    /// For the
+    /// [`le_set_connectionless_iq_sampling_enable`](HostHci::le_set_connectionless_iq_sampling_enable)
+    /// command: The maximum number of sampled CTEs is greater than 16. Includes the number.
+    BadMaxSampledCtes(u8),
+
+    /// For the Constant Tone Extension commands: The antenna switching pattern has fewer than 2
+    /// or more than 75 antenna IDs. Includes the number of antenna IDs.
+    BadSwitchingPatternLength(usize),
+}
+
+async fn set_outbound_data<T>(
+    controller: &mut T,
+    opcode: crate::opcode::Opcode,
+    data: &[u8],
+) -> Result<(), Error>
+where
+    T: crate::Controller,
+{
+    const MAX_DATA_LEN: usize = 31;
+    if data.len() > MAX_DATA_LEN {
+        return Err(Error::AdvertisingDataTooLong(data.len()));
+    }
+    let mut params = [0; 32];
+    params[0] = data.len() as u8;
+    params[1..=data.len()].copy_from_slice(data);
+    controller.controller_write(opcode, &params).await;
+
+    Ok(())
+}
+
+impl<T> HostHci for T
+where
+    T: crate::Controller,
+{
+    async fn disconnect(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        reason: Status,
+    ) -> Result<(), Error> {
+        match reason {
+            Status::AuthFailure
+            | Status::RemoteTerminationByUser
+            | Status::RemoteTerminationLowResources
+            | Status::RemoteTerminationPowerOff
+            | Status::UnsupportedRemoteFeature
+            | Status::PairingWithUnitKeyNotSupported
+            | Status::UnacceptableConnectionParameters => (),
+            _ => return Err(Error::BadDisconnectionReason(reason)),
+        }
+
+        let mut params = [0; 3];
+        LittleEndian::write_u16(&mut params[0..], conn_handle.0);
+        params[2] = reason.into();
+        self.controller_write(crate::opcode::DISCONNECT, &params)
+            .await;
+
+        Ok(())
+    }
+
+    async fn read_remote_version_information(&mut self, conn_handle: ConnectionHandle) {
+        let mut params = [0; 2];
+        LittleEndian::write_u16(&mut params, conn_handle.0);
+        self.controller_write(crate::opcode::READ_REMOTE_VERSION_INFO, &params)
+            .await;
+    }
+
+    async fn set_event_mask(&mut self, mask: EventFlags) {
+        let mut params = [0; 8];
+        LittleEndian::write_u64(&mut params, mask.bits());
+
+        self.controller_write(crate::opcode::SET_EVENT_MASK, &params)
+            .await;
+    }
+
+    async fn reset(&mut self) {
+        self.controller_write(crate::opcode::RESET, &[]).await;
+    }
+
+    async fn read_tx_power_level(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        power_level_type: TxPowerLevel,
+    ) {
+        let mut params = [0; 3];
+        LittleEndian::write_u16(&mut params, conn_handle.0);
+        params[2] = power_level_type as u8;
+        self.controller_write(crate::opcode::READ_TX_POWER_LEVEL, &params)
+            .await;
+    }
+
+    async fn set_controller_to_host_flow_control(&mut self, flow_control: FlowControl) {
+        self.controller_write(
+            crate::opcode::SET_CONTROLLER_TO_HOST_FLOW_CONTROL,
+            &[flow_control as u8],
+        )
+        .await;
+    }
+
+    async fn host_buffer_size(&mut self, params: HostBufferSize) {
+        let mut bytes = [0; 7];
+        params.copy_into_slice(&mut bytes);
+        self.controller_write(crate::opcode::HOST_BUFFER_SIZE, &bytes)
+            .await;
+    }
+
+    async fn number_of_completed_packets(&mut self, params: NumberOfCompletedPackets) {
+        let mut bytes = [0; NUMBER_OF_COMPLETED_PACKETS_MAX_LEN + 1];
+        bytes[0] = params.num_handles as u8;
+        bytes[1..].copy_from_slice(&params.data_buf);
+        let len = 1 + 4 * params.num_handles;
+        self.controller_write(crate::opcode::NUMBER_OF_COMPLETED_PACKETS, &bytes[..len])
+            .await;
+    }
+
+    async fn read_local_version_information(&mut self) {
+        self.controller_write(crate::opcode::READ_LOCAL_VERSION_INFO, &[])
+            .await;
+    }
+
+    async fn read_local_supported_commands(&mut self) {
+        self.controller_write(crate::opcode::READ_LOCAL_SUPPORTED_COMMANDS, &[])
+            .await;
+    }
+
+    async fn read_local_supported_features(&mut self) {
+        self.controller_write(crate::opcode::READ_LOCAL_SUPPORTED_FEATURES, &[])
+            .await;
+    }
+
+    async fn read_bd_addr(&mut self) {
+        self.controller_write(crate::opcode::READ_BD_ADDR, &[])
+            .await;
+    }
+
+    async fn read_rssi(&mut self, conn_handle: ConnectionHandle) {
+        let mut params = [0; 2];
+        LittleEndian::write_u16(&mut params, conn_handle.0);
+        self.controller_write(crate::opcode::READ_RSSI, &params)
+            .await;
+    }
+
+    async fn le_set_event_mask(&mut self, event_mask: LeEventFlags) {
+        let mut params = [0; 8];
+        LittleEndian::write_u64(&mut params, event_mask.bits());
+
+        self.controller_write(crate::opcode::LE_SET_EVENT_MASK, &params)
+            .await;
+    }
+
+    async fn le_read_buffer_size(&mut self) {
+        self.controller_write(crate::opcode::LE_READ_BUFFER_SIZE, &[])
+            .await;
+    }
+
+    async fn le_read_local_supported_features(&mut self) {
+        self.controller_write(crate::opcode::LE_READ_LOCAL_SUPPORTED_FEATURES, &[])
+            .await;
+    }
+
+    async fn le_set_random_address(&mut self, bd_addr: crate::BdAddr) -> Result<(), Error> {
+        validate_random_address(bd_addr)?;
+        self.controller_write(crate::opcode::LE_SET_RANDOM_ADDRESS, &bd_addr.0)
+            .await;
+
+        Ok(())
+    }
+
+    async fn le_set_advertising_parameters(
+        &mut self,
+        params: &AdvertisingParameters,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; 15];
+        params.copy_into_slice(&mut bytes)?;
+        self.controller_write(crate::opcode::LE_SET_ADVERTISING_PARAMETERS, &bytes)
+            .await;
+
+        Ok(())
+    }
+
+    async fn le_read_advertising_channel_tx_power(&mut self) {
+        self.controller_write(crate::opcode::LE_READ_ADVERTISING_CHANNEL_TX_POWER, &[])
+            .await;
+    }
+
+    async fn le_set_advertising_data(&mut self, data: &[u8]) -> Result<(), Error> {
+        set_outbound_data::<T>(self, crate::opcode::LE_SET_ADVERTISING_DATA, data).await
+    }
+
+    async fn le_set_scan_response_data(&mut self, data: &[u8]) -> Result<(), Error> {
+        set_outbound_data::<T>(self, crate::opcode::LE_SET_SCAN_RESPONSE_DATA, data).await
+    }
+
+    async fn le_set_advertising_enable(&mut self, enable: bool) {
+        self.controller_write(crate::opcode::LE_SET_ADVERTISE_ENABLE, &[enable as u8])
+            .await
+    }
+
+    async fn le_set_scan_parameters(&mut self, params: &ScanParameters) {
+        let mut bytes = [0; 7];
+        params.copy_into_slice(&mut bytes);
+        self.controller_write(crate::opcode::LE_SET_SCAN_PARAMETERS, &bytes)
+            .await;
+    }
+
+    async fn le_set_scan_enable(&mut self, enable: bool, filter_duplicates: bool) {
+        self.controller_write(
+            crate::opcode::LE_SET_SCAN_ENABLE,
+            &[enable as u8, filter_duplicates as u8],
+        )
+        .await;
+    }
+
+    async fn le_create_connection(&mut self, params: &ConnectionParameters) {
+        let mut bytes = [0; 25];
+        params.copy_into_slice(&mut bytes);
+        self.controller_write(crate::opcode::LE_CREATE_CONNECTION, &bytes)
+            .await;
+    }
+
+    async fn le_create_connection_cancel(&mut self) {
+        self.controller_write(crate::opcode::LE_CREATE_CONNECTION_CANCEL, &[])
+            .await;
+    }
+
+    async fn le_read_white_list_size(&mut self) {
+        self.controller_write(crate::opcode::LE_READ_WHITE_LIST_SIZE, &[])
+            .await;
+    }
+
+    async fn le_clear_white_list(&mut self) {
+        self.controller_write(crate::opcode::LE_CLEAR_WHITE_LIST, &[])
+            .await;
+    }
+
+    async fn le_add_device_to_white_list(&mut self, addr: crate::BdAddrType) {
+        let mut params = [0; 7];
+        addr.copy_into_slice(&mut params);
+        self.controller_write(crate::opcode::LE_ADD_DEVICE_TO_WHITE_LIST, &params)
+            .await;
+    }
+
+    async fn le_add_anon_advertising_devices_to_white_list(&mut self) {
+        self.controller_write(
+            crate::opcode::LE_ADD_DEVICE_TO_WHITE_LIST,
+            &[0xFF, 0, 0, 0, 0, 0, 0],
+        )
+        .await;
+    }
+
+    async fn le_remove_device_from_white_list(&mut self, addr: crate::BdAddrType) {
+        let mut params = [0; 7];
+        addr.copy_into_slice(&mut params);
+        self.controller_write(crate::opcode::LE_REMOVE_DEVICE_FROM_WHITE_LIST, &params)
+            .await;
+    }
+
+    async fn le_remove_anon_advertising_devices_from_white_list(&mut self) {
+        self.controller_write(
+            crate::opcode::LE_REMOVE_DEVICE_FROM_WHITE_LIST,
+            &[0xFF, 0, 0, 0, 0, 0, 0],
+        )
+        .await;
+    }
+
+    async fn le_connection_update(&mut self, params: &ConnectionUpdateParameters) {
+        let mut bytes = [0; 14];
+        params.copy_into_slice(&mut bytes);
+        self.controller_write(crate::opcode::LE_CONNECTION_UPDATE, &bytes)
+            .await;
+    }
+
+    async fn le_set_host_channel_classification(
+        &mut self,
+        channels: crate::ChannelClassification,
+    ) -> Result<(), Error> {
+        if channels.is_empty() {
+            return Err(Error::NoValidChannel);
+        }
+
+        let mut bytes = [0; 5];
+        channels.copy_into_slice(&mut bytes);
+        self.controller_write(crate::opcode::LE_SET_HOST_CHANNEL_CLASSIFICATION, &bytes)
+            .await;
+
+        Ok(())
+    }
+
+    async fn le_read_channel_map(&mut self, conn_handle: ConnectionHandle) {
+        let mut bytes = [0; 2];
+        LittleEndian::write_u16(&mut bytes, conn_handle.0);
+        self.controller_write(crate::opcode::LE_READ_CHANNEL_MAP, &bytes)
+            .await;
+    }
+
+    async fn le_read_remote_used_features(&mut self, conn_handle: ConnectionHandle) {
+        let mut bytes = [0; 2];
+        LittleEndian::write_u16(&mut bytes, conn_handle.0);
+        self.controller_write(crate::opcode::LE_READ_REMOTE_USED_FEATURES, &bytes)
+            .await;
+    }
+
+    async fn le_encrypt(&mut self, params: &AesParameters) {
+        let mut bytes = [0; 32];
+        bytes[..16].copy_from_slice(&params.key.0);
+        bytes[16..].copy_from_slice(&params.plaintext_data.0);
+        self.controller_write(crate::opcode::LE_ENCRYPT, &bytes)
+            .await;
+    }
+
+    async fn le_rand(&mut self) {
+        self.controller_write(crate::opcode::LE_RAND, &[]).await;
+    }
+
+    async fn le_start_encryption(&mut self, params: &EncryptionParameters) {
+        let mut bytes = [0; 28];
+        LittleEndian::write_u16(&mut bytes[0..], params.conn_handle.0);
+        LittleEndian::write_u64(&mut bytes[2..], params.random_number);
+        LittleEndian::write_u16(&mut bytes[10..], params.encrypted_diversifier);
+        bytes[12..].copy_from_slice(&params.long_term_key.0);
+        self.controller_write(crate::opcode::LE_START_ENCRYPTION, &bytes)
+            .await;
+    }
+
+    async fn le_long_term_key_request_reply(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        key: &EncryptionKey,
+    ) {
+        let mut bytes = [0; 18];
+        LittleEndian::write_u16(&mut bytes[0..], conn_handle.0);
+        bytes[2..].copy_from_slice(&key.0);
+        self.controller_write(crate::opcode::LE_LTK_REQUEST_REPLY, &bytes)
+            .await;
+    }
+
+    async fn le_long_term_key_request_negative_reply(&mut self, conn_handle: ConnectionHandle) {
+        let mut bytes = [0; 2];
+        LittleEndian::write_u16(&mut bytes[0..], conn_handle.0);
+        self.controller_write(crate::opcode::LE_LTK_REQUEST_NEGATIVE_REPLY, &bytes)
+            .await;
+    }
+
+    async fn le_read_supported_states(&mut self) {
+        self.controller_write(crate::opcode::LE_READ_STATES, &[])
+            .await;
+    }
+
+    async fn le_receiver_test(&mut self, channel: u8) -> Result<(), Error> {
+        if channel > MAX_TEST_CHANNEL {
+            return Err(Error::InvalidTestChannel(channel));
+        }
+
+        self.controller_write(crate::opcode::LE_RECEIVER_TEST, &[channel])
+            .await;
+
+        Ok(())
+    }
+
+    async fn le_transmitter_test(
+        &mut self,
+        channel: u8,
+        payload_length: usize,
+        payload: TestPacketPayload,
+    ) -> Result<(), Error> {
+        if channel > MAX_TEST_CHANNEL {
+            return Err(Error::InvalidTestChannel(channel));
+        }
+
+        const MAX_PAYLOAD_LENGTH: usize = 0x25;
+        if payload_length > MAX_PAYLOAD_LENGTH {
+            return Err(Error::InvalidTestPayloadLength(payload_length));
+        }
+
+        self.controller_write(
+            crate::opcode::LE_TRANSMITTER_TEST,
+            &[channel, payload_length as u8, payload as u8],
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_test_end(&mut self) {
+        self.controller_write(crate::opcode::LE_TEST_END, &[]).await;
+    }
+
+    async fn le_set_data_length(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        tx_octets: u16,
+        tx_time: u16,
+    ) -> Result<(), Error> {
+        validate_data_length(tx_octets, tx_time)?;
+
+        let mut bytes = [0; 6];
+        LittleEndian::write_u16(&mut bytes[0..], conn_handle.0);
+        LittleEndian::write_u16(&mut bytes[2..], tx_octets);
+        LittleEndian::write_u16(&mut bytes[4..], tx_time);
+        self.controller_write(crate::opcode::LE_SET_DATA_LENGTH, &bytes)
+            .await;
+
+        Ok(())
+    }
+
+    async fn le_read_suggested_default_data_length(&mut self) {
+        self.controller_write(crate::opcode::LE_READ_SUGGESTED_DEFAULT_DATA_LENGTH, &[])
+            .await;
+    }
+
+    async fn le_write_suggested_default_data_length(
+        &mut self,
+        tx_octets: u16,
+        tx_time: u16,
+    ) -> Result<(), Error> {
+        validate_data_length(tx_octets, tx_time)?;
+
+        let mut bytes = [0; 4];
+        LittleEndian::write_u16(&mut bytes[0..], tx_octets);
+        LittleEndian::write_u16(&mut bytes[2..], tx_time);
+        self.controller_write(
+            crate::opcode::LE_WRITE_SUGGESTED_DEFAULT_DATA_LENGTH,
+            &bytes,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_read_maximum_data_length(&mut self) {
+        self.controller_write(crate::opcode::LE_READ_MAXIMUM_DATA_LENGTH, &[])
+            .await;
+    }
+
+    async fn le_read_phy(&mut self, conn_handle: ConnectionHandle) {
+        let mut bytes = [0; 2];
+        LittleEndian::write_u16(&mut bytes, conn_handle.0);
+        self.controller_write(crate::opcode::LE_READ_PHY, &bytes)
+            .await;
+    }
+
+    async fn le_set_default_phy(&mut self, phys: &PhyPreferences) -> Result<(), Error> {
+        phys.validate()?;
+
+        let mut bytes = [0; 3];
+        phys.copy_into_slice(&mut bytes);
+        self.controller_write(crate::opcode::LE_SET_DEFAULT_PHY, &bytes)
+            .await;
+
+        Ok(())
+    }
+
+    async fn le_set_phy(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        phys: &PhyPreferences,
+        options: PhyOptions,
+    ) -> Result<(), Error> {
+        phys.validate()?;
+
+        let mut bytes = [0; 7];
+        LittleEndian::write_u16(&mut bytes[0..], conn_handle.0);
+        phys.copy_into_slice(&mut bytes[2..5]);
+        LittleEndian::write_u16(&mut bytes[5..], options as u16);
+        self.controller_write(crate::opcode::LE_SET_PHY, &bytes)
+            .await;
+
+        Ok(())
+    }
+
+    async fn le_add_device_to_resolving_list(&mut self, params: &ResolvingListParameters) {
+        let mut bytes = [0; 39];
+        params.copy_into_slice(&mut bytes);
+        self.controller_write(crate::opcode::LE_ADD_DEVICE_TO_RESOLVING_LIST, &bytes)
+            .await;
+    }
+
+    async fn le_remove_device_from_resolving_list(
+        &mut self,
+        peer_identity_address: crate::BdAddrType,
+    ) {
+        let mut bytes = [0; 7];
+        peer_identity_address.copy_into_slice(&mut bytes);
+        self.controller_write(crate::opcode::LE_REMOVE_DEVICE_FROM_RESOLVING_LIST, &bytes)
+            .await;
+    }
+
+    async fn le_clear_resolving_list(&mut self) {
+        self.controller_write(crate::opcode::LE_CLEAR_RESOLVING_LIST, &[])
+            .await;
+    }
+
+    async fn le_read_resolving_list_size(&mut self) {
+        self.controller_write(crate::opcode::LE_READ_RESOLVING_LIST_SIZE, &[])
+            .await;
+    }
+
+    async fn le_read_peer_resolvable_address(&mut self, peer_identity_address: crate::BdAddrType) {
+        let mut bytes = [0; 7];
+        peer_identity_address.copy_into_slice(&mut bytes);
+        self.controller_write(crate::opcode::LE_READ_PEER_RESOLVABLE_ADDRESS, &bytes)
+            .await;
+    }
+
+    async fn le_read_local_resolvable_address(&mut self, peer_identity_address: crate::BdAddrType) {
+        let mut bytes = [0; 7];
+        peer_identity_address.copy_into_slice(&mut bytes);
+        self.controller_write(crate::opcode::LE_READ_LOCAL_RESOLVABLE_ADDRESS, &bytes)
+            .await;
+    }
+
+    async fn le_set_address_resolution_enable(&mut self, enable: bool) {
+        self.controller_write(
+            crate::opcode::LE_SET_ADDRESS_RESOLUTION_ENABLE,
+            &[enable as u8],
+        )
+        .await;
+    }
+
+    async fn le_set_resolvable_private_address_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        const TIMEOUT_RANGE_SECS: core::ops::RangeInclusive<u64> = 0x0001..=0x0E10;
+        if !TIMEOUT_RANGE_SECS.contains(&timeout.as_secs()) {
+            return Err(Error::BadRpaTimeout(timeout));
+        }
+
+        let mut bytes = [0; 2];
+        LittleEndian::write_u16(&mut bytes, timeout.as_secs() as u16);
+        self.controller_write(
+            crate::opcode::LE_SET_RESOLVABLE_PRIVATE_ADDRESS_TIMEOUT,
+            &bytes,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_set_privacy_mode(
+        &mut self,
+        peer_identity_address: crate::BdAddrType,
+        privacy_mode: PrivacyMode,
+    ) {
+        let mut bytes = [0; 8];
+        peer_identity_address.copy_into_slice(&mut bytes[0..7]);
+        bytes[7] = privacy_mode as u8;
+        self.controller_write(crate::opcode::LE_SET_PRIVACY_MODE, &bytes)
+            .await;
+    }
+
+    async fn le_read_local_p256_public_key(&mut self) {
+        self.controller_write(crate::opcode::LE_READ_LOCAL_P256_PUBLIC_KEY, &[])
+            .await;
+    }
+
+    async fn le_generate_dhkey(&mut self, remote_key: &P256PublicKey) {
+        self.controller_write(crate::opcode::LE_GENERATE_DHKEY, &remote_key.0)
+            .await;
+    }
+
+    async fn le_generate_dhkey_v2(&mut self, remote_key: &P256PublicKey, key_type: PrivateKeyType) {
+        let mut bytes = [0; 65];
+        bytes[0..64].copy_from_slice(&remote_key.0);
+        bytes[64] = key_type as u8;
+        self.controller_write(crate::opcode::LE_GENERATE_DHKEY_V2, &bytes)
+            .await;
+    }
+
+    async fn le_set_extended_advertising_parameters(
+        &mut self,
+        params: &ExtendedAdvertisingParameters,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; 25];
+        params.copy_into_slice(&mut bytes)?;
+        self.controller_write(
+            crate::opcode::LE_SET_EXTENDED_ADVERTISING_PARAMETERS,
+            &bytes,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_set_extended_advertising_data(
+        &mut self,
+        data: &ExtendedAdvertisingData<'_>,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; ExtendedAdvertisingData::MAX_LENGTH];
+        let len = data.copy_into_slice(&mut bytes)?;
+        self.controller_write(
+            crate::opcode::LE_SET_EXTENDED_ADVERTISING_DATA,
+            &bytes[..len],
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_set_extended_scan_response_data(
+        &mut self,
+        data: &ExtendedAdvertisingData<'_>,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; ExtendedAdvertisingData::MAX_LENGTH];
+        let len = data.copy_into_slice(&mut bytes)?;
+        self.controller_write(
+            crate::opcode::LE_SET_EXTENDED_SCAN_RESPONSE_DATA,
+            &bytes[..len],
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_set_extended_advertising_enable(
+        &mut self,
+        enable: bool,
+        sets: &[AdvSet],
+    ) -> Result<(), Error> {
+        const MAX_SETS: usize = 0x3F;
+        if sets.len() > MAX_SETS {
+            return Err(Error::TooManyAdvertisingSets(sets.len()));
+        }
+
+        let mut bytes = [0; 2 + 4 * MAX_SETS];
+        bytes[0] = enable as u8;
+        bytes[1] = sets.len() as u8;
+        for (set, chunk) in sets.iter().zip(bytes[2..].chunks_mut(4)) {
+            set.copy_into_slice(chunk);
+        }
+        self.controller_write(
+            crate::opcode::LE_SET_EXTENDED_ADVERTISING_ENABLE,
+            &bytes[..2 + 4 * sets.len()],
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_read_maximum_advertising_data_length(&mut self) {
+        self.controller_write(crate::opcode::LE_READ_MAXIMUM_ADVERTISING_DATA_LENGTH, &[])
+            .await;
+    }
+
+    async fn le_read_number_of_supported_advertising_sets(&mut self) {
+        self.controller_write(
+            crate::opcode::LE_READ_NUMBER_OF_SUPPORTED_ADVERTISING_SETS,
+            &[],
+        )
+        .await;
+    }
+
+    async fn le_remove_advertising_set(&mut self, adv_handle: AdvertisingHandle) {
+        self.controller_write(crate::opcode::LE_REMOVE_ADVERTISING_SET, &[adv_handle.0])
+            .await;
+    }
+
+    async fn le_clear_advertising_sets(&mut self) {
+        self.controller_write(crate::opcode::LE_CLEAR_ADVERTISING_SETS, &[])
+            .await;
+    }
+
+    async fn le_set_extended_scan_parameters(
+        &mut self,
+        params: &ExtendedScanParameters,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; ExtendedScanParameters::MAX_LENGTH];
+        let len = params.copy_into_slice(&mut bytes)?;
+        self.controller_write(
+            crate::opcode::LE_SET_EXTENDED_SCAN_PARAMETERS,
+            &bytes[..len],
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_set_extended_scan_enable(
+        &mut self,
+        params: &ExtendedScanEnable,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; 6];
+        params.copy_into_slice(&mut bytes)?;
+        self.controller_write(crate::opcode::LE_SET_EXTENDED_SCAN_ENABLE, &bytes)
+            .await;
+
+        Ok(())
+    }
+
+    async fn le_extended_create_connection(
+        &mut self,
+        params: &ExtendedConnectionParameters,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; ExtendedConnectionParameters::MAX_LENGTH];
+        let len = params.copy_into_slice(&mut bytes)?;
+        self.controller_write(crate::opcode::LE_EXTENDED_CREATE_CONNECTION, &bytes[..len])
+            .await;
+
+        Ok(())
+    }
+
+    async fn le_set_periodic_advertising_parameters(
+        &mut self,
+        params: &PeriodicAdvertisingParameters,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; 7];
+        params.copy_into_slice(&mut bytes)?;
+        self.controller_write(
+            crate::opcode::LE_SET_PERIODIC_ADVERTISING_PARAMETERS,
+            &bytes,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_set_periodic_advertising_data(
+        &mut self,
+        data: &PeriodicAdvertisingData<'_>,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; PeriodicAdvertisingData::MAX_LENGTH];
+        let len = data.copy_into_slice(&mut bytes)?;
+        self.controller_write(
+            crate::opcode::LE_SET_PERIODIC_ADVERTISING_DATA,
+            &bytes[..len],
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_set_periodic_advertising_enable(
+        &mut self,
+        enable: bool,
+        adv_handle: AdvertisingHandle,
+    ) {
+        self.controller_write(
+            crate::opcode::LE_SET_PERIODIC_ADVERTISING_ENABLE,
+            &[enable as u8, adv_handle.0],
+        )
+        .await;
+    }
+
+    async fn le_periodic_advertising_create_sync(
+        &mut self,
+        params: &PeriodicAdvertisingSyncParameters,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; 14];
+        params.copy_into_slice(&mut bytes)?;
+        self.controller_write(crate::opcode::LE_PERIODIC_ADVERTISING_CREATE_SYNC, &bytes)
+            .await;
+
+        Ok(())
+    }
+
+    async fn le_periodic_advertising_create_sync_cancel(&mut self) {
+        self.controller_write(
+            crate::opcode::LE_PERIODIC_ADVERTISING_CREATE_SYNC_CANCEL,
+            &[],
+        )
+        .await;
+    }
+
+    async fn le_periodic_advertising_terminate_sync(&mut self, sync_handle: SyncHandle) {
+        let mut bytes = [0; 2];
+        LittleEndian::write_u16(&mut bytes, sync_handle.0);
+        self.controller_write(
+            crate::opcode::LE_PERIODIC_ADVERTISING_TERMINATE_SYNC,
+            &bytes,
+        )
+        .await;
+    }
+
+    async fn le_add_device_to_periodic_advertiser_list(
+        &mut self,
+        advertiser: &PeriodicAdvertiser,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; 8];
+        advertiser.copy_into_slice(&mut bytes)?;
+        self.controller_write(
+            crate::opcode::LE_ADD_DEVICE_TO_PERIODIC_ADVERTISER_LIST,
+            &bytes,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_remove_device_from_periodic_advertiser_list(
+        &mut self,
+        advertiser: &PeriodicAdvertiser,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; 8];
+        advertiser.copy_into_slice(&mut bytes)?;
+        self.controller_write(
+            crate::opcode::LE_REMOVE_DEVICE_FROM_PERIODIC_ADVERTISER_LIST,
+            &bytes,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_clear_periodic_advertiser_list(&mut self) {
+        self.controller_write(crate::opcode::LE_CLEAR_PERIODIC_ADVERTISER_LIST, &[])
+            .await;
+    }
+
+    async fn le_read_periodic_advertiser_list_size(&mut self) {
+        self.controller_write(crate::opcode::LE_READ_PERIODIC_ADVERTISER_LIST_SIZE, &[])
+            .await;
+    }
+
+    async fn le_periodic_advertising_sync_transfer(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        service_data: u16,
+        sync_handle: SyncHandle,
+    ) {
+        let mut bytes = [0; 6];
+        LittleEndian::write_u16(&mut bytes[0..], conn_handle.0);
+        LittleEndian::write_u16(&mut bytes[2..], service_data);
+        LittleEndian::write_u16(&mut bytes[4..], sync_handle.0);
+        self.controller_write(crate::opcode::LE_PERIODIC_ADVERTISING_SYNC_TRANSFER, &bytes)
+            .await;
+    }
+
+    async fn le_periodic_advertising_set_info_transfer(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        service_data: u16,
+        adv_handle: AdvertisingHandle,
+    ) {
+        let mut bytes = [0; 5];
+        LittleEndian::write_u16(&mut bytes[0..], conn_handle.0);
+        LittleEndian::write_u16(&mut bytes[2..], service_data);
+        bytes[4] = adv_handle.0;
+        self.controller_write(
+            crate::opcode::LE_PERIODIC_ADVERTISING_SET_INFO_TRANSFER,
+            &bytes,
+        )
+        .await;
+    }
+
+    async fn le_set_periodic_advertising_sync_transfer_parameters(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        params: &SyncTransferParameters,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; 8];
+        LittleEndian::write_u16(&mut bytes[0..], conn_handle.0);
+        params.copy_into_slice(&mut bytes[2..])?;
+        self.controller_write(
+            crate::opcode::LE_SET_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS,
+            &bytes,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_set_default_periodic_advertising_sync_transfer_parameters(
+        &mut self,
+        params: &SyncTransferParameters,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; 6];
+        params.copy_into_slice(&mut bytes)?;
+        self.controller_write(
+            crate::opcode::LE_SET_DEFAULT_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS,
+            &bytes,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_read_iso_tx_sync(&mut self, conn_handle: ConnectionHandle) {
+        let mut bytes = [0; 2];
+        LittleEndian::write_u16(&mut bytes, conn_handle.0);
+        self.controller_write(crate::opcode::LE_READ_ISO_TX_SYNC, &bytes)
+            .await;
+    }
+
+    async fn le_set_cig_parameters(
+        &mut self,
+        params: &CigParameters,
+        cis: &[CisParameters],
+    ) -> Result<(), Error> {
+        // Max parameter length (255 bytes) less the CIG parameters (15 bytes) divided by the size
+        // of the CIS parameters (9 bytes):
+        const MAX_CIS: usize = 26;
+        if cis.len() > MAX_CIS {
+            return Err(Error::TooManyCis(cis.len()));
+        }
+
+        let mut bytes = [0; 15 + CisParameters::LEN * MAX_CIS];
+        params.copy_into_slice(&mut bytes[..14])?;
+        bytes[14] = cis.len() as u8;
+        for (cis, chunk) in cis.iter().zip(bytes[15..].chunks_mut(CisParameters::LEN)) {
+            cis.copy_into_slice(chunk)?;
+        }
+        self.controller_write(
+            crate::opcode::LE_SET_CIG_PARAMETERS,
+            &bytes[..15 + CisParameters::LEN * cis.len()],
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_set_cig_parameters_test(
+        &mut self,
+        params: &CigTestParameters,
+        cis: &[CisTestParameters],
+    ) -> Result<(), Error> {
+        // Max parameter length (255 bytes) less the CIG parameters (15 bytes) divided by the size
+        // of the CIS parameters (14 bytes):
+        const MAX_CIS: usize = 17;
+        if cis.len() > MAX_CIS {
+            return Err(Error::TooManyCis(cis.len()));
+        }
+
+        let mut bytes = [0; 15 + CisTestParameters::LEN * MAX_CIS];
+        params.copy_into_slice(&mut bytes[..14])?;
+        bytes[14] = cis.len() as u8;
+        for (cis, chunk) in cis
+            .iter()
+            .zip(bytes[15..].chunks_mut(CisTestParameters::LEN))
+        {
+            cis.copy_into_slice(chunk)?;
+        }
+        self.controller_write(
+            crate::opcode::LE_SET_CIG_PARAMETERS_TEST,
+            &bytes[..15 + CisTestParameters::LEN * cis.len()],
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_create_cis(&mut self, cis: &[CisConnection]) -> Result<(), Error> {
+        const MAX_CIS: usize = 0x1F;
+        if cis.len() > MAX_CIS {
+            return Err(Error::TooManyCis(cis.len()));
+        }
+
+        let mut bytes = [0; 1 + CisConnection::LEN * MAX_CIS];
+        bytes[0] = cis.len() as u8;
+        for (cis, chunk) in cis.iter().zip(bytes[1..].chunks_mut(CisConnection::LEN)) {
+            cis.copy_into_slice(chunk);
+        }
+        self.controller_write(
+            crate::opcode::LE_CREATE_CIS,
+            &bytes[..1 + CisConnection::LEN * cis.len()],
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_remove_cig(&mut self, cig_id: u8) {
+        self.controller_write(crate::opcode::LE_REMOVE_CIG, &[cig_id])
+            .await;
+    }
+
+    async fn le_accept_cis_request(&mut self, conn_handle: ConnectionHandle) {
+        let mut bytes = [0; 2];
+        LittleEndian::write_u16(&mut bytes, conn_handle.0);
+        self.controller_write(crate::opcode::LE_ACCEPT_CIS_REQUEST, &bytes)
+            .await;
+    }
+
+    async fn le_reject_cis_request(&mut self, conn_handle: ConnectionHandle, reason: Status) {
+        let mut bytes = [0; 3];
+        LittleEndian::write_u16(&mut bytes, conn_handle.0);
+        bytes[2] = reason.into();
+        self.controller_write(crate::opcode::LE_REJECT_CIS_REQUEST, &bytes)
+            .await;
+    }
+
+    async fn le_setup_iso_data_path(
+        &mut self,
+        params: &IsoDataPathParameters<'_>,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; 255];
+        let len = params.copy_into_slice(&mut bytes)?;
+        self.controller_write(crate::opcode::LE_SETUP_ISO_DATA_PATH, &bytes[..len])
+            .await;
+
+        Ok(())
+    }
+
+    async fn le_remove_iso_data_path(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        directions: DataPathDirections,
+    ) {
+        let mut bytes = [0; 3];
+        LittleEndian::write_u16(&mut bytes, conn_handle.0);
+        bytes[2] = directions.bits();
+        self.controller_write(crate::opcode::LE_REMOVE_ISO_DATA_PATH, &bytes)
+            .await;
+    }
+
+    async fn le_create_big(&mut self, params: &BigParameters) -> Result<(), Error> {
+        let mut bytes = [0; 31];
+        params.copy_into_slice(&mut bytes)?;
+        self.controller_write(crate::opcode::LE_CREATE_BIG, &bytes)
+            .await;
+
+        Ok(())
+    }
+
+    async fn le_terminate_big(&mut self, big_handle: BigHandle, reason: Status) {
+        self.controller_write(
+            crate::opcode::LE_TERMINATE_BIG,
+            &[big_handle.0, reason.into()],
+        )
+        .await;
+    }
+
+    async fn le_big_create_sync(&mut self, params: &BigSyncParameters<'_>) -> Result<(), Error> {
+        let mut bytes = [0; 24 + MAX_BIS_COUNT];
+        let len = params.copy_into_slice(&mut bytes)?;
+        self.controller_write(crate::opcode::LE_BIG_CREATE_SYNC, &bytes[..len])
+            .await;
+
+        Ok(())
+    }
+
+    async fn le_big_terminate_sync(&mut self, big_handle: BigHandle) {
+        self.controller_write(crate::opcode::LE_BIG_TERMINATE_SYNC, &[big_handle.0])
+            .await;
+    }
+
+    async fn le_enhanced_read_transmit_power_level(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        phy: PowerControlPhy,
+    ) {
+        let mut bytes = [0; 3];
+        LittleEndian::write_u16(&mut bytes, conn_handle.0);
+        bytes[2] = phy.into();
+        self.controller_write(crate::opcode::LE_ENHANCED_READ_TRANSMIT_POWER_LEVEL, &bytes)
+            .await;
+    }
+
+    async fn le_read_remote_transmit_power_level(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        phy: PowerControlPhy,
+    ) {
+        let mut bytes = [0; 3];
+        LittleEndian::write_u16(&mut bytes, conn_handle.0);
+        bytes[2] = phy.into();
+        self.controller_write(crate::opcode::LE_READ_REMOTE_TRANSMIT_POWER_LEVEL, &bytes)
+            .await;
+    }
+
+    async fn le_set_path_loss_reporting_parameters(
+        &mut self,
+        params: &PathLossReportingParameters,
+    ) {
+        let mut bytes = [0; 8];
+        params.copy_into_slice(&mut bytes);
+        self.controller_write(crate::opcode::LE_SET_PATH_LOSS_REPORTING_PARAMETERS, &bytes)
+            .await;
+    }
+
+    async fn le_set_path_loss_reporting_enable(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        enable: bool,
+    ) {
+        let mut bytes = [0; 3];
+        LittleEndian::write_u16(&mut bytes, conn_handle.0);
+        bytes[2] = enable as u8;
+        self.controller_write(crate::opcode::LE_SET_PATH_LOSS_REPORTING_ENABLE, &bytes)
+            .await;
+    }
+
+    async fn le_set_transmit_power_reporting_enable(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        local_enable: bool,
+        remote_enable: bool,
+    ) {
+        let mut bytes = [0; 4];
+        LittleEndian::write_u16(&mut bytes, conn_handle.0);
+        bytes[2] = local_enable as u8;
+        bytes[3] = remote_enable as u8;
+        self.controller_write(
+            crate::opcode::LE_SET_TRANSMIT_POWER_REPORTING_ENABLE,
+            &bytes,
+        )
+        .await;
+    }
+
+    async fn le_set_default_subrate(&mut self, params: &SubrateParameters) {
+        let mut bytes = [0; 10];
+        params.copy_into_slice(&mut bytes);
+        self.controller_write(crate::opcode::LE_SET_DEFAULT_SUBRATE, &bytes)
+            .await;
+    }
+
+    async fn le_subrate_request(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        params: &SubrateParameters,
+    ) {
+        let mut bytes = [0; 12];
+        LittleEndian::write_u16(&mut bytes[0..2], conn_handle.0);
+        params.copy_into_slice(&mut bytes[2..]);
+        self.controller_write(crate::opcode::LE_SUBRATE_REQUEST, &bytes)
+            .await;
+    }
+
+    async fn le_set_connectionless_cte_transmit_parameters(
+        &mut self,
+        params: &ConnectionlessCteTransmitParameters<'_>,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; 5 + MAX_SWITCHING_PATTERN_LEN];
+        let len = params.copy_into_slice(&mut bytes)?;
+        self.controller_write(
+            crate::opcode::LE_SET_CONNECTIONLESS_CTE_TRANSMIT_PARAMETERS,
+            &bytes[..len],
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_set_connectionless_cte_transmit_enable(
+        &mut self,
+        adv_handle: AdvertisingHandle,
+        enable: bool,
+    ) {
+        self.controller_write(
+            crate::opcode::LE_SET_CONNECTIONLESS_CTE_TRANSMIT_ENABLE,
+            &[adv_handle.0, enable as u8],
+        )
+        .await;
+    }
+
+    async fn le_set_connectionless_iq_sampling_enable(
+        &mut self,
+        params: &ConnectionlessIqSamplingParameters<'_>,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; 6 + MAX_SWITCHING_PATTERN_LEN];
+        let len = params.copy_into_slice(&mut bytes)?;
+        self.controller_write(
+            crate::opcode::LE_SET_CONNECTIONLESS_IQ_SAMPLING_ENABLE,
+            &bytes[..len],
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_set_connection_cte_receive_parameters(
+        &mut self,
+        params: &ConnectionCteReceiveParameters<'_>,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; 5 + MAX_SWITCHING_PATTERN_LEN];
+        let len = params.copy_into_slice(&mut bytes)?;
+        self.controller_write(
+            crate::opcode::LE_SET_CONNECTION_CTE_RECEIVE_PARAMETERS,
+            &bytes[..len],
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn le_set_connection_cte_transmit_parameters(
+        &mut self,
+        params: &ConnectionCteTransmitParameters<'_>,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; 4 + MAX_SWITCHING_PATTERN_LEN];
+        let len = params.copy_into_slice(&mut bytes)?;
+        self.controller_write(
+            crate::opcode::LE_SET_CONNECTION_CTE_TRANSMIT_PARAMETERS,
+            &bytes[..len],
         )
         .await;
+
+        Ok(())
+    }
+
+    async fn le_connection_cte_request_enable(
+        &mut self,
+        params: &ConnectionCteRequestParameters,
+    ) -> Result<(), Error> {
+        let mut bytes = [0; 7];
+        params.copy_into_slice(&mut bytes)?;
+        self.controller_write(crate::opcode::LE_CONNECTION_CTE_REQUEST_ENABLE, &bytes)
+            .await;
+
+        Ok(())
+    }
+
+    async fn le_connection_cte_response_enable(
+        &mut self,
+        conn_handle: ConnectionHandle,
+        enable: bool,
+    ) {
+        let mut bytes = [0; 3];
+        LittleEndian::write_u16(&mut bytes, conn_handle.0);
+        bytes[2] = enable as u8;
+        self.controller_write(crate::opcode::LE_CONNECTION_CTE_RESPONSE_ENABLE, &bytes)
+            .await;
+    }
+
+    async fn le_read_antenna_information(&mut self) {
+        self.controller_write(crate::opcode::LE_READ_ANTENNA_INFORMATION, &[])
+            .await;
+    }
+}
+
+const MAX_TEST_CHANNEL: u8 = 0x27;
+
+fn validate_data_length(tx_octets: u16, tx_time: u16) -> Result<(), Error> {
+    const TX_OCTETS_RANGE: core::ops::RangeInclusive<u16> = 0x001B..=0x00FB;
+    const TX_TIME_RANGE: core::ops::RangeInclusive<u16> = 0x0148..=0x4290;
+
+    if !TX_OCTETS_RANGE.contains(&tx_octets) {
+        return Err(Error::InvalidDataLengthOctets(tx_octets));
+    }
+    if !TX_TIME_RANGE.contains(&tx_time) {
+        return Err(Error::InvalidDataLengthTime(tx_time));
+    }
+
+    Ok(())
+}
+
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// Event flags defined for the [`set_event_mask`](HostHci::set_event_mask) command.
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct EventFlags : u64 {
+        /// Inquiry complete event
+        const INQUIRY_COMPLETE = 0x0000_0000_0000_0001;
+        /// Inquiry result event
+        const INQUIRY_RESULT = 0x0000_0000_0000_0002;
+        /// Connection complete event
+        const CONNECTION_COMPLETE = 0x0000_0000_0000_0004;
+        /// Connection request event
+        const CONNECTION_REQUEST = 0x0000_0000_0000_0008;
+        /// Disconnection complete event
+        const DISCONNECTION_COMPLETE = 0x0000_0000_0000_0010;
+        /// Authentication complete event
+        const AUTHENTICATION_COMPLETE = 0x0000_0000_0000_0020;
+        /// Remote name request complete event
+        const REMOTE_NAME_REQUEST_COMPLETE = 0x0000_0000_0000_0040;
+        /// Encryption change event
+        const ENCRYPTION_CHANGE = 0x0000_0000_0000_0080;
+        /// Change connection link key complete event
+        const CHANGE_CONNECTION_LINK_KEY_COMPLETE = 0x0000_0000_0000_0100;
+        /// Master link key complete event
+        const MASTER_LINK_KEY_COMPLETE = 0x0000_0000_0000_0200;
+        /// Read remote supported features complete event
+        const READ_REMOTE_SUPPORTED_FEATURES_COMPLETE = 0x0000_0000_0000_0400;
+        /// Read remote version information complete event
+        const READ_REMOTE_VERSION_INFORMATION_COMPLETE = 0x0000_0000_0000_0800;
+        /// Qos setup complete event
+        const QOS_SETUP_COMPLETE = 0x0000_0000_0000_1000;
+        /// Hardware error event
+        const HARDWARE_ERROR = 0x0000_0000_0000_8000;
+        /// Flush occurred event
+        const FLUSH_OCCURRED = 0x0000_0000_0001_0000;
+        /// Role change event
+        const ROLE_CHANGE = 0x0000_0000_0002_0000;
+        /// Mode change event
+        const MODE_CHANGE = 0x0000_0000_0008_0000;
+        /// Return link keys event
+        const RETURN_LINK_KEYS = 0x0000_0000_0010_0000;
+        /// Pin code request event
+        const PIN_CODE_REQUEST = 0x0000_0000_0020_0000;
+        /// Link key request event
+        const LINK_KEY_REQUEST = 0x0000_0000_0040_0000;
+        /// Link key notification event
+        const LINK_KEY_NOTIFICATION = 0x0000_0000_0080_0000;
+        /// Loopback command event
+        const LOOPBACK_COMMAND = 0x0000_0000_0100_0000;
+        /// Data buffer overflow event
+        const DATA_BUFFER_OVERFLOW = 0x0000_0000_0200_0000;
+        /// Max slots change event
+        const MAX_SLOTS_CHANGE = 0x0000_0000_0400_0000;
+        /// Read clock offset complete event
+        const READ_CLOCK_OFFSET_COMPLETE = 0x0000_0000_0800_0000;
+        /// Connection packet type changed event
+        const CONNECTION_PACKET_TYPE_CHANGED = 0x0000_0000_1000_0000;
+        /// Qos violation event
+        const QOS_VIOLATION = 0x0000_0000_2000_0000;
+        /// Page scan mode change event. Deprecated in Bluetooth spec.
+        #[deprecated]
+        const PAGE_SCAN_MODE_CHANGE = 0x0000_0000_4000_0000;
+        /// Page scan repetition mode change event
+        const PAGE_SCAN_REPETITION_MODE_CHANGE = 0x0000_0000_8000_0000;
+        /// Flow specification complete event
+        const FLOW_SPECIFICATION_COMPLETE = 0x0000_0001_0000_0000;
+        /// Inquiry result with rssi event
+        const INQUIRY_RESULT_WITH_RSSI = 0x0000_0002_0000_0000;
+        /// Read remote extended features complete event
+        const READ_REMOTE_EXTENDED_FEATURES_COMPLETE = 0x0000_0004_0000_0000;
+        /// Synchronous connection complete event
+        const SYNCHRONOUS_CONNECTION_COMPLETE = 0x0000_0800_0000_0000;
+        /// Synchronous connection changed event
+        const SYNCHRONOUS_CONNECTION_CHANGED = 0x0000_1000_0000_0000;
+        /// Sniff subrating event
+        const SNIFF_SUBRATING = 0x0000_2000_0000_0000;
+        /// Extended inquiry result event
+        const EXTENDED_INQUIRY_RESULT = 0x0000_4000_0000_0000;
+        /// Encryption key refresh complete event
+        const ENCRYPTION_KEY_REFRESH_COMPLETE = 0x0000_8000_0000_0000;
+        /// Io capability request event
+        const IO_CAPABILITY_REQUEST = 0x0001_0000_0000_0000;
+        /// Io capability request reply event
+        const IO_CAPABILITY_REQUEST_REPLY = 0x0002_0000_0000_0000;
+        /// User confirmation request event
+        const USER_CONFIRMATION_REQUEST = 0x0004_0000_0000_0000;
+        /// User passkey request event
+        const USER_PASSKEY_REQUEST = 0x0008_0000_0000_0000;
+        /// Remote oob data request event
+        const REMOTE_OOB_DATA_REQUEST = 0x0010_0000_0000_0000;
+        /// Simple pairing complete event
+        const SIMPLE_PAIRING_COMPLETE = 0x0020_0000_0000_0000;
+        /// Link supervision timeout changed event
+        const LINK_SUPERVISION_TIMEOUT_CHANGED = 0x0080_0000_0000_0000;
+        /// Enhanced flush complete event
+        const ENHANCED_FLUSH_COMPLETE = 0x0100_0000_0000_0000;
+        /// User passkey notification event
+        const USER_PASSKEY_NOTIFICATION = 0x0400_0000_0000_0000;
+        /// Keypress notification event
+        const KEYPRESS_NOTIFICATION = 0x0800_0000_0000_0000;
+        /// Remote host supported features notification event
+        const REMOTE_HOST_SUPPORTED_FEATURES_NOTIFICATION = 0x1000_0000_0000_0000;
+        /// LE meta-events
+        const LE_META_EVENT = 0x2000_0000_0000_0000;
+    }
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// Event flags defined for the [`set_event_mask`](HostHci::set_event_mask) command.
+    #[derive(Default)]
+    pub struct EventFlags : u64 {
+        /// Inquiry complete event
+        const INQUIRY_COMPLETE = 0x0000_0000_0000_0001;
+        /// Inquiry result event
+        const INQUIRY_RESULT = 0x0000_0000_0000_0002;
+        /// Connection complete event
+        const CONNECTION_COMPLETE = 0x0000_0000_0000_0004;
+        /// Connection request event
+        const CONNECTION_REQUEST = 0x0000_0000_0000_0008;
+        /// Disconnection complete event
+        const DISCONNECTION_COMPLETE = 0x0000_0000_0000_0010;
+        /// Authentication complete event
+        const AUTHENTICATION_COMPLETE = 0x0000_0000_0000_0020;
+        /// Remote name request complete event
+        const REMOTE_NAME_REQUEST_COMPLETE = 0x0000_0000_0000_0040;
+        /// Encryption change event
+        const ENCRYPTION_CHANGE = 0x0000_0000_0000_0080;
+        /// Change connection link key complete event
+        const CHANGE_CONNECTION_LINK_KEY_COMPLETE = 0x0000_0000_0000_0100;
+        /// Master link key complete event
+        const MASTER_LINK_KEY_COMPLETE = 0x0000_0000_0000_0200;
+        /// Read remote supported features complete event
+        const READ_REMOTE_SUPPORTED_FEATURES_COMPLETE = 0x0000_0000_0000_0400;
+        /// Read remote version information complete event
+        const READ_REMOTE_VERSION_INFORMATION_COMPLETE = 0x0000_0000_0000_0800;
+        /// Qos setup complete event
+        const QOS_SETUP_COMPLETE = 0x0000_0000_0000_1000;
+        /// Hardware error event
+        const HARDWARE_ERROR = 0x0000_0000_0000_8000;
+        /// Flush occurred event
+        const FLUSH_OCCURRED = 0x0000_0000_0001_0000;
+        /// Role change event
+        const ROLE_CHANGE = 0x0000_0000_0002_0000;
+        /// Mode change event
+        const MODE_CHANGE = 0x0000_0000_0008_0000;
+        /// Return link keys event
+        const RETURN_LINK_KEYS = 0x0000_0000_0010_0000;
+        /// Pin code request event
+        const PIN_CODE_REQUEST = 0x0000_0000_0020_0000;
+        /// Link key request event
+        const LINK_KEY_REQUEST = 0x0000_0000_0040_0000;
+        /// Link key notification event
+        const LINK_KEY_NOTIFICATION = 0x0000_0000_0080_0000;
+        /// Loopback command event
+        const LOOPBACK_COMMAND = 0x0000_0000_0100_0000;
+        /// Data buffer overflow event
+        const DATA_BUFFER_OVERFLOW = 0x0000_0000_0200_0000;
+        /// Max slots change event
+        const MAX_SLOTS_CHANGE = 0x0000_0000_0400_0000;
+        /// Read clock offset complete event
+        const READ_CLOCK_OFFSET_COMPLETE = 0x0000_0000_0800_0000;
+        /// Connection packet type changed event
+        const CONNECTION_PACKET_TYPE_CHANGED = 0x0000_0000_1000_0000;
+        /// Qos violation event
+        const QOS_VIOLATION = 0x0000_0000_2000_0000;
+        /// Page scan mode change event. Deprecated in Bluetooth spec.
+        #[deprecated]
+        const PAGE_SCAN_MODE_CHANGE = 0x0000_0000_4000_0000;
+        /// Page scan repetition mode change event
+        const PAGE_SCAN_REPETITION_MODE_CHANGE = 0x0000_0000_8000_0000;
+        /// Flow specification complete event
+        const FLOW_SPECIFICATION_COMPLETE = 0x0000_0001_0000_0000;
+        /// Inquiry result with rssi event
+        const INQUIRY_RESULT_WITH_RSSI = 0x0000_0002_0000_0000;
+        /// Read remote extended features complete event
+        const READ_REMOTE_EXTENDED_FEATURES_COMPLETE = 0x0000_0004_0000_0000;
+        /// Synchronous connection complete event
+        const SYNCHRONOUS_CONNECTION_COMPLETE = 0x0000_0800_0000_0000;
+        /// Synchronous connection changed event
+        const SYNCHRONOUS_CONNECTION_CHANGED = 0x0000_1000_0000_0000;
+        /// Sniff subrating event
+        const SNIFF_SUBRATING = 0x0000_2000_0000_0000;
+        /// Extended inquiry result event
+        const EXTENDED_INQUIRY_RESULT = 0x0000_4000_0000_0000;
+        /// Encryption key refresh complete event
+        const ENCRYPTION_KEY_REFRESH_COMPLETE = 0x0000_8000_0000_0000;
+        /// Io capability request event
+        const IO_CAPABILITY_REQUEST = 0x0001_0000_0000_0000;
+        /// Io capability request reply event
+        const IO_CAPABILITY_REQUEST_REPLY = 0x0002_0000_0000_0000;
+        /// User confirmation request event
+        const USER_CONFIRMATION_REQUEST = 0x0004_0000_0000_0000;
+        /// User passkey request event
+        const USER_PASSKEY_REQUEST = 0x0008_0000_0000_0000;
+        /// Remote oob data request event
+        const REMOTE_OOB_DATA_REQUEST = 0x0010_0000_0000_0000;
+        /// Simple pairing complete event
+        const SIMPLE_PAIRING_COMPLETE = 0x0020_0000_0000_0000;
+        /// Link supervision timeout changed event
+        const LINK_SUPERVISION_TIMEOUT_CHANGED = 0x0080_0000_0000_0000;
+        /// Enhanced flush complete event
+        const ENHANCED_FLUSH_COMPLETE = 0x0100_0000_0000_0000;
+        /// User passkey notification event
+        const USER_PASSKEY_NOTIFICATION = 0x0400_0000_0000_0000;
+        /// Keypress notification event
+        const KEYPRESS_NOTIFICATION = 0x0800_0000_0000_0000;
+        /// Remote host supported features notification event
+        const REMOTE_HOST_SUPPORTED_FEATURES_NOTIFICATION = 0x1000_0000_0000_0000;
+        /// LE meta-events
+        const LE_META_EVENT = 0x2000_0000_0000_0000;
+    }
+}
+
+/// For the [`read_tx_power_level`](HostHci::read_tx_power_level) command, the allowed values for the
+/// type of power level to read.
+///
+/// See the Bluetooth spec, Vol 2, Part E, Section 7.3.35.
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TxPowerLevel {
+    /// Read Current Transmit Power Level.
+    Current = 0x00,
+    /// Read Maximum Transmit Power Level.
+    Maximum = 0x01,
+}
+
+/// For the [set_controller_to_host_flow_control](HostHci::set_controller_to_host_flow_control) command, the
+/// allowed values for flow control.
+///
+/// See Bluetooth spec. v.5.4 [Vol 4, Part E, 7.3.38].
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FlowControl {
+    /// Flow control off in direction from controller to host. `default`
+    #[default]
+    Off = 0x00,
+    /// Flow control on for HCI ACL Data Packets and off for HCI synchronous.
+    /// Data Packets in direction from Controller to Host.
+    HciAclDataOnly = 0x01,
+    /// Flow control off for HCI ACL Data Packets and on for HCI synchronous.
+    /// Data Packets in direction from Controller to Host.
+    HciSyncDataOnly = 0x02,
+    /// control on both for HCI ACL Data Packets and HCI synchronous.
+    /// Data Packets in direction from Controller to Host.
+    Both = 0x03,
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+/// Parameters for the [host_buffer_size](HostHci::host_buffer_size) commad
+///
+/// # Note:
+/// The [Host ACL Data Packet Length](HostBufferSize::acl_data_packet_length) and
+/// [Host Synchronous Data Packet Length](HostBufferSize::sync_data_packet_length) command parameters
+/// do not include the length of the HCI Data Packet header.
+///
+/// See Bluetooth spec. v.5.4 [Vol 4, Part E, 7.3.39].
+pub struct HostBufferSize {
+    /// Maximum length (in octets) of the data portion of each HCI ACL Data Packet that the host is able
+    /// to accept.
+    ///
+    /// this parameter will be used to determine the size of the L2CAP segments contained in the ACL Data
+    /// Packets, which are transferred from the Controller to the Host.
+    ///
+    /// Values:
+    /// - 251 .. 65535
+    pub acl_data_packet_length: u16,
+    /// Maximum length (in octets) of the data portion of each HCI Synchronous Data Packet that the Host
+    /// is able to accept. `NOT USED`
+    ///
+    /// This parameter is used to determine the maximum size of HCI Synchronous Data Packets. Both the Host
+    /// and the Controller shall support command and event packets, zhere the data portion (excluding header)
+    /// contained in the packet is 255 octets is size.
+    pub sync_data_packet_length: u8,
+    /// The total number of HCI ACL Data Packets that can be stored in the data buffers of the Host.
+    ///
+    /// This parameter contains the total number of HCI ACL Data Packets that can be stored in the data buffers
+    /// of the Host. The Controller will determine how the buffers are to be divided between different
+    /// [Connection Handles](ConnectionHandle).
+    pub total_acl_data_packets: u16,
+    /// Total number of HCI Synchronous Data Packets that can be stored in the data buffers of the Host. `NOT USED`
+    ///
+    /// This parameter gives the save information for HCI Synchronous Data Packets.
+    pub total_sync_data_packets: u16,
+}
+
+impl HostBufferSize {
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 7);
+
+        LittleEndian::write_u16(&mut bytes[0..], self.acl_data_packet_length);
+        bytes[2] = self.sync_data_packet_length;
+        LittleEndian::write_u16(&mut bytes[3..], self.total_acl_data_packets);
+        LittleEndian::write_u16(&mut bytes[5..], self.total_sync_data_packets);
+    }
+}
+
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// Event flags defined for the [`le_set_event_mask`](HostHci::le_set_event_mask) command.
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct LeEventFlags : u64 {
+        /// LE connection complete event
+        const CONNECTION_COMPLETE = 1 << 0;
+        /// LE advertising report event
+        const ADVERTISING_REPORT = 1 << 1;
+        /// LE connection update complete event
+        const CONNECTION_UPDATE_COMPLETE = 1 << 2;
+        /// LE read remote features complete event
+        const READ_REMOTE_FEATURES_COMPLETE = 1 << 3;
+        /// LE long term key request event
+        const LONG_TERM_KEY_REQUEST = 1 << 4;
+        /// LE remote connection parameter request event
+        const REMOTE_CONNECTION_PARAMETER_REQUEST = 1 << 5;
+        /// LE data length change event
+        const DATA_LENGTH_CHANGE = 1 << 6;
+        /// LE read local p256 public key complete event
+        const READ_LOCAL_P256_PUBLIC_KEY_COMPLETE = 1 << 7;
+        /// LE generate dhkey complete event
+        const GENERATE_DHKEY_COMPLETE = 1 << 8;
+        /// LE enhanced connection complete event
+        const ENHANCED_CONNECTION_COMPLETE = 1 << 9;
+        /// LE directed advertising report event
+        const DIRECTED_ADVERTISING_REPORT = 1 << 10;
+        /// LE phy update complete event
+        const PHY_UPDATE_COMPLETE = 1 << 11;
+        /// LE extended advertising report event
+        const EXTENDED_ADVERTISING_REPORT = 1 << 12;
+        /// LE periodic advertising sync established event
+        const PERIODIC_ADVERTISING_SYNC_ESTABLISHED = 1 << 13;
+        /// LE periodic advertising report event
+        const PERIODIC_ADVERTISING_REPORT = 1 << 14;
+        /// LE periodic advertising sync lost event
+        const PERIODIC_ADVERTISING_SYNC_LOST = 1 << 15;
+        /// LE extended scan timeout event
+        const EXTENDED_SCAN_TIMEOUT = 1 << 16;
+        /// LE extended advertising set terminated event
+        const EXTENDED_ADVERTISING_SET_TERMINATED = 1 << 17;
+        /// LE scan request received event
+        const SCAN_REQUEST_RECEIVED = 1 << 18;
+        /// LE channel selection algorithm event
+        const CHANNEL_SELECTION_ALGORITHM = 1 << 19;
+        /// LE connectionless IQ report event
+        const CONNECTIONLESS_IQ_REPORT = 1 << 20;
+        /// LE connection IQ report event
+        const CONNECTION_IQ_REPORT = 1 << 21;
+        /// LE CTE request failed event
+        const CTE_REQUEST_FAILED = 1 << 22;
+        /// LE periodic advertising sync transfer received event
+        const PERIODIC_ADVERTISING_SYNC_TRANSFER_RECEIVED = 1 << 23;
+        /// LE CIS established event
+        const CIS_ESTABLISHED = 1 << 24;
+        /// LE CIS request event
+        const CIS_REQUEST = 1 << 25;
+        /// LE create BIG complete event
+        const CREATE_BIG_COMPLETE = 1 << 26;
+        /// LE terminate BIG complete event
+        const TERMINATE_BIG_COMPLETE = 1 << 27;
+        /// LE BIG sync established event
+        const BIG_SYNC_ESTABLISHED = 1 << 28;
+        /// LE BIG sync lost event
+        const BIG_SYNC_LOST = 1 << 29;
+        /// LE path loss threshold event
+        const PATH_LOSS_THRESHOLD = 1 << 31;
+        /// LE transmit power reporting event
+        const TRANSMIT_POWER_REPORTING = 1 << 32;
+        /// LE BIGInfo advertising report event
+        const BIG_INFO_ADVERTISING_REPORT = 1 << 33;
+        /// LE subrate change event
+        const SUBRATE_CHANGE = 1 << 34;
+    }
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// Event flags defined for the [`le_set_event_mask`](HostHci::le_set_event_mask) command.
+    #[derive(Default)]
+    pub struct LeEventFlags : u64 {
+        /// LE connection complete event
+        const CONNECTION_COMPLETE = 1 << 0;
+        /// LE advertising report event
+        const ADVERTISING_REPORT = 1 << 1;
+        /// LE connection update complete event
+        const CONNECTION_UPDATE_COMPLETE = 1 << 2;
+        /// LE read remote features complete event
+        const READ_REMOTE_FEATURES_COMPLETE = 1 << 3;
+        /// LE long term key request event
+        const LONG_TERM_KEY_REQUEST = 1 << 4;
+        /// LE remote connection parameter request event
+        const REMOTE_CONNECTION_PARAMETER_REQUEST = 1 << 5;
+        /// LE data length change event
+        const DATA_LENGTH_CHANGE = 1 << 6;
+        /// LE read local p256 public key complete event
+        const READ_LOCAL_P256_PUBLIC_KEY_COMPLETE = 1 << 7;
+        /// LE generate dhkey complete event
+        const GENERATE_DHKEY_COMPLETE = 1 << 8;
+        /// LE enhanced connection complete event
+        const ENHANCED_CONNECTION_COMPLETE = 1 << 9;
+        /// LE directed advertising report event
+        const DIRECTED_ADVERTISING_REPORT = 1 << 10;
+        /// LE phy update complete event
+        const PHY_UPDATE_COMPLETE = 1 << 11;
+        /// LE extended advertising report event
+        const EXTENDED_ADVERTISING_REPORT = 1 << 12;
+        /// LE periodic advertising sync established event
+        const PERIODIC_ADVERTISING_SYNC_ESTABLISHED = 1 << 13;
+        /// LE periodic advertising report event
+        const PERIODIC_ADVERTISING_REPORT = 1 << 14;
+        /// LE periodic advertising sync lost event
+        const PERIODIC_ADVERTISING_SYNC_LOST = 1 << 15;
+        /// LE extended scan timeout event
+        const EXTENDED_SCAN_TIMEOUT = 1 << 16;
+        /// LE extended advertising set terminated event
+        const EXTENDED_ADVERTISING_SET_TERMINATED = 1 << 17;
+        /// LE scan request received event
+        const SCAN_REQUEST_RECEIVED = 1 << 18;
+        /// LE channel selection algorithm event
+        const CHANNEL_SELECTION_ALGORITHM = 1 << 19;
+        /// LE connectionless IQ report event
+        const CONNECTIONLESS_IQ_REPORT = 1 << 20;
+        /// LE connection IQ report event
+        const CONNECTION_IQ_REPORT = 1 << 21;
+        /// LE CTE request failed event
+        const CTE_REQUEST_FAILED = 1 << 22;
+        /// LE periodic advertising sync transfer received event
+        const PERIODIC_ADVERTISING_SYNC_TRANSFER_RECEIVED = 1 << 23;
+        /// LE CIS established event
+        const CIS_ESTABLISHED = 1 << 24;
+        /// LE CIS request event
+        const CIS_REQUEST = 1 << 25;
+        /// LE create BIG complete event
+        const CREATE_BIG_COMPLETE = 1 << 26;
+        /// LE terminate BIG complete event
+        const TERMINATE_BIG_COMPLETE = 1 << 27;
+        /// LE BIG sync established event
+        const BIG_SYNC_ESTABLISHED = 1 << 28;
+        /// LE BIG sync lost event
+        const BIG_SYNC_LOST = 1 << 29;
+        /// LE path loss threshold event
+        const PATH_LOSS_THRESHOLD = 1 << 31;
+        /// LE transmit power reporting event
+        const TRANSMIT_POWER_REPORTING = 1 << 32;
+        /// LE BIGInfo advertising report event
+        const BIG_INFO_ADVERTISING_REPORT = 1 << 33;
+        /// LE subrate change event
+        const SUBRATE_CHANGE = 1 << 34;
+    }
+}
+
+fn validate_random_address(bd_addr: crate::BdAddr) -> Result<(), Error> {
+    let (pop_count, bit_count) = match (bd_addr.0[5] & 0b1100_0000) >> 6 {
+        0b00 | 0b11 => (pop_count_except_top_2_bits(&bd_addr.0[0..]), 46),
+        0b10 => (pop_count_except_top_2_bits(&bd_addr.0[3..]), 22),
+        _ => return Err(Error::BadRandomAddress(bd_addr)),
+    };
+
+    if pop_count == 0 || pop_count == bit_count {
+        return Err(Error::BadRandomAddress(bd_addr));
+    }
+
+    Ok(())
+}
+
+fn pop_count_except_top_2_bits(bytes: &[u8]) -> u32 {
+    let mut pop_count = 0;
+    for byte in bytes[..bytes.len() - 1].iter() {
+        pop_count += pop_count_of(*byte);
+    }
+    pop_count += pop_count_of(bytes[bytes.len() - 1] & 0b0011_1111);
+
+    pop_count
+}
+
+fn pop_count_of(byte: u8) -> u32 {
+    byte.count_ones()
+}
+
+/// Parameters for the [`le_set_advertising_parameters`](HostHci::le_set_advertising_parameters)
+/// command.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AdvertisingParameters {
+    /// Type and allowable duration of advertising.
+    pub advertising_interval: AdvertisingInterval,
+
+    /// Indicates the type of address being used in the advertising packets.
+    ///
+    /// If this is [`PrivateFallbackPublic`](OwnAddressType) or
+    /// [`PrivateFallbackRandom`](OwnAddressType), the
+    /// [`peer_address`](AdvertisingParameters::peer_address) parameter contains the peer's identity
+    /// address and type. These parameters are used to locate the corresponding local IRK in the
+    /// resolving list; this IRK is used to generate the own address used in the advertisement.
+    pub own_address_type: OwnAddressType,
+
+    /// If directed advertising is performed, i.e. when `advertising_type` is set to
+    /// [`ConnectableDirectedHighDutyCycle`](AdvertisingType::ConnectableDirectedHighDutyCycle) or
+    /// [`ConnectableDirectedLowDutyCycle`](AdvertisingType::ConnectableDirectedLowDutyCycle), then
+    /// `peer_address` shall be valid.
+    ///
+    /// If `own_address_type` is [`PrivateFallbackPublic`](OwnAddressType) or
+    /// [`PrivateFallbackRandom`](OwnAddressType), the Controller generates
+    /// the peer's Resolvable Private Address using the peer's IRK corresponding to the peer's
+    /// Identity Address contained in `peer_address`.
+    pub peer_address: crate::BdAddrType,
+
+    /// Bit field that indicates the advertising channels that shall be used when transmitting
+    /// advertising packets. At least one channel bit shall be set in the bitfield.
+    pub advertising_channel_map: Channels,
+
+    /// This parameter shall be ignored when directed advertising is enabled.
+    pub advertising_filter_policy: AdvertisingFilterPolicy,
+}
+
+impl AdvertisingParameters {
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<(), Error> {
+        assert_eq!(bytes.len(), 15);
+
+        if self.advertising_channel_map.is_empty() {
+            return Err(Error::BadChannelMap(self.advertising_channel_map));
+        }
+
+        self.advertising_interval.copy_into_slice(&mut bytes[0..5]);
+        bytes[5] = self.own_address_type as u8;
+        self.peer_address.copy_into_slice(&mut bytes[6..13]);
+        bytes[13] = self.advertising_channel_map.bits();
+        bytes[14] = self.advertising_filter_policy as u8;
+
+        Ok(())
+    }
+}
+
+/// Indicates the type of address being used in the advertising packets.
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum OwnAddressType {
+    /// Public Device Address (default)
+    Public = 0x00,
+    /// Random Device Address
+    Random = 0x01,
+    /// Controller generates Resolvable Private Address based on the local IRK from resolving
+    /// list. If resolving list contains no matching entry, use public address.
+    PrivateFallbackPublic = 0x02,
+    /// Controller generates Resolvable Private Address based on the local IRK from resolving
+    /// list. If resolving list contains no matching entry, use random address from
+    /// [`le_set_random_address`](HostHci::le_set_random_address).
+    PrivateFallbackRandom = 0x03,
+}
+
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// The advertising channels that shall be used when transmitting advertising packets.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Channels : u8 {
+        /// Channel 37 shall be used
+        const CH_37 = 0b0000_0001;
+        /// Channel 38 shall be used
+        const CH_38 = 0b0000_0010;
+        /// Channel 39 shall be used
+        const CH_39 = 0b0000_0100;
+    }
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// The advertising channels that shall be used when transmitting advertising packets.
+    pub struct Channels : u8 {
+        /// Channel 37 shall be used
+        const CH_37 = 0b0000_0001;
+        /// Channel 38 shall be used
+        const CH_38 = 0b0000_0010;
+        /// Channel 39 shall be used
+        const CH_39 = 0b0000_0100;
+    }
+}
+
+impl Default for Channels {
+    fn default() -> Channels {
+        Channels::all()
+    }
+}
+
+/// Possible filter policies used for undirected advertising.
+///
+/// See [`AdvertisingParameters`](crate::host::AdvertisingParameters).
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AdvertisingFilterPolicy {
+    /// Process scan and connection requests from all devices (i.e., the White List is not in use)
+    /// (default).
+    AllowConnectionAndScan = 0x00,
+    /// Process connection requests from all devices and only scan requests from devices that are in
+    /// the White List.
+    AllowConnectionWhiteListScan = 0x01,
+    /// Process scan requests from all devices and only connection requests from devices that are in
+    /// the White List.
+    WhiteListConnectionAllowScan = 0x02,
+    /// Process scan and connection requests only from devices in the White List.
+    WhiteListConnectionAndScan = 0x03,
+}
+
+/// Parameters for the [`le_set_scan_parameters`](HostHci::le_set_scan_parameters) command.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ScanParameters {
+    /// The type of scan to perform
+    pub scan_type: ScanType,
+
+    /// Recommendation from the host on how frequently the controller should scan.  See the
+    /// Bluetooth spec, Vol 6, Part B, Section 4.5.3.
+    pub scan_window: ScanWindow,
+
+    /// Indicates the type of address being used in the scan request packets.
+    pub own_address_type: OwnAddressType,
+
+    /// Indicates which advertising packets to accept.
+    pub filter_policy: ScanFilterPolicy,
+}
+
+impl ScanParameters {
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 7);
+
+        bytes[0] = self.scan_type as u8;
+        self.scan_window.copy_into_slice(&mut bytes[1..5]);
+        bytes[5] = self.own_address_type as u8;
+        bytes[6] = self.filter_policy as u8;
+    }
+}
+
+/// Types of scan to perform.
+///
+/// See [`ScanParameters`] and [`le_set_scan_parameters`](HostHci::le_set_scan_parameters).
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u8)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ScanType {
+    /// Passive Scanning. No scanning PDUs shall be sent (default).
+    Passive = 0x00,
+    /// Active scanning. Scanning PDUs may be sent.
+    Active = 0x01,
+}
+
+/// Which advertising packets to accept from a scan.
+///
+/// See [`ScanParameters`] and [`le_set_scan_parameters`](HostHci::le_set_scan_parameters).
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u8)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ScanFilterPolicy {
+    /// Accept all advertising packets except directed advertising packets not addressed to this
+    /// device (default).
+    AcceptAll = 0x00,
+    /// Accept only advertising packets from devices where the advertiser’s address is in the White
+    /// List. Directed advertising packets which are not addressed to this device shall be ignored.
+    WhiteList = 0x01,
+    /// Accept all advertising packets except directed advertising packets where the initiator's
+    /// identity address does not address this device.
+    ///
+    /// Note: Directed advertising packets where the initiator's address is a resolvable private
+    /// address that cannot be resolved are also accepted.
+    AddressedToThisDevice = 0x02,
+    /// Accept all advertising packets except:
+    /// - advertising packets where the advertiser's identity address is not in the White List; and
+    /// - directed advertising packets where the initiator's identity addressdoes not address this
+    ///   device
+    ///
+    /// Note: Directed advertising packets where the initiator's address is a resolvable private
+    /// address that cannot be resolved are also accepted.
+    WhiteListAddressedToThisDevice = 0x03,
+}
+
+/// Parameters for the [`le_create_connection`](HostHci::le_create_connection`) event.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ConnectionParameters {
+    /// Recommendation from the host on how frequently the Controller should scan.
+    pub scan_window: ScanWindow,
+
+    /// Determines whether the White List is used.  If the White List is not used, `peer_address`
+    /// specifies the address type and address of the advertising device to connect to.
+    pub initiator_filter_policy: ConnectionFilterPolicy,
+
+    /// Indicates the type and value of the address used in the connectable advertisement sent by
+    /// the peer. The Host shall not use [`PublicIdentityAddress`](PeerAddrType) or
+    /// [`RandomIdentityAddress`](PeerAddrType) (both introduced in v4.2) if both the Host and the
+    /// Controller support the `le_set_privacy_mode` command (introduced in v5.0). If a Controller
+    /// that supports the LE Set Privacy Mode command receives the
+    /// [`le_create_connection`](HostHci::le_create_connection) command with `peer_address` set to
+    /// either [`PublicIdentityAddress`](PeerAddrType) or [`RandomIdentityAddress`](PeerAddrType),
+    /// it may use either device privacy mode or network privacy mode for that peer device.
+    pub peer_address: PeerAddrType,
+
+    /// The type of address being used in the connection request packets.
+    ///
+    /// If this is [`Random`](OwnAddressType::Random) and the random address for the device has not
+    /// been initialized, the Controller shall return the error code
+    /// [`Status::InvalidParameters`].
+    ///
+    /// If this is [`PrivateFallbackRemote`](OwnAddressType), `initiator_filter_policy` is
+    /// [`UseAddress`](ConnectionFilterPolicy::UseAddress), the controller's resolving list did not
+    /// contain a matching entry, and the random address for the device has not been initialized,
+    /// the Controller shall return the error code [`Status::InvalidParameters`].
+    ///
+    /// If this is set [`PrivateFallbackRandom`](`OwnAddressType`), `initiator_filter_policy` is
+    /// [`WhiteList`](ConnectionFilterPolicy::WhiteList), and the random address for the device has
+    /// not been initialized, the Controller shall return the error code
+    /// [`Status::InvalidParameters`].
+    pub own_address_type: OwnAddressType,
+
+    /// Defines the minimum and maximum allowed connection interval, latency, and supervision
+    /// timeout.
+    pub conn_interval: ConnectionInterval,
+
+    /// Informative parameters providing the Controller with the expected minimum and maximum length
+    /// of the connection events.
+    pub expected_connection_length: ExpectedConnectionLength,
+}
+
+impl ConnectionParameters {
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 25);
+
+        self.scan_window.copy_into_slice(&mut bytes[0..4]);
+        bytes[4] = self.initiator_filter_policy as u8;
+        match self.initiator_filter_policy {
+            ConnectionFilterPolicy::UseAddress => {
+                self.peer_address.copy_into_slice(&mut bytes[5..12]);
+            }
+            ConnectionFilterPolicy::WhiteList => {
+                bytes[5..12].copy_from_slice(&[0; 7]);
+            }
+        }
+        bytes[12] = self.own_address_type as u8;
+        self.conn_interval.copy_into_slice(&mut bytes[13..21]);
+        self.expected_connection_length
+            .copy_into_slice(&mut bytes[21..25]);
+    }
+}
+
+/// Possible values for the initiator filter policy in the
+/// [`le_create_connection`](HostHci::le_create_connection) command.
+#[derive(Copy, Clone, Debug)]
+#[repr(u8)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ConnectionFilterPolicy {
+    /// White List is not used to determine which advertiser to connect to.  `peer_address` shall be
+    /// used in the connection complete event.
+    UseAddress = 0x00,
+
+    /// White List is used to determine which advertiser to connect to. `peer_address` shall be
+    /// ignored in the connection complete event.
+    WhiteList = 0x01,
+}
+
+/// Possible values for the peer address in the [`le_create_connection`](HostHci::le_create_connection)
+/// command.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PeerAddrType {
+    /// Public Device Address
+    PublicDeviceAddress(crate::BdAddr),
+    /// Random Device Address
+    RandomDeviceAddress(crate::BdAddr),
+    /// Public Identity Address (Corresponds to peer's Resolvable Private Address). This value shall
+    /// only be used by the Host if either the Host or the Controller does not support the LE Set
+    /// Privacy Mode command.
+    PublicIdentityAddress(crate::BdAddr),
+    /// Random (static) Identity Address (Corresponds to peer’s Resolvable Private Address). This
+    /// value shall only be used by a Host if either the Host or the Controller does not support the
+    /// LE Set Privacy Mode command.
+    RandomIdentityAddress(crate::BdAddr),
+}
+
+impl PeerAddrType {
+    /// Serialize the peer address into the given byte buffer.
+    ///
+    /// # Panics
+    ///
+    /// `bytes` must be 7 bytes long.
+    pub fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 7);
+        match *self {
+            PeerAddrType::PublicDeviceAddress(bd_addr) => {
+                bytes[0] = 0x00;
+                bytes[1..7].copy_from_slice(&bd_addr.0);
+            }
+            PeerAddrType::RandomDeviceAddress(bd_addr) => {
+                bytes[0] = 0x01;
+                bytes[1..7].copy_from_slice(&bd_addr.0);
+            }
+            PeerAddrType::PublicIdentityAddress(bd_addr) => {
+                bytes[0] = 0x02;
+                bytes[1..7].copy_from_slice(&bd_addr.0);
+            }
+            PeerAddrType::RandomIdentityAddress(bd_addr) => {
+                bytes[0] = 0x03;
+                bytes[1..7].copy_from_slice(&bd_addr.0);
+            }
+        }
     }
+}
 
-    async fn host_buffer_size(&mut self, params: HostBufferSize) {
-        let mut bytes = [0; 6];
-        params.copy_into_slice(&mut bytes);
-        self.controller_write(crate::opcode::HOST_BUFFER_SIZE, &bytes)
-            .await;
+/// Parameters for the [`le_connection_update`](HostHci::le_connection_update) command.
+///
+/// See the Bluetooth spec, Vol 2, Part E, Section 7.8.18.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ConnectionUpdateParameters {
+    /// Handle for identifying a connection.
+    pub conn_handle: ConnectionHandle,
+
+    /// Defines the connection interval, latency, and supervision timeout.
+    pub conn_interval: ConnectionInterval,
+
+    /// Information parameters providing the Controller with a hint about the expected minimum and
+    /// maximum length of the connection events.
+    pub expected_connection_length: ExpectedConnectionLength,
+}
+
+impl ConnectionUpdateParameters {
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 14);
+
+        LittleEndian::write_u16(&mut bytes[0..], self.conn_handle.0);
+        self.conn_interval.copy_into_slice(&mut bytes[2..10]);
+        self.expected_connection_length
+            .copy_into_slice(&mut bytes[10..14]);
     }
+}
 
-    async fn number_of_completed_packets(&mut self, params: NumberOfCompletedPackets) {
-        let mut bytes = [0; NUMBER_OF_COMPLETED_PACKETS_MAX_LEN + 1];
-        bytes[0] = params.num_handles as u8;
-        bytes[1..].copy_from_slice(&params.data_buf);
-        self.controller_write(crate::opcode::NUMBER_OF_COMPLETED_PACKETS, &bytes)
-            .await;
+/// Parameters for the [`le_encrypt`](HostHci::le_encrypt) command.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AesParameters {
+    /// Key for the encryption of the data given in the command.
+    ///
+    /// The most significant (last) octet of the key corresponds to `key[0]` using the notation
+    /// specified in FIPS 197.
+    pub key: EncryptionKey,
+
+    /// Data block that is requested to be encrypted.
+    ///
+    /// The most significant (last) octet of the PlainText_Data corresponds to `in[0]` using the
+    /// notation specified in FIPS 197.
+    pub plaintext_data: PlaintextBlock,
+}
+
+/// Newtype for the encryption key.
+///
+/// See [`AesParameters`]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EncryptionKey(pub [u8; 16]);
+
+impl Debug for EncryptionKey {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "AES-128 Key ({:X?})", self.0)
+    }
+}
+
+/// Newtype for the plaintext data.
+///
+/// See [`AesParameters`].
+#[derive(Clone)]
+pub struct PlaintextBlock(pub [u8; 16]);
+
+impl Debug for PlaintextBlock {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "AES-128 Plaintext (REDACTED)")
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for PlaintextBlock {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "AES-128 Plaintext (REDACTED)")
+    }
+}
+
+/// Parameters for the [`le_start_encryption`](HostHci::le_start_encryption) command.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EncryptionParameters {
+    /// ID for the connection.
+    pub conn_handle: ConnectionHandle,
+
+    /// Random value distrubuted by the peripheral during pairing
+    pub random_number: u64,
+
+    /// Encrypted diversifier distrubuted by the peripheral during pairing
+    pub encrypted_diversifier: u16,
+
+    /// Encryption key, distributed by the host.
+    pub long_term_key: EncryptionKey,
+}
+
+/// Possible values of the `payload` parameter for the
+/// [`le_transmitter_test`](HostHci::le_transmitter_test) command.
+#[derive(Copy, Clone, Debug)]
+#[repr(u8)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TestPacketPayload {
+    /// Pseudo-Random bit sequence 9
+    PrbS9 = 0x00,
+    /// Pattern of alternating bits `11110000
+    Nibbles10 = 0x01,
+    /// Pattern of alternating bits `10101010'
+    Bits10 = 0x02,
+    /// Pseudo-Random bit sequence 15
+    PrbS15 = 0x03,
+    /// Pattern of All `1' bits
+    All1 = 0x04,
+    /// Pattern of All `0' bits
+    All0 = 0x05,
+    /// Pattern of alternating bits `00001111
+    Nibbles01 = 0x06,
+    /// Pattern of alternating bits `0101'
+    Bits01 = 0x07,
+}
+
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// PHY directions for which the Host has no preference, for the
+    /// [`le_set_default_phy`](HostHci::le_set_default_phy) and [`le_set_phy`](HostHci::le_set_phy)
+    /// commands.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct AllPhys : u8 {
+        /// The Host has no preference among the transmitter PHYs. The `tx_phys` preferences are
+        /// ignored.
+        const NO_TX_PREFERENCE = 0x01;
+        /// The Host has no preference among the receiver PHYs. The `rx_phys` preferences are
+        /// ignored.
+        const NO_RX_PREFERENCE = 0x02;
+    }
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// PHY directions for which the Host has no preference, for the
+    /// [`le_set_default_phy`](HostHci::le_set_default_phy) and [`le_set_phy`](HostHci::le_set_phy)
+    /// commands.
+    #[derive(Default)]
+    pub struct AllPhys : u8 {
+        /// The Host has no preference among the transmitter PHYs. The `tx_phys` preferences are
+        /// ignored.
+        const NO_TX_PREFERENCE = 0x01;
+        /// The Host has no preference among the receiver PHYs. The `rx_phys` preferences are
+        /// ignored.
+        const NO_RX_PREFERENCE = 0x02;
+    }
+}
+
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// Set of LE PHYs.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct PhyMask : u8 {
+        /// LE 1M PHY
+        const LE_1M = 0x01;
+        /// LE 2M PHY
+        const LE_2M = 0x02;
+        /// LE Coded PHY
+        const LE_CODED = 0x04;
+    }
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// Set of LE PHYs.
+    #[derive(Default)]
+    pub struct PhyMask : u8 {
+        /// LE 1M PHY
+        const LE_1M = 0x01;
+        /// LE 2M PHY
+        const LE_2M = 0x02;
+        /// LE Coded PHY
+        const LE_CODED = 0x04;
+    }
+}
+
+/// Preferred PHYs for the [`le_set_default_phy`](HostHci::le_set_default_phy) and
+/// [`le_set_phy`](HostHci::le_set_phy) commands.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.48.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PhyPreferences {
+    /// Directions for which the Host has no preference.
+    pub all_phys: AllPhys,
+
+    /// Transmitter PHYs that the Host prefers the Controller to use. Must not be empty unless
+    /// `all_phys` includes [`NO_TX_PREFERENCE`](AllPhys::NO_TX_PREFERENCE).
+    pub tx_phys: PhyMask,
+
+    /// Receiver PHYs that the Host prefers the Controller to use. Must not be empty unless
+    /// `all_phys` includes [`NO_RX_PREFERENCE`](AllPhys::NO_RX_PREFERENCE).
+    pub rx_phys: PhyMask,
+}
+
+impl PhyPreferences {
+    fn validate(&self) -> Result<(), Error> {
+        if !self.all_phys.contains(AllPhys::NO_TX_PREFERENCE) && self.tx_phys.is_empty() {
+            return Err(Error::NoTxPhys);
+        }
+        if !self.all_phys.contains(AllPhys::NO_RX_PREFERENCE) && self.rx_phys.is_empty() {
+            return Err(Error::NoRxPhys);
+        }
+
+        Ok(())
+    }
+
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 3);
+
+        bytes[0] = self.all_phys.bits();
+        bytes[1] = self.tx_phys.bits();
+        bytes[2] = self.rx_phys.bits();
+    }
+}
+
+/// Preferred coding when transmitting on the LE Coded PHY, for the
+/// [`le_set_phy`](HostHci::le_set_phy) command.
+#[repr(u16)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PhyOptions {
+    /// The Host has no preferred coding.
+    NoPreference = 0x0000,
+    /// The Host prefers that S=2 coding be used.
+    S2 = 0x0001,
+    /// The Host prefers that S=8 coding be used.
+    S8 = 0x0002,
+}
+
+/// Parameters for the [`le_add_device_to_resolving_list`](HostHci::le_add_device_to_resolving_list)
+/// command.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.38.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ResolvingListParameters {
+    /// Public or static random identity address of the peer device.
+    pub peer_identity_address: crate::BdAddrType,
+
+    /// Identity Resolving Key of the peer device. All zeros if the peer does not use Resolvable
+    /// Private Addresses.
+    pub peer_irk: EncryptionKey,
+
+    /// Identity Resolving Key of the local device, used to generate the local Resolvable Private
+    /// Addresses for this peer. All zeros if the local device does not use Resolvable Private
+    /// Addresses with this peer.
+    pub local_irk: EncryptionKey,
+}
+
+impl ResolvingListParameters {
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 39);
+
+        self.peer_identity_address.copy_into_slice(&mut bytes[0..7]);
+        bytes[7..23].copy_from_slice(&self.peer_irk.0);
+        bytes[23..39].copy_from_slice(&self.local_irk.0);
+    }
+}
+
+/// Privacy modes for the [`le_set_privacy_mode`](HostHci::le_set_privacy_mode) command.
+///
+/// See the Bluetooth spec, Vol 6, Part B, Section 4.7.
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PrivacyMode {
+    /// The peer is only accepted when it uses a Resolvable Private Address that resolves with its
+    /// IRK (default).
+    Network = 0x00,
+    /// The peer is also accepted when it uses its identity address, even though its IRK is known.
+    Device = 0x01,
+}
+
+/// Private keys that the [`le_generate_dhkey_v2`](HostHci::le_generate_dhkey_v2) command may use.
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PrivateKeyType {
+    /// Use the private key generated by the
+    /// [`le_read_local_p256_public_key`](HostHci::le_read_local_p256_public_key) command.
+    Generated = 0x00,
+    /// Use the debug private key defined in the Bluetooth spec, Vol 3, Part H, Section 2.3.5.6.1.
+    Debug = 0x01,
+}
+
+/// Parameters for the
+/// [`le_set_extended_advertising_parameters`](HostHci::le_set_extended_advertising_parameters)
+/// command.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.53.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ExtendedAdvertisingParameters {
+    /// Used to identify an advertising set.
+    pub adv_handle: AdvertisingHandle,
+
+    /// Type of advertising event.
+    pub adv_event_properties: AdvertisingEvent,
+
+    /// Range of the advertising interval for undirected and low duty cycle directed advertising.
+    pub adv_interval: ExtendedAdvertisingInterval,
+
+    /// Advertising channels used on the primary advertising PHY. At least one channel shall be
+    /// used.
+    pub primary_adv_channel_map: Channels,
+
+    /// Type of address used in the advertising packets.
+    pub own_address_type: OwnAddressType,
+
+    /// Address of the device to advertise to, for directed advertising. Also used to find the
+    /// local IRK in the resolving list if `own_address_type` is one of the private types.
+    pub peer_address: crate::BdAddrType,
+
+    /// Filtering of scan and connection requests.
+    pub adv_filter_policy: AdvertisingFilterPolicy,
+
+    /// Maximum power level at which the advertising packets are to be transmitted. The Controller
+    /// may choose a lower power. `None` if the Host has no preference.
+    pub adv_tx_power: Option<crate::Dbm>,
+
+    /// PHY used on the primary advertising channels. Shall be [`Le1M`](AdvertisingPhy::Le1M) or
+    /// [`LeCoded`](AdvertisingPhy::LeCoded).
+    pub primary_adv_phy: AdvertisingPhy,
+
+    /// Maximum number of advertising events that can be skipped before the `AUX_ADV_IND` is sent
+    /// on the secondary advertising channel.
+    pub secondary_adv_max_skip: u8,
+
+    /// PHY used on the secondary advertising channels.
+    pub secondary_adv_phy: AdvertisingPhy,
+
+    /// Value of the Advertising SID subfield in the ADI field of the PDU, from 0x00 to 0x0F.
+    pub adv_sid: u8,
+
+    /// Whether the Controller notifies the Host when it receives a scan request, with the
+    /// [LE Scan Request Received](crate::event::Event::LeScanRequestReceived) event.
+    pub scan_request_notification_enable: bool,
+}
+
+impl ExtendedAdvertisingParameters {
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<(), Error> {
+        assert_eq!(bytes.len(), 25);
+
+        if self.primary_adv_channel_map.is_empty() {
+            return Err(Error::BadChannelMap(self.primary_adv_channel_map));
+        }
+        if self.primary_adv_phy == AdvertisingPhy::Le2M {
+            return Err(Error::BadPrimaryAdvertisingPhy(self.primary_adv_phy));
+        }
+
+        bytes[0] = self.adv_handle.0;
+        LittleEndian::write_u16(&mut bytes[1..], self.adv_event_properties.bits());
+        self.adv_interval.copy_into_slice_u24(&mut bytes[3..9]);
+        bytes[9] = self.primary_adv_channel_map.bits();
+        bytes[10] = self.own_address_type as u8;
+        self.peer_address.copy_into_slice(&mut bytes[11..18]);
+        bytes[18] = self.adv_filter_policy as u8;
+        bytes[19] = i8::cast_unsigned(self.adv_tx_power.map_or(0x7F, |power| power.0));
+        bytes[20] = self.primary_adv_phy as u8;
+        bytes[21] = self.secondary_adv_max_skip;
+        bytes[22] = self.secondary_adv_phy as u8;
+        bytes[23] = self.adv_sid;
+        bytes[24] = self.scan_request_notification_enable as u8;
+
+        Ok(())
+    }
+}
+
+/// Parameters for the [`le_set_extended_advertising_data`](HostHci::le_set_extended_advertising_data)
+/// and [`le_set_extended_scan_response_data`](HostHci::le_set_extended_scan_response_data)
+/// commands.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ExtendedAdvertisingData<'a> {
+    /// Used to identify an advertising set.
+    pub adv_handle: AdvertisingHandle,
+
+    /// Which part of the complete data the fragment is.
+    pub operation: AdvertisingOperation,
+
+    /// Fragment preference. If `true`, the Controller may fragment all data, otherwise it should
+    /// not fragment or should minimize fragmentation of the data.
+    pub fragment: bool,
+
+    /// Fragment of the data, formatted as defined in the Bluetooth spec, Vol 3, Part C, Section
+    /// 11. At most 251 bytes.
+    pub data: &'a [u8],
+}
+
+impl ExtendedAdvertisingData<'_> {
+    const MAX_DATA_LENGTH: usize = 251;
+    const MAX_LENGTH: usize = 4 + Self::MAX_DATA_LENGTH;
+
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<usize, Error> {
+        assert!(bytes.len() >= Self::MAX_LENGTH);
+
+        if self.data.len() > Self::MAX_DATA_LENGTH {
+            return Err(Error::ExtendedAdvertisingDataTooLong(self.data.len()));
+        }
+
+        bytes[0] = self.adv_handle.0;
+        bytes[1] = self.operation as u8;
+        bytes[2] = (!self.fragment) as u8;
+        bytes[3] = self.data.len() as u8;
+        bytes[4..4 + self.data.len()].copy_from_slice(self.data);
+
+        Ok(4 + self.data.len())
     }
+}
+
+/// Scan parameters used on one PHY by the
+/// [`le_set_extended_scan_parameters`](HostHci::le_set_extended_scan_parameters) command.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PhyScanParameters {
+    /// The type of scan to perform.
+    pub scan_type: ScanType,
+
+    /// Recommendation from the host on how frequently the controller should scan. See the
+    /// Bluetooth spec, Vol 6, Part B, Section 4.4.3.
+    pub scan_window: ScanWindow,
+}
 
-    async fn read_local_version_information(&mut self) {
-        self.controller_write(crate::opcode::READ_LOCAL_VERSION_INFO, &[])
-            .await;
-    }
+impl PhyScanParameters {
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 5);
 
-    async fn read_local_supported_commands(&mut self) {
-        self.controller_write(crate::opcode::READ_LOCAL_SUPPORTED_COMMANDS, &[])
-            .await;
+        bytes[0] = self.scan_type as u8;
+        self.scan_window.copy_into_slice(&mut bytes[1..5]);
     }
+}
 
-    async fn read_local_supported_features(&mut self) {
-        self.controller_write(crate::opcode::READ_LOCAL_SUPPORTED_FEATURES, &[])
-            .await;
-    }
+/// Parameters for the [`le_set_extended_scan_parameters`](HostHci::le_set_extended_scan_parameters)
+/// command.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.64.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ExtendedScanParameters {
+    /// Indicates the type of address being used in the scan request packets.
+    pub own_address_type: OwnAddressType,
 
-    async fn read_bd_addr(&mut self) {
-        self.controller_write(crate::opcode::READ_BD_ADDR, &[])
-            .await;
-    }
+    /// Indicates which advertising packets to accept.
+    pub filter_policy: ScanFilterPolicy,
 
-    async fn read_rssi(&mut self, conn_handle: ConnectionHandle) {
-        let mut params = [0; 2];
-        LittleEndian::write_u16(&mut params, conn_handle.0);
-        self.controller_write(crate::opcode::READ_RSSI, &params)
-            .await;
-    }
+    /// Parameters for scanning on the LE 1M PHY. `None` to not scan on the LE 1M PHY.
+    pub le_1m_phy: Option<PhyScanParameters>,
 
-    async fn le_set_event_mask(&mut self, event_mask: LeEventFlags) {
-        let mut params = [0; 8];
-        LittleEndian::write_u64(&mut params, event_mask.bits());
+    /// Parameters for scanning on the LE Coded PHY. `None` to not scan on the LE Coded PHY.
+    pub le_coded_phy: Option<PhyScanParameters>,
+}
 
-        self.controller_write(crate::opcode::LE_SET_EVENT_MASK, &params)
-            .await;
-    }
+impl ExtendedScanParameters {
+    const MAX_LENGTH: usize = 3 + 2 * 5;
+
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<usize, Error> {
+        assert!(bytes.len() >= Self::MAX_LENGTH);
+
+        let mut phys = PhyMask::empty();
+        let mut len = 3;
+        for (phy, params) in [
+            (PhyMask::LE_1M, &self.le_1m_phy),
+            (PhyMask::LE_CODED, &self.le_coded_phy),
+        ] {
+            if let Some(params) = params {
+                phys |= phy;
+                params.copy_into_slice(&mut bytes[len..len + 5]);
+                len += 5;
+            }
+        }
+        if phys.is_empty() {
+            return Err(Error::NoScanningPhys);
+        }
 
-    async fn le_read_buffer_size(&mut self) {
-        self.controller_write(crate::opcode::LE_READ_BUFFER_SIZE, &[])
-            .await;
-    }
+        bytes[0] = self.own_address_type as u8;
+        bytes[1] = self.filter_policy as u8;
+        bytes[2] = phys.bits();
 
-    async fn le_read_local_supported_features(&mut self) {
-        self.controller_write(crate::opcode::LE_READ_LOCAL_SUPPORTED_FEATURES, &[])
-            .await;
+        Ok(len)
     }
+}
 
-    async fn le_set_random_address(&mut self, bd_addr: crate::BdAddr) -> Result<(), Error> {
-        validate_random_address(bd_addr)?;
-        self.controller_write(crate::opcode::LE_SET_RANDOM_ADDRESS, &bd_addr.0)
-            .await;
+/// Duplicate filtering for the [`le_set_extended_scan_enable`](HostHci::le_set_extended_scan_enable)
+/// command.
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FilterDuplicates {
+    /// Every advertising packet received is reported.
+    Disabled = 0x00,
+    /// Duplicate advertising reports are filtered out until scanning is disabled.
+    Enabled = 0x01,
+    /// Duplicate advertising reports are filtered out, and the filter is reset at the beginning of
+    /// each scan period.
+    ResetEachPeriod = 0x02,
+}
 
-        Ok(())
-    }
+/// Parameters for the [`le_set_extended_scan_enable`](HostHci::le_set_extended_scan_enable)
+/// command.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.65.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ExtendedScanEnable {
+    /// Whether scanning is enabled.
+    pub enable: bool,
 
-    async fn le_set_advertising_parameters(
-        &mut self,
-        params: &AdvertisingParameters,
-    ) -> Result<(), Error> {
-        let mut bytes = [0; 15];
-        params.copy_into_slice(&mut bytes)?;
-        self.controller_write(crate::opcode::LE_SET_ADVERTISING_PARAMETERS, &bytes)
-            .await;
+    /// Whether the controller should filter out duplicate advertising reports.
+    pub filter_duplicates: FilterDuplicates,
 
-        Ok(())
-    }
+    /// How long to scan for in each period, with a resolution of 10 ms. Zero to scan until
+    /// scanning is disabled.
+    pub duration: Duration,
 
-    async fn le_read_advertising_channel_tx_power(&mut self) {
-        self.controller_write(crate::opcode::LE_READ_ADVERTISING_CHANNEL_TX_POWER, &[])
-            .await;
-    }
+    /// Time from the start of one scan to the start of the next, with a resolution of 1.28
+    /// seconds. Zero to scan only once.
+    pub period: Duration,
+}
 
-    async fn le_set_advertising_data(&mut self, data: &[u8]) -> Result<(), Error> {
-        set_outbound_data::<T>(self, crate::opcode::LE_SET_ADVERTISING_DATA, data).await
-    }
+impl ExtendedScanEnable {
+    const DURATION_UNIT: Duration = Duration::from_millis(10);
+    const PERIOD_UNIT: Duration = Duration::from_millis(1280);
 
-    async fn le_set_scan_response_data(&mut self, data: &[u8]) -> Result<(), Error> {
-        set_outbound_data::<T>(self, crate::opcode::LE_SET_SCAN_RESPONSE_DATA, data).await
-    }
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<(), Error> {
+        assert_eq!(bytes.len(), 6);
 
-    async fn le_set_advertising_enable(&mut self, enable: bool) {
-        self.controller_write(crate::opcode::LE_SET_ADVERTISE_ENABLE, &[enable as u8])
-            .await
-    }
+        let duration = to_units(self.duration, Self::DURATION_UNIT)
+            .ok_or(Error::BadScanDuration(self.duration))?;
+        let period =
+            to_units(self.period, Self::PERIOD_UNIT).ok_or(Error::BadScanPeriod(self.period))?;
+        if duration != 0 && period != 0 && self.duration >= self.period {
+            return Err(Error::BadScanPeriod(self.period));
+        }
 
-    async fn le_set_scan_parameters(&mut self, params: &ScanParameters) {
-        let mut bytes = [0; 7];
-        params.copy_into_slice(&mut bytes);
-        self.controller_write(crate::opcode::LE_SET_SCAN_PARAMETERS, &bytes)
-            .await;
-    }
+        bytes[0] = self.enable as u8;
+        bytes[1] = self.filter_duplicates as u8;
+        LittleEndian::write_u16(&mut bytes[2..], duration);
+        LittleEndian::write_u16(&mut bytes[4..], period);
 
-    async fn le_set_scan_enable(&mut self, enable: bool, filter_duplicates: bool) {
-        self.controller_write(
-            crate::opcode::LE_SET_SCAN_ENABLE,
-            &[enable as u8, filter_duplicates as u8],
-        )
-        .await;
+        Ok(())
     }
+}
 
-    async fn le_create_connection(&mut self, params: &ConnectionParameters) {
-        let mut bytes = [0; 25];
-        params.copy_into_slice(&mut bytes);
-        self.controller_write(crate::opcode::LE_CREATE_CONNECTION, &bytes)
-            .await;
+// Converts a duration to a number of units for a 2-byte parameter where 0 has a special meaning.
+// Returns None if a non-zero duration rounds to 0 units, or does not fit in 2 bytes.
+fn to_units(duration: Duration, unit: Duration) -> Option<u16> {
+    let units = duration.as_micros() / unit.as_micros();
+    if units == 0 && !duration.is_zero() {
+        return None;
     }
 
-    async fn le_create_connection_cancel(&mut self) {
-        self.controller_write(crate::opcode::LE_CREATE_CONNECTION_CANCEL, &[])
-            .await;
-    }
+    units.try_into().ok()
+}
 
-    async fn le_read_white_list_size(&mut self) {
-        self.controller_write(crate::opcode::LE_READ_WHITE_LIST_SIZE, &[])
-            .await;
-    }
+/// Connection parameters used on one PHY by the
+/// [`le_extended_create_connection`](HostHci::le_extended_create_connection) command.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PhyConnectionParameters {
+    /// Recommendation from the host on how frequently the Controller should scan. Ignored for the
+    /// LE 2M PHY, on which advertisements are not received.
+    pub scan_window: ScanWindow,
 
-    async fn le_clear_white_list(&mut self) {
-        self.controller_write(crate::opcode::LE_CLEAR_WHITE_LIST, &[])
-            .await;
-    }
+    /// Defines the minimum and maximum allowed connection interval, latency, and supervision
+    /// timeout.
+    pub conn_interval: ConnectionInterval,
 
-    async fn le_add_device_to_white_list(&mut self, addr: crate::BdAddrType) {
-        let mut params = [0; 7];
-        addr.copy_into_slice(&mut params);
-        self.controller_write(crate::opcode::LE_ADD_DEVICE_TO_WHITE_LIST, &params)
-            .await;
-    }
+    /// Informative parameters providing the Controller with the expected minimum and maximum length
+    /// of the connection events.
+    pub expected_connection_length: ExpectedConnectionLength,
+}
 
-    async fn le_add_anon_advertising_devices_to_white_list(&mut self) {
-        self.controller_write(
-            crate::opcode::LE_ADD_DEVICE_TO_WHITE_LIST,
-            &[0xFF, 0, 0, 0, 0, 0, 0],
-        )
-        .await;
-    }
+impl PhyConnectionParameters {
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), 16);
 
-    async fn le_remove_device_from_white_list(&mut self, addr: crate::BdAddrType) {
-        let mut params = [0; 7];
-        addr.copy_into_slice(&mut params);
-        self.controller_write(crate::opcode::LE_REMOVE_DEVICE_FROM_WHITE_LIST, &params)
-            .await;
+        self.scan_window.copy_into_slice(&mut bytes[0..4]);
+        self.conn_interval.copy_into_slice(&mut bytes[4..12]);
+        self.expected_connection_length
+            .copy_into_slice(&mut bytes[12..16]);
     }
+}
 
-    async fn le_remove_anon_advertising_devices_from_white_list(&mut self) {
-        self.controller_write(
-            crate::opcode::LE_REMOVE_DEVICE_FROM_WHITE_LIST,
-            &[0xFF, 0, 0, 0, 0, 0, 0],
-        )
-        .await;
-    }
+/// Parameters for the [`le_extended_create_connection`](HostHci::le_extended_create_connection)
+/// command.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.66.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ExtendedConnectionParameters {
+    /// Determines whether the White List is used. If the White List is not used, `peer_address`
+    /// specifies the address type and address of the advertising device to connect to.
+    pub initiator_filter_policy: ConnectionFilterPolicy,
 
-    async fn le_connection_update(&mut self, params: &ConnectionUpdateParameters) {
-        let mut bytes = [0; 14];
-        params.copy_into_slice(&mut bytes);
-        self.controller_write(crate::opcode::LE_CONNECTION_UPDATE, &bytes)
-            .await;
-    }
+    /// The type of address being used in the connection request packets. See
+    /// [`ConnectionParameters::own_address_type`].
+    pub own_address_type: OwnAddressType,
 
-    async fn le_set_host_channel_classification(
-        &mut self,
-        channels: crate::ChannelClassification,
-    ) -> Result<(), Error> {
-        if channels.is_empty() {
-            return Err(Error::NoValidChannel);
-        }
+    /// Indicates the type and value of the address used in the connectable advertisement sent by
+    /// the peer. See [`ConnectionParameters::peer_address`].
+    pub peer_address: PeerAddrType,
 
-        let mut bytes = [0; 5];
-        channels.copy_into_slice(&mut bytes);
-        self.controller_write(crate::opcode::LE_SET_HOST_CHANNEL_CLASSIFICATION, &bytes)
-            .await;
+    /// Parameters for connecting on the LE 1M PHY. `None` to not scan for connectable
+    /// advertisements, nor connect, on the LE 1M PHY.
+    pub le_1m_phy: Option<PhyConnectionParameters>,
 
-        Ok(())
-    }
+    /// Connection parameters used if the connection is established on the LE 2M PHY. `None` to
+    /// not connect on the LE 2M PHY.
+    pub le_2m_phy: Option<PhyConnectionParameters>,
 
-    async fn le_read_channel_map(&mut self, conn_handle: ConnectionHandle) {
-        let mut bytes = [0; 2];
-        LittleEndian::write_u16(&mut bytes, conn_handle.0);
-        self.controller_write(crate::opcode::LE_READ_CHANNEL_MAP, &bytes)
-            .await;
-    }
+    /// Parameters for connecting on the LE Coded PHY. `None` to not scan for connectable
+    /// advertisements, nor connect, on the LE Coded PHY.
+    pub le_coded_phy: Option<PhyConnectionParameters>,
+}
 
-    async fn le_read_remote_used_features(&mut self, conn_handle: ConnectionHandle) {
-        let mut bytes = [0; 2];
-        LittleEndian::write_u16(&mut bytes, conn_handle.0);
-        self.controller_write(crate::opcode::LE_READ_REMOTE_USED_FEATURES, &bytes)
-            .await;
-    }
+impl ExtendedConnectionParameters {
+    const MAX_LENGTH: usize = 10 + 3 * 16;
+
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<usize, Error> {
+        assert!(bytes.len() >= Self::MAX_LENGTH);
+
+        let mut phys = PhyMask::empty();
+        let mut len = 10;
+        for (phy, params) in [
+            (PhyMask::LE_1M, &self.le_1m_phy),
+            (PhyMask::LE_2M, &self.le_2m_phy),
+            (PhyMask::LE_CODED, &self.le_coded_phy),
+        ] {
+            if let Some(params) = params {
+                phys |= phy;
+                params.copy_into_slice(&mut bytes[len..len + 16]);
+                len += 16;
+            }
+        }
+        if phys.is_empty() {
+            return Err(Error::NoInitiatingPhys);
+        }
 
-    async fn le_encrypt(&mut self, params: &AesParameters) {
-        let mut bytes = [0; 32];
-        bytes[..16].copy_from_slice(&params.key.0);
-        bytes[16..].copy_from_slice(&params.plaintext_data.0);
-        self.controller_write(crate::opcode::LE_ENCRYPT, &bytes)
-            .await;
-    }
+        bytes[0] = self.initiator_filter_policy as u8;
+        bytes[1] = self.own_address_type as u8;
+        match self.initiator_filter_policy {
+            ConnectionFilterPolicy::UseAddress => {
+                self.peer_address.copy_into_slice(&mut bytes[2..9]);
+            }
+            ConnectionFilterPolicy::WhiteList => {
+                bytes[2..9].copy_from_slice(&[0; 7]);
+            }
+        }
+        bytes[9] = phys.bits();
 
-    async fn le_rand(&mut self) {
-        self.controller_write(crate::opcode::LE_RAND, &[]).await;
+        Ok(len)
     }
+}
 
-    async fn le_start_encryption(&mut self, params: &EncryptionParameters) {
-        let mut bytes = [0; 28];
-        LittleEndian::write_u16(&mut bytes[0..], params.conn_handle.0);
-        LittleEndian::write_u64(&mut bytes[2..], params.random_number);
-        LittleEndian::write_u16(&mut bytes[10..], params.encrypted_diversifier);
-        bytes[12..].copy_from_slice(&params.long_term_key.0);
-        self.controller_write(crate::opcode::LE_START_ENCRYPTION, &bytes)
-            .await;
-    }
+/// Parameters for the
+/// [`le_set_periodic_advertising_parameters`](HostHci::le_set_periodic_advertising_parameters)
+/// command.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.61.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PeriodicAdvertisingParameters {
+    /// Advertising set in which periodic advertising is done.
+    pub adv_handle: AdvertisingHandle,
 
-    async fn le_long_term_key_request_reply(
-        &mut self,
-        conn_handle: ConnectionHandle,
-        key: &EncryptionKey,
-    ) {
-        let mut bytes = [0; 18];
-        LittleEndian::write_u16(&mut bytes[0..], conn_handle.0);
-        bytes[2..].copy_from_slice(&key.0);
-        self.controller_write(crate::opcode::LE_LTK_REQUEST_REPLY, &bytes)
-            .await;
-    }
+    /// Minimum periodic advertising interval, with a resolution of 1.25 ms. Shall be at least 7.5
+    /// ms.
+    pub interval_min: Duration,
 
-    async fn le_long_term_key_request_negative_reply(&mut self, conn_handle: ConnectionHandle) {
-        let mut bytes = [0; 2];
-        LittleEndian::write_u16(&mut bytes[0..], conn_handle.0);
-        self.controller_write(crate::opcode::LE_LTK_REQUEST_NEGATIVE_REPLY, &bytes)
-            .await;
-    }
+    /// Maximum periodic advertising interval, with a resolution of 1.25 ms. Shall be at most
+    /// 81.91875 s.
+    pub interval_max: Duration,
+
+    /// Whether the TxPower is included in the advertising PDUs.
+    pub include_tx_power: bool,
+}
 
-    async fn le_read_supported_states(&mut self) {
-        self.controller_write(crate::opcode::LE_READ_STATES, &[])
-            .await;
-    }
+impl PeriodicAdvertisingParameters {
+    const INTERVAL_UNIT: Duration = Duration::from_micros(1250);
+    const MIN_INTERVAL: Duration = Duration::from_micros(7500);
+    pub(crate) const INCLUDE_TX_POWER: u16 = 1 << 6;
 
-    async fn le_receiver_test(&mut self, channel: u8) -> Result<(), Error> {
-        if channel > MAX_TEST_CHANNEL {
-            return Err(Error::InvalidTestChannel(channel));
-        }
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<(), Error> {
+        assert_eq!(bytes.len(), 7);
 
-        self.controller_write(crate::opcode::LE_RECEIVER_TEST, &[channel])
-            .await;
+        let bad_interval =
+            Error::BadPeriodicAdvertisingInterval(self.interval_min, self.interval_max);
+        if self.interval_min < Self::MIN_INTERVAL || self.interval_min > self.interval_max {
+            return Err(bad_interval);
+        }
+        let interval_min = to_units(self.interval_min, Self::INTERVAL_UNIT).ok_or(bad_interval)?;
+        let interval_max = to_units(self.interval_max, Self::INTERVAL_UNIT).ok_or(bad_interval)?;
+
+        bytes[0] = self.adv_handle.0;
+        LittleEndian::write_u16(&mut bytes[1..], interval_min);
+        LittleEndian::write_u16(&mut bytes[3..], interval_max);
+        LittleEndian::write_u16(
+            &mut bytes[5..],
+            if self.include_tx_power {
+                Self::INCLUDE_TX_POWER
+            } else {
+                0
+            },
+        );
 
         Ok(())
     }
+}
 
-    async fn le_transmitter_test(
-        &mut self,
-        channel: u8,
-        payload_length: usize,
-        payload: TestPacketPayload,
-    ) -> Result<(), Error> {
-        if channel > MAX_TEST_CHANNEL {
-            return Err(Error::InvalidTestChannel(channel));
-        }
+/// Parameters for the [`le_set_periodic_advertising_data`](HostHci::le_set_periodic_advertising_data)
+/// command.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.62.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PeriodicAdvertisingData<'a> {
+    /// Advertising set in which periodic advertising is done.
+    pub adv_handle: AdvertisingHandle,
 
-        const MAX_PAYLOAD_LENGTH: usize = 0x25;
-        if payload_length > MAX_PAYLOAD_LENGTH {
-            return Err(Error::InvalidTestPayloadLength(payload_length));
-        }
+    /// Which part of the complete data the fragment is.
+    pub operation: AdvertisingOperation,
 
-        self.controller_write(
-            crate::opcode::LE_TRANSMITTER_TEST,
-            &[channel, payload_length as u8, payload as u8],
-        )
-        .await;
+    /// Fragment of the data, formatted as defined in the Bluetooth spec, Vol 3, Part C, Section
+    /// 11. At most 252 bytes.
+    pub data: &'a [u8],
+}
 
-        Ok(())
-    }
+impl PeriodicAdvertisingData<'_> {
+    const MAX_DATA_LENGTH: usize = 252;
+    const MAX_LENGTH: usize = 3 + Self::MAX_DATA_LENGTH;
 
-    async fn le_test_end(&mut self) {
-        self.controller_write(crate::opcode::LE_TEST_END, &[]).await;
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<usize, Error> {
+        assert!(bytes.len() >= Self::MAX_LENGTH);
+
+        if self.data.len() > Self::MAX_DATA_LENGTH {
+            return Err(Error::PeriodicAdvertisingDataTooLong(self.data.len()));
+        }
+
+        bytes[0] = self.adv_handle.0;
+        bytes[1] = self.operation as u8;
+        bytes[2] = self.data.len() as u8;
+        bytes[3..3 + self.data.len()].copy_from_slice(self.data);
+
+        Ok(3 + self.data.len())
     }
 }
 
-const MAX_TEST_CHANNEL: u8 = 0x27;
-
 #[cfg(not(feature = "defmt"))]
 bitflags::bitflags! {
-    /// Event flags defined for the [`set_event_mask`](HostHci::set_event_mask) command.
-    #[derive(Default)]
-    pub struct EventFlags : u64 {
-        /// Inquiry complete event
-        const INQUIRY_COMPLETE = 0x0000_0000_0000_0001;
-        /// Inquiry result event
-        const INQUIRY_RESULT = 0x0000_0000_0000_0002;
-        /// Connection complete event
-        const CONNECTION_COMPLETE = 0x0000_0000_0000_0004;
-        /// Connection request event
-        const CONNECTION_REQUEST = 0x0000_0000_0000_0008;
-        /// Disconnection complete event
-        const DISCONNECTION_COMPLETE = 0x0000_0000_0000_0010;
-        /// Authentication complete event
-        const AUTHENTICATION_COMPLETE = 0x0000_0000_0000_0020;
-        /// Remote name request complete event
-        const REMOTE_NAME_REQUEST_COMPLETE = 0x0000_0000_0000_0040;
-        /// Encryption change event
-        const ENCRYPTION_CHANGE = 0x0000_0000_0000_0080;
-        /// Change connection link key complete event
-        const CHANGE_CONNECTION_LINK_KEY_COMPLETE = 0x0000_0000_0000_0100;
-        /// Master link key complete event
-        const MASTER_LINK_KEY_COMPLETE = 0x0000_0000_0000_0200;
-        /// Read remote supported features complete event
-        const READ_REMOTE_SUPPORTED_FEATURES_COMPLETE = 0x0000_0000_0000_0400;
-        /// Read remote version information complete event
-        const READ_REMOTE_VERSION_INFORMATION_COMPLETE = 0x0000_0000_0000_0800;
-        /// Qos setup complete event
-        const QOS_SETUP_COMPLETE = 0x0000_0000_0000_1000;
-        /// Hardware error event
-        const HARDWARE_ERROR = 0x0000_0000_0000_8000;
-        /// Flush occurred event
-        const FLUSH_OCCURRED = 0x0000_0000_0001_0000;
-        /// Role change event
-        const ROLE_CHANGE = 0x0000_0000_0002_0000;
-        /// Mode change event
-        const MODE_CHANGE = 0x0000_0000_0008_0000;
-        /// Return link keys event
-        const RETURN_LINK_KEYS = 0x0000_0000_0010_0000;
-        /// Pin code request event
-        const PIN_CODE_REQUEST = 0x0000_0000_0020_0000;
-        /// Link key request event
-        const LINK_KEY_REQUEST = 0x0000_0000_0040_0000;
-        /// Link key notification event
-        const LINK_KEY_NOTIFICATION = 0x0000_0000_0080_0000;
-        /// Loopback command event
-        const LOOPBACK_COMMAND = 0x0000_0000_0100_0000;
-        /// Data buffer overflow event
-        const DATA_BUFFER_OVERFLOW = 0x0000_0000_0200_0000;
-        /// Max slots change event
-        const MAX_SLOTS_CHANGE = 0x0000_0000_0400_0000;
-        /// Read clock offset complete event
-        const READ_CLOCK_OFFSET_COMPLETE = 0x0000_0000_0800_0000;
-        /// Connection packet type changed event
-        const CONNECTION_PACKET_TYPE_CHANGED = 0x0000_0000_1000_0000;
-        /// Qos violation event
-        const QOS_VIOLATION = 0x0000_0000_2000_0000;
-        /// Page scan mode change event. Deprecated in Bluetooth spec.
-        #[deprecated]
-        const PAGE_SCAN_MODE_CHANGE = 0x0000_0000_4000_0000;
-        /// Page scan repetition mode change event
-        const PAGE_SCAN_REPETITION_MODE_CHANGE = 0x0000_0000_8000_0000;
-        /// Flow specification complete event
-        const FLOW_SPECIFICATION_COMPLETE = 0x0000_0001_0000_0000;
-        /// Inquiry result with rssi event
-        const INQUIRY_RESULT_WITH_RSSI = 0x0000_0002_0000_0000;
-        /// Read remote extended features complete event
-        const READ_REMOTE_EXTENDED_FEATURES_COMPLETE = 0x0000_0004_0000_0000;
-        /// Synchronous connection complete event
-        const SYNCHRONOUS_CONNECTION_COMPLETE = 0x0000_0800_0000_0000;
-        /// Synchronous connection changed event
-        const SYNCHRONOUS_CONNECTION_CHANGED = 0x0000_1000_0000_0000;
-        /// Sniff subrating event
-        const SNIFF_SUBRATING = 0x0000_2000_0000_0000;
-        /// Extended inquiry result event
-        const EXTENDED_INQUIRY_RESULT = 0x0000_4000_0000_0000;
-        /// Encryption key refresh complete event
-        const ENCRYPTION_KEY_REFRESH_COMPLETE = 0x0000_8000_0000_0000;
-        /// Io capability request event
-        const IO_CAPABILITY_REQUEST = 0x0001_0000_0000_0000;
-        /// Io capability request reply event
-        const IO_CAPABILITY_REQUEST_REPLY = 0x0002_0000_0000_0000;
-        /// User confirmation request event
-        const USER_CONFIRMATION_REQUEST = 0x0004_0000_0000_0000;
-        /// User passkey request event
-        const USER_PASSKEY_REQUEST = 0x0008_0000_0000_0000;
-        /// Remote oob data request event
-        const REMOTE_OOB_DATA_REQUEST = 0x0010_0000_0000_0000;
-        /// Simple pairing complete event
-        const SIMPLE_PAIRING_COMPLETE = 0x0020_0000_0000_0000;
-        /// Link supervision timeout changed event
-        const LINK_SUPERVISION_TIMEOUT_CHANGED = 0x0080_0000_0000_0000;
-        /// Enhanced flush complete event
-        const ENHANCED_FLUSH_COMPLETE = 0x0100_0000_0000_0000;
-        /// User passkey notification event
-        const USER_PASSKEY_NOTIFICATION = 0x0400_0000_0000_0000;
-        /// Keypress notification event
-        const KEYPRESS_NOTIFICATION = 0x0800_0000_0000_0000;
-        /// Remote host supported features notification event
-        const REMOTE_HOST_SUPPORTED_FEATURES_NOTIFICATION = 0x1000_0000_0000_0000;
-        /// LE meta-events
-        const LE_META_EVENT = 0x2000_0000_0000_0000;
+    /// Options for the
+    /// [`le_periodic_advertising_create_sync`](HostHci::le_periodic_advertising_create_sync)
+    /// command.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct SyncOptions : u8 {
+        /// Use the periodic advertiser list to find the advertiser to synchronize with, instead
+        /// of the advertiser given in the parameters.
+        const USE_PERIODIC_ADVERTISER_LIST = 0x01;
+        /// Disable the [LE Periodic Advertising Reports](crate::event::Event::LePeriodicAdvertisingReport)
+        /// when synchronization is established.
+        const REPORTING_INITIALLY_DISABLED = 0x02;
+        /// Enable duplicate filtering of the reports when synchronization is established.
+        const DUPLICATE_FILTERING_INITIALLY_ENABLED = 0x04;
     }
-}
-
-#[cfg(feature = "defmt")]
-defmt::bitflags! {
-    /// Event flags defined for the [`set_event_mask`](HostHci::set_event_mask) command.
-    #[derive(Default)]
-    pub struct EventFlags : u64 {
-        /// Inquiry complete event
-        const INQUIRY_COMPLETE = 0x0000_0000_0000_0001;
-        /// Inquiry result event
-        const INQUIRY_RESULT = 0x0000_0000_0000_0002;
-        /// Connection complete event
-        const CONNECTION_COMPLETE = 0x0000_0000_0000_0004;
-        /// Connection request event
-        const CONNECTION_REQUEST = 0x0000_0000_0000_0008;
-        /// Disconnection complete event
-        const DISCONNECTION_COMPLETE = 0x0000_0000_0000_0010;
-        /// Authentication complete event
-        const AUTHENTICATION_COMPLETE = 0x0000_0000_0000_0020;
-        /// Remote name request complete event
-        const REMOTE_NAME_REQUEST_COMPLETE = 0x0000_0000_0000_0040;
-        /// Encryption change event
-        const ENCRYPTION_CHANGE = 0x0000_0000_0000_0080;
-        /// Change connection link key complete event
-        const CHANGE_CONNECTION_LINK_KEY_COMPLETE = 0x0000_0000_0000_0100;
-        /// Master link key complete event
-        const MASTER_LINK_KEY_COMPLETE = 0x0000_0000_0000_0200;
-        /// Read remote supported features complete event
-        const READ_REMOTE_SUPPORTED_FEATURES_COMPLETE = 0x0000_0000_0000_0400;
-        /// Read remote version information complete event
-        const READ_REMOTE_VERSION_INFORMATION_COMPLETE = 0x0000_0000_0000_0800;
-        /// Qos setup complete event
-        const QOS_SETUP_COMPLETE = 0x0000_0000_0000_1000;
-        /// Hardware error event
-        const HARDWARE_ERROR = 0x0000_0000_0000_8000;
-        /// Flush occurred event
-        const FLUSH_OCCURRED = 0x0000_0000_0001_0000;
-        /// Role change event
-        const ROLE_CHANGE = 0x0000_0000_0002_0000;
-        /// Mode change event
-        const MODE_CHANGE = 0x0000_0000_0008_0000;
-        /// Return link keys event
-        const RETURN_LINK_KEYS = 0x0000_0000_0010_0000;
-        /// Pin code request event
-        const PIN_CODE_REQUEST = 0x0000_0000_0020_0000;
-        /// Link key request event
-        const LINK_KEY_REQUEST = 0x0000_0000_0040_0000;
-        /// Link key notification event
-        const LINK_KEY_NOTIFICATION = 0x0000_0000_0080_0000;
-        /// Loopback command event
-        const LOOPBACK_COMMAND = 0x0000_0000_0100_0000;
-        /// Data buffer overflow event
-        const DATA_BUFFER_OVERFLOW = 0x0000_0000_0200_0000;
-        /// Max slots change event
-        const MAX_SLOTS_CHANGE = 0x0000_0000_0400_0000;
-        /// Read clock offset complete event
-        const READ_CLOCK_OFFSET_COMPLETE = 0x0000_0000_0800_0000;
-        /// Connection packet type changed event
-        const CONNECTION_PACKET_TYPE_CHANGED = 0x0000_0000_1000_0000;
-        /// Qos violation event
-        const QOS_VIOLATION = 0x0000_0000_2000_0000;
-        /// Page scan mode change event. Deprecated in Bluetooth spec.
-        #[deprecated]
-        const PAGE_SCAN_MODE_CHANGE = 0x0000_0000_4000_0000;
-        /// Page scan repetition mode change event
-        const PAGE_SCAN_REPETITION_MODE_CHANGE = 0x0000_0000_8000_0000;
-        /// Flow specification complete event
-        const FLOW_SPECIFICATION_COMPLETE = 0x0000_0001_0000_0000;
-        /// Inquiry result with rssi event
-        const INQUIRY_RESULT_WITH_RSSI = 0x0000_0002_0000_0000;
-        /// Read remote extended features complete event
-        const READ_REMOTE_EXTENDED_FEATURES_COMPLETE = 0x0000_0004_0000_0000;
-        /// Synchronous connection complete event
-        const SYNCHRONOUS_CONNECTION_COMPLETE = 0x0000_0800_0000_0000;
-        /// Synchronous connection changed event
-        const SYNCHRONOUS_CONNECTION_CHANGED = 0x0000_1000_0000_0000;
-        /// Sniff subrating event
-        const SNIFF_SUBRATING = 0x0000_2000_0000_0000;
-        /// Extended inquiry result event
-        const EXTENDED_INQUIRY_RESULT = 0x0000_4000_0000_0000;
-        /// Encryption key refresh complete event
-        const ENCRYPTION_KEY_REFRESH_COMPLETE = 0x0000_8000_0000_0000;
-        /// Io capability request event
-        const IO_CAPABILITY_REQUEST = 0x0001_0000_0000_0000;
-        /// Io capability request reply event
-        const IO_CAPABILITY_REQUEST_REPLY = 0x0002_0000_0000_0000;
-        /// User confirmation request event
-        const USER_CONFIRMATION_REQUEST = 0x0004_0000_0000_0000;
-        /// User passkey request event
-        const USER_PASSKEY_REQUEST = 0x0008_0000_0000_0000;
-        /// Remote oob data request event
-        const REMOTE_OOB_DATA_REQUEST = 0x0010_0000_0000_0000;
-        /// Simple pairing complete event
-        const SIMPLE_PAIRING_COMPLETE = 0x0020_0000_0000_0000;
-        /// Link supervision timeout changed event
-        const LINK_SUPERVISION_TIMEOUT_CHANGED = 0x0080_0000_0000_0000;
-        /// Enhanced flush complete event
-        const ENHANCED_FLUSH_COMPLETE = 0x0100_0000_0000_0000;
-        /// User passkey notification event
-        const USER_PASSKEY_NOTIFICATION = 0x0400_0000_0000_0000;
-        /// Keypress notification event
-        const KEYPRESS_NOTIFICATION = 0x0800_0000_0000_0000;
-        /// Remote host supported features notification event
-        const REMOTE_HOST_SUPPORTED_FEATURES_NOTIFICATION = 0x1000_0000_0000_0000;
-        /// LE meta-events
-        const LE_META_EVENT = 0x2000_0000_0000_0000;
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// Options for the
+    /// [`le_periodic_advertising_create_sync`](HostHci::le_periodic_advertising_create_sync)
+    /// command.
+    #[derive(Default)]
+    pub struct SyncOptions : u8 {
+        /// Use the periodic advertiser list to find the advertiser to synchronize with, instead
+        /// of the advertiser given in the parameters.
+        const USE_PERIODIC_ADVERTISER_LIST = 0x01;
+        /// Disable the [LE Periodic Advertising Reports](crate::event::Event::LePeriodicAdvertisingReport)
+        /// when synchronization is established.
+        const REPORTING_INITIALLY_DISABLED = 0x02;
+        /// Enable duplicate filtering of the reports when synchronization is established.
+        const DUPLICATE_FILTERING_INITIALLY_ENABLED = 0x04;
     }
 }
 
-/// For the [`read_tx_power_level`](HostHci::read_tx_power_level) command, the allowed values for the
-/// type of power level to read.
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// Types of Constant Tone Extension that prevent synchronizing with a periodic advertising
+    /// train, for the
+    /// [`le_periodic_advertising_create_sync`](HostHci::le_periodic_advertising_create_sync)
+    /// command.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct SyncCteType : u8 {
+        /// Do not sync to packets with an AoA Constant Tone Extension
+        const NO_AOA = 0x01;
+        /// Do not sync to packets with an AoD Constant Tone Extension with 1 μs slots
+        const NO_AOD_1US = 0x02;
+        /// Do not sync to packets with an AoD Constant Tone Extension with 2 μs slots
+        const NO_AOD_2US = 0x04;
+        /// Do not sync to packets with a type 3 Constant Tone Extension
+        const NO_TYPE_3 = 0x08;
+        /// Do not sync to packets without a Constant Tone Extension
+        const NO_CTE = 0x10;
+    }
+}
+
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// Types of Constant Tone Extension that prevent synchronizing with a periodic advertising
+    /// train, for the
+    /// [`le_periodic_advertising_create_sync`](HostHci::le_periodic_advertising_create_sync)
+    /// command.
+    #[derive(Default)]
+    pub struct SyncCteType : u8 {
+        /// Do not sync to packets with an AoA Constant Tone Extension
+        const NO_AOA = 0x01;
+        /// Do not sync to packets with an AoD Constant Tone Extension with 1 μs slots
+        const NO_AOD_1US = 0x02;
+        /// Do not sync to packets with an AoD Constant Tone Extension with 2 μs slots
+        const NO_AOD_2US = 0x04;
+        /// Do not sync to packets with a type 3 Constant Tone Extension
+        const NO_TYPE_3 = 0x08;
+        /// Do not sync to packets without a Constant Tone Extension
+        const NO_CTE = 0x10;
+    }
+}
+
+/// Parameters for the
+/// [`le_periodic_advertising_create_sync`](HostHci::le_periodic_advertising_create_sync) command.
 ///
-/// See the Bluetooth spec, Vol 2, Part E, Section 7.3.35.
-#[repr(u8)]
-#[derive(Copy, Clone, Debug, PartialEq)]
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.67.
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub enum TxPowerLevel {
-    /// Read Current Transmit Power Level.
-    Current = 0x00,
-    /// Read Maximum Transmit Power Level.
-    Maximum = 0x01,
+pub struct PeriodicAdvertisingSyncParameters {
+    /// How the advertiser is found, and how reports are initially generated.
+    pub options: SyncOptions,
+
+    /// Advertising SID subfield in the ADI field used to identify the periodic advertising, from
+    /// 0x00 to 0x0F. Ignored when using the periodic advertiser list.
+    pub adv_sid: u8,
+
+    /// Address of the advertiser. Ignored when using the periodic advertiser list.
+    pub adv_address: crate::BdAddrType,
+
+    /// Maximum number of periodic advertising events that can be skipped after a successful
+    /// receive, from 0x0000 to 0x01F3.
+    pub skip: u16,
+
+    /// Synchronization timeout for the periodic advertising train, with a resolution of 10 ms.
+    /// Shall be between 100 ms and 163.84 s.
+    pub sync_timeout: Duration,
+
+    /// Types of Constant Tone Extension the periodic advertising shall not have to synchronize
+    /// with it.
+    pub sync_cte_type: SyncCteType,
 }
 
-/// For the [set_controller_to_host_flow_control](HostHci::set_controller_to_host_flow_control) command, the
-/// allowed values for flow control.
+impl PeriodicAdvertisingSyncParameters {
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<(), Error> {
+        assert_eq!(bytes.len(), 14);
+
+        if self.adv_sid > MAX_ADVERTISING_SID {
+            return Err(Error::BadAdvertisingSid(self.adv_sid));
+        }
+        let sync_timeout = to_sync_timeout_units(self.skip, self.sync_timeout)?;
+
+        bytes[0] = self.options.bits();
+        bytes[1] = self.adv_sid;
+        self.adv_address.copy_into_slice(&mut bytes[2..9]);
+        LittleEndian::write_u16(&mut bytes[9..], self.skip);
+        LittleEndian::write_u16(&mut bytes[11..], sync_timeout);
+        bytes[13] = self.sync_cte_type.bits();
+
+        Ok(())
+    }
+}
+
+// Validates the skip and sync timeout used when synchronizing with a periodic advertising train,
+// and returns the timeout in units of 10 ms.
+fn to_sync_timeout_units(skip: u16, sync_timeout: Duration) -> Result<u16, Error> {
+    const MAX_SKIP: u16 = 0x01F3;
+    const SYNC_TIMEOUT_RANGE: core::ops::RangeInclusive<Duration> =
+        Duration::from_millis(100)..=Duration::from_millis(163_840);
+
+    if skip > MAX_SKIP {
+        return Err(Error::BadSyncSkip(skip));
+    }
+    if !SYNC_TIMEOUT_RANGE.contains(&sync_timeout) {
+        return Err(Error::BadSyncTimeout(sync_timeout));
+    }
+
+    Ok(to_units(sync_timeout, Duration::from_millis(10)).unwrap())
+}
+
+const MAX_ADVERTISING_SID: u8 = 0x0F;
+
+/// An entry of the periodic advertiser list, for the
+/// [`le_add_device_to_periodic_advertiser_list`](HostHci::le_add_device_to_periodic_advertiser_list)
+/// and
+/// [`le_remove_device_from_periodic_advertiser_list`](HostHci::le_remove_device_from_periodic_advertiser_list)
+/// commands.
 ///
-/// See Bluetooth spec. v.5.4 [Vol 4, Part E, 7.3.38].
-#[repr(u8)]
-#[derive(Copy, Clone, Debug, PartialEq, Default)]
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.70.
+#[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub enum FlowControl {
-    /// Flow control off in direction from controller to host. `default`
-    #[default]
-    Off = 0x00,
-    /// Flow control on for HCI ACL Data Packets and off for HCI synchronous.
-    /// Data Packets in direction from Controller to Host.
-    HciAclDataOnly = 0x01,
-    /// Flow control off for HCI ACL Data Packets and on for HCI synchronous.
-    /// Data Packets in direction from Controller to Host.
-    HciSyncDataOnly = 0x02,
-    /// control on both for HCI ACL Data Packets and HCI synchronous.
-    /// Data Packets in direction from Controller to Host.
-    Both = 0x03,
+pub struct PeriodicAdvertiser {
+    /// Address of the advertiser.
+    pub address: crate::BdAddrType,
+
+    /// Advertising SID subfield in the ADI field used to identify the periodic advertising, from
+    /// 0x00 to 0x0F.
+    pub adv_sid: u8,
 }
 
-#[derive(Copy, Clone, Debug)]
+impl PeriodicAdvertiser {
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<(), Error> {
+        assert_eq!(bytes.len(), 8);
+
+        if self.adv_sid > MAX_ADVERTISING_SID {
+            return Err(Error::BadAdvertisingSid(self.adv_sid));
+        }
+
+        self.address.copy_into_slice(&mut bytes[0..7]);
+        bytes[7] = self.adv_sid;
+
+        Ok(())
+    }
+}
+
+/// How the Controller processes periodic advertising synchronization information received from a
+/// connected device.
+#[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-/// Parameters for the [host_buffer_size](HostHci::host_buffer_size) commad
-///
-/// # Note:
-/// The [Host ACL Data Packet Length](HostBufferSize::acl_data_packet_length) and
-/// [Host Synchronous Data Packet Length](HostBufferSize::sync_data_packet_length) command parameters
-/// do not include the length of the HCI Data Packet header.
+pub enum SyncTransferMode {
+    /// No attempt is made to synchronize to the periodic advertising, and no
+    /// [LE Periodic Advertising Sync Transfer Received](crate::event::Event::LePeriodicAdvertisingSyncTransferReceived)
+    /// event is sent to the Host.
+    NoSync = 0x00,
+    /// The Host is notified of the synchronization, but
+    /// [LE Periodic Advertising Reports](crate::event::Event::LePeriodicAdvertisingReport) are
+    /// disabled.
+    ReportsDisabled = 0x01,
+    /// The Host is notified of the synchronization, and periodic advertising reports are enabled
+    /// with duplicate filtering disabled.
+    ReportsEnabled = 0x02,
+    /// The Host is notified of the synchronization, and periodic advertising reports are enabled
+    /// with duplicate filtering enabled.
+    ReportsEnabledFilterDuplicates = 0x03,
+}
+
+/// Parameters for the
+/// [`le_set_periodic_advertising_sync_transfer_parameters`](HostHci::le_set_periodic_advertising_sync_transfer_parameters)
+/// and
+/// [`le_set_default_periodic_advertising_sync_transfer_parameters`](HostHci::le_set_default_periodic_advertising_sync_transfer_parameters)
+/// commands.
 ///
-/// See Bluetooth spec. v.5.4 [Vol 4, Part E, 7.3.39].
-pub struct HostBufferSize {
-    /// Maximum length (in octets) of the data portion of each HCI ACL Data Packet that the host is able
-    /// to accept.
-    ///
-    /// this parameter will be used to determine the size of the L2CAP segments contained in the ACL Data
-    /// Packets, which are transferred from the Controller to the Host.
-    ///
-    /// Values:
-    /// - 251 .. 65535
-    pub acl_data_packet_length: u16,
-    /// Maximum length (in octets) of the data portion of each HCI Synchronous Data Packet that the Host
-    /// is able to accept. `NOT USED`
-    ///
-    /// This parameter is used to determine the maximum size of HCI Synchronous Data Packets. Both the Host
-    /// and the Controller shall support command and event packets, zhere the data portion (excluding header)
-    /// contained in the packet is 255 octets is size.
-    pub sync_data_packet_length: u8,
-    /// The total number of HCI ACL Data Packets that can be stored in the data buffers of the Host.
-    ///
-    /// This parameter contains the total number of HCI ACL Data Packets that can be stored in the data buffers
-    /// of the Host. The Controller will determine how the buffers are to be divided between different
-    /// [Connection Handles](ConnectionHandle).
-    pub total_acl_data_packets: u16,
-    /// Total number of HCI Synchronous Data Packets that can be stored in the data buffers of the Host. `NOT USED`
-    ///
-    /// This parameter gives the save information for HCI Synchronous Data Packets.
-    pub total_sync_data_packets: u16,
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.91.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SyncTransferParameters {
+    /// What to do with received synchronization information.
+    pub mode: SyncTransferMode,
+
+    /// Maximum number of periodic advertising events that can be skipped after a successful
+    /// receive, from 0x0000 to 0x01F3.
+    pub skip: u16,
+
+    /// Synchronization timeout for the periodic advertising train, with a resolution of 10 ms.
+    /// Shall be between 100 ms and 163.84 s.
+    pub sync_timeout: Duration,
+
+    /// Types of Constant Tone Extension the periodic advertising shall not have to synchronize
+    /// with it.
+    pub sync_cte_type: SyncCteType,
 }
 
-impl HostBufferSize {
-    fn copy_into_slice(&self, bytes: &mut [u8]) {
+impl SyncTransferParameters {
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<(), Error> {
         assert_eq!(bytes.len(), 6);
 
-        LittleEndian::write_u16(&mut bytes[0..], self.acl_data_packet_length);
-        bytes[2] = self.sync_data_packet_length;
-        LittleEndian::write_u16(&mut bytes[3..], self.total_acl_data_packets);
-        LittleEndian::write_u16(&mut bytes[5..], self.total_sync_data_packets);
+        let sync_timeout = to_sync_timeout_units(self.skip, self.sync_timeout)?;
+
+        bytes[0] = self.mode as u8;
+        LittleEndian::write_u16(&mut bytes[1..], self.skip);
+        LittleEndian::write_u16(&mut bytes[3..], sync_timeout);
+        bytes[5] = self.sync_cte_type.bits();
+
+        Ok(())
     }
 }
 
-#[cfg(not(feature = "defmt"))]
-bitflags::bitflags! {
-    /// Event flags defined for the [`le_set_event_mask`](HostHci::le_set_event_mask) command.
-    #[derive(Default)]
-    pub struct LeEventFlags : u64 {
-        /// LE connection complete event
-        const CONNECTION_COMPLETE = 1 << 0;
-        /// LE advertising report event
-        const ADVERTISING_REPORT = 1 << 1;
-        /// LE connection update complete event
-        const CONNECTION_UPDATE_COMPLETE = 1 << 2;
-        /// LE read remote features complete event
-        const READ_REMOTE_FEATURES_COMPLETE = 1 << 3;
-        /// LE long term key request event
-        const LONG_TERM_KEY_REQUEST = 1 << 4;
-        /// LE remote connection parameter request event
-        const REMOTE_CONNECTION_PARAMETER_REQUEST = 1 << 5;
-        /// LE data length change event
-        const DATA_LENGTH_CHANGE = 1 << 6;
-        /// LE read local p256 public key complete event
-        const READ_LOCAL_P256_PUBLIC_KEY_COMPLETE = 1 << 7;
-        /// LE generate dhkey complete event
-        const GENERATE_DHKEY_COMPLETE = 1 << 8;
-        /// LE enhanced connection complete event
-        const ENHANCED_CONNECTION_COMPLETE = 1 << 9;
-        /// LE directed advertising report event
-        const DIRECTED_ADVERTISING_REPORT = 1 << 10;
-        /// LE phy update complete event
-        const PHY_UPDATE_COMPLETE = 1 << 11;
-        /// LE extended advertising report event
-        const EXTENDED_ADVERTISING_REPORT = 1 << 12;
-        /// LE periodic advertising sync established event
-        const PERIODIC_ADVERTISING_SYNC_ESTABLISHED = 1 << 13;
-        /// LE periodic advertising report event
-        const PERIODIC_ADVERTISING_REPORT = 1 << 14;
-        /// LE periodic advertising sync lost event
-        const PERIODIC_ADVERTISING_SYNC_LOST = 1 << 15;
-        /// LE extended scan timeout event
-        const EXTENDED_SCAN_TIMEOUT = 1 << 16;
-        /// LE extended advertising set terminated event
-        const EXTENDED_ADVERTISING_SET_TERMINATED = 1 << 17;
-        /// LE scan request received event
-        const SCAN_REQUEST_RECEIVED = 1 << 18;
-        /// LE channel selection algorithm event
-        const CHANNEL_SELECTION_ALGORITHM = 1 << 19;
+const MAX_CIG_ID: u8 = 0xEF;
+
+/// Arrangement of the subevents of the CISes in a CIG, for the
+/// [`le_set_cig_parameters`](HostHci::le_set_cig_parameters) and
+/// [`le_set_cig_parameters_test`](HostHci::le_set_cig_parameters_test) commands.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Packing {
+    /// The subevents of each CIS are scheduled one CIS after the other.
+    Sequential = 0x00,
+    /// The subevents of the CISes are interleaved.
+    Interleaved = 0x01,
+}
+
+/// Format of the isochronous data PDUs, for the
+/// [`le_set_cig_parameters`](HostHci::le_set_cig_parameters) and
+/// [`le_set_cig_parameters_test`](HostHci::le_set_cig_parameters_test) commands.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Framing {
+    /// Each SDU is carried in one or more PDUs, without segmentation headers.
+    Unframed = 0x00,
+    /// SDUs are segmented into PDUs, with a segmentation header.
+    Framed = 0x01,
+}
+
+/// Parameters of a CIG for the [`le_set_cig_parameters`](HostHci::le_set_cig_parameters) command.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.97.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CigParameters {
+    /// Identifies the CIG, from 0x00 to 0xEF.
+    pub cig_id: u8,
+
+    /// Interval between the start of consecutive SDUs from the Central's Host, with a resolution
+    /// of 1 µs. Shall be between 255 µs and 1,048,575 µs.
+    pub sdu_interval_c_to_p: Duration,
+
+    /// Interval between the start of consecutive SDUs from the Peripheral's Host, with a
+    /// resolution of 1 µs. Shall be between 255 µs and 1,048,575 µs.
+    pub sdu_interval_p_to_c: Duration,
+
+    /// Worst-case sleep clock accuracy of all the Peripherals that will participate in the CIG.
+    pub worst_case_sca: CentralClockAccuracy,
+
+    /// Preferred arrangement of the CISes.
+    pub packing: Packing,
+
+    /// Format of the CIS data PDUs.
+    pub framing: Framing,
+
+    /// Maximum transport latency from the Central's Controller to the Peripheral's Controller,
+    /// with a resolution of 1 ms. Shall be between 5 ms and 4 s.
+    pub max_transport_latency_c_to_p: Duration,
+
+    /// Maximum transport latency from the Peripheral's Controller to the Central's Controller,
+    /// with a resolution of 1 ms. Shall be between 5 ms and 4 s.
+    pub max_transport_latency_p_to_c: Duration,
+}
+
+impl CigParameters {
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<(), Error> {
+        assert_eq!(bytes.len(), 14);
+
+        validate_max_transport_latency(self.max_transport_latency_c_to_p)?;
+        validate_max_transport_latency(self.max_transport_latency_p_to_c)?;
+        copy_cig_id_and_sdu_intervals(
+            self.cig_id,
+            self.sdu_interval_c_to_p,
+            self.sdu_interval_p_to_c,
+            &mut bytes[0..7],
+        )?;
+        bytes[7] = self.worst_case_sca.into();
+        bytes[8] = self.packing as u8;
+        bytes[9] = self.framing as u8;
+        LittleEndian::write_u16(
+            &mut bytes[10..],
+            self.max_transport_latency_c_to_p.as_millis() as u16,
+        );
+        LittleEndian::write_u16(
+            &mut bytes[12..],
+            self.max_transport_latency_p_to_c.as_millis() as u16,
+        );
+
+        Ok(())
     }
 }
 
-#[cfg(feature = "defmt")]
-defmt::bitflags! {
-    /// Event flags defined for the [`le_set_event_mask`](HostHci::le_set_event_mask) command.
-    #[derive(Default)]
-    pub struct LeEventFlags : u64 {
-        /// LE connection complete event
-        const CONNECTION_COMPLETE = 1 << 0;
-        /// LE advertising report event
-        const ADVERTISING_REPORT = 1 << 1;
-        /// LE connection update complete event
-        const CONNECTION_UPDATE_COMPLETE = 1 << 2;
-        /// LE read remote features complete event
-        const READ_REMOTE_FEATURES_COMPLETE = 1 << 3;
-        /// LE long term key request event
-        const LONG_TERM_KEY_REQUEST = 1 << 4;
-        /// LE remote connection parameter request event
-        const REMOTE_CONNECTION_PARAMETER_REQUEST = 1 << 5;
-        /// LE data length change event
-        const DATA_LENGTH_CHANGE = 1 << 6;
-        /// LE read local p256 public key complete event
-        const READ_LOCAL_P256_PUBLIC_KEY_COMPLETE = 1 << 7;
-        /// LE generate dhkey complete event
-        const GENERATE_DHKEY_COMPLETE = 1 << 8;
-        /// LE enhanced connection complete event
-        const ENHANCED_CONNECTION_COMPLETE = 1 << 9;
-        /// LE directed advertising report event
-        const DIRECTED_ADVERTISING_REPORT = 1 << 10;
-        /// LE phy update complete event
-        const PHY_UPDATE_COMPLETE = 1 << 11;
-        /// LE extended advertising report event
-        const EXTENDED_ADVERTISING_REPORT = 1 << 12;
-        /// LE periodic advertising sync established event
-        const PERIODIC_ADVERTISING_SYNC_ESTABLISHED = 1 << 13;
-        /// LE periodic advertising report event
-        const PERIODIC_ADVERTISING_REPORT = 1 << 14;
-        /// LE periodic advertising sync lost event
-        const PERIODIC_ADVERTISING_SYNC_LOST = 1 << 15;
-        /// LE extended scan timeout event
-        const EXTENDED_SCAN_TIMEOUT = 1 << 16;
-        /// LE extended advertising set terminated event
-        const EXTENDED_ADVERTISING_SET_TERMINATED = 1 << 17;
-        /// LE scan request received event
-        const SCAN_REQUEST_RECEIVED = 1 << 18;
-        /// LE channel selection algorithm event
-        const CHANNEL_SELECTION_ALGORITHM = 1 << 19;
+fn copy_cig_id_and_sdu_intervals(
+    cig_id: u8,
+    sdu_interval_c_to_p: Duration,
+    sdu_interval_p_to_c: Duration,
+    bytes: &mut [u8],
+) -> Result<(), Error> {
+    if cig_id > MAX_CIG_ID {
+        return Err(Error::BadCigId(cig_id));
     }
+    validate_sdu_interval(sdu_interval_c_to_p)?;
+    validate_sdu_interval(sdu_interval_p_to_c)?;
+
+    bytes[0] = cig_id;
+    LittleEndian::write_u24(&mut bytes[1..], sdu_interval_c_to_p.as_micros() as u32);
+    LittleEndian::write_u24(&mut bytes[4..], sdu_interval_p_to_c.as_micros() as u32);
+
+    Ok(())
 }
 
-fn validate_random_address(bd_addr: crate::BdAddr) -> Result<(), Error> {
-    let (pop_count, bit_count) = match (bd_addr.0[5] & 0b1100_0000) >> 6 {
-        0b00 | 0b11 => (pop_count_except_top_2_bits(&bd_addr.0[0..]), 46),
-        0b10 => (pop_count_except_top_2_bits(&bd_addr.0[3..]), 22),
-        _ => return Err(Error::BadRandomAddress(bd_addr)),
-    };
+fn validate_sdu_interval(interval: Duration) -> Result<(), Error> {
+    const SDU_INTERVAL_RANGE: core::ops::RangeInclusive<Duration> =
+        Duration::from_micros(0x0000FF)..=Duration::from_micros(0x0FFFFF);
 
-    if pop_count == 0 || pop_count == bit_count {
-        return Err(Error::BadRandomAddress(bd_addr));
+    if !SDU_INTERVAL_RANGE.contains(&interval) {
+        return Err(Error::BadSduInterval(interval));
     }
 
     Ok(())
 }
 
-fn pop_count_except_top_2_bits(bytes: &[u8]) -> u32 {
-    let mut pop_count = 0;
-    for byte in bytes[..bytes.len() - 1].iter() {
-        pop_count += pop_count_of(*byte);
+fn validate_max_transport_latency(latency: Duration) -> Result<(), Error> {
+    const MAX_TRANSPORT_LATENCY_RANGE: core::ops::RangeInclusive<Duration> =
+        Duration::from_millis(5)..=Duration::from_millis(4000);
+
+    if !MAX_TRANSPORT_LATENCY_RANGE.contains(&latency) {
+        return Err(Error::BadMaxTransportLatency(latency));
     }
-    pop_count += pop_count_of(bytes[bytes.len() - 1] & 0b0011_1111);
 
-    pop_count
+    Ok(())
 }
 
-fn pop_count_of(byte: u8) -> u32 {
-    byte.count_ones()
+/// Parameters of one CIS for the [`le_set_cig_parameters`](HostHci::le_set_cig_parameters)
+/// command.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.97.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CisParameters {
+    /// Identifies the CIS in the CIG, from 0x00 to 0xEF.
+    pub cis_id: u8,
+
+    /// Maximum size of an SDU from the Central's Host, from 0x000 to 0xFFF bytes.
+    pub max_sdu_c_to_p: u16,
+
+    /// Maximum size of an SDU from the Peripheral's Host, from 0x000 to 0xFFF bytes.
+    pub max_sdu_p_to_c: u16,
+
+    /// PHYs that may be used from the Central to the Peripheral.
+    pub phy_c_to_p: PhyMask,
+
+    /// PHYs that may be used from the Peripheral to the Central.
+    pub phy_p_to_c: PhyMask,
+
+    /// Number of times every CIS data PDU should be retransmitted from the Central to the
+    /// Peripheral.
+    pub rtn_c_to_p: u8,
+
+    /// Number of times every CIS data PDU should be retransmitted from the Peripheral to the
+    /// Central.
+    pub rtn_p_to_c: u8,
 }
 
-/// Parameters for the [`le_set_advertising_parameters`](HostHci::le_set_advertising_parameters)
+impl CisParameters {
+    pub(crate) const LEN: usize = 9;
+
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<(), Error> {
+        assert_eq!(bytes.len(), Self::LEN);
+
+        if self.cis_id > MAX_CIG_ID {
+            return Err(Error::BadCisId(self.cis_id));
+        }
+
+        bytes[0] = self.cis_id;
+        LittleEndian::write_u16(&mut bytes[1..], self.max_sdu_c_to_p);
+        LittleEndian::write_u16(&mut bytes[3..], self.max_sdu_p_to_c);
+        bytes[5] = self.phy_c_to_p.bits();
+        bytes[6] = self.phy_p_to_c.bits();
+        bytes[7] = self.rtn_c_to_p;
+        bytes[8] = self.rtn_p_to_c;
+
+        Ok(())
+    }
+}
+
+/// Parameters of a CIG for the [`le_set_cig_parameters_test`](HostHci::le_set_cig_parameters_test)
 /// command.
-#[derive(Clone, Debug)]
-pub struct AdvertisingParameters {
-    /// Type and allowable duration of advertising.
-    pub advertising_interval: AdvertisingInterval,
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.98.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CigTestParameters {
+    /// Identifies the CIG, from 0x00 to 0xEF.
+    pub cig_id: u8,
 
-    /// Indicates the type of address being used in the advertising packets.
-    ///
-    /// If this is [`PrivateFallbackPublic`](OwnAddressType) or
-    /// [`PrivateFallbackRandom`](OwnAddressType), the
-    /// [`peer_address`](AdvertisingParameters::peer_address) parameter contains the peer's identity
-    /// address and type. These parameters are used to locate the corresponding local IRK in the
-    /// resolving list; this IRK is used to generate the own address used in the advertisement.
-    pub own_address_type: OwnAddressType,
+    /// Interval between the start of consecutive SDUs from the Central's Host, with a resolution
+    /// of 1 µs. Shall be between 255 µs and 1,048,575 µs.
+    pub sdu_interval_c_to_p: Duration,
 
-    /// If directed advertising is performed, i.e. when `advertising_type` is set to
-    /// [`ConnectableDirectedHighDutyCycle`](AdvertisingType::ConnectableDirectedHighDutyCycle) or
-    /// [`ConnectableDirectedLowDutyCycle`](AdvertisingType::ConnectableDirectedLowDutyCycle), then
-    /// `peer_address` shall be valid.
-    ///
-    /// If `own_address_type` is [`PrivateFallbackPublic`](OwnAddressType) or
-    /// [`PrivateFallbackRandom`](OwnAddressType), the Controller generates
-    /// the peer's Resolvable Private Address using the peer's IRK corresponding to the peer's
-    /// Identity Address contained in `peer_address`.
-    pub peer_address: crate::BdAddrType,
+    /// Interval between the start of consecutive SDUs from the Peripheral's Host, with a
+    /// resolution of 1 µs. Shall be between 255 µs and 1,048,575 µs.
+    pub sdu_interval_p_to_c: Duration,
 
-    /// Bit field that indicates the advertising channels that shall be used when transmitting
-    /// advertising packets. At least one channel bit shall be set in the bitfield.
-    pub advertising_channel_map: Channels,
+    /// Flush timeout, in multiples of the ISO interval, for each payload sent from the Central to
+    /// the Peripheral.
+    pub ft_c_to_p: u8,
 
-    /// This parameter shall be ignored when directed advertising is enabled.
-    pub advertising_filter_policy: AdvertisingFilterPolicy,
+    /// Flush timeout, in multiples of the ISO interval, for each payload sent from the Peripheral
+    /// to the Central.
+    pub ft_p_to_c: u8,
+
+    /// Time between consecutive CIS anchor points, with a resolution of 1.25 ms. Shall be between
+    /// 5 ms and 4 s.
+    pub iso_interval: Duration,
+
+    /// Worst-case sleep clock accuracy of all the Peripherals that will participate in the CIG.
+    pub worst_case_sca: CentralClockAccuracy,
+
+    /// Preferred arrangement of the CISes.
+    pub packing: Packing,
+
+    /// Format of the CIS data PDUs.
+    pub framing: Framing,
 }
 
-impl AdvertisingParameters {
+impl CigTestParameters {
     fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<(), Error> {
-        assert_eq!(bytes.len(), 15);
+        assert_eq!(bytes.len(), 14);
 
-        if self.advertising_channel_map.is_empty() {
-            return Err(Error::BadChannelMap(self.advertising_channel_map));
+        const ISO_INTERVAL_RANGE: core::ops::RangeInclusive<Duration> =
+            Duration::from_millis(5)..=Duration::from_millis(4000);
+        if !ISO_INTERVAL_RANGE.contains(&self.iso_interval) {
+            return Err(Error::BadIsoInterval(self.iso_interval));
         }
 
-        self.advertising_interval.copy_into_slice(&mut bytes[0..5]);
-        bytes[5] = self.own_address_type as u8;
-        self.peer_address.copy_into_slice(&mut bytes[6..13]);
-        bytes[13] = self.advertising_channel_map.bits();
-        bytes[14] = self.advertising_filter_policy as u8;
+        copy_cig_id_and_sdu_intervals(
+            self.cig_id,
+            self.sdu_interval_c_to_p,
+            self.sdu_interval_p_to_c,
+            &mut bytes[0..7],
+        )?;
+        bytes[7] = self.ft_c_to_p;
+        bytes[8] = self.ft_p_to_c;
+        LittleEndian::write_u16(
+            &mut bytes[9..],
+            to_units(self.iso_interval, Duration::from_micros(1250)).unwrap(),
+        );
+        bytes[11] = self.worst_case_sca.into();
+        bytes[12] = self.packing as u8;
+        bytes[13] = self.framing as u8;
 
         Ok(())
     }
 }
 
-/// Indicates the type of address being used in the advertising packets.
-#[repr(u8)]
+/// Parameters of one CIS for the
+/// [`le_set_cig_parameters_test`](HostHci::le_set_cig_parameters_test) command.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.98.
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub enum OwnAddressType {
-    /// Public Device Address (default)
-    Public = 0x00,
-    /// Random Device Address
-    Random = 0x01,
-    /// Controller generates Resolvable Private Address based on the local IRK from resolving
-    /// list. If resolving list contains no matching entry, use public address.
-    PrivateFallbackPublic = 0x02,
-    /// Controller generates Resolvable Private Address based on the local IRK from resolving
-    /// list. If resolving list contains no matching entry, use random address from
-    /// [`le_set_random_address`](HostHci::le_set_random_address).
-    PrivateFallbackRandom = 0x03,
+pub struct CisTestParameters {
+    /// Identifies the CIS in the CIG, from 0x00 to 0xEF.
+    pub cis_id: u8,
+
+    /// Maximum number of subevents in each CIS event.
+    pub nse: u8,
+
+    /// Maximum size of an SDU from the Central's Host, from 0x000 to 0xFFF bytes.
+    pub max_sdu_c_to_p: u16,
+
+    /// Maximum size of an SDU from the Peripheral's Host, from 0x000 to 0xFFF bytes.
+    pub max_sdu_p_to_c: u16,
+
+    /// Maximum size of the payload of a CIS data PDU from the Central, from 0x00 to 0xFB bytes.
+    pub max_pdu_c_to_p: u16,
+
+    /// Maximum size of the payload of a CIS data PDU from the Peripheral, from 0x00 to 0xFB
+    /// bytes.
+    pub max_pdu_p_to_c: u16,
+
+    /// PHY used from the Central to the Peripheral.
+    pub phy_c_to_p: PhyMask,
+
+    /// PHY used from the Peripheral to the Central.
+    pub phy_p_to_c: PhyMask,
+
+    /// Burst number from the Central to the Peripheral: number of new payloads in each CIS event.
+    pub bn_c_to_p: u8,
+
+    /// Burst number from the Peripheral to the Central: number of new payloads in each CIS event.
+    pub bn_p_to_c: u8,
+}
+
+impl CisTestParameters {
+    pub(crate) const LEN: usize = 14;
+
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<(), Error> {
+        assert_eq!(bytes.len(), Self::LEN);
+
+        if self.cis_id > MAX_CIG_ID {
+            return Err(Error::BadCisId(self.cis_id));
+        }
+
+        bytes[0] = self.cis_id;
+        bytes[1] = self.nse;
+        LittleEndian::write_u16(&mut bytes[2..], self.max_sdu_c_to_p);
+        LittleEndian::write_u16(&mut bytes[4..], self.max_sdu_p_to_c);
+        LittleEndian::write_u16(&mut bytes[6..], self.max_pdu_c_to_p);
+        LittleEndian::write_u16(&mut bytes[8..], self.max_pdu_p_to_c);
+        bytes[10] = self.phy_c_to_p.bits();
+        bytes[11] = self.phy_p_to_c.bits();
+        bytes[12] = self.bn_c_to_p;
+        bytes[13] = self.bn_p_to_c;
+
+        Ok(())
+    }
+}
+
+/// A CIS to create with the [`le_create_cis`](HostHci::le_create_cis) command.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CisConnection {
+    /// Connection handle of the CIS, as returned by the
+    /// [`le_set_cig_parameters`](HostHci::le_set_cig_parameters) command.
+    pub cis_conn_handle: ConnectionHandle,
+
+    /// Connection handle of the ACL connection with the Peripheral.
+    pub acl_conn_handle: ConnectionHandle,
+}
+
+impl CisConnection {
+    pub(crate) const LEN: usize = 4;
+
+    fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), Self::LEN);
+
+        LittleEndian::write_u16(&mut bytes[0..], self.cis_conn_handle.0);
+        LittleEndian::write_u16(&mut bytes[2..], self.acl_conn_handle.0);
+    }
+}
+
+/// Direction of an isochronous data path, for the
+/// [`le_setup_iso_data_path`](HostHci::le_setup_iso_data_path) command.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DataPathDirection {
+    /// Data flows from the Host to the Controller.
+    Input = 0x00,
+    /// Data flows from the Controller to the Host.
+    Output = 0x01,
 }
 
 #[cfg(not(feature = "defmt"))]
 bitflags::bitflags! {
-    /// The advertising channels that shall be used when transmitting advertising packets.
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    pub struct Channels : u8 {
-        /// Channel 37 shall be used
-        const CH_37 = 0b0000_0001;
-        /// Channel 38 shall be used
-        const CH_38 = 0b0000_0010;
-        /// Channel 39 shall be used
-        const CH_39 = 0b0000_0100;
+    /// Directions of the isochronous data paths removed by the
+    /// [`le_remove_iso_data_path`](HostHci::le_remove_iso_data_path) command.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct DataPathDirections : u8 {
+        /// Host to Controller data path
+        const INPUT = 0x01;
+        /// Controller to Host data path
+        const OUTPUT = 0x02;
     }
 }
 
 #[cfg(feature = "defmt")]
 defmt::bitflags! {
-    /// The advertising channels that shall be used when transmitting advertising packets.
-    pub struct Channels : u8 {
-        /// Channel 37 shall be used
-        const CH_37 = 0b0000_0001;
-        /// Channel 38 shall be used
-        const CH_38 = 0b0000_0010;
-        /// Channel 39 shall be used
-        const CH_39 = 0b0000_0100;
+    /// Directions of the isochronous data paths removed by the
+    /// [`le_remove_iso_data_path`](HostHci::le_remove_iso_data_path) command.
+    #[derive(Default)]
+    pub struct DataPathDirections : u8 {
+        /// Host to Controller data path
+        const INPUT = 0x01;
+        /// Controller to Host data path
+        const OUTPUT = 0x02;
     }
 }
 
-impl Default for Channels {
-    fn default() -> Channels {
-        Channels::all()
-    }
+/// Identifies the codec used on an isochronous data path.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.109.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CodecId {
+    /// Coding format, as defined in the Assigned Numbers. 0x03 is transparent, and 0xFF is
+    /// vendor-specific.
+    pub coding_format: u8,
+
+    /// Company ID of the vendor-specific codec. Shall be 0 if the coding format is not 0xFF.
+    pub company_id: u16,
+
+    /// Vendor-defined codec ID. Shall be 0 if the coding format is not 0xFF.
+    pub vendor_codec_id: u16,
 }
 
-/// Possible filter policies used for undirected advertising.
+/// Parameters for the [`le_setup_iso_data_path`](HostHci::le_setup_iso_data_path) command.
 ///
-/// See [`AdvertisingParameters`](crate::host::AdvertisingParameters).
-#[repr(u8)]
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.109.
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub enum AdvertisingFilterPolicy {
-    /// Process scan and connection requests from all devices (i.e., the White List is not in use)
-    /// (default).
-    AllowConnectionAndScan = 0x00,
-    /// Process connection requests from all devices and only scan requests from devices that are in
-    /// the White List.
-    AllowConnectionWhiteListScan = 0x01,
-    /// Process scan requests from all devices and only connection requests from devices that are in
-    /// the White List.
-    WhiteListConnectionAllowScan = 0x02,
-    /// Process scan and connection requests only from devices in the White List.
-    WhiteListConnectionAndScan = 0x03,
-}
+pub struct IsoDataPathParameters<'a> {
+    /// Connection handle of the CIS or BIS.
+    pub conn_handle: ConnectionHandle,
 
-/// Parameters for the [`le_set_scan_parameters`](HostHci::le_set_scan_parameters) command.
-#[derive(Clone, Debug, PartialEq)]
-pub struct ScanParameters {
-    /// The type of scan to perform
-    pub scan_type: ScanType,
+    /// Direction of the data path.
+    pub direction: DataPathDirection,
 
-    /// Recommendation from the host on how frequently the controller should scan.  See the
-    /// Bluetooth spec, Vol 6, Part B, Section 4.5.3.
-    pub scan_window: ScanWindow,
+    /// Data path to use: 0x00 for HCI, or a vendor-specific transport from 0x01 to 0xFE.
+    pub data_path_id: u8,
 
-    /// Indicates the type of address being used in the scan request packets.
-    pub own_address_type: OwnAddressType,
+    /// Codec used on the data path.
+    pub codec_id: CodecId,
 
-    /// Indicates which advertising packets to accept.
-    pub filter_policy: ScanFilterPolicy,
+    /// Controller delay, with a resolution of 1 µs. Shall be at most 4 s.
+    pub controller_delay: Duration,
+
+    /// Codec-specific configuration.
+    pub codec_configuration: &'a [u8],
 }
 
-impl ScanParameters {
-    fn copy_into_slice(&self, bytes: &mut [u8]) {
-        assert_eq!(bytes.len(), 7);
+impl IsoDataPathParameters<'_> {
+    const MAX_CODEC_CONFIGURATION_LEN: usize = 242;
 
-        bytes[0] = self.scan_type as u8;
-        self.scan_window.copy_into_slice(&mut bytes[1..5]);
-        bytes[5] = self.own_address_type as u8;
-        bytes[6] = self.filter_policy as u8;
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<usize, Error> {
+        const MAX_CONTROLLER_DELAY: Duration = Duration::from_micros(0x3D0900);
+
+        if self.controller_delay > MAX_CONTROLLER_DELAY {
+            return Err(Error::BadControllerDelay(self.controller_delay));
+        }
+        if self.codec_configuration.len() > Self::MAX_CODEC_CONFIGURATION_LEN {
+            return Err(Error::CodecConfigurationTooLong(
+                self.codec_configuration.len(),
+            ));
+        }
+
+        LittleEndian::write_u16(&mut bytes[0..], self.conn_handle.0);
+        bytes[2] = self.direction as u8;
+        bytes[3] = self.data_path_id;
+        bytes[4] = self.codec_id.coding_format;
+        LittleEndian::write_u16(&mut bytes[5..], self.codec_id.company_id);
+        LittleEndian::write_u16(&mut bytes[7..], self.codec_id.vendor_codec_id);
+        LittleEndian::write_u24(&mut bytes[9..], self.controller_delay.as_micros() as u32);
+        bytes[12] = self.codec_configuration.len() as u8;
+        let end = 13 + self.codec_configuration.len();
+        bytes[13..end].copy_from_slice(self.codec_configuration);
+
+        Ok(end)
     }
 }
 
-/// Types of scan to perform.
+// Maximum number of BISes in a BIG.
+const MAX_BIS_COUNT: usize = 0x1F;
+
+/// Parameters for the [`le_create_big`](HostHci::le_create_big) command.
 ///
-/// See [`ScanParameters`] and [`le_set_scan_parameters`](HostHci::le_set_scan_parameters).
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.103.
 #[derive(Copy, Clone, Debug, PartialEq)]
-#[repr(u8)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub enum ScanType {
-    /// Passive Scanning. No scanning PDUs shall be sent (default).
-    Passive = 0x00,
-    /// Active scanning. Scanning PDUs may be sent.
-    Active = 0x01,
+pub struct BigParameters {
+    /// Identifies the BIG.
+    pub big_handle: BigHandle,
+
+    /// Advertising set whose periodic advertising train carries the BIGInfo.
+    pub adv_handle: AdvertisingHandle,
+
+    /// Number of BISes in the BIG, from 1 to 31.
+    pub num_bis: u8,
+
+    /// Interval between the start of consecutive SDUs, with a resolution of 1 µs. Shall be
+    /// between 255 µs and 1,048,575 µs.
+    pub sdu_interval: Duration,
+
+    /// Maximum size of an SDU, from 0x001 to 0xFFF bytes.
+    pub max_sdu: u16,
+
+    /// Maximum transport latency, with a resolution of 1 ms. Shall be between 5 ms and 4 s.
+    pub max_transport_latency: Duration,
+
+    /// Number of times every PDU should be retransmitted.
+    pub rtn: u8,
+
+    /// PHYs that may be used to transmit the BIS data PDUs.
+    pub phy: PhyMask,
+
+    /// Preferred arrangement of the BISes.
+    pub packing: Packing,
+
+    /// Format of the BIS data PDUs.
+    pub framing: Framing,
+
+    /// Code used to derive the session key that encrypts the BISes. `None` if the BISes are not
+    /// encrypted.
+    pub broadcast_code: Option<[u8; 16]>,
 }
 
-/// Which advertising packets to accept from a scan.
+impl BigParameters {
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<(), Error> {
+        assert_eq!(bytes.len(), 31);
+
+        if self.num_bis == 0 || usize::from(self.num_bis) > MAX_BIS_COUNT {
+            return Err(Error::BadNumBis(self.num_bis));
+        }
+        validate_sdu_interval(self.sdu_interval)?;
+        validate_max_transport_latency(self.max_transport_latency)?;
+
+        bytes[0] = self.big_handle.0;
+        bytes[1] = self.adv_handle.0;
+        bytes[2] = self.num_bis;
+        LittleEndian::write_u24(&mut bytes[3..], self.sdu_interval.as_micros() as u32);
+        LittleEndian::write_u16(&mut bytes[6..], self.max_sdu);
+        LittleEndian::write_u16(
+            &mut bytes[8..],
+            self.max_transport_latency.as_millis() as u16,
+        );
+        bytes[10] = self.rtn;
+        bytes[11] = self.phy.bits();
+        bytes[12] = self.packing as u8;
+        bytes[13] = self.framing as u8;
+        copy_broadcast_code(self.broadcast_code, &mut bytes[14..31]);
+
+        Ok(())
+    }
+}
+
+// Serializes the encryption flag (1 byte) followed by the broadcast code (16 bytes).
+fn copy_broadcast_code(broadcast_code: Option<[u8; 16]>, bytes: &mut [u8]) {
+    if let Some(code) = broadcast_code {
+        bytes[0] = 1;
+        bytes[1..17].copy_from_slice(&code);
+    }
+}
+
+/// Parameters for the [`le_big_create_sync`](HostHci::le_big_create_sync) command.
 ///
-/// See [`ScanParameters`] and [`le_set_scan_parameters`](HostHci::le_set_scan_parameters).
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.106.
 #[derive(Copy, Clone, Debug, PartialEq)]
-#[repr(u8)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub enum ScanFilterPolicy {
-    /// Accept all advertising packets except directed advertising packets not addressed to this
-    /// device (default).
-    AcceptAll = 0x00,
-    /// Accept only advertising packets from devices where the advertiser’s address is in the White
-    /// List. Directed advertising packets which are not addressed to this device shall be ignored.
-    WhiteList = 0x01,
-    /// Accept all advertising packets except directed advertising packets where the initiator's
-    /// identity address does not address this device.
-    ///
-    /// Note: Directed advertising packets where the initiator's address is a resolvable private
-    /// address that cannot be resolved are also accepted.
-    AddressedToThisDevice = 0x02,
-    /// Accept all advertising packets except:
-    /// - advertising packets where the advertiser's identity address is not in the White List; and
-    /// - directed advertising packets where the initiator's identity addressdoes not address this
-    ///   device
-    ///
-    /// Note: Directed advertising packets where the initiator's address is a resolvable private
-    /// address that cannot be resolved are also accepted.
-    WhiteListAddressedToThisDevice = 0x03,
+pub struct BigSyncParameters<'a> {
+    /// Identifies the BIG.
+    pub big_handle: BigHandle,
+
+    /// Periodic advertising train that carries the BIGInfo of the BIG.
+    pub sync_handle: SyncHandle,
+
+    /// Code used to derive the session key that decrypts the BISes. `None` if the BISes are not
+    /// encrypted.
+    pub broadcast_code: Option<[u8; 16]>,
+
+    /// Maximum number of subevents that the Controller should use to receive data payloads in
+    /// each BIS event, from 0x00 (any) to 0x1F.
+    pub mse: u8,
+
+    /// Synchronization timeout for the BIS PDUs, with a resolution of 10 ms. Shall be between
+    /// 100 ms and 163.84 s.
+    pub big_sync_timeout: Duration,
+
+    /// Indices of the BISes to synchronize with, from 0x01 to 0x1F.
+    pub bis: &'a [u8],
+}
+
+impl BigSyncParameters<'_> {
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<usize, Error> {
+        const BIG_SYNC_TIMEOUT_RANGE: core::ops::RangeInclusive<Duration> =
+            Duration::from_millis(100)..=Duration::from_millis(163_840);
+
+        if !BIG_SYNC_TIMEOUT_RANGE.contains(&self.big_sync_timeout) {
+            return Err(Error::BadBigSyncTimeout(self.big_sync_timeout));
+        }
+        if self.bis.len() > MAX_BIS_COUNT {
+            return Err(Error::TooManyBis(self.bis.len()));
+        }
+
+        bytes[0] = self.big_handle.0;
+        LittleEndian::write_u16(&mut bytes[1..], self.sync_handle.0);
+        copy_broadcast_code(self.broadcast_code, &mut bytes[3..20]);
+        bytes[20] = self.mse;
+        LittleEndian::write_u16(
+            &mut bytes[21..],
+            to_units(self.big_sync_timeout, Duration::from_millis(10)).unwrap(),
+        );
+        bytes[23] = self.bis.len() as u8;
+        let end = 24 + self.bis.len();
+        bytes[24..end].copy_from_slice(self.bis);
+
+        Ok(end)
+    }
 }
 
-/// Parameters for the [`le_create_connection`](HostHci::le_create_connection`) event.
-#[derive(Clone, Debug)]
-pub struct ConnectionParameters {
-    /// Recommendation from the host on how frequently the Controller should scan.
-    pub scan_window: ScanWindow,
+/// Parameters for the
+/// [`le_set_path_loss_reporting_parameters`](HostHci::le_set_path_loss_reporting_parameters)
+/// command.
+///
+/// The path loss is divided into three zones by the thresholds. A zone is only considered to be
+/// entered once the path loss has crossed its threshold by more than the hysteresis, and has
+/// stayed there for at least [`min_time_spent`](PathLossReportingParameters::min_time_spent)
+/// connection events.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.119.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PathLossReportingParameters {
+    /// ACL connection whose path loss is monitored.
+    pub conn_handle: ConnectionHandle,
 
-    /// Determines whether the White List is used.  If the White List is not used, `peer_address`
-    /// specifies the address type and address of the advertising device to connect to.
-    pub initiator_filter_policy: ConnectionFilterPolicy,
+    /// Path loss, in dB, above which the path loss is in the high zone, from 0 to 254. `None`
+    /// if the high threshold is not used.
+    pub high_threshold: Option<u8>,
 
-    /// Indicates the type and value of the address used in the connectable advertisement sent by
-    /// the peer. The Host shall not use [`PublicIdentityAddress`](PeerAddrType) or
-    /// [`RandomIdentityAddress`](PeerAddrType) (both introduced in v4.2) if both the Host and the
-    /// Controller support the `le_set_privacy_mode` command (introduced in v5.0). If a Controller
-    /// that supports the LE Set Privacy Mode command receives the
-    /// [`le_create_connection`](HostHci::le_create_connection) command with `peer_address` set to
-    /// either [`PublicIdentityAddress`](PeerAddrType) or [`RandomIdentityAddress`](PeerAddrType),
-    /// it may use either device privacy mode or network privacy mode for that peer device.
-    pub peer_address: PeerAddrType,
+    /// Hysteresis of the high threshold, in dB.
+    pub high_hysteresis: u8,
 
-    /// The type of address being used in the connection request packets.
-    ///
-    /// If this is [`Random`](OwnAddressType::Random) and the random address for the device has not
-    /// been initialized, the Controller shall return the error code
-    /// [`Status::InvalidParameters`].
-    ///
-    /// If this is [`PrivateFallbackRemote`](OwnAddressType), `initiator_filter_policy` is
-    /// [`UseAddress`](ConnectionFilterPolicy::UseAddress), the controller's resolving list did not
-    /// contain a matching entry, and the random address for the device has not been initialized,
-    /// the Controller shall return the error code [`Status::InvalidParameters`].
-    ///
-    /// If this is set [`PrivateFallbackRandom`](`OwnAddressType`), `initiator_filter_policy` is
-    /// [`WhiteList`](ConnectionFilterPolicy::WhiteList), and the random address for the device has
-    /// not been initialized, the Controller shall return the error code
-    /// [`Status::InvalidParameters`].
-    pub own_address_type: OwnAddressType,
+    /// Path loss, in dB, below which the path loss is in the low zone, from 0 to 254. `None` if
+    /// the low threshold is not used.
+    pub low_threshold: Option<u8>,
 
-    /// Defines the minimum and maximum allowed connection interval, latency, and supervision
-    /// timeout.
-    pub conn_interval: ConnectionInterval,
+    /// Hysteresis of the low threshold, in dB.
+    pub low_hysteresis: u8,
 
-    /// Informative parameters providing the Controller with the expected minimum and maximum length
-    /// of the connection events.
-    pub expected_connection_length: ExpectedConnectionLength,
+    /// Minimum number of connection events spent in a zone before it is reported.
+    pub min_time_spent: u16,
 }
 
-impl ConnectionParameters {
+impl PathLossReportingParameters {
     fn copy_into_slice(&self, bytes: &mut [u8]) {
-        assert_eq!(bytes.len(), 25);
+        assert_eq!(bytes.len(), 8);
 
-        self.scan_window.copy_into_slice(&mut bytes[0..4]);
-        bytes[4] = self.initiator_filter_policy as u8;
-        match self.initiator_filter_policy {
-            ConnectionFilterPolicy::UseAddress => {
-                self.peer_address.copy_into_slice(&mut bytes[5..12]);
-            }
-            ConnectionFilterPolicy::WhiteList => {
-                bytes[5..12].copy_from_slice(&[0; 7]);
-            }
-        }
-        bytes[12] = self.own_address_type as u8;
-        self.conn_interval.copy_into_slice(&mut bytes[13..21]);
-        self.expected_connection_length
-            .copy_into_slice(&mut bytes[21..25]);
+        LittleEndian::write_u16(&mut bytes[0..], self.conn_handle.0);
+        bytes[2] = self.high_threshold.unwrap_or(0xFF);
+        bytes[3] = self.high_hysteresis;
+        bytes[4] = self.low_threshold.unwrap_or(0xFF);
+        bytes[5] = self.low_hysteresis;
+        LittleEndian::write_u16(&mut bytes[6..], self.min_time_spent);
     }
 }
 
-/// Possible values for the initiator filter policy in the
-/// [`le_create_connection`](HostHci::le_create_connection) command.
-#[derive(Copy, Clone, Debug)]
-#[repr(u8)]
+// The maximum number of antenna IDs in an antenna switching pattern.
+const MAX_SWITCHING_PATTERN_LEN: usize = 0x4B;
+
+// Serializes the antenna switching pattern as its length (1 byte) followed by the antenna IDs.
+// Returns the number of bytes written.
+fn copy_switching_pattern(antenna_ids: &[u8], bytes: &mut [u8]) -> Result<usize, Error> {
+    const SWITCHING_PATTERN_LEN_RANGE: core::ops::RangeInclusive<usize> =
+        0x02..=MAX_SWITCHING_PATTERN_LEN;
+
+    if !SWITCHING_PATTERN_LEN_RANGE.contains(&antenna_ids.len()) {
+        return Err(Error::BadSwitchingPatternLength(antenna_ids.len()));
+    }
+
+    bytes[0] = antenna_ids.len() as u8;
+    bytes[1..=antenna_ids.len()].copy_from_slice(antenna_ids);
+    Ok(1 + antenna_ids.len())
+}
+
+// Converts the length of a Constant Tone Extension to units of 8 µs.
+fn to_cte_length(cte_length: Duration) -> Result<u8, Error> {
+    const CTE_LENGTH_RANGE: core::ops::RangeInclusive<Duration> =
+        Duration::from_micros(16)..=Duration::from_micros(160);
+
+    if !CTE_LENGTH_RANGE.contains(&cte_length) {
+        return Err(Error::BadCteLength(cte_length));
+    }
+
+    Ok((cte_length.as_micros() / 8) as u8)
+}
+
+/// Parameters for the
+/// [`le_set_connectionless_cte_transmit_parameters`](HostHci::le_set_connectionless_cte_transmit_parameters)
+/// command.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.80.
+#[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub enum ConnectionFilterPolicy {
-    /// White List is not used to determine which advertiser to connect to.  `peer_address` shall be
-    /// used in the connection complete event.
-    UseAddress = 0x00,
+pub struct ConnectionlessCteTransmitParameters<'a> {
+    /// Advertising set whose periodic advertising carries the Constant Tone Extensions.
+    pub adv_handle: AdvertisingHandle,
 
-    /// White List is used to determine which advertiser to connect to. `peer_address` shall be
-    /// ignored in the connection complete event.
-    WhiteList = 0x01,
+    /// Length of each Constant Tone Extension, with a resolution of 8 µs. Shall be between 16 µs
+    /// and 160 µs.
+    pub cte_length: Duration,
+
+    /// Type of the Constant Tone Extensions.
+    pub cte_type: CteType,
+
+    /// Number of Constant Tone Extensions to transmit in each periodic advertising interval, from
+    /// 0x01 to 0x10.
+    pub cte_count: u8,
+
+    /// Antenna IDs making up the antenna switching pattern, from 2 to 75 of them. Only used for
+    /// AoD Constant Tone Extensions.
+    pub antenna_ids: &'a [u8],
 }
 
-/// Possible values for the peer address in the [`le_create_connection`](HostHci::le_create_connection)
+impl ConnectionlessCteTransmitParameters<'_> {
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<usize, Error> {
+        const CTE_COUNT_RANGE: core::ops::RangeInclusive<u8> = 0x01..=0x10;
+
+        if !CTE_COUNT_RANGE.contains(&self.cte_count) {
+            return Err(Error::BadCteCount(self.cte_count));
+        }
+
+        bytes[0] = self.adv_handle.0;
+        bytes[1] = to_cte_length(self.cte_length)?;
+        bytes[2] = self.cte_type.into();
+        bytes[3] = self.cte_count;
+        Ok(4 + copy_switching_pattern(self.antenna_ids, &mut bytes[4..])?)
+    }
+}
+
+/// Parameters for the
+/// [`le_set_connectionless_iq_sampling_enable`](HostHci::le_set_connectionless_iq_sampling_enable)
 /// command.
-#[derive(Copy, Clone, Debug)]
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.82.
+#[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub enum PeerAddrType {
-    /// Public Device Address
-    PublicDeviceAddress(crate::BdAddr),
-    /// Random Device Address
-    RandomDeviceAddress(crate::BdAddr),
-    /// Public Identity Address (Corresponds to peer's Resolvable Private Address). This value shall
-    /// only be used by the Host if either the Host or the Controller does not support the LE Set
-    /// Privacy Mode command.
-    PublicIdentityAddress(crate::BdAddr),
-    /// Random (static) Identity Address (Corresponds to peer’s Resolvable Private Address). This
-    /// value shall only be used by a Host if either the Host or the Controller does not support the
-    /// LE Set Privacy Mode command.
-    RandomIdentityAddress(crate::BdAddr),
+pub struct ConnectionlessIqSamplingParameters<'a> {
+    /// Periodic advertising train whose Constant Tone Extensions are sampled.
+    pub sync_handle: SyncHandle,
+
+    /// Whether IQ sampling is enabled. The other parameters are ignored when it is disabled.
+    pub sampling_enable: bool,
+
+    /// Durations of the switching and sampling slots.
+    pub slot_duration: SlotDuration,
+
+    /// Maximum number of Constant Tone Extensions to sample and report in each periodic
+    /// advertising interval, from 0x01 to 0x10. 0x00 to sample and report all of them.
+    pub max_sampled_ctes: u8,
+
+    /// Antenna IDs making up the antenna switching pattern, from 2 to 75 of them.
+    pub antenna_ids: &'a [u8],
 }
 
-impl PeerAddrType {
-    /// Serialize the peer address into the given byte buffer.
-    ///
-    /// # Panics
-    ///
-    /// `bytes` must be 7 bytes long.
-    pub fn copy_into_slice(&self, bytes: &mut [u8]) {
-        assert_eq!(bytes.len(), 7);
-        match *self {
-            PeerAddrType::PublicDeviceAddress(bd_addr) => {
-                bytes[0] = 0x00;
-                bytes[1..7].copy_from_slice(&bd_addr.0);
-            }
-            PeerAddrType::RandomDeviceAddress(bd_addr) => {
-                bytes[0] = 0x01;
-                bytes[1..7].copy_from_slice(&bd_addr.0);
-            }
-            PeerAddrType::PublicIdentityAddress(bd_addr) => {
-                bytes[0] = 0x02;
-                bytes[1..7].copy_from_slice(&bd_addr.0);
-            }
-            PeerAddrType::RandomIdentityAddress(bd_addr) => {
-                bytes[0] = 0x03;
-                bytes[1..7].copy_from_slice(&bd_addr.0);
-            }
+impl ConnectionlessIqSamplingParameters<'_> {
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<usize, Error> {
+        const MAX_SAMPLED_CTES_MAX: u8 = 0x10;
+
+        if self.max_sampled_ctes > MAX_SAMPLED_CTES_MAX {
+            return Err(Error::BadMaxSampledCtes(self.max_sampled_ctes));
         }
+
+        LittleEndian::write_u16(&mut bytes[0..], self.sync_handle.0);
+        bytes[2] = self.sampling_enable as u8;
+        bytes[3] = self.slot_duration.into();
+        bytes[4] = self.max_sampled_ctes;
+        if !self.sampling_enable {
+            // The Controller ignores the antenna switching pattern when sampling is disabled.
+            bytes[5] = 0;
+            return Ok(6);
+        }
+        Ok(5 + copy_switching_pattern(self.antenna_ids, &mut bytes[5..])?)
     }
 }
 
-/// Parameters for the [`le_connection_update`](HostHci::le_connection_update) command.
+/// Parameters for the
+/// [`le_set_connection_cte_receive_parameters`](HostHci::le_set_connection_cte_receive_parameters)
+/// command.
 ///
-/// See the Bluetooth spec, Vol 2, Part E, Section 7.8.18.
-pub struct ConnectionUpdateParameters {
-    /// Handle for identifying a connection.
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.83.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ConnectionCteReceiveParameters<'a> {
+    /// ACL connection whose Constant Tone Extensions are sampled.
     pub conn_handle: ConnectionHandle,
 
-    /// Defines the connection interval, latency, and supervision timeout.
-    pub conn_interval: ConnectionInterval,
+    /// Whether IQ sampling is enabled. The other parameters are ignored when it is disabled.
+    pub sampling_enable: bool,
 
-    /// Information parameters providing the Controller with a hint about the expected minimum and
-    /// maximum length of the connection events.
-    pub expected_connection_length: ExpectedConnectionLength,
-}
+    /// Durations of the switching and sampling slots.
+    pub slot_duration: SlotDuration,
 
-impl ConnectionUpdateParameters {
-    fn copy_into_slice(&self, bytes: &mut [u8]) {
-        assert_eq!(bytes.len(), 14);
+    /// Antenna IDs making up the antenna switching pattern, from 2 to 75 of them.
+    pub antenna_ids: &'a [u8],
+}
 
+impl ConnectionCteReceiveParameters<'_> {
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<usize, Error> {
         LittleEndian::write_u16(&mut bytes[0..], self.conn_handle.0);
-        self.conn_interval.copy_into_slice(&mut bytes[2..10]);
-        self.expected_connection_length
-            .copy_into_slice(&mut bytes[10..14]);
+        bytes[2] = self.sampling_enable as u8;
+        bytes[3] = self.slot_duration.into();
+        if !self.sampling_enable {
+            // The Controller ignores the antenna switching pattern when sampling is disabled.
+            bytes[4] = 0;
+            return Ok(5);
+        }
+        Ok(4 + copy_switching_pattern(self.antenna_ids, &mut bytes[4..])?)
     }
 }
 
-/// Parameters for the [`le_encrypt`](HostHci::le_encrypt) command.
-#[derive(Clone, Debug)]
-pub struct AesParameters {
-    /// Key for the encryption of the data given in the command.
-    ///
-    /// The most significant (last) octet of the key corresponds to `key[0]` using the notation
-    /// specified in FIPS 197.
-    pub key: EncryptionKey,
+#[cfg(not(feature = "defmt"))]
+bitflags::bitflags! {
+    /// Types of Constant Tone Extension, for the
+    /// [`le_set_connection_cte_transmit_parameters`](HostHci::le_set_connection_cte_transmit_parameters)
+    /// command.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct CteTypes : u8 {
+        /// AoA Constant Tone Extension
+        const AOA = 0x01;
+        /// AoD Constant Tone Extension with 1 μs slots
+        const AOD_1US = 0x02;
+        /// AoD Constant Tone Extension with 2 μs slots
+        const AOD_2US = 0x04;
+    }
+}
 
-    /// Data block that is requested to be encrypted.
-    ///
-    /// The most significant (last) octet of the PlainText_Data corresponds to `in[0]` using the
-    /// notation specified in FIPS 197.
-    pub plaintext_data: PlaintextBlock,
+#[cfg(feature = "defmt")]
+defmt::bitflags! {
+    /// Types of Constant Tone Extension, for the
+    /// [`le_set_connection_cte_transmit_parameters`](HostHci::le_set_connection_cte_transmit_parameters)
+    /// command.
+    #[derive(Default)]
+    pub struct CteTypes : u8 {
+        /// AoA Constant Tone Extension
+        const AOA = 0x01;
+        /// AoD Constant Tone Extension with 1 μs slots
+        const AOD_1US = 0x02;
+        /// AoD Constant Tone Extension with 2 μs slots
+        const AOD_2US = 0x04;
+    }
 }
 
-/// Newtype for the encryption key.
+/// Parameters for the
+/// [`le_set_connection_cte_transmit_parameters`](HostHci::le_set_connection_cte_transmit_parameters)
+/// command.
 ///
-/// See [`AesParameters`]
-#[derive(Clone, PartialEq)]
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.84.
+#[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct EncryptionKey(pub [u8; 16]);
+pub struct ConnectionCteTransmitParameters<'a> {
+    /// ACL connection on which Constant Tone Extensions are transmitted.
+    pub conn_handle: ConnectionHandle,
 
-impl Debug for EncryptionKey {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        write!(f, "AES-128 Key ({:X?})", self.0)
-    }
-}
+    /// Types of Constant Tone Extension that may be transmitted.
+    pub cte_types: CteTypes,
 
-/// Newtype for the plaintext data.
-///
-/// See [`AesParameters`].
-#[derive(Clone)]
-pub struct PlaintextBlock(pub [u8; 16]);
+    /// Antenna IDs making up the antenna switching pattern, from 2 to 75 of them. Only used for
+    /// AoD Constant Tone Extensions.
+    pub antenna_ids: &'a [u8],
+}
 
-impl Debug for PlaintextBlock {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        write!(f, "AES-128 Plaintext (REDACTED)")
+impl ConnectionCteTransmitParameters<'_> {
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<usize, Error> {
+        LittleEndian::write_u16(&mut bytes[0..], self.conn_handle.0);
+        bytes[2] = self.cte_types.bits();
+        Ok(3 + copy_switching_pattern(self.antenna_ids, &mut bytes[3..])?)
     }
 }
 
-/// Parameters for the [`le_start_encryption`](HostHci::le_start_encryption) command.
-pub struct EncryptionParameters {
-    /// ID for the connection.
+/// Parameters for the
+/// [`le_connection_cte_request_enable`](HostHci::le_connection_cte_request_enable) command.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 7.8.85.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ConnectionCteRequestParameters {
+    /// ACL connection on which Constant Tone Extensions are requested.
     pub conn_handle: ConnectionHandle,
 
-    /// Random value distrubuted by the peripheral during pairing
-    pub random_number: u64,
+    /// Whether Constant Tone Extension requests are enabled. The other parameters are ignored
+    /// when they are disabled.
+    pub enable: bool,
 
-    /// Encrypted diversifier distrubuted by the peripheral during pairing
-    pub encrypted_diversifier: u16,
+    /// Number of connection events between requests. 0 to request a Constant Tone Extension only
+    /// once.
+    pub cte_request_interval: u16,
 
-    /// Encryption key, distributed by the host.
-    pub long_term_key: EncryptionKey,
+    /// Minimum length of the requested Constant Tone Extensions, with a resolution of 8 µs. Shall
+    /// be between 16 µs and 160 µs.
+    pub requested_cte_length: Duration,
+
+    /// Type of the requested Constant Tone Extensions.
+    pub requested_cte_type: CteType,
 }
 
-/// Possible values of the `payload` parameter for the
-/// [`le_transmitter_test`](HostHci::le_transmitter_test) command.
-#[derive(Copy, Clone, Debug)]
-#[repr(u8)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub enum TestPacketPayload {
-    /// Pseudo-Random bit sequence 9
-    PrbS9 = 0x00,
-    /// Pattern of alternating bits `11110000
-    Nibbles10 = 0x01,
-    /// Pattern of alternating bits `10101010'
-    Bits10 = 0x02,
-    /// Pseudo-Random bit sequence 15
-    PrbS15 = 0x03,
-    /// Pattern of All `1' bits
-    All1 = 0x04,
-    /// Pattern of All `0' bits
-    All0 = 0x05,
-    /// Pattern of alternating bits `00001111
-    Nibbles01 = 0x06,
-    /// Pattern of alternating bits `0101'
-    Bits01 = 0x07,
+impl ConnectionCteRequestParameters {
+    fn copy_into_slice(&self, bytes: &mut [u8]) -> Result<(), Error> {
+        assert_eq!(bytes.len(), 7);
+
+        LittleEndian::write_u16(&mut bytes[0..], self.conn_handle.0);
+        bytes[2] = self.enable as u8;
+        LittleEndian::write_u16(&mut bytes[3..], self.cte_request_interval);
+        bytes[5] = to_cte_length(self.requested_cte_length)?;
+        bytes[6] = self.requested_cte_type.into();
+
+        Ok(())
+    }
 }
@@ -0,0 +1,272 @@
+//! Flow control of ACL data sent by the controller to the host.
+//!
+//! When controller to host flow control is enabled, the host tells the controller how many ACL data
+//! packets it can buffer with the [Host Buffer Size](super::HostHci::host_buffer_size) command, and
+//! the controller does not send more packets than that until the host reports buffers free again
+//! with the [Number of Completed Packets](super::HostHci::number_of_completed_packets) command.
+//! When a connection is closed, the controller considers the buffers holding its packets free once
+//! it sends the [Disconnection Complete](crate::event::Event::DisconnectionComplete) event, and the
+//! host shall not report them. See the Bluetooth spec, Vol 4, Part E, Section 4.2.
+//!
+//! [`ReceivePool`] wraps a [`Controller`] to do that bookkeeping for the ACL data packets read
+//! through it.
+
+use byteorder::{ByteOrder, LittleEndian};
+
+use crate::event::{NumberOfCompletedPackets, NumberOfCompletedPacketsPair};
+use crate::host::{FlowControl, HostBufferSize, HostHci};
+use crate::{ConnectionHandle, Controller, DataController, Opcode};
+
+const PACKET_TYPE_ACL_DATA: u8 = 0x02;
+const PACKET_TYPE_HCI_EVENT: u8 = 0x04;
+const EVENT_DISCONNECTION_COMPLETE: u8 = 0x05;
+
+/// Wraps a [`Controller`] to manage a pool of host buffers for ACL data packets received from the
+/// controller.
+///
+/// The wrapper implements [`Controller`] itself, so all of the command traits are available on it.
+/// Each ACL data packet read through it occupies a buffer until the application
+/// [releases](ReceivePool::release) it. Released buffers are reported back to the controller in
+/// batches, in a single [Number of Completed Packets](super::HostHci::number_of_completed_packets)
+/// command for all connections.
+///
+/// Packets must be read whole, as [`UartHci::read`](super::uart::UartHci::read) and
+/// [`UartHci::read_into`](super::uart::UartHci::read_into) do. Up to `N` connections may hold
+/// buffers at the same time. The pool cannot hold packets received on other connections, so their
+/// buffers are reported back to the controller as soon as they are read, and they are not counted
+/// in [`in_use`](ReceivePool::in_use).
+pub struct ReceivePool<C, const N: usize = 8> {
+    controller: C,
+    acl_data_packet_length: u16,
+    total_acl_data_packets: u16,
+    batch_size: usize,
+    in_use: usize,
+    unreported: usize,
+    connections: [Option<Buffers>; N],
+}
+
+#[derive(Copy, Clone)]
+struct Buffers {
+    conn_handle: ConnectionHandle,
+    held: usize,
+    completed: usize,
+}
+
+impl<C, const N: usize> ReceivePool<C, N> {
+    /// Wraps the given controller, with a pool of `total_acl_data_packets` buffers that each hold
+    /// up to `acl_data_packet_length` bytes of ACL data.
+    ///
+    /// Released buffers are reported once half of the pool has been released, or one buffer for a
+    /// pool of one.
+    pub fn new(controller: C, acl_data_packet_length: u16, total_acl_data_packets: u16) -> Self {
+        Self {
+            controller,
+            acl_data_packet_length,
+            total_acl_data_packets,
+            batch_size: (total_acl_data_packets as usize / 2).max(1),
+            in_use: 0,
+            unreported: 0,
+            connections: [None; N],
+        }
+    }
+
+    /// Sets the number of released buffers that triggers a report to the controller. Values
+    /// smaller than 1 are treated as 1.
+    ///
+    /// A batch size larger than the pool means buffers are only reported by
+    /// [`flush`](ReceivePool::flush).
+    pub fn set_batch_size(&mut self, batch_size: usize) {
+        self.batch_size = batch_size.max(1);
+    }
+
+    /// Returns the parameters that advertise the pool to the controller through the
+    /// [Host Buffer Size](super::HostHci::host_buffer_size) command. Synchronous data is not
+    /// buffered.
+    pub fn buffer_size(&self) -> HostBufferSize {
+        HostBufferSize {
+            acl_data_packet_length: self.acl_data_packet_length,
+            sync_data_packet_length: 0,
+            total_acl_data_packets: self.total_acl_data_packets,
+            total_sync_data_packets: 0,
+        }
+    }
+
+    /// Returns the number of buffers holding packets that the controller considers in use. This
+    /// includes buffers released but not reported yet.
+    pub fn in_use(&self) -> usize {
+        self.in_use
+    }
+
+    /// Returns the number of buffers holding packets received on the connection that have not
+    /// been released.
+    pub fn held(&self, conn_handle: ConnectionHandle) -> usize {
+        self.find(conn_handle)
+            .map_or(0, |i| self.connections[i].unwrap().held)
+    }
+
+    /// Returns a reference to the wrapped controller.
+    pub fn controller(&self) -> &C {
+        &self.controller
+    }
+
+    /// Returns a mutable reference to the wrapped controller.
+    ///
+    /// Packets read directly through the wrapped controller do not occupy buffers.
+    pub fn controller_mut(&mut self) -> &mut C {
+        &mut self.controller
+    }
+
+    /// Returns the wrapped controller.
+    pub fn into_inner(self) -> C {
+        self.controller
+    }
+
+    fn find(&self, conn_handle: ConnectionHandle) -> Option<usize> {
+        self.connections
+            .iter()
+            .position(|c| matches!(c, Some(c) if c.conn_handle == conn_handle))
+    }
+
+    /// Returns false if the packet cannot be held because `N` other connections hold buffers.
+    fn packet_received(&mut self, conn_handle: ConnectionHandle) -> bool {
+        if let Some(i) = self.find(conn_handle) {
+            self.connections[i].as_mut().unwrap().held += 1;
+        } else if let Some(slot) = self.connections.iter_mut().find(|c| c.is_none()) {
+            *slot = Some(Buffers {
+                conn_handle,
+                held: 1,
+                completed: 0,
+            });
+        } else {
+            return false;
+        }
+        self.in_use += 1;
+        true
+    }
+
+    fn disconnected(&mut self, conn_handle: ConnectionHandle) {
+        if let Some(i) = self.find(conn_handle) {
+            let buffers = self.connections[i].unwrap();
+            self.connections[i] = None;
+            self.in_use -= buffers.held + buffers.completed;
+            self.unreported -= buffers.completed;
+        }
+    }
+
+    /// Returns the connection handle of an ACL data packet that cannot be held.
+    fn update_from_packet(&mut self, packet: &[u8]) -> Option<ConnectionHandle> {
+        if packet.len() < 3 {
+            return None;
+        }
+
+        match packet[0] {
+            PACKET_TYPE_ACL_DATA => {
+                let conn_handle = ConnectionHandle(LittleEndian::read_u16(&packet[1..]) & 0x0FFF);
+                if !self.packet_received(conn_handle) {
+                    return Some(conn_handle);
+                }
+            }
+            // Event code (1), parameter length (1), status (1), connection handle (2), reason (1).
+            PACKET_TYPE_HCI_EVENT
+                if packet.len() >= 6
+                    && packet[1] == EVENT_DISCONNECTION_COMPLETE
+                    && packet[3] == 0 =>
+            {
+                self.disconnected(ConnectionHandle(LittleEndian::read_u16(&packet[4..])));
+            }
+            _ => (),
+        }
+
+        None
+    }
+}
+
+impl<C: Controller, const N: usize> ReceivePool<C, N> {
+    /// Advertises the pool to the controller with the
+    /// [Host Buffer Size](super::HostHci::host_buffer_size) command, then enables flow control for
+    /// ACL data with the
+    /// [Set Controller to Host Flow Control](super::HostHci::set_controller_to_host_flow_control)
+    /// command.
+    ///
+    /// Flow control shall only be enabled while no connections exist.
+    pub async fn enable(&mut self) {
+        self.host_buffer_size(self.buffer_size()).await;
+        self.set_controller_to_host_flow_control(FlowControl::HciAclDataOnly)
+            .await;
+    }
+
+    /// Releases `count` buffers holding packets received on the connection, once the application
+    /// is done with them. If the number of released buffers not yet reported reaches the batch
+    /// size, reports them to the controller.
+    ///
+    /// Releasing more buffers than the connection holds releases all of them. Buffers of unknown
+    /// or disconnected connections are ignored.
+    pub async fn release(&mut self, conn_handle: ConnectionHandle, count: usize) {
+        if let Some(i) = self.find(conn_handle) {
+            let buffers = self.connections[i].as_mut().unwrap();
+            let count = count.min(buffers.held);
+            buffers.held -= count;
+            buffers.completed += count;
+            self.unreported += count;
+        }
+
+        if self.unreported >= self.batch_size {
+            self.flush().await;
+        }
+    }
+
+    /// Reports all released buffers to the controller in a
+    /// [Number of Completed Packets](super::HostHci::number_of_completed_packets) command. Nothing
+    /// is sent if no buffers were released since the last report.
+    pub async fn flush(&mut self) {
+        if self.unreported == 0 {
+            return;
+        }
+
+        let params = NumberOfCompletedPackets::new(self.connections.iter().flatten().filter_map(
+            |buffers| {
+                (buffers.completed > 0).then_some(NumberOfCompletedPacketsPair {
+                    conn_handle: buffers.conn_handle,
+                    num_completed_packets: buffers.completed,
+                })
+            },
+        ));
+        self.number_of_completed_packets(params).await;
+
+        for slot in self.connections.iter_mut() {
+            if let Some(buffers) = slot {
+                buffers.completed = 0;
+                if buffers.held == 0 {
+                    *slot = None;
+                }
+            }
+        }
+        self.in_use -= self.unreported;
+        self.unreported = 0;
+    }
+}
+
+impl<C: Controller, const N: usize> Controller for ReceivePool<C, N> {
+    async fn controller_write(&mut self, opcode: Opcode, payload: &[u8]) {
+        self.controller.controller_write(opcode, payload).await;
+    }
+
+    async fn controller_read_into(&mut self, buffer: &mut [u8]) {
+        self.controller.controller_read_into(buffer).await;
+        if let Some(conn_handle) = self.update_from_packet(buffer) {
+            self.number_of_completed_packets(NumberOfCompletedPackets::new(core::iter::once(
+                NumberOfCompletedPacketsPair {
+                    conn_handle,
+                    num_completed_packets: 1,
+                },
+            )))
+            .await;
+        }
+    }
+}
+
+impl<C: DataController, const N: usize> DataController for ReceivePool<C, N> {
+    async fn controller_write_data(&mut self, header: &[u8], payload: &[u8]) {
+        self.controller.controller_write_data(header, payload).await;
+    }
+}
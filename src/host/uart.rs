@@ -1,11 +1,16 @@
 //! Implementation of the HCI that includes the packet ID byte in the header.
 
 use byteorder::{ByteOrder, LittleEndian};
+use core::convert::TryFrom;
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+use crate::ConnectionHandle;
 
 const PACKET_TYPE_HCI_COMMAND: u8 = 0x01;
-// const PACKET_TYPE_ACL_DATA: u8 = 0x02;
-// const PACKET_TYPE_SYNC_DATA: u8 = 0x03;
+const PACKET_TYPE_ACL_DATA: u8 = 0x02;
+const PACKET_TYPE_SYNC_DATA: u8 = 0x03;
 const PACKET_TYPE_HCI_EVENT: u8 = 0x04;
+const PACKET_TYPE_ISO_DATA: u8 = 0x05;
 
 /// Potential errors from reading or writing packets to the controller.
 ///
@@ -16,6 +21,19 @@ pub enum Error {
     /// The host expected the controller to begin a packet, but the next byte is not a valid packet
     /// type byte. Contains the value of the byte.
     BadPacketType(u8),
+    /// For ACL data packets, the broadcast flag has a reserved value. Contains the value of the
+    /// flag.
+    BadBroadcastFlag(u8),
+    /// For ISO data packets, the packet status flag has a reserved value. Contains the value of
+    /// the flag.
+    BadPacketStatusFlag(u8),
+    /// The data packet is too long to be sent or received: its length does not fit in the header,
+    /// or is larger than the receive buffer. Contains the length of the data, or 0 if the receive
+    /// buffer is too short to contain the packet header.
+    DataTooLong(usize),
+    /// For ISO data packets, the data load is too short to contain the SDU header indicated by the
+    /// packet boundary and timestamp flags. Contains the length of the data load.
+    DataTooShort(usize),
     /// There was an error deserializing an event. Contains the underlying error.
     BLE(crate::event::Error),
 }
@@ -24,19 +42,771 @@ pub enum Error {
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Packet {
-    // AclData(AclData),
-    // SyncData(SyncData),
+    /// The HCI ACL Data Packet is used to exchange data between the Host and Controller, for
+    /// example L2CAP PDUs.
+    AclData(AclData),
+    /// The HCI Synchronous Data Packet is used to exchange synchronous (SCO or eSCO) data between
+    /// the Host and Controller.
+    SyncData(SyncData),
+    /// The HCI ISO Data Packet is used to exchange isochronous data between the Host and
+    /// Controller.
+    IsoData(IsoData),
     /// The HCI Event Packet is used by the Controller to notify the Host when events
     /// occur. The event is specialized to support vendor-specific events.
     Event(crate::Event),
 }
 
+/// Packet types that may be read from the controller into a caller-supplied buffer by
+/// [`UartHci::read_into`].
+///
+/// Unlike [`Packet`], variable-length contents are borrowed from the buffer instead of being copied
+/// into fixed-size arrays.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BorrowedPacket<'a> {
+    /// The HCI ACL Data Packet is used to exchange data between the Host and Controller, for
+    /// example L2CAP PDUs.
+    AclData(BorrowedData<'a, AclDataHeader>),
+    /// The HCI Synchronous Data Packet is used to exchange synchronous (SCO or eSCO) data between
+    /// the Host and Controller.
+    SyncData(BorrowedData<'a, SyncDataHeader>),
+    /// The HCI ISO Data Packet is used to exchange isochronous data between the Host and
+    /// Controller.
+    IsoData(BorrowedData<'a, IsoDataHeader>),
+    /// The HCI Event Packet is used by the Controller to notify the Host when events occur.
+    Event(crate::event::borrowed::Event<'a>),
+}
+
+/// A data packet whose data is borrowed from the buffer it was read into.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BorrowedData<'a, H> {
+    /// Header of the packet.
+    pub header: H,
+
+    /// The data carried by the packet. For ISO data packets, this does not include the SDU header.
+    pub data: &'a [u8],
+}
+
 /// Header for HCI Commands.
 pub struct CommandHeader {
     opcode: crate::opcode::Opcode,
     param_len: u8,
 }
 
+/// Maximum length of the data in an ACL data packet that can be read from the controller.
+///
+/// This is the largest LE ACL data length a controller can report (see
+/// [`LeReadBufferSize`](crate::event::command::LeReadBufferSize)), which also fits the data in
+/// a single 256-byte read including the packet type and ACL header.
+pub const MAX_ACL_DATA_LEN: usize = 251;
+
+/// Header for HCI ACL data packets.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 5.4.2.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AclDataHeader {
+    /// Connection handle on which the data is sent or was received.
+    pub conn_handle: ConnectionHandle,
+
+    /// Indicates whether the packet starts or continues a higher-layer PDU.
+    pub packet_boundary: AclPacketBoundary,
+
+    /// Indicates whether the packet is sent point-to-point or broadcast.
+    pub broadcast: AclBroadcastFlag,
+}
+
+impl AclDataHeader {
+    const LENGTH: usize = 5;
+
+    fn copy_into_slice(&self, buffer: &mut [u8], data_len: usize) {
+        assert_eq!(buffer.len(), Self::LENGTH);
+
+        buffer[0] = PACKET_TYPE_ACL_DATA;
+        LittleEndian::write_u16(
+            &mut buffer[1..=2],
+            (self.conn_handle.0 & 0x0FFF)
+                | ((self.packet_boundary as u16) << 12)
+                | ((self.broadcast as u16) << 14),
+        );
+        LittleEndian::write_u16(&mut buffer[3..=4], data_len as u16);
+    }
+}
+
+/// Values for the packet boundary flag of an ACL data packet.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 5.4.2.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AclPacketBoundary {
+    /// First non-automatically-flushable packet of a higher-layer message. This is the value used
+    /// on LE-U logical links for the first fragment of an L2CAP PDU sent by the host.
+    FirstNonFlushable = 0b00,
+    /// Continuing fragment of a higher-layer message.
+    Continuing = 0b01,
+    /// First automatically-flushable packet of a higher-layer message. This is the value used by
+    /// the controller for the first fragment of an L2CAP PDU sent to the host.
+    FirstFlushable = 0b10,
+    /// A complete, automatically-flushable L2CAP PDU. Only used for BR/EDR loopback.
+    Complete = 0b11,
+}
+
+impl From<u16> for AclPacketBoundary {
+    fn from(value: u16) -> Self {
+        match value & 0b11 {
+            0b00 => AclPacketBoundary::FirstNonFlushable,
+            0b01 => AclPacketBoundary::Continuing,
+            0b10 => AclPacketBoundary::FirstFlushable,
+            _ => AclPacketBoundary::Complete,
+        }
+    }
+}
+
+/// Values for the broadcast flag of an ACL data packet.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 5.4.2.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AclBroadcastFlag {
+    /// Point-to-point data. This is the only value allowed on LE-U logical links.
+    PointToPoint = 0b00,
+    /// BR/EDR broadcast data.
+    BrEdrBroadcast = 0b01,
+}
+
+impl TryFrom<u16> for AclBroadcastFlag {
+    type Error = Error;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            0b00 => Ok(AclBroadcastFlag::PointToPoint),
+            0b01 => Ok(AclBroadcastFlag::BrEdrBroadcast),
+            _ => Err(Error::BadBroadcastFlag(value as u8)),
+        }
+    }
+}
+
+/// An ACL data packet read from the controller.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AclData {
+    /// Header of the packet.
+    pub header: AclDataHeader,
+
+    data_len: usize,
+    data_buf: [u8; MAX_ACL_DATA_LEN],
+}
+
+impl AclData {
+    /// Returns the data carried by the packet.
+    pub fn data(&self) -> &[u8] {
+        &self.data_buf[..self.data_len]
+    }
+}
+
+impl Debug for AclData {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{{{:?}, {:?}}}", self.header, self.data())
+    }
+}
+
+fn to_acl_data_ref(packet: &[u8]) -> Result<BorrowedData<'_, AclDataHeader>, Error> {
+    let handle_and_flags = LittleEndian::read_u16(&packet[0..]);
+    let data_len = LittleEndian::read_u16(&packet[2..]) as usize;
+
+    Ok(BorrowedData {
+        header: AclDataHeader {
+            conn_handle: ConnectionHandle(handle_and_flags & 0x0FFF),
+            packet_boundary: AclPacketBoundary::from(handle_and_flags >> 12),
+            broadcast: AclBroadcastFlag::try_from(handle_and_flags >> 14)?,
+        },
+        data: &packet[4..4 + data_len],
+    })
+}
+
+fn to_acl_data(packet: &[u8]) -> Result<AclData, Error> {
+    let acl = to_acl_data_ref(packet)?;
+    if acl.data.len() > MAX_ACL_DATA_LEN {
+        return Err(Error::DataTooLong(acl.data.len()));
+    }
+
+    let mut data_buf = [0; MAX_ACL_DATA_LEN];
+    data_buf[..acl.data.len()].copy_from_slice(acl.data);
+
+    Ok(AclData {
+        header: acl.header,
+        data_len: acl.data.len(),
+        data_buf,
+    })
+}
+
+/// Maximum length of the data in a synchronous data packet that can be read from the controller.
+///
+/// This is the largest length that fits in a single 256-byte read including the packet type and
+/// synchronous data header.
+pub const MAX_SYNC_DATA_LEN: usize = 252;
+
+/// Header for HCI synchronous data packets.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 5.4.3.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SyncDataHeader {
+    /// Connection handle on which the data is sent or was received.
+    pub conn_handle: ConnectionHandle,
+
+    /// Indicates the quality of the data received by the controller. Shall be
+    /// [`SyncPacketStatus::CorrectlyReceived`] for data sent to the controller.
+    pub packet_status: SyncPacketStatus,
+}
+
+impl SyncDataHeader {
+    const LENGTH: usize = 4;
+
+    fn copy_into_slice(&self, buffer: &mut [u8], data_len: usize) {
+        assert_eq!(buffer.len(), Self::LENGTH);
+
+        buffer[0] = PACKET_TYPE_SYNC_DATA;
+        LittleEndian::write_u16(
+            &mut buffer[1..=2],
+            (self.conn_handle.0 & 0x0FFF) | ((self.packet_status as u16) << 12),
+        );
+        buffer[3] = data_len as u8;
+    }
+}
+
+/// Values for the packet status flag of a synchronous data packet.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 5.4.3.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SyncPacketStatus {
+    /// Correctly received data.
+    CorrectlyReceived = 0b00,
+    /// Possibly invalid data.
+    PossiblyInvalid = 0b01,
+    /// No data received. All data is set to 0.
+    NoData = 0b10,
+    /// Data partially lost.
+    PartiallyLost = 0b11,
+}
+
+impl From<u16> for SyncPacketStatus {
+    fn from(value: u16) -> Self {
+        match value & 0b11 {
+            0b00 => SyncPacketStatus::CorrectlyReceived,
+            0b01 => SyncPacketStatus::PossiblyInvalid,
+            0b10 => SyncPacketStatus::NoData,
+            _ => SyncPacketStatus::PartiallyLost,
+        }
+    }
+}
+
+/// A synchronous data packet read from the controller.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SyncData {
+    /// Header of the packet.
+    pub header: SyncDataHeader,
+
+    data_len: usize,
+    data_buf: [u8; MAX_SYNC_DATA_LEN],
+}
+
+impl SyncData {
+    /// Returns the data carried by the packet.
+    pub fn data(&self) -> &[u8] {
+        &self.data_buf[..self.data_len]
+    }
+}
+
+impl Debug for SyncData {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{{{:?}, {:?}}}", self.header, self.data())
+    }
+}
+
+fn to_sync_data_ref(packet: &[u8]) -> Result<BorrowedData<'_, SyncDataHeader>, Error> {
+    let handle_and_flags = LittleEndian::read_u16(&packet[0..]);
+    let data_len = packet[2] as usize;
+
+    Ok(BorrowedData {
+        header: SyncDataHeader {
+            conn_handle: ConnectionHandle(handle_and_flags & 0x0FFF),
+            packet_status: SyncPacketStatus::from(handle_and_flags >> 12),
+        },
+        data: &packet[3..3 + data_len],
+    })
+}
+
+fn to_sync_data(packet: &[u8]) -> Result<SyncData, Error> {
+    let sync = to_sync_data_ref(packet)?;
+    if sync.data.len() > MAX_SYNC_DATA_LEN {
+        return Err(Error::DataTooLong(sync.data.len()));
+    }
+
+    let mut data_buf = [0; MAX_SYNC_DATA_LEN];
+    data_buf[..sync.data.len()].copy_from_slice(sync.data);
+
+    Ok(SyncData {
+        header: sync.header,
+        data_len: sync.data.len(),
+        data_buf,
+    })
+}
+
+/// Maximum length of the data load in an ISO data packet that can be read from the controller.
+///
+/// This is the largest length that fits in a single 256-byte read including the packet type and
+/// ISO data header. The SDU header, if present, is part of the data load.
+pub const MAX_ISO_DATA_LEN: usize = 251;
+
+/// Header for HCI ISO data packets, including the SDU header that starts the data load of the first
+/// fragment of an SDU.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 5.4.5.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct IsoDataHeader {
+    /// Connection handle of the CIS or BIS on which the data is sent or was received.
+    pub conn_handle: ConnectionHandle,
+
+    /// Indicates whether the packet carries a complete SDU or a fragment of one. The first
+    /// fragment and complete SDUs also carry the [SDU header](IsoSduHeader).
+    pub packet_boundary: IsoPacketBoundary,
+}
+
+impl IsoDataHeader {
+    const MAX_LENGTH: usize = 13;
+
+    fn copy_into_slice(&self, buffer: &mut [u8], data_len: usize) -> Result<usize, Error> {
+        assert_eq!(buffer.len(), Self::MAX_LENGTH);
+
+        let (pb_flag, sdu_header) = match self.packet_boundary {
+            IsoPacketBoundary::First(sdu_header) => (0b00, Some(sdu_header)),
+            IsoPacketBoundary::Continuation => (0b01, None),
+            IsoPacketBoundary::Complete(sdu_header) => (0b10, Some(sdu_header)),
+            IsoPacketBoundary::Last => (0b11, None),
+        };
+        let ts_flag = sdu_header.is_some_and(|h| h.timestamp.is_some());
+
+        let mut len = 5;
+        if let Some(sdu_header) = sdu_header {
+            if sdu_header.sdu_length > 0x0FFF {
+                return Err(Error::DataTooLong(sdu_header.sdu_length as usize));
+            }
+
+            if let Some(timestamp) = sdu_header.timestamp {
+                LittleEndian::write_u32(&mut buffer[len..len + 4], timestamp);
+                len += 4;
+            }
+            LittleEndian::write_u16(&mut buffer[len..len + 2], sdu_header.sequence_number);
+            LittleEndian::write_u16(
+                &mut buffer[len + 2..len + 4],
+                sdu_header.sdu_length | ((sdu_header.packet_status as u16) << 14),
+            );
+            len += 4;
+        }
+
+        let load_len = len - 5 + data_len;
+        if load_len > 0x3FFF {
+            return Err(Error::DataTooLong(load_len));
+        }
+
+        buffer[0] = PACKET_TYPE_ISO_DATA;
+        LittleEndian::write_u16(
+            &mut buffer[1..=2],
+            (self.conn_handle.0 & 0x0FFF) | (pb_flag << 12) | ((ts_flag as u16) << 14),
+        );
+        LittleEndian::write_u16(&mut buffer[3..=4], load_len as u16);
+
+        Ok(len)
+    }
+}
+
+/// Values for the packet boundary flag of an ISO data packet.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 5.4.5.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum IsoPacketBoundary {
+    /// The first fragment of a fragmented SDU.
+    First(IsoSduHeader),
+    /// A continuation fragment of a fragmented SDU.
+    Continuation,
+    /// A complete SDU.
+    Complete(IsoSduHeader),
+    /// The last fragment of a fragmented SDU.
+    Last,
+}
+
+/// Fields at the start of the data load of an ISO data packet that carries a complete SDU or the
+/// first fragment of one.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 5.4.5.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct IsoSduHeader {
+    /// Time stamp of the SDU, in microseconds, if present.
+    pub timestamp: Option<u32>,
+
+    /// Sequence number of the SDU.
+    pub sequence_number: u16,
+
+    /// Total length of the SDU, in bytes. Range 0x0000 to 0x0FFF.
+    pub sdu_length: u16,
+
+    /// Indicates the quality of the SDU received by the controller. Shall be
+    /// [`IsoPacketStatus::Valid`] for data sent to the controller.
+    pub packet_status: IsoPacketStatus,
+}
+
+/// Values for the packet status flag of an ISO data packet.
+///
+/// See the Bluetooth spec, Vol 4, Part E, Section 5.4.5.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum IsoPacketStatus {
+    /// Valid data. The complete SDU was received correctly.
+    Valid = 0b00,
+    /// Possibly invalid data. The contents of the SDU may contain errors, or part of the SDU may
+    /// be missing.
+    PossiblyInvalid = 0b01,
+    /// Part(s) of the SDU were not received correctly.
+    PartiallyLost = 0b10,
+}
+
+impl TryFrom<u16> for IsoPacketStatus {
+    type Error = Error;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            0b00 => Ok(IsoPacketStatus::Valid),
+            0b01 => Ok(IsoPacketStatus::PossiblyInvalid),
+            0b10 => Ok(IsoPacketStatus::PartiallyLost),
+            _ => Err(Error::BadPacketStatusFlag(value as u8)),
+        }
+    }
+}
+
+/// An ISO data packet read from the controller.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct IsoData {
+    /// Header of the packet.
+    pub header: IsoDataHeader,
+
+    data_len: usize,
+    data_buf: [u8; MAX_ISO_DATA_LEN],
+}
+
+impl IsoData {
+    /// Returns the SDU data (or SDU fragment) carried by the packet, without the SDU header.
+    pub fn data(&self) -> &[u8] {
+        &self.data_buf[..self.data_len]
+    }
+}
+
+impl Debug for IsoData {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{{{:?}, {:?}}}", self.header, self.data())
+    }
+}
+
+fn to_iso_data_ref(packet: &[u8]) -> Result<BorrowedData<'_, IsoDataHeader>, Error> {
+    let handle_and_flags = LittleEndian::read_u16(&packet[0..]);
+    let load_len = (LittleEndian::read_u16(&packet[2..]) & 0x3FFF) as usize;
+
+    let load = &packet[4..4 + load_len];
+    let has_timestamp = (handle_and_flags >> 14) & 0b1 == 1;
+    let sdu_header_len = if has_timestamp { 8 } else { 4 };
+    let to_sdu_header = || -> Result<IsoSduHeader, Error> {
+        if load_len < sdu_header_len {
+            return Err(Error::DataTooShort(load_len));
+        }
+
+        let sdu_info = &load[sdu_header_len - 4..];
+        let length_and_status = LittleEndian::read_u16(&sdu_info[2..]);
+        Ok(IsoSduHeader {
+            timestamp: if has_timestamp {
+                Some(LittleEndian::read_u32(load))
+            } else {
+                None
+            },
+            sequence_number: LittleEndian::read_u16(sdu_info),
+            sdu_length: length_and_status & 0x0FFF,
+            packet_status: IsoPacketStatus::try_from(length_and_status >> 14)?,
+        })
+    };
+
+    let (packet_boundary, data) = match (handle_and_flags >> 12) & 0b11 {
+        0b00 => (
+            IsoPacketBoundary::First(to_sdu_header()?),
+            &load[sdu_header_len..],
+        ),
+        0b01 => (IsoPacketBoundary::Continuation, load),
+        0b10 => (
+            IsoPacketBoundary::Complete(to_sdu_header()?),
+            &load[sdu_header_len..],
+        ),
+        _ => (IsoPacketBoundary::Last, load),
+    };
+
+    Ok(BorrowedData {
+        header: IsoDataHeader {
+            conn_handle: ConnectionHandle(handle_and_flags & 0x0FFF),
+            packet_boundary,
+        },
+        data,
+    })
+}
+
+fn to_iso_data(packet: &[u8]) -> Result<IsoData, Error> {
+    let load_len = (LittleEndian::read_u16(&packet[2..]) & 0x3FFF) as usize;
+    if load_len > MAX_ISO_DATA_LEN {
+        return Err(Error::DataTooLong(load_len));
+    }
+
+    let iso = to_iso_data_ref(packet)?;
+    let mut data_buf = [0; MAX_ISO_DATA_LEN];
+    data_buf[..iso.data.len()].copy_from_slice(iso.data);
+
+    Ok(IsoData {
+        header: iso.header,
+        data_len: iso.data.len(),
+        data_buf,
+    })
+}
+
+/// Returns the length of the header that follows the packet type byte for packets that may be read
+/// from the controller, or `None` if the packet type is not one of those.
+fn header_length(packet_type: u8) -> Option<usize> {
+    match packet_type {
+        PACKET_TYPE_ACL_DATA => Some(4),
+        PACKET_TYPE_SYNC_DATA => Some(3),
+        PACKET_TYPE_HCI_EVENT => Some(2),
+        PACKET_TYPE_ISO_DATA => Some(4),
+        _ => None,
+    }
+}
+
+/// Returns the length of the data that follows the header. `header` must include the packet type
+/// byte and the complete header.
+fn data_length(header: &[u8]) -> usize {
+    match header[0] {
+        PACKET_TYPE_ACL_DATA => LittleEndian::read_u16(&header[3..]) as usize,
+        PACKET_TYPE_SYNC_DATA => header[3] as usize,
+        PACKET_TYPE_HCI_EVENT => header[2] as usize,
+        PACKET_TYPE_ISO_DATA => (LittleEndian::read_u16(&header[3..]) & 0x3FFF) as usize,
+        _ => 0,
+    }
+}
+
+/// Returns the packet type and the rest of the packet (header and data) from `packet`, which
+/// starts with the packet type byte. `packet` may be longer than the packet; any trailing bytes
+/// are ignored.
+fn split_packet(packet: &[u8]) -> Result<(u8, &[u8]), Error> {
+    const PACKET_HEADER_LENGTH: usize = 1;
+
+    let packet_type = *packet.first().ok_or(Error::DataTooLong(0))?;
+    let header_len = header_length(packet_type).ok_or(Error::BadPacketType(packet_type))?;
+    let header = packet
+        .get(..PACKET_HEADER_LENGTH + header_len)
+        .ok_or(Error::DataTooLong(0))?;
+    let data_len = data_length(header);
+    let packet = packet
+        .get(PACKET_HEADER_LENGTH..PACKET_HEADER_LENGTH + header_len + data_len)
+        .ok_or(Error::DataTooLong(data_len))?;
+
+    Ok((packet_type, packet))
+}
+
+/// Deserializes a packet, including its packet type byte. `packet` may be longer than the packet;
+/// any trailing bytes are ignored.
+fn to_packet(packet: &[u8]) -> Result<Packet, Error> {
+    let (packet_type, packet) = split_packet(packet)?;
+    match packet_type {
+        PACKET_TYPE_ACL_DATA => Ok(Packet::AclData(to_acl_data(packet)?)),
+        PACKET_TYPE_SYNC_DATA => Ok(Packet::SyncData(to_sync_data(packet)?)),
+        PACKET_TYPE_ISO_DATA => Ok(Packet::IsoData(to_iso_data(packet)?)),
+        _ => Ok(Packet::Event(
+            crate::event::Event::new(crate::event::Packet(packet)).map_err(Error::BLE)?,
+        )),
+    }
+}
+
+/// Deserializes a packet, including its packet type byte, borrowing variable-length contents from
+/// `packet`. `packet` may be longer than the packet; any trailing bytes are ignored.
+fn to_borrowed_packet(packet: &[u8]) -> Result<BorrowedPacket<'_>, Error> {
+    let (packet_type, packet) = split_packet(packet)?;
+    match packet_type {
+        PACKET_TYPE_ACL_DATA => Ok(BorrowedPacket::AclData(to_acl_data_ref(packet)?)),
+        PACKET_TYPE_SYNC_DATA => Ok(BorrowedPacket::SyncData(to_sync_data_ref(packet)?)),
+        PACKET_TYPE_ISO_DATA => Ok(BorrowedPacket::IsoData(to_iso_data_ref(packet)?)),
+        _ => Ok(BorrowedPacket::Event(
+            crate::event::borrowed::Event::new(crate::event::Packet(packet)).map_err(Error::BLE)?,
+        )),
+    }
+}
+
+/// Length of the largest packet the [`H4Decoder`] can buffer: the packet type byte, the event
+/// header and 255 bytes of event parameters.
+const MAX_H4_PACKET_LEN: usize = 258;
+
+/// Incremental decoder for a stream of H4 (UART transport) packets from the controller.
+///
+/// Unlike [`UartHci::read`], which expects each read to start with a complete packet, the decoder
+/// accepts the byte stream in arbitrary pieces (for example, whatever a DMA transfer or a serial
+/// port read returned) and yields each [`Packet`] as soon as its last byte has been received.
+///
+/// The decoder resynchronizes on errors:
+///
+/// - A byte that is not a valid packet type is reported as [`Error::BadPacketType`] and dropped;
+///   decoding continues with the next byte.
+/// - A packet that is too long to buffer is reported as [`Error::DataTooLong`] as soon as its
+///   header is received, and the rest of the packet is skipped.
+/// - A packet that cannot be deserialized is reported with the corresponding error; decoding
+///   continues with the following packet.
+/// - A truncated packet cannot be detected from the byte stream alone. Call
+///   [`reset`](H4Decoder::reset) when the line goes idle (or a read times out) in the middle of a
+///   packet to drop it.
+///
+/// See the Bluetooth spec, Vol 4, Part A, Section 2.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct H4Decoder {
+    buf: [u8; MAX_H4_PACKET_LEN],
+    len: usize,
+    packet_len: usize,
+    header_complete: bool,
+    skip_len: usize,
+}
+
+impl Default for H4Decoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl H4Decoder {
+    /// Returns a decoder that expects the next byte to be the packet type byte.
+    pub const fn new() -> H4Decoder {
+        H4Decoder {
+            buf: [0; MAX_H4_PACKET_LEN],
+            len: 0,
+            packet_len: 0,
+            header_complete: false,
+            skip_len: 0,
+        }
+    }
+
+    /// Drops any partially received packet, so that the next byte is expected to be a packet type
+    /// byte.
+    pub fn reset(&mut self) {
+        self.len = 0;
+        self.skip_len = 0;
+    }
+
+    /// Returns true if the decoder is between packets, i.e. it has no partially received packet
+    /// buffered and is not skipping a packet that was too long.
+    pub fn is_idle(&self) -> bool {
+        self.len == 0 && self.skip_len == 0
+    }
+
+    /// Feeds bytes to the decoder, stopping at the end of the first complete packet.
+    ///
+    /// Returns the number of bytes consumed from `bytes` and, if a packet was completed (or
+    /// rejected), the result of deserializing it. If no packet was completed, all of `bytes` was
+    /// consumed. Otherwise, the caller should feed the remaining bytes again.
+    pub fn push(&mut self, bytes: &[u8]) -> (usize, Option<Result<Packet, Error>>) {
+        let mut consumed = 0;
+        while consumed < bytes.len() {
+            let remaining = &bytes[consumed..];
+
+            if self.skip_len > 0 {
+                let n = self.skip_len.min(remaining.len());
+                self.skip_len -= n;
+                consumed += n;
+                continue;
+            }
+
+            if self.len == 0 {
+                let packet_type = remaining[0];
+                consumed += 1;
+                match header_length(packet_type) {
+                    Some(header_len) => {
+                        self.buf[0] = packet_type;
+                        self.len = 1;
+                        self.packet_len = 1 + header_len;
+                        self.header_complete = false;
+                    }
+                    None => return (consumed, Some(Err(Error::BadPacketType(packet_type)))),
+                }
+                continue;
+            }
+
+            let n = (self.packet_len - self.len).min(remaining.len());
+            self.buf[self.len..self.len + n].copy_from_slice(&remaining[..n]);
+            self.len += n;
+            consumed += n;
+            if self.len < self.packet_len {
+                continue;
+            }
+
+            if !self.header_complete {
+                self.header_complete = true;
+
+                let data_len = data_length(&self.buf[..self.len]);
+                if self.len + data_len > MAX_H4_PACKET_LEN {
+                    self.len = 0;
+                    self.skip_len = data_len;
+                    return (consumed, Some(Err(Error::DataTooLong(data_len))));
+                }
+
+                self.packet_len += data_len;
+                if data_len > 0 {
+                    continue;
+                }
+            }
+
+            self.len = 0;
+            return (consumed, Some(to_packet(&self.buf[..self.packet_len])));
+        }
+
+        (consumed, None)
+    }
+
+    /// Returns an iterator over the packets completed by `bytes`. Bytes that do not complete a
+    /// packet remain buffered in the decoder for the next call.
+    ///
+    /// Bytes are only fed to the decoder as the iterator advances, so the iterator should be
+    /// exhausted to consume all of `bytes`.
+    pub fn decode<'a>(&'a mut self, bytes: &'a [u8]) -> H4Packets<'a> {
+        H4Packets {
+            decoder: self,
+            bytes,
+        }
+    }
+}
+
+/// Iterator over the packets decoded from a slice of bytes by [`H4Decoder::decode`].
+pub struct H4Packets<'a> {
+    decoder: &'a mut H4Decoder,
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for H4Packets<'a> {
+    type Item = Result<Packet, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (consumed, packet) = self.decoder.push(self.bytes);
+        self.bytes = &self.bytes[consumed..];
+
+        packet
+    }
+}
+
 /// Trait for reading packets from the controller.
 ///
 /// Implementors must also implement [`crate::host::HostHci`], which provides all of the functions to
@@ -59,6 +829,59 @@ pub trait UartHci: super::HostHci {
     /// - Returns [`Error::Comm`] if there is an error reading from the
     ///   controller.
     async fn read(&mut self) -> Result<Packet, Error>;
+
+    /// Reads a packet from the controller into `buf` and returns it. Unlike [`read`](UartHci::read),
+    /// the packet is not copied into a stack buffer, and variable-length contents (data, advertising
+    /// reports, attribute values, ...) are borrowed from `buf`.
+    ///
+    /// `buf` is passed to [`Controller::controller_read_into`](crate::Controller::controller_read_into)
+    /// as is, so it must be large enough to hold the largest packet the controller may send.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`read`](UartHci::read). In addition, returns [`Error::DataTooLong`] if the packet,
+    /// or its header, does not fit in `buf`.
+    async fn read_into<'a>(&mut self, buf: &'a mut [u8]) -> Result<BorrowedPacket<'a>, Error>;
+
+    /// Writes an ACL data packet to the controller. The header's length field is computed from
+    /// `data`, which must already be fragmented to fit the controller's ACL data buffers.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 5.4.2.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`Error::DataTooLong`] if `data` is longer than 65535 bytes. Nothing is written in
+    ///   this case.
+    async fn write_acl_data(&mut self, header: &AclDataHeader, data: &[u8]) -> Result<(), Error>
+    where
+        Self: crate::DataController;
+
+    /// Writes a synchronous data packet to the controller. The header's length field is computed
+    /// from `data`.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 5.4.3.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`Error::DataTooLong`] if `data` is longer than 255 bytes. Nothing is written in
+    ///   this case.
+    async fn write_sync_data(&mut self, header: &SyncDataHeader, data: &[u8]) -> Result<(), Error>
+    where
+        Self: crate::DataController;
+
+    /// Writes an ISO data packet to the controller. The header's data load length is computed from
+    /// `data` and the SDU header, if any. `data` must not include the SDU header.
+    ///
+    /// See the Bluetooth spec, Vol 4, Part E, Section 5.4.5.
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`Error::DataTooLong`] if the SDU length is greater than 0x0FFF, or if the data
+    ///   load (SDU header and `data`) is longer than 0x3FFF bytes. Nothing is written in this
+    ///   case.
+    async fn write_iso_data(&mut self, header: &IsoDataHeader, data: &[u8]) -> Result<(), Error>
+    where
+        Self: crate::DataController;
 }
 
 impl super::HciHeader for CommandHeader {
@@ -83,31 +906,58 @@ where
     T: crate::Controller,
 {
     async fn read(&mut self) -> Result<Packet, Error> {
-        const MAX_EVENT_LENGTH: usize = 256;
-        const PACKET_HEADER_LENGTH: usize = 1;
-        const EVENT_PACKET_HEADER_LENGTH: usize = 3;
-        const PARAM_LEN_BYTE: usize = 2;
+        const MAX_PACKET_LENGTH: usize = 256;
 
-        let mut packet = [0u8; MAX_EVENT_LENGTH];
+        let mut packet = [0u8; MAX_PACKET_LENGTH];
         self.controller_read_into(&mut packet).await;
 
-        let packet_type = packet[0];
-        match packet_type {
-            PACKET_TYPE_HCI_EVENT => {
-                let param_len = packet[PARAM_LEN_BYTE] as usize;
-
-                let mut buf = [0; MAX_EVENT_LENGTH + EVENT_PACKET_HEADER_LENGTH];
-                buf[..EVENT_PACKET_HEADER_LENGTH + param_len]
-                    .copy_from_slice(&packet[..EVENT_PACKET_HEADER_LENGTH + param_len]);
-
-                Ok(Packet::Event(
-                    crate::event::Event::new(crate::event::Packet(
-                        &buf[PACKET_HEADER_LENGTH..EVENT_PACKET_HEADER_LENGTH + param_len],
-                    ))
-                    .map_err(Error::BLE)?,
-                ))
-            }
-            x => Err(Error::BadPacketType(x)),
+        to_packet(&packet)
+    }
+
+    async fn read_into<'a>(&mut self, buf: &'a mut [u8]) -> Result<BorrowedPacket<'a>, Error> {
+        self.controller_read_into(buf).await;
+
+        to_borrowed_packet(buf)
+    }
+
+    async fn write_acl_data(&mut self, header: &AclDataHeader, data: &[u8]) -> Result<(), Error>
+    where
+        Self: crate::DataController,
+    {
+        if data.len() > u16::MAX as usize {
+            return Err(Error::DataTooLong(data.len()));
+        }
+
+        let mut bytes = [0; AclDataHeader::LENGTH];
+        header.copy_into_slice(&mut bytes, data.len());
+        self.controller_write_data(&bytes, data).await;
+
+        Ok(())
+    }
+
+    async fn write_sync_data(&mut self, header: &SyncDataHeader, data: &[u8]) -> Result<(), Error>
+    where
+        Self: crate::DataController,
+    {
+        if data.len() > u8::MAX as usize {
+            return Err(Error::DataTooLong(data.len()));
         }
+
+        let mut bytes = [0; SyncDataHeader::LENGTH];
+        header.copy_into_slice(&mut bytes, data.len());
+        self.controller_write_data(&bytes, data).await;
+
+        Ok(())
+    }
+
+    async fn write_iso_data(&mut self, header: &IsoDataHeader, data: &[u8]) -> Result<(), Error>
+    where
+        Self: crate::DataController,
+    {
+        let mut bytes = [0; IsoDataHeader::MAX_LENGTH];
+        let len = header.copy_into_slice(&mut bytes, data.len())?;
+        self.controller_write_data(&bytes[..len], data).await;
+
+        Ok(())
     }
 }
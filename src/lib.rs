@@ -20,8 +20,8 @@
 //!
 //! The [`host::Hci`] trait defines all of the functions that communicate from the host to the
 //! controller. The [`host::uart::Hci`] trait defines a read function that returns a
-//! [`host::uart::Packet`], which can contain an [`Event`], `AclData` (TODO), or `SyncData`
-//! (TODO). Both of these traits have default implementations in terms of the [`Controller`], so
+//! [`host::uart::Packet`], which can contain an [`Event`], `AclData`, `SyncData`, or
+//! `IsoData`. Both of these traits have default implementations in terms of the [`Controller`], so
 //! calling code does not need to implement any commands or event parsing code.
 //!
 //! ## Vendor-specific commands and events
@@ -152,6 +152,17 @@ pub trait Controller {
     async fn controller_read_into(&mut self, buf: &mut [u8]);
 }
 
+/// Interface to the Bluetooth controller for data packets.
+///
+/// Device crates whose transport carries ACL, synchronous or ISO data implement this trait in
+/// addition to [`Controller`], which enables the data functions of [`host::uart::UartHci`].
+pub trait DataController: Controller {
+    /// Writes a data packet (ACL, synchronous or ISO data) to the controller, in a single
+    /// transaction if possible. `header` is the complete packet header, including the packet type
+    /// byte, and shall be written in full, followed by all of `payload`.
+    async fn controller_write_data(&mut self, header: &[u8], payload: &[u8]);
+}
+
 /// List of possible error codes, Bluetooth Spec, Vol 2, Part D, Section 2.
 ///
 /// Includes an extension point for vendor-specific status codes.
@@ -494,6 +505,27 @@ pub struct ConnectionHandle(pub u16);
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct AdvertisingHandle(pub u8);
 
+/// Newtype for a periodic advertising sync handle.
+///
+/// Values:
+/// - 0x0000 .. 0x0EFF
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SyncHandle(pub u16);
+
+/// Newtype for a BIG handle.
+///
+/// Values:
+/// - 0x00 .. 0xEF
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BigHandle(pub u8);
+
+/// Newtype for a transmit power level, in dBm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Dbm(pub i8);
+
 /// Newtype for BDADDR.
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -99,5 +99,77 @@ opcodes! {
         pub const LE_RECEIVER_TEST = 0x001D;
         pub const LE_TRANSMITTER_TEST = 0x001E;
         pub const LE_TEST_END = 0x001F;
+        pub const LE_SET_DATA_LENGTH = 0x0022;
+        pub const LE_READ_SUGGESTED_DEFAULT_DATA_LENGTH = 0x0023;
+        pub const LE_WRITE_SUGGESTED_DEFAULT_DATA_LENGTH = 0x0024;
+        pub const LE_READ_LOCAL_P256_PUBLIC_KEY = 0x0025;
+        pub const LE_GENERATE_DHKEY = 0x0026;
+        pub const LE_ADD_DEVICE_TO_RESOLVING_LIST = 0x0027;
+        pub const LE_REMOVE_DEVICE_FROM_RESOLVING_LIST = 0x0028;
+        pub const LE_CLEAR_RESOLVING_LIST = 0x0029;
+        pub const LE_READ_RESOLVING_LIST_SIZE = 0x002A;
+        pub const LE_READ_PEER_RESOLVABLE_ADDRESS = 0x002B;
+        pub const LE_READ_LOCAL_RESOLVABLE_ADDRESS = 0x002C;
+        pub const LE_SET_ADDRESS_RESOLUTION_ENABLE = 0x002D;
+        pub const LE_SET_RESOLVABLE_PRIVATE_ADDRESS_TIMEOUT = 0x002E;
+        pub const LE_READ_MAXIMUM_DATA_LENGTH = 0x002F;
+        pub const LE_READ_PHY = 0x0030;
+        pub const LE_SET_DEFAULT_PHY = 0x0031;
+        pub const LE_SET_PHY = 0x0032;
+        pub const LE_SET_EXTENDED_ADVERTISING_PARAMETERS = 0x0036;
+        pub const LE_SET_EXTENDED_ADVERTISING_DATA = 0x0037;
+        pub const LE_SET_EXTENDED_SCAN_RESPONSE_DATA = 0x0038;
+        pub const LE_SET_EXTENDED_ADVERTISING_ENABLE = 0x0039;
+        pub const LE_READ_MAXIMUM_ADVERTISING_DATA_LENGTH = 0x003A;
+        pub const LE_READ_NUMBER_OF_SUPPORTED_ADVERTISING_SETS = 0x003B;
+        pub const LE_REMOVE_ADVERTISING_SET = 0x003C;
+        pub const LE_CLEAR_ADVERTISING_SETS = 0x003D;
+        pub const LE_SET_PERIODIC_ADVERTISING_PARAMETERS = 0x003E;
+        pub const LE_SET_PERIODIC_ADVERTISING_DATA = 0x003F;
+        pub const LE_SET_PERIODIC_ADVERTISING_ENABLE = 0x0040;
+        pub const LE_SET_EXTENDED_SCAN_PARAMETERS = 0x0041;
+        pub const LE_SET_EXTENDED_SCAN_ENABLE = 0x0042;
+        pub const LE_EXTENDED_CREATE_CONNECTION = 0x0043;
+        pub const LE_PERIODIC_ADVERTISING_CREATE_SYNC = 0x0044;
+        pub const LE_PERIODIC_ADVERTISING_CREATE_SYNC_CANCEL = 0x0045;
+        pub const LE_PERIODIC_ADVERTISING_TERMINATE_SYNC = 0x0046;
+        pub const LE_ADD_DEVICE_TO_PERIODIC_ADVERTISER_LIST = 0x0047;
+        pub const LE_REMOVE_DEVICE_FROM_PERIODIC_ADVERTISER_LIST = 0x0048;
+        pub const LE_CLEAR_PERIODIC_ADVERTISER_LIST = 0x0049;
+        pub const LE_READ_PERIODIC_ADVERTISER_LIST_SIZE = 0x004A;
+        pub const LE_SET_PRIVACY_MODE = 0x004E;
+        pub const LE_SET_CONNECTIONLESS_CTE_TRANSMIT_PARAMETERS = 0x0051;
+        pub const LE_SET_CONNECTIONLESS_CTE_TRANSMIT_ENABLE = 0x0052;
+        pub const LE_SET_CONNECTIONLESS_IQ_SAMPLING_ENABLE = 0x0053;
+        pub const LE_SET_CONNECTION_CTE_RECEIVE_PARAMETERS = 0x0054;
+        pub const LE_SET_CONNECTION_CTE_TRANSMIT_PARAMETERS = 0x0055;
+        pub const LE_CONNECTION_CTE_REQUEST_ENABLE = 0x0056;
+        pub const LE_CONNECTION_CTE_RESPONSE_ENABLE = 0x0057;
+        pub const LE_READ_ANTENNA_INFORMATION = 0x0058;
+        pub const LE_PERIODIC_ADVERTISING_SYNC_TRANSFER = 0x005A;
+        pub const LE_PERIODIC_ADVERTISING_SET_INFO_TRANSFER = 0x005B;
+        pub const LE_SET_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS = 0x005C;
+        pub const LE_SET_DEFAULT_PERIODIC_ADVERTISING_SYNC_TRANSFER_PARAMETERS = 0x005D;
+        pub const LE_GENERATE_DHKEY_V2 = 0x005E;
+        pub const LE_READ_ISO_TX_SYNC = 0x0061;
+        pub const LE_SET_CIG_PARAMETERS = 0x0062;
+        pub const LE_SET_CIG_PARAMETERS_TEST = 0x0063;
+        pub const LE_CREATE_CIS = 0x0064;
+        pub const LE_REMOVE_CIG = 0x0065;
+        pub const LE_ACCEPT_CIS_REQUEST = 0x0066;
+        pub const LE_REJECT_CIS_REQUEST = 0x0067;
+        pub const LE_CREATE_BIG = 0x0068;
+        pub const LE_TERMINATE_BIG = 0x006A;
+        pub const LE_BIG_CREATE_SYNC = 0x006B;
+        pub const LE_BIG_TERMINATE_SYNC = 0x006C;
+        pub const LE_SETUP_ISO_DATA_PATH = 0x006E;
+        pub const LE_REMOVE_ISO_DATA_PATH = 0x006F;
+        pub const LE_ENHANCED_READ_TRANSMIT_POWER_LEVEL = 0x0076;
+        pub const LE_READ_REMOTE_TRANSMIT_POWER_LEVEL = 0x0077;
+        pub const LE_SET_PATH_LOSS_REPORTING_PARAMETERS = 0x0078;
+        pub const LE_SET_PATH_LOSS_REPORTING_ENABLE = 0x0079;
+        pub const LE_SET_TRANSMIT_POWER_REPORTING_ENABLE = 0x007A;
+        pub const LE_SET_DEFAULT_SUBRATE = 0x007D;
+        pub const LE_SUBRATE_REQUEST = 0x007E;
     }
 }
@@ -19,6 +19,7 @@ use core::time::Duration;
 /// or [`NonConnectableUndirected`](AdvertisingType::NonConnectableUndirected).  This
 /// restriction is removed in version 5.0 of the spec.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct AdvertisingInterval {
     // The first field is the min; the second is the max
     interval: (Duration, Duration),
@@ -50,6 +50,27 @@ impl ExpectedConnectionLength {
         LittleEndian::write_u16(&mut bytes[2..4], Self::duration_as_u16(self.range.1));
     }
 
+    /// Deserializes the expected connection length range from the given byte buffer.
+    ///
+    /// - The minimum length, in units of 0.625 ms (2 bytes)
+    /// - The maximum length, in units of 0.625 ms (2 bytes)
+    ///
+    /// # Panics
+    ///
+    /// The buffer must be at least 4 bytes long.
+    ///
+    /// # Errors
+    ///
+    /// - [Inverted](ExpectedConnectionLengthError::Inverted) if the minimum is greater than the
+    ///   maximum.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ExpectedConnectionLengthError> {
+        assert!(bytes.len() >= 4);
+
+        let min = Duration::from_micros(625) * u32::from(LittleEndian::read_u16(&bytes[0..2]));
+        let max = Duration::from_micros(625) * u32::from(LittleEndian::read_u16(&bytes[2..4]));
+        ExpectedConnectionLength::new(min, max)
+    }
+
     fn duration_as_u16(d: Duration) -> u16 {
         // T = 0.625 ms * N
         // so N = T / 0.625 ms
@@ -7,6 +7,7 @@ use crate::AdvertisingHandle;
 #[cfg(not(feature = "defmt"))]
 bitflags::bitflags! {
     /// Extended advertising modes
+    #[derive(Debug, Clone, Copy)]
     pub struct AdvertisingMode: u8 {
         /// Use specific random address
         const SPECIFIC = 0x01;
@@ -25,6 +26,7 @@ defmt::bitflags! {
 #[cfg(not(feature = "defmt"))]
 bitflags::bitflags! {
     /// Advertising event types
+    #[derive(Debug, Clone, Copy)]
     pub struct AdvertisingEvent: u16 {
         /// Connectable advertising
         const CONNECTABLE = 0x0001;
@@ -70,6 +72,8 @@ defmt::bitflags! {
 /// max. The advertising interval min and advertising interval max should not be the same
 /// values to enable the Controller to determine the best advertising interval given other
 /// adctivities, through this implementation allows them to be equal.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ExtendedAdvertisingInterval {
     /// The first field is the min, the second is the max
     interval: (Duration, Duration),
@@ -131,6 +135,73 @@ impl ExtendedAdvertisingInterval {
         LittleEndian::write_u32(&mut bytes[0..], Self::duration_as_u32(self.interval.0));
         LittleEndian::write_u32(&mut bytes[4..], Self::duration_as_u32(self.interval.1));
     }
+
+    /// Serialize the interval into the given buffer, in the format of the
+    /// [LE Set Extended Advertising Parameters](crate::host::HostHci::le_set_extended_advertising_parameters)
+    /// command.
+    ///
+    /// Serializes the minimum range of the interval (3 bytes), the maximum range of the
+    /// interval (3 bytes)
+    ///
+    /// # Panics
+    ///
+    /// - If the provided buffer is not at least 6 bytes long.
+    pub fn copy_into_slice_u24(&self, bytes: &mut [u8]) {
+        LittleEndian::write_u24(&mut bytes[0..], Self::duration_as_u32(self.interval.0));
+        LittleEndian::write_u24(&mut bytes[3..], Self::duration_as_u32(self.interval.1));
+    }
+
+    /// Deserializes the interval from the given buffer.
+    ///
+    /// - The minimum range of the interval, in units of 0.625 ms (4 bytes)
+    /// - The maximum range of the interval, in units of 0.625 ms (4 bytes)
+    ///
+    /// # Panics
+    ///
+    /// - If the provided buffer is not at least 8 bytes long.
+    ///
+    /// # Errors
+    ///
+    /// Any of the errors from [`with_range`](ExtendedAdvertisingInterval::with_range).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ExtendedAdvertisingIntervalError> {
+        assert!(bytes.len() >= 8);
+
+        let min = Duration::from_micros(625) * LittleEndian::read_u32(&bytes[0..]);
+        let max = Duration::from_micros(625) * LittleEndian::read_u32(&bytes[4..]);
+        Self::with_range(min, max)
+    }
+
+    /// Deserializes the interval from the given buffer, in the format of the
+    /// [LE Set Extended Advertising Parameters](crate::host::HostHci::le_set_extended_advertising_parameters)
+    /// command.
+    ///
+    /// - The minimum range of the interval, in units of 0.625 ms (3 bytes)
+    /// - The maximum range of the interval, in units of 0.625 ms (3 bytes)
+    ///
+    /// # Panics
+    ///
+    /// - If the provided buffer is not at least 6 bytes long.
+    ///
+    /// # Errors
+    ///
+    /// Any of the errors from [`with_range`](ExtendedAdvertisingInterval::with_range).
+    pub fn from_u24_bytes(bytes: &[u8]) -> Result<Self, ExtendedAdvertisingIntervalError> {
+        assert!(bytes.len() >= 6);
+
+        let min = Duration::from_micros(625) * LittleEndian::read_u24(&bytes[0..]);
+        let max = Duration::from_micros(625) * LittleEndian::read_u24(&bytes[3..]);
+        Self::with_range(min, max)
+    }
+
+    /// Returns the minimum advertising interval.
+    pub fn min(&self) -> Duration {
+        self.interval.0
+    }
+
+    /// Returns the maximum advertising interval.
+    pub fn max(&self) -> Duration {
+        self.interval.1
+    }
 }
 
 /// Potential errors that can occur when specifying an [`ExtendedAdvertisingInterval`].
@@ -147,16 +218,20 @@ pub enum ExtendedAdvertisingIntervalError {
 }
 
 /// Advertising PHY
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum AdvertisingPhy {
     /// Advertisement PHY is LE 1M
     Le1M = 0x01,
     /// Advertisement PHY is LE 2M
     Le2M = 0x02,
+    /// Advertisement PHY is LE Coded
+    LeCoded = 0x03,
 }
 
 /// Advertising set
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct AdvSet {
     /// Used to identify an advertising set
     pub handle: AdvertisingHandle,
@@ -185,7 +260,7 @@ impl AdvSet {
 }
 
 /// Advertising Operation
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum AdvertisingOperation {
     /// Intermediate fragment of fragmented extended advertising data
@@ -199,3 +274,61 @@ pub enum AdvertisingOperation {
     /// Unchanged data (just update the advertising DID)
     UnchangedData = 0x04,
 }
+
+pub(crate) const ADV_SET_LEN: usize = 4;
+
+/// List of advertising sets in a deserialized
+/// [GAP Adv Set Enable](crate::vendor::command::gap::GapCommands::adv_set_enable) or
+/// [LE Set Extended Advertising Enable](crate::host::HostHci::le_set_extended_advertising_enable)
+/// command.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AdvSetList<'a> {
+    pub(crate) data: &'a [u8],
+}
+
+impl<'a> AdvSetList<'a> {
+    /// Returns the number of advertising sets in the list.
+    pub fn len(&self) -> usize {
+        self.data.len() / ADV_SET_LEN
+    }
+
+    /// Returns true if the list contains no advertising sets.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns an iterator over the advertising sets in the list.
+    pub fn iter(&self) -> AdvSetIterator<'a> {
+        AdvSetIterator {
+            data: self.data,
+            next_index: 0,
+        }
+    }
+}
+
+/// Iterator over the advertising sets in an [`AdvSetList`].
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AdvSetIterator<'a> {
+    data: &'a [u8],
+    next_index: usize,
+}
+
+impl<'a> Iterator for AdvSetIterator<'a> {
+    type Item = AdvSet;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_index >= self.data.len() {
+            return None;
+        }
+
+        let index = self.next_index;
+        self.next_index += ADV_SET_LEN;
+
+        Some(AdvSet {
+            handle: AdvertisingHandle(self.data[index]),
+            duration: LittleEndian::read_u16(&self.data[index + 1..]),
+            max_extended_adv_events: self.data[index + 3],
+        })
+    }
+}
@@ -6,11 +6,15 @@ mod common;
 mod connection_interval;
 mod expected_connection_length;
 pub mod extended_advertisement;
+mod p256_public_key;
 mod scan_window;
+mod subrate;
 
 pub use self::advertisement::*;
 pub use self::advertising_interval::*;
 pub use self::common::*;
 pub use self::connection_interval::*;
 pub use self::expected_connection_length::*;
+pub use self::p256_public_key::*;
 pub use self::scan_window::*;
+pub use self::subrate::*;
@@ -0,0 +1,24 @@
+//! Types related to LE Secure Connections public keys.
+
+/// P-256 public key used for LE Secure Connections pairing.
+///
+/// The key is made of the X coordinate followed by the Y coordinate, each 32 octets long and
+/// little-endian, as transmitted over HCI. It is returned by the controller in the
+/// [LE Read Local P-256 Public Key Complete](crate::event::Event::LeReadLocalP256PublicKeyComplete)
+/// event, and the remote device's key is given to the
+/// [LE Generate DHKey](crate::host::HostHci::le_generate_dhkey) command.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct P256PublicKey(pub [u8; 64]);
+
+impl P256PublicKey {
+    /// Returns the X coordinate of the key, little-endian.
+    pub fn x(&self) -> &[u8] {
+        &self.0[..32]
+    }
+
+    /// Returns the Y coordinate of the key, little-endian.
+    pub fn y(&self) -> &[u8] {
+        &self.0[32..]
+    }
+}
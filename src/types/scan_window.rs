@@ -41,6 +41,27 @@ impl ScanWindow {
         LittleEndian::write_u16(&mut bytes[2..4], Self::duration_as_u16(self.window_width));
     }
 
+    /// Deserializes the window from the given byte buffer.
+    ///
+    /// - The interval, in units of 0.625 ms (2 bytes)
+    /// - The window, in units of 0.625 ms (2 bytes)
+    ///
+    /// # Panics
+    ///
+    /// The provided buffer must be at least 4 bytes long.
+    ///
+    /// # Errors
+    ///
+    /// Any of the errors from [`start_every`](ScanWindow::start_every) or the
+    /// [builder](ScanWindowBuilder::open_for).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ScanWindowError> {
+        assert!(bytes.len() >= 4);
+
+        let interval = Duration::from_micros(625) * u32::from(LittleEndian::read_u16(&bytes[0..2]));
+        let window = Duration::from_micros(625) * u32::from(LittleEndian::read_u16(&bytes[2..4]));
+        ScanWindow::start_every(interval)?.open_for(window)
+    }
+
     /// Begins building a [ScanWindow]. The scan window has the given interval. Returns a
     /// [builder](ScanWindowBuilder) that can be used to set the window duration.
     ///
@@ -0,0 +1,346 @@
+//! Types related to connection subrating.
+
+use byteorder::{ByteOrder, LittleEndian};
+use core::cmp;
+use core::time::Duration;
+
+/// Define the range of subrate factors with the maximum latency, continuation number and
+/// supervision timeout of a subrated connection. This value is passed to the controller, which
+/// determines the [actual subrate factor](crate::event::LeSubrateChange).
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SubrateParameters {
+    subrate_: (u16, u16),
+    max_latency_: u16,
+    continuation_number_: u16,
+    supervision_timeout_: Duration,
+}
+
+impl SubrateParameters {
+    /// Returns the subrate factor range.
+    pub fn subrate(&self) -> (u16, u16) {
+        self.subrate_
+    }
+
+    /// Returns the maximum Peripheral latency, in number of subrated connection events.
+    pub fn max_latency(&self) -> u16 {
+        self.max_latency_
+    }
+
+    /// Returns the continuation number: the number of underlying connection events to remain
+    /// active for after a packet containing data is received.
+    pub fn continuation_number(&self) -> u16 {
+        self.continuation_number_
+    }
+
+    /// Returns the supervision timeout.
+    pub fn supervision_timeout(&self) -> Duration {
+        self.supervision_timeout_
+    }
+
+    /// Serializes the subrate parameters into the given byte buffer.
+    ///
+    /// The parameters are serialized as:
+    /// - The minimum subrate factor (2 bytes)
+    /// - The maximum subrate factor (2 bytes)
+    /// - The maximum Peripheral latency (2 bytes)
+    /// - The continuation number (2 bytes)
+    /// - The supervision timeout, appropriately converted (2 bytes)
+    ///
+    /// # Panics
+    ///
+    /// The provided buffer must be at least 10 bytes long.
+    pub fn copy_into_slice(&self, bytes: &mut [u8]) {
+        assert!(bytes.len() >= 10);
+
+        LittleEndian::write_u16(&mut bytes[0..2], self.subrate_.0);
+        LittleEndian::write_u16(&mut bytes[2..4], self.subrate_.1);
+        LittleEndian::write_u16(&mut bytes[4..6], self.max_latency_);
+        LittleEndian::write_u16(&mut bytes[6..8], self.continuation_number_);
+        LittleEndian::write_u16(
+            &mut bytes[8..10],
+            (self.supervision_timeout_.as_millis() / 10) as u16,
+        );
+    }
+
+    /// Deserializes the subrate parameters from the given byte buffer.
+    ///
+    /// - The minimum subrate factor (2 bytes)
+    /// - The maximum subrate factor (2 bytes)
+    /// - The maximum Peripheral latency (2 bytes)
+    /// - The continuation number (2 bytes)
+    /// - The supervision timeout, appropriately converted (2 bytes)
+    ///
+    /// # Panics
+    ///
+    /// The provided buffer must be at least 10 bytes long.
+    ///
+    /// # Errors
+    ///
+    /// Any of the errors from the [builder](SubrateParametersBuilder::build) except for
+    /// Incomplete.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SubrateParametersError> {
+        assert!(bytes.len() >= 10);
+
+        SubrateParametersBuilder::new()
+            .with_subrate_range(
+                LittleEndian::read_u16(&bytes[0..2]),
+                LittleEndian::read_u16(&bytes[2..4]),
+            )
+            .with_max_latency(LittleEndian::read_u16(&bytes[4..6]))
+            .with_continuation_number(LittleEndian::read_u16(&bytes[6..8]))
+            .with_supervision_timeout(
+                Duration::from_millis(10) * u32::from(LittleEndian::read_u16(&bytes[8..10])),
+            )
+            .build()
+    }
+}
+
+/// Intermediate builder for the [`SubrateParameters`].
+#[derive(Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SubrateParametersBuilder {
+    subrate: Option<(u16, u16)>,
+    max_latency: Option<u16>,
+    continuation_number: Option<u16>,
+    supervision_timeout: Option<Duration>,
+    connection_interval: Option<Duration>,
+}
+
+impl SubrateParametersBuilder {
+    /// Initializes a new builder.
+    pub fn new() -> SubrateParametersBuilder {
+        SubrateParametersBuilder {
+            subrate: None,
+            max_latency: None,
+            continuation_number: None,
+            supervision_timeout: None,
+            connection_interval: None,
+        }
+    }
+
+    /// Sets the subrate factor range.
+    ///
+    /// # Errors
+    ///
+    /// There are no errors from this function, but it may cause errors in
+    /// [build](SubrateParametersBuilder::build) if:
+    /// - `min` is greater than `max`
+    /// - Either `min` or `max` is 0 or greater than 500.
+    /// - `max` leads to an excessive total latency.
+    /// - `max` leads to an invalid relative supervision timeout.
+    pub fn with_subrate_range(&mut self, min: u16, max: u16) -> &mut SubrateParametersBuilder {
+        self.subrate = Some((min, max));
+        self
+    }
+
+    /// Sets the maximum Peripheral latency, in number of subrated connection events.
+    ///
+    /// # Errors
+    ///
+    /// There are no errors from this function, but it may cause errors in
+    /// [build](SubrateParametersBuilder::build) if:
+    /// - `latency` is 500 or greater.
+    /// - `latency` leads to an excessive total latency.
+    /// - `latency` leads to an invalid relative supervision timeout.
+    pub fn with_max_latency(&mut self, latency: u16) -> &mut SubrateParametersBuilder {
+        self.max_latency = Some(latency);
+        self
+    }
+
+    /// Sets the continuation number.
+    ///
+    /// # Errors
+    ///
+    /// There are no errors from this function, but it may cause errors in
+    /// [build](SubrateParametersBuilder::build) if `continuation_number` is not less than the
+    /// maximum subrate factor.
+    pub fn with_continuation_number(
+        &mut self,
+        continuation_number: u16,
+    ) -> &mut SubrateParametersBuilder {
+        self.continuation_number = Some(continuation_number);
+        self
+    }
+
+    /// Sets the supervision timeout.
+    ///
+    /// # Errors
+    ///
+    /// There are no errors from this function, but it may cause errors in
+    /// [build](SubrateParametersBuilder::build) if:
+    /// - `timeout` is less than 100 ms or greater than 32 seconds.
+    /// - `timeout` results in an invalid relative supervision timeout.
+    pub fn with_supervision_timeout(&mut self, timeout: Duration) -> &mut SubrateParametersBuilder {
+        self.supervision_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the connection interval of the connection to subrate. The connection interval is not
+    /// sent to the controller; it is only used to check the relative supervision timeout. If it is
+    /// not set, the minimum connection interval of 7.5 ms is assumed.
+    ///
+    /// # Errors
+    ///
+    /// There are no errors from this function, but it may cause errors in
+    /// [build](SubrateParametersBuilder::build) if:
+    /// - `interval` is less than 7.5 ms or more than 4 seconds.
+    /// - `interval` leads to an invalid relative supervision timeout.
+    pub fn with_connection_interval(
+        &mut self,
+        interval: Duration,
+    ) -> &mut SubrateParametersBuilder {
+        self.connection_interval = Some(interval);
+        self
+    }
+
+    /// Builds the subrate parameters if all parameters are valid.
+    ///
+    /// # Errors
+    ///
+    /// - [Incomplete](SubrateParametersError::Incomplete) if any of
+    ///   [`with_subrate_range`](SubrateParametersBuilder::with_subrate_range),
+    ///   [`with_max_latency`](SubrateParametersBuilder::with_max_latency),
+    ///   [`with_continuation_number`](SubrateParametersBuilder::with_continuation_number), or
+    ///   [`with_supervision_timeout`](SubrateParametersBuilder::with_supervision_timeout) have not
+    ///   been called.
+    /// - [BadSubrateFactor](SubrateParametersError::BadSubrateFactor) if either subrate factor is
+    ///   0 or greater than 500.
+    /// - [SubrateInverted](SubrateParametersError::SubrateInverted) if the minimum subrate factor
+    ///   is greater than the maximum.
+    /// - [BadMaxLatency](SubrateParametersError::BadMaxLatency) if the maximum latency is 500 or
+    ///   more.
+    /// - [ExcessiveLatency](SubrateParametersError::ExcessiveLatency) if the maximum subrate
+    ///   factor * (maximum latency + 1) is 500 or more.
+    /// - [BadContinuationNumber](SubrateParametersError::BadContinuationNumber) if the
+    ///   continuation number is not less than the maximum subrate factor.
+    /// - [BadConnectionInterval](SubrateParametersError::BadConnectionInterval) if the connection
+    ///   interval is less than 7.5 ms or more than 4 seconds.
+    /// - [SupervisionTimeoutTooShort](SubrateParametersError::SupervisionTimeoutTooShort) if the
+    ///   supervision timeout is less than 100 ms, or if it is not more than the computed minimum:
+    ///   connection interval * maximum subrate factor * (maximum latency + 1) * 2.
+    /// - [SupervisionTimeoutTooLong](SubrateParametersError::SupervisionTimeoutTooLong) if the
+    ///   supervision timeout is more than 32 seconds.
+    /// - [ImpossibleSupervisionTimeout](SubrateParametersError::ImpossibleSupervisionTimeout) if
+    ///   the computed minimum supervision timeout (connection interval * maximum subrate factor *
+    ///   (maximum latency + 1) * 2) is 32 seconds or more.
+    pub fn build(&self) -> Result<SubrateParameters, SubrateParametersError> {
+        if self.subrate.is_none()
+            || self.max_latency.is_none()
+            || self.continuation_number.is_none()
+            || self.supervision_timeout.is_none()
+        {
+            return Err(SubrateParametersError::Incomplete);
+        }
+
+        let subrate = self.subrate.unwrap();
+        const SUBRATE_MAX: u16 = 0x01F4;
+        for factor in [subrate.0, subrate.1] {
+            if factor == 0 || factor > SUBRATE_MAX {
+                return Err(SubrateParametersError::BadSubrateFactor(factor));
+            }
+        }
+
+        if subrate.0 > subrate.1 {
+            return Err(SubrateParametersError::SubrateInverted(
+                subrate.0, subrate.1,
+            ));
+        }
+
+        let max_latency = self.max_latency.unwrap();
+        const LATENCY_MAX: u16 = 0x01F3;
+        if max_latency > LATENCY_MAX {
+            return Err(SubrateParametersError::BadMaxLatency(max_latency));
+        }
+
+        if u32::from(subrate.1) * (1 + u32::from(max_latency)) >= u32::from(SUBRATE_MAX) {
+            return Err(SubrateParametersError::ExcessiveLatency(
+                subrate.1,
+                max_latency,
+            ));
+        }
+
+        let continuation_number = self.continuation_number.unwrap();
+        if continuation_number >= subrate.1 {
+            return Err(SubrateParametersError::BadContinuationNumber(
+                continuation_number,
+            ));
+        }
+
+        const INTERVAL_MIN: Duration = Duration::from_micros(7500);
+        const INTERVAL_MAX: Duration = Duration::from_secs(4);
+        let interval = self.connection_interval.unwrap_or(INTERVAL_MIN);
+        if interval < INTERVAL_MIN || interval > INTERVAL_MAX {
+            return Err(SubrateParametersError::BadConnectionInterval(interval));
+        }
+
+        let supervision_timeout = self.supervision_timeout.unwrap();
+        let computed_timeout_min =
+            interval * u32::from(subrate.1) * (1 + u32::from(max_latency)) * 2;
+        const TIMEOUT_MAX: Duration = Duration::from_secs(32);
+        if computed_timeout_min >= TIMEOUT_MAX {
+            return Err(SubrateParametersError::ImpossibleSupervisionTimeout(
+                computed_timeout_min,
+            ));
+        }
+
+        const TIMEOUT_ABS_MIN: Duration = Duration::from_millis(100);
+        if supervision_timeout < TIMEOUT_ABS_MIN || supervision_timeout <= computed_timeout_min {
+            return Err(SubrateParametersError::SupervisionTimeoutTooShort(
+                supervision_timeout,
+                cmp::max(computed_timeout_min, TIMEOUT_ABS_MIN),
+            ));
+        }
+
+        if supervision_timeout > TIMEOUT_MAX {
+            return Err(SubrateParametersError::SupervisionTimeoutTooLong(
+                supervision_timeout,
+            ));
+        }
+
+        Ok(SubrateParameters {
+            subrate_: subrate,
+            max_latency_: max_latency,
+            continuation_number_: continuation_number,
+            supervision_timeout_: supervision_timeout,
+        })
+    }
+}
+
+/// Types of errors that can occur when creating [`SubrateParameters`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SubrateParametersError {
+    /// At least one of any of
+    /// [`with_subrate_range`](SubrateParametersBuilder::with_subrate_range),
+    /// [`with_max_latency`](SubrateParametersBuilder::with_max_latency),
+    /// [`with_continuation_number`](SubrateParametersBuilder::with_continuation_number), or
+    /// [`with_supervision_timeout`](SubrateParametersBuilder::with_supervision_timeout) has not
+    /// been called.
+    Incomplete,
+    /// A subrate factor is 0 or greater than 500. Includes the invalid value.
+    BadSubrateFactor(u16),
+    /// The minimum subrate factor is greater than the maximum. Includes the provided minimum and
+    /// maximum, respectively.
+    SubrateInverted(u16, u16),
+    /// The maximum latency is 500 or more. Includes the provided value.
+    BadMaxLatency(u16),
+    /// The maximum subrate factor * (maximum latency + 1) is 500 or more. Includes the provided
+    /// maximum subrate factor and maximum latency, respectively.
+    ExcessiveLatency(u16, u16),
+    /// The continuation number is not less than the maximum subrate factor. Includes the provided
+    /// value.
+    BadContinuationNumber(u16),
+    /// The connection interval is less than 7.5 ms or more than 4 seconds. Includes the provided
+    /// value.
+    BadConnectionInterval(Duration),
+    /// The supervision timeout is less than 100 ms, or it is not more than the computed minimum:
+    /// connection interval * maximum subrate factor * (maximum latency + 1) * 2. The first value
+    /// is the provided timeout; the second is the required minimum.
+    SupervisionTimeoutTooShort(Duration, Duration),
+    /// The supervision timeout is more than 32 seconds. Includes the provided timeout.
+    SupervisionTimeoutTooLong(Duration),
+    /// The computed minimum supervision timeout (connection interval * maximum subrate factor *
+    /// (maximum latency + 1) * 2) is 32 seconds or more. Includes the computed minimum.
+    ImpossibleSupervisionTimeout(Duration),
+}
@@ -0,0 +1,206 @@
+extern crate stm32wb_hci as hci;
+
+mod vendor;
+
+use hci::event::command::LeReadBufferSize;
+use hci::host::HostHci;
+use hci::host::acl_credits::{AclCredits, Error};
+use hci::host::uart::{AclBroadcastFlag, AclDataHeader, AclPacketBoundary, Packet, UartHci};
+use hci::{ConnectionHandle, Event, Status};
+use vendor::PacketSink;
+
+const LE_READ_BUFFER_SIZE_COMPLETE: &[u8] = &[0x04, 0x0E, 7, 1, 0x02, 0x20, 0x00, 4, 0, 2];
+
+fn with_buffers(packets: &[&[u8]]) -> AclCredits<PacketSink, 2> {
+    let mut hci = AclCredits::new(PacketSink::new(packets));
+    hci.set_buffer_size(&LeReadBufferSize {
+        status: Status::Success,
+        data_packet_length: 4,
+        data_packet_count: 2,
+    });
+    hci
+}
+
+#[tokio::test]
+async fn unknown_buffer_size() {
+    let mut hci: AclCredits<_> = AclCredits::new(PacketSink::new(&[]));
+    assert_eq!(hci.data_packet_length(), None);
+    let err = hci
+        .write_pdu(ConnectionHandle(0x0201), &[1, 2, 3], |_| {})
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::UnknownBufferSize);
+    assert!(hci.into_inner().written_data.is_empty());
+}
+
+#[tokio::test]
+async fn learns_buffer_size_from_command_complete() {
+    let mut hci: AclCredits<_> = AclCredits::new(PacketSink::new(&[LE_READ_BUFFER_SIZE_COMPLETE]));
+    hci.le_read_buffer_size().await;
+    hci.read().await.unwrap();
+    assert_eq!(hci.data_packet_length(), Some(4));
+    assert_eq!(hci.available(), 2);
+}
+
+#[tokio::test]
+async fn ignores_failed_buffer_size() {
+    let mut hci: AclCredits<_> = AclCredits::new(PacketSink::new(&[&[
+        0x04, 0x0E, 7, 1, 0x02, 0x20, 0x01, 4, 0, 2,
+    ]]));
+    hci.read().await.unwrap();
+    assert_eq!(hci.data_packet_length(), None);
+}
+
+#[tokio::test]
+async fn shared_buffers() {
+    // No dedicated LE buffers: LE data uses the buffers of the Read Buffer Size command.
+    let mut hci: AclCredits<_> = AclCredits::new(PacketSink::new(&[&[
+        0x04, 0x0E, 7, 1, 0x02, 0x20, 0x00, 0, 0, 0,
+    ]]));
+    hci.read().await.unwrap();
+    assert_eq!(hci.data_packet_length(), None);
+
+    hci.set_buffers(4, 2);
+    assert_eq!(hci.data_packet_length(), Some(4));
+    assert_eq!(hci.available(), 2);
+    hci.write_pdu(ConnectionHandle(0x0201), &[1, 2, 3, 4, 5], |_| {
+        panic!("No packets should be read")
+    })
+    .await
+    .unwrap();
+    assert_eq!(hci.available(), 0);
+    assert_eq!(
+        hci.into_inner().written_data,
+        [
+            vec![0x02, 0x01, 0x02, 4, 0, 1, 2, 3, 4],
+            vec![0x02, 0x01, 0x12, 1, 0, 5],
+        ]
+    );
+}
+
+#[tokio::test]
+async fn fragments_pdu() {
+    let mut hci = AclCredits::<_, 2>::new(PacketSink::new(&[]));
+    hci.set_buffer_size(&LeReadBufferSize {
+        status: Status::Success,
+        data_packet_length: 4,
+        data_packet_count: 3,
+    });
+    hci.write_pdu(
+        ConnectionHandle(0x0201),
+        &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+        |_| panic!("No packets should be read"),
+    )
+    .await
+    .unwrap();
+    assert_eq!(hci.available(), 0);
+    assert_eq!(hci.outstanding(ConnectionHandle(0x0201)), 3);
+    assert_eq!(
+        hci.into_inner().written_data,
+        [
+            vec![0x02, 0x01, 0x02, 4, 0, 1, 2, 3, 4],
+            vec![0x02, 0x01, 0x12, 4, 0, 5, 6, 7, 8],
+            vec![0x02, 0x01, 0x12, 2, 0, 9, 10],
+        ]
+    );
+}
+
+#[tokio::test]
+async fn blocks_until_packets_completed() {
+    let mut hci = with_buffers(&[
+        &[0x04, 0x05, 4, 0x00, 0x04, 0x03, 0x13],
+        // Number of Completed Packets: 1 packet on 0x0201
+        &[0x04, 0x13, 5, 1, 0x01, 0x02, 1, 0],
+    ]);
+
+    let mut packets = Vec::new();
+    hci.write_pdu(
+        ConnectionHandle(0x0201),
+        &[1, 2, 3, 4, 5, 6, 7, 8, 9],
+        |packet| packets.push(packet),
+    )
+    .await
+    .unwrap();
+    assert_eq!(packets.len(), 2);
+    assert!(matches!(
+        packets[1],
+        Packet::Event(Event::NumberOfCompletedPackets(_))
+    ));
+    assert_eq!(hci.available(), 0);
+    assert_eq!(hci.outstanding(ConnectionHandle(0x0201)), 2);
+    assert_eq!(hci.into_inner().written_data.len(), 3);
+}
+
+#[tokio::test]
+async fn disconnection_releases_credits() {
+    let mut hci = with_buffers(&[
+        // Failed disconnection does not release the buffers.
+        &[0x04, 0x05, 4, 0x0C, 0x01, 0x02, 0x13],
+        &[0x04, 0x05, 4, 0x00, 0x01, 0x02, 0x13],
+    ]);
+    hci.write_pdu(ConnectionHandle(0x0201), &[1, 2, 3, 4, 5], |_| {})
+        .await
+        .unwrap();
+    assert_eq!(hci.available(), 0);
+
+    hci.read().await.unwrap();
+    assert_eq!(hci.available(), 0);
+    hci.read().await.unwrap();
+    assert_eq!(hci.available(), 2);
+    assert_eq!(hci.outstanding(ConnectionHandle(0x0201)), 0);
+}
+
+#[tokio::test]
+async fn completed_packets_per_connection() {
+    let mut hci = with_buffers(&[&[0x04, 0x13, 9, 2, 0x01, 0x02, 1, 0, 0x03, 0x04, 5, 0]]);
+    hci.write_pdu(ConnectionHandle(0x0201), &[1], |_| {})
+        .await
+        .unwrap();
+    hci.write_pdu(ConnectionHandle(0x0403), &[1], |_| {})
+        .await
+        .unwrap();
+    assert_eq!(hci.available(), 0);
+
+    // More packets reported than outstanding are not counted twice.
+    hci.read().await.unwrap();
+    assert_eq!(hci.available(), 2);
+    assert_eq!(hci.outstanding(ConnectionHandle(0x0201)), 0);
+    assert_eq!(hci.outstanding(ConnectionHandle(0x0403)), 0);
+}
+
+#[tokio::test]
+async fn too_many_connections() {
+    let mut hci = AclCredits::<_, 1>::new(PacketSink::new(&[]));
+    hci.set_buffer_size(&LeReadBufferSize {
+        status: Status::Success,
+        data_packet_length: 4,
+        data_packet_count: 2,
+    });
+    hci.write_pdu(ConnectionHandle(0x0201), &[1], |_| {})
+        .await
+        .unwrap();
+    let err = hci
+        .write_pdu(ConnectionHandle(0x0403), &[1], |_| {})
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::TooManyConnections(ConnectionHandle(0x0403)));
+}
+
+#[tokio::test]
+async fn acl_data_written_directly_is_counted() {
+    let mut hci = with_buffers(&[]);
+    hci.write_acl_data(
+        &AclDataHeader {
+            conn_handle: ConnectionHandle(0x0201),
+            packet_boundary: AclPacketBoundary::FirstNonFlushable,
+            broadcast: AclBroadcastFlag::PointToPoint,
+        },
+        &[1, 2],
+    )
+    .await
+    .unwrap();
+    assert_eq!(hci.available(), 1);
+    assert_eq!(hci.outstanding(ConnectionHandle(0x0201)), 1);
+}
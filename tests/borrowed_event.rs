@@ -0,0 +1,360 @@
+extern crate stm32wb_hci as hci;
+
+mod vendor;
+
+use hci::event::{AdvertisementEvent, Error, Event, Packet, borrowed};
+use hci::host::uart::{BorrowedPacket, UartHci};
+use hci::vendor::event::borrowed::{GapProcedure, VendorEvent};
+use vendor::RecordingSink;
+
+#[test]
+fn number_of_completed_packets() {
+    let buffer = [0x13, 9, 2, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+    match borrowed::Event::new(Packet(&buffer)) {
+        Ok(borrowed::Event::NumberOfCompletedPackets(event)) => {
+            let pairs: Vec<_> = event
+                .iter()
+                .map(|pair| (pair.conn_handle, pair.num_completed_packets))
+                .collect();
+            assert_eq!(
+                pairs,
+                [
+                    (hci::ConnectionHandle(0x0201), 0x0403),
+                    (hci::ConnectionHandle(0x0605), 0x0807)
+                ]
+            );
+        }
+        other => panic!("Did not get number of completed packets: {:?}", other),
+    }
+}
+
+#[test]
+fn le_advertising_report() {
+    let buffer = [
+        0x3E, 27, 0x02, 2, 0, 0, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 2, 0x07, 0x08, 0x09, 1, 1,
+        0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 3, 0x10, 0x11, 0x12, 0x13,
+    ];
+    match borrowed::Event::new(Packet(&buffer)) {
+        Ok(borrowed::Event::LeAdvertisingReport(event)) => {
+            let mut iter = event.iter();
+            let report = iter.next().unwrap();
+            assert_eq!(report.event_type, AdvertisementEvent::Advertisement);
+            assert_eq!(report.data, [0x07, 0x08]);
+            assert_eq!(report.data.as_ptr(), buffer[13..].as_ptr());
+
+            let report = iter.next().unwrap();
+            assert_eq!(report.event_type, AdvertisementEvent::DirectAdvertisement);
+            assert_eq!(report.data, [0x10, 0x11, 0x12]);
+            assert!(iter.next().is_none());
+        }
+        other => panic!("Did not get advertising report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_advertising_report_failed_incomplete() {
+    let buffer = [
+        0x3E, 27, 0x02, 2, 0, 0, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 2, 0x07, 0x08, 0x09, 1, 1,
+        0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 4, 0x10, 0x11, 0x12, 0x13,
+    ];
+    match borrowed::Event::new(Packet(&buffer)) {
+        Err(Error::LeAdvertisementReportIncomplete) => (),
+        other => panic!("Did not get incomplete advertising report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_extended_advertising_report() {
+    let buffer = [
+        0x3E, 55, 0x0D, 2, 0x13, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x01, 0x00, 0xFF,
+        0x7F, 0xD8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 2, 0x02, 0x01, 0x24,
+        0x00, 0x03, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x03, 0x02, 0x05, 0xF6, 0x7F, 0x50, 0x00,
+        0xFE, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 3, 0x07, 0x08, 0x09,
+    ];
+    match borrowed::Event::new(Packet(&buffer)) {
+        Ok(borrowed::Event::LeExtendedAdvertisingReport(event)) => {
+            let mut iter = event.iter();
+            let report = iter.next().unwrap();
+            assert_eq!(report.data, [0x02, 0x01]);
+            assert_eq!(report.data.as_ptr(), buffer[28..].as_ptr());
+
+            let report = iter.next().unwrap();
+            assert_eq!(report.data, [0x07, 0x08, 0x09]);
+            assert!(iter.next().is_none());
+        }
+        other => panic!("Did not get extended advertising report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_periodic_advertising_report() {
+    let buffer = [
+        0x3E, 11, 0x0F, 0x01, 0x02, 0xF6, 0xD8, 0xFF, 0x00, 3, 0x07, 0x08, 0x09,
+    ];
+    match borrowed::Event::new(Packet(&buffer)) {
+        Ok(borrowed::Event::LePeriodicAdvertisingReport(event)) => {
+            assert_eq!(event.sync_handle, hci::SyncHandle(0x0201));
+            assert_eq!(event.data, [0x07, 0x08, 0x09]);
+            assert_eq!(event.data.as_ptr(), buffer[10..].as_ptr());
+        }
+        other => panic!("Did not get periodic advertising report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_create_big_complete() {
+    let buffer = [
+        0x3E, 23, 0x1B, 0x00, 0x01, 0x10, 0x27, 0x00, 0x20, 0x4E, 0x00, 0x02, 0x02, 0x01, 0x00,
+        0x02, 0x28, 0x00, 0x08, 0x00, 2, 0x01, 0x02, 0x03, 0x04,
+    ];
+    match borrowed::Event::new(Packet(&buffer)) {
+        Ok(borrowed::Event::LeCreateBigComplete(event)) => {
+            assert_eq!(event.big_handle, hci::BigHandle(0x01));
+            assert_eq!(
+                event.bis_handles().collect::<Vec<_>>(),
+                [hci::ConnectionHandle(0x0201), hci::ConnectionHandle(0x0403)]
+            );
+        }
+        other => panic!("Did not get create BIG complete: {:?}", other),
+    }
+}
+
+#[test]
+fn le_create_big_complete_failure_status() {
+    let buffer = [
+        0x3E, 19, 0x1B, 0x07, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0,
+    ];
+    match borrowed::Event::new(Packet(&buffer)) {
+        Ok(borrowed::Event::LeCreateBigComplete(event)) => {
+            assert_eq!(event.status, hci::Status::OutOfMemory);
+            assert_eq!(event.phy, None);
+            assert_eq!(event.bis_handles().count(), 0);
+        }
+        other => panic!("Did not get create BIG complete: {:?}", other),
+    }
+}
+
+#[test]
+fn le_big_sync_established() {
+    let buffer = [
+        0x3E, 17, 0x1D, 0x00, 0x01, 0x20, 0x4E, 0x00, 0x02, 0x01, 0x00, 0x02, 0x28, 0x00, 0x08,
+        0x00, 1, 0x01, 0x02,
+    ];
+    match borrowed::Event::new(Packet(&buffer)) {
+        Ok(borrowed::Event::LeBigSyncEstablished(event)) => {
+            assert_eq!(event.big_handle, hci::BigHandle(0x01));
+            assert_eq!(
+                event.bis_handles().collect::<Vec<_>>(),
+                [hci::ConnectionHandle(0x0201)]
+            );
+        }
+        other => panic!("Did not get BIG sync established: {:?}", other),
+    }
+}
+
+#[test]
+fn le_connectionless_iq_report() {
+    let buffer = [
+        0x3E, 31, 0x15, 0x01, 0x02, 0x05, 0xA8, 0xFD, 0x01, 0x00, 0x01, 0x00, 0x34, 0x12, 9, 0x10,
+        0xF0, 0x20, 0xE0, 0x7F, 0x80, 0x00, 0x00, 0x01, 0xFF, 0x02, 0xFE, 0x03, 0xFD, 0x04, 0xFC,
+        0x05, 0xFB,
+    ];
+    match borrowed::Event::new(Packet(&buffer)) {
+        Ok(borrowed::Event::LeConnectionlessIqReport(event)) => {
+            assert_eq!(event.sync_handle, hci::SyncHandle(0x0201));
+            let samples: Vec<_> = event.iq_samples().map(|s| (s.i, s.q)).collect();
+            assert_eq!(samples.len(), 9);
+            assert_eq!(samples[..3], [(16, -16), (32, -32), (127, -128)]);
+        }
+        other => panic!("Did not get connectionless IQ report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_connectionless_iq_report_insufficient_resources() {
+    let buffer = [
+        0x3E, 13, 0x15, 0x01, 0x02, 0xFF, 0xA8, 0xFD, 0x01, 0xFF, 0xFF, 0xFF, 0x34, 0x12, 0,
+    ];
+    match borrowed::Event::new(Packet(&buffer)) {
+        Ok(borrowed::Event::LeConnectionlessIqReport(event)) => {
+            assert_eq!(event.channel_index, None);
+            assert_eq!(event.cte_type, None);
+            assert_eq!(event.slot_duration, None);
+            assert_eq!(
+                event.packet_status,
+                hci::event::IqPacketStatus::InsufficientResources
+            );
+            assert_eq!(event.iq_samples().count(), 0);
+        }
+        other => panic!("Did not get connectionless IQ report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_connection_iq_report() {
+    let buffer = [
+        0x3E, 32, 0x16, 0x01, 0x02, 0x02, 0x10, 0xA8, 0xFD, 0x02, 0x01, 0x02, 0x01, 0x34, 0x12, 9,
+        0x10, 0xF0, 0x20, 0xE0, 0x7F, 0x80, 0x00, 0x00, 0x01, 0xFF, 0x02, 0xFE, 0x03, 0xFD, 0x04,
+        0xFC, 0x05, 0xFB,
+    ];
+    match borrowed::Event::new(Packet(&buffer)) {
+        Ok(borrowed::Event::LeConnectionIqReport(event)) => {
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            let samples: Vec<_> = event.iq_samples().map(|s| (s.i, s.q)).collect();
+            assert_eq!(samples.len(), 9);
+            assert_eq!(samples[8], (5, -5));
+        }
+        other => panic!("Did not get connection IQ report: {:?}", other),
+    }
+}
+
+#[test]
+fn other_event() {
+    let buffer = [0x1A, 1, 0x00];
+    match borrowed::Event::new(Packet(&buffer)) {
+        Ok(borrowed::Event::Other(event)) => {
+            assert_eq!(event.event_code(), 0x1A);
+            assert_eq!(event.params(), [0x00]);
+            match event.to_event() {
+                Ok(Event::DataBufferOverflow(_)) => (),
+                other => panic!("Did not get data buffer overflow: {:?}", other),
+            }
+        }
+        other => panic!("Did not get other event: {:?}", other),
+    }
+}
+
+#[test]
+fn other_event_unknown() {
+    let buffer = [0x02, 1, 0x00];
+    match borrowed::Event::new(Packet(&buffer)) {
+        Ok(borrowed::Event::Other(event)) => {
+            assert_eq!(event.to_event().err(), Some(Error::UnknownEvent(0x02)));
+        }
+        other => panic!("Did not get other event: {:?}", other),
+    }
+}
+
+#[test]
+fn event_size() {
+    assert!(core::mem::size_of::<borrowed::Event>() <= 80);
+    assert!(core::mem::size_of::<VendorEvent>() <= 40);
+}
+
+#[test]
+fn gap_device_found() {
+    let buffer = [
+        0xFF, 14, 0x06, 0x04, 0x04, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 2, 0x07, 0x08, 0xF6,
+    ];
+    match borrowed::Event::new(Packet(&buffer)) {
+        Ok(borrowed::Event::Vendor(VendorEvent::GapDeviceFound(event))) => {
+            assert_eq!(event.event, AdvertisementEvent::ScanResponse);
+            assert_eq!(
+                event.bdaddr,
+                hci::BdAddrType::Random(hci::BdAddr([0x01, 0x02, 0x03, 0x04, 0x05, 0x06]))
+            );
+            assert_eq!(event.data, [0x07, 0x08]);
+            assert_eq!(event.rssi, Some(-10));
+        }
+        other => panic!("Did not get GAP device found: {:?}", other),
+    }
+}
+
+#[test]
+fn gap_procedure_complete_name_discovery() {
+    let buffer = [0xFF, 7, 0x07, 0x04, 0x04, 0x00, b'a', b'b', b'c'];
+    match borrowed::Event::new(Packet(&buffer)) {
+        Ok(borrowed::Event::Vendor(VendorEvent::GapProcedureComplete(event))) => {
+            assert_eq!(event.procedure, GapProcedure::NameDiscovery(b"abc"));
+        }
+        other => panic!("Did not get GAP procedure complete: {:?}", other),
+    }
+}
+
+#[test]
+fn gatt_notification() {
+    let buffer = [
+        0xFF, 10, 0x0F, 0x0C, 0x01, 0x02, 5, 0x03, 0x04, 0x05, 0x06, 0x07,
+    ];
+    match borrowed::Event::new(Packet(&buffer)) {
+        Ok(borrowed::Event::Vendor(VendorEvent::GattNotification(event))) => {
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(
+                event.attribute_handle,
+                hci::vendor::event::AttributeHandle(0x0403)
+            );
+            assert_eq!(event.value, [0x05, 0x06, 0x07]);
+        }
+        other => panic!("Did not get GATT notification: {:?}", other),
+    }
+}
+
+#[test]
+fn other_vendor_event() {
+    let buffer = [0xFF, 2, 0x00, 0x04];
+    match borrowed::Event::new(Packet(&buffer)) {
+        Ok(borrowed::Event::Vendor(VendorEvent::Other(event))) => {
+            assert_eq!(event.event_code(), 0x0400);
+            assert_eq!(event.params(), []);
+            match event.to_event() {
+                Ok(hci::vendor::event::VendorEvent::GapLimitedDiscoverableTimeout) => (),
+                other => panic!("Did not get GAP limited discoverable timeout: {:?}", other),
+            }
+        }
+        other => panic!("Did not get other vendor event: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn read_into() {
+    let mut sink = RecordingSink::new();
+    sink.read_data = vec![0x04, 0x13, 5, 1, 0x01, 0x02, 0x03, 0x04];
+
+    let mut buffer = [0; 256];
+    match sink.read_into(&mut buffer).await {
+        Ok(BorrowedPacket::Event(borrowed::Event::NumberOfCompletedPackets(event))) => {
+            let pair = event.iter().next().unwrap();
+            assert_eq!(pair.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(pair.num_completed_packets, 0x0403);
+        }
+        other => panic!("Did not get number of completed packets: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn read_into_acl_data() {
+    let mut sink = RecordingSink::new();
+    sink.read_data = vec![0x02, 0x01, 0x22, 2, 0, 0x0A, 0x0B];
+
+    let mut buffer = [0; 256];
+    match sink.read_into(&mut buffer).await {
+        Ok(BorrowedPacket::AclData(acl)) => {
+            assert_eq!(acl.header.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(acl.data, [0x0A, 0x0B]);
+        }
+        other => panic!("Did not get ACL data: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn read_into_buffer_too_short() {
+    for len in 0..5 {
+        let mut sink = RecordingSink::new();
+        sink.read_data = vec![0x02, 0x01, 0x22, 2, 0, 0x0A, 0x0B];
+
+        let mut buffer = vec![0; len];
+        assert_eq!(
+            sink.read_into(&mut buffer).await.err(),
+            Some(hci::host::uart::Error::DataTooLong(0))
+        );
+    }
+
+    let mut sink = RecordingSink::new();
+    sink.read_data = vec![0x02, 0x01, 0x22, 2, 0, 0x0A, 0x0B];
+    let mut buffer = [0; 6];
+    assert_eq!(
+        sink.read_into(&mut buffer).await.err(),
+        Some(hci::host::uart::Error::DataTooLong(2))
+    );
+}
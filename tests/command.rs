@@ -0,0 +1,1455 @@
+extern crate stm32wb_hci as hci;
+
+mod vendor;
+
+use hci::host::command::{Command, Error, Packet};
+use hci::host::*;
+use hci::types::extended_advertisement::{
+    AdvSet, AdvertisingEvent, AdvertisingOperation, AdvertisingPhy, ExtendedAdvertisingInterval,
+};
+use hci::vendor::command::gap::{GapCommands, Role};
+use hci::vendor::command::gatt::{
+    AddServiceParameters, GattCommands, MultipleCharacteristicReadParameters, ServiceType, Uuid,
+};
+use hci::vendor::command::hal::{HalCommands, PowerLevel};
+use hci::vendor::command::l2cap::L2capCommands;
+use hci::vendor::command::{VendorCommand, VendorCommandError};
+use hci::vendor::event::AttributeHandle;
+use std::time::Duration;
+use vendor::RecordingSink;
+
+fn decode(sink: &RecordingSink) -> Result<Command<'_>, Error> {
+    // Skip the packet type byte written by the UART transport.
+    Command::new(Packet(&sink.written_data[1..]))
+}
+
+#[tokio::test]
+async fn disconnect() {
+    let mut sink = RecordingSink::new();
+    sink.disconnect(hci::ConnectionHandle(0x0201), hci::Status::AuthFailure)
+        .await
+        .unwrap();
+    match decode(&sink) {
+        Ok(Command::Disconnect {
+            conn_handle,
+            reason,
+        }) => {
+            assert_eq!(conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(reason, hci::Status::AuthFailure);
+        }
+        other => panic!("Did not get Disconnect: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn reset() {
+    let mut sink = RecordingSink::new();
+    sink.reset().await;
+    match decode(&sink) {
+        Ok(Command::Reset) => (),
+        other => panic!("Did not get Reset: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn set_event_mask() {
+    let mut sink = RecordingSink::new();
+    HostHci::set_event_mask(
+        &mut sink,
+        EventFlags::DISCONNECTION_COMPLETE | EventFlags::HARDWARE_ERROR,
+    )
+    .await;
+    match decode(&sink) {
+        Ok(Command::SetEventMask(flags)) => assert_eq!(
+            flags.bits(),
+            (EventFlags::DISCONNECTION_COMPLETE | EventFlags::HARDWARE_ERROR).bits()
+        ),
+        other => panic!("Did not get SetEventMask: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_set_advertising_data() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_advertising_data(&[1, 2, 3]).await.unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetAdvertisingData(data)) => assert_eq!(data, [1, 2, 3]),
+        other => panic!("Did not get LeSetAdvertisingData: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_set_scan_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_scan_parameters(&ScanParameters {
+        scan_type: ScanType::Active,
+        scan_window: ScanWindow::start_every(Duration::from_millis(10))
+            .unwrap()
+            .open_for(Duration::from_millis(5))
+            .unwrap(),
+        own_address_type: OwnAddressType::Public,
+        filter_policy: ScanFilterPolicy::AcceptAll,
+    })
+    .await;
+    match decode(&sink) {
+        Ok(Command::LeSetScanParameters(params)) => {
+            assert_eq!(params.scan_type, ScanType::Active);
+            assert_eq!(params.scan_window.interval(), Duration::from_millis(10));
+            assert_eq!(params.scan_window.window(), Duration::from_millis(5));
+            assert_eq!(params.own_address_type, OwnAddressType::Public);
+            assert_eq!(params.filter_policy, ScanFilterPolicy::AcceptAll);
+        }
+        other => panic!("Did not get LeSetScanParameters: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_set_data_length() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_data_length(hci::ConnectionHandle(0x0201), 0x00FB, 0x0848)
+        .await
+        .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetDataLength {
+            conn_handle,
+            tx_octets,
+            tx_time,
+        }) => {
+            assert_eq!(conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(tx_octets, 0x00FB);
+            assert_eq!(tx_time, 0x0848);
+        }
+        other => panic!("Did not get LeSetDataLength: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_set_phy() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_phy(
+        hci::ConnectionHandle(0x0201),
+        &PhyPreferences {
+            all_phys: AllPhys::NO_TX_PREFERENCE,
+            tx_phys: PhyMask::empty(),
+            rx_phys: PhyMask::LE_2M,
+        },
+        PhyOptions::S2,
+    )
+    .await
+    .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetPhy {
+            conn_handle,
+            phys,
+            options,
+        }) => {
+            assert_eq!(conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(phys.all_phys, AllPhys::NO_TX_PREFERENCE);
+            assert_eq!(phys.tx_phys, PhyMask::empty());
+            assert_eq!(phys.rx_phys, PhyMask::LE_2M);
+            assert_eq!(options, PhyOptions::S2);
+        }
+        other => panic!("Did not get LeSetPhy: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_generate_dhkey_v2() {
+    let mut sink = RecordingSink::new();
+    let remote_key = P256PublicKey([0x5A; 64]);
+    sink.le_generate_dhkey_v2(&remote_key, PrivateKeyType::Generated)
+        .await;
+    match decode(&sink) {
+        Ok(Command::LeGenerateDHKeyV2 {
+            remote_key: key,
+            key_type,
+        }) => {
+            assert_eq!(key, remote_key);
+            assert_eq!(key_type, PrivateKeyType::Generated);
+        }
+        other => panic!("Did not get LeGenerateDHKeyV2: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_add_device_to_resolving_list() {
+    let mut sink = RecordingSink::new();
+    sink.le_add_device_to_resolving_list(&ResolvingListParameters {
+        peer_identity_address: hci::BdAddrType::Public(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        peer_irk: EncryptionKey([0x11; 16]),
+        local_irk: EncryptionKey([0x22; 16]),
+    })
+    .await;
+    match decode(&sink) {
+        Ok(Command::LeAddDeviceToResolvingList(params)) => {
+            assert_eq!(
+                params.peer_identity_address,
+                hci::BdAddrType::Public(hci::BdAddr([1, 2, 3, 4, 5, 6]))
+            );
+            assert_eq!(params.peer_irk.0, [0x11; 16]);
+            assert_eq!(params.local_irk.0, [0x22; 16]);
+        }
+        other => panic!("Did not get LeAddDeviceToResolvingList: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_set_resolvable_private_address_timeout() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_resolvable_private_address_timeout(Duration::from_secs(900))
+        .await
+        .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetResolvablePrivateAddressTimeout(timeout)) => {
+            assert_eq!(timeout, Duration::from_secs(900));
+        }
+        other => panic!(
+            "Did not get LeSetResolvablePrivateAddressTimeout: {:?}",
+            other
+        ),
+    }
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_advertising_parameters(&ExtendedAdvertisingParameters {
+        adv_handle: hci::AdvertisingHandle(0x01),
+        adv_event_properties: AdvertisingEvent::LEGACY | AdvertisingEvent::CONNECTABLE,
+        adv_interval: ExtendedAdvertisingInterval::with_range(
+            Duration::from_millis(20),
+            Duration::from_secs(10),
+        )
+        .unwrap(),
+        primary_adv_channel_map: Channels::all(),
+        own_address_type: OwnAddressType::Public,
+        peer_address: hci::BdAddrType::Random(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        adv_filter_policy: AdvertisingFilterPolicy::AllowConnectionAndScan,
+        adv_tx_power: None,
+        primary_adv_phy: AdvertisingPhy::Le1M,
+        secondary_adv_max_skip: 0,
+        secondary_adv_phy: AdvertisingPhy::LeCoded,
+        adv_sid: 0x03,
+        scan_request_notification_enable: false,
+    })
+    .await
+    .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetExtendedAdvertisingParameters(params)) => {
+            assert_eq!(params.adv_handle, hci::AdvertisingHandle(0x01));
+            assert_eq!(params.adv_event_properties.bits(), 0x0011);
+            assert_eq!(params.adv_interval.min(), Duration::from_millis(20));
+            assert_eq!(params.adv_interval.max(), Duration::from_secs(10));
+            assert_eq!(params.primary_adv_channel_map, Channels::all());
+            assert_eq!(params.own_address_type, OwnAddressType::Public);
+            assert_eq!(
+                params.peer_address,
+                hci::BdAddrType::Random(hci::BdAddr([1, 2, 3, 4, 5, 6]))
+            );
+            assert_eq!(params.adv_tx_power, None);
+            assert_eq!(params.primary_adv_phy, AdvertisingPhy::Le1M);
+            assert_eq!(params.secondary_adv_phy, AdvertisingPhy::LeCoded);
+            assert_eq!(params.adv_sid, 0x03);
+            assert!(!params.scan_request_notification_enable);
+        }
+        other => panic!(
+            "Did not get LeSetExtendedAdvertisingParameters: {:?}",
+            other
+        ),
+    }
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_data() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_advertising_data(&ExtendedAdvertisingData {
+        adv_handle: hci::AdvertisingHandle(0x02),
+        operation: AdvertisingOperation::LastFragment,
+        fragment: true,
+        data: &[0x01, 0x02, 0x03],
+    })
+    .await
+    .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetExtendedAdvertisingData(data)) => {
+            assert_eq!(data.adv_handle, hci::AdvertisingHandle(0x02));
+            assert_eq!(data.operation, AdvertisingOperation::LastFragment);
+            assert!(data.fragment);
+            assert_eq!(data.data, [0x01, 0x02, 0x03]);
+        }
+        other => panic!("Did not get LeSetExtendedAdvertisingData: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_advertising_enable(
+        true,
+        &[AdvSet {
+            handle: hci::AdvertisingHandle(0x01),
+            duration: 0x0201,
+            max_extended_adv_events: 3,
+        }],
+    )
+    .await
+    .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetExtendedAdvertisingEnable { enable, sets }) => {
+            assert!(enable);
+            assert_eq!(sets.len(), 1);
+            let set = sets.iter().next().unwrap();
+            assert_eq!(set.handle, hci::AdvertisingHandle(0x01));
+            assert_eq!(set.duration, 0x0201);
+            assert_eq!(set.max_extended_adv_events, 3);
+        }
+        other => panic!("Did not get LeSetExtendedAdvertisingEnable: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_scan_parameters(&ExtendedScanParameters {
+        own_address_type: OwnAddressType::Random,
+        filter_policy: ScanFilterPolicy::WhiteList,
+        le_1m_phy: None,
+        le_coded_phy: Some(PhyScanParameters {
+            scan_type: ScanType::Active,
+            scan_window: ScanWindow::start_every(Duration::from_millis(100))
+                .and_then(|b| b.open_for(Duration::from_millis(50)))
+                .unwrap(),
+        }),
+    })
+    .await
+    .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetExtendedScanParameters(params)) => {
+            assert_eq!(params.own_address_type, OwnAddressType::Random);
+            assert_eq!(params.filter_policy, ScanFilterPolicy::WhiteList);
+            assert!(params.le_1m_phy.is_none());
+            let coded = params.le_coded_phy.unwrap();
+            assert_eq!(coded.scan_type, ScanType::Active);
+            assert_eq!(coded.scan_window.interval(), Duration::from_millis(100));
+            assert_eq!(coded.scan_window.window(), Duration::from_millis(50));
+        }
+        other => panic!("Did not get LeSetExtendedScanParameters: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_enable() {
+    let mut sink = RecordingSink::new();
+    let params = ExtendedScanEnable {
+        enable: true,
+        filter_duplicates: FilterDuplicates::Enabled,
+        duration: Duration::from_millis(500),
+        period: Duration::from_millis(2560),
+    };
+    sink.le_set_extended_scan_enable(&params).await.unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetExtendedScanEnable(decoded)) => assert_eq!(decoded, params),
+        other => panic!("Did not get LeSetExtendedScanEnable: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_extended_create_connection() {
+    let mut sink = RecordingSink::new();
+    sink.le_extended_create_connection(&ExtendedConnectionParameters {
+        initiator_filter_policy: ConnectionFilterPolicy::UseAddress,
+        own_address_type: OwnAddressType::Public,
+        peer_address: PeerAddrType::RandomDeviceAddress(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        le_1m_phy: Some(PhyConnectionParameters {
+            scan_window: ScanWindow::start_every(Duration::from_millis(50))
+                .and_then(|b| b.open_for(Duration::from_millis(25)))
+                .unwrap(),
+            conn_interval: ConnectionIntervalBuilder::new()
+                .with_range(Duration::from_millis(50), Duration::from_millis(500))
+                .with_latency(10)
+                .with_supervision_timeout(Duration::from_secs(15))
+                .build()
+                .unwrap(),
+            expected_connection_length: ExpectedConnectionLength::new(
+                Duration::from_millis(200),
+                Duration::from_millis(500),
+            )
+            .unwrap(),
+        }),
+        le_2m_phy: None,
+        le_coded_phy: None,
+    })
+    .await
+    .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeExtendedCreateConnection(params)) => {
+            assert_eq!(params.own_address_type, OwnAddressType::Public);
+            match params.peer_address {
+                PeerAddrType::RandomDeviceAddress(addr) => {
+                    assert_eq!(addr, hci::BdAddr([1, 2, 3, 4, 5, 6]))
+                }
+                other => panic!("Got peer address: {:?}", other),
+            }
+            assert!(params.le_2m_phy.is_none());
+            assert!(params.le_coded_phy.is_none());
+            let phy = params.le_1m_phy.unwrap();
+            assert_eq!(phy.scan_window.interval(), Duration::from_millis(50));
+            assert_eq!(phy.conn_interval.conn_latency(), 10);
+        }
+        other => panic!("Did not get LeExtendedCreateConnection: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_periodic_advertising_parameters(&PeriodicAdvertisingParameters {
+        adv_handle: hci::AdvertisingHandle(1),
+        interval_min: Duration::from_micros(7500),
+        interval_max: Duration::from_millis(200),
+        include_tx_power: true,
+    })
+    .await
+    .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetPeriodicAdvertisingParameters(params)) => {
+            assert_eq!(params.adv_handle, hci::AdvertisingHandle(1));
+            assert_eq!(params.interval_min, Duration::from_micros(7500));
+            assert_eq!(params.interval_max, Duration::from_millis(200));
+            assert!(params.include_tx_power);
+        }
+        other => panic!(
+            "Did not get LeSetPeriodicAdvertisingParameters: {:?}",
+            other
+        ),
+    }
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_data() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_periodic_advertising_data(&PeriodicAdvertisingData {
+        adv_handle: hci::AdvertisingHandle(1),
+        operation: AdvertisingOperation::FirstFragment,
+        data: &[1, 2, 3],
+    })
+    .await
+    .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetPeriodicAdvertisingData(data)) => {
+            assert_eq!(data.adv_handle, hci::AdvertisingHandle(1));
+            assert_eq!(data.operation, AdvertisingOperation::FirstFragment);
+            assert_eq!(data.data, [1, 2, 3]);
+        }
+        other => panic!("Did not get LeSetPeriodicAdvertisingData: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_periodic_advertising_enable(true, hci::AdvertisingHandle(2))
+        .await;
+    match decode(&sink) {
+        Ok(Command::LeSetPeriodicAdvertisingEnable { enable, adv_handle }) => {
+            assert!(enable);
+            assert_eq!(adv_handle, hci::AdvertisingHandle(2));
+        }
+        other => panic!("Did not get LeSetPeriodicAdvertisingEnable: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_create_sync() {
+    let mut sink = RecordingSink::new();
+    sink.le_periodic_advertising_create_sync(&PeriodicAdvertisingSyncParameters {
+        options: SyncOptions::USE_PERIODIC_ADVERTISER_LIST
+            | SyncOptions::REPORTING_INITIALLY_DISABLED,
+        adv_sid: 3,
+        adv_address: hci::BdAddrType::Random(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        skip: 5,
+        sync_timeout: Duration::from_secs(1),
+        sync_cte_type: SyncCteType::NO_CTE,
+    })
+    .await
+    .unwrap();
+    match decode(&sink) {
+        Ok(Command::LePeriodicAdvertisingCreateSync(params)) => {
+            assert_eq!(
+                params.options,
+                SyncOptions::USE_PERIODIC_ADVERTISER_LIST
+                    | SyncOptions::REPORTING_INITIALLY_DISABLED
+            );
+            assert_eq!(params.adv_sid, 3);
+            assert_eq!(
+                params.adv_address,
+                hci::BdAddrType::Random(hci::BdAddr([1, 2, 3, 4, 5, 6]))
+            );
+            assert_eq!(params.skip, 5);
+            assert_eq!(params.sync_timeout, Duration::from_secs(1));
+            assert_eq!(params.sync_cte_type, SyncCteType::NO_CTE);
+        }
+        other => panic!("Did not get LePeriodicAdvertisingCreateSync: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_terminate_sync() {
+    let mut sink = RecordingSink::new();
+    sink.le_periodic_advertising_terminate_sync(hci::SyncHandle(0x0201))
+        .await;
+    match decode(&sink) {
+        Ok(Command::LePeriodicAdvertisingTerminateSync(sync_handle)) => {
+            assert_eq!(sync_handle, hci::SyncHandle(0x0201))
+        }
+        other => panic!(
+            "Did not get LePeriodicAdvertisingTerminateSync: {:?}",
+            other
+        ),
+    }
+}
+
+#[tokio::test]
+async fn le_remove_device_from_periodic_advertiser_list() {
+    let mut sink = RecordingSink::new();
+    let advertiser = PeriodicAdvertiser {
+        address: hci::BdAddrType::Public(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        adv_sid: 0x0F,
+    };
+    sink.le_remove_device_from_periodic_advertiser_list(&advertiser)
+        .await
+        .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeRemoveDeviceFromPeriodicAdvertiserList(decoded)) => {
+            assert_eq!(decoded, advertiser)
+        }
+        other => panic!(
+            "Did not get LeRemoveDeviceFromPeriodicAdvertiserList: {:?}",
+            other
+        ),
+    }
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_sync_transfer() {
+    let mut sink = RecordingSink::new();
+    sink.le_periodic_advertising_sync_transfer(
+        hci::ConnectionHandle(0x0201),
+        0x0403,
+        hci::SyncHandle(0x0605),
+    )
+    .await;
+    match decode(&sink) {
+        Ok(Command::LePeriodicAdvertisingSyncTransfer {
+            conn_handle,
+            service_data,
+            sync_handle,
+        }) => {
+            assert_eq!(conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(service_data, 0x0403);
+            assert_eq!(sync_handle, hci::SyncHandle(0x0605));
+        }
+        other => panic!("Did not get LePeriodicAdvertisingSyncTransfer: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_set_info_transfer() {
+    let mut sink = RecordingSink::new();
+    sink.le_periodic_advertising_set_info_transfer(
+        hci::ConnectionHandle(0x0201),
+        0x0403,
+        hci::AdvertisingHandle(0x05),
+    )
+    .await;
+    match decode(&sink) {
+        Ok(Command::LePeriodicAdvertisingSetInfoTransfer {
+            conn_handle,
+            service_data,
+            adv_handle,
+        }) => {
+            assert_eq!(conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(service_data, 0x0403);
+            assert_eq!(adv_handle, hci::AdvertisingHandle(0x05));
+        }
+        other => panic!(
+            "Did not get LePeriodicAdvertisingSetInfoTransfer: {:?}",
+            other
+        ),
+    }
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_sync_transfer_parameters() {
+    let expected = SyncTransferParameters {
+        mode: SyncTransferMode::ReportsEnabledFilterDuplicates,
+        skip: 5,
+        sync_timeout: Duration::from_secs(1),
+        sync_cte_type: SyncCteType::NO_AOA | SyncCteType::NO_TYPE_3,
+    };
+    let mut sink = RecordingSink::new();
+    sink.le_set_periodic_advertising_sync_transfer_parameters(
+        hci::ConnectionHandle(0x0201),
+        &expected,
+    )
+    .await
+    .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetPeriodicAdvertisingSyncTransferParameters {
+            conn_handle,
+            params,
+        }) => {
+            assert_eq!(conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(params, expected);
+        }
+        other => panic!(
+            "Did not get LeSetPeriodicAdvertisingSyncTransferParameters: {:?}",
+            other
+        ),
+    }
+}
+
+#[tokio::test]
+async fn le_set_default_periodic_advertising_sync_transfer_parameters() {
+    let expected = SyncTransferParameters {
+        mode: SyncTransferMode::NoSync,
+        skip: 0,
+        sync_timeout: Duration::from_millis(100),
+        sync_cte_type: SyncCteType::empty(),
+    };
+    let mut sink = RecordingSink::new();
+    sink.le_set_default_periodic_advertising_sync_transfer_parameters(&expected)
+        .await
+        .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetDefaultPeriodicAdvertisingSyncTransferParameters(params)) => {
+            assert_eq!(params, expected);
+        }
+        other => panic!(
+            "Did not get LeSetDefaultPeriodicAdvertisingSyncTransferParameters: {:?}",
+            other
+        ),
+    }
+}
+
+#[tokio::test]
+async fn le_set_cig_parameters() {
+    let expected_params = CigParameters {
+        cig_id: 0x01,
+        sdu_interval_c_to_p: Duration::from_millis(10),
+        sdu_interval_p_to_c: Duration::from_micros(7500),
+        worst_case_sca: hci::event::CentralClockAccuracy::Ppm50,
+        packing: Packing::Interleaved,
+        framing: Framing::Unframed,
+        max_transport_latency_c_to_p: Duration::from_millis(20),
+        max_transport_latency_p_to_c: Duration::from_millis(40),
+    };
+    let expected_cis = [CisParameters {
+        cis_id: 0x02,
+        max_sdu_c_to_p: 40,
+        max_sdu_p_to_c: 0,
+        phy_c_to_p: PhyMask::LE_2M,
+        phy_p_to_c: PhyMask::LE_1M | PhyMask::LE_CODED,
+        rtn_c_to_p: 2,
+        rtn_p_to_c: 0,
+    }];
+    let mut sink = RecordingSink::new();
+    sink.le_set_cig_parameters(&expected_params, &expected_cis)
+        .await
+        .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetCigParameters { params, cis }) => {
+            assert_eq!(params, expected_params);
+            assert_eq!(cis.len(), 1);
+            assert_eq!(cis.iter().collect::<Vec<_>>(), expected_cis);
+        }
+        other => panic!("Did not get LeSetCigParameters: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_set_cig_parameters_test() {
+    let expected_params = CigTestParameters {
+        cig_id: 0x02,
+        sdu_interval_c_to_p: Duration::from_millis(10),
+        sdu_interval_p_to_c: Duration::from_millis(10),
+        ft_c_to_p: 1,
+        ft_p_to_c: 2,
+        iso_interval: Duration::from_millis(10),
+        worst_case_sca: hci::event::CentralClockAccuracy::Ppm20,
+        packing: Packing::Sequential,
+        framing: Framing::Framed,
+    };
+    let expected_cis = [CisTestParameters {
+        cis_id: 0x03,
+        nse: 2,
+        max_sdu_c_to_p: 40,
+        max_sdu_p_to_c: 40,
+        max_pdu_c_to_p: 40,
+        max_pdu_p_to_c: 40,
+        phy_c_to_p: PhyMask::LE_2M,
+        phy_p_to_c: PhyMask::LE_2M,
+        bn_c_to_p: 1,
+        bn_p_to_c: 1,
+    }];
+    let mut sink = RecordingSink::new();
+    sink.le_set_cig_parameters_test(&expected_params, &expected_cis)
+        .await
+        .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetCigParametersTest { params, cis }) => {
+            assert_eq!(params, expected_params);
+            assert_eq!(cis.iter().collect::<Vec<_>>(), expected_cis);
+        }
+        other => panic!("Did not get LeSetCigParametersTest: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_create_cis() {
+    let expected = [
+        CisConnection {
+            cis_conn_handle: hci::ConnectionHandle(0x0201),
+            acl_conn_handle: hci::ConnectionHandle(0x0403),
+        },
+        CisConnection {
+            cis_conn_handle: hci::ConnectionHandle(0x0605),
+            acl_conn_handle: hci::ConnectionHandle(0x0403),
+        },
+    ];
+    let mut sink = RecordingSink::new();
+    sink.le_create_cis(&expected).await.unwrap();
+    match decode(&sink) {
+        Ok(Command::LeCreateCis(cis)) => {
+            assert_eq!(cis.len(), 2);
+            assert_eq!(cis.iter().collect::<Vec<_>>(), expected);
+        }
+        other => panic!("Did not get LeCreateCis: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_remove_cig() {
+    let mut sink = RecordingSink::new();
+    sink.le_remove_cig(0x01).await;
+    match decode(&sink) {
+        Ok(Command::LeRemoveCig(cig_id)) => assert_eq!(cig_id, 0x01),
+        other => panic!("Did not get LeRemoveCig: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_reject_cis_request() {
+    let mut sink = RecordingSink::new();
+    sink.le_reject_cis_request(hci::ConnectionHandle(0x0201), hci::Status::LimitedResources)
+        .await;
+    match decode(&sink) {
+        Ok(Command::LeRejectCisRequest {
+            conn_handle,
+            reason,
+        }) => {
+            assert_eq!(conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(reason, hci::Status::LimitedResources);
+        }
+        other => panic!("Did not get LeRejectCisRequest: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_create_big() {
+    let expected = BigParameters {
+        big_handle: hci::BigHandle(0x01),
+        adv_handle: hci::AdvertisingHandle(0x02),
+        num_bis: 2,
+        sdu_interval: Duration::from_millis(10),
+        max_sdu: 40,
+        max_transport_latency: Duration::from_millis(20),
+        rtn: 2,
+        phy: PhyMask::LE_2M,
+        packing: Packing::Interleaved,
+        framing: Framing::Framed,
+        broadcast_code: Some([0x5A; 16]),
+    };
+    let mut sink = RecordingSink::new();
+    sink.le_create_big(&expected).await.unwrap();
+    match decode(&sink) {
+        Ok(Command::LeCreateBig(params)) => assert_eq!(params, expected),
+        other => panic!("Did not get LeCreateBig: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_terminate_big() {
+    let mut sink = RecordingSink::new();
+    sink.le_terminate_big(
+        hci::BigHandle(0x01),
+        hci::Status::ConnectionTerminatedByHost,
+    )
+    .await;
+    match decode(&sink) {
+        Ok(Command::LeTerminateBig { big_handle, reason }) => {
+            assert_eq!(big_handle, hci::BigHandle(0x01));
+            assert_eq!(reason, hci::Status::ConnectionTerminatedByHost);
+        }
+        other => panic!("Did not get LeTerminateBig: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_big_create_sync() {
+    let expected = BigSyncParameters {
+        big_handle: hci::BigHandle(0x01),
+        sync_handle: hci::SyncHandle(0x0201),
+        broadcast_code: None,
+        mse: 0x1F,
+        big_sync_timeout: Duration::from_millis(2500),
+        bis: &[0x01, 0x03],
+    };
+    let mut sink = RecordingSink::new();
+    sink.le_big_create_sync(&expected).await.unwrap();
+    match decode(&sink) {
+        Ok(Command::LeBigCreateSync(params)) => assert_eq!(params, expected),
+        other => panic!("Did not get LeBigCreateSync: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_big_terminate_sync() {
+    let mut sink = RecordingSink::new();
+    sink.le_big_terminate_sync(hci::BigHandle(0x01)).await;
+    match decode(&sink) {
+        Ok(Command::LeBigTerminateSync(big_handle)) => {
+            assert_eq!(big_handle, hci::BigHandle(0x01))
+        }
+        other => panic!("Did not get LeBigTerminateSync: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_setup_iso_data_path() {
+    let expected = IsoDataPathParameters {
+        conn_handle: hci::ConnectionHandle(0x0201),
+        direction: DataPathDirection::Input,
+        data_path_id: 0x01,
+        codec_id: CodecId {
+            coding_format: 0xFF,
+            company_id: 0x0030,
+            vendor_codec_id: 0x0102,
+        },
+        controller_delay: Duration::from_micros(2500),
+        codec_configuration: &[0x0A, 0x0B],
+    };
+    let mut sink = RecordingSink::new();
+    sink.le_setup_iso_data_path(&expected).await.unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetupIsoDataPath(params)) => assert_eq!(params, expected),
+        other => panic!("Did not get LeSetupIsoDataPath: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_remove_iso_data_path() {
+    let mut sink = RecordingSink::new();
+    sink.le_remove_iso_data_path(hci::ConnectionHandle(0x0201), DataPathDirections::OUTPUT)
+        .await;
+    match decode(&sink) {
+        Ok(Command::LeRemoveIsoDataPath {
+            conn_handle,
+            directions,
+        }) => {
+            assert_eq!(conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(directions, DataPathDirections::OUTPUT);
+        }
+        other => panic!("Did not get LeRemoveIsoDataPath: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_enhanced_read_transmit_power_level() {
+    let mut sink = RecordingSink::new();
+    sink.le_enhanced_read_transmit_power_level(
+        hci::ConnectionHandle(0x0201),
+        hci::event::PowerControlPhy::LeCodedS8,
+    )
+    .await;
+    match decode(&sink) {
+        Ok(Command::LeEnhancedReadTransmitPowerLevel { conn_handle, phy }) => {
+            assert_eq!(conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(phy, hci::event::PowerControlPhy::LeCodedS8);
+        }
+        other => panic!("Did not get LeEnhancedReadTransmitPowerLevel: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_read_remote_transmit_power_level() {
+    let mut sink = RecordingSink::new();
+    sink.le_read_remote_transmit_power_level(
+        hci::ConnectionHandle(0x0201),
+        hci::event::PowerControlPhy::Le1M,
+    )
+    .await;
+    match decode(&sink) {
+        Ok(Command::LeReadRemoteTransmitPowerLevel { conn_handle, phy }) => {
+            assert_eq!(conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(phy, hci::event::PowerControlPhy::Le1M);
+        }
+        other => panic!("Did not get LeReadRemoteTransmitPowerLevel: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_set_path_loss_reporting_parameters() {
+    let expected = PathLossReportingParameters {
+        conn_handle: hci::ConnectionHandle(0x0201),
+        high_threshold: None,
+        high_hysteresis: 0,
+        low_threshold: Some(30),
+        low_hysteresis: 3,
+        min_time_spent: 8,
+    };
+    let mut sink = RecordingSink::new();
+    sink.le_set_path_loss_reporting_parameters(&expected).await;
+    match decode(&sink) {
+        Ok(Command::LeSetPathLossReportingParameters(params)) => assert_eq!(params, expected),
+        other => panic!("Did not get LeSetPathLossReportingParameters: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_set_path_loss_reporting_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_path_loss_reporting_enable(hci::ConnectionHandle(0x0201), true)
+        .await;
+    match decode(&sink) {
+        Ok(Command::LeSetPathLossReportingEnable {
+            conn_handle,
+            enable,
+        }) => {
+            assert_eq!(conn_handle, hci::ConnectionHandle(0x0201));
+            assert!(enable);
+        }
+        other => panic!("Did not get LeSetPathLossReportingEnable: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_set_transmit_power_reporting_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_transmit_power_reporting_enable(hci::ConnectionHandle(0x0201), true, false)
+        .await;
+    match decode(&sink) {
+        Ok(Command::LeSetTransmitPowerReportingEnable {
+            conn_handle,
+            local_enable,
+            remote_enable,
+        }) => {
+            assert_eq!(conn_handle, hci::ConnectionHandle(0x0201));
+            assert!(local_enable);
+            assert!(!remote_enable);
+        }
+        other => panic!("Did not get LeSetTransmitPowerReportingEnable: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_set_default_subrate() {
+    let params = SubrateParametersBuilder::new()
+        .with_subrate_range(2, 4)
+        .with_max_latency(10)
+        .with_continuation_number(1)
+        .with_supervision_timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+    let mut sink = RecordingSink::new();
+    sink.le_set_default_subrate(&params).await;
+    match decode(&sink) {
+        Ok(Command::LeSetDefaultSubrate(decoded)) => assert_eq!(decoded, params),
+        other => panic!("Did not get LeSetDefaultSubrate: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_subrate_request() {
+    let params = SubrateParametersBuilder::new()
+        .with_subrate_range(2, 4)
+        .with_max_latency(10)
+        .with_continuation_number(1)
+        .with_supervision_timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+    let mut sink = RecordingSink::new();
+    sink.le_subrate_request(hci::ConnectionHandle(0x0201), &params)
+        .await;
+    match decode(&sink) {
+        Ok(Command::LeSubrateRequest {
+            conn_handle,
+            params: decoded,
+        }) => {
+            assert_eq!(conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(decoded, params);
+        }
+        other => panic!("Did not get LeSubrateRequest: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_set_connectionless_cte_transmit_parameters() {
+    let expected = ConnectionlessCteTransmitParameters {
+        adv_handle: hci::AdvertisingHandle(0x01),
+        cte_length: Duration::from_micros(160),
+        cte_type: hci::event::CteType::AoD1us,
+        cte_count: 2,
+        antenna_ids: &[0x00, 0x01, 0x02],
+    };
+    let mut sink = RecordingSink::new();
+    sink.le_set_connectionless_cte_transmit_parameters(&expected)
+        .await
+        .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetConnectionlessCteTransmitParameters(params)) => {
+            assert_eq!(params, expected)
+        }
+        other => panic!(
+            "Did not get LeSetConnectionlessCteTransmitParameters: {:?}",
+            other
+        ),
+    }
+}
+
+#[tokio::test]
+async fn le_set_connectionless_iq_sampling_enable() {
+    let expected = ConnectionlessIqSamplingParameters {
+        sync_handle: hci::SyncHandle(0x0201),
+        sampling_enable: true,
+        slot_duration: hci::event::SlotDuration::Slot2us,
+        max_sampled_ctes: 0,
+        antenna_ids: &[0x00, 0x01],
+    };
+    let mut sink = RecordingSink::new();
+    sink.le_set_connectionless_iq_sampling_enable(&expected)
+        .await
+        .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetConnectionlessIqSamplingEnable(params)) => assert_eq!(params, expected),
+        other => panic!(
+            "Did not get LeSetConnectionlessIqSamplingEnable: {:?}",
+            other
+        ),
+    }
+}
+
+#[tokio::test]
+async fn le_set_connection_cte_transmit_parameters() {
+    let expected = ConnectionCteTransmitParameters {
+        conn_handle: hci::ConnectionHandle(0x0201),
+        cte_types: CteTypes::AOA | CteTypes::AOD_2US,
+        antenna_ids: &[0x00, 0x01],
+    };
+    let mut sink = RecordingSink::new();
+    sink.le_set_connection_cte_transmit_parameters(&expected)
+        .await
+        .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeSetConnectionCteTransmitParameters(params)) => assert_eq!(params, expected),
+        other => panic!(
+            "Did not get LeSetConnectionCteTransmitParameters: {:?}",
+            other
+        ),
+    }
+}
+
+#[tokio::test]
+async fn le_connection_cte_request_enable() {
+    let expected = ConnectionCteRequestParameters {
+        conn_handle: hci::ConnectionHandle(0x0201),
+        enable: true,
+        cte_request_interval: 0,
+        requested_cte_length: Duration::from_micros(80),
+        requested_cte_type: hci::event::CteType::AoD2us,
+    };
+    let mut sink = RecordingSink::new();
+    sink.le_connection_cte_request_enable(&expected)
+        .await
+        .unwrap();
+    match decode(&sink) {
+        Ok(Command::LeConnectionCteRequestEnable(params)) => assert_eq!(params, expected),
+        other => panic!("Did not get LeConnectionCteRequestEnable: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn le_read_antenna_information() {
+    let mut sink = RecordingSink::new();
+    sink.le_read_antenna_information().await;
+    match decode(&sink) {
+        Ok(Command::LeReadAntennaInformation) => (),
+        other => panic!("Did not get LeReadAntennaInformation: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn hal_set_tx_power_level() {
+    let mut sink = RecordingSink::new();
+    sink.set_tx_power_level(PowerLevel::Minus14_1dBm).await;
+    match decode(&sink) {
+        Ok(Command::Vendor(VendorCommand::HalSetTxPowerLevel(level))) => {
+            assert_eq!(level, PowerLevel::Minus14_1dBm)
+        }
+        other => panic!("Did not get HalSetTxPowerLevel: {:?}", other),
+    }
+}
+
+#[test]
+fn power_level_to_dbm() {
+    assert_eq!(hci::Dbm::from(PowerLevel::Minus40dBm), hci::Dbm(-40));
+    assert_eq!(hci::Dbm::from(PowerLevel::Minus16_5dBm), hci::Dbm(-17));
+    assert_eq!(hci::Dbm::from(PowerLevel::Minus14_1dBm), hci::Dbm(-14));
+    assert_eq!(hci::Dbm::from(PowerLevel::Minus0_15dBm), hci::Dbm(0));
+    assert_eq!(hci::Dbm::from(PowerLevel::Plus6dBm), hci::Dbm(6));
+}
+
+#[tokio::test]
+async fn gap_init() {
+    let mut sink = RecordingSink::new();
+    GapCommands::init(&mut sink, Role::PERIPHERAL | Role::CENTRAL, true, 7).await;
+    match decode(&sink) {
+        Ok(Command::Vendor(VendorCommand::GapInit {
+            role,
+            privacy_enabled,
+            dev_name_characteristic_len,
+        })) => {
+            assert_eq!(role.bits(), (Role::PERIPHERAL | Role::CENTRAL).bits());
+            assert!(privacy_enabled);
+            assert_eq!(dev_name_characteristic_len, 7);
+        }
+        other => panic!("Did not get GapInit: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn gatt_add_service() {
+    let mut sink = RecordingSink::new();
+    sink.add_service(&AddServiceParameters {
+        uuid: Uuid::Uuid16(0x0201),
+        service_type: ServiceType::Primary,
+        max_attribute_records: 3,
+    })
+    .await;
+    match decode(&sink) {
+        Ok(Command::Vendor(VendorCommand::GattAddService(params))) => {
+            assert_eq!(params.uuid, Uuid::Uuid16(0x0201));
+            assert_eq!(params.service_type, ServiceType::Primary);
+            assert_eq!(params.max_attribute_records, 3);
+        }
+        other => panic!("Did not get GattAddService: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn gatt_read_multiple_characteristic_values() {
+    let mut sink = RecordingSink::new();
+    sink.read_multiple_characteristic_values(&MultipleCharacteristicReadParameters {
+        conn_handle: hci::ConnectionHandle(0x0201),
+        handles: &[AttributeHandle(0x0403), AttributeHandle(0x0605)],
+    })
+    .await
+    .unwrap();
+    match decode(&sink) {
+        Ok(Command::Vendor(VendorCommand::GattReadMultipleCharacteristicValues {
+            conn_handle,
+            handles,
+        })) => {
+            assert_eq!(conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(
+                handles.iter().collect::<Vec<_>>(),
+                [AttributeHandle(0x0403), AttributeHandle(0x0605)]
+            );
+        }
+        other => panic!(
+            "Did not get GattReadMultipleCharacteristicValues: {:?}",
+            other
+        ),
+    }
+}
+
+#[tokio::test]
+async fn gatt_cancel_write_request() {
+    let mut sink = RecordingSink::new();
+    sink.cancel_write_request(hci::ConnectionHandle(0x0201))
+        .await;
+    match decode(&sink) {
+        Ok(Command::Vendor(VendorCommand::GattCancelWriteRequest(conn_handle))) => {
+            assert_eq!(conn_handle, hci::ConnectionHandle(0x0201))
+        }
+        other => panic!("Did not get GattCancelWriteRequest: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn l2cap_coc_disconnect() {
+    let mut sink = RecordingSink::new();
+    sink.coc_disconnect(3).await;
+    match decode(&sink) {
+        Ok(Command::Vendor(VendorCommand::L2CapCocDisconnect(channel_index))) => {
+            assert_eq!(channel_index, 3)
+        }
+        other => panic!("Did not get L2CapCocDisconnect: {:?}", other),
+    }
+}
+
+#[test]
+fn empty_packet() {
+    let err = Command::new(Packet(&[])).err().unwrap();
+    assert_eq!(err, Error::BadLength(0, 3));
+}
+
+#[test]
+fn truncated_packet() {
+    let err = Command::new(Packet(&[0x06, 0x04, 3, 0x01, 0x02]))
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadLength(5, 6));
+}
+
+#[test]
+fn bad_param_length() {
+    let err = Command::new(Packet(&[0x03, 0x0C, 1, 0x00])).err().unwrap();
+    assert_eq!(err, Error::BadLength(1, 0));
+}
+
+#[test]
+fn unknown_opcode() {
+    let err = Command::new(Packet(&[0xFF, 0x03, 0])).err().unwrap();
+    assert_eq!(err, Error::UnknownOpcode(hci::Opcode(0x03FF)));
+}
+
+#[test]
+fn unknown_vendor_opcode() {
+    let err = Command::new(Packet(&[0xFF, 0xFF, 0])).err().unwrap();
+    assert_eq!(err, Error::UnknownOpcode(hci::Opcode(0xFFFF)));
+}
+
+#[test]
+fn bad_disconnection_status() {
+    let err = Command::new(Packet(&[0x06, 0x04, 3, 0x01, 0x02, 0x45]))
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadStatus(0x45));
+}
+
+#[test]
+fn bad_vendor_power_level() {
+    let err = Command::new(Packet(&[0x0F, 0xFC, 2, 0x00, 0xFF]))
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::Vendor(VendorCommandError::BadPowerLevel(0xFF)));
+}
+
+#[test]
+fn bad_phy_options() {
+    let err = Command::new(Packet(&[
+        0x32, 0x20, 7, 0x01, 0x02, 0x00, 0x01, 0x01, 0x03, 0x00,
+    ]))
+    .err()
+    .unwrap();
+    assert_eq!(err, Error::BadPhyOptions(0x0003));
+}
+
+#[test]
+fn bad_privacy_mode() {
+    let err = Command::new(Packet(&[0x4E, 0x20, 8, 0x00, 1, 2, 3, 4, 5, 6, 0x02]))
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadPrivacyMode(0x02));
+}
+
+#[test]
+fn bad_private_key_type() {
+    let mut buffer = [0; 68];
+    buffer[..3].copy_from_slice(&[0x5E, 0x20, 65]);
+    buffer[67] = 0x02;
+    let err = Command::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadPrivateKeyType(0x02));
+}
+
+#[test]
+fn bad_advertising_phy() {
+    let err = Command::new(Packet(&[
+        0x36, 0x20, 25, 0x01, 0x01, 0x00, 0x20, 0x00, 0x00, 0x20, 0x00, 0x00, 0x07, 0x00, 0x00, 1,
+        2, 3, 4, 5, 6, 0x00, 0x7F, 0x04, 0x00, 0x01, 0x00, 0x00,
+    ]))
+    .err()
+    .unwrap();
+    assert_eq!(err, Error::BadAdvertisingPhy(0x04));
+}
+
+#[test]
+fn bad_filter_duplicates() {
+    let err = Command::new(Packet(&[0x42, 0x20, 6, 1, 3, 0x00, 0x00, 0x00, 0x00]))
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadFilterDuplicates(3));
+}
+
+#[test]
+fn extended_scan_parameters_on_le_2m_phy() {
+    let err = Command::new(Packet(&[
+        0x41, 0x20, 8, 0x00, 0x00, 0x02, 0x00, 0x10, 0x00, 0x10, 0x00,
+    ]))
+    .err()
+    .unwrap();
+    assert_eq!(err, Error::BadPhyMask(0x02));
+}
+
+#[test]
+fn bad_periodic_advertising_properties() {
+    let err = Command::new(Packet(&[
+        0x3E, 0x20, 7, 0x01, 0x50, 0x00, 0xA0, 0x00, 0x41, 0x00,
+    ]))
+    .err()
+    .unwrap();
+    assert_eq!(err, Error::BadPeriodicAdvertisingProperties(0x0041));
+}
+
+#[test]
+fn bad_sync_options() {
+    let err = Command::new(Packet(&[
+        0x44, 0x20, 14, 0x08, 0x03, 0x01, 1, 2, 3, 4, 5, 6, 0x05, 0x00, 0x64, 0x00, 0x00,
+    ]))
+    .err()
+    .unwrap();
+    assert_eq!(err, Error::BadSyncOptions(0x08));
+}
+
+#[test]
+fn bad_sync_cte_type() {
+    let err = Command::new(Packet(&[
+        0x44, 0x20, 14, 0x00, 0x03, 0x01, 1, 2, 3, 4, 5, 6, 0x05, 0x00, 0x64, 0x00, 0x20,
+    ]))
+    .err()
+    .unwrap();
+    assert_eq!(err, Error::BadSyncCteType(0x20));
+}
+
+#[test]
+fn bad_sync_transfer_mode() {
+    let err = Command::new(Packet(&[0x5D, 0x20, 6, 0x04, 0x05, 0x00, 0x64, 0x00, 0x10]))
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadSyncTransferMode(0x04));
+}
+
+#[test]
+fn bad_cis_phy() {
+    let err = Command::new(Packet(&[
+        0x62, 0x20, 24, 0x01, 0x10, 0x27, 0x00, 0x10, 0x27, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00,
+        0x14, 0x00, 1, 0x00, 0x28, 0x00, 0x28, 0x00, 0x02, 0x08, 0x00, 0x00,
+    ]))
+    .err()
+    .unwrap();
+    assert_eq!(err, Error::BadPhyMask(0x08));
+}
+
+#[test]
+fn bad_packing() {
+    let err = Command::new(Packet(&[
+        0x62, 0x20, 15, 0x01, 0x10, 0x27, 0x00, 0x10, 0x27, 0x00, 0x00, 0x02, 0x00, 0x14, 0x00,
+        0x14, 0x00, 0,
+    ]))
+    .err()
+    .unwrap();
+    assert_eq!(err, Error::BadPacking(0x02));
+}
+
+#[test]
+fn bad_framing() {
+    let err = Command::new(Packet(&[
+        0x62, 0x20, 15, 0x01, 0x10, 0x27, 0x00, 0x10, 0x27, 0x00, 0x00, 0x00, 0x02, 0x14, 0x00,
+        0x14, 0x00, 0,
+    ]))
+    .err()
+    .unwrap();
+    assert_eq!(err, Error::BadFraming(0x02));
+}
+
+#[test]
+fn bad_sleep_clock_accuracy() {
+    let err = Command::new(Packet(&[
+        0x62, 0x20, 15, 0x01, 0x10, 0x27, 0x00, 0x10, 0x27, 0x00, 0x08, 0x00, 0x00, 0x14, 0x00,
+        0x14, 0x00, 0,
+    ]))
+    .err()
+    .unwrap();
+    assert_eq!(err, Error::BadSleepClockAccuracy(0x08));
+}
+
+#[test]
+fn bad_data_path_direction() {
+    let err = Command::new(Packet(&[
+        0x6E, 0x20, 13, 0x01, 0x02, 0x02, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0,
+    ]))
+    .err()
+    .unwrap();
+    assert_eq!(err, Error::BadDataPathDirection(0x02));
+}
+
+#[test]
+fn bad_big_phy() {
+    let err = Command::new(Packet(&[
+        0x68, 0x20, 31, 0x01, 0x02, 2, 0x10, 0x27, 0x00, 0x28, 0x00, 0x14, 0x00, 2, 0x08, 0x00,
+        0x00, 0x00, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ]))
+    .err()
+    .unwrap();
+    assert_eq!(err, Error::BadPhyMask(0x08));
+}
+
+#[test]
+fn bad_power_control_phy() {
+    let err = Command::new(Packet(&[0x76, 0x20, 3, 0x01, 0x02, 0x05]))
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadPowerControlPhy(0x05));
+}
+
+#[test]
+fn bad_subrate_parameters() {
+    let err = Command::new(Packet(&[
+        0x7D, 0x20, 10, 0x04, 0x00, 0x02, 0x00, 0x0A, 0x00, 0x01, 0x00, 0xF4, 0x01,
+    ]))
+    .err()
+    .unwrap();
+    assert_eq!(
+        err,
+        Error::BadSubrateParameters(hci::types::SubrateParametersError::SubrateInverted(4, 2))
+    );
+}
+
+#[test]
+fn bad_cte_type() {
+    let err = Command::new(Packet(&[
+        0x56, 0x20, 7, 0x01, 0x02, 0x01, 0x00, 0x00, 0x05, 0x03,
+    ]))
+    .err()
+    .unwrap();
+    assert_eq!(err, Error::BadCteType(0x03));
+}
+
+#[test]
+fn bad_cte_types() {
+    let err = Command::new(Packet(&[0x55, 0x20, 6, 0x01, 0x02, 0x08, 2, 0x00, 0x01]))
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadCteTypes(0x08));
+}
+
+#[test]
+fn bad_slot_duration() {
+    let err = Command::new(Packet(&[
+        0x54, 0x20, 7, 0x01, 0x02, 0x01, 0x03, 2, 0x00, 0x01,
+    ]))
+    .err()
+    .unwrap();
+    assert_eq!(err, Error::BadSlotDuration(0x03));
+}
@@ -116,6 +116,7 @@ fn read_tx_power_level() {
                     assert_eq!(params.status, hci::Status::Success);
                     assert_eq!(params.conn_handle, hci::ConnectionHandle(0x0201));
                     assert_eq!(params.tx_power_level_dbm, 0x03);
+                    assert_eq!(params.tx_power_level(), hci::Dbm(3));
                 }
                 other => panic!("Got return parameters: {:?}", other),
             }
@@ -355,6 +356,20 @@ fn le_read_local_supported_features() {
     }
 }
 
+#[test]
+fn read_tx_power_level_negative() {
+    let buffer = [0x0E, 7, 6, 0x2D, 0x0C, 0x00, 0x01, 0x02, 0xEC];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => match event.return_params {
+            ReturnParameters::ReadTxPowerLevel(params) => {
+                assert_eq!(params.tx_power_level(), hci::Dbm(-20));
+            }
+            other => panic!("Got return parameters: {:?}", other),
+        },
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+}
+
 #[test]
 fn le_read_advertising_channel_tx_power() {
     let buffer = [0x0E, 5, 1, 0x07, 0x20, 0x00, 0x01];
@@ -365,6 +380,7 @@ fn le_read_advertising_channel_tx_power() {
                 ReturnParameters::LeReadAdvertisingChannelTxPower(params) => {
                     assert_eq!(params.status, hci::Status::Success);
                     assert_eq!(params.power, 0x01);
+                    assert_eq!(params.power(), hci::Dbm(1));
                 }
                 other => panic!(
                     "Did not get LE Read Advertising Channel TX Power return params: {:?}",
@@ -593,6 +609,241 @@ fn le_test_end() {
     }
 }
 
+#[test]
+fn le_set_data_length() {
+    let buffer = [0x0E, 6, 1, 0x22, 0x20, 0x00, 0x01, 0x02];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 1);
+            match event.return_params {
+                ReturnParameters::LeSetDataLength(params) => {
+                    assert_eq!(params.status, hci::Status::Success);
+                    assert_eq!(params.conn_handle, hci::ConnectionHandle(0x0201));
+                }
+                other => panic!("Did not get LE Set Data Length return params: {:?}", other),
+            }
+        }
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+}
+
+#[test]
+fn le_read_suggested_default_data_length() {
+    let buffer = [0x0E, 8, 1, 0x23, 0x20, 0x00, 0x1B, 0x00, 0x48, 0x01];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 1);
+            match event.return_params {
+                ReturnParameters::LeReadSuggestedDefaultDataLength(params) => {
+                    assert_eq!(params.status, hci::Status::Success);
+                    assert_eq!(params.suggested_max_tx_octets, 0x001B);
+                    assert_eq!(params.suggested_max_tx_time, 0x0148);
+                }
+                other => panic!(
+                    "Did not get LE Read Suggested Default Data Length return params: {:?}",
+                    other
+                ),
+            }
+        }
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+}
+
+#[test]
+fn le_write_suggested_default_data_length() {
+    let buffer = [0x0E, 4, 1, 0x24, 0x20, 0x00];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 1);
+            match event.return_params {
+                ReturnParameters::LeWriteSuggestedDefaultDataLength(status) => {
+                    assert_eq!(status, hci::Status::Success);
+                }
+                other => panic!(
+                    "Did not get LE Write Suggested Default Data Length return params: {:?}",
+                    other
+                ),
+            }
+        }
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+}
+
+#[test]
+fn le_read_maximum_data_length() {
+    let buffer = [
+        0x0E, 12, 1, 0x2F, 0x20, 0x00, 0xFB, 0x00, 0x90, 0x42, 0xFA, 0x00, 0x48, 0x08,
+    ];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 1);
+            match event.return_params {
+                ReturnParameters::LeReadMaximumDataLength(params) => {
+                    assert_eq!(params.status, hci::Status::Success);
+                    assert_eq!(params.supported_max_tx_octets, 0x00FB);
+                    assert_eq!(params.supported_max_tx_time, 0x4290);
+                    assert_eq!(params.supported_max_rx_octets, 0x00FA);
+                    assert_eq!(params.supported_max_rx_time, 0x0848);
+                }
+                other => panic!(
+                    "Did not get LE Read Maximum Data Length return params: {:?}",
+                    other
+                ),
+            }
+        }
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+}
+
+#[test]
+fn le_read_resolving_list_size() {
+    let buffer = [0x0E, 5, 1, 0x2A, 0x20, 0x00, 0x08];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 1);
+            match event.return_params {
+                ReturnParameters::LeReadResolvingListSize(status, size) => {
+                    assert_eq!(status, hci::Status::Success);
+                    assert_eq!(size, 8);
+                }
+                other => panic!(
+                    "Did not get LE Read Resolving List Size return params: {:?}",
+                    other
+                ),
+            }
+        }
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+}
+
+#[test]
+fn le_read_peer_resolvable_address() {
+    let buffer = [0x0E, 10, 1, 0x2B, 0x20, 0x00, 1, 2, 3, 4, 5, 0x46];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 1);
+            match event.return_params {
+                ReturnParameters::LeReadPeerResolvableAddress(params) => {
+                    assert_eq!(params.status, hci::Status::Success);
+                    assert_eq!(params.address, hci::BdAddr([1, 2, 3, 4, 5, 0x46]));
+                }
+                other => panic!(
+                    "Did not get LE Read Peer Resolvable Address return params: {:?}",
+                    other
+                ),
+            }
+        }
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+}
+
+#[test]
+fn le_read_phy() {
+    let buffer = [0x0E, 8, 1, 0x30, 0x20, 0x00, 0x01, 0x02, 0x01, 0x02];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 1);
+            match event.return_params {
+                ReturnParameters::LeReadPhy(params) => {
+                    assert_eq!(params.status, hci::Status::Success);
+                    assert_eq!(params.conn_handle, hci::ConnectionHandle(0x0201));
+                    assert_eq!(params.tx_phy, Phy::Le1M);
+                    assert_eq!(params.rx_phy, Phy::Le2M);
+                }
+                other => panic!("Did not get LE Read PHY return params: {:?}", other),
+            }
+        }
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+}
+
+#[test]
+fn le_read_phy_failed_bad_phy() {
+    let buffer = [0x0E, 8, 1, 0x30, 0x20, 0x00, 0x01, 0x02, 0x04, 0x02];
+    let err = Event::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadPhy(0x04));
+}
+
+#[test]
+fn le_set_default_phy() {
+    let buffer = [0x0E, 4, 1, 0x31, 0x20, 0x00];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 1);
+            match event.return_params {
+                ReturnParameters::LeSetDefaultPhy(status) => {
+                    assert_eq!(status, hci::Status::Success);
+                }
+                other => panic!("Did not get LE Set Default PHY return params: {:?}", other),
+            }
+        }
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+}
+
+#[test]
+fn le_set_extended_advertising_parameters() {
+    let buffer = [0x0E, 5, 1, 0x36, 0x20, 0x00, 0xFB];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 1);
+            match event.return_params {
+                ReturnParameters::LeSetExtendedAdvertisingParameters(params) => {
+                    assert_eq!(params.status, hci::Status::Success);
+                    assert_eq!(params.selected_tx_power, hci::Dbm(-5));
+                }
+                other => panic!(
+                    "Did not get LE Set Extended Advertising Parameters return params: {:?}",
+                    other
+                ),
+            }
+        }
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+}
+
+#[test]
+fn le_read_maximum_advertising_data_length() {
+    let buffer = [0x0E, 6, 1, 0x3A, 0x20, 0x00, 0x72, 0x06];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 1);
+            match event.return_params {
+                ReturnParameters::LeReadMaximumAdvertisingDataLength(status, length) => {
+                    assert_eq!(status, hci::Status::Success);
+                    assert_eq!(length, 1650);
+                }
+                other => panic!(
+                    "Did not get LE Read Maximum Advertising Data Length return params: {:?}",
+                    other
+                ),
+            }
+        }
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+}
+
+#[test]
+fn le_read_number_of_supported_advertising_sets() {
+    let buffer = [0x0E, 5, 1, 0x3B, 0x20, 0x00, 0x06];
+    match Event::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => {
+            assert_eq!(event.num_hci_command_packets, 1);
+            match event.return_params {
+                ReturnParameters::LeReadNumberOfSupportedAdvertisingSets(status, count) => {
+                    assert_eq!(status, hci::Status::Success);
+                    assert_eq!(count, 6);
+                }
+                other => panic!(
+                    "Did not get LE Read Number of Supported Advertising Sets return params: {:?}",
+                    other
+                ),
+            }
+        }
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+}
+
 #[test]
 fn vendor_command() {
     let buffer = [0x0E, 6, 1, 0x00, 0xFC, 0x00, 0x00, 0x00];
@@ -0,0 +1,176 @@
+extern crate stm32wb_hci as hci;
+
+mod vendor;
+
+use hci::host::HostHci;
+use hci::host::command_credits::{CommandCredits, NoCommandCredits};
+use hci::host::correlator::{CommandResponse, Correlator};
+use hci::host::uart::{Error, Packet, UartHci};
+use hci::{Event, Opcode};
+use vendor::PacketSink;
+
+fn ignore(_: Result<Packet, Error>) {}
+
+#[tokio::test]
+async fn starts_with_one_credit() {
+    let hci = CommandCredits::new(PacketSink::new(&[]), ignore);
+    assert_eq!(hci.credits(), 1);
+    assert_eq!(hci.check_credit(), Ok(()));
+}
+
+#[tokio::test]
+async fn command_consumes_credit() {
+    let mut hci = CommandCredits::new(PacketSink::new(&[]), ignore);
+    hci.reset().await;
+    assert_eq!(hci.credits(), 0);
+    assert_eq!(hci.check_credit(), Err(NoCommandCredits));
+
+    assert_eq!(hci.into_inner().written_opcodes, [Opcode(0x0C03)]);
+}
+
+#[tokio::test]
+async fn command_without_credit_waits_for_credit() {
+    let mut packets = Vec::new();
+    let mut hci = CommandCredits::with_credits(
+        PacketSink::new(&[
+            &[0x04, 0x05, 4, 0x00, 0x01, 0x02, 0x13],
+            &[0x04, 0x0E, 3, 1, 0x00, 0x00],
+        ]),
+        0,
+        |packet: Result<Packet, Error>| packets.push(packet),
+    );
+    hci.read_rssi(hci::ConnectionHandle(0x0201)).await;
+    assert_eq!(hci.credits(), 0);
+    let sink = hci.into_inner();
+    assert_eq!(sink.written_opcodes, [Opcode(0x1405)]);
+    assert!(sink.packets.is_empty());
+
+    // The packets read while waiting are passed on, including the one that returned the credit.
+    assert_eq!(packets.len(), 2);
+    assert!(matches!(
+        packets[0],
+        Ok(Packet::Event(Event::DisconnectionComplete(_)))
+    ));
+}
+
+#[tokio::test]
+async fn send_with_credit() {
+    let mut hci = CommandCredits::new(PacketSink::new(&[]), ignore);
+    assert_eq!(hci.send(async |hci| hci.reset().await).await, Ok(()));
+    assert_eq!(hci.credits(), 0);
+    assert_eq!(hci.into_inner().written_opcodes, [Opcode(0x0C03)]);
+}
+
+#[tokio::test]
+async fn send_without_credit() {
+    let mut hci = CommandCredits::with_credits(PacketSink::new(&[]), 0, ignore);
+    assert_eq!(
+        hci.send(async |hci| hci.reset().await).await,
+        Err(NoCommandCredits)
+    );
+    assert_eq!(hci.into_inner().written_opcodes, []);
+}
+
+#[tokio::test]
+async fn command_complete_replenishes_credits() {
+    let mut hci = CommandCredits::new(
+        PacketSink::new(&[&[0x04, 0x0E, 4, 5, 0x03, 0x0C, 0x00]]),
+        ignore,
+    );
+    hci.reset().await;
+    hci.read().await.unwrap();
+    assert_eq!(hci.credits(), 5);
+}
+
+#[tokio::test]
+async fn command_status_replenishes_credits() {
+    let mut hci = CommandCredits::new(
+        PacketSink::new(&[&[0x04, 0x0F, 4, 0x00, 3, 0x06, 0x04]]),
+        ignore,
+    );
+    hci.disconnect(
+        hci::ConnectionHandle(0x0201),
+        hci::Status::RemoteTerminationByUser,
+    )
+    .await
+    .unwrap();
+    hci.read().await.unwrap();
+    assert_eq!(hci.credits(), 3);
+}
+
+#[tokio::test]
+async fn other_packets_leave_credits() {
+    let mut hci = CommandCredits::with_credits(
+        PacketSink::new(&[
+            &[0x04, 0x05, 4, 0x00, 0x01, 0x02, 0x13],
+            &[0x02, 0x01, 0x22, 1, 0, 0x0A],
+        ]),
+        2,
+        ignore,
+    );
+    hci.read().await.unwrap();
+    hci.read().await.unwrap();
+    assert_eq!(hci.credits(), 2);
+}
+
+#[tokio::test]
+async fn credits_counted_from_bad_event() {
+    // Command Complete for an unknown opcode cannot be deserialized, but still returns credits.
+    let mut hci = CommandCredits::with_credits(
+        PacketSink::new(&[&[0x04, 0x0E, 4, 2, 0xFF, 0x03, 0x00]]),
+        0,
+        ignore,
+    );
+    assert!(hci.read().await.is_err());
+    assert_eq!(hci.credits(), 2);
+}
+
+#[tokio::test]
+async fn wait_for_credit_at_startup() {
+    let mut hci = CommandCredits::with_credits(
+        PacketSink::new(&[
+            // Controller is not ready yet.
+            &[0x04, 0x0E, 3, 0, 0x00, 0x00],
+            &[0x04, 0x05, 4, 0x00, 0x01, 0x02, 0x13],
+            // Controller is ready.
+            &[0x04, 0x0E, 3, 1, 0x00, 0x00],
+            &[0x04, 0x05, 4, 0x00, 0x01, 0x02, 0x13],
+        ]),
+        0,
+        ignore,
+    );
+
+    let mut packets = Vec::new();
+    hci.wait_for_credit(|packet| packets.push(packet))
+        .await
+        .unwrap();
+    assert_eq!(hci.credits(), 1);
+    assert_eq!(packets.len(), 3);
+    assert!(matches!(
+        packets[1],
+        Packet::Event(Event::DisconnectionComplete(_))
+    ));
+
+    // Already has a credit, so nothing is read.
+    hci.wait_for_credit(|_| panic!("No packets should be read"))
+        .await
+        .unwrap();
+    assert_eq!(hci.into_inner().packets.len(), 1);
+}
+
+#[tokio::test]
+async fn with_correlator() {
+    let mut hci = Correlator::new(CommandCredits::new(
+        PacketSink::new(&[&[0x04, 0x0E, 4, 1, 0x03, 0x0C, 0x00]]),
+        ignore,
+    ));
+    hci.controller().check_credit().unwrap();
+    hci.reset().await;
+    assert_eq!(hci.controller().credits(), 0);
+
+    assert!(matches!(
+        hci.response(|_| {}).await,
+        Ok(CommandResponse::Complete(_))
+    ));
+    assert_eq!(hci.controller().credits(), 1);
+}
@@ -0,0 +1,139 @@
+extern crate stm32wb_hci as hci;
+
+mod vendor;
+
+use hci::event::command::ReturnParameters;
+use hci::host::HostHci;
+use hci::host::correlator::{CommandResponse, Correlator, Error};
+use hci::host::uart::Packet;
+use hci::{Event, Opcode, Status};
+use vendor::PacketSink;
+
+#[tokio::test]
+async fn command_complete() {
+    let mut hci = Correlator::new(PacketSink::new(&[&[0x04, 0x0E, 4, 1, 0x03, 0x0C, 0x00]]));
+    hci.reset().await;
+    assert_eq!(hci.pending_opcode(), Some(Opcode(0x0C03)));
+
+    match hci.response(|_| panic!("No unrelated packets")).await {
+        Ok(CommandResponse::Complete(ReturnParameters::Reset(status))) => {
+            assert_eq!(status, Status::Success)
+        }
+        other => panic!("Did not get Reset return parameters: {:?}", other),
+    }
+    assert_eq!(hci.pending_opcode(), None);
+    assert_eq!(hci.into_inner().written_opcodes, [Opcode(0x0C03)]);
+}
+
+#[tokio::test]
+async fn command_complete_failed() {
+    let mut hci = Correlator::new(PacketSink::new(&[&[0x04, 0x0E, 4, 1, 0x03, 0x0C, 0x03]]));
+    hci.reset().await;
+
+    let err = hci.response(|_| {}).await.err().unwrap();
+    assert_eq!(err, Error::CommandFailed(Status::HardwareFailure));
+    assert_eq!(hci.pending_opcode(), None);
+}
+
+#[tokio::test]
+async fn command_status() {
+    let mut hci = Correlator::new(PacketSink::new(&[&[0x04, 0x0F, 4, 0x00, 1, 0x06, 0x04]]));
+    hci.disconnect(
+        hci::ConnectionHandle(0x0201),
+        Status::RemoteTerminationByUser,
+    )
+    .await
+    .unwrap();
+
+    match hci.response(|_| panic!("No unrelated packets")).await {
+        Ok(CommandResponse::Started) => (),
+        other => panic!("Did not get Started: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn command_status_failed() {
+    let mut hci = Correlator::new(PacketSink::new(&[&[0x04, 0x0F, 4, 0x02, 1, 0x06, 0x04]]));
+    hci.disconnect(
+        hci::ConnectionHandle(0x0201),
+        Status::RemoteTerminationByUser,
+    )
+    .await
+    .unwrap();
+
+    let err = hci.response(|_| {}).await.err().unwrap();
+    assert_eq!(err, Error::CommandFailed(Status::UnknownConnectionId));
+    assert_eq!(hci.pending_opcode(), None);
+}
+
+#[tokio::test]
+async fn unrelated_packets_are_dispatched() {
+    let mut hci = Correlator::new(PacketSink::new(&[
+        // Disconnection Complete
+        &[0x04, 0x05, 4, 0x00, 0x01, 0x02, 0x13],
+        // Spontaneous Command Complete
+        &[0x04, 0x0E, 3, 1, 0x00, 0x00],
+        // Command Status for another command
+        &[0x04, 0x0F, 4, 0x00, 1, 0x06, 0x04],
+        // ACL data
+        &[0x02, 0x01, 0x22, 1, 0, 0x0A],
+        // Read RSSI Command Complete
+        &[0x04, 0x0E, 7, 1, 0x05, 0x14, 0x00, 0x01, 0x02, 0xF0],
+    ]));
+    hci.read_rssi(hci::ConnectionHandle(0x0201)).await;
+
+    let mut unrelated = Vec::new();
+    let response = hci.response(|packet| unrelated.push(packet)).await;
+    match response {
+        Ok(CommandResponse::Complete(ReturnParameters::ReadRssi(params))) => {
+            assert_eq!(params.status, Status::Success);
+            assert_eq!(params.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(params.rssi, -16);
+        }
+        other => panic!("Did not get Read RSSI return parameters: {:?}", other),
+    }
+
+    assert_eq!(unrelated.len(), 4);
+    assert!(matches!(
+        unrelated[0],
+        Packet::Event(Event::DisconnectionComplete(_))
+    ));
+    assert!(matches!(
+        unrelated[1],
+        Packet::Event(Event::CommandComplete(ref event))
+            if matches!(event.return_params, ReturnParameters::Spontaneous)
+    ));
+    assert!(matches!(
+        unrelated[2],
+        Packet::Event(Event::CommandStatus(ref event)) if event.opcode == Opcode(0x0406)
+    ));
+    assert!(matches!(unrelated[3], Packet::AclData(_)));
+}
+
+#[tokio::test]
+async fn no_pending_command() {
+    let mut hci = Correlator::new(PacketSink::new(&[]));
+    let err = hci.response(|_| {}).await.err().unwrap();
+    assert_eq!(err, Error::NoPendingCommand);
+}
+
+#[tokio::test]
+async fn read_error_keeps_command_pending() {
+    let mut hci = Correlator::new(PacketSink::new(&[
+        &[0x09],
+        &[0x04, 0x0E, 4, 1, 0x03, 0x0C, 0x00],
+    ]));
+    hci.reset().await;
+
+    let err = hci.response(|_| {}).await.err().unwrap();
+    assert_eq!(
+        err,
+        Error::Read(hci::host::uart::Error::BadPacketType(0x09))
+    );
+    assert_eq!(hci.pending_opcode(), Some(Opcode(0x0C03)));
+
+    assert!(matches!(
+        hci.response(|_| {}).await,
+        Ok(CommandResponse::Complete(ReturnParameters::Reset(_)))
+    ));
+}
@@ -0,0 +1,432 @@
+extern crate stm32wb_hci as hci;
+
+use hci::event::*;
+
+fn assert_round_trip(buffer: &[u8]) {
+    let event = match Event::new(Packet(buffer)) {
+        Ok(event) => event,
+        Err(e) => panic!("Did not decode event: {:?}", e),
+    };
+
+    let mut encoded = [0; 257];
+    let len = event.write_into(&mut encoded);
+    assert_eq!(&encoded[..len], buffer);
+
+    let decoded = Event::new(Packet(&encoded[..len])).unwrap();
+    assert_eq!(format!("{:?}", decoded), format!("{:?}", event));
+}
+
+macro_rules! round_trip {
+    {
+        $($fn:ident($($byte:expr),* $(,)?);)*
+    } => {
+        $(
+            #[test]
+            fn $fn() {
+                assert_round_trip(&[$($byte),*]);
+            }
+        )*
+    }
+}
+
+round_trip! {
+    connection_complete(0x03, 11, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x00, 0x00);
+    disconnection_complete(0x05, 4, 0, 0x01, 0x02, 0);
+    encryption_change(0x08, 4, 0x00, 0x01, 0x02, 0x00);
+    read_remote_version_complete(0x0C, 8, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07);
+    command_status(0x0F, 4, 0, 8, 0x01, 0x02);
+    command_status_vendor_status(0x0F, 4, 0x48, 8, 0x01, 0x02);
+    hardware_error(0x10, 1, 0x01);
+    number_of_completed_packets(0x13, 9, 2, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08);
+    data_buffer_overflow(0x1A, 1, 0x00);
+    encryption_key_refresh_complete(0x30, 3, 0, 0x01, 0x02);
+    le_connection_complete(
+        0x3E, 19, 0x01, 0x00, 0x01, 0x02, 0x00, 0x00, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09,
+        0x00, 0x0B, 0x00, 0x0D, 0x0A, 0x00,
+    );
+    le_advertising_report(
+        0x3E, 27, 0x02, 2, 0, 0, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 2, 0x07, 0x08, 0x09, 1, 1,
+        0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 3, 0x10, 0x11, 0x12, 0x13,
+    );
+    le_connection_update_complete(
+        0x3E, 10, 0x03, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x00, 0x07, 0x08,
+    );
+    le_read_remote_used_features_complete(
+        0x3E, 12, 0x04, 0x00, 0x01, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    );
+    le_long_term_key_request(
+        0x3E, 13, 0x05, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C,
+    );
+    le_phy_update_complete(0x3E, 6, 0x0C, 0x00, 0x01, 0x02, 0x01, 0x03);
+    le_extended_advertising_report(
+        0x3E, 55, 0x0D, 2, 0x13, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x01, 0x00, 0xFF,
+        0x7F, 0xD8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 2, 0x02, 0x01, 0x24,
+        0x00, 0x03, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x03, 0x02, 0x05, 0xF6, 0x7F, 0x50, 0x00,
+        0xFE, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 3, 0x07, 0x08, 0x09,
+    );
+    le_directed_advertising_report(
+        0x3E, 34, 0x0B, 2, 0x01, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x01, 0x11, 0x12, 0x13,
+        0x14, 0x15, 0x56, 0xD8, 0x01, 0x03, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x01, 0x21, 0x22,
+        0x23, 0x24, 0x25, 0x66, 0x7F,
+    );
+    le_scan_timeout(0x3E, 1, 0x11);
+    le_advertising_set_terminated(0x3E, 6, 0x12, 0x00, 0x01, 0x01, 0x02, 0x05);
+    le_scan_request_received(0x3E, 9, 0x13, 0x01, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06);
+    le_channel_selection_algorithm(0x3E, 4, 0x14, 0x01, 0x02, 0x01);
+    le_periodic_advertising_sync_established(
+        0x3E, 16, 0x0E, 0x00, 0x01, 0x02, 0x03, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x02,
+        0x50, 0x00, 0x04,
+    );
+    le_periodic_advertising_report(
+        0x3E, 11, 0x0F, 0x01, 0x02, 0xF6, 0xD8, 0x01, 0x01, 3, 0x07, 0x08, 0x09,
+    );
+    le_periodic_advertising_sync_lost(0x3E, 3, 0x10, 0x01, 0x02);
+    le_periodic_advertising_sync_transfer_received(
+        0x3E, 20, 0x18, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x00, 0x01, 0x02, 0x03,
+        0x04, 0x05, 0x06, 0x03, 0x50, 0x00, 0x02,
+    );
+    le_cis_established(
+        0x3E, 29, 0x19, 0x00, 0x01, 0x02, 0x10, 0x27, 0x00, 0x88, 0x13, 0x00, 0x20, 0x4E, 0x00,
+        0x30, 0x75, 0x00, 0x02, 0x01, 0x04, 0x01, 0x02, 0x01, 0x02, 0x28, 0x00, 0x3C, 0x00, 0x08,
+        0x00,
+    );
+    le_cis_request(0x3E, 7, 0x1A, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06);
+    le_create_big_complete(
+        0x3E, 23, 0x1B, 0x00, 0x01, 0x10, 0x27, 0x00, 0x20, 0x4E, 0x00, 0x02, 0x02, 0x01, 0x00,
+        0x02, 0x28, 0x00, 0x08, 0x00, 2, 0x01, 0x02, 0x03, 0x04,
+    );
+    le_terminate_big_complete(0x3E, 3, 0x1C, 0x01, 0x16);
+    le_big_sync_established(
+        0x3E, 17, 0x1D, 0x00, 0x01, 0x20, 0x4E, 0x00, 0x02, 0x01, 0x00, 0x02, 0x28, 0x00, 0x08,
+        0x00, 1, 0x01, 0x02,
+    );
+    le_big_sync_lost(0x3E, 3, 0x1E, 0x01, 0x08);
+    le_path_loss_threshold(0x3E, 5, 0x20, 0x01, 0x02, 0x40, 0x02);
+    le_transmit_power_reporting(0x3E, 9, 0x21, 0x00, 0x01, 0x02, 0x01, 0x03, 0xF6, 0x01, 0xFD);
+    le_big_info_advertising_report(
+        0x3E, 20, 0x22, 0x01, 0x02, 2, 4, 0x08, 0x00, 1, 0, 2, 0x28, 0x00, 0x10, 0x27, 0x00, 0x28,
+        0x00, 0x02, 0x01, 0x01,
+    );
+    le_subrate_change(
+        0x3E, 12, 0x23, 0x00, 0x01, 0x02, 0x04, 0x00, 0x0A, 0x00, 0x01, 0x00, 0xF4, 0x01,
+    );
+    le_connectionless_iq_report(
+        0x3E, 31, 0x15, 0x01, 0x02, 0x05, 0xA8, 0xFD, 0x01, 0x00, 0x01, 0x00, 0x34, 0x12, 9, 0x10,
+        0xF0, 0x20, 0xE0, 0x7F, 0x80, 0x00, 0x00, 0x01, 0xFF, 0x02, 0xFE, 0x03, 0xFD, 0x04, 0xFC,
+        0x05, 0xFB,
+    );
+    le_connection_iq_report(
+        0x3E, 14, 0x16, 0x01, 0x02, 0x02, 0x00, 0xA8, 0xFD, 0x02, 0x00, 0x00, 0xFF, 0x34, 0x12, 0,
+    );
+    le_cte_request_failed(0x3E, 4, 0x17, 0x00, 0x01, 0x02);
+
+    command_complete_spontaneous(0x0E, 3, 1, 0x00, 0x00);
+    set_event_mask(0x0E, 4, 8, 0x01, 0x0C, 0);
+    reset(0x0E, 4, 8, 0x03, 0x0C, 0);
+    le_set_event_mask(0x0E, 4, 8, 0x01, 0x20, 0);
+    le_set_random_address(0x0E, 4, 8, 0x05, 0x20, 0);
+    le_set_advertising_parameters(0x0E, 4, 8, 0x06, 0x20, 0);
+    le_set_advertising_data(0x0E, 4, 8, 0x08, 0x20, 0);
+    le_set_scan_response_data(0x0E, 4, 8, 0x09, 0x20, 0);
+    le_set_advertising_enable(0x0E, 4, 8, 0x0A, 0x20, 0);
+    le_set_scan_parameters(0x0E, 4, 8, 0x0B, 0x20, 0);
+    le_set_scan_enable(0x0E, 4, 8, 0x0C, 0x20, 0);
+    le_create_connection_cancel(0x0E, 4, 8, 0x0E, 0x20, 0);
+    le_clear_white_list(0x0E, 4, 8, 0x10, 0x20, 0);
+    le_add_device_to_whitelist(0x0E, 4, 8, 0x11, 0x20, 0);
+    le_remove_device_from_whitelist(0x0E, 4, 8, 0x12, 0x20, 0);
+    le_set_host_channel_classification(0x0E, 4, 8, 0x14, 0x20, 0);
+    le_receiver_test(0x0E, 4, 8, 0x1D, 0x20, 0);
+    le_transmitter_test(0x0E, 4, 8, 0x1E, 0x20, 0);
+    read_tx_power_level(0x0E, 7, 6, 0x2D, 0x0C, 0x00, 0x01, 0x02, 0x03);
+    read_local_version_information(
+        0x0E, 12, 0x01, 0x01, 0x10, 0x00, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09,
+    );
+    read_local_supported_commands(
+        0x0E, 68, 1, 0x02, 0x10, 0x00, 0x01, 0x02, 0x04, 0x00, 0x10, 0x20, 0x40, 0x80, 0x01, 0x02,
+        0x04, 0x08, 0x10, 0x00, 0x40, 0x80, 0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x01,
+        0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    );
+    read_local_supported_features(
+        0x0E, 12, 1, 0x03, 0x10, 0x00, 0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80,
+    );
+    read_bd_addr(0x0E, 10, 1, 0x09, 0x10, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06);
+    read_rssi(0x0E, 7, 1, 0x05, 0x14, 0x00, 0x01, 0x02, 0x03);
+    le_read_buffer_size(0x0E, 7, 2, 0x02, 0x20, 0x00, 0x01, 0x02, 0x03);
+    le_read_local_supported_features(
+        0x0E, 12, 1, 0x03, 0x20, 0x00, 0x04, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+    );
+    le_read_advertising_channel_tx_power(0x0E, 5, 1, 0x07, 0x20, 0x00, 0x01);
+    le_read_white_list_size(0x0E, 5, 1, 0x0F, 0x20, 0x00, 0x16);
+    le_read_channel_map(
+        0x0E, 11, 1, 0x15, 0x20, 0x00, 0x01, 0x02, 0x11, 0x11, 0x11, 0x11, 0x11,
+    );
+    le_encrypt(
+        0x0E, 20, 1, 0x17, 0x20, 0x00, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+    );
+    le_rand(0x0E, 12, 1, 0x18, 0x20, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08);
+    le_long_term_key_request_reply(0x0E, 6, 1, 0x1A, 0x20, 0x00, 0x01, 0x02);
+    le_long_term_key_request_negative_reply(0x0E, 6, 1, 0x1B, 0x20, 0x00, 0x01, 0x02);
+    le_read_supported_states(
+        0x0E, 12, 1, 0x1C, 0x20, 0x00, 0x01, 0x02, 0x04, 0x08, 0x10, 0x02, 0x00, 0x00,
+    );
+    le_test_end(0x0E, 6, 1, 0x1F, 0x20, 0x00, 0x01, 0x02);
+    le_set_data_length(0x0E, 6, 1, 0x22, 0x20, 0x00, 0x01, 0x02);
+    le_read_suggested_default_data_length(0x0E, 8, 1, 0x23, 0x20, 0x00, 0x1B, 0x00, 0x48, 0x01);
+    le_write_suggested_default_data_length(0x0E, 4, 1, 0x24, 0x20, 0);
+    le_read_maximum_data_length(
+        0x0E, 12, 1, 0x2F, 0x20, 0x00, 0xFB, 0x00, 0x90, 0x42, 0xFA, 0x00, 0x48, 0x08,
+    );
+    le_add_device_to_resolving_list(0x0E, 4, 1, 0x27, 0x20, 0);
+    le_remove_device_from_resolving_list(0x0E, 4, 1, 0x28, 0x20, 0);
+    le_clear_resolving_list(0x0E, 4, 1, 0x29, 0x20, 0);
+    le_read_resolving_list_size(0x0E, 5, 1, 0x2A, 0x20, 0x00, 0x08);
+    le_read_peer_resolvable_address(0x0E, 10, 1, 0x2B, 0x20, 0x00, 1, 2, 3, 4, 5, 0x46);
+    le_read_local_resolvable_address(0x0E, 10, 1, 0x2C, 0x20, 0x00, 1, 2, 3, 4, 5, 0x46);
+    le_set_address_resolution_enable(0x0E, 4, 1, 0x2D, 0x20, 0);
+    le_set_resolvable_private_address_timeout(0x0E, 4, 1, 0x2E, 0x20, 0);
+    le_read_phy(0x0E, 8, 1, 0x30, 0x20, 0x00, 0x01, 0x02, 0x01, 0x03);
+    le_set_default_phy(0x0E, 4, 1, 0x31, 0x20, 0);
+    le_set_extended_advertising_parameters(0x0E, 5, 1, 0x36, 0x20, 0x00, 0xFB);
+    le_set_extended_advertising_data(0x0E, 4, 1, 0x37, 0x20, 0);
+    le_set_extended_scan_response_data(0x0E, 4, 1, 0x38, 0x20, 0);
+    le_set_extended_advertising_enable(0x0E, 4, 1, 0x39, 0x20, 0);
+    le_read_maximum_advertising_data_length(0x0E, 6, 1, 0x3A, 0x20, 0x00, 0x72, 0x06);
+    le_read_number_of_supported_advertising_sets(0x0E, 5, 1, 0x3B, 0x20, 0x00, 0x06);
+    le_remove_advertising_set(0x0E, 4, 1, 0x3C, 0x20, 0);
+    le_clear_advertising_sets(0x0E, 4, 1, 0x3D, 0x20, 0);
+    le_set_extended_scan_parameters(0x0E, 4, 1, 0x41, 0x20, 0);
+    le_set_extended_scan_enable(0x0E, 4, 1, 0x42, 0x20, 0);
+    le_set_periodic_advertising_parameters(0x0E, 4, 1, 0x3E, 0x20, 0);
+    le_set_periodic_advertising_data(0x0E, 4, 1, 0x3F, 0x20, 0);
+    le_set_periodic_advertising_enable(0x0E, 4, 1, 0x40, 0x20, 0);
+    le_periodic_advertising_create_sync_cancel(0x0E, 4, 1, 0x45, 0x20, 0);
+    le_periodic_advertising_terminate_sync(0x0E, 4, 1, 0x46, 0x20, 0);
+    le_add_device_to_periodic_advertiser_list(0x0E, 4, 1, 0x47, 0x20, 0);
+    le_remove_device_from_periodic_advertiser_list(0x0E, 4, 1, 0x48, 0x20, 0);
+    le_clear_periodic_advertiser_list(0x0E, 4, 1, 0x49, 0x20, 0);
+    le_read_periodic_advertiser_list_size(0x0E, 5, 1, 0x4A, 0x20, 0x00, 0x08);
+    le_set_privacy_mode(0x0E, 4, 1, 0x4E, 0x20, 0);
+    le_periodic_advertising_sync_transfer(0x0E, 6, 1, 0x5A, 0x20, 0, 0x01, 0x02);
+    le_periodic_advertising_set_info_transfer(0x0E, 6, 1, 0x5B, 0x20, 0, 0x01, 0x02);
+    le_set_periodic_advertising_sync_transfer_parameters(0x0E, 6, 1, 0x5C, 0x20, 0, 0x01, 0x02);
+    le_set_default_periodic_advertising_sync_transfer_parameters(0x0E, 4, 1, 0x5D, 0x20, 0);
+    le_read_iso_tx_sync(
+        0x0E, 15, 1, 0x61, 0x20, 0, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A,
+        0x00,
+    );
+    le_set_cig_parameters(0x0E, 10, 1, 0x62, 0x20, 0, 0x01, 2, 0x01, 0x02, 0x03, 0x04);
+    le_set_cig_parameters_test(0x0E, 8, 1, 0x63, 0x20, 0, 0x01, 1, 0x01, 0x02);
+    le_remove_cig(0x0E, 5, 1, 0x65, 0x20, 0, 0x01);
+    le_reject_cis_request(0x0E, 6, 1, 0x67, 0x20, 0, 0x01, 0x02);
+    le_big_terminate_sync(0x0E, 5, 1, 0x6C, 0x20, 0, 0x01);
+    le_setup_iso_data_path(0x0E, 6, 1, 0x6E, 0x20, 0, 0x01, 0x02);
+    le_remove_iso_data_path(0x0E, 6, 1, 0x6F, 0x20, 0, 0x01, 0x02);
+    le_enhanced_read_transmit_power_level(0x0E, 9, 1, 0x76, 0x20, 0, 0x01, 0x02, 0x02, 0x7F, 0x14);
+    le_set_path_loss_reporting_parameters(0x0E, 6, 1, 0x78, 0x20, 0, 0x01, 0x02);
+    le_set_path_loss_reporting_enable(0x0E, 6, 1, 0x79, 0x20, 0, 0x01, 0x02);
+    le_set_transmit_power_reporting_enable(0x0E, 6, 1, 0x7A, 0x20, 0, 0x01, 0x02);
+    le_set_default_subrate(0x0E, 4, 1, 0x7D, 0x20, 0);
+    le_set_connectionless_cte_transmit_parameters(0x0E, 4, 1, 0x51, 0x20, 0);
+    le_set_connectionless_iq_sampling_enable(0x0E, 6, 1, 0x53, 0x20, 0, 0x01, 0x02);
+    le_connection_cte_request_enable(0x0E, 6, 1, 0x56, 0x20, 0, 0x01, 0x02);
+    le_read_antenna_information(0x0E, 8, 1, 0x58, 0x20, 0, 0x07, 4, 0x10, 0x14);
+    vendor_command(0x0E, 6, 1, 0x00, 0xFC, 0x00, 0x00, 0x00);
+    hal_write_config_data(0x0E, 4, 1, 0x0C, 0xFC, 0x00);
+    hal_set_tx_power_level(0x0E, 4, 1, 0x0F, 0xFC, 0x00);
+    hal_device_standby(0x0E, 4, 1, 0x13, 0xFC, 0x00);
+    hal_start_tone(0x0E, 4, 1, 0x15, 0xFC, 0x00);
+    hal_stop_tone(0x0E, 4, 1, 0x16, 0xFC, 0x00);
+    hal_read_config_data_public_address(
+        0x0E, 10, 1, 0x0D, 0xFC, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06,
+    );
+    hal_read_config_data_diversifier(0x0E, 6, 1, 0x0D, 0xFC, 0x00, 0x01, 0x02);
+    hal_read_config_data_key(
+        0x0E, 20, 1, 0x0D, 0xFC, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A,
+        0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10,
+    );
+    hal_read_config_data_byte(0x0E, 5, 1, 0x0D, 0xFC, 0x00, 0x01);
+    hal_tx_test_packet_count(0x0E, 8, 1, 0x14, 0xFC, 0x00, 0x01, 0x02, 0x03, 0x04);
+    hal_get_link_status(
+        0x0E, 28, 1, 0x17, 0xFC, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x10, 0x11,
+        0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F,
+    );
+    hal_get_anchor_period(
+        0x0E, 12, 1, 0x19, 0xFC, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+    );
+    hal_get_pm_debug_info(0x0E, 6, 1, 0x1C, 0xFC, 0x01, 0x02, 0x03);
+    hal_read_rssi(0x0E, 4, 1, 0x22, 0xFC, 0xC5);
+    hal_read_radio_reg(0x0E, 4, 1, 0x30, 0xFC, 0x5A);
+    hal_read_raw_rssi(0x0E, 4, 1, 0x32, 0xFC, 0xA5);
+    gap_set_nondiscoverable(0x0E, 4, 1, 0x81, 0xFC, 0x00);
+    gap_set_discoverable(0x0E, 4, 1, 0x83, 0xFC, 0x00);
+    gap_set_direct_connectable(0x0E, 4, 1, 0x84, 0xFC, 0x00);
+    gap_set_io_capability(0x0E, 4, 1, 0x85, 0xFC, 0x00);
+    gap_set_authentication_requirement(0x0E, 4, 1, 0x86, 0xFC, 0x00);
+    gap_set_authorization_requirement(0x0E, 4, 1, 0x87, 0xFC, 0x00);
+    gap_pass_key_response(0x0E, 4, 1, 0x88, 0xFC, 0x00);
+    gap_authorization_response(0x0E, 4, 1, 0x89, 0xFC, 0x00);
+    gap_set_nonconnectable(0x0E, 4, 1, 0x8B, 0xFC, 0x00);
+    gap_set_undirected_connectable(0x0E, 4, 1, 0x8C, 0xFC, 0x00);
+    gap_update_advertising_data(0x0E, 4, 1, 0x8E, 0xFC, 0x00);
+    gap_delete_ad_type(0x0E, 4, 1, 0x8F, 0xFC, 0x00);
+    gap_set_event_mask(0x0E, 4, 1, 0x91, 0xFC, 0x00);
+    gap_configure_white_list(0x0E, 4, 1, 0x92, 0xFC, 0x00);
+    gap_clear_security_database(0x0E, 4, 1, 0x94, 0xFC, 0x00);
+    gap_allow_rebond(0x0E, 4, 1, 0x95, 0xFC, 0x00);
+    gap_terminate_procedure(0x0E, 4, 1, 0x9D, 0xFC, 0x00);
+    gap_set_broadcast_mode(0x0E, 4, 1, 0xA1, 0xFC, 0x00);
+    gap_start_observation_procedure(0x0E, 4, 1, 0xA2, 0xFC, 0x00);
+    gap_is_device_bonded(0x0E, 4, 1, 0xA4, 0xFC, 0x00);
+    gap_init(0x0E, 10, 1, 0x8A, 0xFC, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06);
+    gap_get_security_level(0x0E, 8, 1, 0x90, 0xFC, 0x00, 0x01, 0x00, 0x01, 0x02);
+    gap_resolve_private_address(0x0E, 10, 1, 0xA0, 0xFC, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06);
+    gap_resolve_private_address_failed(0x0E, 4, 1, 0xA0, 0xFC, 0x12);
+    gap_get_bonded_devices(
+        0x0E, 19, 1, 0xA3, 0xFC, 0x00, 0x02, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x01, 0x07,
+        0x08, 0x09, 0x0A, 0x0B, 0x0C,
+    );
+    gatt_init(0x0E, 4, 1, 0x01, 0xFD, 0x00);
+    gatt_update_characteristic_value(0x0E, 4, 1, 0x06, 0xFD, 0x00);
+    gatt_delete_characteristic(0x0E, 4, 1, 0x07, 0xFD, 0x00);
+    gatt_delete_service(0x0E, 4, 1, 0x08, 0xFD, 0x00);
+    gatt_delete_included_service(0x0E, 4, 1, 0x09, 0xFD, 0x00);
+    gatt_set_event_mask(0x0E, 4, 1, 0x0A, 0xFD, 0x00);
+    gatt_write_without_response(0x0E, 4, 1, 0x23, 0xFD, 0x00);
+    gatt_signed_write_without_response(0x0E, 4, 1, 0x24, 0xFD, 0x00);
+    gatt_confirm_indication(0x0E, 4, 1, 0x25, 0xFD, 0x00);
+    gatt_write_response(0x0E, 4, 1, 0x26, 0xFD, 0x00);
+    gatt_allow_read(0x0E, 4, 1, 0x27, 0xFD, 0x00);
+    gatt_set_security_permission(0x0E, 4, 1, 0x28, 0xFD, 0x00);
+    gatt_set_descriptor_value(0x0E, 4, 1, 0x29, 0xFD, 0x00);
+    gatt_update_long_characteristic_value(0x0E, 4, 1, 0x2C, 0xFD, 0x00);
+    gatt_add_service(0x0E, 6, 1, 0x02, 0xFD, 0x00, 0x01, 0x02);
+    gatt_include_service(0x0E, 6, 1, 0x03, 0xFD, 0x00, 0x01, 0x02);
+    gatt_add_characteristic(0x0E, 6, 1, 0x04, 0xFD, 0x00, 0x01, 0x02);
+    gatt_add_characteristic_descriptor(0x0E, 6, 1, 0x05, 0xFD, 0x00, 0x01, 0x02);
+    gatt_read_handle_value(0x0E, 9, 1, 0x2A, 0xFD, 0x00, 0x03, 0x00, 0x01, 0x02, 0x03);
+    gatt_read_handle_value_offset(0x0E, 9, 1, 0x2B, 0xFD, 0x00, 0x03, 0x00, 0x01, 0x02, 0x03);
+    l2cap_connection_parameter_update_response(0x0E, 4, 1, 0x82, 0xFD, 0x00);
+
+    gap_device_found(
+        0xFF, 14, 0x06, 0x04, 0x04, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 2, 0x07, 0x08, 0xF6,
+    );
+    gap_procedure_complete_name_discovery(0xFF, 7, 0x07, 0x04, 0x04, 0x00, b'a', b'b', b'c');
+    gatt_notification(0xFF, 10, 0x0F, 0x0C, 0x01, 0x02, 5, 0x03, 0x04, 0x05, 0x06, 0x07);
+    gap_limited_discoverable_timeout(0xFF, 2, 0x00, 0x04);
+    coprocessor_ready(0xFF, 3, 0x00, 0x92, 0x01);
+    hal_end_of_radio_activity(0xFF, 10, 0x04, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08);
+    hal_scan_req_report(0xFF, 10, 0x05, 0x00, 0xC5, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06);
+    hal_firmware_error(0xFF, 7, 0x06, 0x00, 0x01, 0x03, 0x0A, 0x0B, 0x0C);
+    gap_pairing_complete(0xFF, 6, 0x01, 0x04, 0x01, 0x02, 0x02, 0x05);
+    gap_pass_key_request(0xFF, 4, 0x02, 0x04, 0x01, 0x02);
+    gap_authorization_request(0xFF, 4, 0x03, 0x04, 0x01, 0x02);
+    gap_peripheral_security_initiated(0xFF, 2, 0x04, 0x04);
+    gap_bond_lost(0xFF, 2, 0x05, 0x04);
+    gap_address_not_resolved(0xFF, 4, 0x08, 0x04, 0x01, 0x02);
+    gap_numeric_comparison_value(0xFF, 8, 0x09, 0x04, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06);
+    gap_keypress_notification(0xFF, 5, 0x0A, 0x04, 0x01, 0x02, 0x03);
+    l2cap_connection_update_response(
+        0xFF, 11, 0x00, 0x08, 0x01, 0x02, 0x06, 0x13, 0x00, 0x02, 0x00, 0x01, 0x00,
+    );
+    l2cap_connection_update_response_rejected(
+        0xFF, 11, 0x00, 0x08, 0x01, 0x02, 0x06, 0x01, 0x00, 0x02, 0x00, 0x02, 0x00,
+    );
+    l2cap_procedure_timeout(0xFF, 5, 0x01, 0x08, 0x01, 0x02, 0x00);
+    l2cap_connection_update_request(
+        0xFF, 16, 0x02, 0x08, 0x01, 0x02, 0x0B, 0x03, 0x08, 0x00, 0x28, 0x00, 0x30, 0x00, 0x00,
+        0x00, 0xC8, 0x00,
+    );
+    l2cap_coc_connect(
+        0xFF, 13, 0x10, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x01,
+    );
+    l2cap_coc_connect_confirm(
+        0xFF, 15, 0x11, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x02,
+        0x01, 0x02,
+    );
+    l2cap_coc_reconfig(0xFF, 11, 0x12, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x02, 0x01, 0x02);
+    l2cap_coc_reconfig_confirm(0xFF, 6, 0x13, 0x08, 0x01, 0x02, 0x03, 0x04);
+    l2cap_coc_disconnect(0xFF, 3, 0x14, 0x08, 0x05);
+    l2cap_coc_flow_control(0xFF, 5, 0x15, 0x08, 0x05, 0x01, 0x02);
+    l2cap_coc_rx_data(0xFF, 8, 0x16, 0x08, 0x05, 0x03, 0x00, 0x0A, 0x0B, 0x0C);
+    l2cap_coc_tx_pool_available(0xFF, 2, 0x17, 0x08);
+    gatt_attribute_modified(
+        0xFF, 13, 0x01, 0x0C, 0x01, 0x02, 0x03, 0x04, 0x00, 0x00, 0x03, 0x00, 0x0A, 0x0B, 0x0C,
+    );
+    gatt_procedure_timeout(0xFF, 4, 0x02, 0x0C, 0x01, 0x02);
+    att_exchange_mtu_response(0xFF, 6, 0x03, 0x0C, 0x01, 0x02, 0x17, 0x00);
+    att_find_information_response_16(
+        0xFF, 14, 0x04, 0x0C, 0x01, 0x02, 0x01, 0x08, 0x01, 0x00, 0x0A, 0x18, 0x02, 0x00, 0x0B,
+        0x18,
+    );
+    att_find_information_response_128(
+        0xFF, 24, 0x04, 0x0C, 0x01, 0x02, 0x02, 0x12, 0x01, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05,
+        0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10,
+    );
+    att_find_by_type_value_response(
+        0xFF, 13, 0x05, 0x0C, 0x01, 0x02, 0x08, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04, 0x00,
+    );
+    att_read_by_type_response(
+        0xFF, 14, 0x06, 0x0C, 0x01, 0x02, 0x04, 0x08, 0x01, 0x00, 0x0A, 0x0B, 0x02, 0x00, 0x0C,
+        0x0D,
+    );
+    att_read_response(0xFF, 8, 0x07, 0x0C, 0x01, 0x02, 0x03, 0x0A, 0x0B, 0x0C);
+    att_read_blob_response(0xFF, 8, 0x08, 0x0C, 0x01, 0x02, 0x03, 0x0A, 0x0B, 0x0C);
+    att_read_multiple_response(0xFF, 8, 0x09, 0x0C, 0x01, 0x02, 0x03, 0x0A, 0x0B, 0x0C);
+    att_read_by_group_type_response(
+        0xFF, 12, 0x0A, 0x0C, 0x01, 0x02, 0x06, 0x06, 0x01, 0x00, 0x02, 0x00, 0x0A, 0x0B,
+    );
+    att_prepare_write_response(
+        0xFF, 12, 0x0C, 0x0C, 0x01, 0x02, 0x07, 0x03, 0x00, 0x04, 0x00, 0x0A, 0x0B, 0x0C,
+    );
+    att_execute_write_response(0xFF, 4, 0x0D, 0x0C, 0x01, 0x02);
+    gatt_indication(0xFF, 10, 0x0E, 0x0C, 0x01, 0x02, 0x05, 0x03, 0x04, 0x05, 0x06, 0x07);
+    gatt_procedure_complete(0xFF, 5, 0x10, 0x0C, 0x01, 0x02, 0x00);
+    att_error_response(0xFF, 8, 0x11, 0x0C, 0x01, 0x02, 0x0A, 0x03, 0x04, 0x01);
+    gatt_discover_or_read_characteristic_by_uuid_response(
+        0xFF, 10, 0x12, 0x0C, 0x01, 0x02, 0x05, 0x03, 0x04, 0x05, 0x06, 0x07,
+    );
+    att_write_permit_request(0xFF, 10, 0x13, 0x0C, 0x01, 0x02, 0x03, 0x04, 0x03, 0x0A, 0x0B, 0x0C);
+    att_read_permit_request(0xFF, 8, 0x14, 0x0C, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06);
+    att_read_multiple_permit_request(0xFF, 9, 0x15, 0x0C, 0x01, 0x02, 0x04, 0x03, 0x04, 0x05, 0x06);
+    gatt_tx_pool_available(0xFF, 6, 0x16, 0x0C, 0x01, 0x02, 0x03, 0x00);
+    gatt_server_confirmation(0xFF, 4, 0x17, 0x0C, 0x01, 0x02);
+    att_prepare_write_permit_request(
+        0xFF, 12, 0x18, 0x0C, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x03, 0x0A, 0x0B, 0x0C,
+    );
+    gatt_eatt_bearer(0xFF, 5, 0x19, 0x0C, 0x05, 0x01, 0x00);
+    gatt_multi_notification(
+        0xFF, 11, 0x1A, 0x0C, 0x01, 0x02, 0x03, 0x04, 0x03, 0x00, 0x0A, 0x0B, 0x0C,
+    );
+    gatt_notification_complete(0xFF, 4, 0x1B, 0x0C, 0x01, 0x02);
+    gatt_read_ext(0xFF, 11, 0x1D, 0x0C, 0x01, 0x02, 0x03, 0x04, 0x03, 0x00, 0x0A, 0x0B, 0x0C);
+    gatt_indication_ext(
+        0xFF, 13, 0x1E, 0x0C, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x03, 0x00, 0x0A, 0x0B, 0x0C,
+    );
+    gatt_notification_ext(
+        0xFF, 13, 0x1F, 0x0C, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x03, 0x00, 0x0A, 0x0B, 0x0C,
+    );
+}
+
+#[test]
+fn le_read_local_p256_public_key_complete() {
+    let mut buffer = [0x5A; 68];
+    buffer[..4].copy_from_slice(&[0x3E, 66, 0x08, 0x00]);
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn le_generate_dhkey_complete() {
+    let mut buffer = [0x77; 36];
+    buffer[..4].copy_from_slice(&[0x3E, 34, 0x09, 0x00]);
+    assert_round_trip(&buffer);
+}
+
+#[test]
+fn l2cap_command_reject() {
+    let mut buffer = [0x3C; 257];
+    buffer[..10].copy_from_slice(&[0xFF, 255, 0x0A, 0x08, 0x01, 0x02, 0x03, 0x04, 0x00, 247]);
+    assert_round_trip(&buffer);
+}
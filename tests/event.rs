@@ -448,3 +448,1064 @@ fn le_long_term_key_request() {
         other => panic!("Did not Get LE LTK Request: {:?}", other),
     }
 }
+
+#[test]
+fn le_phy_update_complete() {
+    let buffer = [0x3E, 6, 0x0C, 0x00, 0x01, 0x02, 0x02, 0x03];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePhyUpdateComplete(event)) => {
+            assert_eq!(event.status, hci::Status::Success);
+            assert_eq!(event.conn_handle, ::hci::ConnectionHandle(0x0201));
+            assert_eq!(event.tx_phy, Phy::Le2M);
+            assert_eq!(event.rx_phy, Phy::LeCoded);
+        }
+        other => panic!("Did not get LE PHY Update Complete: {:?}", other),
+    }
+}
+
+#[test]
+fn le_phy_update_complete_failed_bad_phy() {
+    let buffer = [0x3E, 6, 0x0C, 0x00, 0x01, 0x02, 0x00, 0x01];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadPhy(0x00));
+}
+
+#[test]
+fn le_read_local_p256_public_key_complete() {
+    let mut buffer = [0; 68];
+    buffer[..4].copy_from_slice(&[0x3E, 66, 0x08, 0x00]);
+    for (i, byte) in buffer[4..].iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeReadLocalP256PublicKeyComplete(event)) => {
+            assert_eq!(event.status, hci::Status::Success);
+            assert_eq!(event.key.0, buffer[4..]);
+            assert_eq!(event.key.x(), &buffer[4..36]);
+            assert_eq!(event.key.y(), &buffer[36..]);
+        }
+        other => panic!(
+            "Did not get LE Read Local P-256 Public Key Complete: {:?}",
+            other
+        ),
+    }
+}
+
+#[test]
+fn le_generate_dhkey_complete() {
+    let mut buffer = [0x77; 36];
+    buffer[..4].copy_from_slice(&[0x3E, 34, 0x09, 0x00]);
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeGenerateDHKeyComplete(event)) => {
+            assert_eq!(event.status, hci::Status::Success);
+            assert_eq!(event.dh_key, [0x77; 32]);
+        }
+        other => panic!("Did not get LE Generate DHKey Complete: {:?}", other),
+    }
+}
+
+#[test]
+fn le_generate_dhkey_complete_failed_bad_status() {
+    let mut buffer = [0; 36];
+    buffer[..4].copy_from_slice(&[0x3E, 34, 0x09, 0x45]);
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadStatus(0x45));
+}
+
+#[test]
+fn le_extended_advertising_report() {
+    let buffer = [
+        0x3E, 55, 0x0D, 2, 0x13, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x01, 0x00, 0xFF,
+        0x7F, 0xD8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 2, 0x02, 0x01, 0x24,
+        0x00, 0x03, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x03, 0x02, 0x05, 0xF6, 0x7F, 0x50, 0x00,
+        0xFE, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 3, 0x07, 0x08, 0x09,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeExtendedAdvertisingReport(event)) => {
+            let mut iter = event.iter();
+            let report = iter.next().unwrap();
+            assert_eq!(
+                report.event_type,
+                ExtendedAdvertisementEvent::CONNECTABLE
+                    | ExtendedAdvertisementEvent::SCANNABLE
+                    | ExtendedAdvertisementEvent::LEGACY
+            );
+            assert_eq!(report.data_status, AdvertisingDataStatus::Complete);
+            assert_eq!(
+                report.address,
+                Some(hci::host::PeerAddrType::PublicDeviceAddress(hci::BdAddr([
+                    0x01, 0x02, 0x03, 0x04, 0x05, 0x06
+                ])))
+            );
+            assert_eq!(report.primary_phy, Phy::Le1M);
+            assert_eq!(report.secondary_phy, None);
+            assert_eq!(report.sid, None);
+            assert_eq!(report.tx_power, None);
+            assert_eq!(report.rssi, Some(-40));
+            assert_eq!(report.periodic_adv_interval, None);
+            assert_eq!(report.direct_address, None);
+            assert_eq!(report.data, [0x02, 0x01]);
+
+            let report = iter.next().unwrap();
+            assert_eq!(report.event_type, ExtendedAdvertisementEvent::DIRECTED);
+            assert_eq!(report.data_status, AdvertisingDataStatus::Incomplete);
+            assert_eq!(
+                report.address,
+                Some(hci::host::PeerAddrType::RandomIdentityAddress(hci::BdAddr(
+                    [0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F]
+                )))
+            );
+            assert_eq!(report.primary_phy, Phy::LeCoded);
+            assert_eq!(report.secondary_phy, Some(Phy::Le2M));
+            assert_eq!(report.sid, Some(0x05));
+            assert_eq!(report.tx_power, Some(hci::Dbm(-10)));
+            assert_eq!(report.rssi, None);
+            assert_eq!(
+                report.periodic_adv_interval,
+                Some(Duration::from_millis(100))
+            );
+            assert_eq!(
+                report.direct_address,
+                Some(DirectAddress::UnresolvedRandom(hci::BdAddr([
+                    0x11, 0x12, 0x13, 0x14, 0x15, 0x16
+                ])))
+            );
+            assert_eq!(report.data, [0x07, 0x08, 0x09]);
+            assert!(iter.next().is_none());
+        }
+        other => panic!("Did not get extended advertising report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_extended_advertising_report_anonymous() {
+    let buffer = [
+        0x3E, 26, 0x0D, 1, 0x40, 0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x03, 0x01,
+        0x7F, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeExtendedAdvertisingReport(event)) => {
+            let report = event.iter().next().unwrap();
+            assert_eq!(report.event_type, ExtendedAdvertisementEvent::empty());
+            assert_eq!(report.data_status, AdvertisingDataStatus::Truncated);
+            assert_eq!(report.address, None);
+            assert_eq!(report.sid, Some(0x01));
+            assert_eq!(report.data, []);
+        }
+        other => panic!("Did not get extended advertising report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_extended_advertising_report_failed_incomplete() {
+    let buffer = [
+        0x3E, 28, 0x0D, 1, 0x13, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x01, 0x00, 0xFF,
+        0x7F, 0xD8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 3, 0x02, 0x01,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Err(Error::LeAdvertisementReportIncomplete) => (),
+        other => panic!("Did not get incomplete advertising report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_extended_advertising_report_failed_bad_data_status() {
+    let buffer = [
+        0x3E, 26, 0x0D, 1, 0x73, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x01, 0x00, 0xFF,
+        0x7F, 0xD8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0,
+    ];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadLeExtendedAdvertisementType(0x0073));
+}
+
+#[test]
+fn le_extended_advertising_report_failed_bad_addr_type() {
+    let buffer = [
+        0x3E, 26, 0x0D, 1, 0x13, 0x00, 0x04, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x01, 0x00, 0xFF,
+        0x7F, 0xD8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0,
+    ];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadLeAddressType(0x04));
+}
+
+#[test]
+fn le_directed_advertising_report() {
+    let buffer = [
+        0x3E, 34, 0x0B, 2, 0x01, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x01, 0x11, 0x12, 0x13,
+        0x14, 0x15, 0x56, 0xD8, 0x01, 0x03, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x01, 0x21, 0x22,
+        0x23, 0x24, 0x25, 0x66, 0x7F,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeDirectedAdvertisingReport(event)) => {
+            let mut iter = event.iter();
+            let report = iter.next().unwrap();
+            assert_eq!(
+                report.address,
+                hci::host::PeerAddrType::PublicDeviceAddress(hci::BdAddr([
+                    0x01, 0x02, 0x03, 0x04, 0x05, 0x06
+                ]))
+            );
+            assert_eq!(
+                report.direct_address,
+                hci::BdAddr([0x11, 0x12, 0x13, 0x14, 0x15, 0x56])
+            );
+            assert_eq!(report.rssi, Some(-40));
+
+            let report = iter.next().unwrap();
+            assert_eq!(
+                report.address,
+                hci::host::PeerAddrType::RandomIdentityAddress(hci::BdAddr([
+                    0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F
+                ]))
+            );
+            assert_eq!(report.rssi, None);
+            assert!(iter.next().is_none());
+        }
+        other => panic!("Did not get directed advertising report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_directed_advertising_report_failed_bad_advertisement_type() {
+    let buffer = [
+        0x3E, 18, 0x0B, 1, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x01, 0x11, 0x12, 0x13,
+        0x14, 0x15, 0x56, 0xD8,
+    ];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadLeAdvertisementType(0x00));
+}
+
+#[test]
+fn le_directed_advertising_report_failed_bad_direct_addr_type() {
+    let buffer = [
+        0x3E, 18, 0x0B, 1, 0x01, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x00, 0x11, 0x12, 0x13,
+        0x14, 0x15, 0x56, 0xD8,
+    ];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadLeAddressType(0x00));
+}
+
+#[test]
+fn le_directed_advertising_report_failed_incomplete() {
+    let buffer = [
+        0x3E, 17, 0x0B, 1, 0x01, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x01, 0x11, 0x12, 0x13,
+        0x14, 0x15, 0x56,
+    ];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadLength(17, 18));
+}
+
+#[test]
+fn le_scan_timeout() {
+    let buffer = [0x3E, 1, 0x11];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeScanTimeout) => (),
+        other => panic!("Did not get scan timeout: {:?}", other),
+    }
+}
+
+#[test]
+fn le_advertising_set_terminated() {
+    let buffer = [0x3E, 6, 0x12, 0x43, 0x01, 0x01, 0x02, 0x05];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeAdvertisingSetTerminated(event)) => {
+            assert_eq!(event.status, hci::Status::LimitReached);
+            assert_eq!(event.adv_handle, hci::AdvertisingHandle(0x01));
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(event.num_completed_extended_adv_events, 5);
+        }
+        other => panic!("Did not get advertising set terminated: {:?}", other),
+    }
+}
+
+#[test]
+fn le_advertising_set_terminated_failed_bad_status() {
+    let buffer = [0x3E, 6, 0x12, 0x45, 0x01, 0x01, 0x02, 0x05];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadStatus(0x45));
+}
+
+#[test]
+fn le_scan_request_received() {
+    let buffer = [
+        0x3E, 9, 0x13, 0x01, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeScanRequestReceived(event)) => {
+            assert_eq!(event.adv_handle, hci::AdvertisingHandle(0x01));
+            assert_eq!(
+                event.scanner_address,
+                hci::host::PeerAddrType::RandomDeviceAddress(hci::BdAddr([
+                    0x01, 0x02, 0x03, 0x04, 0x05, 0x06
+                ]))
+            );
+        }
+        other => panic!("Did not get scan request received: {:?}", other),
+    }
+}
+
+#[test]
+fn le_channel_selection_algorithm() {
+    let buffer = [0x3E, 4, 0x14, 0x01, 0x02, 0x01];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeChannelSelectionAlgorithm(event)) => {
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(event.algorithm, ChannelSelectionAlgorithm::Algorithm2);
+        }
+        other => panic!("Did not get channel selection algorithm: {:?}", other),
+    }
+}
+
+#[test]
+fn le_channel_selection_algorithm_failed_bad_algorithm() {
+    let buffer = [0x3E, 4, 0x14, 0x01, 0x02, 0x02];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadChannelSelectionAlgorithm(0x02));
+}
+
+#[test]
+fn le_periodic_advertising_sync_established() {
+    let buffer = [
+        0x3E, 16, 0x0E, 0x00, 0x01, 0x02, 0x03, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x02,
+        0x50, 0x00, 0x04,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePeriodicAdvertisingSyncEstablished(event)) => {
+            assert_eq!(event.status, hci::Status::Success);
+            assert_eq!(event.sync_handle, hci::SyncHandle(0x0201));
+            assert_eq!(event.adv_sid, 0x03);
+            assert_eq!(
+                event.adv_address,
+                hci::host::PeerAddrType::RandomDeviceAddress(hci::BdAddr([
+                    0x01, 0x02, 0x03, 0x04, 0x05, 0x06
+                ]))
+            );
+            assert_eq!(event.adv_phy, Some(Phy::Le2M));
+            assert_eq!(event.periodic_adv_interval, Duration::from_millis(100));
+            assert_eq!(event.adv_clock_accuracy, Some(CentralClockAccuracy::Ppm75));
+        }
+        other => panic!(
+            "Did not get periodic advertising sync established: {:?}",
+            other
+        ),
+    }
+}
+
+#[test]
+fn le_periodic_advertising_sync_established_failed_bad_phy() {
+    let buffer = [
+        0x3E, 16, 0x0E, 0x00, 0x01, 0x02, 0x03, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x04,
+        0x50, 0x00, 0x04,
+    ];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadPhy(0x04));
+}
+
+#[test]
+fn le_periodic_advertising_sync_established_cancelled() {
+    // The other parameters are zeroed when synchronization fails.
+    let buffer = [
+        0x3E, 16, 0x0E, 0x44, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePeriodicAdvertisingSyncEstablished(event)) => {
+            assert_eq!(event.status, hci::Status::OperationCancelledByHost);
+            assert_eq!(event.adv_phy, None);
+            assert_eq!(event.adv_clock_accuracy, None);
+        }
+        other => panic!(
+            "Did not get periodic advertising sync established: {:?}",
+            other
+        ),
+    }
+}
+
+#[test]
+fn le_periodic_advertising_report() {
+    let buffer = [
+        0x3E, 11, 0x0F, 0x01, 0x02, 0xF6, 0xD8, 0x01, 0x01, 3, 0x07, 0x08, 0x09,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePeriodicAdvertisingReport(event)) => {
+            assert_eq!(event.sync_handle, hci::SyncHandle(0x0201));
+            assert_eq!(event.tx_power, Some(hci::Dbm(-10)));
+            assert_eq!(event.rssi, Some(-40));
+            assert_eq!(event.cte_type, Some(CteType::AoD1us));
+            assert_eq!(event.data_status, AdvertisingDataStatus::Incomplete);
+            assert_eq!(event.data(), [0x07, 0x08, 0x09]);
+        }
+        other => panic!("Did not get periodic advertising report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_periodic_advertising_report_no_cte() {
+    let buffer = [0x3E, 8, 0x0F, 0x01, 0x02, 0x7F, 0x7F, 0xFF, 0x00, 0];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePeriodicAdvertisingReport(event)) => {
+            assert_eq!(event.tx_power, None);
+            assert_eq!(event.rssi, None);
+            assert_eq!(event.cte_type, None);
+            assert_eq!(event.data_status, AdvertisingDataStatus::Complete);
+            assert_eq!(event.data(), []);
+        }
+        other => panic!("Did not get periodic advertising report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_periodic_advertising_report_failed_bad_data_status() {
+    let buffer = [0x3E, 8, 0x0F, 0x01, 0x02, 0x7F, 0x7F, 0xFF, 0x03, 0];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadAdvertisingDataStatus(0x03));
+}
+
+#[test]
+fn le_periodic_advertising_report_failed_bad_cte_type() {
+    let buffer = [0x3E, 8, 0x0F, 0x01, 0x02, 0x7F, 0x7F, 0x03, 0x00, 0];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadCteType(0x03));
+}
+
+#[test]
+fn le_periodic_advertising_report_failed_incomplete() {
+    let buffer = [
+        0x3E, 10, 0x0F, 0x01, 0x02, 0x7F, 0x7F, 0xFF, 0x00, 3, 0x07, 0x08,
+    ];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadLength(10, 11));
+}
+
+#[test]
+fn le_periodic_advertising_sync_lost() {
+    let buffer = [0x3E, 3, 0x10, 0x01, 0x02];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePeriodicAdvertisingSyncLost(sync_handle)) => {
+            assert_eq!(sync_handle, hci::SyncHandle(0x0201));
+        }
+        other => panic!("Did not get periodic advertising sync lost: {:?}", other),
+    }
+}
+
+#[test]
+fn le_periodic_advertising_sync_transfer_received() {
+    let buffer = [
+        0x3E, 20, 0x18, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x00, 0x01, 0x02, 0x03,
+        0x04, 0x05, 0x06, 0x03, 0x50, 0x00, 0x02,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePeriodicAdvertisingSyncTransferReceived(event)) => {
+            assert_eq!(event.status, hci::Status::Success);
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(event.service_data, 0x0403);
+            assert_eq!(event.sync_handle, hci::SyncHandle(0x0605));
+            assert_eq!(event.adv_sid, 0x07);
+            assert_eq!(
+                event.adv_address,
+                hci::host::PeerAddrType::PublicDeviceAddress(hci::BdAddr([
+                    0x01, 0x02, 0x03, 0x04, 0x05, 0x06
+                ]))
+            );
+            assert_eq!(event.adv_phy, Some(Phy::LeCoded));
+            assert_eq!(event.periodic_adv_interval, Duration::from_millis(100));
+            assert_eq!(event.adv_clock_accuracy, Some(CentralClockAccuracy::Ppm150));
+        }
+        other => panic!(
+            "Did not get periodic advertising sync transfer received: {:?}",
+            other
+        ),
+    }
+}
+
+#[test]
+fn le_periodic_advertising_sync_transfer_received_failed_bad_clock_accuracy() {
+    let buffer = [
+        0x3E, 20, 0x18, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x00, 0x01, 0x02, 0x03,
+        0x04, 0x05, 0x06, 0x03, 0x50, 0x00, 0x08,
+    ];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadLeCentralClockAccuracy(0x08));
+}
+
+#[test]
+fn le_periodic_advertising_sync_transfer_received_failed() {
+    // The advertising train parameters are zeroed when synchronization fails.
+    let buffer = [
+        0x3E, 20, 0x18, 0x3E, 0x01, 0x02, 0x03, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePeriodicAdvertisingSyncTransferReceived(event)) => {
+            assert_eq!(event.status, hci::Status::ConnectionFailedToEstablish);
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(event.service_data, 0x0403);
+            assert_eq!(event.adv_phy, None);
+            assert_eq!(event.adv_clock_accuracy, None);
+        }
+        other => panic!(
+            "Did not get periodic advertising sync transfer received: {:?}",
+            other
+        ),
+    }
+}
+
+#[test]
+fn le_cis_established() {
+    let buffer = [
+        0x3E, 29, 0x19, 0x00, 0x01, 0x02, 0x10, 0x27, 0x00, 0x88, 0x13, 0x00, 0x20, 0x4E, 0x00,
+        0x30, 0x75, 0x00, 0x02, 0x01, 0x04, 0x01, 0x02, 0x01, 0x02, 0x28, 0x00, 0x3C, 0x00, 0x08,
+        0x00,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeCisEstablished(event)) => {
+            assert_eq!(event.status, hci::Status::Success);
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(event.cig_sync_delay, Duration::from_millis(10));
+            assert_eq!(event.cis_sync_delay, Duration::from_millis(5));
+            assert_eq!(event.transport_latency_c_to_p, Duration::from_millis(20));
+            assert_eq!(event.transport_latency_p_to_c, Duration::from_millis(30));
+            assert_eq!(event.phy_c_to_p, Some(Phy::Le2M));
+            assert_eq!(event.phy_p_to_c, Some(Phy::Le1M));
+            assert_eq!(event.nse, 4);
+            assert_eq!(event.bn_c_to_p, 1);
+            assert_eq!(event.bn_p_to_c, 2);
+            assert_eq!(event.ft_c_to_p, 1);
+            assert_eq!(event.ft_p_to_c, 2);
+            assert_eq!(event.max_pdu_c_to_p, 40);
+            assert_eq!(event.max_pdu_p_to_c, 60);
+            assert_eq!(event.iso_interval, Duration::from_millis(10));
+        }
+        other => panic!("Did not get CIS established: {:?}", other),
+    }
+}
+
+#[test]
+fn le_cis_established_failed() {
+    // The CIS parameters are zeroed when the CIS could not be established.
+    let buffer = [
+        0x3E, 29, 0x19, 0x3E, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeCisEstablished(event)) => {
+            assert_eq!(event.status, hci::Status::ConnectionFailedToEstablish);
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(event.phy_c_to_p, None);
+            assert_eq!(event.phy_p_to_c, None);
+        }
+        other => panic!("Did not get CIS established: {:?}", other),
+    }
+}
+
+#[test]
+fn le_cis_established_failed_bad_phy() {
+    let buffer = [
+        0x3E, 29, 0x19, 0x00, 0x01, 0x02, 0x10, 0x27, 0x00, 0x88, 0x13, 0x00, 0x20, 0x4E, 0x00,
+        0x30, 0x75, 0x00, 0x02, 0x04, 0x04, 0x01, 0x02, 0x01, 0x02, 0x28, 0x00, 0x3C, 0x00, 0x08,
+        0x00,
+    ];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadPhy(0x04));
+}
+
+#[test]
+fn le_cis_request() {
+    let buffer = [0x3E, 7, 0x1A, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeCisRequest(event)) => {
+            assert_eq!(event.acl_conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(event.cis_conn_handle, hci::ConnectionHandle(0x0403));
+            assert_eq!(event.cig_id, 0x05);
+            assert_eq!(event.cis_id, 0x06);
+        }
+        other => panic!("Did not get CIS request: {:?}", other),
+    }
+}
+
+#[test]
+fn le_set_cig_parameters_command_complete() {
+    let buffer = [
+        0x0E, 10, 1, 0x62, 0x20, 0x00, 0x01, 2, 0x01, 0x02, 0x03, 0x04,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => match event.return_params {
+            command::ReturnParameters::LeSetCigParameters(params) => {
+                assert_eq!(params.status, hci::Status::Success);
+                assert_eq!(params.cig_id, 0x01);
+                assert_eq!(
+                    params.conn_handles(),
+                    [hci::ConnectionHandle(0x0201), hci::ConnectionHandle(0x0403)]
+                );
+            }
+            other => panic!("Did not get LeSetCigParameters: {:?}", other),
+        },
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+}
+
+#[test]
+fn le_set_cig_parameters_command_complete_bad_length() {
+    let buffer = [0x0E, 9, 1, 0x62, 0x20, 0x00, 0x01, 2, 0x01, 0x02, 0x03];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadLength(6, 7));
+}
+
+#[test]
+fn le_create_big_complete() {
+    let buffer = [
+        0x3E, 23, 0x1B, 0x00, 0x01, 0x10, 0x27, 0x00, 0x20, 0x4E, 0x00, 0x02, 0x02, 0x01, 0x00,
+        0x02, 0x28, 0x00, 0x08, 0x00, 2, 0x01, 0x02, 0x03, 0x04,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeCreateBigComplete(event)) => {
+            assert_eq!(event.status, hci::Status::Success);
+            assert_eq!(event.big_handle, hci::BigHandle(0x01));
+            assert_eq!(event.big_sync_delay, Duration::from_millis(10));
+            assert_eq!(event.transport_latency_big, Duration::from_millis(20));
+            assert_eq!(event.phy, Some(Phy::Le2M));
+            assert_eq!(event.nse, 2);
+            assert_eq!(event.bn, 1);
+            assert_eq!(event.pto, 0);
+            assert_eq!(event.irc, 2);
+            assert_eq!(event.max_pdu, 40);
+            assert_eq!(event.iso_interval, Duration::from_millis(10));
+            assert_eq!(
+                event.bis_handles().collect::<Vec<_>>(),
+                [hci::ConnectionHandle(0x0201), hci::ConnectionHandle(0x0403)]
+            );
+        }
+        other => panic!("Did not get create BIG complete: {:?}", other),
+    }
+}
+
+#[test]
+fn le_create_big_complete_failure_status() {
+    // The BIG parameters are zeroed when the BIG could not be created.
+    let buffer = [
+        0x3E, 19, 0x1B, 0x07, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeCreateBigComplete(event)) => {
+            assert_eq!(event.status, hci::Status::OutOfMemory);
+            assert_eq!(event.big_handle, hci::BigHandle(0x01));
+            assert_eq!(event.phy, None);
+            assert_eq!(event.bis_handles().count(), 0);
+        }
+        other => panic!("Did not get create BIG complete: {:?}", other),
+    }
+}
+
+#[test]
+fn le_create_big_complete_failed_too_many_bis() {
+    let mut buffer = [0; 83];
+    buffer[..21].copy_from_slice(&[
+        0x3E, 81, 0x1B, 0x00, 0x01, 0x10, 0x27, 0x00, 0x20, 0x4E, 0x00, 0x02, 0x02, 0x01, 0x00,
+        0x02, 0x28, 0x00, 0x08, 0x00, 32,
+    ]);
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadNumBis(32));
+}
+
+#[test]
+fn le_terminate_big_complete() {
+    let buffer = [0x3E, 3, 0x1C, 0x01, 0x16];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeTerminateBigComplete(event)) => {
+            assert_eq!(event.big_handle, hci::BigHandle(0x01));
+            assert_eq!(event.reason, hci::Status::ConnectionTerminatedByHost);
+        }
+        other => panic!("Did not get terminate BIG complete: {:?}", other),
+    }
+}
+
+#[test]
+fn le_big_sync_established() {
+    let buffer = [
+        0x3E, 17, 0x1D, 0x00, 0x01, 0x20, 0x4E, 0x00, 0x02, 0x01, 0x00, 0x02, 0x28, 0x00, 0x08,
+        0x00, 1, 0x01, 0x02,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeBigSyncEstablished(event)) => {
+            assert_eq!(event.status, hci::Status::Success);
+            assert_eq!(event.big_handle, hci::BigHandle(0x01));
+            assert_eq!(event.transport_latency_big, Duration::from_millis(20));
+            assert_eq!(event.nse, 2);
+            assert_eq!(event.bn, 1);
+            assert_eq!(event.pto, 0);
+            assert_eq!(event.irc, 2);
+            assert_eq!(event.max_pdu, 40);
+            assert_eq!(event.iso_interval, Duration::from_millis(10));
+            assert_eq!(
+                event.bis_handles().collect::<Vec<_>>(),
+                [hci::ConnectionHandle(0x0201)]
+            );
+        }
+        other => panic!("Did not get BIG sync established: {:?}", other),
+    }
+}
+
+#[test]
+fn le_big_sync_established_failed_bad_length() {
+    let buffer = [
+        0x3E, 17, 0x1D, 0x00, 0x01, 0x20, 0x4E, 0x00, 0x02, 0x01, 0x00, 0x02, 0x28, 0x00, 0x08,
+        0x00, 2, 0x01, 0x02,
+    ];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadLength(17, 19));
+}
+
+#[test]
+fn le_big_sync_lost() {
+    let buffer = [0x3E, 3, 0x1E, 0x01, 0x08];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeBigSyncLost(event)) => {
+            assert_eq!(event.big_handle, hci::BigHandle(0x01));
+            assert_eq!(event.reason, hci::Status::ConnectionTimeout);
+        }
+        other => panic!("Did not get BIG sync lost: {:?}", other),
+    }
+}
+
+#[test]
+fn le_big_info_advertising_report() {
+    let buffer = [
+        0x3E, 20, 0x22, 0x01, 0x02, 2, 4, 0x08, 0x00, 1, 0, 2, 0x28, 0x00, 0x10, 0x27, 0x00, 0x28,
+        0x00, 0x02, 0x01, 0x01,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeBigInfoAdvertisingReport(event)) => {
+            assert_eq!(event.sync_handle, hci::SyncHandle(0x0201));
+            assert_eq!(event.num_bis, 2);
+            assert_eq!(event.nse, 4);
+            assert_eq!(event.iso_interval, Duration::from_millis(10));
+            assert_eq!(event.bn, 1);
+            assert_eq!(event.pto, 0);
+            assert_eq!(event.irc, 2);
+            assert_eq!(event.max_pdu, 40);
+            assert_eq!(event.sdu_interval, Duration::from_millis(10));
+            assert_eq!(event.max_sdu, 40);
+            assert_eq!(event.phy, Phy::Le2M);
+            assert_eq!(event.framing, hci::host::Framing::Framed);
+            assert!(event.encryption);
+        }
+        other => panic!("Did not get BIGInfo advertising report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_big_info_advertising_report_failed_bad_framing() {
+    let buffer = [
+        0x3E, 20, 0x22, 0x01, 0x02, 2, 4, 0x08, 0x00, 1, 0, 2, 0x28, 0x00, 0x10, 0x27, 0x00, 0x28,
+        0x00, 0x02, 0x02, 0x01,
+    ];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadFraming(0x02));
+}
+
+#[test]
+fn le_big_terminate_sync_command_complete() {
+    let buffer = [0x0E, 5, 1, 0x6C, 0x20, 0x00, 0x01];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => match event.return_params {
+            command::ReturnParameters::LeBigTerminateSync(params) => {
+                assert_eq!(params.status, hci::Status::Success);
+                assert_eq!(params.big_handle, hci::BigHandle(0x01));
+            }
+            other => panic!("Did not get LeBigTerminateSync: {:?}", other),
+        },
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+}
+
+#[test]
+fn le_path_loss_threshold() {
+    let buffer = [0x3E, 5, 0x20, 0x01, 0x02, 0xFF, 0x01];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LePathLossThreshold(event)) => {
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(event.current_path_loss, None);
+            assert_eq!(event.zone_entered, PathLossZone::Middle);
+        }
+        other => panic!("Did not get path loss threshold: {:?}", other),
+    }
+}
+
+#[test]
+fn le_path_loss_threshold_failed_bad_zone() {
+    let buffer = [0x3E, 5, 0x20, 0x01, 0x02, 0x40, 0x03];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadPathLossZone(0x03));
+}
+
+#[test]
+fn le_transmit_power_reporting() {
+    let buffer = [
+        0x3E, 9, 0x21, 0x00, 0x01, 0x02, 0x01, 0x03, 0xF6, 0x01, 0xFD,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeTransmitPowerReporting(event)) => {
+            assert_eq!(event.status, hci::Status::Success);
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(event.reason, TransmitPowerReportingReason::RemoteChange);
+            assert_eq!(event.phy, PowerControlPhy::LeCodedS8);
+            assert_eq!(event.tx_power_level, Some(hci::Dbm(-10)));
+            assert_eq!(event.tx_power_level_flags, TxPowerLevelFlags::AT_MINIMUM);
+            assert_eq!(event.delta, Some(-3));
+        }
+        other => panic!("Did not get transmit power reporting: {:?}", other),
+    }
+}
+
+#[test]
+fn le_transmit_power_reporting_not_available() {
+    let buffer = [
+        0x3E, 9, 0x21, 0x00, 0x01, 0x02, 0x02, 0x01, 0x7F, 0x00, 0x7F,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeTransmitPowerReporting(event)) => {
+            assert_eq!(
+                event.reason,
+                TransmitPowerReportingReason::ReadRemoteCompleted
+            );
+            assert_eq!(event.tx_power_level, None);
+            assert_eq!(event.delta, None);
+        }
+        other => panic!("Did not get transmit power reporting: {:?}", other),
+    }
+}
+
+#[test]
+fn le_transmit_power_reporting_failed_bad_phy() {
+    let buffer = [
+        0x3E, 9, 0x21, 0x00, 0x01, 0x02, 0x01, 0x05, 0xF6, 0x01, 0xFD,
+    ];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadPowerControlPhy(0x05));
+}
+
+#[test]
+fn le_transmit_power_reporting_failed_bad_flags() {
+    let buffer = [
+        0x3E, 9, 0x21, 0x00, 0x01, 0x02, 0x01, 0x03, 0xF6, 0x04, 0xFD,
+    ];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadTxPowerLevelFlags(0x04));
+}
+
+#[test]
+fn le_enhanced_read_transmit_power_level_command_complete() {
+    let buffer = [0x0E, 9, 1, 0x76, 0x20, 0x00, 0x01, 0x02, 0x02, 0x04, 0x14];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => match event.return_params {
+            command::ReturnParameters::LeEnhancedReadTransmitPowerLevel(params) => {
+                assert_eq!(params.status, hci::Status::Success);
+                assert_eq!(params.conn_handle, hci::ConnectionHandle(0x0201));
+                assert_eq!(params.phy, PowerControlPhy::Le2M);
+                assert_eq!(params.current_tx_power_level, Some(hci::Dbm(4)));
+                assert_eq!(params.max_tx_power_level, hci::Dbm(20));
+            }
+            other => panic!("Did not get LeEnhancedReadTransmitPowerLevel: {:?}", other),
+        },
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+}
+
+#[test]
+fn le_subrate_change() {
+    let buffer = [
+        0x3E, 12, 0x23, 0x00, 0x01, 0x02, 0x04, 0x00, 0x0A, 0x00, 0x01, 0x00, 0xF4, 0x01,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeSubrateChange(event)) => {
+            assert_eq!(event.status, hci::Status::Success);
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(event.subrate_factor, 4);
+            assert_eq!(event.peripheral_latency, 10);
+            assert_eq!(event.continuation_number, 1);
+            assert_eq!(event.supervision_timeout, Duration::from_secs(5));
+        }
+        other => panic!("Did not get subrate change: {:?}", other),
+    }
+}
+
+#[test]
+fn le_set_default_subrate_command_complete() {
+    let buffer = [0x0E, 4, 1, 0x7D, 0x20, 0x00];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => match event.return_params {
+            command::ReturnParameters::LeSetDefaultSubrate(status) => {
+                assert_eq!(status, hci::Status::Success);
+            }
+            other => panic!("Did not get LeSetDefaultSubrate: {:?}", other),
+        },
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+}
+
+#[test]
+fn le_connectionless_iq_report() {
+    let buffer = [
+        0x3E, 31, 0x15, 0x01, 0x02, 0x05, 0xA8, 0xFD, 0x01, 0x00, 0x01, 0x00, 0x34, 0x12, 9, 0x10,
+        0xF0, 0x20, 0xE0, 0x7F, 0x80, 0x00, 0x00, 0x01, 0xFF, 0x02, 0xFE, 0x03, 0xFD, 0x04, 0xFC,
+        0x05, 0xFB,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeConnectionlessIqReport(event)) => {
+            assert_eq!(event.sync_handle, hci::SyncHandle(0x0201));
+            assert_eq!(event.channel_index, Some(5));
+            assert_eq!(event.rssi, -600);
+            assert_eq!(event.rssi_antenna_id, 1);
+            assert_eq!(event.cte_type, Some(CteType::AoA));
+            assert_eq!(event.slot_duration, Some(SlotDuration::Slot1us));
+            assert_eq!(event.packet_status, IqPacketStatus::CrcCorrect);
+            assert_eq!(event.periodic_event_counter, 0x1234);
+            let samples: Vec<_> = event.iq_samples().map(|s| (s.i, s.q)).collect();
+            assert_eq!(
+                samples,
+                [
+                    (16, -16),
+                    (32, -32),
+                    (127, -128),
+                    (0, 0),
+                    (1, -1),
+                    (2, -2),
+                    (3, -3),
+                    (4, -4),
+                    (5, -5)
+                ]
+            );
+        }
+        other => panic!("Did not get connectionless IQ report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_connectionless_iq_report_insufficient_resources() {
+    // The channel index, CTE type and slot durations are not valid without IQ samples.
+    let buffer = [
+        0x3E, 13, 0x15, 0x01, 0x02, 0xFF, 0xA8, 0xFD, 0x01, 0xFF, 0xFF, 0xFF, 0x34, 0x12, 0,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeConnectionlessIqReport(event)) => {
+            assert_eq!(event.channel_index, None);
+            assert_eq!(event.cte_type, None);
+            assert_eq!(event.slot_duration, None);
+            assert_eq!(event.packet_status, IqPacketStatus::InsufficientResources);
+            assert_eq!(event.periodic_event_counter, 0x1234);
+            assert_eq!(event.iq_samples().count(), 0);
+        }
+        other => panic!("Did not get connectionless IQ report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_connectionless_iq_report_failed_bad_sample_count() {
+    let buffer = [
+        0x3E, 21, 0x15, 0x01, 0x02, 0x05, 0xA8, 0xFD, 0x01, 0x00, 0x01, 0x00, 0x34, 0x12, 4, 0x10,
+        0xF0, 0x20, 0xE0, 0x7F, 0x80, 0x00, 0x00,
+    ];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadIqSampleCount(4));
+}
+
+#[test]
+fn le_connectionless_iq_report_failed_bad_slot_duration() {
+    let buffer = [
+        0x3E, 13, 0x15, 0x01, 0x02, 0x05, 0xA8, 0xFD, 0x01, 0x00, 0x03, 0x00, 0x34, 0x12, 0,
+    ];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadSlotDuration(0x03));
+}
+
+#[test]
+fn le_connection_iq_report() {
+    let buffer = [
+        0x3E, 32, 0x16, 0x01, 0x02, 0x02, 0x10, 0xA8, 0xFD, 0x02, 0x01, 0x02, 0x01, 0x34, 0x12, 9,
+        0x10, 0xF0, 0x20, 0xE0, 0x7F, 0x80, 0x00, 0x00, 0x01, 0xFF, 0x02, 0xFE, 0x03, 0xFD, 0x04,
+        0xFC, 0x05, 0xFB,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeConnectionIqReport(event)) => {
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(event.rx_phy, Phy::Le2M);
+            assert_eq!(event.data_channel_index, Some(0x10));
+            assert_eq!(event.rssi, -600);
+            assert_eq!(event.rssi_antenna_id, 2);
+            assert_eq!(event.cte_type, Some(CteType::AoD1us));
+            assert_eq!(event.slot_duration, Some(SlotDuration::Slot2us));
+            assert_eq!(event.packet_status, IqPacketStatus::CrcIncorrectUsedLength);
+            assert_eq!(event.connection_event_counter, 0x1234);
+            assert_eq!(event.iq_samples().count(), 9);
+            assert_eq!(
+                event.iq_samples().nth(2),
+                Some(IqSample { i: 127, q: -128 })
+            );
+        }
+        other => panic!("Did not get connection IQ report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_connection_iq_report_insufficient_resources() {
+    // The channel index, CTE type and slot durations are not valid without IQ samples.
+    let buffer = [
+        0x3E, 14, 0x16, 0x01, 0x02, 0x02, 0xFF, 0xA8, 0xFD, 0x02, 0xFF, 0xFF, 0xFF, 0x34, 0x12, 0,
+    ];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeConnectionIqReport(event)) => {
+            assert_eq!(event.rx_phy, Phy::Le2M);
+            assert_eq!(event.data_channel_index, None);
+            assert_eq!(event.cte_type, None);
+            assert_eq!(event.slot_duration, None);
+            assert_eq!(event.packet_status, IqPacketStatus::InsufficientResources);
+            assert_eq!(event.iq_samples().count(), 0);
+        }
+        other => panic!("Did not get connection IQ report: {:?}", other),
+    }
+}
+
+#[test]
+fn le_connection_iq_report_failed_bad_packet_status() {
+    let buffer = [
+        0x3E, 14, 0x16, 0x01, 0x02, 0x02, 0x10, 0xA8, 0xFD, 0x02, 0x01, 0x02, 0x03, 0x34, 0x12, 0,
+    ];
+    let err = TestEvent::new(Packet(&buffer)).err().unwrap();
+    assert_eq!(err, Error::BadIqPacketStatus(0x03));
+}
+
+#[test]
+fn le_cte_request_failed() {
+    let buffer = [0x3E, 4, 0x17, 0x00, 0x01, 0x02];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::LeCteRequestFailed(event)) => {
+            assert_eq!(event.status, hci::Status::Success);
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+        }
+        other => panic!("Did not get CTE request failed: {:?}", other),
+    }
+}
+
+#[test]
+fn le_read_antenna_information_command_complete() {
+    let buffer = [0x0E, 8, 1, 0x58, 0x20, 0x00, 0x05, 4, 0x10, 0x14];
+    match TestEvent::new(Packet(&buffer)) {
+        Ok(Event::CommandComplete(event)) => match event.return_params {
+            command::ReturnParameters::LeReadAntennaInformation(params) => {
+                assert_eq!(params.status, hci::Status::Success);
+                assert_eq!(
+                    params.supported_switching_sampling_rates,
+                    command::SwitchingSamplingRates::AOD_TX_SWITCHING_1US
+                        | command::SwitchingSamplingRates::AOA_RX_SWITCHING_SAMPLING_1US
+                );
+                assert_eq!(params.num_antennae, 4);
+                assert_eq!(params.max_switching_pattern_length, 0x10);
+                assert_eq!(params.max_cte_length, Duration::from_micros(160));
+            }
+            other => panic!("Did not get LeReadAntennaInformation: {:?}", other),
+        },
+        other => panic!("Did not get command complete event: {:?}", other),
+    }
+}
@@ -0,0 +1,127 @@
+extern crate stm32wb_hci as hci;
+
+use hci::host::uart::{Error, H4Decoder, Packet};
+
+const CONNECTION_COMPLETE: [u8; 14] = [
+    0x04, 0x03, 11, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x00, 0x00,
+];
+const ACL_DATA: [u8; 7] = [0x02, 0x01, 0x22, 2, 0, 0x0A, 0x0B];
+
+fn assert_connection_complete(packet: Option<Result<Packet, Error>>) {
+    match packet {
+        Some(Ok(Packet::Event(hci::Event::ConnectionComplete(event)))) => {
+            assert_eq!(event.conn_handle, hci::ConnectionHandle(0x0201));
+        }
+        other => panic!("Did not get connection complete event: {other:?}"),
+    }
+}
+
+fn assert_acl_data(packet: Option<Result<Packet, Error>>) {
+    match packet {
+        Some(Ok(Packet::AclData(acl))) => {
+            assert_eq!(acl.header.conn_handle, hci::ConnectionHandle(0x0201));
+            assert_eq!(acl.data(), [0x0A, 0x0B]);
+        }
+        other => panic!("Did not get ACL data: {other:?}"),
+    }
+}
+
+#[test]
+fn single_packet() {
+    let mut decoder = H4Decoder::new();
+    let (consumed, packet) = decoder.push(&CONNECTION_COMPLETE);
+    assert_eq!(consumed, CONNECTION_COMPLETE.len());
+    assert_connection_complete(packet);
+    assert!(decoder.is_idle());
+}
+
+#[test]
+fn byte_at_a_time() {
+    let mut decoder = H4Decoder::new();
+    let (last, first) = CONNECTION_COMPLETE.split_last().unwrap();
+    for byte in first {
+        assert!(matches!(decoder.push(&[*byte]), (1, None)));
+        assert!(!decoder.is_idle());
+    }
+
+    let (consumed, packet) = decoder.push(&[*last]);
+    assert_eq!(consumed, 1);
+    assert_connection_complete(packet);
+}
+
+#[test]
+fn packets_split_across_chunks() {
+    let mut stream = Vec::new();
+    stream.extend_from_slice(&CONNECTION_COMPLETE);
+    stream.extend_from_slice(&ACL_DATA);
+
+    let mut decoder = H4Decoder::new();
+    let (chunk0, chunk1) = stream.split_at(10);
+    assert_eq!(decoder.decode(chunk0).count(), 0);
+
+    let mut packets = decoder.decode(chunk1);
+    assert_connection_complete(packets.next());
+    assert_acl_data(packets.next());
+    assert!(packets.next().is_none());
+}
+
+#[test]
+fn resync_after_bad_packet_type() {
+    let mut stream = vec![0xFF, 0x00];
+    stream.extend_from_slice(&ACL_DATA);
+
+    let mut decoder = H4Decoder::new();
+    let mut packets = decoder.decode(&stream);
+    assert_eq!(
+        packets.next().unwrap().err(),
+        Some(Error::BadPacketType(0xFF))
+    );
+    assert_eq!(
+        packets.next().unwrap().err(),
+        Some(Error::BadPacketType(0x00))
+    );
+    assert_acl_data(packets.next());
+    assert!(packets.next().is_none());
+}
+
+#[test]
+fn skip_packet_too_long() {
+    let mut stream = vec![0x02, 0x01, 0x22, 0x00, 0x01];
+    stream.extend_from_slice(&[0xFF; 0x100]);
+    stream.extend_from_slice(&ACL_DATA);
+
+    let mut decoder = H4Decoder::new();
+    let mut packets = decoder.decode(&stream);
+    assert_eq!(
+        packets.next().unwrap().err(),
+        Some(Error::DataTooLong(0x100))
+    );
+    assert_acl_data(packets.next());
+    assert!(packets.next().is_none());
+}
+
+#[test]
+fn resync_after_bad_packet() {
+    let mut stream = vec![0x05, 0x01, 0x62, 2, 0, 0x01, 0x02];
+    stream.extend_from_slice(&ACL_DATA);
+
+    let mut decoder = H4Decoder::new();
+    let mut packets = decoder.decode(&stream);
+    assert_eq!(packets.next().unwrap().err(), Some(Error::DataTooShort(2)));
+    assert_acl_data(packets.next());
+    assert!(packets.next().is_none());
+}
+
+#[test]
+fn reset_drops_truncated_packet() {
+    let mut decoder = H4Decoder::new();
+    assert!(decoder.decode(&CONNECTION_COMPLETE[..6]).next().is_none());
+    assert!(!decoder.is_idle());
+
+    decoder.reset();
+    assert!(decoder.is_idle());
+
+    let mut packets = decoder.decode(&ACL_DATA);
+    assert_acl_data(packets.next());
+    assert!(packets.next().is_none());
+}
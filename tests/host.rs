@@ -2,7 +2,11 @@ extern crate stm32wb_hci as hci;
 
 mod vendor;
 
+use hci::host::uart::UartHci;
 use hci::host::*;
+use hci::types::extended_advertisement::{
+    AdvSet, AdvertisingEvent, AdvertisingOperation, AdvertisingPhy, ExtendedAdvertisingInterval,
+};
 use std::time::Duration;
 use vendor::RecordingSink;
 
@@ -55,6 +59,7 @@ conn_handle_only! {
     le_read_channel_map(0x15, 0x20);
     le_read_remote_used_features(0x16, 0x20);
     le_long_term_key_request_negative_reply(0x1B, 0x20);
+    le_read_phy(0x30, 0x20);
 }
 
 macro_rules! no_params {
@@ -91,6 +96,14 @@ no_params! {
     le_rand(0x18, 0x20);
     le_read_supported_states(0x1C, 0x20);
     le_test_end(0x1F, 0x20);
+    le_read_suggested_default_data_length(0x23, 0x20);
+    le_read_local_p256_public_key(0x25, 0x20);
+    le_read_maximum_advertising_data_length(0x3A, 0x20);
+    le_read_number_of_supported_advertising_sets(0x3B, 0x20);
+    le_clear_advertising_sets(0x3D, 0x20);
+    le_clear_resolving_list(0x29, 0x20);
+    le_read_resolving_list_size(0x2A, 0x20);
+    le_read_maximum_data_length(0x2F, 0x20);
 }
 
 #[tokio::test]
@@ -112,6 +125,47 @@ async fn read_tx_power_level() {
     assert_eq!(sink.written_data, [1, 0x2D, 0x0C, 3, 0x01, 0x02, 0x00])
 }
 
+#[tokio::test]
+async fn host_buffer_size() {
+    let mut sink = RecordingSink::new();
+    sink.host_buffer_size(HostBufferSize {
+        acl_data_packet_length: 0x0201,
+        sync_data_packet_length: 0x03,
+        total_acl_data_packets: 0x0504,
+        total_sync_data_packets: 0x0706,
+    })
+    .await;
+    assert_eq!(
+        sink.written_data,
+        [1, 0x33, 0x0C, 7, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07]
+    );
+}
+
+#[tokio::test]
+async fn number_of_completed_packets() {
+    let mut sink = RecordingSink::new();
+    sink.number_of_completed_packets(hci::event::NumberOfCompletedPackets::new(
+        [
+            hci::event::NumberOfCompletedPacketsPair {
+                conn_handle: hci::ConnectionHandle(0x0201),
+                num_completed_packets: 3,
+            },
+            hci::event::NumberOfCompletedPacketsPair {
+                conn_handle: hci::ConnectionHandle(0x0605),
+                num_completed_packets: 0x0807,
+            },
+        ]
+        .into_iter(),
+    ))
+    .await;
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x35, 0x0C, 9, 2, 0x01, 0x02, 0x03, 0x00, 0x05, 0x06, 0x07, 0x08
+        ]
+    );
+}
+
 #[tokio::test]
 async fn le_set_event_mask() {
     let mut sink = RecordingSink::new();
@@ -656,3 +710,2224 @@ async fn le_transmitter_test_length_out_of_range() {
     assert_eq!(err, Error::InvalidTestPayloadLength(0x26));
     assert_eq!(sink.written_data, []);
 }
+
+#[tokio::test]
+async fn le_set_data_length() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_data_length(hci::ConnectionHandle(0x0201), 0x00FB, 0x0848)
+        .await
+        .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x22, 0x20, 6, 0x01, 0x02, 0xFB, 0x00, 0x48, 0x08]
+    );
+}
+
+#[tokio::test]
+async fn le_set_data_length_octets_out_of_range() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_data_length(hci::ConnectionHandle(0x0201), 0x001A, 0x0148)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::InvalidDataLengthOctets(0x001A));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_data_length_time_out_of_range() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_data_length(hci::ConnectionHandle(0x0201), 0x001B, 0x4291)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::InvalidDataLengthTime(0x4291));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_write_suggested_default_data_length() {
+    let mut sink = RecordingSink::new();
+    sink.le_write_suggested_default_data_length(0x001B, 0x0148)
+        .await
+        .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x24, 0x20, 4, 0x1B, 0x00, 0x48, 0x01]
+    );
+}
+
+#[tokio::test]
+async fn le_write_suggested_default_data_length_out_of_range() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_write_suggested_default_data_length(0x00FC, 0x0148)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::InvalidDataLengthOctets(0x00FC));
+    assert_eq!(sink.written_data, []);
+}
+
+fn remote_key() -> P256PublicKey {
+    let mut key = [0; 64];
+    for (i, byte) in key.iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+    P256PublicKey(key)
+}
+
+#[tokio::test]
+async fn le_generate_dhkey() {
+    let mut sink = RecordingSink::new();
+    sink.le_generate_dhkey(&remote_key()).await;
+    assert_eq!(sink.written_data[..4], [1, 0x26, 0x20, 64]);
+    assert_eq!(sink.written_data[4..], remote_key().0);
+}
+
+#[tokio::test]
+async fn le_generate_dhkey_v2() {
+    let mut sink = RecordingSink::new();
+    sink.le_generate_dhkey_v2(&remote_key(), PrivateKeyType::Debug)
+        .await;
+    assert_eq!(sink.written_data[..4], [1, 0x5E, 0x20, 65]);
+    assert_eq!(sink.written_data[4..68], remote_key().0);
+    assert_eq!(sink.written_data[68], 0x01);
+}
+
+#[tokio::test]
+async fn le_add_device_to_resolving_list() {
+    let mut sink = RecordingSink::new();
+    sink.le_add_device_to_resolving_list(&ResolvingListParameters {
+        peer_identity_address: hci::BdAddrType::Random(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        peer_irk: EncryptionKey([
+            0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D,
+            0x1E, 0x1F,
+        ]),
+        local_irk: EncryptionKey([
+            0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2A, 0x2B, 0x2C, 0x2D,
+            0x2E, 0x2F,
+        ]),
+    })
+    .await;
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x27, 0x20, 39, 0x01, 1, 2, 3, 4, 5, 6, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16,
+            0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F, 0x20, 0x21, 0x22, 0x23, 0x24,
+            0x25, 0x26, 0x27, 0x28, 0x29, 0x2A, 0x2B, 0x2C, 0x2D, 0x2E, 0x2F
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_remove_device_from_resolving_list() {
+    let mut sink = RecordingSink::new();
+    sink.le_remove_device_from_resolving_list(hci::BdAddrType::Public(hci::BdAddr([
+        1, 2, 3, 4, 5, 6,
+    ])))
+    .await;
+    assert_eq!(
+        sink.written_data,
+        [1, 0x28, 0x20, 7, 0x00, 1, 2, 3, 4, 5, 6]
+    );
+}
+
+#[tokio::test]
+async fn le_read_peer_resolvable_address() {
+    let mut sink = RecordingSink::new();
+    sink.le_read_peer_resolvable_address(hci::BdAddrType::Random(hci::BdAddr([1, 2, 3, 4, 5, 6])))
+        .await;
+    assert_eq!(
+        sink.written_data,
+        [1, 0x2B, 0x20, 7, 0x01, 1, 2, 3, 4, 5, 6]
+    );
+}
+
+#[tokio::test]
+async fn le_read_local_resolvable_address() {
+    let mut sink = RecordingSink::new();
+    sink.le_read_local_resolvable_address(hci::BdAddrType::Public(hci::BdAddr([1, 2, 3, 4, 5, 6])))
+        .await;
+    assert_eq!(
+        sink.written_data,
+        [1, 0x2C, 0x20, 7, 0x00, 1, 2, 3, 4, 5, 6]
+    );
+}
+
+#[tokio::test]
+async fn le_set_address_resolution_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_address_resolution_enable(true).await;
+    assert_eq!(sink.written_data, [1, 0x2D, 0x20, 1, 0x01]);
+}
+
+#[tokio::test]
+async fn le_set_resolvable_private_address_timeout() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_resolvable_private_address_timeout(Duration::from_secs(900))
+        .await
+        .unwrap();
+    assert_eq!(sink.written_data, [1, 0x2E, 0x20, 2, 0x84, 0x03]);
+}
+
+#[tokio::test]
+async fn le_set_resolvable_private_address_timeout_too_short() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_resolvable_private_address_timeout(Duration::from_millis(999))
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadRpaTimeout(Duration::from_millis(999)));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_resolvable_private_address_timeout_too_long() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_resolvable_private_address_timeout(Duration::from_secs(3601))
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadRpaTimeout(Duration::from_secs(3601)));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_privacy_mode() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_privacy_mode(
+        hci::BdAddrType::Random(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        PrivacyMode::Device,
+    )
+    .await;
+    assert_eq!(
+        sink.written_data,
+        [1, 0x4E, 0x20, 8, 0x01, 1, 2, 3, 4, 5, 6, 0x01]
+    );
+}
+
+#[tokio::test]
+async fn le_set_default_phy() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_default_phy(&PhyPreferences {
+        all_phys: AllPhys::NO_RX_PREFERENCE,
+        tx_phys: PhyMask::LE_1M | PhyMask::LE_2M,
+        rx_phys: PhyMask::empty(),
+    })
+    .await
+    .unwrap();
+    assert_eq!(sink.written_data, [1, 0x31, 0x20, 3, 0x02, 0x03, 0x00]);
+}
+
+#[tokio::test]
+async fn le_set_default_phy_no_tx_phys() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_default_phy(&PhyPreferences {
+            all_phys: AllPhys::NO_RX_PREFERENCE,
+            tx_phys: PhyMask::empty(),
+            rx_phys: PhyMask::empty(),
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::NoTxPhys);
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_phy() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_phy(
+        hci::ConnectionHandle(0x0201),
+        &PhyPreferences {
+            all_phys: AllPhys::empty(),
+            tx_phys: PhyMask::LE_CODED,
+            rx_phys: PhyMask::LE_1M | PhyMask::LE_CODED,
+        },
+        PhyOptions::S8,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x32, 0x20, 7, 0x01, 0x02, 0x00, 0x04, 0x05, 0x02, 0x00]
+    );
+}
+
+#[tokio::test]
+async fn le_set_phy_no_rx_phys() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_phy(
+            hci::ConnectionHandle(0x0201),
+            &PhyPreferences {
+                all_phys: AllPhys::empty(),
+                tx_phys: PhyMask::LE_2M,
+                rx_phys: PhyMask::empty(),
+            },
+            PhyOptions::NoPreference,
+        )
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::NoRxPhys);
+    assert_eq!(sink.written_data, []);
+}
+
+fn extended_advertising_parameters() -> ExtendedAdvertisingParameters {
+    ExtendedAdvertisingParameters {
+        adv_handle: hci::AdvertisingHandle(0x01),
+        adv_event_properties: AdvertisingEvent::CONNECTABLE | AdvertisingEvent::SCANNABLE,
+        adv_interval: ExtendedAdvertisingInterval::with_range(
+            Duration::from_millis(100),
+            Duration::from_millis(200),
+        )
+        .unwrap(),
+        primary_adv_channel_map: Channels::CH_37 | Channels::CH_39,
+        own_address_type: OwnAddressType::Random,
+        peer_address: hci::BdAddrType::Public(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        adv_filter_policy: AdvertisingFilterPolicy::AllowConnectionWhiteListScan,
+        adv_tx_power: Some(hci::Dbm(-4)),
+        primary_adv_phy: AdvertisingPhy::LeCoded,
+        secondary_adv_max_skip: 2,
+        secondary_adv_phy: AdvertisingPhy::Le2M,
+        adv_sid: 0x0A,
+        scan_request_notification_enable: true,
+    }
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_advertising_parameters(&extended_advertising_parameters())
+        .await
+        .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x36, 0x20, 25, 0x01, 0x03, 0x00, 0xA0, 0x00, 0x00, 0x40, 0x01, 0x00, 0x05, 0x01,
+            0x00, 1, 2, 3, 4, 5, 6, 0x01, 0xFC, 0x03, 2, 0x02, 0x0A, 0x01
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_parameters_no_tx_power_preference() {
+    let mut sink = RecordingSink::new();
+    let mut params = extended_advertising_parameters();
+    params.adv_tx_power = None;
+    sink.le_set_extended_advertising_parameters(&params)
+        .await
+        .unwrap();
+    assert_eq!(sink.written_data[23], 0x7F);
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_parameters_bad_primary_phy() {
+    let mut sink = RecordingSink::new();
+    let mut params = extended_advertising_parameters();
+    params.primary_adv_phy = AdvertisingPhy::Le2M;
+    let err = sink
+        .le_set_extended_advertising_parameters(&params)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadPrimaryAdvertisingPhy(AdvertisingPhy::Le2M));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_parameters_bad_channel_map() {
+    let mut sink = RecordingSink::new();
+    let mut params = extended_advertising_parameters();
+    params.primary_adv_channel_map = Channels::empty();
+    let err = sink
+        .le_set_extended_advertising_parameters(&params)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadChannelMap(Channels::empty()));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_data() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_advertising_data(&ExtendedAdvertisingData {
+        adv_handle: hci::AdvertisingHandle(0x01),
+        operation: AdvertisingOperation::FirstFragment,
+        fragment: false,
+        data: &[0x02, 0x01, 0x06],
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x37, 0x20, 7, 0x01, 0x01, 0x01, 3, 0x02, 0x01, 0x06]
+    );
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_data_too_long() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_extended_advertising_data(&ExtendedAdvertisingData {
+            adv_handle: hci::AdvertisingHandle(0x01),
+            operation: AdvertisingOperation::CompleteData,
+            fragment: true,
+            data: &[0; 252],
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::ExtendedAdvertisingDataTooLong(252));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_response_data() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_scan_response_data(&ExtendedAdvertisingData {
+        adv_handle: hci::AdvertisingHandle(0x02),
+        operation: AdvertisingOperation::CompleteData,
+        fragment: true,
+        data: &[0x01, 0x02],
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x38, 0x20, 6, 0x02, 0x03, 0x00, 2, 0x01, 0x02]
+    );
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_advertising_enable(
+        true,
+        &[
+            AdvSet {
+                handle: hci::AdvertisingHandle(0x01),
+                duration: 0x0201,
+                max_extended_adv_events: 0,
+            },
+            AdvSet {
+                handle: hci::AdvertisingHandle(0x02),
+                duration: 0,
+                max_extended_adv_events: 5,
+            },
+        ],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x39, 0x20, 10, 0x01, 2, 0x01, 0x01, 0x02, 0x00, 0x02, 0x00, 0x00, 0x05
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_disable_all() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_advertising_enable(false, &[])
+        .await
+        .unwrap();
+    assert_eq!(sink.written_data, [1, 0x39, 0x20, 2, 0x00, 0]);
+}
+
+#[tokio::test]
+async fn le_set_extended_advertising_enable_too_many_sets() {
+    let mut sink = RecordingSink::new();
+    let sets = [AdvSet {
+        handle: hci::AdvertisingHandle(0x01),
+        duration: 0,
+        max_extended_adv_events: 0,
+    }; 64];
+    let err = sink
+        .le_set_extended_advertising_enable(true, &sets)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::TooManyAdvertisingSets(64));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_remove_advertising_set() {
+    let mut sink = RecordingSink::new();
+    sink.le_remove_advertising_set(hci::AdvertisingHandle(0x03))
+        .await;
+    assert_eq!(sink.written_data, [1, 0x3C, 0x20, 1, 0x03]);
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_scan_parameters(&ExtendedScanParameters {
+        own_address_type: OwnAddressType::Public,
+        filter_policy: ScanFilterPolicy::AcceptAll,
+        le_1m_phy: Some(PhyScanParameters {
+            scan_type: ScanType::Active,
+            scan_window: ScanWindow::start_every(Duration::from_millis(21))
+                .and_then(|b| b.open_for(Duration::from_millis(10)))
+                .unwrap(),
+        }),
+        le_coded_phy: Some(PhyScanParameters {
+            scan_type: ScanType::Passive,
+            scan_window: ScanWindow::start_every(Duration::from_millis(100))
+                .and_then(|b| b.open_for(Duration::from_millis(50)))
+                .unwrap(),
+        }),
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x41, 0x20, 13, 0x00, 0x00, 0x05, 0x01, 0x21, 0x00, 0x10, 0x00, 0x00, 0xA0, 0x00,
+            0x50, 0x00
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_parameters_coded_only() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_scan_parameters(&ExtendedScanParameters {
+        own_address_type: OwnAddressType::Random,
+        filter_policy: ScanFilterPolicy::WhiteList,
+        le_1m_phy: None,
+        le_coded_phy: Some(PhyScanParameters {
+            scan_type: ScanType::Passive,
+            scan_window: ScanWindow::start_every(Duration::from_millis(100))
+                .and_then(|b| b.open_for(Duration::from_millis(50)))
+                .unwrap(),
+        }),
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x41, 0x20, 8, 0x01, 0x01, 0x04, 0x00, 0xA0, 0x00, 0x50, 0x00
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_parameters_no_phys() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_extended_scan_parameters(&ExtendedScanParameters {
+            own_address_type: OwnAddressType::Public,
+            filter_policy: ScanFilterPolicy::AcceptAll,
+            le_1m_phy: None,
+            le_coded_phy: None,
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::NoScanningPhys);
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_extended_scan_enable(&ExtendedScanEnable {
+        enable: true,
+        filter_duplicates: FilterDuplicates::ResetEachPeriod,
+        duration: Duration::from_millis(500),
+        period: Duration::from_millis(2560),
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x42, 0x20, 6, 1, 2, 0x32, 0x00, 0x02, 0x00]
+    );
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_enable_bad_duration() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_extended_scan_enable(&ExtendedScanEnable {
+            enable: true,
+            filter_duplicates: FilterDuplicates::Disabled,
+            duration: Duration::from_millis(5),
+            period: Duration::from_secs(0),
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadScanDuration(Duration::from_millis(5)));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_enable_period_too_long() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_extended_scan_enable(&ExtendedScanEnable {
+            enable: true,
+            filter_duplicates: FilterDuplicates::Disabled,
+            duration: Duration::from_secs(0),
+            period: Duration::from_secs(84_000),
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadScanPeriod(Duration::from_secs(84_000)));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_extended_scan_enable_period_not_longer_than_duration() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_extended_scan_enable(&ExtendedScanEnable {
+            enable: true,
+            filter_duplicates: FilterDuplicates::Enabled,
+            duration: Duration::from_millis(1280),
+            period: Duration::from_millis(1280),
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadScanPeriod(Duration::from_millis(1280)));
+    assert_eq!(sink.written_data, []);
+}
+
+fn phy_connection_parameters() -> PhyConnectionParameters {
+    PhyConnectionParameters {
+        scan_window: ScanWindow::start_every(Duration::from_millis(50))
+            .and_then(|b| b.open_for(Duration::from_millis(25)))
+            .unwrap(),
+        conn_interval: ConnectionIntervalBuilder::new()
+            .with_range(Duration::from_millis(50), Duration::from_millis(500))
+            .with_latency(10)
+            .with_supervision_timeout(Duration::from_secs(15))
+            .build()
+            .unwrap(),
+        expected_connection_length: ExpectedConnectionLength::new(
+            Duration::from_millis(200),
+            Duration::from_millis(500),
+        )
+        .unwrap(),
+    }
+}
+
+#[tokio::test]
+async fn le_extended_create_connection() {
+    let mut sink = RecordingSink::new();
+    sink.le_extended_create_connection(&ExtendedConnectionParameters {
+        initiator_filter_policy: ConnectionFilterPolicy::UseAddress,
+        own_address_type: OwnAddressType::Public,
+        peer_address: PeerAddrType::RandomDeviceAddress(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        le_1m_phy: Some(phy_connection_parameters()),
+        le_2m_phy: None,
+        le_coded_phy: Some(phy_connection_parameters()),
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        vec![
+            1, 0x43, 0x20, 42, 0x00, 0x00, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x05, 0x50,
+            0x00, 0x28, 0x00, 0x28, 0x00, 0x90, 0x01, 0x0A, 0x00, 0xDC, 0x05, 0x40, 0x01, 0x20,
+            0x03, 0x50, 0x00, 0x28, 0x00, 0x28, 0x00, 0x90, 0x01, 0x0A, 0x00, 0xDC, 0x05, 0x40,
+            0x01, 0x20, 0x03,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_extended_create_connection_use_whitelist() {
+    let mut sink = RecordingSink::new();
+    sink.le_extended_create_connection(&ExtendedConnectionParameters {
+        initiator_filter_policy: ConnectionFilterPolicy::WhiteList,
+        own_address_type: OwnAddressType::Random,
+        peer_address: PeerAddrType::PublicDeviceAddress(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        le_1m_phy: None,
+        le_2m_phy: Some(phy_connection_parameters()),
+        le_coded_phy: None,
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        vec![
+            1, 0x43, 0x20, 26, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x50,
+            0x00, 0x28, 0x00, 0x28, 0x00, 0x90, 0x01, 0x0A, 0x00, 0xDC, 0x05, 0x40, 0x01, 0x20,
+            0x03,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_extended_create_connection_no_phys() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_extended_create_connection(&ExtendedConnectionParameters {
+            initiator_filter_policy: ConnectionFilterPolicy::UseAddress,
+            own_address_type: OwnAddressType::Public,
+            peer_address: PeerAddrType::PublicDeviceAddress(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+            le_1m_phy: None,
+            le_2m_phy: None,
+            le_coded_phy: None,
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::NoInitiatingPhys);
+    assert_eq!(sink.written_data, []);
+}
+
+fn periodic_advertising_parameters(
+    interval_min: Duration,
+    interval_max: Duration,
+) -> PeriodicAdvertisingParameters {
+    PeriodicAdvertisingParameters {
+        adv_handle: hci::AdvertisingHandle(1),
+        interval_min,
+        interval_max,
+        include_tx_power: true,
+    }
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_periodic_advertising_parameters(&periodic_advertising_parameters(
+        Duration::from_millis(100),
+        Duration::from_millis(200),
+    ))
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x3E, 0x20, 7, 0x01, 0x50, 0x00, 0xA0, 0x00, 0x40, 0x00]
+    );
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_parameters_interval_too_short() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_periodic_advertising_parameters(&periodic_advertising_parameters(
+            Duration::from_millis(5),
+            Duration::from_millis(200),
+        ))
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        Error::BadPeriodicAdvertisingInterval(Duration::from_millis(5), Duration::from_millis(200))
+    );
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_parameters_interval_too_long() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_periodic_advertising_parameters(&periodic_advertising_parameters(
+            Duration::from_millis(100),
+            Duration::from_secs(82),
+        ))
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        Error::BadPeriodicAdvertisingInterval(Duration::from_millis(100), Duration::from_secs(82))
+    );
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_parameters_interval_inverted() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_periodic_advertising_parameters(&periodic_advertising_parameters(
+            Duration::from_millis(200),
+            Duration::from_millis(100),
+        ))
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        Error::BadPeriodicAdvertisingInterval(
+            Duration::from_millis(200),
+            Duration::from_millis(100)
+        )
+    );
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_data() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_periodic_advertising_data(&PeriodicAdvertisingData {
+        adv_handle: hci::AdvertisingHandle(1),
+        operation: AdvertisingOperation::CompleteData,
+        data: &[1, 2, 3],
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x3F, 0x20, 6, 0x01, 0x03, 3, 1, 2, 3]
+    );
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_data_too_long() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_periodic_advertising_data(&PeriodicAdvertisingData {
+            adv_handle: hci::AdvertisingHandle(1),
+            operation: AdvertisingOperation::CompleteData,
+            data: &[0; 253],
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::PeriodicAdvertisingDataTooLong(253));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_periodic_advertising_enable(true, hci::AdvertisingHandle(2))
+        .await;
+    assert_eq!(sink.written_data, [1, 0x40, 0x20, 2, 0x01, 0x02]);
+}
+
+fn periodic_advertising_sync_parameters() -> PeriodicAdvertisingSyncParameters {
+    PeriodicAdvertisingSyncParameters {
+        options: SyncOptions::DUPLICATE_FILTERING_INITIALLY_ENABLED,
+        adv_sid: 3,
+        adv_address: hci::BdAddrType::Random(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        skip: 5,
+        sync_timeout: Duration::from_secs(1),
+        sync_cte_type: SyncCteType::NO_AOA | SyncCteType::NO_TYPE_3,
+    }
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_create_sync() {
+    let mut sink = RecordingSink::new();
+    sink.le_periodic_advertising_create_sync(&periodic_advertising_sync_parameters())
+        .await
+        .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x44, 0x20, 14, 0x04, 0x03, 0x01, 1, 2, 3, 4, 5, 6, 0x05, 0x00, 0x64, 0x00, 0x09
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_create_sync_bad_sid() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_periodic_advertising_create_sync(&PeriodicAdvertisingSyncParameters {
+            adv_sid: 0x10,
+            ..periodic_advertising_sync_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadAdvertisingSid(0x10));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_create_sync_bad_skip() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_periodic_advertising_create_sync(&PeriodicAdvertisingSyncParameters {
+            skip: 0x01F4,
+            ..periodic_advertising_sync_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadSyncSkip(0x01F4));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_create_sync_timeout_too_short() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_periodic_advertising_create_sync(&PeriodicAdvertisingSyncParameters {
+            sync_timeout: Duration::from_millis(90),
+            ..periodic_advertising_sync_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadSyncTimeout(Duration::from_millis(90)));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_create_sync_timeout_too_long() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_periodic_advertising_create_sync(&PeriodicAdvertisingSyncParameters {
+            sync_timeout: Duration::from_millis(163_850),
+            ..periodic_advertising_sync_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadSyncTimeout(Duration::from_millis(163_850)));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_create_sync_cancel() {
+    let mut sink = RecordingSink::new();
+    sink.le_periodic_advertising_create_sync_cancel().await;
+    assert_eq!(sink.written_data, [1, 0x45, 0x20, 0]);
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_terminate_sync() {
+    let mut sink = RecordingSink::new();
+    sink.le_periodic_advertising_terminate_sync(hci::SyncHandle(0x0201))
+        .await;
+    assert_eq!(sink.written_data, [1, 0x46, 0x20, 2, 0x01, 0x02]);
+}
+
+#[tokio::test]
+async fn le_add_device_to_periodic_advertiser_list() {
+    let mut sink = RecordingSink::new();
+    sink.le_add_device_to_periodic_advertiser_list(&PeriodicAdvertiser {
+        address: hci::BdAddrType::Public(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        adv_sid: 0x0F,
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x47, 0x20, 8, 0x00, 1, 2, 3, 4, 5, 6, 0x0F]
+    );
+}
+
+#[tokio::test]
+async fn le_add_device_to_periodic_advertiser_list_bad_sid() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_add_device_to_periodic_advertiser_list(&PeriodicAdvertiser {
+            address: hci::BdAddrType::Public(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+            adv_sid: 0x10,
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadAdvertisingSid(0x10));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_remove_device_from_periodic_advertiser_list() {
+    let mut sink = RecordingSink::new();
+    sink.le_remove_device_from_periodic_advertiser_list(&PeriodicAdvertiser {
+        address: hci::BdAddrType::Random(hci::BdAddr([1, 2, 3, 4, 5, 6])),
+        adv_sid: 0x02,
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x48, 0x20, 8, 0x01, 1, 2, 3, 4, 5, 6, 0x02]
+    );
+}
+
+#[tokio::test]
+async fn le_clear_periodic_advertiser_list() {
+    let mut sink = RecordingSink::new();
+    sink.le_clear_periodic_advertiser_list().await;
+    assert_eq!(sink.written_data, [1, 0x49, 0x20, 0]);
+}
+
+#[tokio::test]
+async fn le_read_periodic_advertiser_list_size() {
+    let mut sink = RecordingSink::new();
+    sink.le_read_periodic_advertiser_list_size().await;
+    assert_eq!(sink.written_data, [1, 0x4A, 0x20, 0]);
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_sync_transfer() {
+    let mut sink = RecordingSink::new();
+    sink.le_periodic_advertising_sync_transfer(
+        hci::ConnectionHandle(0x0201),
+        0x0403,
+        hci::SyncHandle(0x0605),
+    )
+    .await;
+    assert_eq!(
+        sink.written_data,
+        [1, 0x5A, 0x20, 6, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06]
+    );
+}
+
+#[tokio::test]
+async fn le_periodic_advertising_set_info_transfer() {
+    let mut sink = RecordingSink::new();
+    sink.le_periodic_advertising_set_info_transfer(
+        hci::ConnectionHandle(0x0201),
+        0x0403,
+        hci::AdvertisingHandle(0x05),
+    )
+    .await;
+    assert_eq!(
+        sink.written_data,
+        [1, 0x5B, 0x20, 5, 0x01, 0x02, 0x03, 0x04, 0x05]
+    );
+}
+
+fn sync_transfer_parameters() -> SyncTransferParameters {
+    SyncTransferParameters {
+        mode: SyncTransferMode::ReportsEnabled,
+        skip: 5,
+        sync_timeout: Duration::from_secs(1),
+        sync_cte_type: SyncCteType::NO_CTE,
+    }
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_sync_transfer_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_periodic_advertising_sync_transfer_parameters(
+        hci::ConnectionHandle(0x0201),
+        &sync_transfer_parameters(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x5C, 0x20, 8, 0x01, 0x02, 0x02, 0x05, 0x00, 0x64, 0x00, 0x10
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_set_periodic_advertising_sync_transfer_parameters_bad_skip() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_periodic_advertising_sync_transfer_parameters(
+            hci::ConnectionHandle(0x0201),
+            &SyncTransferParameters {
+                skip: 0x01F4,
+                ..sync_transfer_parameters()
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadSyncSkip(0x01F4));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_default_periodic_advertising_sync_transfer_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_default_periodic_advertising_sync_transfer_parameters(&sync_transfer_parameters())
+        .await
+        .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x5D, 0x20, 6, 0x02, 0x05, 0x00, 0x64, 0x00, 0x10]
+    );
+}
+
+#[tokio::test]
+async fn le_set_default_periodic_advertising_sync_transfer_parameters_bad_timeout() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_default_periodic_advertising_sync_transfer_parameters(&SyncTransferParameters {
+            sync_timeout: Duration::from_millis(90),
+            ..sync_transfer_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadSyncTimeout(Duration::from_millis(90)));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_read_iso_tx_sync() {
+    let mut sink = RecordingSink::new();
+    sink.le_read_iso_tx_sync(hci::ConnectionHandle(0x0201))
+        .await;
+    assert_eq!(sink.written_data, [1, 0x61, 0x20, 2, 0x01, 0x02]);
+}
+
+fn cig_parameters() -> CigParameters {
+    CigParameters {
+        cig_id: 0x01,
+        sdu_interval_c_to_p: Duration::from_millis(10),
+        sdu_interval_p_to_c: Duration::from_micros(7500),
+        worst_case_sca: hci::event::CentralClockAccuracy::Ppm50,
+        packing: Packing::Interleaved,
+        framing: Framing::Unframed,
+        max_transport_latency_c_to_p: Duration::from_millis(20),
+        max_transport_latency_p_to_c: Duration::from_millis(40),
+    }
+}
+
+fn cis_parameters() -> [CisParameters; 2] {
+    [
+        CisParameters {
+            cis_id: 0x00,
+            max_sdu_c_to_p: 40,
+            max_sdu_p_to_c: 40,
+            phy_c_to_p: PhyMask::LE_2M,
+            phy_p_to_c: PhyMask::LE_2M,
+            rtn_c_to_p: 2,
+            rtn_p_to_c: 2,
+        },
+        CisParameters {
+            cis_id: 0x01,
+            max_sdu_c_to_p: 0x0100,
+            max_sdu_p_to_c: 0,
+            phy_c_to_p: PhyMask::LE_1M | PhyMask::LE_2M,
+            phy_p_to_c: PhyMask::LE_1M,
+            rtn_c_to_p: 4,
+            rtn_p_to_c: 0,
+        },
+    ]
+}
+
+#[tokio::test]
+async fn le_set_cig_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_cig_parameters(&cig_parameters(), &cis_parameters())
+        .await
+        .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x62, 0x20, 33, 0x01, 0x10, 0x27, 0x00, 0x4C, 0x1D, 0x00, 0x05, 0x01, 0x00, 0x14,
+            0x00, 0x28, 0x00, 2, 0x00, 0x28, 0x00, 0x28, 0x00, 0x02, 0x02, 0x02, 0x02, 0x01, 0x00,
+            0x01, 0x00, 0x00, 0x03, 0x01, 0x04, 0x00
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_set_cig_parameters_bad_cig_id() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_cig_parameters(
+            &CigParameters {
+                cig_id: 0xF0,
+                ..cig_parameters()
+            },
+            &cis_parameters(),
+        )
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadCigId(0xF0));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_cig_parameters_bad_cis_id() {
+    let mut sink = RecordingSink::new();
+    let mut cis = cis_parameters();
+    cis[1].cis_id = 0xF0;
+    let err = sink
+        .le_set_cig_parameters(&cig_parameters(), &cis)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadCisId(0xF0));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_cig_parameters_sdu_interval_too_short() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_cig_parameters(
+            &CigParameters {
+                sdu_interval_p_to_c: Duration::from_micros(254),
+                ..cig_parameters()
+            },
+            &cis_parameters(),
+        )
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadSduInterval(Duration::from_micros(254)));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_cig_parameters_max_transport_latency_too_long() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_cig_parameters(
+            &CigParameters {
+                max_transport_latency_c_to_p: Duration::from_millis(4001),
+                ..cig_parameters()
+            },
+            &cis_parameters(),
+        )
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        Error::BadMaxTransportLatency(Duration::from_millis(4001))
+    );
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_cig_parameters_too_many_cis() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_cig_parameters(&cig_parameters(), &[cis_parameters()[0]; 27])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::TooManyCis(27));
+    assert_eq!(sink.written_data, []);
+}
+
+fn cig_test_parameters() -> CigTestParameters {
+    CigTestParameters {
+        cig_id: 0x02,
+        sdu_interval_c_to_p: Duration::from_millis(10),
+        sdu_interval_p_to_c: Duration::from_millis(10),
+        ft_c_to_p: 1,
+        ft_p_to_c: 1,
+        iso_interval: Duration::from_millis(10),
+        worst_case_sca: hci::event::CentralClockAccuracy::Ppm20,
+        packing: Packing::Sequential,
+        framing: Framing::Framed,
+    }
+}
+
+fn cis_test_parameters() -> CisTestParameters {
+    CisTestParameters {
+        cis_id: 0x03,
+        nse: 2,
+        max_sdu_c_to_p: 40,
+        max_sdu_p_to_c: 40,
+        max_pdu_c_to_p: 40,
+        max_pdu_p_to_c: 40,
+        phy_c_to_p: PhyMask::LE_2M,
+        phy_p_to_c: PhyMask::LE_2M,
+        bn_c_to_p: 1,
+        bn_p_to_c: 1,
+    }
+}
+
+#[tokio::test]
+async fn le_set_cig_parameters_test() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_cig_parameters_test(&cig_test_parameters(), &[cis_test_parameters()])
+        .await
+        .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x63, 0x20, 29, 0x02, 0x10, 0x27, 0x00, 0x10, 0x27, 0x00, 0x01, 0x01, 0x08, 0x00,
+            0x07, 0x00, 0x01, 1, 0x03, 0x02, 0x28, 0x00, 0x28, 0x00, 0x28, 0x00, 0x28, 0x00, 0x02,
+            0x02, 0x01, 0x01
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_set_cig_parameters_test_bad_iso_interval() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_cig_parameters_test(
+            &CigTestParameters {
+                iso_interval: Duration::from_millis(4),
+                ..cig_test_parameters()
+            },
+            &[cis_test_parameters()],
+        )
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadIsoInterval(Duration::from_millis(4)));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_cig_parameters_test_too_many_cis() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_cig_parameters_test(&cig_test_parameters(), &[cis_test_parameters(); 18])
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::TooManyCis(18));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_create_cis() {
+    let mut sink = RecordingSink::new();
+    sink.le_create_cis(&[
+        CisConnection {
+            cis_conn_handle: hci::ConnectionHandle(0x0201),
+            acl_conn_handle: hci::ConnectionHandle(0x0403),
+        },
+        CisConnection {
+            cis_conn_handle: hci::ConnectionHandle(0x0605),
+            acl_conn_handle: hci::ConnectionHandle(0x0807),
+        },
+    ])
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x64, 0x20, 9, 2, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_create_cis_too_many_cis() {
+    let mut sink = RecordingSink::new();
+    let cis = CisConnection {
+        cis_conn_handle: hci::ConnectionHandle(0x0201),
+        acl_conn_handle: hci::ConnectionHandle(0x0403),
+    };
+    let err = sink.le_create_cis(&[cis; 32]).await.err().unwrap();
+    assert_eq!(err, Error::TooManyCis(32));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_remove_cig() {
+    let mut sink = RecordingSink::new();
+    sink.le_remove_cig(0x01).await;
+    assert_eq!(sink.written_data, [1, 0x65, 0x20, 1, 0x01]);
+}
+
+#[tokio::test]
+async fn le_accept_cis_request() {
+    let mut sink = RecordingSink::new();
+    sink.le_accept_cis_request(hci::ConnectionHandle(0x0201))
+        .await;
+    assert_eq!(sink.written_data, [1, 0x66, 0x20, 2, 0x01, 0x02]);
+}
+
+#[tokio::test]
+async fn le_reject_cis_request() {
+    let mut sink = RecordingSink::new();
+    sink.le_reject_cis_request(hci::ConnectionHandle(0x0201), hci::Status::LimitedResources)
+        .await;
+    assert_eq!(sink.written_data, [1, 0x67, 0x20, 3, 0x01, 0x02, 0x0D]);
+}
+
+fn iso_data_path_parameters() -> IsoDataPathParameters<'static> {
+    IsoDataPathParameters {
+        conn_handle: hci::ConnectionHandle(0x0201),
+        direction: DataPathDirection::Output,
+        data_path_id: 0x00,
+        codec_id: CodecId {
+            coding_format: 0x06,
+            company_id: 0,
+            vendor_codec_id: 0,
+        },
+        controller_delay: Duration::from_millis(10),
+        codec_configuration: &[0x02, 0x01, 0x08],
+    }
+}
+
+#[tokio::test]
+async fn le_setup_iso_data_path() {
+    let mut sink = RecordingSink::new();
+    sink.le_setup_iso_data_path(&iso_data_path_parameters())
+        .await
+        .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x6E, 0x20, 16, 0x01, 0x02, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x10, 0x27,
+            0x00, 3, 0x02, 0x01, 0x08
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_setup_iso_data_path_bad_controller_delay() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_setup_iso_data_path(&IsoDataPathParameters {
+            controller_delay: Duration::from_secs(5),
+            ..iso_data_path_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadControllerDelay(Duration::from_secs(5)));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_setup_iso_data_path_codec_configuration_too_long() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_setup_iso_data_path(&IsoDataPathParameters {
+            codec_configuration: &[0; 243],
+            ..iso_data_path_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::CodecConfigurationTooLong(243));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_remove_iso_data_path() {
+    let mut sink = RecordingSink::new();
+    sink.le_remove_iso_data_path(
+        hci::ConnectionHandle(0x0201),
+        DataPathDirections::INPUT | DataPathDirections::OUTPUT,
+    )
+    .await;
+    assert_eq!(sink.written_data, [1, 0x6F, 0x20, 3, 0x01, 0x02, 0x03]);
+}
+
+fn big_parameters() -> BigParameters {
+    BigParameters {
+        big_handle: hci::BigHandle(0x01),
+        adv_handle: hci::AdvertisingHandle(0x02),
+        num_bis: 2,
+        sdu_interval: Duration::from_millis(10),
+        max_sdu: 40,
+        max_transport_latency: Duration::from_millis(20),
+        rtn: 2,
+        phy: PhyMask::LE_2M,
+        packing: Packing::Sequential,
+        framing: Framing::Unframed,
+        broadcast_code: None,
+    }
+}
+
+#[tokio::test]
+async fn le_create_big() {
+    let mut sink = RecordingSink::new();
+    sink.le_create_big(&big_parameters()).await.unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x68, 0x20, 31, 0x01, 0x02, 2, 0x10, 0x27, 0x00, 0x28, 0x00, 0x14, 0x00, 2, 0x02,
+            0x00, 0x00, 0x00, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_create_big_encrypted() {
+    let mut sink = RecordingSink::new();
+    sink.le_create_big(&BigParameters {
+        broadcast_code: Some([
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E,
+            0x0F, 0x10,
+        ]),
+        ..big_parameters()
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x68, 0x20, 31, 0x01, 0x02, 2, 0x10, 0x27, 0x00, 0x28, 0x00, 0x14, 0x00, 2, 0x02,
+            0x00, 0x00, 0x01, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B,
+            0x0C, 0x0D, 0x0E, 0x0F, 0x10
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_create_big_no_bis() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_create_big(&BigParameters {
+            num_bis: 0,
+            ..big_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadNumBis(0));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_create_big_too_many_bis() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_create_big(&BigParameters {
+            num_bis: 32,
+            ..big_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadNumBis(32));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_create_big_sdu_interval_too_long() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_create_big(&BigParameters {
+            sdu_interval: Duration::from_micros(0x10_0000),
+            ..big_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadSduInterval(Duration::from_micros(0x10_0000)));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_create_big_max_transport_latency_too_short() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_create_big(&BigParameters {
+            max_transport_latency: Duration::from_millis(4),
+            ..big_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadMaxTransportLatency(Duration::from_millis(4)));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_terminate_big() {
+    let mut sink = RecordingSink::new();
+    sink.le_terminate_big(
+        hci::BigHandle(0x01),
+        hci::Status::ConnectionTerminatedByHost,
+    )
+    .await;
+    assert_eq!(sink.written_data, [1, 0x6A, 0x20, 2, 0x01, 0x16]);
+}
+
+fn big_sync_parameters() -> BigSyncParameters<'static> {
+    BigSyncParameters {
+        big_handle: hci::BigHandle(0x01),
+        sync_handle: hci::SyncHandle(0x0201),
+        broadcast_code: None,
+        mse: 0,
+        big_sync_timeout: Duration::from_secs(1),
+        bis: &[0x01, 0x02],
+    }
+}
+
+#[tokio::test]
+async fn le_big_create_sync() {
+    let mut sink = RecordingSink::new();
+    sink.le_big_create_sync(&big_sync_parameters())
+        .await
+        .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x6B, 0x20, 26, 0x01, 0x01, 0x02, 0x00, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0x64, 0x00, 2, 0x01, 0x02
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_big_create_sync_timeout_too_short() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_big_create_sync(&BigSyncParameters {
+            big_sync_timeout: Duration::from_millis(90),
+            ..big_sync_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadBigSyncTimeout(Duration::from_millis(90)));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_big_create_sync_too_many_bis() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_big_create_sync(&BigSyncParameters {
+            bis: &[0x01; 32],
+            ..big_sync_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::TooManyBis(32));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_big_terminate_sync() {
+    let mut sink = RecordingSink::new();
+    sink.le_big_terminate_sync(hci::BigHandle(0x01)).await;
+    assert_eq!(sink.written_data, [1, 0x6C, 0x20, 1, 0x01]);
+}
+
+#[tokio::test]
+async fn le_enhanced_read_transmit_power_level() {
+    let mut sink = RecordingSink::new();
+    sink.le_enhanced_read_transmit_power_level(
+        hci::ConnectionHandle(0x0201),
+        hci::event::PowerControlPhy::LeCodedS2,
+    )
+    .await;
+    assert_eq!(sink.written_data, [1, 0x76, 0x20, 3, 0x01, 0x02, 0x04]);
+}
+
+#[tokio::test]
+async fn le_read_remote_transmit_power_level() {
+    let mut sink = RecordingSink::new();
+    sink.le_read_remote_transmit_power_level(
+        hci::ConnectionHandle(0x0201),
+        hci::event::PowerControlPhy::Le2M,
+    )
+    .await;
+    assert_eq!(sink.written_data, [1, 0x77, 0x20, 3, 0x01, 0x02, 0x02]);
+}
+
+#[tokio::test]
+async fn le_set_path_loss_reporting_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_path_loss_reporting_parameters(&PathLossReportingParameters {
+        conn_handle: hci::ConnectionHandle(0x0201),
+        high_threshold: Some(60),
+        high_hysteresis: 5,
+        low_threshold: None,
+        low_hysteresis: 0,
+        min_time_spent: 0x0403,
+    })
+    .await;
+    assert_eq!(
+        sink.written_data,
+        [1, 0x78, 0x20, 8, 0x01, 0x02, 60, 5, 0xFF, 0, 0x03, 0x04]
+    );
+}
+
+#[tokio::test]
+async fn le_set_path_loss_reporting_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_path_loss_reporting_enable(hci::ConnectionHandle(0x0201), true)
+        .await;
+    assert_eq!(sink.written_data, [1, 0x79, 0x20, 3, 0x01, 0x02, 0x01]);
+}
+
+#[tokio::test]
+async fn le_set_transmit_power_reporting_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_transmit_power_reporting_enable(hci::ConnectionHandle(0x0201), false, true)
+        .await;
+    assert_eq!(
+        sink.written_data,
+        [1, 0x7A, 0x20, 4, 0x01, 0x02, 0x00, 0x01]
+    );
+}
+
+#[tokio::test]
+async fn le_set_default_subrate() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_default_subrate(
+        &SubrateParametersBuilder::new()
+            .with_subrate_range(2, 4)
+            .with_max_latency(10)
+            .with_continuation_number(1)
+            .with_supervision_timeout(Duration::from_secs(5))
+            .build()
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x7D, 0x20, 10, 0x02, 0x00, 0x04, 0x00, 0x0A, 0x00, 0x01, 0x00, 0xF4, 0x01
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_subrate_request() {
+    let mut sink = RecordingSink::new();
+    sink.le_subrate_request(
+        hci::ConnectionHandle(0x0201),
+        &SubrateParametersBuilder::new()
+            .with_subrate_range(2, 4)
+            .with_max_latency(10)
+            .with_continuation_number(1)
+            .with_supervision_timeout(Duration::from_secs(5))
+            .build()
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x7E, 0x20, 12, 0x01, 0x02, 0x02, 0x00, 0x04, 0x00, 0x0A, 0x00, 0x01, 0x00, 0xF4,
+            0x01
+        ]
+    );
+}
+
+fn connectionless_cte_transmit_parameters() -> ConnectionlessCteTransmitParameters<'static> {
+    ConnectionlessCteTransmitParameters {
+        adv_handle: hci::AdvertisingHandle(0x01),
+        cte_length: Duration::from_micros(160),
+        cte_type: hci::event::CteType::AoD2us,
+        cte_count: 2,
+        antenna_ids: &[0x00, 0x01, 0x02, 0x03],
+    }
+}
+
+#[tokio::test]
+async fn le_set_connectionless_cte_transmit_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_connectionless_cte_transmit_parameters(&connectionless_cte_transmit_parameters())
+        .await
+        .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x51, 0x20, 9, 0x01, 0x14, 0x02, 0x02, 4, 0x00, 0x01, 0x02, 0x03
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_set_connectionless_cte_transmit_parameters_bad_cte_length() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_connectionless_cte_transmit_parameters(&ConnectionlessCteTransmitParameters {
+            cte_length: Duration::from_micros(8),
+            ..connectionless_cte_transmit_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadCteLength(Duration::from_micros(8)));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_connectionless_cte_transmit_parameters_bad_cte_count() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_connectionless_cte_transmit_parameters(&ConnectionlessCteTransmitParameters {
+            cte_count: 0,
+            ..connectionless_cte_transmit_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadCteCount(0));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_connectionless_cte_transmit_parameters_bad_switching_pattern_length() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_connectionless_cte_transmit_parameters(&ConnectionlessCteTransmitParameters {
+            antenna_ids: &[0x00],
+            ..connectionless_cte_transmit_parameters()
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadSwitchingPatternLength(1));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_connectionless_cte_transmit_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_connectionless_cte_transmit_enable(hci::AdvertisingHandle(0x01), true)
+        .await;
+    assert_eq!(sink.written_data, [1, 0x52, 0x20, 2, 0x01, 0x01]);
+}
+
+#[tokio::test]
+async fn le_set_connectionless_iq_sampling_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_connectionless_iq_sampling_enable(&ConnectionlessIqSamplingParameters {
+        sync_handle: hci::SyncHandle(0x0201),
+        sampling_enable: true,
+        slot_duration: hci::event::SlotDuration::Slot1us,
+        max_sampled_ctes: 4,
+        antenna_ids: &[0x00, 0x01],
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x53, 0x20, 8, 0x01, 0x02, 0x01, 0x01, 0x04, 2, 0x00, 0x01
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_set_connectionless_iq_sampling_disable() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_connectionless_iq_sampling_enable(&ConnectionlessIqSamplingParameters {
+        sync_handle: hci::SyncHandle(0x0201),
+        sampling_enable: false,
+        slot_duration: hci::event::SlotDuration::Slot1us,
+        max_sampled_ctes: 0,
+        antenna_ids: &[],
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x53, 0x20, 6, 0x01, 0x02, 0x00, 0x01, 0x00, 0]
+    );
+}
+
+#[tokio::test]
+async fn le_set_connectionless_iq_sampling_enable_bad_max_sampled_ctes() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_connectionless_iq_sampling_enable(&ConnectionlessIqSamplingParameters {
+            sync_handle: hci::SyncHandle(0x0201),
+            sampling_enable: true,
+            slot_duration: hci::event::SlotDuration::Slot1us,
+            max_sampled_ctes: 17,
+            antenna_ids: &[0x00, 0x01],
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadMaxSampledCtes(17));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_connection_cte_receive_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_connection_cte_receive_parameters(&ConnectionCteReceiveParameters {
+        conn_handle: hci::ConnectionHandle(0x0201),
+        sampling_enable: true,
+        slot_duration: hci::event::SlotDuration::Slot2us,
+        antenna_ids: &[0x00, 0x01, 0x02],
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            1, 0x54, 0x20, 8, 0x01, 0x02, 0x01, 0x02, 3, 0x00, 0x01, 0x02
+        ]
+    );
+}
+
+#[tokio::test]
+async fn le_set_connection_cte_receive_parameters_bad_switching_pattern_length() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_set_connection_cte_receive_parameters(&ConnectionCteReceiveParameters {
+            conn_handle: hci::ConnectionHandle(0x0201),
+            sampling_enable: true,
+            slot_duration: hci::event::SlotDuration::Slot2us,
+            antenna_ids: &[0x00; 76],
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadSwitchingPatternLength(76));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_set_connection_cte_transmit_parameters() {
+    let mut sink = RecordingSink::new();
+    sink.le_set_connection_cte_transmit_parameters(&ConnectionCteTransmitParameters {
+        conn_handle: hci::ConnectionHandle(0x0201),
+        cte_types: CteTypes::AOD_1US | CteTypes::AOD_2US,
+        antenna_ids: &[0x00, 0x01],
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x55, 0x20, 6, 0x01, 0x02, 0x06, 2, 0x00, 0x01]
+    );
+}
+
+#[tokio::test]
+async fn le_connection_cte_request_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_connection_cte_request_enable(&ConnectionCteRequestParameters {
+        conn_handle: hci::ConnectionHandle(0x0201),
+        enable: true,
+        cte_request_interval: 0x0010,
+        requested_cte_length: Duration::from_micros(40),
+        requested_cte_type: hci::event::CteType::AoA,
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [1, 0x56, 0x20, 7, 0x01, 0x02, 0x01, 0x10, 0x00, 0x05, 0x00]
+    );
+}
+
+#[tokio::test]
+async fn le_connection_cte_request_enable_bad_cte_length() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .le_connection_cte_request_enable(&ConnectionCteRequestParameters {
+            conn_handle: hci::ConnectionHandle(0x0201),
+            enable: true,
+            cte_request_interval: 0x0010,
+            requested_cte_length: Duration::from_micros(168),
+            requested_cte_type: hci::event::CteType::AoA,
+        })
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, Error::BadCteLength(Duration::from_micros(168)));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn le_connection_cte_response_enable() {
+    let mut sink = RecordingSink::new();
+    sink.le_connection_cte_response_enable(hci::ConnectionHandle(0x0201), true)
+        .await;
+    assert_eq!(sink.written_data, [1, 0x57, 0x20, 3, 0x01, 0x02, 0x01]);
+}
+
+#[tokio::test]
+async fn le_read_antenna_information() {
+    let mut sink = RecordingSink::new();
+    sink.le_read_antenna_information().await;
+    assert_eq!(sink.written_data, [1, 0x58, 0x20, 0]);
+}
+
+#[tokio::test]
+async fn write_acl_data() {
+    let mut sink = RecordingSink::new();
+    sink.write_acl_data(
+        &uart::AclDataHeader {
+            conn_handle: hci::ConnectionHandle(0x0201),
+            packet_boundary: uart::AclPacketBoundary::FirstNonFlushable,
+            broadcast: uart::AclBroadcastFlag::PointToPoint,
+        },
+        &[0x01, 0x02, 0x03],
+    )
+    .await
+    .unwrap();
+    assert_eq!(sink.written_data, [2, 0x01, 0x02, 3, 0, 0x01, 0x02, 0x03]);
+}
+
+#[tokio::test]
+async fn write_acl_data_continuing() {
+    let mut sink = RecordingSink::new();
+    sink.write_acl_data(
+        &uart::AclDataHeader {
+            conn_handle: hci::ConnectionHandle(0x0201),
+            packet_boundary: uart::AclPacketBoundary::Continuing,
+            broadcast: uart::AclBroadcastFlag::BrEdrBroadcast,
+        },
+        &[0x04],
+    )
+    .await
+    .unwrap();
+    assert_eq!(sink.written_data, [2, 0x01, 0x52, 1, 0, 0x04]);
+}
+
+#[tokio::test]
+async fn write_acl_data_too_long() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .write_acl_data(
+            &uart::AclDataHeader {
+                conn_handle: hci::ConnectionHandle(0x0201),
+                packet_boundary: uart::AclPacketBoundary::FirstNonFlushable,
+                broadcast: uart::AclBroadcastFlag::PointToPoint,
+            },
+            &[0; 0x10000],
+        )
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, uart::Error::DataTooLong(0x10000));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn read_acl_data() {
+    let mut sink = RecordingSink::new();
+    sink.read_data = vec![2, 0x01, 0x22, 4, 0, 0x03, 0x00, 0x04, 0x00];
+    match sink.read().await {
+        Ok(uart::Packet::AclData(acl)) => {
+            assert_eq!(
+                acl.header,
+                uart::AclDataHeader {
+                    conn_handle: hci::ConnectionHandle(0x0201),
+                    packet_boundary: uart::AclPacketBoundary::FirstFlushable,
+                    broadcast: uart::AclBroadcastFlag::PointToPoint,
+                }
+            );
+            assert_eq!(acl.data(), [0x03, 0x00, 0x04, 0x00]);
+        }
+        other => panic!("Did not get ACL data: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn read_acl_data_bad_broadcast_flag() {
+    let mut sink = RecordingSink::new();
+    sink.read_data = vec![2, 0x01, 0x82, 1, 0, 0x00];
+    let err = sink.read().await.err().unwrap();
+    assert_eq!(err, uart::Error::BadBroadcastFlag(0b10));
+}
+
+#[tokio::test]
+async fn read_acl_data_too_long() {
+    let mut sink = RecordingSink::new();
+    sink.read_data = vec![2, 0x01, 0x22, 252, 0];
+    let err = sink.read().await.err().unwrap();
+    assert_eq!(err, uart::Error::DataTooLong(252));
+}
+
+#[tokio::test]
+async fn write_sync_data() {
+    let mut sink = RecordingSink::new();
+    sink.write_sync_data(
+        &uart::SyncDataHeader {
+            conn_handle: hci::ConnectionHandle(0x0201),
+            packet_status: uart::SyncPacketStatus::CorrectlyReceived,
+        },
+        &[0x01, 0x02, 0x03],
+    )
+    .await
+    .unwrap();
+    assert_eq!(sink.written_data, [3, 0x01, 0x02, 3, 0x01, 0x02, 0x03]);
+}
+
+#[tokio::test]
+async fn write_sync_data_too_long() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .write_sync_data(
+            &uart::SyncDataHeader {
+                conn_handle: hci::ConnectionHandle(0x0201),
+                packet_status: uart::SyncPacketStatus::CorrectlyReceived,
+            },
+            &[0; 256],
+        )
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, uart::Error::DataTooLong(256));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn read_sync_data() {
+    let mut sink = RecordingSink::new();
+    sink.read_data = vec![3, 0x01, 0x32, 2, 0x0A, 0x0B];
+    match sink.read().await {
+        Ok(uart::Packet::SyncData(sync)) => {
+            assert_eq!(
+                sync.header,
+                uart::SyncDataHeader {
+                    conn_handle: hci::ConnectionHandle(0x0201),
+                    packet_status: uart::SyncPacketStatus::PartiallyLost,
+                }
+            );
+            assert_eq!(sync.data(), [0x0A, 0x0B]);
+        }
+        other => panic!("Did not get sync data: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn write_iso_data_complete_with_timestamp() {
+    let mut sink = RecordingSink::new();
+    sink.write_iso_data(
+        &uart::IsoDataHeader {
+            conn_handle: hci::ConnectionHandle(0x0201),
+            packet_boundary: uart::IsoPacketBoundary::Complete(uart::IsoSduHeader {
+                timestamp: Some(0x0403_0201),
+                sequence_number: 0x0605,
+                sdu_length: 2,
+                packet_status: uart::IsoPacketStatus::Valid,
+            }),
+        },
+        &[0x0A, 0x0B],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [
+            5, 0x01, 0x62, 10, 0, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 2, 0x00, 0x0A, 0x0B
+        ]
+    );
+}
+
+#[tokio::test]
+async fn write_iso_data_first_fragment() {
+    let mut sink = RecordingSink::new();
+    sink.write_iso_data(
+        &uart::IsoDataHeader {
+            conn_handle: hci::ConnectionHandle(0x0201),
+            packet_boundary: uart::IsoPacketBoundary::First(uart::IsoSduHeader {
+                timestamp: None,
+                sequence_number: 0x0605,
+                sdu_length: 0x0123,
+                packet_status: uart::IsoPacketStatus::Valid,
+            }),
+        },
+        &[0x0A],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sink.written_data,
+        [5, 0x01, 0x02, 5, 0, 0x05, 0x06, 0x23, 0x01, 0x0A]
+    );
+}
+
+#[tokio::test]
+async fn write_iso_data_continuation() {
+    let mut sink = RecordingSink::new();
+    sink.write_iso_data(
+        &uart::IsoDataHeader {
+            conn_handle: hci::ConnectionHandle(0x0201),
+            packet_boundary: uart::IsoPacketBoundary::Continuation,
+        },
+        &[0x0A, 0x0B],
+    )
+    .await
+    .unwrap();
+    assert_eq!(sink.written_data, [5, 0x01, 0x12, 2, 0, 0x0A, 0x0B]);
+}
+
+#[tokio::test]
+async fn write_iso_data_sdu_too_long() {
+    let mut sink = RecordingSink::new();
+    let err = sink
+        .write_iso_data(
+            &uart::IsoDataHeader {
+                conn_handle: hci::ConnectionHandle(0x0201),
+                packet_boundary: uart::IsoPacketBoundary::First(uart::IsoSduHeader {
+                    timestamp: None,
+                    sequence_number: 0,
+                    sdu_length: 0x1000,
+                    packet_status: uart::IsoPacketStatus::Valid,
+                }),
+            },
+            &[0x0A],
+        )
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err, uart::Error::DataTooLong(0x1000));
+    assert_eq!(sink.written_data, []);
+}
+
+#[tokio::test]
+async fn read_iso_data_complete() {
+    let mut sink = RecordingSink::new();
+    sink.read_data = vec![5, 0x01, 0x22, 6, 0, 0x05, 0x06, 0x02, 0x80, 0x0A, 0x0B];
+    match sink.read().await {
+        Ok(uart::Packet::IsoData(iso)) => {
+            assert_eq!(
+                iso.header,
+                uart::IsoDataHeader {
+                    conn_handle: hci::ConnectionHandle(0x0201),
+                    packet_boundary: uart::IsoPacketBoundary::Complete(uart::IsoSduHeader {
+                        timestamp: None,
+                        sequence_number: 0x0605,
+                        sdu_length: 2,
+                        packet_status: uart::IsoPacketStatus::PartiallyLost,
+                    }),
+                }
+            );
+            assert_eq!(iso.data(), [0x0A, 0x0B]);
+        }
+        other => panic!("Did not get ISO data: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn read_iso_data_last_fragment() {
+    let mut sink = RecordingSink::new();
+    sink.read_data = vec![5, 0x01, 0x32, 1, 0, 0x0C];
+    match sink.read().await {
+        Ok(uart::Packet::IsoData(iso)) => {
+            assert_eq!(
+                iso.header,
+                uart::IsoDataHeader {
+                    conn_handle: hci::ConnectionHandle(0x0201),
+                    packet_boundary: uart::IsoPacketBoundary::Last,
+                }
+            );
+            assert_eq!(iso.data(), [0x0C]);
+        }
+        other => panic!("Did not get ISO data: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn read_iso_data_too_short_for_sdu_header() {
+    let mut sink = RecordingSink::new();
+    sink.read_data = vec![5, 0x01, 0x62, 4, 0, 0x01, 0x02, 0x03, 0x04];
+    let err = sink.read().await.err().unwrap();
+    assert_eq!(err, uart::Error::DataTooShort(4));
+}
+
+#[tokio::test]
+async fn read_iso_data_bad_packet_status() {
+    let mut sink = RecordingSink::new();
+    sink.read_data = vec![5, 0x01, 0x22, 4, 0, 0x05, 0x06, 0x00, 0xC0];
+    let err = sink.read().await.err().unwrap();
+    assert_eq!(err, uart::Error::BadPacketStatusFlag(0b11));
+}
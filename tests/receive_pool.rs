@@ -0,0 +1,165 @@
+extern crate stm32wb_hci as hci;
+
+mod vendor;
+
+use hci::ConnectionHandle;
+use hci::host::HostBufferSize;
+use hci::host::receive_pool::ReceivePool;
+use hci::host::uart::UartHci;
+use vendor::{PacketSink, RecordingSink};
+
+const ACL_0201: &[u8] = &[0x02, 0x01, 0x22, 1, 0, 0x0A];
+const ACL_0403: &[u8] = &[0x02, 0x03, 0x24, 1, 0, 0x0A];
+
+#[tokio::test]
+async fn enable() {
+    let mut hci: ReceivePool<_> = ReceivePool::new(RecordingSink::new(), 251, 4);
+    let params: HostBufferSize = hci.buffer_size();
+    assert_eq!(params.acl_data_packet_length, 251);
+    assert_eq!(params.total_acl_data_packets, 4);
+
+    hci.enable().await;
+    // Only the last command is recorded.
+    assert_eq!(hci.into_inner().written_data, [1, 0x31, 0x0C, 1, 0x01]);
+}
+
+#[tokio::test]
+async fn counts_received_packets() {
+    let mut hci: ReceivePool<_> =
+        ReceivePool::new(PacketSink::new(&[ACL_0201, ACL_0403, ACL_0201]), 251, 4);
+    for _ in 0..3 {
+        hci.read().await.unwrap();
+    }
+    assert_eq!(hci.in_use(), 3);
+    assert_eq!(hci.held(ConnectionHandle(0x0201)), 2);
+    assert_eq!(hci.held(ConnectionHandle(0x0403)), 1);
+}
+
+#[tokio::test]
+async fn batches_released_buffers() {
+    let mut hci: ReceivePool<_> =
+        ReceivePool::new(PacketSink::new(&[ACL_0201, ACL_0403, ACL_0201]), 251, 4);
+    for _ in 0..3 {
+        hci.read().await.unwrap();
+    }
+
+    // Half of the pool must be released before reporting.
+    hci.release(ConnectionHandle(0x0201), 1).await;
+    assert!(hci.controller().written_opcodes.is_empty());
+    assert_eq!(hci.in_use(), 3);
+
+    hci.release(ConnectionHandle(0x0403), 1).await;
+    assert_eq!(hci.controller().written_opcodes, [hci::Opcode(0x0C35)]);
+    assert_eq!(hci.in_use(), 1);
+    assert_eq!(hci.held(ConnectionHandle(0x0201)), 1);
+    assert_eq!(hci.held(ConnectionHandle(0x0403)), 0);
+}
+
+#[tokio::test]
+async fn report_covers_all_connections() {
+    let mut hci: ReceivePool<_> = ReceivePool::new(RecordingSink::new(), 251, 4);
+    for packet in [ACL_0201, ACL_0403, ACL_0201] {
+        hci.controller_mut().read_data = packet.to_vec();
+        hci.read().await.unwrap();
+    }
+
+    hci.set_batch_size(3);
+    hci.release(ConnectionHandle(0x0201), 2).await;
+    hci.release(ConnectionHandle(0x0403), 1).await;
+    assert_eq!(
+        hci.controller().written_data,
+        [
+            1, 0x35, 0x0C, 9, 2, 0x01, 0x02, 0x02, 0x00, 0x03, 0x04, 0x01, 0x00
+        ]
+    );
+    assert_eq!(hci.in_use(), 0);
+}
+
+#[tokio::test]
+async fn flush_reports_remaining_buffers() {
+    let mut hci: ReceivePool<_> = ReceivePool::new(PacketSink::new(&[ACL_0201]), 251, 4);
+    hci.read().await.unwrap();
+    hci.release(ConnectionHandle(0x0201), 5).await;
+    assert!(hci.controller().written_opcodes.is_empty());
+
+    hci.flush().await;
+    assert_eq!(hci.controller().written_opcodes, [hci::Opcode(0x0C35)]);
+    assert_eq!(hci.in_use(), 0);
+
+    // Nothing left to report.
+    hci.flush().await;
+    assert_eq!(hci.controller().written_opcodes.len(), 1);
+}
+
+#[tokio::test]
+async fn disconnection_frees_buffers() {
+    let mut hci: ReceivePool<_> = ReceivePool::new(
+        PacketSink::new(&[ACL_0201, ACL_0201, &[0x04, 0x05, 4, 0x00, 0x01, 0x02, 0x13]]),
+        251,
+        4,
+    );
+    hci.read().await.unwrap();
+    hci.read().await.unwrap();
+    hci.release(ConnectionHandle(0x0201), 1).await;
+    assert_eq!(hci.in_use(), 2);
+
+    hci.read().await.unwrap();
+    assert_eq!(hci.in_use(), 0);
+    assert_eq!(hci.held(ConnectionHandle(0x0201)), 0);
+
+    // Buffers of the closed connection are not reported.
+    hci.release(ConnectionHandle(0x0201), 1).await;
+    hci.flush().await;
+    assert!(hci.controller().written_opcodes.is_empty());
+}
+
+#[tokio::test]
+async fn untracked_connections_reported_on_read() {
+    let mut hci = ReceivePool::<_, 1>::new(
+        PacketSink::new(&[
+            ACL_0201,
+            ACL_0403,
+            ACL_0403,
+            &[0x04, 0x05, 4, 0x00, 0x01, 0x02, 0x13],
+            ACL_0403,
+        ]),
+        251,
+        4,
+    );
+    for _ in 0..3 {
+        hci.read().await.unwrap();
+    }
+    assert_eq!(hci.in_use(), 1);
+    assert_eq!(hci.held(ConnectionHandle(0x0403)), 0);
+
+    // Each packet of the untracked connection is reported as soon as it is read.
+    assert_eq!(
+        hci.controller().written_opcodes,
+        [hci::Opcode(0x0C35), hci::Opcode(0x0C35)]
+    );
+    hci.release(ConnectionHandle(0x0403), 2).await;
+    hci.flush().await;
+    assert_eq!(hci.controller().written_opcodes.len(), 2);
+
+    // Once the tracked connection closes, its slot is free for the next connection.
+    hci.read().await.unwrap();
+    assert_eq!(hci.in_use(), 0);
+    hci.read().await.unwrap();
+    assert_eq!(hci.in_use(), 1);
+    assert_eq!(hci.held(ConnectionHandle(0x0403)), 1);
+}
+
+#[tokio::test]
+async fn untracked_packet_report() {
+    let mut hci = ReceivePool::<_, 1>::new(RecordingSink::new(), 251, 4);
+    for packet in [ACL_0201, ACL_0403] {
+        hci.controller_mut().read_data = packet.to_vec();
+        hci.read().await.unwrap();
+    }
+
+    assert_eq!(
+        hci.controller().written_data,
+        [1, 0x35, 0x0C, 5, 1, 0x03, 0x04, 0x01, 0x00]
+    );
+    assert_eq!(hci.in_use(), 1);
+}
@@ -0,0 +1,240 @@
+extern crate stm32wb_hci as hci;
+
+use hci::types::{SubrateParameters, SubrateParametersBuilder, SubrateParametersError};
+use std::time::Duration;
+
+fn builder() -> SubrateParametersBuilder {
+    let mut builder = SubrateParametersBuilder::new();
+    builder
+        .with_subrate_range(2, 4)
+        .with_max_latency(10)
+        .with_continuation_number(1)
+        .with_supervision_timeout(Duration::from_secs(5));
+    builder
+}
+
+#[test]
+fn valid() {
+    let params = builder().build().unwrap();
+    assert_eq!(params.subrate(), (2, 4));
+    assert_eq!(params.max_latency(), 10);
+    assert_eq!(params.continuation_number(), 1);
+    assert_eq!(params.supervision_timeout(), Duration::from_secs(5));
+
+    let mut bytes = [0; 10];
+    params.copy_into_slice(&mut bytes);
+
+    // 5000 ms / 10 ms = 500 = 0x01F4
+    assert_eq!(
+        bytes,
+        [0x02, 0x00, 0x04, 0x00, 0x0A, 0x00, 0x01, 0x00, 0xF4, 0x01]
+    );
+}
+
+#[test]
+fn incomplete() {
+    assert_eq!(
+        SubrateParametersBuilder::new()
+            .with_max_latency(10)
+            .with_continuation_number(1)
+            .with_supervision_timeout(Duration::from_secs(5))
+            .build()
+            .err()
+            .unwrap(),
+        SubrateParametersError::Incomplete
+    );
+    assert_eq!(
+        SubrateParametersBuilder::new()
+            .with_subrate_range(2, 4)
+            .with_continuation_number(1)
+            .with_supervision_timeout(Duration::from_secs(5))
+            .build()
+            .err()
+            .unwrap(),
+        SubrateParametersError::Incomplete
+    );
+    assert_eq!(
+        SubrateParametersBuilder::new()
+            .with_subrate_range(2, 4)
+            .with_max_latency(10)
+            .with_supervision_timeout(Duration::from_secs(5))
+            .build()
+            .err()
+            .unwrap(),
+        SubrateParametersError::Incomplete
+    );
+    assert_eq!(
+        SubrateParametersBuilder::new()
+            .with_subrate_range(2, 4)
+            .with_max_latency(10)
+            .with_continuation_number(1)
+            .build()
+            .err()
+            .unwrap(),
+        SubrateParametersError::Incomplete
+    );
+}
+
+#[test]
+fn subrate_factor_zero() {
+    let err = builder().with_subrate_range(0, 4).build().err().unwrap();
+    assert_eq!(err, SubrateParametersError::BadSubrateFactor(0));
+}
+
+#[test]
+fn subrate_factor_too_large() {
+    let err = builder()
+        .with_subrate_range(2, 501)
+        .with_max_latency(0)
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(err, SubrateParametersError::BadSubrateFactor(501));
+}
+
+#[test]
+fn subrate_inverted() {
+    let err = builder().with_subrate_range(4, 2).build().err().unwrap();
+    assert_eq!(err, SubrateParametersError::SubrateInverted(4, 2));
+}
+
+#[test]
+fn max_latency_too_large() {
+    let err = builder()
+        .with_subrate_range(1, 1)
+        .with_continuation_number(0)
+        .with_max_latency(500)
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(err, SubrateParametersError::BadMaxLatency(500));
+}
+
+#[test]
+fn excessive_latency() {
+    // 4 * (123 + 1) = 496 is allowed...
+    builder()
+        .with_max_latency(123)
+        .with_supervision_timeout(Duration::from_secs(10))
+        .build()
+        .unwrap();
+
+    // ... but 4 * (124 + 1) = 500 is not.
+    let err = builder().with_max_latency(124).build().err().unwrap();
+    assert_eq!(err, SubrateParametersError::ExcessiveLatency(4, 124));
+}
+
+#[test]
+fn bad_continuation_number() {
+    let err = builder().with_continuation_number(4).build().err().unwrap();
+    assert_eq!(err, SubrateParametersError::BadContinuationNumber(4));
+}
+
+#[test]
+fn supervision_timeout_too_short() {
+    let err = builder()
+        .with_supervision_timeout(Duration::from_millis(99))
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        SubrateParametersError::SupervisionTimeoutTooShort(
+            Duration::from_millis(99),
+            Duration::from_millis(660)
+        )
+    );
+}
+
+#[test]
+fn supervision_timeout_too_short_for_interval() {
+    // 50 ms * 4 * (10 + 1) * 2 = 4.4 s
+    let err = builder()
+        .with_connection_interval(Duration::from_millis(50))
+        .with_supervision_timeout(Duration::from_millis(4400))
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        SubrateParametersError::SupervisionTimeoutTooShort(
+            Duration::from_millis(4400),
+            Duration::from_millis(4400)
+        )
+    );
+
+    builder()
+        .with_connection_interval(Duration::from_millis(50))
+        .with_supervision_timeout(Duration::from_millis(4410))
+        .build()
+        .unwrap();
+}
+
+#[test]
+fn impossible_supervision_timeout() {
+    // 4 s * 4 * (10 + 1) * 2 = 352 s
+    let err = builder()
+        .with_connection_interval(Duration::from_secs(4))
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        SubrateParametersError::ImpossibleSupervisionTimeout(Duration::from_secs(352))
+    );
+}
+
+#[test]
+fn bad_connection_interval() {
+    let err = builder()
+        .with_connection_interval(Duration::from_millis(7))
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        SubrateParametersError::BadConnectionInterval(Duration::from_millis(7))
+    );
+
+    let err = builder()
+        .with_connection_interval(Duration::from_millis(4001))
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        SubrateParametersError::BadConnectionInterval(Duration::from_millis(4001))
+    );
+}
+
+#[test]
+fn supervision_timeout_too_long() {
+    let err = builder()
+        .with_supervision_timeout(Duration::from_millis(32_010))
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        SubrateParametersError::SupervisionTimeoutTooLong(Duration::from_millis(32_010))
+    );
+}
+
+#[test]
+fn from_bytes() {
+    let params = SubrateParameters::from_bytes(&[
+        0x02, 0x00, 0x04, 0x00, 0x0A, 0x00, 0x01, 0x00, 0xF4, 0x01,
+    ])
+    .unwrap();
+    assert_eq!(params, builder().build().unwrap());
+}
+
+#[test]
+fn from_bytes_invalid() {
+    let err = SubrateParameters::from_bytes(&[
+        0x02, 0x00, 0x04, 0x00, 0x0A, 0x00, 0x04, 0x00, 0xF4, 0x01,
+    ])
+    .err()
+    .unwrap();
+    assert_eq!(err, SubrateParametersError::BadContinuationNumber(4));
+}